@@ -10,12 +10,16 @@ pkg_build_deps=(core/protobuf core/protobuf-rust core/coreutils core/cacerts
   core/rust core/gcc core/git core/pkg-config core/node core/phantomjs core/python2 core/make)
 pkg_exports=(
   [port]=http.port
+  [log_stream_port]=log_stream.port
   [url]=web.app_url
 )
-pkg_exposes=(port)
+pkg_exposes=(port log_stream_port)
 pkg_binds=(
   [router]="port"
 )
+pkg_binds_optional=(
+  [jobsrv]="log_publisher"
+)
 bin="bldr-api"
 pkg_svc_run="$bin start -c ${pkg_svc_config_path}/config.toml"
 
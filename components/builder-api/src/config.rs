@@ -31,6 +31,10 @@ pub struct Config {
     pub http: HttpCfg,
     /// List of net addresses for routing servers to connect to
     pub routers: Vec<RouterAddr>,
+    /// List of job servers to follow live job log output from
+    pub jobsrv: Vec<JobSrvAddr>,
+    /// Listener which streams live job logs to clients
+    pub log_stream: LogStreamCfg,
     pub github: GitHubCfg,
     pub ui: UiCfg,
     /// Depot's configuration
@@ -46,6 +50,8 @@ impl Default for Config {
         Config {
             http: HttpCfg::default(),
             routers: vec![RouterAddr::default()],
+            jobsrv: vec![JobSrvAddr::default()],
+            log_stream: LogStreamCfg::default(),
             github: GitHubCfg::default(),
             ui: UiCfg::default(),
             depot: depot::config::Config::default(),
@@ -107,6 +113,68 @@ impl ToSocketAddrs for HttpCfg {
     }
 }
 
+/// Net address of a job server's log publisher
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct JobSrvAddr {
+    pub host: IpAddr,
+    pub log_publish_port: u16,
+}
+
+impl JobSrvAddr {
+    pub fn log_publish_addr(&self) -> String {
+        format!("tcp://{}:{}", self.host, self.log_publish_port)
+    }
+}
+
+impl Default for JobSrvAddr {
+    fn default() -> Self {
+        JobSrvAddr {
+            host: IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
+            log_publish_port: 5569,
+        }
+    }
+}
+
+/// Listening net address and limits for streaming live job logs. Streams are long-lived, so
+/// they are served apart from the rest of the API, by a thread pool of their own.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct LogStreamCfg {
+    pub listen: IpAddr,
+    pub port: u16,
+    /// URL the API redirects log stream requests to, up to and including the `/v1` path. If not
+    /// set, requests are redirected to `port` on the host they were sent to.
+    pub url: Option<String>,
+    /// Number of logs which may be streamed at once. Requests beyond this are refused.
+    pub max_streams: usize,
+    /// Seconds after which a stream is closed, leaving it to the client to reconnect
+    pub max_duration: u64,
+}
+
+impl Default for LogStreamCfg {
+    fn default() -> Self {
+        LogStreamCfg {
+            listen: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+            port: 9637,
+            url: None,
+            max_streams: 64,
+            max_duration: 3_600,
+        }
+    }
+}
+
+impl ToSocketAddrs for LogStreamCfg {
+    type Iter = IntoIter<SocketAddr>;
+
+    fn to_socket_addrs(&self) -> io::Result<IntoIter<SocketAddr>> {
+        match self.listen {
+            IpAddr::V4(ref a) => (*a, self.port).to_socket_addrs(),
+            IpAddr::V6(ref a) => (*a, self.port).to_socket_addrs(),
+        }
+    }
+}
+
 #[derive(Debug, Default, Deserialize)]
 #[serde(default)]
 pub struct UiCfg {
@@ -148,6 +216,16 @@ mod tests {
         port = 9632
         heartbeat = 9001
 
+        [[jobsrv]]
+        host = "172.18.0.3"
+        log_publish_port = 9633
+
+        [log_stream]
+        port = 9700
+        url = "https://bldr.example.com/v1"
+        max_streams = 16
+        max_duration = 600
+
         [github]
         url = "https://api.github.com"
         client_id = "0c2f738a7d0bd300de10"
@@ -158,6 +236,14 @@ mod tests {
         assert_eq!(&format!("{}", config.http.listen), "::1");
         assert_eq!(config.http.port, 9636);
         assert_eq!(&format!("{}", config.routers[0]), "172.18.0.2:9632");
+        assert_eq!(config.jobsrv[0].log_publish_addr(), "tcp://172.18.0.3:9633");
+        assert_eq!(config.log_stream.port, 9700);
+        assert_eq!(
+            config.log_stream.url,
+            Some("https://bldr.example.com/v1".to_string())
+        );
+        assert_eq!(config.log_stream.max_streams, 16);
+        assert_eq!(config.log_stream.max_duration, 600);
         assert_eq!(config.github.url, "https://api.github.com");
         assert_eq!(config.github.client_id, "0c2f738a7d0bd300de10");
         assert_eq!(
@@ -176,5 +262,8 @@ mod tests {
 
         let config = Config::from_raw(&content).unwrap();
         assert_eq!(config.http.port, 9000);
+        assert_eq!(config.jobsrv[0].log_publish_addr(), "tcp://127.0.0.1:5569");
+        assert_eq!(config.log_stream.port, 9637);
+        assert_eq!(config.log_stream.url, None);
     }
 }
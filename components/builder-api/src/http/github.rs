@@ -0,0 +1,224 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Payloads and signature checks for GitHub's push webhook, which starts builds of the projects
+//! whose plans were changed by a push.
+
+use std::path::Path;
+
+use openssl::hash::MessageDigest;
+use openssl::memcmp;
+use openssl::pkey::PKey;
+use openssl::sign::Signer;
+use protocol::originsrv::OriginProject;
+
+/// Prefix GitHub puts in front of the hex encoded HMAC in the `X-Hub-Signature` header
+const SIGNATURE_PREFIX: &'static str = "sha1=";
+
+/// The parts of a GitHub `push` event needed to decide what to build.
+#[derive(Clone, Debug, Deserialize)]
+pub struct PushEvent {
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    /// SHA of the commit at the head of `git_ref` after the push
+    pub after: String,
+    #[serde(default)]
+    pub deleted: bool,
+    pub repository: Repository,
+    #[serde(default)]
+    pub commits: Vec<Commit>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Repository {
+    pub clone_url: String,
+    #[serde(default)]
+    pub git_url: String,
+    #[serde(default)]
+    pub ssh_url: String,
+    #[serde(default)]
+    pub html_url: String,
+    pub default_branch: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Commit {
+    #[serde(default)]
+    pub added: Vec<String>,
+    #[serde(default)]
+    pub removed: Vec<String>,
+    #[serde(default)]
+    pub modified: Vec<String>,
+}
+
+impl PushEvent {
+    /// Whether the push moved the head of the repository's default branch. Pushes to other
+    /// branches, tags and branch deletions don't trigger builds.
+    pub fn is_default_branch(&self) -> bool {
+        !self.deleted && self.git_ref == format!("refs/heads/{}", self.repository.default_branch)
+    }
+
+    /// Every URL the pushed repository could have been registered under in a project's
+    /// `vcs_data`.
+    pub fn repo_urls(&self) -> Vec<String> {
+        let repo = &self.repository;
+        let mut urls = vec![repo.clone_url.clone()];
+        if !repo.html_url.is_empty() {
+            urls.push(repo.html_url.clone());
+            urls.push(format!("{}.git", repo.html_url));
+        }
+        for url in vec![&repo.git_url, &repo.ssh_url] {
+            if !url.is_empty() {
+                urls.push(url.clone());
+            }
+        }
+        urls.sort();
+        urls.dedup();
+        urls
+    }
+
+    /// Whether the push changed a file in the directory holding the project's plan. A push
+    /// without any commit details is assumed to touch every project.
+    pub fn touches(&self, project: &OriginProject) -> bool {
+        if self.commits.is_empty() {
+            return true;
+        }
+        let plan_dir = match Path::new(project.get_plan_path()).parent() {
+            Some(dir) => dir,
+            None => return true,
+        };
+        self.commits.iter().any(|commit| {
+            commit
+                .added
+                .iter()
+                .chain(commit.removed.iter())
+                .chain(commit.modified.iter())
+                .any(|path| Path::new(path).starts_with(plan_dir))
+        })
+    }
+}
+
+/// Sign a webhook payload the way GitHub does, returning the value of its `X-Hub-Signature`
+/// header.
+pub fn sign(secret: &str, body: &[u8]) -> Option<String> {
+    let key = match PKey::hmac(secret.as_bytes()) {
+        Ok(key) => key,
+        Err(err) => {
+            warn!("Unable to create webhook signing key, err={}", err);
+            return None;
+        }
+    };
+    let mut signer = match Signer::new(MessageDigest::sha1(), &key) {
+        Ok(signer) => signer,
+        Err(err) => {
+            warn!("Unable to create webhook signer, err={}", err);
+            return None;
+        }
+    };
+    let mac = match signer.update(body).and_then(|_| signer.sign_to_vec()) {
+        Ok(mac) => mac,
+        Err(err) => {
+            warn!("Unable to sign webhook payload, err={}", err);
+            return None;
+        }
+    };
+    let hex: Vec<String> = mac.iter().map(|b| format!("{:02x}", b)).collect();
+    Some(format!("{}{}", SIGNATURE_PREFIX, hex.join("")))
+}
+
+/// Check a payload's `X-Hub-Signature` against the one computed with a project's webhook
+/// secret. Projects without a secret never match.
+pub fn verify_signature(secret: &str, body: &[u8], signature: &str) -> bool {
+    if secret.is_empty() {
+        return false;
+    }
+    match sign(secret, body) {
+        Some(ref expected) if expected.len() == signature.len() => {
+            memcmp::eq(expected.as_bytes(), signature.as_bytes())
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+
+    const PUSH: &'static str = include_str!("../../tests/fixtures/github-push.json");
+
+    fn project(plan_path: &str) -> OriginProject {
+        let mut project = OriginProject::new();
+        project.set_plan_path(plan_path.to_string());
+        project
+    }
+
+    #[test]
+    fn verify_signature_of_fixture() {
+        let signature = sign("sekrit", PUSH.as_bytes()).unwrap();
+        assert!(signature.starts_with("sha1="));
+        assert_eq!(signature.len(), 45);
+        assert!(verify_signature("sekrit", PUSH.as_bytes(), &signature));
+        assert!(!verify_signature("other", PUSH.as_bytes(), &signature));
+        assert!(!verify_signature("", PUSH.as_bytes(), &signature));
+        assert!(!verify_signature("sekrit", b"{}", &signature));
+        assert!(!verify_signature("sekrit", PUSH.as_bytes(), "sha1=nope"));
+    }
+
+    #[test]
+    fn sign_matches_github() {
+        // Known HMAC-SHA1 value from RFC 2202, test case 2
+        assert_eq!(
+            sign("Jefe", b"what do ya want for nothing?").unwrap(),
+            "sha1=effcdf6ae5eb2fa2d27416d5f184df9c259a7c79"
+        );
+    }
+
+    #[test]
+    fn push_event_from_fixture() {
+        let push: PushEvent = serde_json::from_str(PUSH).unwrap();
+        assert!(push.is_default_branch());
+        assert_eq!(push.after, "0d1a26e67d8f5eaf1f6ba5c57fc3c7d91ac0fd1c");
+        let urls = push.repo_urls();
+        assert!(urls.contains(
+            &"https://github.com/habitat-sh/core-plans.git".to_string(),
+        ));
+        assert!(urls.contains(
+            &"git@github.com:habitat-sh/core-plans.git".to_string(),
+        ));
+    }
+
+    #[test]
+    fn push_to_other_refs_is_not_built() {
+        let mut push: PushEvent = serde_json::from_str(PUSH).unwrap();
+        push.git_ref = "refs/tags/v1.0.0".to_string();
+        assert!(!push.is_default_branch());
+        push.git_ref = "refs/heads/master".to_string();
+        push.deleted = true;
+        assert!(!push.is_default_branch());
+    }
+
+    #[test]
+    fn touches_projects_with_changed_plan_dirs() {
+        let mut push: PushEvent = serde_json::from_str(PUSH).unwrap();
+        assert!(push.touches(&project("nginx/plan.sh")));
+        assert!(push.touches(&project("redis/plan.sh")));
+        assert!(push.touches(&project("plan.sh")));
+        assert!(!push.touches(&project("nginx-extras/plan.sh")));
+        assert!(!push.touches(&project("zlib/plan.sh")));
+        push.commits.clear();
+        assert!(push.touches(&project("zlib/plan.sh")));
+    }
+}
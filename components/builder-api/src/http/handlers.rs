@@ -23,18 +23,29 @@ use hab_core::package::Plan;
 use hab_core::event::*;
 use hab_net;
 use hab_net::http::controller::*;
+use hab_net::http::headers::{CacheControl, LastEventId, XGitHubEvent, XHubSignature};
 use hab_net::routing::Broker;
+use iron::headers::{ContentType, Location};
+use iron::mime::{Mime, SubLevel, TopLevel};
 use iron::prelude::*;
+use iron::response::WriteBody;
 use iron::status;
 use iron::typemap;
 use params::{Params, Value, FromValue};
 use persistent;
-use protocol::jobsrv::{Job, JobGet, JobLogGet, JobLog, JobSpec, ProjectJobsGet,
+use protocol::jobsrv::{Job, JobCancel, JobGet, JobLogGet, JobLog, JobSpec, ProjectJobsGet,
                        ProjectJobsGetResponse};
 use protocol::originsrv::*;
 use protocol::sessionsrv;
 use protocol::net::{self, NetOk, ErrCode};
+use protobuf::RepeatedField;
 use router::Router;
+use serde_json;
+use url::Url;
+
+use super::github;
+use super::log_stream::{stream_url, LogPublishers, LogStream, LogStreams, LogStreamTarget,
+                        StreamSlots};
 
 // For the initial release, Builder will only be enabled on the "core"
 // origin. Later, we'll roll it out to other origins; at that point,
@@ -46,6 +57,8 @@ define_event_log!();
 #[derive(Clone, Serialize, Deserialize)]
 struct JobCreateReq {
     project_id: String,
+    /// Branch, tag or commit SHA to build. The repository's default branch is built if unset.
+    vcs_ref: Option<String>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -53,12 +66,18 @@ struct ProjectCreateReq {
     origin: String,
     plan_path: String,
     github: GitHubProject,
+    /// Seconds a build of the project may run for before being stopped. Worker default if unset.
+    build_timeout: Option<u64>,
+    /// Secret shared with GitHub to sign push notifications for the project's repository
+    webhook_secret: Option<String>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
 struct ProjectUpdateReq {
     plan_path: String,
     github: GitHubProject,
+    build_timeout: Option<u64>,
+    webhook_secret: Option<String>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -114,23 +133,33 @@ pub fn github_authenticate(req: &mut Request) -> IronResult<Response> {
 
 pub fn job_create(req: &mut Request) -> IronResult<Response> {
     let mut project_get = OriginProjectGet::new();
-    {
+    let vcs_ref = {
         match req.get::<bodyparser::Struct<JobCreateReq>>() {
-            Ok(Some(body)) => project_get.set_name(body.project_id),
+            Ok(Some(body)) => {
+                project_get.set_name(body.project_id);
+                body.vcs_ref
+            }
             _ => return Ok(Response::with(status::UnprocessableEntity)),
         }
-    }
+    };
     // TODO: SA - Eliminate need to clone the session
     let session = req.extensions.get::<Authenticated>().unwrap().clone();
     let mut conn = Broker::connect().unwrap();
-    let project = match conn.route::<OriginProjectGet, OriginProject>(&project_get) {
+    let mut project = match conn.route::<OriginProjectGet, OriginProject>(&project_get) {
         Ok(project) => project,
         Err(err) => return Ok(render_net_error(&err)),
     };
+    // Workers have no use for the secret, so keep it from travelling along with the job
+    project.clear_webhook_secret();
 
     let mut job_spec: JobSpec = JobSpec::new();
     job_spec.set_owner_id(session.get_id());
     job_spec.set_project(project);
+    if let Some(vcs_ref) = vcs_ref {
+        if !vcs_ref.is_empty() {
+            job_spec.set_vcs_ref(vcs_ref);
+        }
+    }
 
     match conn.route::<JobSpec, Job>(&job_spec) {
         Ok(job) => {
@@ -162,6 +191,44 @@ pub fn job_show(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+pub fn job_cancel(req: &mut Request) -> IronResult<Response> {
+    let session_id = {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        session.get_id()
+    };
+    let id = {
+        let params = req.extensions.get::<Router>().unwrap();
+        match params.find("id").unwrap().parse::<u64>() {
+            Ok(id) => id,
+            Err(_) => return Ok(Response::with(status::BadRequest)),
+        }
+    };
+    let mut conn = Broker::connect().unwrap();
+    let mut get = JobGet::new();
+    get.set_id(id);
+    let job = match conn.route::<JobGet, Job>(&get) {
+        Ok(job) => job,
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+
+    if !try!(check_origin_access(
+        req,
+        session_id,
+        job.get_project().get_origin_name(),
+    ))
+    {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut request = JobCancel::new();
+    request.set_id(id);
+    request.set_requestor_id(session_id);
+    match conn.route::<JobCancel, Job>(&request) {
+        Ok(job) => Ok(render_json(status::Ok, &job)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
 pub fn job_log(req: &mut Request) -> IronResult<Response> {
     let start = {
         let params = req.get_ref::<Params>().unwrap();
@@ -211,6 +278,165 @@ pub fn job_log(req: &mut Request) -> IronResult<Response> {
 
 }
 
+/// Redirects a request to stream a job's log to the log stream listener, which serves
+/// long-running streams apart from the rest of the API.
+pub fn job_log_stream_redirect(req: &mut Request) -> IronResult<Response> {
+    let id = {
+        let params = req.extensions.get::<Router>().unwrap();
+        match params.find("id").unwrap().parse::<u64>() {
+            Ok(id) => id,
+            Err(_) => return Ok(Response::with(status::BadRequest)),
+        }
+    };
+    let target = req.get::<persistent::Read<LogStreamTarget>>().unwrap();
+    let (ref base, port) = *target;
+    let req_url: Url = req.url.clone().into();
+    match stream_url(base.as_ref().map(String::as_str), port, &req_url, id) {
+        Some(url) => {
+            let mut response = Response::with(status::TemporaryRedirect);
+            response.headers.set(Location(url.into_string()));
+            Ok(response)
+        }
+        None => Ok(Response::with(status::InternalServerError)),
+    }
+}
+
+/// Streams a job's log as Server-Sent Events until the log is complete, starting from line
+/// `start` or just after the `Last-Event-ID` of a reconnecting client.
+pub fn job_log_stream(req: &mut Request) -> IronResult<Response> {
+    let slot = {
+        let slots = req.get::<persistent::Read<LogStreams>>().unwrap();
+        match StreamSlots::acquire(&slots) {
+            Some(slot) => slot,
+            None => return Ok(Response::with(status::ServiceUnavailable)),
+        }
+    };
+    let start = match log_stream_start(req) {
+        Some(start) => start,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let include_color = req.get_ref::<Params>()
+        .unwrap()
+        .find(&["color"])
+        .and_then(FromValue::from_value)
+        .unwrap_or(false);
+    let id = {
+        let params = req.extensions.get::<Router>().unwrap();
+        match params.find("id").unwrap().parse::<u64>() {
+            Ok(id) => id,
+            Err(_) => return Ok(Response::with(status::BadRequest)),
+        }
+    };
+    let mut conn = Broker::connect().unwrap();
+    let mut request = JobGet::new();
+    request.set_id(id);
+    if let Err(err) = conn.route::<JobGet, Job>(&request) {
+        return Ok(render_net_error(&err));
+    }
+
+    let publishers = req.get::<persistent::Read<LogPublishers>>().unwrap();
+    let stream = LogStream::new(id, start, include_color, (*publishers).clone(), slot);
+    let mut response = Response::with((status::Ok, Box::new(stream) as Box<WriteBody>));
+    response.headers.set(ContentType(Mime(
+        TopLevel::Text,
+        SubLevel::Ext(String::from("event-stream")),
+        vec![],
+    )));
+    response.headers.set(CacheControl(String::from("no-cache")));
+    Ok(response)
+}
+
+fn log_stream_start(req: &mut Request) -> Option<u64> {
+    if let Some(&LastEventId(ref id)) = req.headers.get::<LastEventId>() {
+        return id.parse::<u64>().ok().map(|line| line + 1);
+    }
+    match req.get_ref::<Params>().unwrap().find(&["start"]) {
+        Some(&Value::String(ref val)) => val.parse::<u64>().ok(),
+        _ => Some(0),
+    }
+}
+
+/// Endpoint for GitHub's push webhook. Creates a job for each project in the origin that is
+/// built from the pushed repository, whose webhook secret signed the payload, and whose plan
+/// directory was changed by a push to the default branch.
+pub fn notify_github(req: &mut Request) -> IronResult<Response> {
+    let origin = {
+        let params = req.extensions.get::<Router>().unwrap();
+        params.find("origin").unwrap().to_string()
+    };
+    match req.headers.get::<XGitHubEvent>() {
+        Some(&XGitHubEvent(ref event)) if event == "push" => (),
+        // Sent once when the webhook is added to a repository
+        Some(&XGitHubEvent(ref event)) if event == "ping" => {
+            return Ok(Response::with(status::Ok))
+        }
+        Some(_) => return Ok(Response::with(status::NoContent)),
+        None => return Ok(Response::with(status::BadRequest)),
+    }
+    let signature = match req.headers.get::<XHubSignature>() {
+        Some(&XHubSignature(ref signature)) => signature.clone(),
+        None => return Ok(Response::with(status::Unauthorized)),
+    };
+    let body = match req.get::<bodyparser::Raw>() {
+        Ok(Some(body)) => body,
+        _ => return Ok(Response::with(status::UnprocessableEntity)),
+    };
+    let push: github::PushEvent = match serde_json::from_str(&body) {
+        Ok(push) => push,
+        Err(err) => {
+            debug!("Unable to parse GitHub push event, err={}", err);
+            return Ok(Response::with(status::UnprocessableEntity));
+        }
+    };
+
+    let mut request = OriginProjectListRequest::new();
+    request.set_origin(origin);
+    request.set_vcs_data(RepeatedField::from_vec(push.repo_urls()));
+    let mut conn = Broker::connect().unwrap();
+    let projects = match conn.route::<OriginProjectListRequest, OriginProjectListResponse>(
+        &request,
+    ) {
+        Ok(mut response) => response.take_projects().into_vec(),
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+    // Unsigned requests get the same answer whether or not the origin builds the repository,
+    // so that callers can't probe for which repositories it builds
+    let projects: Vec<OriginProject> = projects
+        .into_iter()
+        .filter(|project| {
+            github::verify_signature(project.get_webhook_secret(), body.as_bytes(), &signature)
+        })
+        .collect();
+    if projects.is_empty() {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut jobs = vec![];
+    if push.is_default_branch() {
+        for mut project in projects.into_iter().filter(|project| push.touches(project)) {
+            project.clear_webhook_secret();
+            let mut job_spec = JobSpec::new();
+            job_spec.set_owner_id(project.get_owner_id());
+            job_spec.set_vcs_ref(push.after.clone());
+            job_spec.set_project(project);
+            match conn.route::<JobSpec, Job>(&job_spec) {
+                Ok(job) => {
+                    log_event!(
+                        req,
+                        Event::JobCreate {
+                            package: job.get_project().get_id().to_string(),
+                            account: job.get_owner_id().to_string(),
+                        }
+                    );
+                    jobs.push(job);
+                }
+                Err(err) => return Ok(render_net_error(&err)),
+            }
+        }
+    }
+    Ok(render_json(status::Ok, &jobs))
+}
+
 /// Endpoint for determining availability of builder-api components.
 ///
 /// Returns a status 200 on success. Any non-200 responses are an outage or a partial outage.
@@ -280,6 +506,12 @@ pub fn project_create(req: &mut Request) -> IronResult<Response> {
             origin_get.set_name(body.origin);
             project.set_plan_path(body.plan_path);
             project.set_vcs_type(String::from("git"));
+            if let Some(timeout) = body.build_timeout {
+                project.set_build_timeout(timeout);
+            }
+            if let Some(secret) = body.webhook_secret {
+                project.set_webhook_secret(secret);
+            }
             match github.repo(
                 &session.get_token(),
                 &body.github.organization,
@@ -436,6 +668,12 @@ pub fn project_update(req: &mut Request) -> IronResult<Response> {
             }
             project.set_vcs_type(String::from("git"));
             project.set_plan_path(body.plan_path);
+            if let Some(timeout) = body.build_timeout {
+                project.set_build_timeout(timeout);
+            }
+            if let Some(secret) = body.webhook_secret {
+                project.set_webhook_secret(secret);
+            }
             match github.repo(&session_token, &body.github.organization, &body.github.repo) {
                 Ok(repo) => project.set_vcs_data(repo.clone_url),
                 Err(_) => return Ok(Response::with((status::UnprocessableEntity, "rg:pu:1"))),
@@ -0,0 +1,433 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Streams a job's log output to HTTP clients as Server-Sent Events while the job runs.
+//!
+//! Lines the job server has already recorded are read with `JobLogGet`, and new lines are
+//! followed from the job servers' log publishers until the job server reports the log complete.
+//!
+//! Streams hold on to their thread for as long as they run, so they're served by a listener of
+//! their own; the API redirects clients to it. How many streams run at once, and for how long,
+//! is capped by `StreamSlots`.
+
+use std::cmp;
+use std::io::{self, Write};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use hab_net;
+use hab_net::routing::{Broker, BrokerConn};
+use hab_net::server::ZMQ_CONTEXT;
+use iron::response::WriteBody;
+use iron::typemap;
+use protobuf::parse_from_bytes;
+use protocol::jobsrv::{Job, JobGet, JobLog, JobLogChunk, JobLogGet, JobState};
+use protocol::net::ErrCode;
+use url::Url;
+use zmq;
+
+use error::{Error, Result};
+
+/// ZMQ protocol frame to indicate a log line is being sent
+const LOG_LINE: &'static [u8] = b"L";
+/// ZMQ protocol frame to indicate a log has finished
+const LOG_COMPLETE: &'static [u8] = b"C";
+/// Milliseconds to wait for log output before sending a keep-alive and checking on the job
+const KEEPALIVE_MS: i64 = 15_000;
+/// How many times to ask the job server for lines missing from the stream before giving up
+const CATCH_UP_ATTEMPTS: u32 = 5;
+/// Milliseconds to give the job server to record missing lines before asking again
+const CATCH_UP_RETRY_MS: u64 = 200;
+
+/// Addresses of the job servers' log publishers to subscribe to
+pub struct LogPublishers;
+
+impl typemap::Key for LogPublishers {
+    type Value = Vec<String>;
+}
+
+/// Limits on the log streams served at once
+pub struct LogStreams;
+
+impl typemap::Key for LogStreams {
+    type Value = Arc<StreamSlots>;
+}
+
+/// Where the API redirects log stream requests to: a configured URL, or else the port of the log
+/// stream listener
+pub struct LogStreamTarget;
+
+impl typemap::Key for LogStreamTarget {
+    type Value = (Option<String>, u16);
+}
+
+/// Counts the log streams being served, so that no more than `max` run at once, and bounds how
+/// long each may run.
+pub struct StreamSlots {
+    active: AtomicUsize,
+    max: usize,
+    max_duration: Duration,
+}
+
+impl StreamSlots {
+    pub fn new(max: usize, max_duration: Duration) -> Self {
+        StreamSlots {
+            active: AtomicUsize::new(0),
+            max: max,
+            max_duration: max_duration,
+        }
+    }
+
+    /// Take a slot for a new stream, or `None` if every slot is in use. The slot is given back
+    /// when the returned `StreamSlot` is dropped.
+    pub fn acquire(slots: &Arc<StreamSlots>) -> Option<StreamSlot> {
+        let mut active = slots.active.load(Ordering::SeqCst);
+        loop {
+            if active >= slots.max {
+                return None;
+            }
+            let prev = slots.active.compare_and_swap(active, active + 1, Ordering::SeqCst);
+            if prev == active {
+                return Some(StreamSlot(slots.clone()));
+            }
+            active = prev;
+        }
+    }
+}
+
+/// A stream's hold on one of the `StreamSlots`
+pub struct StreamSlot(Arc<StreamSlots>);
+
+impl Drop for StreamSlot {
+    fn drop(&mut self) {
+        self.0.active.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// URL of a job's log stream on the log stream listener. `base` is the configured URL of the
+/// listener; without one, the stream is on `port` of the host the request was sent to.
+pub fn stream_url(base: Option<&str>, port: u16, req_url: &Url, job_id: u64) -> Option<Url> {
+    let mut url = match base {
+        Some(base) => {
+            match Url::parse(&format!("{}/", base.trim_right_matches('/'))) {
+                Ok(url) => url,
+                Err(err) => {
+                    warn!("Invalid log stream url, url={}, err={}", base, err);
+                    return None;
+                }
+            }
+        }
+        None => {
+            let mut url = req_url.clone();
+            if url.set_port(Some(port)).is_err() {
+                return None;
+            }
+            url.set_path("/v1/");
+            url
+        }
+    };
+    url = match url.join(&format!("jobs/{}/log/stream", job_id)) {
+        Ok(url) => url,
+        Err(_) => return None,
+    };
+    url.set_query(req_url.query());
+    Some(url)
+}
+
+/// Subscription topic for the log of the given job on a job server's log publisher
+fn log_topic(job_id: u64) -> String {
+    format!("{}:", job_id)
+}
+
+enum LogMsg {
+    Line(JobLogChunk),
+    Complete,
+}
+
+/// Response body which writes a job's log as a stream of events. Each line is sent as a
+/// `message` event whose id is its zero-indexed line number, so a reconnecting client can
+/// resume from its `Last-Event-ID`. A final `complete` event carries the total line count.
+pub struct LogStream {
+    job_id: u64,
+    /// Zero-indexed line of the log output to send next
+    next: u64,
+    include_color: bool,
+    publishers: Vec<String>,
+    /// When the stream is closed, whether or not the log is complete
+    deadline: Instant,
+    _slot: StreamSlot,
+}
+
+impl LogStream {
+    pub fn new(
+        job_id: u64,
+        start: u64,
+        include_color: bool,
+        publishers: Vec<String>,
+        slot: StreamSlot,
+    ) -> Self {
+        let deadline = Instant::now() + slot.0.max_duration;
+        LogStream {
+            job_id: job_id,
+            next: start,
+            include_color: include_color,
+            publishers: publishers,
+            deadline: deadline,
+            _slot: slot,
+        }
+    }
+
+    fn stream(&mut self, out: &mut Write) -> Result<()> {
+        // Subscribe before reading what has been recorded so far, so that no line published
+        // in between goes missing
+        let sock = try!(self.subscribe());
+        let mut conn = try!(Broker::connect().map_err(Error::NetError));
+        if try!(self.catch_up(&mut conn, out)) {
+            return self.send_complete(out);
+        }
+        loop {
+            let now = Instant::now();
+            if now >= self.deadline {
+                // The client picks up where it left off when it reconnects
+                try!(out.write_all(b": time limit reached\n\n"));
+                try!(out.flush());
+                return Ok(());
+            }
+            match try!(self.recv(&sock, self.deadline - now)) {
+                Some(LogMsg::Line(mut chunk)) => {
+                    if chunk.get_seq() == 0 {
+                        continue;
+                    }
+                    let line = chunk.get_seq() - 1;
+                    if line < self.next {
+                        // Already sent while catching up
+                        continue;
+                    }
+                    // Lines published before the subscription took effect, which the job
+                    // server may not have recorded yet
+                    let mut attempts = 0;
+                    while line > self.next {
+                        if attempts == CATCH_UP_ATTEMPTS {
+                            // Lines are never skipped; the client picks up from the last one it
+                            // got when it reconnects
+                            try!(out.write_all(b": log lines missing\n\n"));
+                            try!(out.flush());
+                            return Ok(());
+                        }
+                        if attempts > 0 {
+                            thread::sleep(Duration::from_millis(CATCH_UP_RETRY_MS));
+                        }
+                        if try!(self.catch_up(&mut conn, out)) {
+                            return self.send_complete(out);
+                        }
+                        attempts += 1;
+                    }
+                    if line < self.next {
+                        continue;
+                    }
+                    if !self.include_color {
+                        chunk.strip_ansi();
+                    }
+                    try!(self.send_line(out, chunk.get_content()));
+                }
+                Some(LogMsg::Complete) => {
+                    try!(self.catch_up(&mut conn, out));
+                    return self.send_complete(out);
+                }
+                None => {
+                    // Comments keep proxies from closing an idle connection, and fail once the
+                    // client has gone away
+                    try!(out.write_all(b": keep-alive\n\n"));
+                    try!(out.flush());
+                    // A worker that goes away mid-build never completes the log
+                    if try!(self.is_finished(&mut conn)) {
+                        try!(self.catch_up(&mut conn, out));
+                        return self.send_complete(out);
+                    }
+                }
+            }
+        }
+    }
+
+    fn subscribe(&self) -> Result<zmq::Socket> {
+        let sock = try!((**ZMQ_CONTEXT).as_mut().socket(zmq::SUB));
+        try!(sock.set_subscribe(log_topic(self.job_id).as_bytes()));
+        for addr in &self.publishers {
+            try!(sock.connect(addr));
+        }
+        Ok(sock)
+    }
+
+    fn recv(&self, sock: &zmq::Socket, time_left: Duration) -> Result<Option<LogMsg>> {
+        {
+            let mut items = [sock.as_poll_item(zmq::POLLIN)];
+            let time_left_ms = time_left.as_secs() as i64 * 1_000 +
+                time_left.subsec_nanos() as i64 / 1_000_000;
+            try!(zmq::poll(&mut items, cmp::min(KEEPALIVE_MS, time_left_ms)));
+            if (items[0].get_revents() & zmq::POLLIN) == 0 {
+                return Ok(None);
+            }
+        }
+        // 3 frames per message: the job's topic, the message type and a protobuf message
+        try!(sock.recv_bytes(0));
+        let code = try!(sock.recv_bytes(0));
+        let body = try!(sock.recv_bytes(0));
+        if code.as_slice() == LOG_LINE {
+            Ok(Some(LogMsg::Line(try!(parse_from_bytes::<JobLogChunk>(&body)))))
+        } else if code.as_slice() == LOG_COMPLETE {
+            Ok(Some(LogMsg::Complete))
+        } else {
+            warn!("Unrecognized log protocol code: {:?}", code);
+            self.recv(sock, time_left)
+        }
+    }
+
+    /// Send the lines from `next` onwards that the job server has already recorded. Returns
+    /// whether the log is complete.
+    fn catch_up(&mut self, conn: &mut BrokerConn, out: &mut Write) -> Result<bool> {
+        let mut request = JobLogGet::new();
+        request.set_id(self.job_id);
+        request.set_start(self.next);
+        match conn.route::<JobLogGet, JobLog>(&request) {
+            Ok(mut log) => {
+                if !self.include_color {
+                    log.strip_ansi();
+                }
+                for line in log.get_content() {
+                    try!(self.send_line(out, line));
+                }
+                Ok(log.get_is_complete())
+            }
+            // Nothing has been logged for the job yet
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => Ok(false),
+            Err(err) => Err(Error::NetError(hab_net::Error::Net(err))),
+        }
+    }
+
+    fn is_finished(&self, conn: &mut BrokerConn) -> Result<bool> {
+        let mut request = JobGet::new();
+        request.set_id(self.job_id);
+        match conn.route::<JobGet, Job>(&request) {
+            Ok(job) => {
+                match job.get_state() {
+                    JobState::Complete |
+                    JobState::Failed |
+                    JobState::Rejected |
+                    JobState::Canceled => Ok(true),
+                    _ => Ok(false),
+                }
+            }
+            Err(err) => Err(Error::NetError(hab_net::Error::Net(err))),
+        }
+    }
+
+    fn send_line(&mut self, out: &mut Write, line: &str) -> Result<()> {
+        let line = line.trim_right_matches(|c: char| c == '\n' || c == '\r');
+        try!(write!(out, "id: {}\ndata: {}\n\n", self.next, line));
+        try!(out.flush());
+        self.next += 1;
+        Ok(())
+    }
+
+    fn send_complete(&self, out: &mut Write) -> Result<()> {
+        try!(write!(out, "event: complete\ndata: {}\n\n", self.next));
+        try!(out.flush());
+        Ok(())
+    }
+}
+
+impl WriteBody for LogStream {
+    fn write_body(&mut self, out: &mut Write) -> io::Result<()> {
+        match self.stream(out) {
+            Ok(()) => Ok(()),
+            Err(Error::IO(err)) => Err(err),
+            Err(err) => {
+                warn!("Unable to stream log for job {}, err={}", self.job_id, err);
+                Err(io::Error::new(io::ErrorKind::Other, err.to_string()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slots(max: usize) -> Arc<StreamSlots> {
+        Arc::new(StreamSlots::new(max, Duration::from_secs(60)))
+    }
+
+    fn stream(start: u64) -> LogStream {
+        let slot = StreamSlots::acquire(&slots(1)).unwrap();
+        LogStream::new(1, start, false, vec![], slot)
+    }
+
+    #[test]
+    fn stream_slots_are_capped() {
+        let slots = slots(2);
+        let first = StreamSlots::acquire(&slots).unwrap();
+        let second = StreamSlots::acquire(&slots).unwrap();
+        assert!(StreamSlots::acquire(&slots).is_none());
+        drop(first);
+        let third = StreamSlots::acquire(&slots).unwrap();
+        assert!(StreamSlots::acquire(&slots).is_none());
+        drop(second);
+        drop(third);
+        assert_eq!(slots.active.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn stream_url_on_the_request_host() {
+        let req = Url::parse("http://localhost:9636/v1/jobs/12/log/stream?color=true").unwrap();
+        assert_eq!(
+            stream_url(None, 9637, &req, 12).unwrap().as_str(),
+            "http://localhost:9637/v1/jobs/12/log/stream?color=true"
+        );
+    }
+
+    #[test]
+    fn stream_url_from_config() {
+        let req = Url::parse("http://localhost:9636/v1/jobs/12/log/stream").unwrap();
+        assert_eq!(
+            stream_url(Some("https://bldr.example.com/logs/v1/"), 9637, &req, 12)
+                .unwrap()
+                .as_str(),
+            "https://bldr.example.com/logs/v1/jobs/12/log/stream"
+        );
+        assert!(stream_url(Some("not a url"), 9637, &req, 12).is_none());
+    }
+
+    #[test]
+    fn log_topic_is_unique_per_job() {
+        assert_eq!(log_topic(12), "12:");
+        assert!(!log_topic(123).starts_with(&log_topic(12)));
+    }
+
+    #[test]
+    fn lines_are_sent_as_numbered_events() {
+        let mut out = vec![];
+        let mut log = stream(4);
+        log.send_line(&mut out, "» Building core/nginx\n").unwrap();
+        log.send_line(&mut out, "done").unwrap();
+        log.send_complete(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "id: 4\ndata: » Building core/nginx\n\n\
+             id: 5\ndata: done\n\n\
+             event: complete\ndata: 6\n\n"
+        );
+    }
+}
@@ -14,10 +14,13 @@
 
 //! A module containing the HTTP server and handlers for servicing client requests
 
+pub mod github;
 pub mod handlers;
+pub mod log_stream;
 
 use std::sync::{mpsc, Arc};
 use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
 use depot;
 use hab_net::http::middleware::*;
@@ -31,10 +34,14 @@ use staticfile::Static;
 use config::Config;
 use error::Result;
 use self::handlers::*;
+use self::log_stream::{LogPublishers, LogStreams, LogStreamTarget, StreamSlots};
 
 // Iron defaults to a threadpool of size `8 * num_cpus`.
 // See: http://172.16.2.131:9633/iron/prelude/struct.Iron.html#method.http
 const HTTP_THREAD_COUNT: usize = 128;
+/// Threads of the log stream listener beyond one per stream, left free to turn away requests
+/// once every stream slot is taken
+const LOG_STREAM_SPARE_THREADS: usize = 4;
 
 /// Create a new `iron::Chain` containing a Router and it's required middleware
 pub fn router(config: Arc<Config>) -> Result<Chain> {
@@ -46,7 +53,11 @@ pub fn router(config: Arc<Config>) -> Result<Chain> {
 
         jobs: post "/jobs" => XHandler::new(job_create).before(basic.clone()),
         job: get "/jobs/:id" => job_show,
+        job_cancel: post "/jobs/:id/cancel" => XHandler::new(job_cancel).before(basic.clone()),
         job_log: get "/jobs/:id/log" => job_log,
+        job_log_stream: get "/jobs/:id/log/stream" => job_log_stream_redirect,
+
+        notify_github: post "/notify/github/:origin" => notify_github,
 
         user_invitations: get "/user/invitations" => {
             XHandler::new(list_account_invitations).before(basic.clone())
@@ -71,6 +82,9 @@ pub fn router(config: Arc<Config>) -> Result<Chain> {
     chain.link(persistent::Read::<GitHubCli>::both(
         GitHubClient::new(&*config),
     ));
+    chain.link(Read::<LogStreamTarget>::both(
+        (config.log_stream.url.clone(), config.log_stream.port),
+    ));
     chain.link(Read::<EventLog>::both(
         EventLogger::new(&config.log_dir, config.events_enabled),
     ));
@@ -79,6 +93,22 @@ pub fn router(config: Arc<Config>) -> Result<Chain> {
     Ok(chain)
 }
 
+/// Create a new `iron::Chain` for the log stream listener
+pub fn log_stream_router(config: Arc<Config>) -> Result<Chain> {
+    let router = router!(
+        job_log_stream: get "/jobs/:id/log/stream" => job_log_stream,
+    );
+    let mut chain = Chain::new(router);
+    let log_publishers = config.jobsrv.iter().map(|j| j.log_publish_addr()).collect();
+    chain.link(Read::<LogPublishers>::both(log_publishers));
+    chain.link(Read::<LogStreams>::both(Arc::new(StreamSlots::new(
+        config.log_stream.max_streams,
+        Duration::from_secs(config.log_stream.max_duration),
+    ))));
+    chain.link_after(Cors);
+    Ok(chain)
+}
+
 /// Create a new HTTP listener and run it in a separate thread. This function will block the calling
 /// thread until the new listener has successfully started.
 ///
@@ -93,7 +123,7 @@ pub fn router(config: Arc<Config>) -> Result<Chain> {
 pub fn run(config: Arc<Config>) -> Result<JoinHandle<()>> {
     let (tx, rx) = mpsc::sync_channel(1);
 
-    let depot = depot::DepotUtil::new(config.depot.clone());
+    let depot = try!(depot::DepotUtil::new(config.depot.clone()));
     let depot_chain = try!(depot::server::router(depot));
 
     let mut mount = Mount::new();
@@ -104,6 +134,24 @@ pub fn run(config: Arc<Config>) -> Result<JoinHandle<()>> {
     let chain = try!(router(config.clone()));
     mount.mount("/v1", chain).mount("/v1/depot", depot_chain);
 
+    let mut log_stream_mount = Mount::new();
+    log_stream_mount.mount("/v1", try!(log_stream_router(config.clone())));
+
+    let log_stream_config = config.clone();
+    let (log_stream_tx, log_stream_rx) = mpsc::sync_channel(1);
+    thread::Builder::new()
+        .name("log-stream-srv".to_string())
+        .spawn(move || {
+            let mut server = Iron::new(log_stream_mount);
+            server.threads = log_stream_config.log_stream.max_streams + LOG_STREAM_SPARE_THREADS;
+            server.http(&log_stream_config.log_stream).unwrap();
+            log_stream_tx.send(()).unwrap();
+        })
+        .unwrap();
+    if let Err(e) = log_stream_rx.recv() {
+        panic!("log-stream-srv thread startup error, err={}", e);
+    }
+
     let handle = thread::Builder::new()
         .name("http-srv".to_string())
         .spawn(move || {
@@ -24,6 +24,7 @@ extern crate iron;
 #[macro_use]
 extern crate log;
 extern crate mount;
+extern crate openssl;
 extern crate params;
 extern crate persistent;
 extern crate protobuf;
@@ -35,6 +36,7 @@ extern crate serde_json;
 extern crate staticfile;
 extern crate toml;
 extern crate unicase;
+extern crate url;
 extern crate zmq;
 
 pub mod config;
@@ -23,7 +23,6 @@ extern crate hyper_openssl;
 extern crate log;
 extern crate pbr;
 extern crate protobuf;
-extern crate rand;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
@@ -31,28 +30,34 @@ extern crate serde_derive;
 #[macro_use]
 extern crate serde_json;
 extern crate tee;
+#[cfg(test)]
+extern crate tempdir;
 extern crate url;
 
 pub mod error;
 pub use error::{Error, Result};
 
-use std::fs::{self, File};
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use std::string::ToString;
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
 
 use broadcast::BroadcastWriter;
-use hab_core::package::{Identifiable, PackageArchive};
+use hab_core::os::process;
+use hab_core::package::{Identifiable, PackageArchive, PackageTarget};
 use hab_http::ApiClient;
 use hyper::client::{Body, IntoUrl, Response, RequestBuilder};
 use hyper::status::StatusCode;
-use hyper::header::{Authorization, Bearer};
+use hyper::header::{Authorization, Bearer, ByteRangeSpec, ContentRange, ContentRangeSpec, Range};
 use hyper::Url;
 use protobuf::core::ProtobufEnum;
 use protocol::{originsrv, net};
-use rand::{Rng, thread_rng};
 use tee::TeeReader;
 
+/// Number of downloads this process has started, which tells apart the files they write to
+static DOWNLOADS: AtomicUsize = ATOMIC_USIZE_INIT;
+
 header! { (XFileName, "X-Filename") => [String] }
 header! { (ETag, "ETag") => [String] }
 
@@ -234,6 +239,7 @@ impl Client {
     {
         self.download(
             &format!("origins/{}/keys/{}", origin, revision),
+            None,
             dst_path.as_ref(),
             progress,
         )
@@ -377,7 +383,7 @@ impl Client {
         }
     }
 
-    /// Download the latest release of a package.
+    /// Download the latest release of a package built for `target`.
     ///
     /// An optional version and release can be specified which, when provided, will increase
     /// specificity of the release retrieved. Specifying a version and no release will retrieve
@@ -392,6 +398,7 @@ impl Client {
     pub fn fetch_package<D, I, P>(
         &self,
         ident: &I,
+        target: &PackageTarget,
         dst_path: &P,
         progress: Option<D>,
     ) -> Result<PackageArchive>
@@ -403,7 +410,12 @@ impl Client {
         // JW TODO: We need to add a channel scoped /download route to the API server. Technically
         // this is wrong because we only want to download packages that are in the channel we
         // specified to the API client
-        match self.download(&package_download(ident), dst_path.as_ref(), progress) {
+        match self.download(
+            &package_download(ident),
+            Some(target),
+            dst_path.as_ref(),
+            progress,
+        ) {
             Ok(file) => Ok(PackageArchive::new(PathBuf::from(file))),
             Err(e) => Err(e),
         }
@@ -412,7 +424,8 @@ impl Client {
     /// Returns a package struct for the latest package.
     ///
     /// An optional version can be specified which will scope the release returned to the latest
-    /// release of that package.
+    /// release of that package. When a target is given, only releases built for that target are
+    /// considered; otherwise the Depot picks the target from our `User-Agent`.
     ///
     /// # Failures
     ///
@@ -422,6 +435,7 @@ impl Client {
         &self,
         package: &I,
         channel: Option<&str>,
+        target: Option<&PackageTarget>,
     ) -> Result<originsrv::OriginPackage>
     where
         I: Identifiable,
@@ -436,7 +450,9 @@ impl Client {
         if !package.fully_qualified() {
             url.push_str("/latest");
         }
-        let mut res = self.0.get(&url).send()?;
+        let mut res = self.0
+            .get_with_custom_url(&url, |u| with_target(u, target))
+            .send()?;
         if res.status != StatusCode::Ok {
             return Err(err_from_response(res));
         }
@@ -610,6 +626,46 @@ impl Client {
         }
     }
 
+    /// Returns the identifier of every package release in a channel of an origin.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * Channel cannot be found
+    pub fn list_channel_packages(
+        &self,
+        origin: &str,
+        channel: &str,
+    ) -> Result<Vec<hab_core::package::PackageIdent>> {
+        let mut packages = Vec::new();
+        loop {
+            let path = format!("channels/{}/{}/pkgs", origin, channel);
+            let range = packages.len().to_string();
+            let mut res = self.0
+                .get_with_custom_url(&path, |url| {
+                    url.query_pairs_mut().append_pair("range", &range);
+                })
+                .send()?;
+            let package_results: PackageResults<hab_core::package::PackageIdent> =
+                match res.status {
+                    StatusCode::Ok |
+                    StatusCode::PartialContent => {
+                        let mut encoded = String::new();
+                        try!(res.read_to_string(&mut encoded));
+                        try!(serde_json::from_str(&encoded))
+                    }
+                    _ => return Err(err_from_response(res)),
+                };
+            let received = package_results.package_list.len();
+            packages.extend(package_results.package_list);
+            // The Depot pages its results, so keep asking until we have them all
+            if received == 0 || packages.len() as isize >= package_results.total_count {
+                break;
+            }
+        }
+        Ok(packages)
+    }
+
     /// Returns a vector of PackageIdent structs
     ///
     /// # Failures
@@ -638,30 +694,85 @@ impl Client {
         rb.header(Authorization(Bearer { token: token.to_string() }))
     }
 
-    fn download<D>(&self, path: &str, dst_path: &Path, progress: Option<D>) -> Result<PathBuf>
+    /// Downloads `path` into `dst_path`, resuming a download an earlier attempt left unfinished.
+    ///
+    /// Data is written to a partial file named after `path`, which is only moved to the name the
+    /// Depot gives the file once the download completes. A download first takes the partial file
+    /// over under a name of its own, so that concurrent downloads of the same file never write to
+    /// the same partial file, and hands it back if it fails. When a partial file was taken over,
+    /// only the remaining bytes are requested; a Depot which ignores the range is downloaded from
+    /// scratch.
+    fn download<D>(
+        &self,
+        path: &str,
+        target: Option<&PackageTarget>,
+        dst_path: &Path,
+        progress: Option<D>,
+    ) -> Result<PathBuf>
     where
         D: DisplayProgress + Sized,
     {
-        let mut res = try!(self.0.get(path).send());
-        debug!("Response: {:?}", res);
+        try!(fs::create_dir_all(&dst_path));
+        let part_file_name = partial_file_name(path, target);
+        let part_file_path = dst_path.join(&part_file_name);
+        let own_file_path = dst_path.join(format!(
+            "{}.{}-{}",
+            part_file_name,
+            process::current_pid(),
+            DOWNLOADS.fetch_add(1, Ordering::Relaxed)
+        ));
+        let offset = claim_partial_file(&part_file_path, &own_file_path);
+        let result = self.download_to(path, target, dst_path, &own_file_path, offset, progress);
+        if result.is_err() && own_file_path.is_file() {
+            if let Err(e) = fs::rename(&own_file_path, &part_file_path) {
+                debug!("Unable to keep partial file {}, {}", own_file_path.display(), e);
+                let _ = fs::remove_file(&own_file_path);
+            }
+        }
+        result
+    }
 
-        if res.status != hyper::status::StatusCode::Ok {
-            return Err(err_from_response(res));
+    fn download_to<D>(
+        &self,
+        path: &str,
+        target: Option<&PackageTarget>,
+        dst_path: &Path,
+        part_file_path: &Path,
+        offset: u64,
+        progress: Option<D>,
+    ) -> Result<PathBuf>
+    where
+        D: DisplayProgress + Sized,
+    {
+        let mut rb = self.0.get_with_custom_url(path, |url| with_target(url, target));
+        if offset > 0 {
+            debug!("Resuming download of {} at byte {}", path, offset);
+            rb = rb.header(Range::Bytes(vec![ByteRangeSpec::AllFrom(offset)]));
         }
-        try!(fs::create_dir_all(&dst_path));
+        let mut res = try!(rb.send());
+        debug!("Response: {:?}", res);
+
+        let action = partial_file_action(res.status, res.headers.get::<ContentRange>(), offset);
+        let mut f = match action {
+            Some(PartialFile::Append) => {
+                try!(OpenOptions::new().append(true).open(&part_file_path))
+            }
+            Some(PartialFile::Truncate) => try!(File::create(&part_file_path)),
+            Some(PartialFile::Discard) => {
+                // Whatever we have is not a prefix of the file the Depot serves, so throw it away
+                // and let the next attempt start over.
+                try!(fs::remove_file(&part_file_path));
+                return Err(err_from_response(res));
+            }
+            None => return Err(err_from_response(res)),
+        };
 
         let file_name = match res.headers.get::<XFileName>() {
             Some(filename) => format!("{}", filename),
             None => return Err(Error::NoXFilename),
         };
-        let tmp_file_path = dst_path.join(format!(
-            "{}.tmp-{}",
-            file_name,
-            thread_rng().gen_ascii_chars().take(8).collect::<String>()
-        ));
         let dst_file_path = dst_path.join(file_name);
-        debug!("Writing to {}", &tmp_file_path.display());
-        let mut f = try!(File::create(&tmp_file_path));
+        debug!("Writing to {}", &part_file_path.display());
         match progress {
             Some(mut progress) => {
                 let size: u64 = res.headers.get::<hyper::header::ContentLength>().map_or(
@@ -676,14 +787,85 @@ impl Client {
         };
         debug!(
             "Moving {} to {}",
-            &tmp_file_path.display(),
+            &part_file_path.display(),
             &dst_file_path.display()
         );
-        try!(fs::rename(&tmp_file_path, &dst_file_path));
+        try!(fs::rename(&part_file_path, &dst_file_path));
         Ok(dst_file_path)
     }
 }
 
+/// What a download does with its partial file once the Depot has answered
+#[derive(Debug, PartialEq)]
+enum PartialFile {
+    /// The response continues the partial file
+    Append,
+    /// The response holds the whole file, so the partial file starts over
+    Truncate,
+    /// The partial file is of no use, and the download fails
+    Discard,
+}
+
+/// Returns what to do with a partial file holding `offset` bytes given the status and
+/// `Content-Range` of the Depot's response, or `None` if the download failed.
+///
+/// Data is only appended to a partial content response whose `Content-Range` starts right
+/// after the bytes we have, since a Depot which ignores the range answers with the whole file.
+fn partial_file_action(
+    status: StatusCode,
+    range: Option<&ContentRange>,
+    offset: u64,
+) -> Option<PartialFile> {
+    match status {
+        StatusCode::Ok => Some(PartialFile::Truncate),
+        StatusCode::PartialContent if resumes_at(range, offset) => Some(PartialFile::Append),
+        StatusCode::PartialContent if offset > 0 => Some(PartialFile::Discard),
+        StatusCode::RangeNotSatisfiable if offset > 0 => Some(PartialFile::Discard),
+        _ => None,
+    }
+}
+
+/// Returns true if the `Content-Range` of a partial content response continues a download at
+/// `offset`.
+fn resumes_at(range: Option<&ContentRange>, offset: u64) -> bool {
+    if offset == 0 {
+        return false;
+    }
+    match range {
+        Some(&ContentRange(ContentRangeSpec::Bytes { range: Some((first, _)), .. })) => {
+            first == offset
+        }
+        _ => false,
+    }
+}
+
+/// Moves the partial file at `part_file_path`, if there is one, to `own_file_path` and returns
+/// how many bytes it holds. The move is atomic, so a partial file is only ever taken over by one
+/// download.
+fn claim_partial_file(part_file_path: &Path, own_file_path: &Path) -> u64 {
+    match fs::rename(part_file_path, own_file_path) {
+        Ok(()) => fs::metadata(own_file_path).map(|m| m.len()).unwrap_or(0),
+        Err(_) => 0,
+    }
+}
+
+/// Returns the name of the file a download of `path` is written to until it completes.
+fn partial_file_name(path: &str, target: Option<&PackageTarget>) -> String {
+    let name = path.trim_matches('/').replace('/', "-");
+    match target {
+        Some(target) => format!("{}-{}.part", name, target),
+        None => format!("{}.part", name),
+    }
+}
+
+/// Asks the Depot for the artifact built for `target`, rather than the one matching our
+/// `User-Agent`.
+fn with_target(url: &mut Url, target: Option<&PackageTarget>) {
+    if let Some(target) = target {
+        url.query_pairs_mut().append_pair("target", &target.to_string());
+    }
+}
+
 fn err_from_response(mut response: hyper::client::Response) -> Error {
     let mut buff = String::new();
     match response.read_to_string(&mut buff) {
@@ -777,9 +959,91 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use serde_json;
+    use tempdir::TempDir;
     use super::*;
 
+    #[test]
+    fn partial_file_name_is_stable_for_a_path() {
+        assert_eq!(
+            partial_file_name("pkgs/core/redis/3.2.4/20170514150022/download", None),
+            "pkgs-core-redis-3.2.4-20170514150022-download.part"
+        );
+        assert_eq!(
+            partial_file_name("/origins/core/keys/20160810182414", None),
+            "origins-core-keys-20160810182414.part"
+        );
+    }
+
+    #[test]
+    fn partial_file_is_appended_to_only_when_the_range_matches() {
+        let range = |first, last| {
+            ContentRange(ContentRangeSpec::Bytes {
+                range: Some((first, last)),
+                instance_length: Some(last + 1),
+            })
+        };
+        assert_eq!(
+            partial_file_action(StatusCode::PartialContent, Some(&range(512, 1023)), 512),
+            Some(PartialFile::Append)
+        );
+        assert_eq!(
+            partial_file_action(StatusCode::PartialContent, Some(&range(0, 1023)), 512),
+            Some(PartialFile::Discard)
+        );
+        assert_eq!(
+            partial_file_action(StatusCode::PartialContent, None, 512),
+            Some(PartialFile::Discard)
+        );
+        assert_eq!(
+            partial_file_action(StatusCode::RangeNotSatisfiable, None, 512),
+            Some(PartialFile::Discard)
+        );
+        assert_eq!(partial_file_action(StatusCode::PartialContent, None, 0), None);
+        assert_eq!(partial_file_action(StatusCode::NotFound, None, 512), None);
+    }
+
+    #[test]
+    fn partial_file_starts_over_when_the_range_is_ignored() {
+        assert_eq!(
+            partial_file_action(StatusCode::Ok, None, 512),
+            Some(PartialFile::Truncate)
+        );
+        assert_eq!(
+            partial_file_action(StatusCode::Ok, None, 0),
+            Some(PartialFile::Truncate)
+        );
+    }
+
+    #[test]
+    fn partial_file_is_claimed_by_one_download() {
+        let dir = TempDir::new("depot-client").unwrap();
+        let part = dir.path().join("pkgs-core-redis-download.part");
+        let first = dir.path().join("pkgs-core-redis-download.part.1-0");
+        let second = dir.path().join("pkgs-core-redis-download.part.2-0");
+        File::create(&part).unwrap().write_all(b"partial").unwrap();
+
+        assert_eq!(claim_partial_file(&part, &first), 7);
+        assert_eq!(claim_partial_file(&part, &second), 0);
+        assert!(!part.exists());
+        assert!(first.is_file());
+        assert!(!second.exists());
+    }
+
+    #[test]
+    fn partial_file_name_includes_target() {
+        let target = PackageTarget::from_str("x86_64-windows").unwrap();
+        assert_eq!(
+            partial_file_name(
+                "pkgs/core/redis/3.2.4/20170514150022/download",
+                Some(&target),
+            ),
+            "pkgs-core-redis-3.2.4-20170514150022-download-x86_64-windows.part"
+        );
+    }
+
     #[test]
     fn json_round_trip_u64_fields() {
         let pre = OriginSecretKey {
@@ -23,6 +23,7 @@ use hab_core::package::PackageTarget;
 use hab_net::config::{GitHubCfg, GitHubOAuth, RouterAddr, RouterCfg};
 
 use error::Error;
+use storage::StorageBackend;
 
 #[derive(Clone, Debug, Deserialize)]
 #[serde(default)]
@@ -43,6 +44,10 @@ pub struct Config {
     pub log_dir: String,
     /// A list of package platform and architecture combinations which can be uploaded and hosted
     pub targets: Vec<PackageTarget>,
+    /// Configuration for the package archive store
+    pub storage: StorageCfg,
+    /// Configuration for mirroring an upstream Depot
+    pub upstream: UpstreamCfg,
 }
 
 impl ConfigFile for Config {
@@ -64,6 +69,8 @@ impl Default for Config {
                 PackageTarget::new(Platform::Linux, Architecture::X86_64),
                 PackageTarget::new(Platform::Windows, Architecture::X86_64),
             ],
+            storage: StorageCfg::default(),
+            upstream: UpstreamCfg::default(),
         }
     }
 }
@@ -115,6 +122,92 @@ impl ToSocketAddrs for HttpCfg {
     }
 }
 
+////////////////////////////////////////////////////////////////////////
+// Storage Configuration
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct StorageCfg {
+    pub backend: StorageBackend,
+
+    // These are for S3 storage
+    pub key: Option<String>,
+    pub secret: Option<String>,
+    pub endpoint: Option<String>,
+    pub bucket: Option<String>,
+    pub region: String,
+    // How many bytes of archives fetched from S3 to keep in the local cache
+    pub cache_size: u64,
+}
+
+impl Default for StorageCfg {
+    fn default() -> Self {
+        StorageCfg {
+            backend: StorageBackend::Local,
+
+            key: None,
+            secret: None,
+            endpoint: None,
+            bucket: None,
+            region: String::from("us-east-1"),
+            cache_size: 10 * 1024 * 1024 * 1024,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////
+// Upstream Configuration
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct UpstreamCfg {
+    /// URL of an upstream Depot. When set, packages and origin keys this Depot doesn't have are
+    /// fetched from the upstream Depot, stored and served as if they had been uploaded here.
+    pub url: Option<String>,
+    /// Origin channels whose packages are mirrored from the upstream Depot on a schedule
+    pub mirror: Vec<MirrorCfg>,
+    /// Seconds to wait between mirroring runs
+    pub sync_interval_sec: u64,
+    /// Whether origins which only exist upstream are created here, without an owner, so their
+    /// packages can be mirrored. Packages of other origins are only mirrored once the origin has
+    /// been created here.
+    pub create_origins: bool,
+}
+
+impl UpstreamCfg {
+    /// Returns the origins which are mirrored from the upstream Depot
+    pub fn mirrored_origins(&self) -> Vec<String> {
+        let mut origins: Vec<String> = self.mirror.iter().map(|m| m.origin.clone()).collect();
+        origins.sort();
+        origins.dedup();
+        origins
+    }
+}
+
+impl Default for UpstreamCfg {
+    fn default() -> Self {
+        UpstreamCfg {
+            url: None,
+            mirror: vec![],
+            sync_interval_sec: 3600,
+            create_origins: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct MirrorCfg {
+    pub origin: String,
+    #[serde(default = "MirrorCfg::default_channel")]
+    pub channel: String,
+}
+
+impl MirrorCfg {
+    fn default_channel() -> String {
+        String::from("stable")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,6 +242,26 @@ mod tests {
         url = "https://api.github.com"
         client_id = "0c2f738a7d0bd300de10"
         client_secret = "438223113eeb6e7edf2d2f91a232b72de72b9bdf"
+
+        [storage]
+        backend = "s3"
+        key = "THIS_IS_THE_KEY"
+        secret = "THIS_IS_THE_SECRET"
+        bucket = "bukkit"
+        endpoint = "http://127.0.0.1:9000"
+        cache_size = 1073741824
+
+        [upstream]
+        url = "https://willem.habitat.sh/v1/depot"
+        sync_interval_sec = 600
+        create_origins = true
+
+        [[upstream.mirror]]
+        origin = "core"
+
+        [[upstream.mirror]]
+        origin = "acme"
+        channel = "unstable"
         "#;
 
         let config = Config::from_raw(&content).unwrap();
@@ -171,6 +284,30 @@ mod tests {
         assert_eq!(config.targets[0].architecture, Architecture::X86_64);
         assert_eq!(config.targets[1].platform, Platform::Windows);
         assert_eq!(config.targets[1].architecture, Architecture::X86_64);
+        assert_eq!(config.storage.backend, StorageBackend::S3);
+        assert_eq!(config.storage.key, Some("THIS_IS_THE_KEY".to_string()));
+        assert_eq!(
+            config.storage.secret,
+            Some("THIS_IS_THE_SECRET".to_string())
+        );
+        assert_eq!(config.storage.bucket, Some("bukkit".to_string()));
+        assert_eq!(
+            config.storage.endpoint,
+            Some("http://127.0.0.1:9000".to_string())
+        );
+        assert_eq!(config.storage.region, "us-east-1");
+        assert_eq!(config.storage.cache_size, 1073741824);
+        assert_eq!(
+            config.upstream.url,
+            Some("https://willem.habitat.sh/v1/depot".to_string())
+        );
+        assert_eq!(config.upstream.sync_interval_sec, 600);
+        assert_eq!(config.upstream.create_origins, true);
+        assert_eq!(config.upstream.mirror.len(), 2);
+        assert_eq!(config.upstream.mirror[0].origin, "core");
+        assert_eq!(config.upstream.mirror[0].channel, "stable");
+        assert_eq!(config.upstream.mirror[1].origin, "acme");
+        assert_eq!(config.upstream.mirror[1].channel, "unstable");
     }
 
     #[test]
@@ -182,5 +319,10 @@ mod tests {
 
         let config = Config::from_raw(&content).unwrap();
         assert_eq!(config.http.port, 9000);
+        assert_eq!(config.storage.backend, StorageBackend::Local);
+        assert_eq!(config.storage.cache_size, 10 * 1024 * 1024 * 1024);
+        assert_eq!(config.upstream.url, None);
+        assert!(config.upstream.mirror.is_empty());
+        assert_eq!(config.upstream.create_origins, false);
     }
 }
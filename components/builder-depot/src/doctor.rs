@@ -24,7 +24,7 @@ use time;
 use walkdir::WalkDir;
 
 use super::DepotUtil;
-use error::Result;
+use error::{Error, Result};
 
 #[derive(Debug)]
 /// A struct containing the details of a repair run by `Doctor`.
@@ -102,8 +102,8 @@ impl Default for ReportBuilder {
 
 #[derive(Debug)]
 pub enum OperationType {
-    /// Record of an archive being re-inserted into the datastore. Contains the filepath to the
-    /// final location of the archive.
+    /// Record of an archive being re-inserted into the datastore and the package store. Contains
+    /// the identifier of the archive's package.
     ArchiveInsert(String),
     /// Record of cleaning up after the doctor has run. Contains the filepath of the trash which
     /// was cleaned.
@@ -124,6 +124,7 @@ pub enum Reason {
     IO(io::Error),
     FileExists,
     NotEmpty,
+    Store(Error),
 }
 
 #[derive(Debug)]
@@ -196,28 +197,20 @@ impl<'a> Doctor<'a> {
                         Ok(package) => {
                             let mut conn = Broker::connect().unwrap();
                             conn.route::<originsrv::OriginPackageCreate, originsrv::OriginPackage>(&package)?;
-                            let path = self.depot.archive_path(&ident, &try!(archive.target()));
-                            if let Some(e) = fs::create_dir_all(path.parent().unwrap()).err() {
+                            let target = try!(archive.target());
+                            if let Some(e) = self.depot
+                                .store_archive(&ident, &target, entry.path())
+                                .err()
+                            {
                                 self.report.failure(
                                     OperationType::ArchiveInsert(
                                         entry.path().to_string_lossy().to_string(),
                                     ),
-                                    Reason::IO(e),
+                                    Reason::Store(e),
                                 );
                                 break;
                             }
-                            if let Some(e) = fs::rename(entry.path(), &path).err() {
-                                self.report.failure(
-                                    OperationType::ArchiveInsert(
-                                        entry.path().to_string_lossy().to_string(),
-                                    ),
-                                    Reason::IO(e),
-                                );
-                                break;
-                            }
-                            self.report.success(OperationType::ArchiveInsert(
-                                path.to_string_lossy().to_string(),
-                            ));
+                            self.report.success(OperationType::ArchiveInsert(ident.to_string()));
                         }
                         Err(e) => {
                             // We should be moving this back to the garbage directory and recording
@@ -260,7 +253,7 @@ impl<'a> Doctor<'a> {
 
 /// Runs the repair tool on the given Depot and returns a Report containing the results. A repair
 /// tool analyzes all packages found within the Depot's metadata store and re-inserts them into
-/// the file system and re-builds all indices.
+/// the Depot's package store and re-builds all indices.
 ///
 /// Any files found within the metastore which are not valid or readable archives are moved into a
 /// garbage directory for the user to examine.
@@ -18,29 +18,42 @@ use std::io;
 use std::fmt;
 use std::result;
 
+use aws_sdk_rust::aws::errors::s3::S3Error;
+use depot_client;
 use hab_core;
 use hab_core::package::{self, Identifiable};
 use hab_net;
 use hyper;
 use protocol::net::NetError;
+use url;
 
 #[derive(Debug)]
 pub enum Error {
     BadPort(String),
+    CaughtPanic(String, String),
     ChannelAlreadyExists(String),
     ChannelDoesNotExist(String),
+    DepotClient(depot_client::Error),
     HabitatCore(hab_core::Error),
     HabitatNet(hab_net::Error),
     HTTP(hyper::status::StatusCode),
     InvalidPackageIdent(String),
+    InvalidStorageConfig(String),
+    InvalidUrl,
     IO(io::Error),
     MessageTypeNotFound,
     NoXFilename,
     NoFilePart,
     NulError(ffi::NulError),
     PackageIsAlreadyInChannel(String, String),
+    PackageStore(String, S3Error),
     ProtocolNetError(NetError),
+    RemoteChecksumMismatch(String, String, String),
+    RemoteOriginKeyNotFound(String),
+    RemoteOriginKeyNotTrusted(String),
+    RemoteOriginNotCreated(String),
     RemotePackageNotFound(package::PackageIdent),
+    RemoteTargetMismatch(String, String),
     WriteSyncFailed,
 }
 
@@ -50,8 +63,12 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
             Error::BadPort(ref e) => format!("{} is an invalid port. Valid range 1-65535.", e),
+            Error::CaughtPanic(ref msg, ref source) => {
+                format!("Caught a panic: {}. {}", msg, source)
+            }
             Error::ChannelAlreadyExists(ref e) => format!("{} already exists.", e),
             Error::ChannelDoesNotExist(ref e) => format!("{} does not exist.", e),
+            Error::DepotClient(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HabitatNet(ref e) => format!("{}", e),
             Error::HTTP(ref e) => format!("{}", e),
@@ -62,6 +79,8 @@ impl fmt::Display for Error {
                     e
                 )
             }
+            Error::InvalidStorageConfig(ref e) => format!("Invalid storage configuration: {}", e),
+            Error::InvalidUrl => format!("Bad URL!"),
             Error::IO(ref e) => format!("{}", e),
             Error::MessageTypeNotFound => format!("Unable to find message for given type"),
             Error::NoXFilename => {
@@ -77,7 +96,34 @@ impl fmt::Display for Error {
             Error::PackageIsAlreadyInChannel(ref p, ref c) => {
                 format!("{} is already in the {} channel.", p, c)
             }
+            Error::PackageStore(ref op, ref e) => {
+                format!("Unable to {} in the package store, {}", op, e)
+            }
             Error::ProtocolNetError(ref e) => format!("{}", e),
+            Error::RemoteChecksumMismatch(ref pkg, ref expected, ref actual) => {
+                format!(
+                    "Upstream served {} with checksum {}, but listed its checksum as {}",
+                    pkg,
+                    actual,
+                    expected
+                )
+            }
+            Error::RemoteOriginKeyNotFound(ref origin) => {
+                format!("Cannot find a public origin key for {} in any sources", origin)
+            }
+            Error::RemoteOriginKeyNotTrusted(ref key) => {
+                format!(
+                    "Not importing the {} public origin key from upstream, only keys of origins \
+                     configured for mirroring are trusted",
+                    key
+                )
+            }
+            Error::RemoteOriginNotCreated(ref origin) => {
+                format!(
+                    "Origin {} only exists upstream and creating origins from upstream is disabled",
+                    origin
+                )
+            }
             Error::RemotePackageNotFound(ref pkg) => {
                 if pkg.fully_qualified() {
                     format!("Cannot find package in any sources: {}", pkg)
@@ -85,6 +131,9 @@ impl fmt::Display for Error {
                     format!("Cannot find a release of package in any sources: {}", pkg)
                 }
             }
+            Error::RemoteTargetMismatch(ref pkg, ref target) => {
+                format!("Upstream served {} for a target other than {}", pkg, target)
+            }
             Error::WriteSyncFailed => {
                 format!("Could not write to destination; perhaps the disk is full?")
             }
@@ -97,21 +146,31 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::BadPort(_) => "Received an invalid port or a number outside of the valid range.",
+            Error::CaughtPanic(_, _) => "Caught a panic",
             Error::ChannelAlreadyExists(_) => "Channel already exists.",
             Error::ChannelDoesNotExist(_) => "Channel does not exist.",
+            Error::DepotClient(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
             Error::HabitatNet(ref err) => err.description(),
             Error::HTTP(_) => "Received an HTTP error",
             Error::InvalidPackageIdent(_) => {
                 "Package identifiers must be in origin/name format (example: acme/redis)"
             }
+            Error::InvalidStorageConfig(_) => "Invalid storage configuration",
+            Error::InvalidUrl => "Bad Url!",
             Error::IO(ref err) => err.description(),
             Error::NulError(_) => {
                 "An attempt was made to build a CString with a null byte inside it"
             }
             Error::PackageIsAlreadyInChannel(_, _) => "Package is already in channel",
+            Error::PackageStore(_, _) => "Unable to access the package store",
             Error::ProtocolNetError(ref err) => err.description(),
+            Error::RemoteChecksumMismatch(_, _, _) => "Upstream served a corrupt package",
+            Error::RemoteOriginKeyNotFound(_) => "Cannot find a public origin key in any sources",
+            Error::RemoteOriginKeyNotTrusted(_) => "Upstream origin key is not trusted",
+            Error::RemoteOriginNotCreated(_) => "Origin only exists upstream",
             Error::RemotePackageNotFound(_) => "Cannot find a package in any sources",
+            Error::RemoteTargetMismatch(_, _) => "Upstream served a package for the wrong target",
             Error::NoXFilename => "Invalid download from a Depot - missing X-Filename header",
             Error::NoFilePart => {
                 "An invalid path was passed - we needed a filename, and this path does not have one"
@@ -124,6 +183,12 @@ impl error::Error for Error {
     }
 }
 
+impl From<depot_client::Error> for Error {
+    fn from(err: depot_client::Error) -> Error {
+        Error::DepotClient(err)
+    }
+}
+
 impl From<hab_core::Error> for Error {
     fn from(err: hab_core::Error) -> Error {
         Error::HabitatCore(err)
@@ -153,3 +218,9 @@ impl From<NetError> for Error {
         Error::ProtocolNetError(err)
     }
 }
+
+impl From<url::ParseError> for Error {
+    fn from(_err: url::ParseError) -> Self {
+        Error::InvalidUrl
+    }
+}
@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+extern crate aws_sdk_rust;
 extern crate habitat_builder_protocol as protocol;
 #[macro_use]
 extern crate habitat_core as hab_core;
+extern crate habitat_depot_client as depot_client;
 extern crate habitat_net as hab_net;
 extern crate builder_core as bld_core;
 extern crate bodyparser;
@@ -37,6 +39,8 @@ extern crate serde;
 extern crate serde_derive;
 extern crate serde_json;
 extern crate tempfile;
+#[cfg(test)]
+extern crate tempdir;
 extern crate time;
 extern crate toml;
 extern crate unicase;
@@ -50,26 +54,47 @@ pub mod config;
 pub mod error;
 pub mod doctor;
 pub mod server;
+pub mod storage;
+pub mod upstream;
 
 pub use self::config::Config;
 pub use self::error::{Error, Result};
 
-use std::fs;
+pub const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
+
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-use crypto::sha2::Sha256;
-use crypto::digest::Digest;
-use hab_core::package::{Identifiable, PackageArchive, PackageTarget};
+use hab_core::package::{Identifiable, PackageArchive, PackageIdent, PackageTarget};
 use hab_net::server::NetIdent;
 use iron::typemap;
 
+use storage::PackageStore;
+use upstream::Upstream;
+
 pub struct DepotUtil {
     pub config: Config,
+    store: Box<PackageStore + 'static>,
+    upstream: Option<Arc<Upstream>>,
 }
 
 impl DepotUtil {
-    pub fn new(config: Config) -> DepotUtil {
-        DepotUtil { config: config }
+    pub fn new(config: Config) -> Result<DepotUtil> {
+        let store = try!(storage::from_config(&config));
+        let upstream = match config.upstream.url {
+            Some(ref url) => Some(Arc::new(try!(Upstream::new(
+                url,
+                Path::new(&config.path),
+                config.upstream.create_origins,
+                config.upstream.mirrored_origins(),
+            )))),
+            None => None,
+        };
+        Ok(DepotUtil {
+            config: config,
+            store: store,
+            upstream: upstream,
+        })
     }
 
     // Return a PackageArchive representing the given package. None is returned if the Depot
@@ -79,48 +104,47 @@ impl DepotUtil {
         ident: &T,
         target: &PackageTarget,
     ) -> Option<PackageArchive> {
-        let file = self.archive_path(ident, target);
-        match fs::metadata(&file) {
-            Ok(_) => Some(PackageArchive::new(file)),
-            Err(_) => None,
+        match self.store.fetch(&Self::package_ident(ident), target) {
+            Ok(path) => path.map(PackageArchive::new),
+            Err(e) => {
+                error!("Unable to fetch archive for {}, err={:?}", ident, e);
+                None
+            }
         }
     }
 
-    // Return a formatted string representing the filename of an archive for the given package
-    // identifier pieces.
-    fn archive_path<T: Identifiable>(&self, ident: &T, target: &PackageTarget) -> PathBuf {
-        let mut digest = Sha256::new();
-        let mut output = [0; 64];
-        digest.input_str(&ident.to_string());
-        digest.result(&mut output);
-        self.packages_path()
-            .join(format!("{:x}", output[0]))
-            .join(format!("{:x}", output[1]))
-            .join(format!(
-                "{}-{}-{}-{}-{}-{}.hart",
-                ident.origin(),
-                ident.name(),
-                ident.version().unwrap(),
-                ident.release().unwrap(),
-                target.architecture,
-                target.platform
-            ))
+    // Return true if the Depot has an archive for the given package.
+    fn has_archive<T: Identifiable>(&self, ident: &T, target: &PackageTarget) -> Result<bool> {
+        self.store.exists(&Self::package_ident(ident), target)
     }
 
-    // Return a formatted string representing the folder location for an archive.
-    fn archive_parent<T: Identifiable>(&self, ident: &T) -> PathBuf {
-        let mut digest = Sha256::new();
-        let mut output = [0; 64];
-        digest.input_str(&ident.to_string());
-        digest.result(&mut output);
-        self.packages_path().join(format!("{:x}", output[0])).join(
-            format!("{:x}", output[1]),
-        )
+    // Move the archive at the given path into storage as the archive for the given package.
+    fn store_archive<T: Identifiable>(
+        &self,
+        ident: &T,
+        target: &PackageTarget,
+        file_path: &Path,
+    ) -> Result<()> {
+        self.store.put(&Self::package_ident(ident), target, file_path)
+    }
+
+    // Remove the archive for the given package from storage.
+    fn delete_archive<T: Identifiable>(&self, ident: &T, target: &PackageTarget) -> Result<()> {
+        self.store.delete(&Self::package_ident(ident), target)
+    }
+
+    fn package_ident<T: Identifiable>(ident: &T) -> PackageIdent {
+        PackageIdent::new(ident.origin(), ident.name(), ident.version(), ident.release())
     }
 
     fn packages_path(&self) -> PathBuf {
         Path::new(&self.config.path).join("pkgs")
     }
+
+    // Return the folder location where uploads are written to before being moved into storage.
+    fn uploads_path(&self) -> PathBuf {
+        Path::new(&self.config.path).join("uploads")
+    }
 }
 
 impl typemap::Key for DepotUtil {
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std::any::TypeId;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fs::{self, File};
 use std::path::PathBuf;
 use std::io::{Read, Write, BufWriter};
@@ -58,12 +58,14 @@ use urlencoded::UrlEncodedQuery;
 use super::DepotUtil;
 use config::Config;
 use error::{Error, Result};
+use upstream::{self, Route};
 
 define_event_log!();
 
 #[derive(Default)]
 pub struct TestableBroker {
     message_map: HashMap<TypeId, Vec<u8>>,
+    queued_map: HashMap<TypeId, VecDeque<RouteResult<Vec<u8>>>>,
     error_map: HashMap<TypeId, NetError>,
     cached_messages: HashMap<TypeId, Vec<u8>>,
 }
@@ -78,6 +80,16 @@ impl TestableBroker {
         self.error_map.insert(TypeId::of::<M>(), error);
     }
 
+    /// Queues a response to a message, which answers it once before any response set up with
+    /// `setup` or `setup_error`.
+    pub fn queue<M: Routable, R: protobuf::MessageStatic>(&mut self, response: RouteResult<R>) {
+        let response = response.map(|r| r.write_to_bytes().unwrap());
+        self.queued_map
+            .entry(TypeId::of::<M>())
+            .or_insert(VecDeque::new())
+            .push_back(response);
+    }
+
     pub fn routed_messages(&self) -> RoutedMessages {
         RoutedMessages(self.cached_messages.clone())
     }
@@ -86,6 +98,9 @@ impl TestableBroker {
         let bytes = msg.write_to_bytes().unwrap();
         self.cached_messages.insert(TypeId::of::<M>(), bytes);
         let msg_type = &TypeId::of::<M>();
+        if let Some(response) = self.queued_map.get_mut(msg_type).and_then(|q| q.pop_front()) {
+            return response.map(|message| parse_from_bytes::<R>(&message).unwrap());
+        }
         match self.message_map.get(msg_type) {
             Some(message) => Ok(parse_from_bytes::<R>(message).unwrap()),
             None => {
@@ -147,6 +162,18 @@ fn route_message<M: Routable, R: protobuf::MessageStatic>(
     Broker::connect().unwrap().route::<M, R>(msg)
 }
 
+impl<'a, 'b> Route for Request<'a, 'b> {
+    fn route<M: Routable, R: protobuf::MessageStatic>(&mut self, msg: &M) -> RouteResult<R> {
+        route_message::<M, R>(self, msg)
+    }
+}
+
+impl Route for TestableBroker {
+    fn route<M: Routable, R: protobuf::MessageStatic>(&mut self, msg: &M) -> RouteResult<R> {
+        TestableBroker::route::<M, R>(self, msg)
+    }
+}
+
 fn package_results_json<T: Serialize>(
     packages: &Vec<T>,
     count: isize,
@@ -677,21 +704,21 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
         }
     }
 
-    // Find the path to folder where archive should be created, and
+    // Find the path to folder where the upload should be written, and
     // create the folder if necessary
-    let parent_path = depot.archive_parent(&ident);
+    let uploads_path = depot.uploads_path();
 
-    match fs::create_dir_all(parent_path.clone()) {
+    match fs::create_dir_all(uploads_path.clone()) {
         Ok(_) => {}
         Err(e) => {
-            error!("Unable to create archive directory, err={:?}", e);
+            error!("Unable to create uploads directory, err={:?}", e);
             return Ok(Response::with(status::InternalServerError));
         }
     };
 
-    // Create a temp file at the archive location
+    // Create a temp file at the upload location
     let temp_name = format!("{}.tmp", Uuid::new_v4());
-    let temp_path = parent_path.join(temp_name);
+    let temp_path = uploads_path.join(temp_name);
 
     let mut archive = try!(write_archive(&temp_path, &mut req.body));
     debug!("Package Archive: {:#?}", archive);
@@ -714,14 +741,20 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
 
     let mut ident_req = OriginPackageGet::new();
     ident_req.set_ident(ident.clone());
+    ident_req.set_target(target_from_artifact.to_string());
 
     match route_message::<OriginPackageGet, OriginPackage>(req, &ident_req) {
         Ok(_) => return Ok(Response::with((status::Conflict))),
         Err(err) => {
             match err.get_code() {
                 ErrCode::ENTITY_NOT_FOUND => {
-                    if depot.archive(&ident, &target_from_artifact).is_some() {
-                        return Ok(Response::with((status::Conflict)));
+                    match depot.has_archive(&ident, &target_from_artifact) {
+                        Ok(true) => return Ok(Response::with((status::Conflict))),
+                        Ok(false) => (),
+                        Err(e) => {
+                            error!("Unable to check for an existing archive, err={:?}", e);
+                            return Ok(Response::with(status::InternalServerError));
+                        }
                     }
                 }
                 _ => {
@@ -779,30 +812,28 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
         }
     }
 
-    let filename = depot.archive_path(&ident, &target_from_artifact);
+    let mut package = match OriginPackageCreate::from_archive(&mut archive) {
+        Ok(package) => package,
+        Err(e) => {
+            info!("Error building package from archive: {:#?}", e);
+            return Ok(Response::with(status::UnprocessableEntity));
+        }
+    };
 
-    match fs::rename(&temp_path, &filename) {
+    match depot.store_archive(&ident, &target_from_artifact, &temp_path) {
         Ok(_) => {}
         Err(e) => {
             error!(
-                "Unable to rename temp archive {:?} to {:?}, err={:?}",
+                "Unable to store temp archive {:?} for {}, err={:?}",
                 temp_path,
-                filename,
+                ident,
                 e
             );
             return Ok(Response::with(status::InternalServerError));
         }
     }
 
-    info!("File added to Depot at {}", filename.to_string_lossy());
-    let mut archive = PackageArchive::new(filename);
-    let mut package = match OriginPackageCreate::from_archive(&mut archive) {
-        Ok(package) => package,
-        Err(e) => {
-            info!("Error building package from archive: {:#?}", e);
-            return Ok(Response::with(status::UnprocessableEntity));
-        }
-    };
+    info!("File added to Depot for {}", ident);
     if ident.satisfies(package.get_ident()) {
         package.set_owner_id(session.get_id());
 
@@ -966,7 +997,7 @@ fn get_schedule(req: &mut Request) -> IronResult<Response> {
 }
 
 // This function should not require authentication (session/auth token)
-fn download_origin_key(req: &mut Request) -> IronResult<Response> {
+fn download_local_origin_key(req: &mut Request) -> IronResult<Response> {
     let params = req.extensions.get::<Router>().unwrap();
     let mut conn = Broker::connect().unwrap();
     let mut request = OriginPublicKeyGet::new();
@@ -999,7 +1030,7 @@ fn download_origin_key(req: &mut Request) -> IronResult<Response> {
 }
 
 // This function should not require authentication (session/auth token)
-fn download_latest_origin_key(req: &mut Request) -> IronResult<Response> {
+fn download_local_latest_origin_key(req: &mut Request) -> IronResult<Response> {
     let params = req.extensions.get::<Router>().unwrap();
     let mut conn = Broker::connect().unwrap();
     let mut request = OriginPublicKeyLatestGet::new();
@@ -1027,7 +1058,7 @@ fn download_latest_origin_key(req: &mut Request) -> IronResult<Response> {
     Ok(response)
 }
 
-fn download_package(req: &mut Request) -> IronResult<Response> {
+fn download_local_package(req: &mut Request) -> IronResult<Response> {
     let lock = req.get::<persistent::State<DepotUtil>>().expect(
         "depot not found",
     );
@@ -1037,18 +1068,19 @@ fn download_package(req: &mut Request) -> IronResult<Response> {
         let params = req.extensions.get::<Router>().unwrap();
         ident_req.set_ident(ident_from_params(params));
     };
-    let agent_target = target_from_headers(&req.headers.get::<UserAgent>().unwrap()).unwrap();
-    if !depot.config.targets.contains(&agent_target) {
-        error!(
-            "Unsupported client platform ({}) for this depot.",
-            agent_target
-        );
+    let target = match target_from_request(req) {
+        Ok(target) => target,
+        Err(response) => return Ok(response),
+    };
+    if !depot.config.targets.contains(&target) {
+        error!("Unsupported client platform ({}) for this depot.", target);
         return Ok(Response::with(status::NotImplemented));
     }
+    ident_req.set_target(target.to_string());
 
     match route_message::<OriginPackageGet, OriginPackage>(req, &ident_req) {
         Ok(package) => {
-            if let Some(archive) = depot.archive(package.get_ident(), &agent_target) {
+            if let Some(archive) = depot.archive(package.get_ident(), &target) {
                 match fs::metadata(&archive.path) {
                     Ok(_) => {
                         let mut response = Response::with((status::Ok, archive.path.clone()));
@@ -1476,7 +1508,7 @@ fn delete_channel(req: &mut Request) -> IronResult<Response> {
     }
 }
 
-fn show_package(req: &mut Request) -> IronResult<Response> {
+fn show_local_package(req: &mut Request) -> IronResult<Response> {
     let (mut ident, channel) = {
         let params = req.extensions.get::<Router>().unwrap();
 
@@ -1495,16 +1527,29 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
     };
 
     let qualified = ident.fully_qualified();
+    let explicit_target = match target_from_query(req) {
+        Ok(target) => target,
+        Err(response) => return Ok(response),
+    };
+    // A fully qualified release is shown whatever it was built for, unless a target was asked
+    // for. Otherwise the latest release is picked from those built for the requested target.
+    let target = match explicit_target {
+        Some(target) => Some(target),
+        None if qualified => None,
+        None => {
+            match target_from_headers(&req.headers.get::<UserAgent>().unwrap()) {
+                Ok(target) => Some(target),
+                Err(response) => return Ok(response),
+            }
+        }
+    };
 
     if let Some(channel) = channel {
         if !qualified {
-            let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap())
-                .unwrap()
-                .to_string();
             let mut request = OriginChannelPackageLatestGet::new();
             request.set_name(channel.clone());
             request.set_ident(ident);
-            request.set_target(target);
+            request.set_target(target.as_ref().unwrap().to_string());
             match route_message::<OriginChannelPackageLatestGet, OriginPackageIdent>(
                 req,
                 &request,
@@ -1525,6 +1570,9 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
         let mut request = OriginChannelPackageGet::new();
         request.set_name(channel);
         request.set_ident(ident);
+        if let Some(ref target) = target {
+            request.set_target(target.to_string());
+        }
         match route_message::<OriginChannelPackageGet, OriginPackage>(req, &request) {
             Ok(pkg) => render_package(&pkg, false),
             Err(err) => {
@@ -1539,12 +1587,9 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
         }
     } else {
         if !qualified {
-            let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap())
-                .unwrap()
-                .to_string();
             let mut request = OriginPackageLatestGet::new();
             request.set_ident(ident);
-            request.set_target(target);
+            request.set_target(target.as_ref().unwrap().to_string());
             match route_message::<OriginPackageLatestGet, OriginPackageIdent>(req, &request) {
                 Ok(id) => ident = id.into(),
                 Err(err) => {
@@ -1561,6 +1606,9 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
 
         let mut request = OriginPackageGet::new();
         request.set_ident(ident);
+        if let Some(ref target) = target {
+            request.set_target(target.to_string());
+        }
         match route_message::<OriginPackageGet, OriginPackage>(req, &request) {
             Ok(pkg) => {
                 // If the request was for a fully qualified ident, cache the response, otherwise do
@@ -1622,6 +1670,11 @@ fn search_packages(req: &mut Request) -> IronResult<Response> {
         request.set_distinct(true);
     }
 
+    // Only return packages distributed under the given license, e.g. blah?license=MIT
+    if let Some(license) = extract_query_value("license", req) {
+        request.set_license(license);
+    }
+
     match route_message::<OriginPackageSearchRequest, OriginPackageListResponse>(req, &request) {
         Ok(packages) => {
             debug!(
@@ -1725,8 +1778,15 @@ fn promote_package(req: &mut Request) -> IronResult<Response> {
                 return Ok(Response::with(status::Forbidden));
             }
 
+            let target = match target_from_query(req) {
+                Ok(target) => target,
+                Err(response) => return Ok(response),
+            };
             let mut request = OriginPackageGet::new();
             request.set_ident(ident.clone());
+            if let Some(target) = target {
+                request.set_target(target.to_string());
+            }
             match route_message::<OriginPackageGet, OriginPackage>(req, &request) {
                 Ok(package) => {
                     let mut promote = OriginPackagePromote::new();
@@ -1818,8 +1878,15 @@ fn demote_package(req: &mut Request) -> IronResult<Response> {
                 return Ok(Response::with(status::Forbidden));
             }
 
+            let target = match target_from_query(req) {
+                Ok(target) => target,
+                Err(response) => return Ok(response),
+            };
             let mut request = OriginPackageGet::new();
             request.set_ident(ident.clone());
+            if let Some(target) = target {
+                request.set_target(target.to_string());
+            }
             match route_message::<OriginPackageGet, OriginPackage>(req, &request) {
                 Ok(package) => {
                     let mut demote = OriginPackageDemote::new();
@@ -1857,6 +1924,254 @@ fn demote_package(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn yank_package(req: &mut Request) -> IronResult<Response> {
+    set_package_yanked(req, true)
+}
+
+fn unyank_package(req: &mut Request) -> IronResult<Response> {
+    set_package_yanked(req, false)
+}
+
+// Yanked packages are never resolved as the latest release of a package or listed, but can
+// still be shown and downloaded by their fully qualified identifier.
+fn set_package_yanked(req: &mut Request, yanked: bool) -> IronResult<Response> {
+    let (ident, session_id) = {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        let session_id = session.get_id();
+        let params = req.extensions.get::<Router>().unwrap();
+        (ident_from_params(params), session_id)
+    };
+
+    if !try!(check_origin_access(req, session_id, &ident.get_origin())) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let package = match package_to_change(req, &ident) {
+        Ok(package) => package,
+        Err(response) => return Ok(response),
+    };
+    let mut yank = OriginPackageYank::new();
+    yank.set_package_id(package.get_id());
+    yank.set_ident(ident);
+    yank.set_yanked(yanked);
+    match route_message::<OriginPackageYank, NetOk>(req, &yank) {
+        Ok(_) => Ok(Response::with(status::Ok)),
+        Err(err) => {
+            error!("Error yanking package, {}", err);
+            Ok(render_net_error(&err))
+        }
+    }
+}
+
+fn delete_package(req: &mut Request) -> IronResult<Response> {
+    let (ident, session_id) = {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        let session_id = session.get_id();
+        let params = req.extensions.get::<Router>().unwrap();
+        (ident_from_params(params), session_id)
+    };
+
+    // Only the owner of an origin may delete its packages
+    match try!(get_origin(req, ident.get_origin())) {
+        Some(origin) => {
+            if origin.get_owner_id() != session_id {
+                return Ok(Response::with(status::Forbidden));
+            }
+        }
+        None => return Ok(Response::with(status::NotFound)),
+    }
+
+    let package = match package_to_change(req, &ident) {
+        Ok(package) => package,
+        Err(response) => return Ok(response),
+    };
+
+    // Refuse to delete a package which packages in any origin still depend on
+    let mut request = OriginPackageDependentsGet::new();
+    request.set_ident(ident.clone());
+    match route_message::<OriginPackageDependentsGet, OriginPackageListResponse>(req, &request) {
+        Ok(dependents) => {
+            if dependents.get_count() > 0 {
+                let body = package_results_json(
+                    &dependents.get_idents().to_vec(),
+                    dependents.get_count() as isize,
+                    dependents.get_start() as isize,
+                    dependents.get_stop() as isize,
+                );
+                let mut response = Response::with((status::Conflict, body));
+                response.headers.set(ContentType(Mime(
+                    TopLevel::Application,
+                    SubLevel::Json,
+                    vec![(Attr::Charset, Value::Utf8)],
+                )));
+                dont_cache_response(&mut response);
+                return Ok(response);
+            }
+        }
+        Err(err) => {
+            error!("delete_package:2, err={:?}", err);
+            return Ok(render_net_error(&err));
+        }
+    }
+
+    let mut delete = OriginPackageDelete::new();
+    delete.set_package_id(package.get_id());
+    delete.set_ident(ident.clone());
+    if let Err(err) = route_message::<OriginPackageDelete, NetOk>(req, &delete) {
+        error!("Error deleting package, {}", err);
+        return Ok(render_net_error(&err));
+    }
+
+    match PackageTarget::from_str(package.get_target()) {
+        Ok(target) => {
+            let lock = req.get::<persistent::State<DepotUtil>>().expect(
+                "depot not found",
+            );
+            let depot = lock.read().expect("depot read lock is poisoned");
+            if let Err(e) = depot.delete_archive(&ident, &target) {
+                warn!(
+                    "Unable to remove archive of deleted package {}, {}",
+                    ident,
+                    e
+                );
+            }
+        }
+        Err(e) => warn!("Deleted package {} has an invalid target, {}", ident, e),
+    }
+    Ok(Response::with(status::Ok))
+}
+
+fn show_package(req: &mut Request) -> IronResult<Response> {
+    let response = try!(show_local_package(req));
+    if response.status != Some(status::NotFound) {
+        return Ok(response);
+    }
+    let (ident, channel) = {
+        let params = req.extensions.get::<Router>().unwrap();
+        (
+            ident_from_params(params),
+            params.find("channel").map(|c| c.to_string()),
+        )
+    };
+    let target = match target_from_request(req) {
+        Ok(target) => target,
+        Err(_) => return Ok(response),
+    };
+    if try!(mirror_package(
+        req,
+        &ident,
+        &target,
+        channel.as_ref().map(|c| c.as_str()),
+    ))
+    {
+        show_local_package(req)
+    } else {
+        Ok(response)
+    }
+}
+
+fn download_package(req: &mut Request) -> IronResult<Response> {
+    let response = try!(download_local_package(req));
+    if response.status != Some(status::NotFound) {
+        return Ok(response);
+    }
+    let ident = {
+        let params = req.extensions.get::<Router>().unwrap();
+        ident_from_params(params)
+    };
+    let target = match target_from_request(req) {
+        Ok(target) => target,
+        Err(_) => return Ok(response),
+    };
+    if try!(mirror_package(req, &ident, &target, None)) {
+        download_local_package(req)
+    } else {
+        Ok(response)
+    }
+}
+
+fn download_origin_key(req: &mut Request) -> IronResult<Response> {
+    let response = try!(download_local_origin_key(req));
+    if response.status != Some(status::NotFound) {
+        return Ok(response);
+    }
+    let (origin, revision) = {
+        let params = req.extensions.get::<Router>().unwrap();
+        match (params.find("origin"), params.find("revision")) {
+            (Some(origin), Some(revision)) => (origin.to_string(), revision.to_string()),
+            _ => return Ok(response),
+        }
+    };
+    if try!(mirror_origin_key(req, &origin, Some(&revision))) {
+        download_local_origin_key(req)
+    } else {
+        Ok(response)
+    }
+}
+
+// This function should not require authentication (session/auth token)
+fn download_latest_origin_key(req: &mut Request) -> IronResult<Response> {
+    let response = try!(download_local_latest_origin_key(req));
+    if response.status != Some(status::NotFound) {
+        return Ok(response);
+    }
+    let origin = {
+        let params = req.extensions.get::<Router>().unwrap();
+        match params.find("origin") {
+            Some(origin) => origin.to_string(),
+            None => return Ok(response),
+        }
+    };
+    if try!(mirror_origin_key(req, &origin, None)) {
+        download_local_latest_origin_key(req)
+    } else {
+        Ok(response)
+    }
+}
+
+// Mirror a package from the upstream Depot, if one is configured. Returns true if the package
+// was mirrored. The depot lock is only held while the downloaded archive is stored.
+fn mirror_package(
+    req: &mut Request,
+    ident: &OriginPackageIdent,
+    target: &PackageTarget,
+    channel: Option<&str>,
+) -> IronResult<bool> {
+    let lock = req.get::<persistent::State<DepotUtil>>().expect(
+        "depot not found",
+    );
+    let upstream = match lock.read().expect("depot read lock is poisoned").upstream {
+        Some(ref upstream) => upstream.clone(),
+        None => return Ok(false),
+    };
+    match upstream.mirror_package(req, &*lock, ident, target, channel) {
+        Ok(_) => Ok(true),
+        Err(e) => {
+            warn!("Unable to mirror {} from upstream, {}", ident, e);
+            Ok(false)
+        }
+    }
+}
+
+// Mirror a public origin key from the upstream Depot, if one is configured. Returns true if the
+// key was mirrored.
+fn mirror_origin_key(req: &mut Request, origin: &str, revision: Option<&str>) -> IronResult<bool> {
+    let lock = req.get::<persistent::State<DepotUtil>>().expect(
+        "depot not found",
+    );
+    let upstream = match lock.read().expect("depot read lock is poisoned").upstream {
+        Some(ref upstream) => upstream.clone(),
+        None => return Ok(false),
+    };
+    match upstream.mirror_origin_key(req, origin, revision) {
+        Ok(_) => Ok(true),
+        Err(e) => {
+            warn!("Unable to mirror public origin key for {} from upstream, {}", origin, e);
+            Ok(false)
+        }
+    }
+}
+
 fn ident_from_params(params: &Params) -> OriginPackageIdent {
     let mut ident = OriginPackageIdent::new();
     ident.set_origin(params.find("origin").unwrap().to_string());
@@ -1898,6 +2213,83 @@ fn target_from_headers(user_agent_header: &UserAgent) -> result::Result<PackageT
     }
 }
 
+// Returns the package target a client asked for with the `target` query parameter, if any.
+fn target_from_query(req: &mut Request) -> result::Result<Option<PackageTarget>, Response> {
+    match extract_query_value("target", req) {
+        Some(target) => {
+            match PackageTarget::from_str(&target) {
+                Ok(t) => Ok(Some(t)),
+                Err(_) => Err(Response::with(status::BadRequest)),
+            }
+        }
+        None => Ok(None),
+    }
+}
+
+// Returns the package a request to yank or delete a release acts on: the release built for the
+// target given with the `target` query parameter or, without one, the only target this Depot
+// has the release for. A release built for several targets is never changed for whichever
+// target happens to be found first, so the request is refused until it names a target.
+fn package_to_change(
+    req: &mut Request,
+    ident: &OriginPackageIdent,
+) -> result::Result<OriginPackage, Response> {
+    let targets = match try!(target_from_query(req)) {
+        Some(target) => vec![target],
+        None => {
+            req.get::<persistent::State<DepotUtil>>()
+                .expect("depot not found")
+                .read()
+                .expect("depot read lock is poisoned")
+                .config
+                .targets
+                .clone()
+        }
+    };
+    let mut packages: Vec<OriginPackage> = Vec::new();
+    for target in targets.iter() {
+        let mut request = OriginPackageGet::new();
+        request.set_ident(ident.clone());
+        request.set_target(target.to_string());
+        match route_message::<OriginPackageGet, OriginPackage>(req, &request) {
+            Ok(package) => {
+                if !packages.iter().any(|p| p.get_id() == package.get_id()) {
+                    packages.push(package);
+                }
+            }
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => (),
+            Err(err) => {
+                error!("package_to_change:1, err={:?}", err);
+                return Err(Response::with(status::InternalServerError));
+            }
+        }
+    }
+    match packages.len() {
+        0 => Err(Response::with(status::NotFound)),
+        1 => Ok(packages.pop().unwrap()),
+        _ => {
+            let targets: Vec<&str> = packages.iter().map(|p| p.get_target()).collect();
+            Err(Response::with((
+                status::BadRequest,
+                format!(
+                    "{} is built for several targets, choose one with the target parameter: {}",
+                    ident,
+                    targets.join(", ")
+                ),
+            )))
+        }
+    }
+}
+
+// Returns the package target a client wants, which is the one given with the `target` query
+// parameter or, failing that, the one found in its user agent.
+fn target_from_request(req: &mut Request) -> result::Result<PackageTarget, Response> {
+    match try!(target_from_query(req)) {
+        Some(target) => Ok(target),
+        None => target_from_headers(&req.headers.get::<UserAgent>().unwrap()),
+    }
+}
+
 // Returns a tuple representing the from and to values representing a paginated set.
 // The range (start, stop) values are zero-based.
 //
@@ -1990,6 +2382,15 @@ where
         packages_version: get "/pkgs/:origin/:pkg/:version" => list_packages,
         package_version_latest: get "/pkgs/:origin/:pkg/:version/latest" => show_package,
         package: get "/pkgs/:origin/:pkg/:version/:release" => show_package,
+        package_delete: delete "/pkgs/:origin/:pkg/:version/:release" => {
+            XHandler::new(delete_package).before(basic.clone())
+        },
+        package_yank: put "/pkgs/:origin/:pkg/:version/:release/yank" => {
+            XHandler::new(yank_package).before(basic.clone())
+        },
+        package_unyank: delete "/pkgs/:origin/:pkg/:version/:release/yank" => {
+            XHandler::new(unyank_package).before(basic.clone())
+        },
 
         package_download: get "/pkgs/:origin/:pkg/:version/:release/download" => {
             download_package
@@ -2071,9 +2472,10 @@ pub fn router(depot: DepotUtil) -> Result<Chain> {
 }
 
 pub fn run(config: Config) -> Result<()> {
-    let depot = DepotUtil::new(config.clone());
+    let depot = try!(DepotUtil::new(config.clone()));
     let v1 = try!(router(depot));
     let broker = Broker::run(DepotUtil::net_ident(), &config.route_addrs().clone());
+    try!(upstream::run_sync(config.clone()));
 
     let mut mount = Mount::new();
     mount.mount("/v1", v1);
@@ -2164,7 +2566,7 @@ mod test {
             .join("depot-tests")
             .to_string_lossy()
             .to_string();
-        let depot = DepotUtil::new(config);
+        let depot = DepotUtil::new(config).unwrap();
         req.extensions.insert::<Authenticated>(Session::new());
         req.extensions.insert::<TestableBroker>(broker);
 
@@ -2244,15 +2646,14 @@ mod test {
             .join("depot-tests")
             .to_string_lossy()
             .to_string();
-        let depot = DepotUtil::new(config);
+        let depot = DepotUtil::new(config).unwrap();
         let mut ident = OriginPackageIdent::new();
         ident.set_origin("core".to_string());
         ident.set_name("cacerts".to_string());
         ident.set_version("2017.01.17".to_string());
         ident.set_release("20170209064044".to_string());
         let target = PackageTarget::from_str("x86_64-windows").unwrap();
-        let file_name = depot.archive_path(&ident, &target);
-        let _ = fs::remove_file(&file_name);
+        let _ = depot.delete_archive(&ident, &target);
 
         //setup broker messages
         let mut broker: TestableBroker = Default::default();
@@ -2302,7 +2703,7 @@ mod test {
             result_body,
             "/pkgs/core/cacerts/2017.01.17/20170209064044/download"
         );
-        assert!(fs::metadata(&file_name).is_ok());
+        assert!(depot.has_archive(&ident, &target).unwrap());
 
         //assert we sent the corect data to postgres
         let package_req = msgs.get::<OriginPackageCreate>().unwrap();
@@ -2357,13 +2758,15 @@ mod test {
                 .to_string(),
         ));
 
-        let (response, _) = iron_request(
+        let (response, msgs) = iron_request(
             method::Get,
             "http://localhost/pkgs/core/cacerts/2017.01.17/20170209064045/download",
             &mut Vec::new(),
             headers,
             download_broker,
         );
+        let package_req = msgs.get::<OriginPackageGet>().unwrap();
+        assert_eq!(package_req.get_target(), "x86_64-windows");
 
         //assert headers
         let response = response.unwrap();
@@ -2857,6 +3260,59 @@ mod test {
         );
         let package_req = msgs.get::<OriginPackageGet>().unwrap();
         assert_eq!(package_req.get_ident().to_string(), ident.to_string());
+        assert_eq!(package_req.get_target(), "x86_64-linux");
+    }
+
+    #[test]
+    fn show_package_latest_for_target() {
+        let mut show_broker: TestableBroker = Default::default();
+
+        let mut ident = OriginPackageIdent::new();
+        ident.set_origin("org".to_string());
+        ident.set_name("name".to_string());
+        ident.set_version("1.1.1".to_string());
+        ident.set_release("20170101010101".to_string());
+
+        let mut package = OriginPackage::new();
+        package.set_ident(ident.clone());
+        package.set_target("x86_64-windows".to_string());
+        show_broker.setup::<OriginPackageGet, OriginPackage>(&package);
+        show_broker.setup::<OriginPackageLatestGet, OriginPackageIdent>(&ident);
+
+        //the target parameter wins over the user agent
+        let mut headers = Headers::new();
+        headers.set(UserAgent(
+            "hab/0.20.0-dev/20170326090935 (x86_64-linux; 9.9.9)"
+                .to_string(),
+        ));
+        let (response, msgs) = iron_request(
+            method::Get,
+            "http://localhost/pkgs/org/name/latest?target=x86_64-windows",
+            &mut Vec::new(),
+            headers,
+            show_broker,
+        );
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Ok));
+
+        let latest_req = msgs.get::<OriginPackageLatestGet>().unwrap();
+        assert_eq!(latest_req.get_target(), "x86_64-windows");
+        let package_req = msgs.get::<OriginPackageGet>().unwrap();
+        assert_eq!(package_req.get_target(), "x86_64-windows");
+    }
+
+    #[test]
+    fn show_package_with_invalid_target() {
+        let show_broker: TestableBroker = Default::default();
+        let (response, _) = iron_request(
+            method::Get,
+            "http://localhost/pkgs/org/name/latest?target=x86_64-beos",
+            &mut Vec::new(),
+            Headers::new(),
+            show_broker,
+        );
+        assert_eq!(response.unwrap().status, Some(status::BadRequest));
     }
 
     #[test]
@@ -3032,6 +3488,33 @@ mod test {
         assert_eq!(package_req.get_start(), 2);
         assert_eq!(package_req.get_stop(), 51);
         assert_eq!(package_req.get_query(), "org/name".to_string());
+        assert!(!package_req.has_license());
+    }
+
+    #[test]
+    fn search_packages_by_license() {
+        let mut broker: TestableBroker = Default::default();
+
+        let mut pkg_res = OriginPackageListResponse::new();
+        pkg_res.set_start(0);
+        pkg_res.set_stop(0);
+        pkg_res.set_count(0);
+        broker.setup::<OriginPackageSearchRequest, OriginPackageListResponse>(&pkg_res);
+
+        let (response, msgs) = iron_request(
+            method::Get,
+            "http://localhost/pkgs/search/database?license=GPL-2.0",
+            &mut Vec::new(),
+            Headers::new(),
+            broker,
+        );
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Ok));
+
+        let package_req = msgs.get::<OriginPackageSearchRequest>().unwrap();
+        assert_eq!(package_req.get_query(), "database".to_string());
+        assert_eq!(package_req.get_license(), "GPL-2.0".to_string());
     }
 
     #[test]
@@ -3229,6 +3712,275 @@ mod test {
         assert_eq!(promote.get_ident().to_string(), ident.to_string());
     }
 
+    #[test]
+    fn yank_package() {
+        let mut broker: TestableBroker = Default::default();
+
+        let mut ident = OriginPackageIdent::new();
+        ident.set_origin("org".to_string());
+        ident.set_name("name".to_string());
+        ident.set_version("1.1.1".to_string());
+        ident.set_release("20170101010101".to_string());
+
+        let mut package = OriginPackage::new();
+        package.set_id(5000);
+        package.set_ident(ident.clone());
+        package.set_target("x86_64-linux".to_string());
+        broker.setup::<OriginPackageGet, OriginPackage>(&package);
+
+        let mut access_res = CheckOriginAccessResponse::new();
+        access_res.set_has_access(true);
+        broker.setup::<CheckOriginAccessRequest, CheckOriginAccessResponse>(&access_res);
+
+        broker.setup::<OriginPackageYank, NetOk>(&NetOk::new());
+
+        let (response, msgs) = iron_request(
+            method::Put,
+            "http://localhost/pkgs/org/name/1.1.1/20170101010101/yank",
+            &mut Vec::new(),
+            Headers::new(),
+            broker,
+        );
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Ok));
+
+        let yank = msgs.get::<OriginPackageYank>().unwrap();
+        assert_eq!(yank.get_package_id(), 5000);
+        assert_eq!(yank.get_ident().to_string(), ident.to_string());
+        assert!(yank.get_yanked());
+    }
+
+    #[test]
+    fn unyank_package() {
+        let mut broker: TestableBroker = Default::default();
+
+        let mut package = OriginPackage::new();
+        package.set_id(5000);
+        broker.setup::<OriginPackageGet, OriginPackage>(&package);
+
+        let mut access_res = CheckOriginAccessResponse::new();
+        access_res.set_has_access(true);
+        broker.setup::<CheckOriginAccessRequest, CheckOriginAccessResponse>(&access_res);
+
+        broker.setup::<OriginPackageYank, NetOk>(&NetOk::new());
+
+        let (response, msgs) = iron_request(
+            method::Delete,
+            "http://localhost/pkgs/org/name/1.1.1/20170101010101/yank",
+            &mut Vec::new(),
+            Headers::new(),
+            broker,
+        );
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Ok));
+
+        let yank = msgs.get::<OriginPackageYank>().unwrap();
+        assert_eq!(yank.get_package_id(), 5000);
+        assert!(!yank.get_yanked());
+    }
+
+    #[test]
+    fn yank_package_without_origin_access() {
+        let mut broker: TestableBroker = Default::default();
+
+        let mut access_res = CheckOriginAccessResponse::new();
+        access_res.set_has_access(false);
+        broker.setup::<CheckOriginAccessRequest, CheckOriginAccessResponse>(&access_res);
+
+        let (response, msgs) = iron_request(
+            method::Put,
+            "http://localhost/pkgs/org/name/1.1.1/20170101010101/yank",
+            &mut Vec::new(),
+            Headers::new(),
+            broker,
+        );
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Forbidden));
+        assert!(msgs.get::<OriginPackageYank>().is_err());
+    }
+
+    #[test]
+    fn delete_package() {
+        let mut broker: TestableBroker = Default::default();
+
+        let mut ident = OriginPackageIdent::new();
+        ident.set_origin("org".to_string());
+        ident.set_name("name".to_string());
+        ident.set_version("1.1.1".to_string());
+        ident.set_release("20170101010101".to_string());
+
+        let mut origin = Origin::new();
+        origin.set_name("org".to_string());
+        origin.set_owner_id(Session::new().get_id());
+        broker.setup::<OriginGet, Origin>(&origin);
+
+        let mut package = OriginPackage::new();
+        package.set_id(5000);
+        package.set_ident(ident.clone());
+        package.set_target("x86_64-linux".to_string());
+        broker.setup::<OriginPackageGet, OriginPackage>(&package);
+
+        broker.setup::<OriginPackageDependentsGet, OriginPackageListResponse>(
+            &OriginPackageListResponse::new(),
+        );
+        broker.setup::<OriginPackageDelete, NetOk>(&NetOk::new());
+
+        let (response, msgs) = iron_request(
+            method::Delete,
+            "http://localhost/pkgs/org/name/1.1.1/20170101010101",
+            &mut Vec::new(),
+            Headers::new(),
+            broker,
+        );
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Ok));
+
+        let dependents_get = msgs.get::<OriginPackageDependentsGet>().unwrap();
+        assert_eq!(dependents_get.get_ident().to_string(), ident.to_string());
+
+        let delete = msgs.get::<OriginPackageDelete>().unwrap();
+        assert_eq!(delete.get_package_id(), 5000);
+        assert_eq!(delete.get_ident().to_string(), ident.to_string());
+    }
+
+    #[test]
+    fn delete_package_built_for_several_targets_requires_a_target() {
+        let mut broker: TestableBroker = Default::default();
+
+        let mut origin = Origin::new();
+        origin.set_name("org".to_string());
+        origin.set_owner_id(Session::new().get_id());
+        broker.setup::<OriginGet, Origin>(&origin);
+
+        let mut linux = OriginPackage::new();
+        linux.set_id(5000);
+        linux.set_target("x86_64-linux".to_string());
+        let mut windows = OriginPackage::new();
+        windows.set_id(5001);
+        windows.set_target("x86_64-windows".to_string());
+        broker.queue::<OriginPackageGet, OriginPackage>(Ok(linux));
+        broker.queue::<OriginPackageGet, OriginPackage>(Ok(windows));
+        broker.setup::<OriginPackageDelete, NetOk>(&NetOk::new());
+
+        let (response, msgs) = iron_request(
+            method::Delete,
+            "http://localhost/pkgs/org/name/1.1.1/20170101010101",
+            &mut Vec::new(),
+            Headers::new(),
+            broker,
+        );
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::BadRequest));
+        assert!(response::extract_body_to_string(response).contains(
+            "x86_64-linux, x86_64-windows",
+        ));
+        assert!(msgs.get::<OriginPackageDelete>().is_err());
+    }
+
+    #[test]
+    fn delete_package_for_a_target() {
+        let mut broker: TestableBroker = Default::default();
+
+        let mut origin = Origin::new();
+        origin.set_name("org".to_string());
+        origin.set_owner_id(Session::new().get_id());
+        broker.setup::<OriginGet, Origin>(&origin);
+
+        let mut package = OriginPackage::new();
+        package.set_id(5001);
+        package.set_target("x86_64-windows".to_string());
+        broker.setup::<OriginPackageGet, OriginPackage>(&package);
+        broker.setup::<OriginPackageDependentsGet, OriginPackageListResponse>(
+            &OriginPackageListResponse::new(),
+        );
+        broker.setup::<OriginPackageDelete, NetOk>(&NetOk::new());
+
+        let (response, msgs) = iron_request(
+            method::Delete,
+            "http://localhost/pkgs/org/name/1.1.1/20170101010101?target=x86_64-windows",
+            &mut Vec::new(),
+            Headers::new(),
+            broker,
+        );
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Ok));
+        let get = msgs.get::<OriginPackageGet>().unwrap();
+        assert_eq!(get.get_target(), "x86_64-windows");
+        let delete = msgs.get::<OriginPackageDelete>().unwrap();
+        assert_eq!(delete.get_package_id(), 5001);
+    }
+
+    #[test]
+    fn delete_package_with_dependents() {
+        let mut broker: TestableBroker = Default::default();
+
+        let mut origin = Origin::new();
+        origin.set_name("org".to_string());
+        origin.set_owner_id(Session::new().get_id());
+        broker.setup::<OriginGet, Origin>(&origin);
+
+        let mut package = OriginPackage::new();
+        package.set_id(5000);
+        broker.setup::<OriginPackageGet, OriginPackage>(&package);
+
+        let mut dependent = OriginPackageIdent::new();
+        dependent.set_origin("other".to_string());
+        dependent.set_name("app".to_string());
+        dependent.set_version("2.0.0".to_string());
+        dependent.set_release("20170202020202".to_string());
+        let mut dependents = OriginPackageListResponse::new();
+        dependents.set_start(0);
+        dependents.set_stop(0);
+        dependents.set_count(1);
+        let mut idents = protobuf::RepeatedField::new();
+        idents.push(dependent);
+        dependents.set_idents(idents);
+        broker.setup::<OriginPackageDependentsGet, OriginPackageListResponse>(&dependents);
+
+        let (response, msgs) = iron_request(
+            method::Delete,
+            "http://localhost/pkgs/org/name/1.1.1/20170101010101",
+            &mut Vec::new(),
+            Headers::new(),
+            broker,
+        );
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Conflict));
+        assert!(response::extract_body_to_string(response).contains(
+            "other/app/2.0.0/20170202020202",
+        ));
+        assert!(msgs.get::<OriginPackageDelete>().is_err());
+    }
+
+    #[test]
+    fn delete_package_requires_origin_owner() {
+        let mut broker: TestableBroker = Default::default();
+
+        let mut origin = Origin::new();
+        origin.set_name("org".to_string());
+        origin.set_owner_id(Session::new().get_id() + 1);
+        broker.setup::<OriginGet, Origin>(&origin);
+
+        let (response, msgs) = iron_request(
+            method::Delete,
+            "http://localhost/pkgs/org/name/1.1.1/20170101010101",
+            &mut Vec::new(),
+            Headers::new(),
+            broker,
+        );
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Forbidden));
+        assert!(msgs.get::<OriginPackageDelete>().is_err());
+    }
+
     #[test]
     fn channel_delete() {
         let mut broker: TestableBroker = Default::default();
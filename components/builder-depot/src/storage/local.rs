@@ -0,0 +1,139 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Package store variant which keeps archives in the local filesystem.
+//!
+//! To avoid the problems of large numbers of files stored within a single directory, archives
+//! are stored in a nested directory structure based on the SHA256 checksum of the package's
+//! identifier. For example, `core/cacerts/2017.01.17/20170209064044` built for `x86_64-windows`
+//! is stored at `/pkgs/c6/4f/core-cacerts-2017.01.17-20170209064044-x86_64-windows.hart`, where
+//! `/pkgs` is the root of the store.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crypto::digest::Digest;
+use crypto::sha2::Sha256;
+use hab_core::package::{PackageIdent, PackageTarget};
+
+use error::Result;
+use super::{archive_name, PackageStore};
+
+/// Wraps a `PathBuf` representing the root of a local package store.
+pub struct LocalStore(PathBuf);
+
+impl LocalStore {
+    pub fn new(path: PathBuf) -> LocalStore {
+        LocalStore(path)
+    }
+
+    /// Generate the path that a given package's archive will be stored at.
+    pub fn archive_path(&self, ident: &PackageIdent, target: &PackageTarget) -> PathBuf {
+        let mut digest = Sha256::new();
+        let mut output = [0; 64];
+        digest.input_str(&ident.to_string());
+        digest.result(&mut output);
+        self.0
+            .join(format!("{:x}", output[0]))
+            .join(format!("{:x}", output[1]))
+            .join(archive_name(ident, target))
+    }
+}
+
+impl PackageStore for LocalStore {
+    fn put(&self, ident: &PackageIdent, target: &PackageTarget, file_path: &Path) -> Result<()> {
+        let archive_path = self.archive_path(ident, target);
+        fs::create_dir_all(archive_path.parent().unwrap())?;
+        // The archive may live on another filesystem, in which case it can't simply be renamed
+        if fs::rename(file_path, &archive_path).is_err() {
+            fs::copy(file_path, &archive_path)?;
+            fs::remove_file(file_path)?;
+        }
+        Ok(())
+    }
+
+    fn exists(&self, ident: &PackageIdent, target: &PackageTarget) -> Result<bool> {
+        Ok(self.archive_path(ident, target).is_file())
+    }
+
+    fn fetch(&self, ident: &PackageIdent, target: &PackageTarget) -> Result<Option<PathBuf>> {
+        let archive_path = self.archive_path(ident, target);
+        if archive_path.is_file() {
+            Ok(Some(archive_path))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn delete(&self, ident: &PackageIdent, target: &PackageTarget) -> Result<()> {
+        match fs::remove_file(self.archive_path(ident, target)) {
+            Ok(_) => Ok(()),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::Write;
+    use std::str::FromStr;
+
+    use tempdir::TempDir;
+
+    use super::*;
+
+    fn package() -> (PackageIdent, PackageTarget) {
+        (
+            PackageIdent::from_str("core/cacerts/2017.01.17/20170209064044").unwrap(),
+            PackageTarget::from_str("x86_64-windows").unwrap(),
+        )
+    }
+
+    #[test]
+    fn local_archive_path() {
+        let store = LocalStore::new(PathBuf::from("/pkgs"));
+        let (ident, target) = package();
+        let expected_path = PathBuf::from(
+            "/pkgs/c6/4f/core-cacerts-2017.01.17-20170209064044-x86_64-windows.hart",
+        );
+        assert_eq!(store.archive_path(&ident, &target), expected_path);
+    }
+
+    #[test]
+    fn put_fetch_and_delete() {
+        let root = TempDir::new("local-store").unwrap();
+        let store = LocalStore::new(root.path().join("pkgs"));
+        let (ident, target) = package();
+
+        let upload = root.path().join("upload.tmp");
+        File::create(&upload).unwrap().write_all(b"hart").unwrap();
+        assert!(!store.exists(&ident, &target).unwrap());
+        assert_eq!(store.fetch(&ident, &target).unwrap(), None);
+
+        store.put(&ident, &target, &upload).unwrap();
+        assert!(!upload.exists());
+        assert!(store.exists(&ident, &target).unwrap());
+        assert_eq!(
+            store.fetch(&ident, &target).unwrap(),
+            Some(store.archive_path(&ident, &target))
+        );
+
+        store.delete(&ident, &target).unwrap();
+        assert!(!store.exists(&ident, &target).unwrap());
+        store.delete(&ident, &target).unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contract for storage and retrieval of package archives.
+//!
+//! Metadata about packages lives in the origin server, but the `.hart` files themselves are
+//! handed to a `PackageStore`. Keeping them behind this contract means the depot doesn't need to
+//! own the filesystem they end up on.
+
+pub mod local;
+pub mod s3;
+
+use std::path::{Path, PathBuf};
+
+use hab_core::package::{PackageIdent, PackageTarget};
+
+use config::Config;
+use error::Result;
+
+/// Currently implemented package storage backends
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    Local,
+    S3,
+}
+
+pub trait PackageStore: Send + Sync {
+    /// Given a fully qualified `ident`, the `target` it was built for and the path to its
+    /// archive, moves the archive into storage.
+    fn put(&self, ident: &PackageIdent, target: &PackageTarget, file_path: &Path) -> Result<()>;
+
+    /// Returns true if storage holds an archive for the given package.
+    fn exists(&self, ident: &PackageIdent, target: &PackageTarget) -> Result<bool>;
+
+    /// Returns the path to a local copy of the archive for the given package, or `None` if
+    /// storage holds no such archive.
+    fn fetch(&self, ident: &PackageIdent, target: &PackageTarget) -> Result<Option<PathBuf>>;
+
+    /// Removes the archive for the given package from storage. Removing an archive which isn't
+    /// stored is not an error.
+    fn delete(&self, ident: &PackageIdent, target: &PackageTarget) -> Result<()>;
+}
+
+/// Create appropriate PackageStore variant based on configuration values.
+pub fn from_config(config: &Config) -> Result<Box<PackageStore + 'static>> {
+    let path = Path::new(&config.path);
+    match config.storage.backend {
+        StorageBackend::Local => Ok(Box::new(local::LocalStore::new(path.join("pkgs")))),
+        StorageBackend::S3 => Ok(Box::new(
+            s3::S3Store::new(config.storage.clone(), path.join("cache"))?,
+        )),
+    }
+}
+
+/// Returns the file name of the archive for the given package, which is also the name it is
+/// stored under.
+pub fn archive_name(ident: &PackageIdent, target: &PackageTarget) -> String {
+    format!(
+        "{}-{}-{}-{}-{}-{}.hart",
+        ident.origin,
+        ident.name,
+        ident.version.as_ref().map(|v| v.as_str()).unwrap_or(""),
+        ident.release.as_ref().map(|r| r.as_str()).unwrap_or(""),
+        target.architecture,
+        target.platform
+    )
+}
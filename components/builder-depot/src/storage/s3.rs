@@ -0,0 +1,358 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Package store variant which uses S3 (or an API compatible clone, such as
+//! [Minio](https://minio.io)) for archive storage.
+//!
+//! All archives are stored in a single bucket, using the archive's file name as the key.
+//! Archives are downloaded into a local cache directory before being served, and since a
+//! package's archive never changes once uploaded, cached archives are reused until the package
+//! is deleted. Once the cache grows past its configured size, the archives fetched longest ago
+//! are removed from it.
+//!
+//! # Configuration
+//!
+//! Currently the store must be configured with both an access key ID and a secret access key, as
+//! well as the bucket to store archives in.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::result;
+use std::str::FromStr;
+
+use aws_sdk_rust::aws::common::credentials::{DefaultCredentialsProvider, ParametersProvider};
+use aws_sdk_rust::aws::common::region::Region;
+use aws_sdk_rust::aws::errors::s3::S3Error;
+use aws_sdk_rust::aws::s3::endpoint::{Endpoint, Signature};
+use aws_sdk_rust::aws::s3::object::{DeleteObjectRequest, GetObjectRequest, HeadObjectRequest,
+                                    PutObjectRequest};
+use aws_sdk_rust::aws::s3::s3client::S3Client;
+use hab_core::package::{PackageIdent, PackageTarget};
+use hyper::client::Client as HyperClient;
+use url;
+use uuid::Uuid;
+
+use VERSION;
+use config::StorageCfg;
+use error::{Error, Result};
+use super::{archive_name, PackageStore};
+
+type Client = S3Client<DefaultCredentialsProvider, HyperClient>;
+
+pub struct S3Store {
+    key: String,
+    secret: String,
+    region: Region,
+    endpoint: Option<url::Url>,
+    bucket: String,
+    cache_path: PathBuf,
+    cache_size: u64,
+}
+
+impl S3Store {
+    pub fn new(config: StorageCfg, cache_path: PathBuf) -> Result<S3Store> {
+        let endpoint = match config.endpoint {
+            Some(url) => Some(url::Url::parse(url.as_str())?),
+            None => None,
+        };
+        let region = match Region::from_str(config.region.as_str()) {
+            Ok(region) => region,
+            Err(_) => {
+                return Err(Error::InvalidStorageConfig(
+                    format!("unknown S3 region {}", config.region),
+                ))
+            }
+        };
+        Ok(S3Store {
+            key: required(config.key, "key")?,
+            secret: required(config.secret, "secret")?,
+            region: region,
+            endpoint: endpoint,
+            bucket: required(config.bucket, "bucket")?,
+            cache_path: cache_path,
+            cache_size: config.cache_size,
+        })
+    }
+
+    // The client isn't safe to share between the depot's request threads, so one is built for
+    // every operation.
+    fn client(&self) -> Client {
+        let param_provider = Some(
+            ParametersProvider::with_parameters(
+                self.key.clone(),
+                self.secret.as_str(),
+                None,
+            ).unwrap(),
+        );
+        // If given an endpoint, don't use virtual buckets... if not, assume AWS and use virtual
+        // buckets.
+        let use_virtual_buckets = !self.endpoint.is_some();
+        let user_agent = format!("Habitat-Builder/{}", VERSION);
+
+        let provider = DefaultCredentialsProvider::new(param_provider).unwrap();
+        let endpoint = Endpoint::new(
+            self.region.clone(),
+            Signature::V4,
+            self.endpoint.clone(),
+            None,
+            Some(user_agent),
+            Some(use_virtual_buckets),
+        );
+        S3Client::new(provider, endpoint)
+    }
+
+    /// Runs an operation against the object store.
+    ///
+    /// The S3 library panics if it can't resolve the URL of the object store (e.g., there's a
+    /// netsplit, your Minio goes down, S3 goes down (!)), so we have to catch it or the request
+    /// thread dies with it. The library's code isn't UnwindSafe either, so we deal with that too.
+    fn call<F, T>(&self, operation: &str, key: &str, f: F) -> Result<T>
+    where
+        F: FnOnce(&Client) -> result::Result<T, S3Error>,
+    {
+        let client = self.client();
+        match panic::catch_unwind(AssertUnwindSafe(|| f(&client))) {
+            Ok(Ok(output)) => Ok(output),
+            // This is a "normal", non-panicking error, e.g., they're configured with a
+            // non-existent bucket.
+            Ok(Err(e)) => Err(Error::PackageStore(format!("{} {}", operation, key), e)),
+            Err(e) => {
+                let source = match e.downcast_ref::<String>() {
+                    Some(string) => string.to_string(),
+                    None => format!("{:?}", e),
+                };
+                Err(Error::CaughtPanic(
+                    format!("Failure to {} {}", operation, key),
+                    source,
+                ))
+            }
+        }
+    }
+
+    fn cached_path(&self, key: &str) -> PathBuf {
+        self.cache_path.join(key)
+    }
+}
+
+fn required(value: Option<String>, name: &str) -> Result<String> {
+    match value {
+        Some(value) => Ok(value),
+        None => Err(Error::InvalidStorageConfig(
+            format!("the S3 backend requires a {}", name),
+        )),
+    }
+}
+
+/// Removes the archives fetched longest ago from the cache directory until the archives left in
+/// it take up no more than `cache_size` bytes. The archive at `keep` has just been fetched to be
+/// served, so it is never removed.
+fn prune_cache(cache_path: &Path, cache_size: u64, keep: &Path) -> Result<()> {
+    let mut archives = Vec::new();
+    let mut total = 0;
+    for entry in fs::read_dir(cache_path)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        let path = entry.path();
+        // Skip anything still being downloaded
+        if !metadata.is_file() || path.extension().and_then(|e| e.to_str()) == Some("tmp") {
+            continue;
+        }
+        total += metadata.len();
+        if path != keep {
+            archives.push((metadata.modified()?, path, metadata.len()));
+        }
+    }
+    archives.sort();
+    for (_, path, len) in archives {
+        if total <= cache_size {
+            break;
+        }
+        debug!("Removing {} from the archive cache", path.display());
+        match fs::remove_file(&path) {
+            // Another request may have pruned it first
+            Ok(()) => (),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => (),
+            Err(e) => return Err(Error::from(e)),
+        }
+        total -= len;
+    }
+    Ok(())
+}
+
+/// Returns true if the error code the object store failed an operation with means the object
+/// doesn't exist. A `HEAD` response has no body to carry an error code, so the bare status is all
+/// there is to go on.
+fn is_not_found(code: &str) -> bool {
+    match code {
+        "NoSuchKey" | "NotFound" | "404" => true,
+        _ => false,
+    }
+}
+
+impl PackageStore for S3Store {
+    fn put(&self, ident: &PackageIdent, target: &PackageTarget, file_path: &Path) -> Result<()> {
+        let key = archive_name(ident, target);
+        let mut buffer = Vec::new();
+        let mut file = OpenOptions::new().read(true).open(file_path)?;
+        file.read_to_end(&mut buffer)?;
+
+        let mut request = PutObjectRequest::default();
+        request.bucket = self.bucket.clone();
+        request.key = key.clone();
+        request.body = Some(buffer.as_slice());
+        self.call("upload", &key, |client| client.put_object(&request, None))?;
+        fs::remove_file(file_path)?;
+        Ok(())
+    }
+
+    fn exists(&self, ident: &PackageIdent, target: &PackageTarget) -> Result<bool> {
+        let key = archive_name(ident, target);
+        let mut request = HeadObjectRequest::default();
+        request.bucket = self.bucket.clone();
+        request.key = key.clone();
+        match self.call("find", &key, |client| client.head_object(&request)) {
+            Ok(_) => Ok(true),
+            Err(Error::PackageStore(_, ref e)) if is_not_found(&e.code) => {
+                debug!("Archive {} not found in bucket {}", key, self.bucket);
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    fn fetch(&self, ident: &PackageIdent, target: &PackageTarget) -> Result<Option<PathBuf>> {
+        let key = archive_name(ident, target);
+        let cached_path = self.cached_path(&key);
+        if cached_path.is_file() {
+            return Ok(Some(cached_path));
+        }
+        if !self.exists(ident, target)? {
+            return Ok(None);
+        }
+
+        let mut request = GetObjectRequest::default();
+        request.bucket = self.bucket.clone();
+        request.key = key.clone();
+        let response = self.call("download", &key, |client| {
+            client.get_object(&request, None)
+        })?;
+
+        // Write to a temp file first so a partial download is never served
+        fs::create_dir_all(&self.cache_path)?;
+        let tmp_path = self.cache_path.join(format!("{}.tmp", Uuid::new_v4()));
+        {
+            let mut file = File::create(&tmp_path)?;
+            file.write_all(response.body.as_slice())?;
+        }
+        fs::rename(&tmp_path, &cached_path)?;
+        if let Err(e) = prune_cache(&self.cache_path, self.cache_size, &cached_path) {
+            warn!("Unable to prune the archive cache, {}", e);
+        }
+        Ok(Some(cached_path))
+    }
+
+    fn delete(&self, ident: &PackageIdent, target: &PackageTarget) -> Result<()> {
+        let key = archive_name(ident, target);
+        let mut request = DeleteObjectRequest::default();
+        request.bucket = self.bucket.clone();
+        request.key = key.clone();
+        self.call("delete", &key, |client| client.delete_object(&request, None))?;
+        let cached_path = self.cached_path(&key);
+        if cached_path.is_file() {
+            fs::remove_file(&cached_path)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    use tempdir::TempDir;
+
+    use config::StorageCfg;
+    use error::Error;
+    use super::{is_not_found, prune_cache, S3Store};
+
+    fn config() -> StorageCfg {
+        let mut config = StorageCfg::default();
+        config.key = Some("key".to_string());
+        config.secret = Some("secret".to_string());
+        config.bucket = Some("habitat-depot".to_string());
+        config
+    }
+
+    #[test]
+    fn new_requires_credentials_and_bucket() {
+        assert!(S3Store::new(config(), PathBuf::from("/cache")).is_ok());
+        for missing in 0..3 {
+            let mut config = config();
+            match missing {
+                0 => config.key = None,
+                1 => config.secret = None,
+                _ => config.bucket = None,
+            }
+            match S3Store::new(config, PathBuf::from("/cache")) {
+                Err(Error::InvalidStorageConfig(_)) => (),
+                Err(e) => panic!("Unexpected error {}", e),
+                Ok(_) => panic!("Created a store with an incomplete configuration"),
+            }
+        }
+    }
+
+    #[test]
+    fn new_rejects_an_unknown_region() {
+        let mut config = config();
+        config.region = "middle-earth-1".to_string();
+        assert!(S3Store::new(config, PathBuf::from("/cache")).is_err());
+    }
+
+    #[test]
+    fn prune_cache_removes_the_oldest_archives_over_the_limit() {
+        let cache = TempDir::new("s3-cache").unwrap();
+        for name in ["a.hart", "b.hart", "c.hart"].iter() {
+            let mut file = File::create(cache.path().join(name)).unwrap();
+            file.write_all(&[0; 10]).unwrap();
+        }
+        File::create(cache.path().join("d.tmp"))
+            .unwrap()
+            .write_all(&[0; 10])
+            .unwrap();
+
+        prune_cache(cache.path(), 25, &cache.path().join("c.hart")).unwrap();
+        assert!(!cache.path().join("a.hart").exists());
+        assert!(cache.path().join("b.hart").exists());
+        assert!(cache.path().join("c.hart").exists());
+        assert!(cache.path().join("d.tmp").exists());
+
+        // The archive being served stays even if it alone is over the limit
+        prune_cache(cache.path(), 5, &cache.path().join("c.hart")).unwrap();
+        assert!(!cache.path().join("b.hart").exists());
+        assert!(cache.path().join("c.hart").exists());
+    }
+
+    #[test]
+    fn only_missing_objects_are_not_found() {
+        assert!(is_not_found("NoSuchKey"));
+        assert!(is_not_found("404"));
+        assert!(!is_not_found("AccessDenied"));
+        assert!(!is_not_found("NoSuchBucket"));
+        assert!(!is_not_found("403"));
+    }
+}
@@ -0,0 +1,646 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mirrors packages and origin keys from an upstream Depot.
+//!
+//! When an upstream Depot is configured, a request for a package or origin key this Depot
+//! doesn't have is passed on to the upstream Depot. Whatever it returns is verified, stored and
+//! served as if it had been uploaded here, making this Depot a transparent cache of the upstream
+//! one. Origins which only exist upstream are created here without an owner if the Depot is
+//! configured to do so; otherwise only packages of origins which exist here are mirrored.
+//!
+//! Packages are mirrored one target at a time, so a release built for several targets is only
+//! mirrored for the ones somebody asked for, or for every target this Depot serves when its
+//! channel is mirrored on a schedule.
+//!
+//! Origin channels can also be mirrored on a schedule, so their packages are at hand before
+//! anyone asks for them.
+
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use depot_client::{self, DisplayProgress};
+use hab_core::crypto::artifact;
+use hab_core::crypto::keys::{parse_name_with_rev, PairType};
+use hab_core::crypto::SigKeyPair;
+use hab_core::package::{FromArchive, PackageTarget};
+use hab_net::routing::{Broker, BrokerConn, RouteResult};
+use hyper::status::StatusCode;
+use protobuf;
+use protocol::Routable;
+use protocol::net::{ErrCode, NetOk};
+use protocol::originsrv::*;
+use uuid::Uuid;
+
+use {DepotUtil, VERSION};
+use config::{Config, MirrorCfg};
+use error::{Error, Result};
+
+const PRODUCT: &'static str = "bldr-depot";
+/// Owner name given to origins created while mirroring.
+const UPSTREAM_OWNER: &'static str = "upstream";
+/// Seconds to wait before connecting to the Builder services again when mirroring on a schedule
+const CONNECT_RETRY_SEC: u64 = 10;
+
+/// Routes messages to the Builder services, either on behalf of a request or over a connection
+/// of our own.
+pub trait Route {
+    fn route<M: Routable, R: protobuf::MessageStatic>(&mut self, msg: &M) -> RouteResult<R>;
+}
+
+impl Route for BrokerConn {
+    fn route<M: Routable, R: protobuf::MessageStatic>(&mut self, msg: &M) -> RouteResult<R> {
+        BrokerConn::route::<M, R>(self, msg)
+    }
+}
+
+/// Stores the archives of mirrored packages. Implemented for a `DepotUtil` shared behind a lock,
+/// so that the lock is only held while an archive is stored rather than while it is downloaded.
+pub trait ArchiveStore {
+    fn store_archive(
+        &self,
+        ident: &OriginPackageIdent,
+        target: &PackageTarget,
+        file_path: &Path,
+    ) -> Result<()>;
+}
+
+impl ArchiveStore for DepotUtil {
+    fn store_archive(
+        &self,
+        ident: &OriginPackageIdent,
+        target: &PackageTarget,
+        file_path: &Path,
+    ) -> Result<()> {
+        DepotUtil::store_archive(self, ident, target, file_path)
+    }
+}
+
+impl ArchiveStore for RwLock<DepotUtil> {
+    fn store_archive(
+        &self,
+        ident: &OriginPackageIdent,
+        target: &PackageTarget,
+        file_path: &Path,
+    ) -> Result<()> {
+        self.read().expect("depot read lock is poisoned").store_archive(
+            ident,
+            target,
+            file_path,
+        )
+    }
+}
+
+pub struct Upstream {
+    client: depot_client::Client,
+    /// Directory downloads are written to until they have been verified and stored.
+    cache_path: PathBuf,
+    /// Whether origins which only exist upstream are created here
+    create_origins: bool,
+    /// Origins configured for mirroring. Only their origin key revisions which we don't have yet
+    /// are imported from upstream.
+    trusted_origins: Vec<String>,
+}
+
+impl Upstream {
+    pub fn new(
+        url: &str,
+        depot_path: &Path,
+        create_origins: bool,
+        trusted_origins: Vec<String>,
+    ) -> Result<Upstream> {
+        Ok(Upstream {
+            client: try!(depot_client::Client::new(url, PRODUCT, VERSION, None)),
+            cache_path: depot_path.join("upstream"),
+            create_origins: create_origins,
+            trusted_origins: trusted_origins,
+        })
+    }
+
+    /// Mirrors a package release built for `target` from the upstream Depot and returns it.
+    ///
+    /// An identifier which isn't fully qualified is resolved to the latest release upstream.
+    /// When a channel is given, the release must be in that channel upstream and is promoted to
+    /// the channel of the same name here.
+    pub fn mirror_package<R: Route, S: ArchiveStore + ?Sized>(
+        &self,
+        router: &mut R,
+        depot: &S,
+        ident: &OriginPackageIdent,
+        target: &PackageTarget,
+        channel: Option<&str>,
+    ) -> Result<OriginPackage> {
+        let mut remote = try!(self.client.show_package(ident, channel, Some(target)));
+        let ident = remote.take_ident();
+
+        let mut request = OriginPackageGet::new();
+        request.set_ident(ident.clone());
+        request.set_target(target.to_string());
+        let package = match router.route::<OriginPackageGet, OriginPackage>(&request) {
+            Ok(package) => package,
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => {
+                let download_path = self.cache_path.join(Uuid::new_v4().to_string());
+                let result = self.download_package(
+                    router,
+                    depot,
+                    &ident,
+                    target,
+                    remote.get_checksum(),
+                    &download_path,
+                );
+                if let Err(e) = fs::remove_dir_all(&download_path) {
+                    warn!("Unable to clean up {}, {}", download_path.display(), e);
+                }
+                try!(result)
+            }
+            Err(err) => return Err(Error::ProtocolNetError(err)),
+        };
+        if let Some(channel) = channel {
+            try!(self.promote(router, &package, channel));
+        }
+        Ok(package)
+    }
+
+    /// Mirrors a public origin key from the upstream Depot and returns it. Without a revision,
+    /// the latest revision upstream is mirrored.
+    pub fn mirror_origin_key<R: Route>(
+        &self,
+        router: &mut R,
+        origin: &str,
+        revision: Option<&str>,
+    ) -> Result<OriginPublicKey> {
+        let revision = match revision {
+            Some(revision) => revision.to_string(),
+            None => {
+                let mut keys = try!(self.client.show_origin_keys(origin));
+                keys.sort_by(|a, b| a.get_revision().cmp(b.get_revision()));
+                match keys.pop() {
+                    Some(mut key) => key.take_revision(),
+                    None => {
+                        return Err(Error::RemoteOriginKeyNotFound(origin.to_string()));
+                    }
+                }
+            }
+        };
+        let origin = try!(self.ensure_origin(router, origin));
+        let download_path = self.cache_path.join(Uuid::new_v4().to_string());
+        let result = self.fetch_origin_key(router, &origin, &revision, &download_path);
+        if let Err(e) = fs::remove_dir_all(&download_path) {
+            warn!("Unable to clean up {}, {}", download_path.display(), e);
+        }
+        result
+    }
+
+    /// Mirrors every package release in an origin channel of the upstream Depot which isn't in
+    /// the channel here yet, for each target this Depot serves, returning how many were mirrored.
+    /// Releases which upstream hasn't built for a target are skipped.
+    pub fn sync_channel<R: Route>(
+        &self,
+        router: &mut R,
+        depot: &DepotUtil,
+        mirror: &MirrorCfg,
+    ) -> Result<usize> {
+        let mut mirrored = 0;
+        for ident in try!(self.client.list_channel_packages(
+            &mirror.origin,
+            &mirror.channel,
+        ))
+        {
+            let ident: OriginPackageIdent = ident.into();
+            for target in depot.config.targets.iter() {
+                let mut request = OriginChannelPackageGet::new();
+                request.set_name(mirror.channel.clone());
+                request.set_ident(ident.clone());
+                request.set_target(target.to_string());
+                match router.route::<OriginChannelPackageGet, OriginPackage>(&request) {
+                    Ok(_) => continue,
+                    Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => (),
+                    Err(err) => return Err(Error::ProtocolNetError(err)),
+                }
+                match self.mirror_package(router, depot, &ident, target, Some(&mirror.channel)) {
+                    Ok(_) => mirrored += 1,
+                    Err(Error::DepotClient(
+                        depot_client::Error::APIError(StatusCode::NotFound, _),
+                    )) => debug!("{} is not built for {} upstream", ident, target),
+                    Err(e) => {
+                        warn!(
+                            "Unable to mirror {} for {} from upstream, {}",
+                            ident,
+                            target,
+                            e
+                        )
+                    }
+                }
+            }
+        }
+        Ok(mirrored)
+    }
+
+    /// Downloads a package release built for `target` from the upstream Depot into
+    /// `download_path`, verifies it against the checksum upstream lists for it and its origin
+    /// key, and adds it to this Depot.
+    fn download_package<R: Route, S: ArchiveStore + ?Sized>(
+        &self,
+        router: &mut R,
+        depot: &S,
+        ident: &OriginPackageIdent,
+        target: &PackageTarget,
+        checksum: &str,
+        download_path: &Path,
+    ) -> Result<OriginPackage> {
+        let origin = try!(self.ensure_origin(router, ident.get_origin()));
+        let mut archive = try!(self.client.fetch_package(
+            ident,
+            target,
+            download_path,
+            None::<NoProgress>,
+        ));
+        let actual = try!(archive.checksum());
+        if actual != checksum {
+            return Err(Error::RemoteChecksumMismatch(
+                ident.to_string(),
+                checksum.to_string(),
+                actual,
+            ));
+        }
+        let (_, revision) = try!(parse_name_with_rev(
+            &try!(artifact::artifact_signer(&archive.path)),
+        ));
+        try!(self.fetch_origin_key(router, &origin, &revision, download_path));
+        try!(artifact::verify(&archive.path, download_path));
+
+        if try!(archive.target()) != *target {
+            return Err(Error::RemoteTargetMismatch(ident.to_string(), target.to_string()));
+        }
+        let mut package = try!(OriginPackageCreate::from_archive(&mut archive));
+        package.set_origin_id(origin.get_id());
+        package.set_owner_id(origin.get_owner_id());
+        try!(depot.store_archive(ident, target, &archive.path));
+        let package = try!(router.route::<OriginPackageCreate, OriginPackage>(&package));
+        info!("Mirrored {} from upstream", ident);
+        Ok(package)
+    }
+
+    /// Returns the public key of an origin revision, fetching it from the upstream Depot if we
+    /// don't have it and the origin is configured for mirroring. The key is also written to
+    /// `key_path`, for verifying artifacts with.
+    fn fetch_origin_key<R: Route>(
+        &self,
+        router: &mut R,
+        origin: &Origin,
+        revision: &str,
+        key_path: &Path,
+    ) -> Result<OriginPublicKey> {
+        let mut request = OriginPublicKeyGet::new();
+        request.set_origin(origin.get_name().to_string());
+        request.set_revision(revision.to_string());
+        match router.route::<OriginPublicKeyGet, OriginPublicKey>(&request) {
+            Ok(key) => {
+                try!(fs::create_dir_all(key_path));
+                let mut file = try!(File::create(key_path.join(format!(
+                    "{}-{}.pub",
+                    key.get_name(),
+                    key.get_revision()
+                ))));
+                try!(file.write_all(key.get_body()));
+                return Ok(key);
+            }
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => (),
+            Err(err) => return Err(Error::ProtocolNetError(err)),
+        }
+        // Whoever controls upstream could otherwise sign packages of any origin with a key of
+        // their own making
+        if !self.trusted_origins.iter().any(|o| o == origin.get_name()) {
+            return Err(Error::RemoteOriginKeyNotTrusted(
+                format!("{}-{}", origin.get_name(), revision),
+            ));
+        }
+
+        let file_path = try!(self.client.fetch_origin_key(
+            origin.get_name(),
+            revision,
+            key_path,
+            None::<NoProgress>,
+        ));
+        let mut body = String::new();
+        try!(try!(File::open(&file_path)).read_to_string(&mut body));
+        match try!(SigKeyPair::parse_key_str(&body)) {
+            (PairType::Public, _, _) => (),
+            _ => return Err(Error::RemoteOriginKeyNotFound(origin.get_name().to_string())),
+        }
+
+        let mut request = OriginPublicKeyCreate::new();
+        request.set_origin_id(origin.get_id());
+        request.set_name(origin.get_name().to_string());
+        request.set_revision(revision.to_string());
+        request.set_body(body.into_bytes());
+        request.set_owner_id(0);
+        let key = try!(router.route::<OriginPublicKeyCreate, OriginPublicKey>(&request));
+        warn!(
+            "Imported new public origin key revision {}-{} from upstream",
+            origin.get_name(),
+            revision
+        );
+        Ok(key)
+    }
+
+    /// Returns the origin with the given name. An origin which only exists upstream is created
+    /// if the Depot is configured to create origins, and is otherwise an error.
+    fn ensure_origin<R: Route>(&self, router: &mut R, name: &str) -> Result<Origin> {
+        let mut request = OriginGet::new();
+        request.set_name(name.to_string());
+        match router.route::<OriginGet, Origin>(&request) {
+            Ok(origin) => return Ok(origin),
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => (),
+            Err(err) => return Err(Error::ProtocolNetError(err)),
+        }
+        if !self.create_origins {
+            return Err(Error::RemoteOriginNotCreated(name.to_string()));
+        }
+        info!("Creating origin {} to mirror it from upstream", name);
+        let mut request = OriginCreate::new();
+        request.set_name(name.to_string());
+        request.set_owner_id(0);
+        request.set_owner_name(UPSTREAM_OWNER.to_string());
+        Ok(try!(router.route::<OriginCreate, Origin>(&request)))
+    }
+
+    /// Promotes a package to a channel of its origin, creating the channel if needed.
+    fn promote<R: Route>(&self, router: &mut R, package: &OriginPackage, name: &str) -> Result<()> {
+        let mut request = OriginChannelPackageGet::new();
+        request.set_name(name.to_string());
+        request.set_ident(package.get_ident().clone());
+        request.set_target(package.get_target().to_string());
+        match router.route::<OriginChannelPackageGet, OriginPackage>(&request) {
+            Ok(_) => return Ok(()),
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => (),
+            Err(err) => return Err(Error::ProtocolNetError(err)),
+        }
+
+        let mut request = OriginChannelGet::new();
+        request.set_origin_name(package.get_ident().get_origin().to_string());
+        request.set_name(name.to_string());
+        let channel = match router.route::<OriginChannelGet, OriginChannel>(&request) {
+            Ok(channel) => channel,
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => {
+                let mut request = OriginChannelCreate::new();
+                request.set_origin_id(package.get_origin_id());
+                request.set_origin_name(package.get_ident().get_origin().to_string());
+                request.set_name(name.to_string());
+                request.set_owner_id(0);
+                try!(router.route::<OriginChannelCreate, OriginChannel>(&request))
+            }
+            Err(err) => return Err(Error::ProtocolNetError(err)),
+        };
+
+        let mut request = OriginPackagePromote::new();
+        request.set_channel_id(channel.get_id());
+        request.set_package_id(package.get_id());
+        request.set_ident(package.get_ident().clone());
+        try!(router.route::<OriginPackagePromote, NetOk>(&request));
+        Ok(())
+    }
+}
+
+/// Mirrors the configured origin channels from the upstream Depot, over and over again, in a
+/// thread of its own. Nothing is started if there is no upstream Depot or nothing to mirror.
+pub fn run_sync(config: Config) -> Result<Option<JoinHandle<()>>> {
+    let url = match config.upstream.url {
+        Some(ref url) if !config.upstream.mirror.is_empty() => url.clone(),
+        _ => return Ok(None),
+    };
+    let depot = try!(DepotUtil::new(config.clone()));
+    let upstream = try!(Upstream::new(
+        &url,
+        Path::new(&config.path),
+        config.upstream.create_origins,
+        config.upstream.mirrored_origins(),
+    ));
+    let interval = Duration::from_secs(config.upstream.sync_interval_sec);
+    let handle = try!(
+        thread::Builder::new()
+            .name("upstream-sync".to_string())
+            .spawn(move || loop {
+                let mut conn = match Broker::connect() {
+                    Ok(conn) => conn,
+                    Err(e) => {
+                        warn!(
+                            "Unable to connect to mirror from upstream, retrying in {}s, {}",
+                            CONNECT_RETRY_SEC,
+                            e
+                        );
+                        thread::sleep(Duration::from_secs(CONNECT_RETRY_SEC));
+                        continue;
+                    }
+                };
+                for mirror in config.upstream.mirror.iter() {
+                    match upstream.sync_channel(&mut conn, &depot, mirror) {
+                        Ok(count) => {
+                            info!(
+                                "Mirrored {} packages of {}/{} from upstream",
+                                count,
+                                mirror.origin,
+                                mirror.channel
+                            )
+                        }
+                        Err(e) => {
+                            warn!(
+                                "Unable to mirror {}/{} from upstream, {}",
+                                mirror.origin,
+                                mirror.channel,
+                                e
+                            )
+                        }
+                    }
+                }
+                thread::sleep(interval);
+            })
+    );
+    Ok(Some(handle))
+}
+
+/// Downloads from the upstream Depot don't report their progress anywhere.
+struct NoProgress;
+
+impl Write for NoProgress {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl DisplayProgress for NoProgress {
+    fn size(&mut self, _size: u64) {}
+
+    fn finish(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::Read;
+
+    use protocol::net;
+    use tempdir::TempDir;
+
+    use server::TestableBroker;
+    use super::*;
+
+    fn upstream(create_origins: bool) -> Upstream {
+        Upstream::new(
+            "http://127.0.0.1:1/v1/depot",
+            Path::new("/tmp/depot"),
+            create_origins,
+            vec!["core".to_string()],
+        ).unwrap()
+    }
+
+    fn origin(id: u64, name: &str) -> Origin {
+        let mut origin = Origin::new();
+        origin.set_id(id);
+        origin.set_name(name.to_string());
+        origin
+    }
+
+    fn not_found() -> net::NetError {
+        net::err(ErrCode::ENTITY_NOT_FOUND, "not found")
+    }
+
+    fn package() -> OriginPackage {
+        let mut ident = OriginPackageIdent::new();
+        ident.set_origin("core".to_string());
+        ident.set_name("redis".to_string());
+        ident.set_version("3.2.4".to_string());
+        ident.set_release("20170514150022".to_string());
+        let mut package = OriginPackage::new();
+        package.set_id(42);
+        package.set_origin_id(1);
+        package.set_ident(ident);
+        package.set_target("x86_64-linux".to_string());
+        package
+    }
+
+    #[test]
+    fn ensure_origin_finds_an_existing_origin() {
+        let mut broker = TestableBroker::default();
+        broker.setup::<OriginGet, Origin>(&origin(1, "core"));
+        let found = upstream(false).ensure_origin(&mut broker, "core").unwrap();
+        assert_eq!(found.get_id(), 1);
+        assert!(broker.routed_messages().get::<OriginCreate>().is_err());
+    }
+
+    #[test]
+    fn ensure_origin_does_not_create_origins_unless_configured() {
+        let mut broker = TestableBroker::default();
+        broker.setup_error::<OriginGet>(not_found());
+        match upstream(false).ensure_origin(&mut broker, "core") {
+            Err(Error::RemoteOriginNotCreated(ref name)) => assert_eq!(name, "core"),
+            other => panic!("Unexpected result: {:?}", other.map(|o| o.get_id())),
+        }
+        assert!(broker.routed_messages().get::<OriginCreate>().is_err());
+    }
+
+    #[test]
+    fn ensure_origin_creates_origins_when_configured() {
+        let mut broker = TestableBroker::default();
+        broker.setup_error::<OriginGet>(not_found());
+        broker.setup::<OriginCreate, Origin>(&origin(2, "core"));
+        let created = upstream(true).ensure_origin(&mut broker, "core").unwrap();
+        assert_eq!(created.get_id(), 2);
+        let request = broker.routed_messages().get::<OriginCreate>().unwrap();
+        assert_eq!(request.get_name(), "core");
+        assert_eq!(request.get_owner_name(), UPSTREAM_OWNER);
+    }
+
+    #[test]
+    fn promote_skips_a_package_already_in_the_channel() {
+        let mut broker = TestableBroker::default();
+        broker.setup::<OriginChannelPackageGet, OriginPackage>(&package());
+        upstream(false)
+            .promote(&mut broker, &package(), "stable")
+            .unwrap();
+        assert!(broker.routed_messages().get::<OriginPackagePromote>().is_err());
+    }
+
+    #[test]
+    fn promote_creates_a_missing_channel() {
+        let mut broker = TestableBroker::default();
+        let mut channel = OriginChannel::new();
+        channel.set_id(7);
+        broker.setup_error::<OriginChannelPackageGet>(not_found());
+        broker.setup_error::<OriginChannelGet>(not_found());
+        broker.setup::<OriginChannelCreate, OriginChannel>(&channel);
+        broker.setup::<OriginPackagePromote, NetOk>(&NetOk::new());
+        upstream(false)
+            .promote(&mut broker, &package(), "stable")
+            .unwrap();
+        let messages = broker.routed_messages();
+        let create = messages.get::<OriginChannelCreate>().unwrap();
+        assert_eq!(create.get_origin_name(), "core");
+        assert_eq!(create.get_name(), "stable");
+        let promote = messages.get::<OriginPackagePromote>().unwrap();
+        assert_eq!(promote.get_channel_id(), 7);
+        assert_eq!(promote.get_package_id(), 42);
+    }
+
+    #[test]
+    fn fetch_origin_key_writes_a_key_we_have() {
+        let dir = TempDir::new("upstream").unwrap();
+        let mut key = OriginPublicKey::new();
+        key.set_name("core".to_string());
+        key.set_revision("20160810182414".to_string());
+        key.set_body(Vec::from("SIG-PUB-1"));
+        let mut broker = TestableBroker::default();
+        broker.setup::<OriginPublicKeyGet, OriginPublicKey>(&key);
+
+        let fetched = upstream(false)
+            .fetch_origin_key(&mut broker, &origin(1, "core"), "20160810182414", dir.path())
+            .unwrap();
+        assert_eq!(fetched.get_revision(), "20160810182414");
+        let mut body = String::new();
+        File::open(dir.path().join("core-20160810182414.pub"))
+            .unwrap()
+            .read_to_string(&mut body)
+            .unwrap();
+        assert_eq!(body, "SIG-PUB-1");
+        assert!(broker.routed_messages().get::<OriginPublicKeyCreate>().is_err());
+    }
+
+    #[test]
+    fn fetch_origin_key_refuses_keys_of_origins_not_mirrored() {
+        let dir = TempDir::new("upstream").unwrap();
+        let mut broker = TestableBroker::default();
+        broker.setup_error::<OriginPublicKeyGet>(not_found());
+
+        match upstream(false).fetch_origin_key(
+            &mut broker,
+            &origin(2, "acme"),
+            "20160810182414",
+            dir.path(),
+        ) {
+            Err(Error::RemoteOriginKeyNotTrusted(ref key)) => {
+                assert_eq!(key, "acme-20160810182414")
+            }
+            other => panic!("Unexpected result: {:?}", other.map(|k| k.get_revision().to_string())),
+        }
+        assert!(broker.routed_messages().get::<OriginPublicKeyCreate>().is_err());
+    }
+}
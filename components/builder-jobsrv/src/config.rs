@@ -99,6 +99,10 @@ pub struct NetCfg {
     pub log_ingestion_listen: IpAddr,
     /// Worker Log Ingestion socket's port
     pub log_ingestion_port: u16,
+    /// Log Publisher socket's listening address
+    pub log_publish_listen: IpAddr,
+    /// Log Publisher socket's port
+    pub log_publish_port: u16,
 }
 
 impl NetCfg {
@@ -125,6 +129,14 @@ impl NetCfg {
             self.log_ingestion_port
         )
     }
+
+    pub fn log_publish_addr(&self) -> String {
+        format!(
+            "tcp://{}:{}",
+            self.log_publish_listen,
+            self.log_publish_port
+        )
+    }
 }
 
 impl Default for NetCfg {
@@ -136,6 +148,8 @@ impl Default for NetCfg {
             worker_heartbeat_port: 5567,
             log_ingestion_listen: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
             log_ingestion_port: 5568,
+            log_publish_listen: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+            log_publish_port: 5569,
         }
     }
 }
@@ -194,6 +208,8 @@ mod tests {
         worker_heartbeat_port = 9000
         log_ingestion_listen = "2.2.2.2"
         log_ingestion_port = 9999
+        log_publish_listen = "3.3.3.3"
+        log_publish_port = 7777
 
         [archive]
         backend = "s3"
@@ -232,6 +248,7 @@ mod tests {
         assert_eq!(config.net.worker_command_port, 9000);
         assert_eq!(config.net.worker_heartbeat_port, 9000);
         assert_eq!(config.net.log_ingestion_port, 9999);
+        assert_eq!(config.net.log_publish_addr(), "tcp://3.3.3.3:7777");
         assert_eq!(config.shards, vec![0]);
         assert_eq!(config.worker_threads, 1);
         assert_eq!(config.datastore.port, 9000);
@@ -339,6 +339,206 @@ impl DataStore {
                            WHERE id = p_job_id;
                          $$"#,
         )?;
+
+        // Cancel a job. A job that has not been handed to a worker yet is canceled outright,
+        // while one that is already out with a worker is marked as 'CancelPending' until the
+        // worker reports back. Jobs in any other state are left alone and no row is returned.
+        //
+        // Doing this in a single UPDATE keeps us from racing `pending_jobs_v1`, which could
+        // otherwise dispatch the job between us reading and writing its state.
+        migrator.migrate(
+            "jobsrv-2",
+            r#"CREATE OR REPLACE FUNCTION cancel_job_v1(p_job_id bigint)
+                         RETURNS SETOF jobs
+                         LANGUAGE SQL VOLATILE AS $$
+                           UPDATE jobs
+                           SET job_state = CASE WHEN job_state = 'Pending' THEN 'Canceled' ELSE 'CancelPending' END,
+                               scheduler_sync = false,
+                               updated_at = now()
+                           WHERE id = p_job_id
+                           AND job_state IN ('Pending', 'Dispatched', 'Processing')
+                           RETURNING *;
+                         $$"#,
+        )?;
+        migrator.migrate(
+            "jobsrv-2",
+            r#"CREATE OR REPLACE FUNCTION cancel_pending_jobs_v1()
+                         RETURNS SETOF jobs
+                         LANGUAGE SQL STABLE AS $$
+                           SELECT * FROM jobs WHERE job_state = 'CancelPending';
+                         $$"#,
+        )?;
+
+        // Like `reset_jobs_v1`, but also finishes off any cancellations that were in flight;
+        // after a restart we no longer know which worker had the job, so there is nobody left
+        // to wait on.
+        migrator.migrate(
+            "jobsrv-2",
+            r#"CREATE OR REPLACE FUNCTION reset_jobs_v2() RETURNS void AS $$
+                                BEGIN
+                                    UPDATE jobs SET job_state='Pending', scheduler_sync=false, updated_at=now() WHERE job_state='Dispatched';
+                                    UPDATE jobs SET job_state='Canceled', scheduler_sync=false, updated_at=now() WHERE job_state='CancelPending';
+                                END
+                                $$ LANGUAGE plpgsql VOLATILE"#,
+        )?;
+
+        // Jobs carry their project's build timeout along to the worker; NULL means the worker's
+        // default applies
+        migrator.migrate(
+            "jobsrv-2",
+            r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS project_build_timeout bigint DEFAULT NULL"#,
+        )?;
+        migrator.migrate("jobsrv-2",
+                             r#"CREATE OR REPLACE FUNCTION insert_job_v2 (
+                                owner_id bigint,
+                                project_id bigint,
+                                project_name text,
+                                project_owner_id bigint,
+                                project_plan_path text,
+                                vcs text,
+                                vcs_arguments text[],
+                                project_build_timeout bigint
+                                ) RETURNS SETOF jobs AS $$
+                                    BEGIN
+                                        RETURN QUERY INSERT INTO jobs (owner_id, job_state, project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, project_build_timeout)
+                                            VALUES (owner_id, 'Pending', project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, project_build_timeout)
+                                            RETURNING *;
+                                        RETURN;
+                                    END
+                                $$ LANGUAGE plpgsql VOLATILE
+                                "#)?;
+
+        // The git ref a job was asked to build, and the commit it resolved to on the worker
+        migrator.migrate(
+            "jobsrv-2",
+            r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS vcs_ref text DEFAULT NULL"#,
+        )?;
+        migrator.migrate(
+            "jobsrv-2",
+            r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS vcs_sha text DEFAULT NULL"#,
+        )?;
+        migrator.migrate("jobsrv-2",
+                             r#"CREATE OR REPLACE FUNCTION insert_job_v3 (
+                                owner_id bigint,
+                                project_id bigint,
+                                project_name text,
+                                project_owner_id bigint,
+                                project_plan_path text,
+                                vcs text,
+                                vcs_arguments text[],
+                                project_build_timeout bigint,
+                                vcs_ref text
+                                ) RETURNS SETOF jobs AS $$
+                                    BEGIN
+                                        RETURN QUERY INSERT INTO jobs (owner_id, job_state, project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, project_build_timeout, vcs_ref)
+                                            VALUES (owner_id, 'Pending', project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, project_build_timeout, vcs_ref)
+                                            RETURNING *;
+                                        RETURN;
+                                    END
+                                $$ LANGUAGE plpgsql VOLATILE
+                                "#)?;
+        migrator.migrate(
+            "jobsrv-2",
+            r#"CREATE OR REPLACE FUNCTION update_job_v3(
+                           p_job_id bigint,
+                           p_state text,
+                           p_build_started_at timestamptz,
+                           p_build_finished_at timestamptz,
+                           p_package_ident text,
+                           p_err_code int,
+                           p_err_msg text,
+                           p_vcs_sha text)
+                         RETURNS VOID
+                         LANGUAGE SQL VOLATILE AS $$
+                           UPDATE jobs
+                           SET job_state = p_state,
+                               scheduler_sync = false,
+                               updated_at = now(),
+                               build_started_at = p_build_started_at,
+                               build_finished_at = p_build_finished_at,
+                               package_ident = p_package_ident,
+                               net_error_code = p_err_code,
+                               net_error_msg = p_err_msg,
+                               vcs_sha = p_vcs_sha
+                           WHERE id = p_job_id;
+                         $$"#,
+        )?;
+
+        // Like `update_job_v3`, but a finished job is never changed again, and a job waiting on
+        // its worker to cancel it can only move to a finished state. Otherwise a status the
+        // worker sent before it saw the cancel could put the job back to 'Processing' and the
+        // cancel would be lost, or a late 'Complete' could overwrite 'Canceled'.
+        migrator.migrate(
+            "jobsrv-2",
+            r#"CREATE OR REPLACE FUNCTION update_job_v4(
+                           p_job_id bigint,
+                           p_state text,
+                           p_build_started_at timestamptz,
+                           p_build_finished_at timestamptz,
+                           p_package_ident text,
+                           p_err_code int,
+                           p_err_msg text,
+                           p_vcs_sha text)
+                         RETURNS VOID
+                         LANGUAGE SQL VOLATILE AS $$
+                           UPDATE jobs
+                           SET job_state = p_state,
+                               scheduler_sync = false,
+                               updated_at = now(),
+                               build_started_at = p_build_started_at,
+                               build_finished_at = p_build_finished_at,
+                               package_ident = p_package_ident,
+                               net_error_code = p_err_code,
+                               net_error_msg = p_err_msg,
+                               vcs_sha = p_vcs_sha
+                           WHERE id = p_job_id
+                           AND job_state NOT IN ('Complete', 'Failed', 'Rejected', 'Canceled')
+                           AND (job_state != 'CancelPending'
+                                OR p_state IN ('CancelPending', 'Complete', 'Failed', 'Rejected', 'Canceled'));
+                         $$"#,
+        )?;
+
+        // The worker a job was handed to, so that after a restart we still know where to send
+        // a cancel for a job that is out being built
+        migrator.migrate(
+            "jobsrv-2",
+            r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS worker text DEFAULT NULL"#,
+        )?;
+        migrator.migrate(
+            "jobsrv-2",
+            r#"CREATE OR REPLACE FUNCTION set_job_worker_v1(p_job_id bigint, p_worker text)
+                         RETURNS VOID
+                         LANGUAGE SQL VOLATILE AS $$
+                           UPDATE jobs
+                           SET worker = p_worker,
+                               updated_at = now()
+                           WHERE id = p_job_id;
+                         $$"#,
+        )?;
+        migrator.migrate(
+            "jobsrv-2",
+            r#"CREATE OR REPLACE FUNCTION dispatched_jobs_v1()
+                         RETURNS SETOF jobs
+                         LANGUAGE SQL STABLE AS $$
+                           SELECT * FROM jobs
+                           WHERE job_state IN ('Dispatched', 'Processing', 'CancelPending')
+                           AND worker IS NOT NULL;
+                         $$"#,
+        )?;
+
+        // Like `reset_jobs_v2`, but a cancel is only finished off here if we never learned which
+        // worker had the job. Otherwise it stays 'CancelPending' so the cancel can be sent again.
+        // Dispatched jobs go back to 'Pending' and forget their worker, since it may never have
+        // received them.
+        migrator.migrate(
+            "jobsrv-2",
+            r#"CREATE OR REPLACE FUNCTION reset_jobs_v3() RETURNS void AS $$
+                                BEGIN
+                                    UPDATE jobs SET job_state='Pending', worker=NULL, scheduler_sync=false, updated_at=now() WHERE job_state='Dispatched';
+                                    UPDATE jobs SET job_state='Canceled', scheduler_sync=false, updated_at=now() WHERE job_state='CancelPending' AND worker IS NULL;
+                                END
+                                $$ LANGUAGE plpgsql VOLATILE"#,
+        )?;
         migrator.finish()?;
 
         self.async.register("sync_jobs".to_string(), sync_jobs);
@@ -365,8 +565,20 @@ impl DataStore {
         if job.get_project().get_vcs_type() == "git" {
             let project = job.get_project();
 
+            let build_timeout = if project.get_build_timeout() > 0 {
+                Some(project.get_build_timeout() as i64)
+            } else {
+                None
+            };
+
+            let vcs_ref = if job.has_vcs_ref() {
+                Some(job.get_vcs_ref())
+            } else {
+                None
+            };
+
             let rows = conn.query(
-                "SELECT * FROM insert_job_v1($1, $2, $3, $4, $5, $6, $7)",
+                "SELECT * FROM insert_job_v3($1, $2, $3, $4, $5, $6, $7, $8, $9)",
                 &[
                     &(job.get_owner_id() as i64),
                     &(project.get_id() as i64),
@@ -375,6 +587,8 @@ impl DataStore {
                     &project.get_plan_path(),
                     &project.get_vcs_type(),
                     &vec![project.get_vcs_data()],
+                    &build_timeout,
+                    &vcs_ref,
                 ],
             ).map_err(Error::JobCreate)?;
             let job = row_to_job(&rows.get(0))?;
@@ -449,7 +663,8 @@ impl DataStore {
         Ok(jobs)
     }
 
-    /// Reset any Dispatched jobs back to Pending state
+    /// Reset any Dispatched jobs back to Pending state and mark any jobs waiting on a
+    /// cancellation as Canceled, unless we know which worker has them
     /// This is used for recovery scenario
     ///
     /// # Errors
@@ -457,16 +672,94 @@ impl DataStore {
     /// * If the dispatched jobs cannot be selected from the database
     pub fn reset_jobs(&self) -> Result<()> {
         let conn = self.pool.get_shard(0)?;
-        conn.query("SELECT reset_jobs_v1()", &[]).map_err(
+        conn.query("SELECT reset_jobs_v3()", &[]).map_err(
             Error::JobReset,
         )?;
         Ok(())
     }
 
+    /// Record the worker a job was dispatched to.
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the job cannot be updated in the database
+    pub fn set_job_worker(&self, job_id: u64, worker: &str) -> Result<()> {
+        let conn = self.pool.get_shard(0)?;
+        conn.execute(
+            "SELECT set_job_worker_v1($1, $2)",
+            &[&(job_id as i64), &worker],
+        ).map_err(Error::JobSetWorker)?;
+        Ok(())
+    }
+
+    /// Get the id and worker of every job that is out with a worker. This is used to rebuild
+    /// the worker manager's view of dispatched jobs after a restart.
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the jobs cannot be selected from the database
+    pub fn dispatched_jobs(&self) -> Result<Vec<(u64, String)>> {
+        let mut jobs = Vec::new();
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query("SELECT * FROM dispatched_jobs_v1()", &[])
+            .map_err(Error::JobGet)?;
+        for row in rows {
+            let id: i64 = row.get("id");
+            let worker: String = row.get("worker");
+            jobs.push((id as u64, worker));
+        }
+        Ok(jobs)
+    }
+
+    /// Cancel a job. Pending jobs move straight to Canceled, while Dispatched and Processing
+    /// jobs move to CancelPending until their worker confirms. If the job does not exist, or is
+    /// already finished, we'll get a None result.
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the job cannot be updated in the database
+    pub fn cancel_job(&self, job_cancel: &jobsrv::JobCancel) -> Result<Option<jobsrv::Job>> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query(
+            "SELECT * FROM cancel_job_v1($1)",
+            &[&(job_cancel.get_id() as i64)],
+        ).map_err(Error::JobCancel)?;
+        for row in rows {
+            let job = row_to_job(&row)?;
+            return Ok(Some(job));
+        }
+        Ok(None)
+    }
+
+    /// Get the jobs which are waiting on a worker to cancel them.
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the jobs cannot be selected from the database
+    /// * If the row returned cannot be translated into a Job
+    pub fn cancel_pending_jobs(&self) -> Result<Vec<jobsrv::Job>> {
+        let mut jobs = Vec::new();
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query("SELECT * FROM cancel_pending_jobs_v1()", &[])
+            .map_err(Error::JobCancel)?;
+        for row in rows {
+            let job = row_to_job(&row)?;
+            jobs.push(job);
+        }
+        Ok(jobs)
+    }
+
     /// Updates a job. Currently, this entails updating the state,
     /// build start and stop times, and recording the identifier of
     /// the package the job produced, if any.
     ///
+    /// Jobs that have finished are left untouched, and jobs waiting on a cancel only accept a
+    /// finished state.
+    ///
     /// # Errors
     ///
     /// * If a connection cannot be gotten from the pool
@@ -481,6 +774,8 @@ impl DataStore {
             jobsrv::JobState::Complete => "Complete",
             jobsrv::JobState::Rejected => "Rejected",
             jobsrv::JobState::Failed => "Failed",
+            jobsrv::JobState::CancelPending => "CancelPending",
+            jobsrv::JobState::Canceled => "Canceled",
         };
 
         // Note: the following fields may all be NULL. As currently
@@ -504,6 +799,12 @@ impl DataStore {
             false => None,
         };
 
+        let vcs_sha = if job.has_vcs_sha() {
+            Some(job.get_vcs_sha())
+        } else {
+            None
+        };
+
         let (err_code, err_msg) = if job.has_error() {
             (
                 Some(job.get_error().get_code() as i32),
@@ -514,7 +815,7 @@ impl DataStore {
         };
 
         conn.execute(
-            "SELECT update_job_v2($1, $2, $3, $4, $5, $6, $7)",
+            "SELECT update_job_v4($1, $2, $3, $4, $5, $6, $7, $8)",
             &[
                 &job_id,
                 &job_state,
@@ -523,6 +824,7 @@ impl DataStore {
                 &ident,
                 &err_code,
                 &err_msg,
+                &vcs_sha,
             ],
         ).map_err(Error::JobSetState)?;
 
@@ -562,6 +864,8 @@ fn row_to_job(row: &postgres::rows::Row) -> Result<jobsrv::Job> {
         "Complete" => jobsrv::JobState::Complete,
         "Rejected" => jobsrv::JobState::Rejected,
         "Failed" => jobsrv::JobState::Failed,
+        "CancelPending" => jobsrv::JobState::CancelPending,
+        "Canceled" => jobsrv::JobState::Canceled,
         _ => return Err(Error::UnknownJobState),
     };
     job.set_state(job_state);
@@ -584,6 +888,13 @@ fn row_to_job(row: &postgres::rows::Row) -> Result<jobsrv::Job> {
         job.set_package_ident(ident);
     }
 
+    if let Some(Ok(vcs_ref)) = row.get_opt::<&str, String>("vcs_ref") {
+        job.set_vcs_ref(vcs_ref);
+    }
+    if let Some(Ok(vcs_sha)) = row.get_opt::<&str, String>("vcs_sha") {
+        job.set_vcs_sha(vcs_sha);
+    }
+
     let mut project = originsrv::OriginProject::new();
     let project_id: i64 = row.get("project_id");
     project.set_id(project_id as u64);
@@ -604,6 +915,9 @@ fn row_to_job(row: &postgres::rows::Row) -> Result<jobsrv::Job> {
     let project_owner_id: i64 = row.get("project_owner_id");
     project.set_owner_id(project_owner_id as u64);
     project.set_plan_path(row.get("project_plan_path"));
+    if let Some(Ok(timeout)) = row.get_opt::<&str, i64>("project_build_timeout") {
+        project.set_build_timeout(timeout as u64);
+    }
 
     let rvcs: String = row.get("vcs");
     match rvcs.as_ref() {
@@ -39,6 +39,7 @@ pub enum Error {
     HabitatCore(hab_core::Error),
     InvalidUrl,
     IO(io::Error),
+    JobCancel(postgres::error::Error),
     JobCreate(postgres::error::Error),
     JobGet(postgres::error::Error),
     JobLogArchive(u64, aws_sdk_rust::aws::errors::s3::S3Error),
@@ -48,6 +49,7 @@ pub enum Error {
     JobReset(postgres::error::Error),
     JobSetLogUrl(postgres::error::Error),
     JobSetState(postgres::error::Error),
+    JobSetWorker(postgres::error::Error),
     LogDirDoesNotExist(PathBuf, io::Error),
     LogDirIsNotDir(PathBuf),
     LogDirNotWritable(PathBuf),
@@ -83,6 +85,7 @@ impl fmt::Display for Error {
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::InvalidUrl => format!("Bad URL!"),
             Error::IO(ref e) => format!("{}", e),
+            Error::JobCancel(ref e) => format!("Database error canceling a job, {}", e),
             Error::JobCreate(ref e) => format!("Database error creating a new job, {}", e),
             Error::JobGet(ref e) => format!("Database error getting job data, {}", e),
             Error::JobLogArchive(job_id, ref e) => {
@@ -98,6 +101,7 @@ impl fmt::Display for Error {
             Error::JobReset(ref e) => format!("Database error reseting jobs, {}", e),
             Error::JobSetLogUrl(ref e) => format!("Database error setting job log URL, {}", e),
             Error::JobSetState(ref e) => format!("Database error setting job state, {}", e),
+            Error::JobSetWorker(ref e) => format!("Database error setting job worker, {}", e),
             Error::LogDirDoesNotExist(ref path, ref e) => {
                 format!("Build log directory {:?} doesn't exist!: {:?}", path, e)
             }
@@ -133,6 +137,7 @@ impl error::Error for Error {
             Error::HabitatCore(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
             Error::InvalidUrl => "Bad Url!",
+            Error::JobCancel(ref err) => err.description(),
             Error::JobCreate(ref err) => err.description(),
             Error::JobGet(ref err) => err.description(),
             Error::JobLogArchive(_, ref err) => err.description(),
@@ -142,6 +147,7 @@ impl error::Error for Error {
             Error::JobReset(ref err) => err.description(),
             Error::JobSetLogUrl(ref err) => err.description(),
             Error::JobSetState(ref err) => err.description(),
+            Error::JobSetWorker(ref err) => err.description(),
             Error::LogDirDoesNotExist(_, ref err) => err.description(),
             Error::LogDirIsNotDir(_) => "Build log directory is not a directory",
             Error::LogDirNotWritable(_) => "Build log directory is not writable",
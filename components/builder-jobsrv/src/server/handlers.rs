@@ -64,6 +64,49 @@ pub fn job_get(req: &mut Envelope, sock: &mut zmq::Socket, state: &mut ServerSta
     Ok(())
 }
 
+pub fn job_cancel(
+    req: &mut Envelope,
+    sock: &mut zmq::Socket,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg: proto::JobCancel = try!(req.parse_msg());
+    match state.datastore().cancel_job(&msg) {
+        Ok(Some(ref job)) => {
+            debug!(
+                "Job canceled: id={} requestor_id={} state={:?}",
+                job.get_id(),
+                msg.get_requestor_id(),
+                job.get_state()
+            );
+            // Jobs which are out with a worker need the worker manager to pass the cancel on
+            if job.get_state() == proto::JobState::CancelPending {
+                try!(state.worker_mgr().notify_cancel());
+            }
+            try!(req.reply_complete(sock, job));
+        }
+        Ok(None) => {
+            // Either there is no such job or it has already finished
+            let mut get = proto::JobGet::new();
+            get.set_id(msg.get_id());
+            let err = match state.datastore().get_job(&get) {
+                Ok(Some(_)) => net::err(ErrCode::ENTITY_CONFLICT, "jb:job-cancel:1"),
+                Ok(None) => net::err(ErrCode::ENTITY_NOT_FOUND, "jb:job-cancel:2"),
+                Err(e) => {
+                    error!("datastore error, err={:?}", e);
+                    net::err(ErrCode::DATA_STORE, "jb:job-cancel:3")
+                }
+            };
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::DATA_STORE, "jb:job-cancel:4");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
 pub fn project_jobs_get(
     req: &mut Envelope,
     sock: &mut zmq::Socket,
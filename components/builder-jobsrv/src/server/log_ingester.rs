@@ -16,7 +16,7 @@ use config::Config;
 use data_store::DataStore;
 use error::Result;
 use hab_net::server::ZMQ_CONTEXT;
-use protobuf::parse_from_bytes;
+use protobuf::{parse_from_bytes, Message};
 use protocol::jobsrv::{JobLogComplete, JobLogChunk};
 use server::log_archiver::{self, LogArchiver};
 use server::log_directory::LogDirectory;
@@ -32,10 +32,18 @@ const LOG_LINE: &'static str = "L";
 /// ZMQ protocol frame to indicate a log has finished
 const LOG_COMPLETE: &'static str = "C";
 
+/// Subscription topic for the log of the given job on the publisher socket. The trailing
+/// separator keeps a subscription to one job from also matching jobs whose ids share its prefix.
+fn log_topic(job_id: u64) -> String {
+    format!("{}:", job_id)
+}
+
 /// Listens for log messages from builders and consolidates output for
 /// both streaming to clients and long-term storage.
 pub struct LogIngester {
     intake_sock: zmq::Socket,
+    /// Re-publishes every log message, topic'd by job, to clients following a log live
+    publish_sock: zmq::Socket,
     config: Arc<RwLock<Config>>,
     msg: zmq::Message,
     log_dir: LogDirectory,
@@ -51,11 +59,13 @@ impl LogIngester {
     ) -> Result<Self> {
         let intake_sock = (**ZMQ_CONTEXT).as_mut().socket(zmq::ROUTER)?;
         intake_sock.set_router_mandatory(true)?;
+        let publish_sock = (**ZMQ_CONTEXT).as_mut().socket(zmq::PUB)?;
         let msg = zmq::Message::new()?;
         let archiver = log_archiver::from_config(config.read().unwrap().archive.clone()).unwrap();
 
         Ok(LogIngester {
             intake_sock: intake_sock,
+            publish_sock: publish_sock,
             config: config,
             msg: msg,
             log_dir: log_dir,
@@ -89,6 +99,9 @@ impl LogIngester {
             let addr = cfg.net.log_ingestion_addr();
             println!("Listening for log data on {}", addr);
             self.intake_sock.bind(&addr)?;
+            let addr = cfg.net.log_publish_addr();
+            println!("Publishing log data on {}", addr);
+            self.publish_sock.bind(&addr)?;
         }
 
         rz.send(()).unwrap();
@@ -126,6 +139,11 @@ impl LogIngester {
                                     warn!("Could not open {:?} for appending! {:?}", log_file, e);
                                 }
                             }
+                            // Publish only after the line is on disk, so a client that reads
+                            // the log file and then follows the publisher can't miss a line.
+                            if let Err(e) = self.publish(chunk.get_job_id(), LOG_LINE, &chunk) {
+                                warn!("Error publishing log line: {}", e);
+                            }
                         }
                         Err(e) => {
                             warn!("ERROR parsing JobLogChunk: {:?}", e);
@@ -141,6 +159,10 @@ impl LogIngester {
                                 // to remediate as appropriate.
                                 warn!("Error completing log: {}", e);
                             }
+                            let job_id = complete.get_job_id();
+                            if let Err(e) = self.publish(job_id, LOG_COMPLETE, &complete) {
+                                warn!("Error publishing log completion: {}", e);
+                            }
                         }
                         Err(e) => {
                             warn!("ERROR parsing JobLogComplete: {:?}", e);
@@ -154,6 +176,19 @@ impl LogIngester {
         }
     }
 
+    /// Send a log message to subscribers of the job's log. Messages are 3 frames: the job's
+    /// topic, the same single-character code used on the intake socket, and the protobuf
+    /// message. Subscribers that can't keep up have messages dropped by the socket.
+    fn publish<M: Message>(&self, job_id: u64, code: &str, msg: &M) -> Result<()> {
+        self.publish_sock.send_str(&log_topic(job_id), zmq::SNDMORE)?;
+        self.publish_sock.send_str(code, zmq::SNDMORE)?;
+        self.publish_sock.send(
+            msg.write_to_bytes()?.as_slice(),
+            0,
+        )?;
+        Ok(())
+    }
+
     /// Factored out the above loop to take advantage of ?'s behavior
     /// in Result-returning functions to collapse deeply branching
     /// code.
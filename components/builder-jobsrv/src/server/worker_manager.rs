@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
+use std::collections::HashMap;
 use std::sync::{mpsc, Arc, RwLock};
 use std::time::{Duration, Instant};
 use std::thread::{self, JoinHandle};
@@ -20,6 +22,7 @@ use linked_hash_map::LinkedHashMap;
 use hab_net::server::ZMQ_CONTEXT;
 use protobuf::{parse_from_bytes, Message};
 use protocol::jobsrv;
+use protocol::jobsrv::JobGet;
 use zmq;
 
 use config::Config;
@@ -28,6 +31,13 @@ use error::Result;
 
 const WORKER_MGR_ADDR: &'static str = "inproc://work-manager";
 const WORKER_TIMEOUT_MS: u64 = 33_000;
+/// How long a worker has to confirm a cancel before we give up waiting on it and mark the job
+/// as canceled ourselves
+const CANCEL_TIMEOUT_MS: u64 = 60_000;
+/// Notification that there may be pending jobs to dispatch
+const NOTIFY_WORK: u8 = 1;
+/// Notification that there may be jobs waiting on their worker to cancel them
+const NOTIFY_CANCEL: u8 = 2;
 
 pub struct WorkerMgrClient {
     socket: zmq::Socket,
@@ -40,7 +50,12 @@ impl WorkerMgrClient {
     }
 
     pub fn notify_work(&mut self) -> Result<()> {
-        try!(self.socket.send(&[1], 0));
+        try!(self.socket.send(&[NOTIFY_WORK], 0));
+        Ok(())
+    }
+
+    pub fn notify_cancel(&mut self) -> Result<()> {
+        try!(self.socket.send(&[NOTIFY_CANCEL], 0));
         Ok(())
     }
 }
@@ -55,6 +70,77 @@ impl Default for WorkerMgrClient {
     }
 }
 
+/// Keeps track of which worker each dispatched job went to, and of the jobs we've asked their
+/// worker to cancel.
+#[derive(Default)]
+struct Dispatched {
+    /// Job ids mapped to the worker each job was dispatched to
+    jobs: HashMap<u64, String>,
+    /// Ids of jobs which we've asked their worker to cancel, mapped to when we stop waiting
+    canceling: HashMap<u64, Instant>,
+}
+
+impl Dispatched {
+    fn insert(&mut self, job_id: u64, worker: String) {
+        self.jobs.insert(job_id, worker);
+    }
+
+    fn worker(&self, job_id: u64) -> Option<&String> {
+        self.jobs.get(&job_id)
+    }
+
+    fn is_canceling(&self, job_id: u64) -> bool {
+        self.canceling.contains_key(&job_id)
+    }
+
+    fn cancel_sent(&mut self, job_id: u64, now: Instant) {
+        self.canceling
+            .insert(job_id, now + Duration::from_millis(CANCEL_TIMEOUT_MS));
+    }
+
+    /// Forget a job which its worker is no longer running
+    fn finish(&mut self, job_id: u64) {
+        self.jobs.remove(&job_id);
+        self.canceling.remove(&job_id);
+    }
+
+    /// Forget every job dispatched to a worker which has gone away. Returns the ids of those
+    /// jobs which were waiting on the worker to cancel them.
+    fn worker_gone(&mut self, worker: &str) -> Vec<u64> {
+        let ids: Vec<u64> = self.jobs
+            .iter()
+            .filter(|&(_, w)| w == worker)
+            .map(|(id, _)| *id)
+            .collect();
+        let mut canceled = vec![];
+        for id in ids {
+            if self.canceling.contains_key(&id) {
+                canceled.push(id);
+            }
+            self.finish(id);
+        }
+        canceled
+    }
+
+    /// Forget every cancel which its worker has not confirmed in time and return their job ids
+    fn expire_cancels(&mut self, now: Instant) -> Vec<u64> {
+        let ids: Vec<u64> = self.canceling
+            .iter()
+            .filter(|&(_, deadline)| deadline <= &now)
+            .map(|(id, _)| *id)
+            .collect();
+        for id in ids.iter() {
+            self.finish(*id);
+        }
+        ids
+    }
+
+    /// When the next cancel that is still waiting on its worker times out
+    fn next_deadline(&self) -> Option<Instant> {
+        self.canceling.values().min().cloned()
+    }
+}
+
 pub struct WorkerMgr {
     config: Arc<RwLock<Config>>,
     datastore: DataStore,
@@ -63,6 +149,7 @@ pub struct WorkerMgr {
     work_mgr_sock: zmq::Socket,
     msg: zmq::Message,
     workers: LinkedHashMap<String, Instant>,
+    dispatched: Dispatched,
 }
 
 impl WorkerMgr {
@@ -84,6 +171,7 @@ impl WorkerMgr {
             work_mgr_sock: work_mgr_sock,
             msg: msg,
             workers: LinkedHashMap::new(),
+            dispatched: Dispatched::default(),
         })
     }
 
@@ -119,8 +207,15 @@ impl WorkerMgr {
         let mut process_work = false;
         rz.send(()).unwrap();
 
-        // Reset any Dispatched jobs to Pending
+        // Reset any Dispatched jobs to Pending and finish off any pending cancels whose worker
+        // we never recorded
         self.datastore.reset_jobs()?;
+        // Pick back up the jobs that are still out with a worker, and send along any cancels
+        // that were waiting on them when we went down
+        for (job_id, worker) in self.datastore.dispatched_jobs()? {
+            self.dispatched.insert(job_id, worker);
+        }
+        let mut process_cancels = true;
 
         loop {
             {
@@ -148,15 +243,24 @@ impl WorkerMgr {
                 process_work = try!(self.process_heartbeat());
                 hb_sock = false;
             }
-            self.expire_workers();
+            try!(self.expire_workers());
+            try!(self.expire_cancels());
             if rq_sock {
                 try!(self.process_job_status());
                 rq_sock = false;
             }
             if work_mgr_sock {
-                process_work = true;
                 work_mgr_sock = false;
                 try!(self.work_mgr_sock.recv(&mut self.msg, 0));
+                if self.msg.first() == Some(&NOTIFY_CANCEL) {
+                    process_cancels = true;
+                } else {
+                    process_work = true;
+                }
+            }
+            if process_cancels {
+                try!(self.process_cancels());
+                process_cancels = false;
             }
 
             // Handle potential work in pending_jobs queue
@@ -170,15 +274,24 @@ impl WorkerMgr {
         let now = Instant::now();
         let timeout;
 
-        if let Some((_, expiry)) = self.workers.front() {
+        // Wake up for whichever comes first, the next worker to expire or the next cancel to
+        // time out
+        let next = match (
+            self.workers.front().map(|(_, expiry)| *expiry),
+            self.dispatched.next_deadline(),
+        ) {
+            (Some(expiry), Some(deadline)) => Some(cmp::min(expiry, deadline)),
+            (expiry, deadline) => expiry.or(deadline),
+        };
+        if let Some(expiry) = next {
             // uh-oh. our expiration date is in the past. it's supposed to be in the
             // future. blindly subtracting now from this will panic the current
             // thread, since Instant's are supposed to monotonically increase.
             // let's just timeout immediately instead.
-            if expiry < &now {
+            if expiry < now {
                 return 0;
             } else {
-                timeout = *expiry - now;
+                timeout = expiry - now;
             }
 
             (timeout.as_secs() as i64 * 1000) + (timeout.subsec_nanos() as i64 / 1000 / 1000)
@@ -223,6 +336,8 @@ impl WorkerMgr {
                         self.datastore.update_job(&job)?;
                         continue;
                     }
+                    self.datastore.set_job_worker(job.get_id(), &worker)?;
+                    self.dispatched.insert(job.get_id(), worker);
                 }
                 None => {
                     debug!("no workers available - bailing for now");
@@ -235,7 +350,38 @@ impl WorkerMgr {
         Ok(())
     }
 
-    fn expire_workers(&mut self) {
+    fn process_cancels(&mut self) -> Result<()> {
+        for mut job in self.datastore.cancel_pending_jobs()? {
+            let worker = match self.dispatched.worker(job.get_id()) {
+                Some(worker) => worker.clone(),
+                None => {
+                    // The job never reached a worker we know of, so there's nothing to stop
+                    debug!("canceling job with no known worker, job={:?}", job);
+                    job.set_state(jobsrv::JobState::Canceled);
+                    self.datastore.update_job(&job)?;
+                    continue;
+                }
+            };
+            if self.dispatched.is_canceling(job.get_id()) {
+                continue;
+            }
+            debug!("sending cancel, worker={:?}, job={:?}", worker, job);
+            if self.rq_sock.send_str(&worker, zmq::SNDMORE).is_err() ||
+                self.rq_sock.send(&[], zmq::SNDMORE).is_err() ||
+                self.rq_sock.send(&job.write_to_bytes().unwrap(), 0).is_err()
+            {
+                debug!("failed to send, worker went away, worker={:?}", worker);
+                self.dispatched.finish(job.get_id());
+                job.set_state(jobsrv::JobState::Canceled);
+                self.datastore.update_job(&job)?;
+                continue;
+            }
+            self.dispatched.cancel_sent(job.get_id(), Instant::now());
+        }
+        Ok(())
+    }
+
+    fn expire_workers(&mut self) -> Result<()> {
         let now = Instant::now();
         loop {
             if let Some((_, expiry)) = self.workers.front() {
@@ -245,9 +391,33 @@ impl WorkerMgr {
             } else {
                 break;
             }
-            let worker = self.workers.pop_front();
-            debug!("expiring worker due to inactivity, worker={:?}", worker);
+            if let Some((worker, _)) = self.workers.pop_front() {
+                debug!("expiring worker due to inactivity, worker={:?}", worker);
+                // Nobody is left to confirm the cancels that were sent to this worker
+                for id in self.dispatched.worker_gone(&worker) {
+                    try!(self.set_canceled(id));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn expire_cancels(&mut self) -> Result<()> {
+        for id in self.dispatched.expire_cancels(Instant::now()) {
+            warn!("worker did not confirm cancel in time, job={}", id);
+            try!(self.set_canceled(id));
         }
+        Ok(())
+    }
+
+    fn set_canceled(&mut self, job_id: u64) -> Result<()> {
+        let mut request = JobGet::new();
+        request.set_id(job_id);
+        if let Some(mut job) = try!(self.datastore.get_job(&request)) {
+            job.set_state(jobsrv::JobState::Canceled);
+            try!(self.datastore.update_job(&job));
+        }
+        Ok(())
     }
 
     fn process_heartbeat(&mut self) -> Result<bool> {
@@ -277,10 +447,83 @@ impl WorkerMgr {
         try!(self.rq_sock.recv(&mut self.msg, 0));
         // Pop message body
         try!(self.rq_sock.recv(&mut self.msg, 0));
-        let job: jobsrv::Job = try!(parse_from_bytes(&self.msg));
+        let mut job: jobsrv::Job = try!(parse_from_bytes(&self.msg));
         debug!("job_status={:?}", job);
+        match job.get_state() {
+            jobsrv::JobState::Complete |
+            jobsrv::JobState::Rejected |
+            jobsrv::JobState::Failed |
+            jobsrv::JobState::Canceled => {
+                self.dispatched.finish(job.get_id());
+            }
+            // Don't let a late status from the worker clobber a cancel that's still in flight
+            _ if self.dispatched.is_canceling(job.get_id()) => {
+                job.set_state(jobsrv::JobState::CancelPending);
+            }
+            _ => (),
+        }
         try!(self.datastore.update_job(&job));
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatched_jobs_finish() {
+        let mut dispatched = Dispatched::default();
+        dispatched.insert(1, "worker-a".to_string());
+        assert_eq!(dispatched.worker(1), Some(&"worker-a".to_string()));
+        assert!(!dispatched.is_canceling(1));
+        dispatched.cancel_sent(1, Instant::now());
+        assert!(dispatched.is_canceling(1));
+        dispatched.finish(1);
+        assert_eq!(dispatched.worker(1), None);
+        assert!(!dispatched.is_canceling(1));
+        assert_eq!(dispatched.next_deadline(), None);
+    }
+
+    #[test]
+    fn cancels_on_a_worker_that_went_away_are_returned() {
+        let mut dispatched = Dispatched::default();
+        dispatched.insert(1, "worker-a".to_string());
+        dispatched.insert(2, "worker-a".to_string());
+        dispatched.insert(3, "worker-b".to_string());
+        dispatched.cancel_sent(2, Instant::now());
+        dispatched.cancel_sent(3, Instant::now());
+        assert_eq!(dispatched.worker_gone("worker-a"), vec![2]);
+        assert_eq!(dispatched.worker(1), None);
+        assert_eq!(dispatched.worker(2), None);
+        assert!(!dispatched.is_canceling(2));
+        assert_eq!(dispatched.worker(3), Some(&"worker-b".to_string()));
+        assert!(dispatched.is_canceling(3));
+        assert!(dispatched.worker_gone("worker-a").is_empty());
+    }
+
+    #[test]
+    fn unconfirmed_cancels_expire() {
+        let mut dispatched = Dispatched::default();
+        let now = Instant::now();
+        dispatched.insert(1, "worker-a".to_string());
+        dispatched.insert(2, "worker-b".to_string());
+        dispatched.cancel_sent(1, now);
+        dispatched.cancel_sent(2, now + Duration::from_millis(1_000));
+        assert_eq!(
+            dispatched.next_deadline(),
+            Some(now + Duration::from_millis(CANCEL_TIMEOUT_MS))
+        );
+        assert!(dispatched.expire_cancels(now).is_empty());
+        let deadline = now + Duration::from_millis(CANCEL_TIMEOUT_MS);
+        assert_eq!(dispatched.expire_cancels(deadline), vec![1]);
+        assert_eq!(dispatched.worker(1), None);
+        assert!(dispatched.is_canceling(2));
+        assert_eq!(
+            dispatched.expire_cancels(deadline + Duration::from_millis(1_000)),
+            vec![2]
+        );
+        assert_eq!(dispatched.next_deadline(), None);
+    }
+}
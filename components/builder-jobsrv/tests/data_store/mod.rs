@@ -154,3 +154,135 @@ fn update_job() {
         .expect("No job found");
     assert_eq!(failed_job.get_state(), jobsrv::JobState::Failed);
 }
+
+#[test]
+fn cancel_job() {
+    let mut job1 = test_job();
+    let mut job2 = test_job();
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let rjob1 = ds.create_job(&mut job1).expect("Failed to create job");
+    let rjob2 = ds.create_job(&mut job2).expect("Failed to create job");
+
+    // Dispatch the first job; the second stays Pending
+    let dispatched = ds.pending_jobs(1).expect("Failed to get pending job");
+    assert_eq!(dispatched[0].get_id(), rjob1.get_id());
+
+    // A Pending job is canceled outright
+    let mut cancel = jobsrv::JobCancel::new();
+    cancel.set_id(rjob2.get_id());
+    let canceled = ds.cancel_job(&cancel)
+        .expect("Failed to cancel job")
+        .expect("No job canceled");
+    assert_eq!(canceled.get_state(), jobsrv::JobState::Canceled);
+
+    // A Dispatched job waits on its worker
+    cancel.set_id(rjob1.get_id());
+    let cancel_pending = ds.cancel_job(&cancel)
+        .expect("Failed to cancel job")
+        .expect("No job canceled");
+    assert_eq!(cancel_pending.get_state(), jobsrv::JobState::CancelPending);
+    let waiting = ds.cancel_pending_jobs().expect(
+        "Failed to get jobs pending cancel",
+    );
+    assert_eq!(waiting.len(), 1);
+    assert_eq!(waiting[0].get_id(), rjob1.get_id());
+
+    // Finished jobs can't be canceled
+    cancel.set_id(rjob2.get_id());
+    assert!(
+        ds.cancel_job(&cancel)
+            .expect("Failed to cancel job")
+            .is_none()
+    );
+
+    // Recovery finishes off cancels that were in flight to a worker we never recorded
+    ds.reset_jobs().expect("Failed to reset jobs");
+    let mut get_job = jobsrv::JobGet::new();
+    get_job.set_id(rjob1.get_id());
+    let reset_job = ds.get_job(&get_job)
+        .expect("Failed to get job from database")
+        .expect("No job found");
+    assert_eq!(reset_job.get_state(), jobsrv::JobState::Canceled);
+}
+
+#[test]
+fn cancel_processing_job_after_restart() {
+    let mut job1 = test_job();
+    let mut job2 = test_job();
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let rjob1 = ds.create_job(&mut job1).expect("Failed to create job");
+    let rjob2 = ds.create_job(&mut job2).expect("Failed to create job");
+
+    // Both jobs go out; the first reaches its worker and starts building, while the second is
+    // still only Dispatched when we go down
+    let mut dispatched = ds.pending_jobs(2).expect("Failed to get pending jobs");
+    assert_eq!(dispatched.len(), 2);
+    ds.set_job_worker(rjob1.get_id(), "worker-1")
+        .expect("Failed to set job worker");
+    ds.set_job_worker(rjob2.get_id(), "worker-2")
+        .expect("Failed to set job worker");
+    let mut processing = dispatched.remove(0);
+    assert_eq!(processing.get_id(), rjob1.get_id());
+    processing.set_state(jobsrv::JobState::Processing);
+    ds.update_job(&processing).expect("Failed to update job state");
+
+    // After a restart the Processing job is still out with its worker, and the Dispatched one
+    // is queued again
+    ds.reset_jobs().expect("Failed to reset jobs");
+    assert_eq!(
+        ds.dispatched_jobs().expect("Failed to get dispatched jobs"),
+        vec![(rjob1.get_id(), "worker-1".to_string())]
+    );
+    let mut get_job = jobsrv::JobGet::new();
+    get_job.set_id(rjob2.get_id());
+    let requeued = ds.get_job(&get_job)
+        .expect("Failed to get job from database")
+        .expect("No job found");
+    assert_eq!(requeued.get_state(), jobsrv::JobState::Pending);
+
+    // Canceling it waits on the worker, and that survives another restart
+    let mut cancel = jobsrv::JobCancel::new();
+    cancel.set_id(rjob1.get_id());
+    let cancel_pending = ds.cancel_job(&cancel)
+        .expect("Failed to cancel job")
+        .expect("No job canceled");
+    assert_eq!(cancel_pending.get_state(), jobsrv::JobState::CancelPending);
+    ds.reset_jobs().expect("Failed to reset jobs");
+    let waiting = ds.cancel_pending_jobs().expect(
+        "Failed to get jobs pending cancel",
+    );
+    assert_eq!(waiting.len(), 1);
+    assert_eq!(waiting[0].get_id(), rjob1.get_id());
+    assert_eq!(
+        ds.dispatched_jobs().expect("Failed to get dispatched jobs"),
+        vec![(rjob1.get_id(), "worker-1".to_string())]
+    );
+}
+
+#[test]
+fn job_vcs_ref_and_sha() {
+    let mut job1 = test_job();
+    job1.set_vcs_ref("v1.0.0".to_string());
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let mut rjob1 = ds.create_job(&mut job1).expect("Failed to create job");
+    assert_eq!(rjob1.get_vcs_ref(), "v1.0.0");
+    assert!(!rjob1.has_vcs_sha());
+
+    rjob1.set_vcs_sha("0123456789abcdef0123456789abcdef01234567".to_string());
+    rjob1.set_state(jobsrv::JobState::Complete);
+    ds.update_job(&rjob1).expect("Failed to update job");
+
+    let mut get_job = jobsrv::JobGet::new();
+    get_job.set_id(rjob1.get_id());
+    let complete_job = ds.get_job(&get_job)
+        .expect("Failed to get job from database")
+        .expect("No job found");
+    assert_eq!(complete_job.get_vcs_ref(), "v1.0.0");
+    assert_eq!(
+        complete_job.get_vcs_sha(),
+        "0123456789abcdef0123456789abcdef01234567"
+    );
+}
@@ -109,7 +109,7 @@ impl DataStore {
         let conn = self.pool.get(opc)?;
         let project = opc.get_project();
         conn.execute(
-            "SELECT update_origin_project_v1($1, $2, $3, $4, $5, $6, $7)",
+            "SELECT update_origin_project_v3($1, $2, $3, $4, $5, $6, $7, $8, $9)",
             &[
                 &(project.get_id() as i64),
                 &(project.get_origin_id() as i64),
@@ -118,6 +118,8 @@ impl DataStore {
                 &project.get_vcs_type(),
                 &project.get_vcs_data(),
                 &(project.get_owner_id() as i64),
+                &build_timeout(project),
+                &webhook_secret(project),
             ],
         ).map_err(Error::OriginProjectUpdate)?;
         Ok(())
@@ -149,6 +151,29 @@ impl DataStore {
         }
     }
 
+    pub fn list_origin_projects_by_repo(
+        &self,
+        oplr: &originsrv::OriginProjectListRequest,
+    ) -> Result<originsrv::OriginProjectListResponse> {
+        let conn = self.pool.get(oplr)?;
+        let rows = &conn.query(
+            "SELECT * FROM list_origin_projects_by_repo_v1($1, $2)",
+            &[&oplr.get_origin(), &oplr.get_vcs_data()],
+        ).map_err(Error::OriginProjectList)?;
+
+        let mut response = originsrv::OriginProjectListResponse::new();
+        let mut projects = protobuf::RepeatedField::new();
+        for row in rows {
+            projects.push(self.row_to_origin_project(&row))
+        }
+        if let Some(project) = projects.first() {
+            response.set_origin_id(project.get_origin_id());
+        }
+
+        response.set_projects(projects);
+        Ok(response)
+    }
+
     pub fn row_to_origin_project(&self, row: &postgres::rows::Row) -> originsrv::OriginProject {
         let mut project = originsrv::OriginProject::new();
         let id: i64 = row.get("id");
@@ -163,6 +188,12 @@ impl DataStore {
         project.set_plan_path(row.get("plan_path"));
         project.set_vcs_type(row.get("vcs_type"));
         project.set_vcs_data(row.get("vcs_data"));
+        if let Some(Ok(timeout)) = row.get_opt::<&str, i64>("build_timeout") {
+            project.set_build_timeout(timeout as u64);
+        }
+        if let Some(Ok(secret)) = row.get_opt::<&str, String>("webhook_secret") {
+            project.set_webhook_secret(secret);
+        }
         project
     }
 
@@ -173,7 +204,7 @@ impl DataStore {
         let conn = self.pool.get(opc)?;
         let project = opc.get_project();
         let rows = conn.query(
-            "SELECT * FROM insert_origin_project_v1($1, $2, $3, $4, $5, $6)",
+            "SELECT * FROM insert_origin_project_v3($1, $2, $3, $4, $5, $6, $7, $8)",
             &[
                 &project.get_origin_name(),
                 &project.get_package_name(),
@@ -181,6 +212,8 @@ impl DataStore {
                 &project.get_vcs_type(),
                 &project.get_vcs_data(),
                 &(project.get_owner_id() as i64),
+                &build_timeout(project),
+                &webhook_secret(project),
             ],
         ).map_err(Error::OriginProjectCreate)?;
         let row = rows.get(0);
@@ -547,7 +580,8 @@ impl DataStore {
         let conn = self.pool.get(opc)?;
         let ident = opc.get_ident();
         let rows = conn.query(
-            "SELECT * FROM insert_origin_package_v1($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
+            "SELECT * FROM insert_origin_package_v2($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, \
+             $12, $13, $14, $15)",
             &[
                 &(opc.get_origin_id() as i64),
                 &(opc.get_owner_id() as i64),
@@ -560,6 +594,10 @@ impl DataStore {
                 &self.into_delimited(opc.get_deps().to_vec()),
                 &self.into_delimited(opc.get_tdeps().to_vec()),
                 &self.into_delimited(opc.get_exposes().to_vec()),
+                &opc.get_description(),
+                &self.into_delimited(opc.get_licenses().to_vec()),
+                &opc.get_upstream_url(),
+                &opc.get_maintainer(),
             ],
         ).map_err(Error::OriginPackageCreate)?;
 
@@ -575,8 +613,8 @@ impl DataStore {
     ) -> Result<Option<originsrv::OriginPackage>> {
         let conn = self.pool.get(opg)?;
         let rows = conn.query(
-            "SELECT * FROM get_origin_package_v1($1)",
-            &[&opg.get_ident().to_string()],
+            "SELECT * FROM get_origin_package_v2($1, $2)",
+            &[&opg.get_ident().to_string(), &opg.get_target()],
         ).map_err(Error::OriginPackageGet)?;
         if rows.len() != 0 {
             let row = rows.get(0);
@@ -592,11 +630,12 @@ impl DataStore {
     ) -> Result<Option<originsrv::OriginPackage>> {
         let conn = self.pool.get(ocpg)?;
         let rows = conn.query(
-            "SELECT * FROM get_origin_channel_package_v1($1, $2, $3)",
+            "SELECT * FROM get_origin_channel_package_v2($1, $2, $3, $4)",
             &[
                 &ocpg.get_ident().get_origin(),
                 &ocpg.get_name(),
                 &ocpg.get_ident().to_string(),
+                &ocpg.get_target(),
             ],
         ).map_err(Error::OriginChannelPackageGet)?;
         if rows.len() != 0 {
@@ -637,7 +676,7 @@ impl DataStore {
     ) -> Result<Option<originsrv::OriginPackageIdent>> {
         let conn = self.pool.get(opc)?;
         let rows = conn.query(
-            "SELECT * FROM get_origin_package_latest_v2($1, $2)",
+            "SELECT * FROM get_origin_package_latest_v3($1, $2)",
             &[&self.searchable_ident(opc.get_ident()), &opc.get_target()],
         ).map_err(Error::OriginPackageLatestGet)?;
         if rows.len() != 0 {
@@ -654,7 +693,7 @@ impl DataStore {
     ) -> Result<Option<originsrv::OriginPackageIdent>> {
         let conn = self.pool.get(ocpg)?;
         let rows = conn.query(
-            "SELECT * FROM get_origin_channel_package_latest_v2($1, $2, $3, $4)",
+            "SELECT * FROM get_origin_channel_package_latest_v3($1, $2, $3, $4)",
             &[
                 &ocpg.get_ident().get_origin(),
                 &ocpg.get_name(),
@@ -678,7 +717,7 @@ impl DataStore {
         let conn = self.pool.get(opvl)?;
 
         let rows = conn.query(
-            "SELECT * FROM get_origin_package_versions_for_origin_v3($1, $2)",
+            "SELECT * FROM get_origin_package_versions_for_origin_v4($1, $2)",
             &[&opvl.get_origin(), &opvl.get_name()],
         ).map_err(Error::OriginPackageVersionList)?;
 
@@ -718,9 +757,9 @@ impl DataStore {
         let conn = self.pool.get(opl)?;
 
         let query = if *&opl.get_distinct() {
-            "SELECT * FROM get_origin_packages_for_origin_distinct_v1($1, $2, $3)"
+            "SELECT * FROM get_origin_packages_for_origin_distinct_v2($1, $2, $3)"
         } else {
-            "SELECT * FROM get_origin_packages_for_origin_v2($1, $2, $3)"
+            "SELECT * FROM get_origin_packages_for_origin_v3($1, $2, $3)"
         };
 
         let rows = conn.query(
@@ -772,7 +811,7 @@ impl DataStore {
         let conn = self.pool.get(opl)?;
 
         let rows = conn.query(
-            "SELECT * FROM get_origin_channel_packages_for_channel_v1($1, $2, $3, $4, $5)",
+            "SELECT * FROM get_origin_channel_packages_for_channel_v2($1, $2, $3, $4, $5)",
             &[
                 &opl.get_ident().get_origin(),
                 &opl.get_name(),
@@ -829,15 +868,21 @@ impl DataStore {
 
         let rows = if *&ops.get_distinct() {
             conn.query(
-                "SELECT * FROM search_all_origin_packages_dynamic_v2($1, $2, $3)",
-                &[&ops.get_query(), &ops.limit(), &(ops.get_start() as i64)],
+                "SELECT * FROM search_all_origin_packages_dynamic_v3($1, $2, $3, $4)",
+                &[
+                    &ops.get_query(),
+                    &ops.get_license(),
+                    &ops.limit(),
+                    &(ops.get_start() as i64),
+                ],
             ).map_err(Error::OriginPackageSearch)?
         } else {
             conn.query(
-                "SELECT * FROM search_origin_packages_for_origin_v1($1, $2, $3, $4)",
+                "SELECT * FROM search_origin_packages_for_origin_v2($1, $2, $3, $4, $5)",
                 &[
                     &ops.get_origin(),
                     &ops.get_query(),
+                    &ops.get_license(),
                     &ops.limit(),
                     &(ops.get_start() as i64),
                 ],
@@ -907,6 +952,29 @@ impl DataStore {
         package.set_exposes(exposes);
         package.set_deps(self.into_idents(row.get("deps")));
         package.set_tdeps(self.into_idents(row.get("tdeps")));
+        package.set_yanked(row.get("yanked"));
+        let description: Option<String> = row.get("description");
+        if let Some(description) = description {
+            package.set_description(description);
+        }
+        let licenses: Option<String> = row.get("licenses");
+        if let Some(licenses) = licenses {
+            package.set_licenses(
+                licenses
+                    .split(":")
+                    .filter(|l| !l.is_empty())
+                    .map(|l| l.to_string())
+                    .collect(),
+            );
+        }
+        let upstream_url: Option<String> = row.get("upstream_url");
+        if let Some(upstream_url) = upstream_url {
+            package.set_upstream_url(upstream_url);
+        }
+        let maintainer: Option<String> = row.get("maintainer");
+        if let Some(maintainer) = maintainer {
+            package.set_maintainer(maintainer);
+        }
         package
     }
 
@@ -1016,6 +1084,53 @@ impl DataStore {
         Ok(())
     }
 
+    pub fn yank_origin_package(&self, opy: &originsrv::OriginPackageYank) -> Result<()> {
+        let conn = self.pool.get(opy)?;
+        conn.execute(
+            "SELECT yank_origin_package_v1($1, $2)",
+            &[&(opy.get_package_id() as i64), &opy.get_yanked()],
+        ).map_err(Error::OriginPackageYank)?;
+        Ok(())
+    }
+
+    pub fn delete_origin_package(&self, opd: &originsrv::OriginPackageDelete) -> Result<()> {
+        let conn = self.pool.get(opd)?;
+        conn.execute(
+            "SELECT delete_origin_package_v1($1)",
+            &[&(opd.get_package_id() as i64)],
+        ).map_err(Error::OriginPackageDelete)?;
+        Ok(())
+    }
+
+    /// Lists the packages, in any origin, which have the given package as a transitive
+    /// dependency.
+    pub fn list_origin_package_dependents(
+        &self,
+        opdg: &originsrv::OriginPackageDependentsGet,
+    ) -> Result<originsrv::OriginPackageListResponse> {
+        let conn = self.pool.get(opdg)?;
+        let rows = conn.query(
+            "SELECT * FROM get_origin_package_dependents_dynamic_v1($1)",
+            &[&opdg.get_ident().to_string()],
+        ).map_err(Error::OriginPackageDependentsList)?;
+
+        let mut response = originsrv::OriginPackageListResponse::new();
+        let mut idents = protobuf::RepeatedField::new();
+        for row in rows.iter() {
+            idents.push(self.row_to_origin_package_ident(&row));
+        }
+        idents.sort_by(|a, b| a.cmp(b));
+        response.set_start(0);
+        response.set_stop(if idents.is_empty() {
+            0
+        } else {
+            (idents.len() - 1) as u64
+        });
+        response.set_count(idents.len() as u64);
+        response.set_idents(idents);
+        Ok(response)
+    }
+
     pub fn delete_origin_channel_by_id(&self, ocd: &originsrv::OriginChannelDelete) -> Result<()> {
         let conn = self.pool.get(ocd)?;
         conn.execute(
@@ -1026,6 +1141,24 @@ impl DataStore {
     }
 }
 
+/// A project without a build timeout of its own is stored with a NULL one, leaving the timeout
+/// up to the worker.
+fn build_timeout(project: &originsrv::OriginProject) -> Option<i64> {
+    if project.get_build_timeout() > 0 {
+        Some(project.get_build_timeout() as i64)
+    } else {
+        None
+    }
+}
+
+fn webhook_secret(project: &originsrv::OriginProject) -> Option<&str> {
+    if project.has_webhook_secret() {
+        Some(project.get_webhook_secret())
+    } else {
+        None
+    }
+}
+
 fn sync_origins(pool: Pool) -> DbResult<EventOutcome> {
     error!("I like my butt");
     let mut result = EventOutcome::Finished;
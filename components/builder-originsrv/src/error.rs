@@ -54,6 +54,8 @@ pub enum Error {
     OriginInvitationListForAccount(postgres::error::Error),
     OriginInvitationValidate(postgres::error::Error),
     OriginPackageCreate(postgres::error::Error),
+    OriginPackageDelete(postgres::error::Error),
+    OriginPackageDependentsList(postgres::error::Error),
     OriginPackageGet(postgres::error::Error),
     OriginPackageLatestGet(postgres::error::Error),
     OriginPackageList(postgres::error::Error),
@@ -62,9 +64,11 @@ pub enum Error {
     OriginPackagePromote(postgres::error::Error),
     OriginPackageSearch(postgres::error::Error),
     OriginPackageUniqueList(postgres::error::Error),
+    OriginPackageYank(postgres::error::Error),
     OriginProjectCreate(postgres::error::Error),
     OriginProjectDelete(postgres::error::Error),
     OriginProjectGet(postgres::error::Error),
+    OriginProjectList(postgres::error::Error),
     OriginProjectUpdate(postgres::error::Error),
     OriginSecretKeyCreate(postgres::error::Error),
     OriginSecretKeyGet(postgres::error::Error),
@@ -159,6 +163,12 @@ impl fmt::Display for Error {
             Error::OriginPackageCreate(ref e) => {
                 format!("Error creating package in database, {}", e)
             }
+            Error::OriginPackageDelete(ref e) => {
+                format!("Error deleting package in database, {}", e)
+            }
+            Error::OriginPackageDependentsList(ref e) => {
+                format!("Error getting list of packages depending on a package, {}", e)
+            }
             Error::OriginPackageGet(ref e) => format!("Error getting package in database, {}", e),
             Error::OriginPackageLatestGet(ref e) => {
                 format!("Error getting latest package in database, {}", e)
@@ -187,6 +197,7 @@ impl fmt::Display for Error {
                     e
                 )
             }
+            Error::OriginPackageYank(ref e) => format!("Error yanking package in database, {}", e),
             Error::OriginProjectCreate(ref e) => {
                 format!("Error creating project in database, {}", e)
             }
@@ -194,6 +205,9 @@ impl fmt::Display for Error {
                 format!("Error deleting project in database, {}", e)
             }
             Error::OriginProjectGet(ref e) => format!("Error getting project from database, {}", e),
+            Error::OriginProjectList(ref e) => {
+                format!("Error listing projects from database, {}", e)
+            }
             Error::OriginProjectUpdate(ref e) => {
                 format!("Error updating project in database, {}", e)
             }
@@ -269,6 +283,8 @@ impl error::Error for Error {
             Error::OriginInvitationListForAccount(ref err) => err.description(),
             Error::OriginInvitationValidate(ref err) => err.description(),
             Error::OriginPackageCreate(ref err) => err.description(),
+            Error::OriginPackageDelete(ref err) => err.description(),
+            Error::OriginPackageDependentsList(ref err) => err.description(),
             Error::OriginPackageGet(ref err) => err.description(),
             Error::OriginPackageLatestGet(ref err) => err.description(),
             Error::OriginPackageList(ref err) => err.description(),
@@ -277,9 +293,11 @@ impl error::Error for Error {
             Error::OriginPackagePromote(ref err) => err.description(),
             Error::OriginPackageSearch(ref err) => err.description(),
             Error::OriginPackageUniqueList(ref err) => err.description(),
+            Error::OriginPackageYank(ref err) => err.description(),
             Error::OriginProjectCreate(ref err) => err.description(),
             Error::OriginProjectDelete(ref err) => err.description(),
             Error::OriginProjectGet(ref err) => err.description(),
+            Error::OriginProjectList(ref err) => err.description(),
             Error::OriginProjectUpdate(ref err) => err.description(),
             Error::OriginSecretKeyCreate(ref err) => err.description(),
             Error::OriginSecretKeyGet(ref err) => err.description(),
@@ -197,5 +197,74 @@ pub fn migrate(migrator: &mut Migrator) -> Result<()> {
                     END
                     $$ LANGUAGE plpgsql STABLE"#,
     )?;
+    migrator.migrate(
+        "originsrv-v7",
+        r#"CREATE OR REPLACE FUNCTION get_origin_channel_package_latest_v3 (
+                    op_origin text,
+                    op_channel text,
+                    op_ident text,
+                    op_target text
+                 ) RETURNS SETOF origin_packages AS $$
+                    BEGIN
+                        RETURN QUERY SELECT op.*
+                          FROM origin_packages op
+                          INNER JOIN origin_channel_packages ocp on ocp.package_id = op.id
+                          INNER JOIN origin_channels oc on ocp.channel_id = oc.id
+                          INNER JOIN origins o on oc.origin_id = o.id
+                          WHERE o.name = op_origin
+                          AND oc.name = op_channel
+                          AND op.ident LIKE (op_ident  || '%')
+                          AND op.target = op_target
+                          AND op.yanked = false;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv-v7",
+        r#"CREATE OR REPLACE FUNCTION get_origin_channel_packages_for_channel_v2 (
+                    op_origin text,
+                    op_channel text,
+                    op_ident text,
+                    op_limit bigint,
+                    op_offset bigint
+                 ) RETURNS TABLE(total_count bigint, ident text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(*) OVER () AS total_count, op.ident
+                          FROM origin_packages op
+                          INNER JOIN origin_channel_packages ocp on ocp.package_id = op.id
+                          INNER JOIN origin_channels oc on ocp.channel_id = oc.id
+                          INNER JOIN origins o on oc.origin_id = o.id
+                          WHERE o.name = op_origin
+                          AND oc.name = op_channel
+                          AND op.ident LIKE (op_ident  || '%')
+                          AND op.yanked = false
+                          ORDER BY ident ASC
+                          LIMIT op_limit OFFSET op_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv-v9",
+        r#"CREATE OR REPLACE FUNCTION get_origin_channel_package_v2 (
+                    op_origin text,
+                    op_channel text,
+                    op_ident text,
+                    op_target text
+                 ) RETURNS SETOF origin_packages AS $$
+                    BEGIN
+                        RETURN QUERY SELECT op.*
+                          FROM origin_packages op
+                          INNER JOIN origin_channel_packages ocp on ocp.package_id = op.id
+                          INNER JOIN origin_channels oc on ocp.channel_id = oc.id
+                          INNER JOIN origins o on oc.origin_id = o.id
+                          WHERE op.ident = op_ident AND o.name = op_origin AND oc.name = op_channel
+                          AND (op_target = '' OR op.target = op_target)
+                          ORDER BY op.target ASC;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
     Ok(())
 }
@@ -267,5 +267,227 @@ pub fn migrate(migrator: &mut Migrator) -> Result<()> {
                         RETURN;
                     END
                     $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator.migrate(
+        "originsrv-v7",
+        r#"ALTER TABLE origin_packages ADD COLUMN IF NOT EXISTS yanked bool DEFAULT false"#,
+    )?;
+    migrator.migrate("originsrv-v7",
+                 r#"CREATE OR REPLACE FUNCTION get_origin_package_latest_v3 (
+                    op_ident text,
+                    op_target text
+                 ) RETURNS SETOF origin_packages AS $$
+                    BEGIN
+                        RETURN QUERY SELECT * FROM origin_packages WHERE ident LIKE (op_ident  || '%') AND target = op_target AND yanked = false;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator.migrate("originsrv-v7",
+                     r#"CREATE OR REPLACE FUNCTION get_origin_package_versions_for_origin_v4 (
+                    op_origin text,
+                    op_pkg text
+                 ) RETURNS TABLE(version text, release_count bigint, latest text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT p.partial_ident[3] AS version, COUNT(p.partial_ident[4]) AS release_count, MAX(p.partial_ident[4]) as latest FROM (SELECT regexp_split_to_array(op.ident, '/') AS partial_ident FROM origin_packages op INNER JOIN origins o ON o.id = op.origin_id WHERE o.name = op_origin AND op.name = op_pkg AND op.yanked = false) AS p GROUP BY version ORDER BY version DESC;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator.migrate("originsrv-v7",
+                     r#"CREATE OR REPLACE FUNCTION get_origin_packages_for_origin_v3 (
+                    op_ident text,
+                    op_limit bigint,
+                    op_offset bigint
+                 ) RETURNS TABLE(total_count bigint, ident text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(*) OVER () AS total_count, origin_packages.ident FROM origin_packages WHERE origin_packages.ident LIKE (op_ident  || '%') AND origin_packages.yanked = false
+                          ORDER BY ident DESC
+                          LIMIT op_limit OFFSET op_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator.migrate("originsrv-v7",
+                     r#"CREATE OR REPLACE FUNCTION get_origin_packages_for_origin_distinct_v2 (
+                    op_ident text,
+                    op_limit bigint,
+                    op_offset bigint
+                 ) RETURNS TABLE(total_count bigint, ident text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(p.partial_ident[1] || '/' || p.partial_ident[2]) OVER () AS total_count, p.partial_ident[1] || '/' || p.partial_ident[2] AS ident
+                        FROM (SELECT regexp_split_to_array(op.ident, '/') as partial_ident FROM origin_packages op WHERE op.ident LIKE ('%' || op_ident || '%') AND op.yanked = false) AS p
+                        GROUP BY (p.partial_ident[1] || '/' || p.partial_ident[2])
+                        LIMIT op_limit OFFSET op_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator.migrate("originsrv-v7",
+                     r#"CREATE OR REPLACE FUNCTION search_origin_packages_for_origin_v1 (
+                   op_origin text,
+                   op_query text,
+                   op_limit bigint,
+                   op_offset bigint
+                 ) RETURNS TABLE(total_count bigint, ident text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(*) OVER () AS total_count, origin_packages.ident FROM origins INNER JOIN origin_packages ON origins.id = origin_packages.origin_id WHERE origins.name = op_origin and origin_packages.name LIKE ('%' || op_query || '%') AND origin_packages.yanked = false
+                          ORDER BY ident ASC
+                          LIMIT op_limit OFFSET op_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator.migrate("originsrv-v7",
+                     r#"CREATE OR REPLACE FUNCTION search_all_origin_packages_dynamic_v2 (
+                    op_query text,
+                    op_limit bigint,
+                    op_offset bigint
+                    ) RETURNS TABLE(total_count bigint, ident text) AS $$
+                    DECLARE
+                      schema RECORD;
+                    BEGIN
+                      FOR schema IN EXECUTE
+                        format(
+                          'SELECT schema_name FROM information_schema.schemata WHERE left(schema_name, 6) = %L',
+                          'shard_'
+                        )
+                      LOOP
+                        RETURN QUERY EXECUTE
+                        format('SELECT COUNT(p.partial_ident[1] || %L || p.partial_ident[2]) OVER () AS total_count, p.partial_ident[1] || %L || p.partial_ident[2] AS ident FROM (SELECT regexp_split_to_array(op.ident, %L) as partial_ident FROM %I.origin_packages op WHERE op.ident LIKE (%L || %L || %L) AND op.yanked = false) AS p GROUP BY (p.partial_ident[1] || %L || p.partial_ident[2]) LIMIT %L OFFSET %L', '/', '/', '/', schema.schema_name, '%', op_query, '%', '/', op_limit, op_offset);
+                      END LOOP;
+                    END;
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator.migrate("originsrv-v7",
+                     r#"CREATE OR REPLACE FUNCTION yank_origin_package_v1 (
+                    op_id bigint,
+                    op_yanked bool
+                 ) RETURNS void AS $$
+                    BEGIN
+                        UPDATE origin_packages SET yanked = op_yanked, updated_at = now() WHERE id = op_id;
+                    END
+                    $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator.migrate("originsrv-v7",
+                     r#"CREATE OR REPLACE FUNCTION delete_origin_package_v1 (
+                    op_id bigint
+                 ) RETURNS void AS $$
+                    BEGIN
+                        DELETE FROM origin_channel_packages WHERE package_id = op_id;
+                        DELETE FROM origin_packages WHERE id = op_id;
+                    END
+                    $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator.migrate("originsrv-v7",
+                     r#"CREATE OR REPLACE FUNCTION get_origin_package_dependents_dynamic_v1 (
+                    op_ident text
+                    ) RETURNS TABLE(ident text) AS $$
+                    DECLARE
+                      schema RECORD;
+                    BEGIN
+                      FOR schema IN EXECUTE
+                        format(
+                          'SELECT schema_name FROM information_schema.schemata WHERE left(schema_name, 6) = %L',
+                          'shard_'
+                        )
+                      LOOP
+                        RETURN QUERY EXECUTE
+                        format('SELECT op.ident FROM %I.origin_packages op WHERE strpos(%L || op.tdeps, %L || %L || %L) > 0', schema.schema_name, ':', ':', op_ident, ':');
+                      END LOOP;
+                    END;
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator.migrate(
+        "originsrv-v8",
+        r#"ALTER TABLE origin_packages ADD COLUMN IF NOT EXISTS description text,
+                    ADD COLUMN IF NOT EXISTS licenses text,
+                    ADD COLUMN IF NOT EXISTS upstream_url text,
+                    ADD COLUMN IF NOT EXISTS maintainer text"#,
+    )?;
+    migrator.migrate("originsrv-v8",
+                 r#"CREATE OR REPLACE FUNCTION insert_origin_package_v2 (
+                    op_origin_id bigint,
+                    op_owner_id bigint,
+                    op_name text,
+                    op_ident text,
+                    op_checksum text,
+                    op_manifest text,
+                    op_config text,
+                    op_target text,
+                    op_deps text,
+                    op_tdeps text,
+                    op_exposes text,
+                    op_description text,
+                    op_licenses text,
+                    op_upstream_url text,
+                    op_maintainer text
+                 ) RETURNS SETOF origin_packages AS $$
+                     DECLARE
+                        inserted_package origin_packages;
+                        channel_id bigint;
+                     BEGIN
+                         INSERT INTO origin_packages (origin_id, owner_id, name, ident, checksum, manifest, config, target, deps, tdeps, exposes, description, licenses, upstream_url, maintainer)
+                                VALUES (op_origin_id, op_owner_id, op_name, op_ident, op_checksum, op_manifest, op_config, op_target, op_deps, op_tdeps, op_exposes, op_description, op_licenses, op_upstream_url, op_maintainer)
+                                RETURNING * into inserted_package;
+
+                         SELECT id FROM origin_channels WHERE origin_id = op_origin_id AND name = 'unstable' INTO channel_id;
+                         PERFORM promote_origin_package_v1(channel_id, inserted_package.id);
+
+                         RETURN NEXT inserted_package;
+                         RETURN;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator.migrate("originsrv-v8",
+                     r#"CREATE OR REPLACE FUNCTION search_origin_packages_for_origin_v2 (
+                   op_origin text,
+                   op_query text,
+                   op_license text,
+                   op_limit bigint,
+                   op_offset bigint
+                 ) RETURNS TABLE(total_count bigint, ident text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(*) OVER () AS total_count, origin_packages.ident FROM origins INNER JOIN origin_packages ON origins.id = origin_packages.origin_id WHERE origins.name = op_origin
+                          AND origin_packages.yanked = false
+                          AND (origin_packages.name LIKE ('%' || op_query || '%') OR origin_packages.description ILIKE ('%' || op_query || '%'))
+                          AND (op_license = '' OR strpos(':' || origin_packages.licenses, ':' || op_license || ':') > 0)
+                          ORDER BY ident ASC
+                          LIMIT op_limit OFFSET op_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator.migrate("originsrv-v8",
+                     r#"CREATE OR REPLACE FUNCTION search_all_origin_packages_dynamic_v3 (
+                    op_query text,
+                    op_license text,
+                    op_limit bigint,
+                    op_offset bigint
+                    ) RETURNS TABLE(total_count bigint, ident text) AS $$
+                    DECLARE
+                      schema RECORD;
+                    BEGIN
+                      FOR schema IN EXECUTE
+                        format(
+                          'SELECT schema_name FROM information_schema.schemata WHERE left(schema_name, 6) = %L',
+                          'shard_'
+                        )
+                      LOOP
+                        RETURN QUERY EXECUTE
+                        format('SELECT COUNT(p.partial_ident[1] || %L || p.partial_ident[2]) OVER () AS total_count, p.partial_ident[1] || %L || p.partial_ident[2] AS ident FROM (SELECT regexp_split_to_array(op.ident, %L) as partial_ident FROM %I.origin_packages op WHERE op.yanked = false AND (op.ident LIKE (%L || %L || %L) OR op.description ILIKE (%L || %L || %L)) AND (%L = %L OR strpos(%L || op.licenses, %L || %L || %L) > 0)) AS p GROUP BY (p.partial_ident[1] || %L || p.partial_ident[2]) LIMIT %L OFFSET %L', '/', '/', '/', schema.schema_name, '%', op_query, '%', '%', op_query, '%', op_license, '', ':', ':', op_license, ':', '/', op_limit, op_offset);
+                      END LOOP;
+                    END;
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator.migrate(
+        "originsrv-v9",
+        r#"ALTER TABLE origin_packages DROP CONSTRAINT IF EXISTS origin_packages_ident_key"#,
+    )?;
+    migrator.migrate(
+        "originsrv-v9",
+        r#"ALTER TABLE origin_packages ADD CONSTRAINT origin_packages_ident_target_key UNIQUE (ident, target)"#,
+    )?;
+    migrator.migrate(
+        "originsrv-v9",
+        r#"CREATE OR REPLACE FUNCTION get_origin_package_v2 (
+                    op_ident text,
+                    op_target text
+                 ) RETURNS SETOF origin_packages AS $$
+                    BEGIN
+                        RETURN QUERY SELECT * FROM origin_packages WHERE ident = op_ident
+                          AND (op_target = '' OR target = op_target)
+                          ORDER BY target ASC;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
     Ok(())
 }
@@ -114,5 +114,157 @@ pub fn migrate(migrator: &mut Migrator) -> Result<()> {
                             WHERE id = project_id;
                      END
                  $$ LANGUAGE plpgsql VOLATILE"#)?;
+    // A NULL build_timeout leaves the timeout up to the worker's configuration
+    migrator.migrate(
+        "originsrv-v10",
+        r#"ALTER TABLE origin_projects ADD COLUMN IF NOT EXISTS build_timeout bigint DEFAULT NULL"#,
+    )?;
+    migrator.migrate(
+        "originsrv-v10",
+        r#"CREATE OR REPLACE FUNCTION insert_origin_project_v2 (
+                        project_origin_name text,
+                        project_package_name text,
+                        project_plan_path text,
+                        project_vcs_type text,
+                        project_vcs_data text,
+                        project_owner_id bigint,
+                        project_build_timeout bigint
+                 ) RETURNS SETOF origin_projects AS $$
+                     BEGIN
+                         RETURN QUERY INSERT INTO origin_projects (origin_id,
+                                                      origin_name,
+                                                      package_name,
+                                                      name,
+                                                      plan_path,
+                                                      owner_id,
+                                                      vcs_type,
+                                                      vcs_data,
+                                                      build_timeout)
+                                VALUES (
+                                    (SELECT id FROM origins where name = project_origin_name),
+                                    project_origin_name,
+                                    project_package_name,
+                                    project_origin_name || '/' || project_package_name,
+                                    project_plan_path,
+                                    project_owner_id,
+                                    project_vcs_type,
+                                    project_vcs_data,
+                                    project_build_timeout)
+                                RETURNING *;
+                         RETURN;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+    migrator.migrate("originsrv-v10",
+                     r#"CREATE OR REPLACE FUNCTION update_origin_project_v2 (
+                        project_id bigint,
+                        project_origin_id bigint,
+                        project_package_name text,
+                        project_plan_path text,
+                        project_vcs_type text,
+                        project_vcs_data text,
+                        project_owner_id bigint,
+                        project_build_timeout bigint
+                 ) RETURNS void AS $$
+                     BEGIN
+                        UPDATE origin_projects SET
+                            package_name = project_package_name,
+                            name = (SELECT name FROM origins WHERE id = project_origin_id) || '/' || project_package_name,
+                            plan_path = project_plan_path,
+                            vcs_type = project_vcs_type,
+                            vcs_data = project_vcs_data,
+                            owner_id = project_owner_id,
+                            build_timeout = project_build_timeout,
+                            updated_at = now()
+                            WHERE id = project_id;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    // The secret that signs the push notifications for a project's repository. A NULL secret
+    // means webhooks are not accepted for the project.
+    migrator.migrate(
+        "originsrv-v10",
+        r#"ALTER TABLE origin_projects ADD COLUMN IF NOT EXISTS webhook_secret text DEFAULT NULL"#,
+    )?;
+    migrator.migrate(
+        "originsrv-v10",
+        r#"CREATE OR REPLACE FUNCTION insert_origin_project_v3 (
+                        project_origin_name text,
+                        project_package_name text,
+                        project_plan_path text,
+                        project_vcs_type text,
+                        project_vcs_data text,
+                        project_owner_id bigint,
+                        project_build_timeout bigint,
+                        project_webhook_secret text
+                 ) RETURNS SETOF origin_projects AS $$
+                     BEGIN
+                         RETURN QUERY INSERT INTO origin_projects (origin_id,
+                                                      origin_name,
+                                                      package_name,
+                                                      name,
+                                                      plan_path,
+                                                      owner_id,
+                                                      vcs_type,
+                                                      vcs_data,
+                                                      build_timeout,
+                                                      webhook_secret)
+                                VALUES (
+                                    (SELECT id FROM origins where name = project_origin_name),
+                                    project_origin_name,
+                                    project_package_name,
+                                    project_origin_name || '/' || project_package_name,
+                                    project_plan_path,
+                                    project_owner_id,
+                                    project_vcs_type,
+                                    project_vcs_data,
+                                    project_build_timeout,
+                                    project_webhook_secret)
+                                RETURNING *;
+                         RETURN;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+    // A NULL webhook secret leaves the project's current secret in place
+    migrator.migrate("originsrv-v10",
+                     r#"CREATE OR REPLACE FUNCTION update_origin_project_v3 (
+                        project_id bigint,
+                        project_origin_id bigint,
+                        project_package_name text,
+                        project_plan_path text,
+                        project_vcs_type text,
+                        project_vcs_data text,
+                        project_owner_id bigint,
+                        project_build_timeout bigint,
+                        project_webhook_secret text
+                 ) RETURNS void AS $$
+                     BEGIN
+                        UPDATE origin_projects SET
+                            package_name = project_package_name,
+                            name = (SELECT name FROM origins WHERE id = project_origin_id) || '/' || project_package_name,
+                            plan_path = project_plan_path,
+                            vcs_type = project_vcs_type,
+                            vcs_data = project_vcs_data,
+                            owner_id = project_owner_id,
+                            build_timeout = project_build_timeout,
+                            webhook_secret = COALESCE(project_webhook_secret, webhook_secret),
+                            updated_at = now()
+                            WHERE id = project_id;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator.migrate(
+        "originsrv-v10",
+        r#"CREATE OR REPLACE FUNCTION list_origin_projects_by_repo_v1 (
+                    op_origin_name text,
+                    op_vcs_data text[]
+                 ) RETURNS SETOF origin_projects AS $$
+                    BEGIN
+                        RETURN QUERY SELECT * FROM origin_projects
+                          WHERE origin_name = op_origin_name
+                            AND vcs_data = ANY(op_vcs_data)
+                          ORDER BY name;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
     Ok(())
 }
@@ -367,6 +367,23 @@ pub fn project_get(
     Ok(())
 }
 
+pub fn project_list(
+    req: &mut Envelope,
+    sock: &mut zmq::Socket,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg: proto::OriginProjectListRequest = try!(req.parse_msg());
+    match state.datastore.list_origin_projects_by_repo(&msg) {
+        Ok(ref projects) => try!(req.reply_complete(sock, projects)),
+        Err(err) => {
+            error!("OriginProjectList, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-project-list:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
 pub fn project_update(
     req: &mut Envelope,
     sock: &mut zmq::Socket,
@@ -661,6 +678,57 @@ pub fn origin_package_demote(
     Ok(())
 }
 
+pub fn origin_package_yank(
+    req: &mut Envelope,
+    sock: &mut zmq::Socket,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg: proto::OriginPackageYank = try!(req.parse_msg());
+    match state.datastore.yank_origin_package(&msg) {
+        Ok(()) => try!(req.reply_complete(sock, &net::NetOk::new())),
+        Err(err) => {
+            error!("OriginPackageYank, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-package-yank:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_package_delete(
+    req: &mut Envelope,
+    sock: &mut zmq::Socket,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg: proto::OriginPackageDelete = try!(req.parse_msg());
+    match state.datastore.delete_origin_package(&msg) {
+        Ok(()) => try!(req.reply_complete(sock, &net::NetOk::new())),
+        Err(err) => {
+            error!("OriginPackageDelete, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-package-delete:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_package_dependents_get(
+    req: &mut Envelope,
+    sock: &mut zmq::Socket,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg: proto::OriginPackageDependentsGet = try!(req.parse_msg());
+    match state.datastore.list_origin_package_dependents(&msg) {
+        Ok(ref oplr) => try!(req.reply_complete(sock, oplr)),
+        Err(err) => {
+            error!("OriginPackageDependentsGet, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-package-dependents-get:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
 pub fn origin_package_unique_list(
     req: &mut Envelope,
     sock: &mut zmq::Socket,
@@ -90,6 +90,7 @@ impl Dispatcher for Worker {
             "OriginProjectCreate" => handlers::project_create(message, sock, state),
             "OriginProjectDelete" => handlers::project_delete(message, sock, state),
             "OriginProjectGet" => handlers::project_get(message, sock, state),
+            "OriginProjectListRequest" => handlers::project_list(message, sock, state),
             "OriginProjectUpdate" => handlers::project_update(message, sock, state),
             "OriginPackageCreate" => handlers::origin_package_create(message, sock, state),
             "OriginPackageGet" => handlers::origin_package_get(message, sock, state),
@@ -98,12 +99,17 @@ impl Dispatcher for Worker {
             "OriginPackageVersionListRequest" => {
                 handlers::origin_package_version_list(message, sock, state)
             }
+            "OriginPackageDelete" => handlers::origin_package_delete(message, sock, state),
             "OriginPackageDemote" => handlers::origin_package_demote(message, sock, state),
+            "OriginPackageDependentsGet" => {
+                handlers::origin_package_dependents_get(message, sock, state)
+            }
             "OriginPackagePromote" => handlers::origin_package_promote(message, sock, state),
             "OriginPackageUniqueListRequest" => {
                 handlers::origin_package_unique_list(message, sock, state)
             }
             "OriginPackageSearchRequest" => handlers::origin_package_search(message, sock, state),
+            "OriginPackageYank" => handlers::origin_package_yank(message, sock, state),
             "OriginChannelCreate" => handlers::origin_channel_create(message, sock, state),
             "OriginChannelDelete" => handlers::origin_channel_delete(message, sock, state),
             "OriginChannelGet" => handlers::origin_channel_get(message, sock, state),
@@ -760,6 +760,62 @@ fn update_origin_project() {
     );
 }
 
+#[test]
+fn list_origin_projects_by_repo() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let neurosis = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .expect("Should return the origin");
+
+    for &(package, repo) in &[
+        ("zeal", "https://github.com/habitat-sh/core-plans.git"),
+        ("sepultura", "https://github.com/habitat-sh/core-plans.git"),
+        ("slayer", "https://github.com/habitat-sh/habitat.git"),
+    ]
+    {
+        let mut op = originsrv::OriginProject::new();
+        op.set_origin_name(String::from(neurosis.get_name()));
+        op.set_origin_id(neurosis.get_id());
+        op.set_package_name(String::from(package));
+        op.set_plan_path(format!("{}/plan.sh", package));
+        op.set_vcs_type(String::from("git"));
+        op.set_vcs_data(String::from(repo));
+        op.set_owner_id(1);
+        op.set_webhook_secret(String::from("sekrit"));
+        let mut opc = originsrv::OriginProjectCreate::new();
+        opc.set_project(op);
+        ds.create_origin_project(&opc).expect(
+            "Failed to create origin project",
+        );
+    }
+
+    let mut oplr = originsrv::OriginProjectListRequest::new();
+    oplr.set_origin(String::from("neurosis"));
+    oplr.set_vcs_data(protobuf::RepeatedField::from_vec(vec![
+        String::from("https://github.com/habitat-sh/core-plans"),
+        String::from("https://github.com/habitat-sh/core-plans.git"),
+    ]));
+    let response = ds.list_origin_projects_by_repo(&oplr).expect(
+        "Failed to list origin projects",
+    );
+    assert_eq!(response.get_origin_id(), neurosis.get_id());
+    let projects = response.get_projects();
+    assert_eq!(projects.len(), 2);
+    assert_eq!(projects[0].get_name(), "neurosis/sepultura");
+    assert_eq!(projects[1].get_name(), "neurosis/zeal");
+    assert_eq!(projects[0].get_webhook_secret(), "sekrit");
+
+    oplr.set_origin(String::from("sepultura"));
+    let response = ds.list_origin_projects_by_repo(&oplr).expect(
+        "Failed to list origin projects",
+    );
+    assert!(response.get_projects().is_empty());
+}
+
 #[test]
 fn create_origin_package() {
     let ds = datastore_test!(DataStore);
@@ -878,6 +934,12 @@ fn get_origin_package() {
     package.set_config("config".to_string());
     package.set_target("x86_64-linux".to_string());
     package.set_exposes(vec![1, 2]);
+    package.set_description("Certificate authorities".to_string());
+    package.set_licenses(protobuf::RepeatedField::from_vec(
+        vec!["MPL-2.0".to_string(), "GPL-2.0".to_string()],
+    ));
+    package.set_upstream_url("https://curl.haxx.se".to_string());
+    package.set_maintainer("The Habitat Maintainers".to_string());
     ds.create_origin_package(&package).expect(
         "Failed to create origin package",
     );
@@ -898,6 +960,74 @@ fn get_origin_package() {
     assert_eq!(result.get_exposes().to_vec(), vec![1, 2]);
     assert_eq!(result.get_deps().to_vec(), dep_idents.to_vec());
     assert_eq!(result.get_tdeps().to_vec(), tdep_idents.to_vec());
+    assert_eq!(result.get_description(), "Certificate authorities");
+    assert_eq!(
+        result.get_licenses().to_vec(),
+        vec!["MPL-2.0".to_string(), "GPL-2.0".to_string()]
+    );
+    assert_eq!(result.get_upstream_url(), "https://curl.haxx.se");
+    assert_eq!(result.get_maintainer(), "The Habitat Maintainers");
+}
+
+#[test]
+fn get_origin_package_for_target() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let origin = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let mut ident = originsrv::OriginPackageIdent::new();
+    ident.set_origin("core".to_string());
+    ident.set_name("cacerts".to_string());
+    ident.set_version("2017.01.17".to_string());
+    ident.set_release("20170209064044".to_string());
+
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(origin.get_id());
+    package.set_ident(ident.clone());
+    package.set_checksum("linux".to_string());
+    package.set_manifest("manifest".to_string());
+    package.set_config("config".to_string());
+    package.set_target("x86_64-linux".to_string());
+    ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+    package.set_checksum("windows".to_string());
+    package.set_target("x86_64-windows".to_string());
+    ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+    assert!(ds.create_origin_package(&package).is_err());
+
+    let mut package_get = originsrv::OriginPackageGet::new();
+    package_get.set_ident(ident.clone());
+    package_get.set_target("x86_64-windows".to_string());
+    let result = ds.get_origin_package(&package_get)
+        .expect("Failed to get origin package")
+        .unwrap();
+    assert_eq!(result.get_target(), "x86_64-windows");
+    assert_eq!(result.get_checksum(), "windows");
+
+    package_get.set_target("x86_64-linux".to_string());
+    let result = ds.get_origin_package(&package_get)
+        .expect("Failed to get origin package")
+        .unwrap();
+    assert_eq!(result.get_target(), "x86_64-linux");
+    assert_eq!(result.get_checksum(), "linux");
+
+    let mut channel_get = originsrv::OriginChannelPackageGet::new();
+    channel_get.set_name("unstable".to_string());
+    channel_get.set_ident(ident);
+    channel_get.set_target("x86_64-windows".to_string());
+    let result = ds.get_origin_channel_package(&channel_get)
+        .expect("Failed to get origin channel package")
+        .unwrap();
+    assert_eq!(result.get_checksum(), "windows");
 }
 
 #[test]
@@ -976,6 +1106,211 @@ fn get_latest_package() {
     assert!(result3.is_none());
 }
 
+#[test]
+fn yanked_package_is_not_latest() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let origin = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let mut ident1 = originsrv::OriginPackageIdent::new();
+    ident1.set_origin("core".to_string());
+    ident1.set_name("cacerts".to_string());
+    ident1.set_version("2017.01.17".to_string());
+    ident1.set_release("20170209064044".to_string());
+
+    let mut ident2 = originsrv::OriginPackageIdent::new();
+    ident2.set_origin("core".to_string());
+    ident2.set_name("cacerts".to_string());
+    ident2.set_version("2017.01.18".to_string());
+    ident2.set_release("20170209064044".to_string());
+
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(origin.get_id());
+    package.set_ident(ident1.clone());
+    package.set_checksum("checksum".to_string());
+    package.set_manifest("manifest".to_string());
+    package.set_config("config".to_string());
+    package.set_target("x86_64-linux".to_string());
+    package.set_exposes(vec![1, 2]);
+    let kept = ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+
+    package.set_ident(ident2.clone());
+    let yanked = ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+    assert!(!yanked.get_yanked());
+
+    let mut opy = originsrv::OriginPackageYank::new();
+    opy.set_package_id(yanked.get_id());
+    opy.set_ident(ident2.clone());
+    opy.set_yanked(true);
+    ds.yank_origin_package(&opy).expect("Could not yank package");
+
+    let mut package_get = originsrv::OriginPackageLatestGet::new();
+    let mut search_ident = originsrv::OriginPackageIdent::new();
+    search_ident.set_origin("core".to_string());
+    search_ident.set_name("cacerts".to_string());
+    package_get.set_ident(search_ident.clone());
+    package_get.set_target("x86_64-linux".to_string());
+    let latest = ds.get_origin_package_latest(&package_get.clone()).unwrap();
+    assert_eq!(latest.unwrap().to_string(), ident1.to_string());
+
+    let mut opl = originsrv::OriginPackageListRequest::new();
+    opl.set_ident(search_ident.clone());
+    opl.set_start(0);
+    opl.set_stop(20);
+    let list = ds.list_origin_package_for_origin(&opl).unwrap();
+    assert_eq!(list.get_count(), 1);
+    assert_eq!(list.get_idents()[0].to_string(), ident1.to_string());
+
+    // A yanked package can still be fetched by its fully qualified identifier
+    let mut opg = originsrv::OriginPackageGet::new();
+    opg.set_ident(ident2.clone());
+    let fetched = ds.get_origin_package(&opg).unwrap().unwrap();
+    assert!(fetched.get_yanked());
+
+    // Searches leave out yanked releases, and packages with only yanked releases
+    let mut ops = originsrv::OriginPackageSearchRequest::new();
+    ops.set_origin("core".to_string());
+    ops.set_query("cacerts".to_string());
+    ops.set_start(0);
+    ops.set_stop(20);
+    let result = ds.search_origin_package_for_origin(&ops.clone()).unwrap();
+    assert_eq!(result.get_count(), 1);
+    assert_eq!(result.get_idents()[0].to_string(), ident1.to_string());
+
+    ops.set_query("core/cacerts".to_string());
+    ops.set_distinct(true);
+    let result = ds.search_origin_package_for_origin(&ops.clone()).unwrap();
+    assert_eq!(result.get_count(), 1);
+    let mut opy_kept = originsrv::OriginPackageYank::new();
+    opy_kept.set_package_id(kept.get_id());
+    opy_kept.set_ident(ident1.clone());
+    opy_kept.set_yanked(true);
+    ds.yank_origin_package(&opy_kept).expect(
+        "Could not yank package",
+    );
+    let result = ds.search_origin_package_for_origin(&ops.clone()).unwrap();
+    assert!(result.get_idents().is_empty());
+    opy_kept.set_yanked(false);
+    ds.yank_origin_package(&opy_kept).expect(
+        "Could not unyank package",
+    );
+
+    opy.set_yanked(false);
+    ds.yank_origin_package(&opy).expect("Could not unyank package");
+    let latest = ds.get_origin_package_latest(&package_get.clone()).unwrap();
+    assert_eq!(latest.unwrap().to_string(), ident2.to_string());
+}
+
+#[test]
+fn delete_origin_package() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let origin = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let mut ident = originsrv::OriginPackageIdent::new();
+    ident.set_origin("core".to_string());
+    ident.set_name("cacerts".to_string());
+    ident.set_version("2017.01.17".to_string());
+    ident.set_release("20170209064044".to_string());
+
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(origin.get_id());
+    package.set_ident(ident.clone());
+    package.set_checksum("checksum".to_string());
+    package.set_manifest("manifest".to_string());
+    package.set_config("config".to_string());
+    package.set_target("x86_64-linux".to_string());
+    let package = ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+
+    let mut opd = originsrv::OriginPackageDelete::new();
+    opd.set_package_id(package.get_id());
+    opd.set_ident(ident.clone());
+    ds.delete_origin_package(&opd).expect(
+        "Could not delete package",
+    );
+
+    let mut opg = originsrv::OriginPackageGet::new();
+    opg.set_ident(ident);
+    assert!(ds.get_origin_package(&opg).unwrap().is_none());
+}
+
+#[test]
+fn list_origin_package_dependents() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let core = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+    origin.set_name(String::from("neurosis"));
+    let neurosis = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let glibc = originsrv::OriginPackageIdent::from_str("core/glibc/2.22/20170513201042")
+        .unwrap();
+    let redis = originsrv::OriginPackageIdent::from_str("neurosis/redis/3.2.4/20170514150022")
+        .unwrap();
+    let curl = originsrv::OriginPackageIdent::from_str("core/curl/7.54.0/20170513215502")
+        .unwrap();
+
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_checksum("checksum".to_string());
+    package.set_manifest("manifest".to_string());
+    package.set_config("config".to_string());
+    package.set_target("x86_64-linux".to_string());
+
+    package.set_origin_id(core.get_id());
+    package.set_ident(glibc.clone());
+    ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+    package.set_ident(curl.clone());
+    ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+
+    let mut tdeps = protobuf::RepeatedField::new();
+    tdeps.push(glibc.clone());
+    package.set_origin_id(neurosis.get_id());
+    package.set_ident(redis.clone());
+    package.set_tdeps(tdeps);
+    ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+
+    let mut opdg = originsrv::OriginPackageDependentsGet::new();
+    opdg.set_ident(glibc);
+    let dependents = ds.list_origin_package_dependents(&opdg).unwrap();
+    assert_eq!(dependents.get_count(), 1);
+    assert_eq!(dependents.get_idents()[0].to_string(), redis.to_string());
+
+    opdg.set_ident(curl);
+    let dependents = ds.list_origin_package_dependents(&opdg).unwrap();
+    assert_eq!(dependents.get_count(), 0);
+}
+
 #[test]
 fn list_origin_package_versions_for_origin() {
     let ds = datastore_test!(DataStore);
@@ -1472,6 +1807,94 @@ fn search_origin_package_for_origin() {
     assert_eq!(pkg5.to_string(), "josh/red_dog");
 }
 
+#[test]
+fn search_origin_package_by_description_and_license() {
+    let ds = datastore_test!(DataStore);
+
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let origin = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let mut ident1 = originsrv::OriginPackageIdent::new();
+    ident1.set_origin("core".to_string());
+    ident1.set_name("redis".to_string());
+    ident1.set_version("3.2.4".to_string());
+    ident1.set_release("20170514150022".to_string());
+
+    let mut ident2 = originsrv::OriginPackageIdent::new();
+    ident2.set_origin("core".to_string());
+    ident2.set_name("mysql".to_string());
+    ident2.set_version("5.7.17".to_string());
+    ident2.set_release("20170514150022".to_string());
+
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(origin.get_id());
+    package.set_ident(ident1.clone());
+    package.set_checksum("checksum".to_string());
+    package.set_manifest("manifest".to_string());
+    package.set_config("config".to_string());
+    package.set_target("x86_64-linux".to_string());
+    package.set_description("Persistent key-value database".to_string());
+    package.set_licenses(protobuf::RepeatedField::from_vec(
+        vec!["BSD-3-Clause".to_string()],
+    ));
+    ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+
+    package.set_ident(ident2.clone());
+    package.set_description("Relational Database".to_string());
+    package.set_licenses(protobuf::RepeatedField::from_vec(
+        vec!["GPL-2.0".to_string(), "LGPL-2.1".to_string()],
+    ));
+    ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+
+    let mut ops = originsrv::OriginPackageSearchRequest::new();
+    ops.set_origin("core".to_string());
+    ops.set_query("database".to_string());
+    ops.set_start(0);
+    ops.set_stop(20);
+    let result = ds.search_origin_package_for_origin(&ops.clone()).expect(
+        "Could not get the packages from the database",
+    );
+    assert_eq!(result.get_count(), 2);
+
+    ops.set_license("GPL-2.0".to_string());
+    let result = ds.search_origin_package_for_origin(&ops.clone()).expect(
+        "Could not get the packages from the database",
+    );
+    assert_eq!(result.get_count(), 1);
+    assert_eq!(
+        result.get_idents().iter().nth(0).unwrap().to_string(),
+        ident2.to_string()
+    );
+
+    ops.set_license("GPL".to_string());
+    let result = ds.search_origin_package_for_origin(&ops.clone()).expect(
+        "Could not get the packages from the database",
+    );
+    assert_eq!(result.get_count(), 0);
+
+    ops.set_query("core/".to_string());
+    ops.set_license("BSD-3-Clause".to_string());
+    ops.set_distinct(true);
+    let result = ds.search_origin_package_for_origin(&ops).expect(
+        "Could not get the packages from the database",
+    );
+    assert_eq!(result.get_idents().len(), 1);
+    assert_eq!(
+        result.get_idents().iter().nth(0).unwrap().to_string(),
+        "core/redis"
+    );
+}
+
 #[test]
 fn create_origin_channel() {
     let ds = datastore_test!(DataStore);
@@ -34,6 +34,9 @@ impl Into<Job> for JobSpec {
         job.set_owner_id(self.get_owner_id());
         job.set_state(JobState::default());
         job.set_project(self.take_project());
+        if self.has_vcs_ref() {
+            job.set_vcs_ref(self.take_vcs_ref());
+        }
         job
     }
 }
@@ -62,6 +65,14 @@ impl Routable for JobGet {
     }
 }
 
+impl Routable for JobCancel {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_id()))
+    }
+}
+
 impl Routable for Job {
     type H = InstaId;
 
@@ -139,6 +150,13 @@ impl Serialize for Job {
             )?;
         }
 
+        if self.has_vcs_ref() {
+            strukt.serialize_field("vcs_ref", self.get_vcs_ref())?;
+        }
+        if self.has_vcs_sha() {
+            strukt.serialize_field("vcs_sha", self.get_vcs_sha())?;
+        }
+
         strukt.serialize_field("state", &self.get_state())?;
 
         if self.has_error() {
@@ -159,20 +177,20 @@ impl Serialize for ProjectJobsGetResponse {
     }
 }
 
+lazy_static! {
+    // https://github.com/chalk/ansi-regex/blob/master/index.js
+    static ref ANSI_RE: Regex = Regex::new(
+        r"[\x1b\x9b][[()#;?]*(?:[0-9]{1,4}(?:;[0-9]{0,4})*)?[0-9A-PRZcf-nqry=><]")
+        .unwrap();
+}
+
 impl JobLog {
     /// Strip any ANSI control codes from the contents of the log
     /// chunk. Useful mainly for removing color codes.
     pub fn strip_ansi(&mut self) {
-        lazy_static! {
-            // https://github.com/chalk/ansi-regex/blob/master/index.js
-            static ref RE: Regex = Regex::new(
-                r"[\x1b\x9b][[()#;?]*(?:[0-9]{1,4}(?:;[0-9]{0,4})*)?[0-9A-PRZcf-nqry=><]")
-                .unwrap();
-        }
-
         let mut stripped = RepeatedField::new();
         for line in self.get_content() {
-            let after = RE.replace_all(line, "");
+            let after = ANSI_RE.replace_all(line, "");
             stripped.push(after);
         }
 
@@ -180,6 +198,14 @@ impl JobLog {
     }
 }
 
+impl JobLogChunk {
+    /// Strip any ANSI control codes from the content of the chunk.
+    pub fn strip_ansi(&mut self) {
+        let stripped = ANSI_RE.replace_all(self.get_content(), "").to_string();
+        self.set_content(stripped);
+    }
+}
+
 impl Serialize for JobLog {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
     where
@@ -212,6 +238,8 @@ impl Serialize for JobState {
             3 => serializer.serialize_str("Rejected"),
             4 => serializer.serialize_str("Failed"),
             5 => serializer.serialize_str("Dispatched"),
+            6 => serializer.serialize_str("CancelPending"),
+            7 => serializer.serialize_str("Canceled"),
             _ => panic!("Unexpected enum value"),
         }
     }
@@ -285,4 +313,18 @@ mod tests {
         assert_eq!(stripped_lines, expected);
     }
 
+    #[test]
+    fn test_chunk_ansi_stripping() {
+        let mut chunk = JobLogChunk::new();
+        chunk.set_job_id(1);
+        chunk.set_seq(1);
+        chunk.set_content(
+            "[1;33m» Installing core/hab-backline[0m\n".to_string(),
+        );
+
+        chunk.strip_ansi();
+
+        assert_eq!(chunk.get_content(), "» Installing core/hab-backline\n");
+    }
+
 }
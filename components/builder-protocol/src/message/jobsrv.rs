@@ -314,6 +314,8 @@ pub struct Job {
     build_finished_at: ::protobuf::SingularField<::std::string::String>,
     package_ident: ::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent>,
     is_archived: ::std::option::Option<bool>,
+    vcs_ref: ::protobuf::SingularField<::std::string::String>,
+    vcs_sha: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -699,6 +701,94 @@ impl Job {
     fn mut_is_archived_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
         &mut self.is_archived
     }
+
+    // optional string vcs_ref = 12;
+
+    pub fn clear_vcs_ref(&mut self) {
+        self.vcs_ref.clear();
+    }
+
+    pub fn has_vcs_ref(&self) -> bool {
+        self.vcs_ref.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vcs_ref(&mut self, v: ::std::string::String) {
+        self.vcs_ref = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vcs_ref(&mut self) -> &mut ::std::string::String {
+        if self.vcs_ref.is_none() {
+            self.vcs_ref.set_default();
+        }
+        self.vcs_ref.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_vcs_ref(&mut self) -> ::std::string::String {
+        self.vcs_ref.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_vcs_ref(&self) -> &str {
+        match self.vcs_ref.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_vcs_ref_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.vcs_ref
+    }
+
+    fn mut_vcs_ref_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.vcs_ref
+    }
+
+    // optional string vcs_sha = 13;
+
+    pub fn clear_vcs_sha(&mut self) {
+        self.vcs_sha.clear();
+    }
+
+    pub fn has_vcs_sha(&self) -> bool {
+        self.vcs_sha.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vcs_sha(&mut self, v: ::std::string::String) {
+        self.vcs_sha = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vcs_sha(&mut self) -> &mut ::std::string::String {
+        if self.vcs_sha.is_none() {
+            self.vcs_sha.set_default();
+        }
+        self.vcs_sha.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_vcs_sha(&mut self) -> ::std::string::String {
+        self.vcs_sha.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_vcs_sha(&self) -> &str {
+        match self.vcs_sha.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_vcs_sha_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.vcs_sha
+    }
+
+    fn mut_vcs_sha_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.vcs_sha
+    }
 }
 
 impl ::protobuf::Message for Job {
@@ -771,6 +861,12 @@ impl ::protobuf::Message for Job {
                     let tmp = is.read_bool()?;
                     self.is_archived = ::std::option::Option::Some(tmp);
                 },
+                12 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.vcs_ref)?;
+                },
+                13 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.vcs_sha)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -816,6 +912,12 @@ impl ::protobuf::Message for Job {
         if let Some(v) = self.is_archived {
             my_size += 2;
         }
+        if let Some(ref v) = self.vcs_ref.as_ref() {
+            my_size += ::protobuf::rt::string_size(12, &v);
+        }
+        if let Some(ref v) = self.vcs_sha.as_ref() {
+            my_size += ::protobuf::rt::string_size(13, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -858,6 +960,12 @@ impl ::protobuf::Message for Job {
         if let Some(v) = self.is_archived {
             os.write_bool(11, v)?;
         }
+        if let Some(ref v) = self.vcs_ref.as_ref() {
+            os.write_string(12, &v)?;
+        }
+        if let Some(ref v) = self.vcs_sha.as_ref() {
+            os.write_string(13, &v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -952,6 +1060,16 @@ impl ::protobuf::MessageStatic for Job {
                     Job::get_is_archived_for_reflect,
                     Job::mut_is_archived_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "vcs_ref",
+                    Job::get_vcs_ref_for_reflect,
+                    Job::mut_vcs_ref_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "vcs_sha",
+                    Job::get_vcs_sha_for_reflect,
+                    Job::mut_vcs_sha_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Job>(
                     "Job",
                     fields,
@@ -974,6 +1092,8 @@ impl ::protobuf::Clear for Job {
         self.clear_build_finished_at();
         self.clear_package_ident();
         self.clear_is_archived();
+        self.clear_vcs_ref();
+        self.clear_vcs_sha();
         self.unknown_fields.clear();
     }
 }
@@ -1163,11 +1283,232 @@ impl ::protobuf::reflect::ProtobufValue for JobGet {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct JobCancel {
+    // message fields
+    id: ::std::option::Option<u64>,
+    requestor_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobCancel {}
+
+impl JobCancel {
+    pub fn new() -> JobCancel {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobCancel {
+        static mut instance: ::protobuf::lazy::Lazy<JobCancel> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobCancel,
+        };
+        unsafe {
+            instance.get(JobCancel::new)
+        }
+    }
+
+    // optional uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
+    }
+
+    // optional uint64 requestor_id = 2;
+
+    pub fn clear_requestor_id(&mut self) {
+        self.requestor_id = ::std::option::Option::None;
+    }
+
+    pub fn has_requestor_id(&self) -> bool {
+        self.requestor_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requestor_id(&mut self, v: u64) {
+        self.requestor_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_requestor_id(&self) -> u64 {
+        self.requestor_id.unwrap_or(0)
+    }
+
+    fn get_requestor_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.requestor_id
+    }
+
+    fn mut_requestor_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.requestor_id
+    }
+}
+
+impl ::protobuf::Message for JobCancel {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.requestor_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.requestor_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.requestor_id {
+            os.write_uint64(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobCancel {
+    fn new() -> JobCancel {
+        JobCancel::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobCancel>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    JobCancel::get_id_for_reflect,
+                    JobCancel::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "requestor_id",
+                    JobCancel::get_requestor_id_for_reflect,
+                    JobCancel::mut_requestor_id_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobCancel>(
+                    "JobCancel",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobCancel {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_requestor_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobCancel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobCancel {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct JobSpec {
     // message fields
     owner_id: ::std::option::Option<u64>,
     project: ::protobuf::SingularPtrField<super::originsrv::OriginProject>,
+    vcs_ref: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -1258,6 +1599,50 @@ impl JobSpec {
     fn mut_project_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::originsrv::OriginProject> {
         &mut self.project
     }
+
+    // optional string vcs_ref = 3;
+
+    pub fn clear_vcs_ref(&mut self) {
+        self.vcs_ref.clear();
+    }
+
+    pub fn has_vcs_ref(&self) -> bool {
+        self.vcs_ref.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vcs_ref(&mut self, v: ::std::string::String) {
+        self.vcs_ref = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vcs_ref(&mut self) -> &mut ::std::string::String {
+        if self.vcs_ref.is_none() {
+            self.vcs_ref.set_default();
+        }
+        self.vcs_ref.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_vcs_ref(&mut self) -> ::std::string::String {
+        self.vcs_ref.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_vcs_ref(&self) -> &str {
+        match self.vcs_ref.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_vcs_ref_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.vcs_ref
+    }
+
+    fn mut_vcs_ref_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.vcs_ref
+    }
 }
 
 impl ::protobuf::Message for JobSpec {
@@ -1284,6 +1669,9 @@ impl ::protobuf::Message for JobSpec {
                 2 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.project)?;
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.vcs_ref)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1303,6 +1691,9 @@ impl ::protobuf::Message for JobSpec {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if let Some(ref v) = self.vcs_ref.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1317,6 +1708,9 @@ impl ::protobuf::Message for JobSpec {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if let Some(ref v) = self.vcs_ref.as_ref() {
+            os.write_string(3, &v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1371,6 +1765,11 @@ impl ::protobuf::MessageStatic for JobSpec {
                     JobSpec::get_project_for_reflect,
                     JobSpec::mut_project_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "vcs_ref",
+                    JobSpec::get_vcs_ref_for_reflect,
+                    JobSpec::mut_vcs_ref_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<JobSpec>(
                     "JobSpec",
                     fields,
@@ -1385,6 +1784,7 @@ impl ::protobuf::Clear for JobSpec {
     fn clear(&mut self) {
         self.clear_owner_id();
         self.clear_project();
+        self.clear_vcs_ref();
         self.unknown_fields.clear();
     }
 }
@@ -2868,6 +3268,8 @@ pub enum JobState {
     Rejected = 3,
     Failed = 4,
     Dispatched = 5,
+    CancelPending = 6,
+    Canceled = 7,
 }
 
 impl ::protobuf::ProtobufEnum for JobState {
@@ -2883,6 +3285,8 @@ impl ::protobuf::ProtobufEnum for JobState {
             3 => ::std::option::Option::Some(JobState::Rejected),
             4 => ::std::option::Option::Some(JobState::Failed),
             5 => ::std::option::Option::Some(JobState::Dispatched),
+            6 => ::std::option::Option::Some(JobState::CancelPending),
+            7 => ::std::option::Option::Some(JobState::Canceled),
             _ => ::std::option::Option::None
         }
     }
@@ -2895,6 +3299,8 @@ impl ::protobuf::ProtobufEnum for JobState {
             JobState::Rejected,
             JobState::Failed,
             JobState::Dispatched,
+            JobState::CancelPending,
+            JobState::Canceled,
         ];
         values
     }
@@ -2926,7 +3332,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x1a\x19protocols/originsrv.proto\"n\n\tHeartbeat\x12\x1a\n\x08endpoint\
     \x18\x01\x20\x01(\tR\x08endpoint\x12\x1a\n\x02os\x18\x02\x20\x01(\x0e2\n\
     .jobsrv.OsR\x02os\x12)\n\x05state\x18\x03\x20\x01(\x0e2\x13.jobsrv.Worke\
-    rStateR\x05state\"\x9a\x03\n\x03Job\x12\x0e\n\x02id\x18\x01\x20\x01(\x04\
+    rStateR\x05state\"\xcc\x03\n\x03Job\x12\x0e\n\x02id\x18\x01\x20\x01(\x04\
     R\x02id\x12\x19\n\x08owner_id\x18\x02\x20\x01(\x04R\x07ownerId\x12&\n\
     \x05state\x18\x03\x20\x01(\x0e2\x10.jobsrv.JobStateR\x05state\x122\n\x07\
     project\x18\x04\x20\x01(\x0b2\x18.originsrv.OriginProjectR\x07project\
@@ -2935,193 +3341,224 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x18\x07\x20\x01(\tR\x0ebuildStartedAt\x12*\n\x11build_finished_at\x18\
     \x08\x20\x01(\tR\x0fbuildFinishedAt\x12B\n\rpackage_ident\x18\t\x20\x01(\
     \x0b2\x1d.originsrv.OriginPackageIdentR\x0cpackageIdent\x12\x1f\n\x0bis_\
-    archived\x18\x0b\x20\x01(\x08R\nisArchivedJ\x04\x08\n\x10\x0bR\x07log_ur\
-    l\"\x18\n\x06JobGet\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\"X\n\x07\
-    JobSpec\x12\x19\n\x08owner_id\x18\x01\x20\x01(\x04R\x07ownerId\x122\n\
-    \x07project\x18\x02\x20\x01(\x0b2\x18.originsrv.OriginProjectR\x07projec\
-    t\"$\n\x0eProjectJobsGet\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\"\
-    9\n\x16ProjectJobsGetResponse\x12\x1f\n\x04jobs\x18\x01\x20\x03(\x0b2\
-    \x0b.jobsrv.JobR\x04jobs\"P\n\x0bJobLogChunk\x12\x15\n\x06job_id\x18\x01\
-    \x20\x01(\x04R\x05jobId\x12\x10\n\x03seq\x18\x02\x20\x01(\x04R\x03seq\
-    \x12\x18\n\x07content\x18\x03\x20\x01(\tR\x07content\"'\n\x0eJobLogCompl\
-    ete\x12\x15\n\x06job_id\x18\x01\x20\x01(\x04R\x05jobId\"1\n\tJobLogGet\
-    \x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\x14\n\x05start\x18\x02\
-    \x20\x01(\x04R\x05start\"m\n\x06JobLog\x12\x14\n\x05start\x18\x01\x20\
-    \x01(\x04R\x05start\x12\x12\n\x04stop\x18\x02\x20\x01(\x04R\x04stop\x12\
-    \x18\n\x07content\x18\x03\x20\x03(\tR\x07content\x12\x1f\n\x0bis_complet\
-    e\x18\x04\x20\x01(\x08R\nisComplete*(\n\x02Os\x12\t\n\x05Linux\x10\x01\
-    \x12\n\n\x06Darwin\x10\x02\x12\x0b\n\x07Windows\x10\x03*\"\n\x0bWorkerSt\
-    ate\x12\t\n\x05Ready\x10\0\x12\x08\n\x04Busy\x10\x01*_\n\x08JobState\x12\
-    \x0b\n\x07Pending\x10\0\x12\x0e\n\nProcessing\x10\x01\x12\x0c\n\x08Compl\
-    ete\x10\x02\x12\x0c\n\x08Rejected\x10\x03\x12\n\n\x06Failed\x10\x04\x12\
-    \x0e\n\nDispatched\x10\x05J\x87\"\n\x06\x12\x04\0\0t\x01\n\x08\n\x01\x02\
-    \x12\x03\0\x08\x0e\n\t\n\x02\x03\0\x12\x03\x01\x07\x1c\n\t\n\x02\x03\x01\
-    \x12\x03\x02\x07\"\n\n\n\x02\x05\0\x12\x04\x04\0\x08\x01\n\n\n\x03\x05\0\
-    \x01\x12\x03\x04\x05\x07\n\x0b\n\x04\x05\0\x02\0\x12\x03\x05\x02\x0c\n\
-    \x0c\n\x05\x05\0\x02\0\x01\x12\x03\x05\x02\x07\n\x0c\n\x05\x05\0\x02\0\
-    \x02\x12\x03\x05\n\x0b\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x06\x02\r\n\x0c\
-    \n\x05\x05\0\x02\x01\x01\x12\x03\x06\x02\x08\n\x0c\n\x05\x05\0\x02\x01\
-    \x02\x12\x03\x06\x0b\x0c\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x07\x02\x0e\n\
-    \x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x07\x02\t\n\x0c\n\x05\x05\0\x02\x02\
-    \x02\x12\x03\x07\x0c\r\n\n\n\x02\x05\x01\x12\x04\n\0\r\x01\n\n\n\x03\x05\
-    \x01\x01\x12\x03\n\x05\x10\n\x0b\n\x04\x05\x01\x02\0\x12\x03\x0b\x02\x0c\
-    \n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x0b\x02\x07\n\x0c\n\x05\x05\x01\
-    \x02\0\x02\x12\x03\x0b\n\x0b\n\x0b\n\x04\x05\x01\x02\x01\x12\x03\x0c\x02\
-    \x0b\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\x0c\x02\x06\n\x0c\n\x05\x05\
-    \x01\x02\x01\x02\x12\x03\x0c\t\n\n\n\n\x02\x05\x02\x12\x04\x0f\0\x16\x01\
-    \n\n\n\x03\x05\x02\x01\x12\x03\x0f\x05\r\n\x0b\n\x04\x05\x02\x02\0\x12\
-    \x03\x10\x02\x0e\n\x0c\n\x05\x05\x02\x02\0\x01\x12\x03\x10\x02\t\n\x0c\n\
-    \x05\x05\x02\x02\0\x02\x12\x03\x10\x0c\r\n\x0b\n\x04\x05\x02\x02\x01\x12\
-    \x03\x11\x02\x11\n\x0c\n\x05\x05\x02\x02\x01\x01\x12\x03\x11\x02\x0c\n\
-    \x0c\n\x05\x05\x02\x02\x01\x02\x12\x03\x11\x0f\x10\n\x0b\n\x04\x05\x02\
-    \x02\x02\x12\x03\x12\x02\x0f\n\x0c\n\x05\x05\x02\x02\x02\x01\x12\x03\x12\
-    \x02\n\n\x0c\n\x05\x05\x02\x02\x02\x02\x12\x03\x12\r\x0e\n\x0b\n\x04\x05\
-    \x02\x02\x03\x12\x03\x13\x02\x0f\n\x0c\n\x05\x05\x02\x02\x03\x01\x12\x03\
-    \x13\x02\n\n\x0c\n\x05\x05\x02\x02\x03\x02\x12\x03\x13\r\x0e\n\x0b\n\x04\
-    \x05\x02\x02\x04\x12\x03\x14\x02\r\n\x0c\n\x05\x05\x02\x02\x04\x01\x12\
-    \x03\x14\x02\x08\n\x0c\n\x05\x05\x02\x02\x04\x02\x12\x03\x14\x0b\x0c\n\
-    \x0b\n\x04\x05\x02\x02\x05\x12\x03\x15\x02\x11\n\x0c\n\x05\x05\x02\x02\
-    \x05\x01\x12\x03\x15\x02\x0c\n\x0c\n\x05\x05\x02\x02\x05\x02\x12\x03\x15\
-    \x0f\x10\n\n\n\x02\x04\0\x12\x04\x18\0\x1c\x01\n\n\n\x03\x04\0\x01\x12\
-    \x03\x18\x08\x11\n\x0b\n\x04\x04\0\x02\0\x12\x03\x19\x02\x1f\n\x0c\n\x05\
-    \x04\0\x02\0\x04\x12\x03\x19\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\
-    \x19\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x19\x12\x1a\n\x0c\n\x05\
-    \x04\0\x02\0\x03\x12\x03\x19\x1d\x1e\n\x0b\n\x04\x04\0\x02\x01\x12\x03\
-    \x1a\x02\x15\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03\x1a\x02\n\n\x0c\n\x05\
-    \x04\0\x02\x01\x06\x12\x03\x1a\x0b\r\n\x0c\n\x05\x04\0\x02\x01\x01\x12\
-    \x03\x1a\x0e\x10\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x1a\x13\x14\n\x0b\
-    \n\x04\x04\0\x02\x02\x12\x03\x1b\x02!\n\x0c\n\x05\x04\0\x02\x02\x04\x12\
-    \x03\x1b\x02\n\n\x0c\n\x05\x04\0\x02\x02\x06\x12\x03\x1b\x0b\x16\n\x0c\n\
-    \x05\x04\0\x02\x02\x01\x12\x03\x1b\x17\x1c\n\x0c\n\x05\x04\0\x02\x02\x03\
-    \x12\x03\x1b\x1f\x20\n\n\n\x02\x04\x01\x12\x04\x1e\05\x01\n\n\n\x03\x04\
-    \x01\x01\x12\x03\x1e\x08\x0b\n\n\n\x03\x04\x01\t\x12\x03\x1f\x0b\x0e\n\
-    \x0b\n\x04\x04\x01\t\0\x12\x03\x1f\x0b\r\n\x0c\n\x05\x04\x01\t\0\x01\x12\
-    \x03\x1f\x0b\r\n\x0c\n\x05\x04\x01\t\0\x02\x12\x03\x1f\x0b\r\n\n\n\x03\
-    \x04\x01\n\x12\x03\x20\x0b\x15\n\x0b\n\x04\x04\x01\n\0\x12\x03\x20\x0b\
-    \x14\n\x0b\n\x04\x04\x01\x02\0\x12\x03!\x02\x19\n\x0c\n\x05\x04\x01\x02\
-    \0\x04\x12\x03!\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03!\x0b\x11\n\
-    \x0c\n\x05\x04\x01\x02\0\x01\x12\x03!\x12\x14\n\x0c\n\x05\x04\x01\x02\0\
-    \x03\x12\x03!\x17\x18\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\"\x02\x1f\n\
-    \x0c\n\x05\x04\x01\x02\x01\x04\x12\x03\"\x02\n\n\x0c\n\x05\x04\x01\x02\
-    \x01\x05\x12\x03\"\x0b\x11\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\"\x12\
-    \x1a\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\"\x1d\x1e\n\x0b\n\x04\x04\
-    \x01\x02\x02\x12\x03#\x02\x1e\n\x0c\n\x05\x04\x01\x02\x02\x04\x12\x03#\
-    \x02\n\n\x0c\n\x05\x04\x01\x02\x02\x06\x12\x03#\x0b\x13\n\x0c\n\x05\x04\
-    \x01\x02\x02\x01\x12\x03#\x14\x19\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\
-    \x03#\x1c\x1d\n\x0b\n\x04\x04\x01\x02\x03\x12\x03$\x02/\n\x0c\n\x05\x04\
-    \x01\x02\x03\x04\x12\x03$\x02\n\n\x0c\n\x05\x04\x01\x02\x03\x06\x12\x03$\
-    \x0b\"\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03$#*\n\x0c\n\x05\x04\x01\
-    \x02\x03\x03\x12\x03$-.\n\x0b\n\x04\x04\x01\x02\x04\x12\x03%\x02\"\n\x0c\
-    \n\x05\x04\x01\x02\x04\x04\x12\x03%\x02\n\n\x0c\n\x05\x04\x01\x02\x04\
-    \x06\x12\x03%\x0b\x17\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03%\x18\x1d\n\
-    \x0c\n\x05\x04\x01\x02\x04\x03\x12\x03%\x20!\n\xa5\x01\n\x04\x04\x01\x02\
-    \x05\x12\x03)\x02!\x1a\x97\x01\x20The\x20RFC3339-formatted\x20time\x20th\
-    e\x20job\x20was\x20entered\x20into\x20the\n\x20system.\x20It\x20may\x20n\
-    ot\x20begin\x20processing\x20for\x20some\x20time\x20after\x20this,\n\x20\
-    based\x20on\x20current\x20system\x20load.\n\n\x0c\n\x05\x04\x01\x02\x05\
-    \x04\x12\x03)\x02\n\n\x0c\n\x05\x04\x01\x02\x05\x05\x12\x03)\x0b\x11\n\
-    \x0c\n\x05\x04\x01\x02\x05\x01\x12\x03)\x12\x1c\n\x0c\n\x05\x04\x01\x02\
-    \x05\x03\x12\x03)\x1f\x20\nR\n\x04\x04\x01\x02\x06\x12\x03,\x02'\x1aE\
-    \x20The\x20RFC3339-formatted\x20time\x20the\x20`hab\x20studio\x20build`\
-    \x20process\n\x20started.\n\n\x0c\n\x05\x04\x01\x02\x06\x04\x12\x03,\x02\
-    \n\n\x0c\n\x05\x04\x01\x02\x06\x05\x12\x03,\x0b\x11\n\x0c\n\x05\x04\x01\
-    \x02\x06\x01\x12\x03,\x12\"\n\x0c\n\x05\x04\x01\x02\x06\x03\x12\x03,%&\n\
-    e\n\x04\x04\x01\x02\x07\x12\x03/\x02(\x1aX\x20The\x20RFC3339-formatted\
-    \x20time\x20the\x20`hab\x20studio\x20build`\x20process\n\x20stopped,\x20\
-    successful\x20or\x20not.\n\n\x0c\n\x05\x04\x01\x02\x07\x04\x12\x03/\x02\
-    \n\n\x0c\n\x05\x04\x01\x02\x07\x05\x12\x03/\x0b\x11\n\x0c\n\x05\x04\x01\
-    \x02\x07\x01\x12\x03/\x12#\n\x0c\n\x05\x04\x01\x02\x07\x03\x12\x03/&'\nb\
-    \n\x04\x04\x01\x02\x08\x12\x032\x02:\x1aU\x20The\x20identifier\x20of\x20\
-    the\x20package\x20built\x20by\x20the\x20job.\x20Set\x20only\x20a\n\x20su\
-    ccessfully-built\x20Job.\n\n\x0c\n\x05\x04\x01\x02\x08\x04\x12\x032\x02\
-    \n\n\x0c\n\x05\x04\x01\x02\x08\x06\x12\x032\x0b'\n\x0c\n\x05\x04\x01\x02\
-    \x08\x01\x12\x032(5\n\x0c\n\x05\x04\x01\x02\x08\x03\x12\x03289\nC\n\x04\
-    \x04\x01\x02\t\x12\x034\x02!\x1a6\x20Whether\x20or\x20not\x20the\x20log\
-    \x20for\x20the\x20job\x20has\x20been\x20archived\n\n\x0c\n\x05\x04\x01\
-    \x02\t\x04\x12\x034\x02\n\n\x0c\n\x05\x04\x01\x02\t\x05\x12\x034\x0b\x0f\
-    \n\x0c\n\x05\x04\x01\x02\t\x01\x12\x034\x10\x1b\n\x0c\n\x05\x04\x01\x02\
-    \t\x03\x12\x034\x1e\x20\n)\n\x02\x04\x02\x12\x048\0:\x01\x1a\x1d\x20Retr\
-    ieve\x20a\x20single\x20job\x20by\x20ID\n\n\n\n\x03\x04\x02\x01\x12\x038\
-    \x08\x0e\n\x0b\n\x04\x04\x02\x02\0\x12\x039\x02\x19\n\x0c\n\x05\x04\x02\
-    \x02\0\x04\x12\x039\x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x039\x0b\x11\
-    \n\x0c\n\x05\x04\x02\x02\0\x01\x12\x039\x12\x14\n\x0c\n\x05\x04\x02\x02\
-    \0\x03\x12\x039\x17\x18\n\n\n\x02\x04\x03\x12\x04<\0?\x01\n\n\n\x03\x04\
-    \x03\x01\x12\x03<\x08\x0f\n\x0b\n\x04\x04\x03\x02\0\x12\x03=\x02\x1f\n\
-    \x0c\n\x05\x04\x03\x02\0\x04\x12\x03=\x02\n\n\x0c\n\x05\x04\x03\x02\0\
-    \x05\x12\x03=\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03=\x12\x1a\n\
-    \x0c\n\x05\x04\x03\x02\0\x03\x12\x03=\x1d\x1e\n\x0b\n\x04\x04\x03\x02\
-    \x01\x12\x03>\x02/\n\x0c\n\x05\x04\x03\x02\x01\x04\x12\x03>\x02\n\n\x0c\
-    \n\x05\x04\x03\x02\x01\x06\x12\x03>\x0b\"\n\x0c\n\x05\x04\x03\x02\x01\
-    \x01\x12\x03>#*\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03>-.\n\x88\x01\n\
-    \x02\x04\x04\x12\x04E\0H\x01\x1a|\x20Retrieve\x20jobs\x20for\x20a\x20spe\
-    cific\x20project.\n\n\x20Currently\x20retrieves\x2050\x20most\x20recent;\
-    \x20additional\x20filtering\x20and\n\x20sorting\x20comes\x20later.\n\n\n\
-    \n\x03\x04\x04\x01\x12\x03E\x08\x16\nH\n\x04\x04\x04\x02\0\x12\x03G\x02\
-    \x1b\x1a;\x20The\x20origin-qualified\x20name\x20of\x20a\x20project,\x20e\
-    .g.\x20\"core/nginx\"\n\n\x0c\n\x05\x04\x04\x02\0\x04\x12\x03G\x02\n\n\
-    \x0c\n\x05\x04\x04\x02\0\x05\x12\x03G\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\
-    \x01\x12\x03G\x12\x16\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03G\x19\x1a\n\n\
-    \n\x02\x04\x05\x12\x04J\0L\x01\n\n\n\x03\x04\x05\x01\x12\x03J\x08\x1e\n\
-    \x0b\n\x04\x04\x05\x02\0\x12\x03K\x02\x18\n\x0c\n\x05\x04\x05\x02\0\x04\
-    \x12\x03K\x02\n\n\x0c\n\x05\x04\x05\x02\0\x06\x12\x03K\x0b\x0e\n\x0c\n\
-    \x05\x04\x05\x02\0\x01\x12\x03K\x0f\x13\n\x0c\n\x05\x04\x05\x02\0\x03\
-    \x12\x03K\x16\x17\nQ\n\x02\x04\x06\x12\x04O\0X\x01\x1aE\x20Sent\x20from\
-    \x20a\x20worker\x20to\x20the\x20job\x20server's\x20log\x20ingester\x20du\
-    ring\x20a\x20build.\n\n\n\n\x03\x04\x06\x01\x12\x03O\x08\x13\n\x0b\n\x04\
-    \x04\x06\x02\0\x12\x03P\x02\x1d\n\x0c\n\x05\x04\x06\x02\0\x04\x12\x03P\
-    \x02\n\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03P\x0b\x11\n\x0c\n\x05\x04\
-    \x06\x02\0\x01\x12\x03P\x12\x18\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03P\
-    \x1b\x1c\n\x9c\x01\n\x04\x04\x06\x02\x01\x12\x03T\x02\x1a\x1a\x8e\x01\
-    \x20Ordering\x20of\x20this\x20chunk\x20in\x20the\x20overall\x20log\x20ou\
-    tput,\x20starting\x20at\x201;\n\x20We\x20currently\x20send\x20one\x20lin\
-    e\x20at\x20a\x20time,\x20so\x20this\x20corresponds\x20to\x20line\n\x20nu\
-    mber.\n\n\x0c\n\x05\x04\x06\x02\x01\x04\x12\x03T\x02\n\n\x0c\n\x05\x04\
-    \x06\x02\x01\x05\x12\x03T\x0b\x11\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\
-    \x03T\x12\x15\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03T\x18\x19\nd\n\x04\
-    \x04\x06\x02\x02\x12\x03W\x02\x1e\x1aW\x20The\x20log\x20content\x20being\
-    \x20sent\n\x20TODO:\x20Make\x20this\x20a\x20repeated\x20field\x20for\x20\
-    future\x20compatibility\n\n\x0c\n\x05\x04\x06\x02\x02\x04\x12\x03W\x02\n\
-    \n\x0c\n\x05\x04\x06\x02\x02\x05\x12\x03W\x0b\x11\n\x0c\n\x05\x04\x06\
-    \x02\x02\x01\x12\x03W\x12\x19\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x03W\
-    \x1c\x1d\n\\\n\x02\x04\x07\x12\x04\\\0^\x01\x1aP\x20Sent\x20from\x20a\
-    \x20worker\x20to\x20the\x20job\x20server's\x20log\x20ingester\x20when\
-    \x20a\x20build\x20is\n\x20complete.\n\n\n\n\x03\x04\x07\x01\x12\x03\\\
-    \x08\x16\n\x0b\n\x04\x04\x07\x02\0\x12\x03]\x02\x1d\n\x0c\n\x05\x04\x07\
-    \x02\0\x04\x12\x03]\x02\n\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03]\x0b\x11\
-    \n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03]\x12\x18\n\x0c\n\x05\x04\x07\x02\
-    \0\x03\x12\x03]\x1b\x1c\nl\n\x02\x04\x08\x12\x04b\0f\x01\x1a`\x20Initiat\
-    ed\x20by\x20API\x20request\x20to\x20retrieve\x20a\x20portion\x20of\x20a\
-    \x20job's\x20log\x20data,\n\x20beginning\x20at\x20line\x20`start`\n\n\n\
-    \n\x03\x04\x08\x01\x12\x03b\x08\x11\n\x0b\n\x04\x04\x08\x02\0\x12\x03c\
-    \x02\x19\n\x0c\n\x05\x04\x08\x02\0\x04\x12\x03c\x02\n\n\x0c\n\x05\x04\
-    \x08\x02\0\x05\x12\x03c\x0b\x11\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x03c\
-    \x12\x14\n\x0c\n\x05\x04\x08\x02\0\x03\x12\x03c\x17\x18\n@\n\x04\x04\x08\
-    \x02\x01\x12\x03e\x02\x1c\x1a3\x20Zero-indexed\x20line\x20of\x20the\x20l\
-    og\x20output\x20to\x20start\x20with\n\n\x0c\n\x05\x04\x08\x02\x01\x04\
-    \x12\x03e\x02\n\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x03e\x0b\x11\n\x0c\n\
-    \x05\x04\x08\x02\x01\x01\x12\x03e\x12\x17\n\x0c\n\x05\x04\x08\x02\x01\
-    \x03\x12\x03e\x1a\x1b\n*\n\x02\x04\t\x12\x04i\0t\x01\x1a\x1e\x20Sent\x20\
-    in\x20reply\x20to\x20a\x20JobLogGet\n\n\n\n\x03\x04\t\x01\x12\x03i\x08\
-    \x0e\nK\n\x04\x04\t\x02\0\x12\x03k\x02\x1c\x1a>\x20Zero-indexed\x20(incl\
-    usive)\x20line\x20of\x20the\x20log\x20output\x20in\x20`content`\n\n\x0c\
-    \n\x05\x04\t\x02\0\x04\x12\x03k\x02\n\n\x0c\n\x05\x04\t\x02\0\x05\x12\
-    \x03k\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03k\x12\x17\n\x0c\n\x05\
-    \x04\t\x02\0\x03\x12\x03k\x1a\x1b\nK\n\x04\x04\t\x02\x01\x12\x03m\x02\
-    \x1b\x1a>\x20Zero-indexed\x20(exclusive)\x20line\x20of\x20the\x20log\x20\
-    output\x20in\x20`content`\n\n\x0c\n\x05\x04\t\x02\x01\x04\x12\x03m\x02\n\
-    \n\x0c\n\x05\x04\t\x02\x01\x05\x12\x03m\x0b\x11\n\x0c\n\x05\x04\t\x02\
-    \x01\x01\x12\x03m\x12\x16\n\x0c\n\x05\x04\t\x02\x01\x03\x12\x03m\x19\x1a\
-    \n\"\n\x04\x04\t\x02\x02\x12\x03o\x02\x1e\x1a\x15\x20Lines\x20of\x20log\
-    \x20output\n\n\x0c\n\x05\x04\t\x02\x02\x04\x12\x03o\x02\n\n\x0c\n\x05\
-    \x04\t\x02\x02\x05\x12\x03o\x0b\x11\n\x0c\n\x05\x04\t\x02\x02\x01\x12\
-    \x03o\x12\x19\n\x0c\n\x05\x04\t\x02\x02\x03\x12\x03o\x1c\x1d\n\x9c\x01\n\
-    \x04\x04\t\x02\x03\x12\x03s\x02\x20\x1a\x8e\x01\x20While\x20we\x20need\
-    \x20to\x20poll\x20for\x20logs,\x20this\x20serves\x20as\x20an\x20indicato\
-    r\x20to\n\x20clients\x20if\x20they\x20need\x20to\x20continue\x20polling\
-    \x20to\x20receive\x20more\x20logs,\x20or\n\x20can\x20stop.\n\n\x0c\n\x05\
-    \x04\t\x02\x03\x04\x12\x03s\x02\n\n\x0c\n\x05\x04\t\x02\x03\x05\x12\x03s\
-    \x0b\x0f\n\x0c\n\x05\x04\t\x02\x03\x01\x12\x03s\x10\x1b\n\x0c\n\x05\x04\
-    \t\x02\x03\x03\x12\x03s\x1e\x1f\
+    archived\x18\x0b\x20\x01(\x08R\nisArchived\x12\x17\n\x07vcs_ref\x18\x0c\
+    \x20\x01(\tR\x06vcsRef\x12\x17\n\x07vcs_sha\x18\r\x20\x01(\tR\x06vcsShaJ\
+    \x04\x08\n\x10\x0bR\x07log_url\"\x18\n\x06JobGet\x12\x0e\n\x02id\x18\x01\
+    \x20\x01(\x04R\x02id\">\n\tJobCancel\x12\x0e\n\x02id\x18\x01\x20\x01(\
+    \x04R\x02id\x12!\n\x0crequestor_id\x18\x02\x20\x01(\x04R\x0brequestorId\
+    \"q\n\x07JobSpec\x12\x19\n\x08owner_id\x18\x01\x20\x01(\x04R\x07ownerId\
+    \x122\n\x07project\x18\x02\x20\x01(\x0b2\x18.originsrv.OriginProjectR\
+    \x07project\x12\x17\n\x07vcs_ref\x18\x03\x20\x01(\tR\x06vcsRef\"$\n\x0eP\
+    rojectJobsGet\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\"9\n\x16Proj\
+    ectJobsGetResponse\x12\x1f\n\x04jobs\x18\x01\x20\x03(\x0b2\x0b.jobsrv.Jo\
+    bR\x04jobs\"P\n\x0bJobLogChunk\x12\x15\n\x06job_id\x18\x01\x20\x01(\x04R\
+    \x05jobId\x12\x10\n\x03seq\x18\x02\x20\x01(\x04R\x03seq\x12\x18\n\x07con\
+    tent\x18\x03\x20\x01(\tR\x07content\"'\n\x0eJobLogComplete\x12\x15\n\x06\
+    job_id\x18\x01\x20\x01(\x04R\x05jobId\"1\n\tJobLogGet\x12\x0e\n\x02id\
+    \x18\x01\x20\x01(\x04R\x02id\x12\x14\n\x05start\x18\x02\x20\x01(\x04R\
+    \x05start\"m\n\x06JobLog\x12\x14\n\x05start\x18\x01\x20\x01(\x04R\x05sta\
+    rt\x12\x12\n\x04stop\x18\x02\x20\x01(\x04R\x04stop\x12\x18\n\x07content\
+    \x18\x03\x20\x03(\tR\x07content\x12\x1f\n\x0bis_complete\x18\x04\x20\x01\
+    (\x08R\nisComplete*(\n\x02Os\x12\t\n\x05Linux\x10\x01\x12\n\n\x06Darwin\
+    \x10\x02\x12\x0b\n\x07Windows\x10\x03*\"\n\x0bWorkerState\x12\t\n\x05Rea\
+    dy\x10\0\x12\x08\n\x04Busy\x10\x01*\x80\x01\n\x08JobState\x12\x0b\n\x07P\
+    ending\x10\0\x12\x0e\n\nProcessing\x10\x01\x12\x0c\n\x08Complete\x10\x02\
+    \x12\x0c\n\x08Rejected\x10\x03\x12\n\n\x06Failed\x10\x04\x12\x0e\n\nDisp\
+    atched\x10\x05\x12\x11\n\rCancelPending\x10\x06\x12\x0c\n\x08Canceled\
+    \x10\x07J\xb0'\n\x07\x12\x05\0\0\x82\x01\x01\n\x08\n\x01\x02\x12\x03\0\0\
+    \x0f\n\t\n\x02\x03\0\x12\x03\x01\0\x1d\n\t\n\x02\x03\x01\x12\x03\x02\0#\
+    \n\n\n\x02\x05\0\x12\x04\x04\0\x08\x01\n\n\n\x03\x05\0\x01\x12\x03\x04\
+    \x05\x07\n\x0b\n\x04\x05\0\x02\0\x12\x03\x05\x02\x0c\n\x0c\n\x05\x05\0\
+    \x02\0\x01\x12\x03\x05\x02\x07\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x05\n\
+    \x0b\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x06\x02\r\n\x0c\n\x05\x05\0\x02\
+    \x01\x01\x12\x03\x06\x02\x08\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x06\
+    \x0b\x0c\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x07\x02\x0e\n\x0c\n\x05\x05\0\
+    \x02\x02\x01\x12\x03\x07\x02\t\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x07\
+    \x0c\r\n\n\n\x02\x05\x01\x12\x04\n\0\r\x01\n\n\n\x03\x05\x01\x01\x12\x03\
+    \n\x05\x10\n\x0b\n\x04\x05\x01\x02\0\x12\x03\x0b\x02\x0c\n\x0c\n\x05\x05\
+    \x01\x02\0\x01\x12\x03\x0b\x02\x07\n\x0c\n\x05\x05\x01\x02\0\x02\x12\x03\
+    \x0b\n\x0b\n\x0b\n\x04\x05\x01\x02\x01\x12\x03\x0c\x02\x0b\n\x0c\n\x05\
+    \x05\x01\x02\x01\x01\x12\x03\x0c\x02\x06\n\x0c\n\x05\x05\x01\x02\x01\x02\
+    \x12\x03\x0c\t\n\n\n\n\x02\x05\x02\x12\x04\x0f\0\x18\x01\n\n\n\x03\x05\
+    \x02\x01\x12\x03\x0f\x05\r\n\x0b\n\x04\x05\x02\x02\0\x12\x03\x10\x02\x0e\
+    \n\x0c\n\x05\x05\x02\x02\0\x01\x12\x03\x10\x02\t\n\x0c\n\x05\x05\x02\x02\
+    \0\x02\x12\x03\x10\x0c\r\n\x0b\n\x04\x05\x02\x02\x01\x12\x03\x11\x02\x11\
+    \n\x0c\n\x05\x05\x02\x02\x01\x01\x12\x03\x11\x02\x0c\n\x0c\n\x05\x05\x02\
+    \x02\x01\x02\x12\x03\x11\x0f\x10\n\x0b\n\x04\x05\x02\x02\x02\x12\x03\x12\
+    \x02\x0f\n\x0c\n\x05\x05\x02\x02\x02\x01\x12\x03\x12\x02\n\n\x0c\n\x05\
+    \x05\x02\x02\x02\x02\x12\x03\x12\r\x0e\n\x0b\n\x04\x05\x02\x02\x03\x12\
+    \x03\x13\x02\x0f\n\x0c\n\x05\x05\x02\x02\x03\x01\x12\x03\x13\x02\n\n\x0c\
+    \n\x05\x05\x02\x02\x03\x02\x12\x03\x13\r\x0e\n\x0b\n\x04\x05\x02\x02\x04\
+    \x12\x03\x14\x02\r\n\x0c\n\x05\x05\x02\x02\x04\x01\x12\x03\x14\x02\x08\n\
+    \x0c\n\x05\x05\x02\x02\x04\x02\x12\x03\x14\x0b\x0c\n\x0b\n\x04\x05\x02\
+    \x02\x05\x12\x03\x15\x02\x11\n\x0c\n\x05\x05\x02\x02\x05\x01\x12\x03\x15\
+    \x02\x0c\n\x0c\n\x05\x05\x02\x02\x05\x02\x12\x03\x15\x0f\x10\n\x0b\n\x04\
+    \x05\x02\x02\x06\x12\x03\x16\x02\x14\n\x0c\n\x05\x05\x02\x02\x06\x01\x12\
+    \x03\x16\x02\x0f\n\x0c\n\x05\x05\x02\x02\x06\x02\x12\x03\x16\x12\x13\n\
+    \x0b\n\x04\x05\x02\x02\x07\x12\x03\x17\x02\x0f\n\x0c\n\x05\x05\x02\x02\
+    \x07\x01\x12\x03\x17\x02\n\n\x0c\n\x05\x05\x02\x02\x07\x02\x12\x03\x17\r\
+    \x0e\n\n\n\x02\x04\0\x12\x04\x1a\0\x1e\x01\n\n\n\x03\x04\0\x01\x12\x03\
+    \x1a\x08\x11\n\x0b\n\x04\x04\0\x02\0\x12\x03\x1b\x02\x1f\n\x0c\n\x05\x04\
+    \0\x02\0\x04\x12\x03\x1b\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x1b\
+    \x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x1b\x12\x1a\n\x0c\n\x05\x04\
+    \0\x02\0\x03\x12\x03\x1b\x1d\x1e\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x1c\
+    \x02\x15\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03\x1c\x02\n\n\x0c\n\x05\x04\
+    \0\x02\x01\x06\x12\x03\x1c\x0b\r\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\
+    \x1c\x0e\x10\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x1c\x13\x14\n\x0b\n\
+    \x04\x04\0\x02\x02\x12\x03\x1d\x02!\n\x0c\n\x05\x04\0\x02\x02\x04\x12\
+    \x03\x1d\x02\n\n\x0c\n\x05\x04\0\x02\x02\x06\x12\x03\x1d\x0b\x16\n\x0c\n\
+    \x05\x04\0\x02\x02\x01\x12\x03\x1d\x17\x1c\n\x0c\n\x05\x04\0\x02\x02\x03\
+    \x12\x03\x1d\x1f\x20\n\n\n\x02\x04\x01\x12\x04\x20\0<\x01\n\n\n\x03\x04\
+    \x01\x01\x12\x03\x20\x08\x0b\n\n\n\x03\x04\x01\t\x12\x03!\x02\x0e\n\x0b\
+    \n\x04\x04\x01\t\0\x12\x03!\x0b\r\n\x0c\n\x05\x04\x01\t\0\x01\x12\x03!\
+    \x0b\r\n\x0c\n\x05\x04\x01\t\0\x02\x12\x03!\x0b\r\n\n\n\x03\x04\x01\n\
+    \x12\x03\"\x02\x15\n\x0b\n\x04\x04\x01\n\0\x12\x03\"\x0b\x14\n\x0b\n\x04\
+    \x04\x01\x02\0\x12\x03#\x02\x19\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03#\
+    \x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03#\x0b\x11\n\x0c\n\x05\x04\
+    \x01\x02\0\x01\x12\x03#\x12\x14\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03#\
+    \x17\x18\n\x0b\n\x04\x04\x01\x02\x01\x12\x03$\x02\x1f\n\x0c\n\x05\x04\
+    \x01\x02\x01\x04\x12\x03$\x02\n\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03$\
+    \x0b\x11\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03$\x12\x1a\n\x0c\n\x05\
+    \x04\x01\x02\x01\x03\x12\x03$\x1d\x1e\n\x0b\n\x04\x04\x01\x02\x02\x12\
+    \x03%\x02\x1e\n\x0c\n\x05\x04\x01\x02\x02\x04\x12\x03%\x02\n\n\x0c\n\x05\
+    \x04\x01\x02\x02\x06\x12\x03%\x0b\x13\n\x0c\n\x05\x04\x01\x02\x02\x01\
+    \x12\x03%\x14\x19\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03%\x1c\x1d\n\x0b\
+    \n\x04\x04\x01\x02\x03\x12\x03&\x02/\n\x0c\n\x05\x04\x01\x02\x03\x04\x12\
+    \x03&\x02\n\n\x0c\n\x05\x04\x01\x02\x03\x06\x12\x03&\x0b\"\n\x0c\n\x05\
+    \x04\x01\x02\x03\x01\x12\x03&#*\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03&\
+    -.\n\x0b\n\x04\x04\x01\x02\x04\x12\x03'\x02\"\n\x0c\n\x05\x04\x01\x02\
+    \x04\x04\x12\x03'\x02\n\n\x0c\n\x05\x04\x01\x02\x04\x06\x12\x03'\x0b\x17\
+    \n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03'\x18\x1d\n\x0c\n\x05\x04\x01\
+    \x02\x04\x03\x12\x03'\x20!\n\xa5\x01\n\x04\x04\x01\x02\x05\x12\x03+\x02!\
+    \x1a\x97\x01\x20The\x20RFC3339-formatted\x20time\x20the\x20job\x20was\
+    \x20entered\x20into\x20the\n\x20system.\x20It\x20may\x20not\x20begin\x20\
+    processing\x20for\x20some\x20time\x20after\x20this,\n\x20based\x20on\x20\
+    current\x20system\x20load.\n\n\x0c\n\x05\x04\x01\x02\x05\x04\x12\x03+\
+    \x02\n\n\x0c\n\x05\x04\x01\x02\x05\x05\x12\x03+\x0b\x11\n\x0c\n\x05\x04\
+    \x01\x02\x05\x01\x12\x03+\x12\x1c\n\x0c\n\x05\x04\x01\x02\x05\x03\x12\
+    \x03+\x1f\x20\nR\n\x04\x04\x01\x02\x06\x12\x03.\x02'\x1aE\x20The\x20RFC3\
+    339-formatted\x20time\x20the\x20`hab\x20studio\x20build`\x20process\n\
+    \x20started.\n\n\x0c\n\x05\x04\x01\x02\x06\x04\x12\x03.\x02\n\n\x0c\n\
+    \x05\x04\x01\x02\x06\x05\x12\x03.\x0b\x11\n\x0c\n\x05\x04\x01\x02\x06\
+    \x01\x12\x03.\x12\"\n\x0c\n\x05\x04\x01\x02\x06\x03\x12\x03.%&\ne\n\x04\
+    \x04\x01\x02\x07\x12\x031\x02(\x1aX\x20The\x20RFC3339-formatted\x20time\
+    \x20the\x20`hab\x20studio\x20build`\x20process\n\x20stopped,\x20successf\
+    ul\x20or\x20not.\n\n\x0c\n\x05\x04\x01\x02\x07\x04\x12\x031\x02\n\n\x0c\
+    \n\x05\x04\x01\x02\x07\x05\x12\x031\x0b\x11\n\x0c\n\x05\x04\x01\x02\x07\
+    \x01\x12\x031\x12#\n\x0c\n\x05\x04\x01\x02\x07\x03\x12\x031&'\nb\n\x04\
+    \x04\x01\x02\x08\x12\x034\x02:\x1aU\x20The\x20identifier\x20of\x20the\
+    \x20package\x20built\x20by\x20the\x20job.\x20Set\x20only\x20a\n\x20succe\
+    ssfully-built\x20Job.\n\n\x0c\n\x05\x04\x01\x02\x08\x04\x12\x034\x02\n\n\
+    \x0c\n\x05\x04\x01\x02\x08\x06\x12\x034\x0b'\n\x0c\n\x05\x04\x01\x02\x08\
+    \x01\x12\x034(5\n\x0c\n\x05\x04\x01\x02\x08\x03\x12\x03489\nC\n\x04\x04\
+    \x01\x02\t\x12\x036\x02!\x1a6\x20Whether\x20or\x20not\x20the\x20log\x20f\
+    or\x20the\x20job\x20has\x20been\x20archived\n\n\x0c\n\x05\x04\x01\x02\t\
+    \x04\x12\x036\x02\n\n\x0c\n\x05\x04\x01\x02\t\x05\x12\x036\x0b\x0f\n\x0c\
+    \n\x05\x04\x01\x02\t\x01\x12\x036\x10\x1b\n\x0c\n\x05\x04\x01\x02\t\x03\
+    \x12\x036\x1e\x20\n\x84\x01\n\x04\x04\x01\x02\n\x12\x039\x02\x1f\x1aw\
+    \x20The\x20branch,\x20tag\x20or\x20commit\x20requested\x20for\x20the\x20\
+    build.\x20The\x20default\n\x20branch\x20of\x20the\x20project's\x20reposi\
+    tory\x20is\x20built\x20if\x20unset.\n\n\x0c\n\x05\x04\x01\x02\n\x04\x12\
+    \x039\x02\n\n\x0c\n\x05\x04\x01\x02\n\x05\x12\x039\x0b\x11\n\x0c\n\x05\
+    \x04\x01\x02\n\x01\x12\x039\x12\x19\n\x0c\n\x05\x04\x01\x02\n\x03\x12\
+    \x039\x1c\x1e\nJ\n\x04\x04\x01\x02\x0b\x12\x03;\x02\x1f\x1a=\x20The\x20S\
+    HA\x20of\x20the\x20commit\x20the\x20worker\x20checked\x20out\x20for\x20t\
+    he\x20build.\n\n\x0c\n\x05\x04\x01\x02\x0b\x04\x12\x03;\x02\n\n\x0c\n\
+    \x05\x04\x01\x02\x0b\x05\x12\x03;\x0b\x11\n\x0c\n\x05\x04\x01\x02\x0b\
+    \x01\x12\x03;\x12\x19\n\x0c\n\x05\x04\x01\x02\x0b\x03\x12\x03;\x1c\x1e\n\
+    )\n\x02\x04\x02\x12\x04?\0A\x01\x1a\x1d\x20Retrieve\x20a\x20single\x20jo\
+    b\x20by\x20ID\n\n\n\n\x03\x04\x02\x01\x12\x03?\x08\x0e\n\x0b\n\x04\x04\
+    \x02\x02\0\x12\x03@\x02\x19\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03@\x02\n\
+    \n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03@\x0b\x11\n\x0c\n\x05\x04\x02\x02\
+    \0\x01\x12\x03@\x12\x14\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03@\x17\x18\n\
+    \n\n\x02\x04\x03\x12\x04C\0F\x01\n\n\n\x03\x04\x03\x01\x12\x03C\x08\x11\
+    \n\x0b\n\x04\x04\x03\x02\0\x12\x03D\x02\x19\n\x0c\n\x05\x04\x03\x02\0\
+    \x04\x12\x03D\x02\n\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03D\x0b\x11\n\x0c\
+    \n\x05\x04\x03\x02\0\x01\x12\x03D\x12\x14\n\x0c\n\x05\x04\x03\x02\0\x03\
+    \x12\x03D\x17\x18\n\x0b\n\x04\x04\x03\x02\x01\x12\x03E\x02#\n\x0c\n\x05\
+    \x04\x03\x02\x01\x04\x12\x03E\x02\n\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\
+    \x03E\x0b\x11\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03E\x12\x1e\n\x0c\n\
+    \x05\x04\x03\x02\x01\x03\x12\x03E!\"\n\n\n\x02\x04\x04\x12\x04H\0M\x01\n\
+    \n\n\x03\x04\x04\x01\x12\x03H\x08\x0f\n\x0b\n\x04\x04\x04\x02\0\x12\x03I\
+    \x02\x1f\n\x0c\n\x05\x04\x04\x02\0\x04\x12\x03I\x02\n\n\x0c\n\x05\x04\
+    \x04\x02\0\x05\x12\x03I\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03I\
+    \x12\x1a\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03I\x1d\x1e\n\x0b\n\x04\x04\
+    \x04\x02\x01\x12\x03J\x02/\n\x0c\n\x05\x04\x04\x02\x01\x04\x12\x03J\x02\
+    \n\n\x0c\n\x05\x04\x04\x02\x01\x06\x12\x03J\x0b\"\n\x0c\n\x05\x04\x04\
+    \x02\x01\x01\x12\x03J#*\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03J-.\n1\n\
+    \x04\x04\x04\x02\x02\x12\x03L\x02\x1e\x1a$\x20Branch,\x20tag\x20or\x20co\
+    mmit\x20SHA\x20to\x20build\n\n\x0c\n\x05\x04\x04\x02\x02\x04\x12\x03L\
+    \x02\n\n\x0c\n\x05\x04\x04\x02\x02\x05\x12\x03L\x0b\x11\n\x0c\n\x05\x04\
+    \x04\x02\x02\x01\x12\x03L\x12\x19\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\
+    \x03L\x1c\x1d\n\x88\x01\n\x02\x04\x05\x12\x04S\0V\x01\x1a|\x20Retrieve\
+    \x20jobs\x20for\x20a\x20specific\x20project.\n\n\x20Currently\x20retriev\
+    es\x2050\x20most\x20recent;\x20additional\x20filtering\x20and\n\x20sorti\
+    ng\x20comes\x20later.\n\n\n\n\x03\x04\x05\x01\x12\x03S\x08\x16\nH\n\x04\
+    \x04\x05\x02\0\x12\x03U\x02\x1b\x1a;\x20The\x20origin-qualified\x20name\
+    \x20of\x20a\x20project,\x20e.g.\x20\"core/nginx\"\n\n\x0c\n\x05\x04\x05\
+    \x02\0\x04\x12\x03U\x02\n\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03U\x0b\x11\
+    \n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03U\x12\x16\n\x0c\n\x05\x04\x05\x02\
+    \0\x03\x12\x03U\x19\x1a\n\n\n\x02\x04\x06\x12\x04X\0Z\x01\n\n\n\x03\x04\
+    \x06\x01\x12\x03X\x08\x1e\n\x0b\n\x04\x04\x06\x02\0\x12\x03Y\x02\x18\n\
+    \x0c\n\x05\x04\x06\x02\0\x04\x12\x03Y\x02\n\n\x0c\n\x05\x04\x06\x02\0\
+    \x06\x12\x03Y\x0b\x0e\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03Y\x0f\x13\n\
+    \x0c\n\x05\x04\x06\x02\0\x03\x12\x03Y\x16\x17\nQ\n\x02\x04\x07\x12\x04]\
+    \0f\x01\x1aE\x20Sent\x20from\x20a\x20worker\x20to\x20the\x20job\x20serve\
+    r's\x20log\x20ingester\x20during\x20a\x20build.\n\n\n\n\x03\x04\x07\x01\
+    \x12\x03]\x08\x13\n\x0b\n\x04\x04\x07\x02\0\x12\x03^\x02\x1d\n\x0c\n\x05\
+    \x04\x07\x02\0\x04\x12\x03^\x02\n\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03^\
+    \x0b\x11\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03^\x12\x18\n\x0c\n\x05\x04\
+    \x07\x02\0\x03\x12\x03^\x1b\x1c\n\x9c\x01\n\x04\x04\x07\x02\x01\x12\x03b\
+    \x02\x1a\x1a\x8e\x01\x20Ordering\x20of\x20this\x20chunk\x20in\x20the\x20\
+    overall\x20log\x20output,\x20starting\x20at\x201;\n\x20We\x20currently\
+    \x20send\x20one\x20line\x20at\x20a\x20time,\x20so\x20this\x20corresponds\
+    \x20to\x20line\n\x20number.\n\n\x0c\n\x05\x04\x07\x02\x01\x04\x12\x03b\
+    \x02\n\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03b\x0b\x11\n\x0c\n\x05\x04\
+    \x07\x02\x01\x01\x12\x03b\x12\x15\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\
+    \x03b\x18\x19\nd\n\x04\x04\x07\x02\x02\x12\x03e\x02\x1e\x1aW\x20The\x20l\
+    og\x20content\x20being\x20sent\n\x20TODO:\x20Make\x20this\x20a\x20repeat\
+    ed\x20field\x20for\x20future\x20compatibility\n\n\x0c\n\x05\x04\x07\x02\
+    \x02\x04\x12\x03e\x02\n\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\x03e\x0b\x11\
+    \n\x0c\n\x05\x04\x07\x02\x02\x01\x12\x03e\x12\x19\n\x0c\n\x05\x04\x07\
+    \x02\x02\x03\x12\x03e\x1c\x1d\n\\\n\x02\x04\x08\x12\x04j\0l\x01\x1aP\x20\
+    Sent\x20from\x20a\x20worker\x20to\x20the\x20job\x20server's\x20log\x20in\
+    gester\x20when\x20a\x20build\x20is\n\x20complete.\n\n\n\n\x03\x04\x08\
+    \x01\x12\x03j\x08\x16\n\x0b\n\x04\x04\x08\x02\0\x12\x03k\x02\x1d\n\x0c\n\
+    \x05\x04\x08\x02\0\x04\x12\x03k\x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\
+    \x03k\x0b\x11\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x03k\x12\x18\n\x0c\n\x05\
+    \x04\x08\x02\0\x03\x12\x03k\x1b\x1c\nl\n\x02\x04\t\x12\x04p\0t\x01\x1a`\
+    \x20Initiated\x20by\x20API\x20request\x20to\x20retrieve\x20a\x20portion\
+    \x20of\x20a\x20job's\x20log\x20data,\n\x20beginning\x20at\x20line\x20`st\
+    art`\n\n\n\n\x03\x04\t\x01\x12\x03p\x08\x11\n\x0b\n\x04\x04\t\x02\0\x12\
+    \x03q\x02\x19\n\x0c\n\x05\x04\t\x02\0\x04\x12\x03q\x02\n\n\x0c\n\x05\x04\
+    \t\x02\0\x05\x12\x03q\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03q\x12\
+    \x14\n\x0c\n\x05\x04\t\x02\0\x03\x12\x03q\x17\x18\n@\n\x04\x04\t\x02\x01\
+    \x12\x03s\x02\x1c\x1a3\x20Zero-indexed\x20line\x20of\x20the\x20log\x20ou\
+    tput\x20to\x20start\x20with\n\n\x0c\n\x05\x04\t\x02\x01\x04\x12\x03s\x02\
+    \n\n\x0c\n\x05\x04\t\x02\x01\x05\x12\x03s\x0b\x11\n\x0c\n\x05\x04\t\x02\
+    \x01\x01\x12\x03s\x12\x17\n\x0c\n\x05\x04\t\x02\x01\x03\x12\x03s\x1a\x1b\
+    \n+\n\x02\x04\n\x12\x05w\0\x82\x01\x01\x1a\x1e\x20Sent\x20in\x20reply\
+    \x20to\x20a\x20JobLogGet\n\n\n\n\x03\x04\n\x01\x12\x03w\x08\x0e\nK\n\x04\
+    \x04\n\x02\0\x12\x03y\x02\x1c\x1a>\x20Zero-indexed\x20(inclusive)\x20lin\
+    e\x20of\x20the\x20log\x20output\x20in\x20`content`\n\n\x0c\n\x05\x04\n\
+    \x02\0\x04\x12\x03y\x02\n\n\x0c\n\x05\x04\n\x02\0\x05\x12\x03y\x0b\x11\n\
+    \x0c\n\x05\x04\n\x02\0\x01\x12\x03y\x12\x17\n\x0c\n\x05\x04\n\x02\0\x03\
+    \x12\x03y\x1a\x1b\nK\n\x04\x04\n\x02\x01\x12\x03{\x02\x1b\x1a>\x20Zero-i\
+    ndexed\x20(exclusive)\x20line\x20of\x20the\x20log\x20output\x20in\x20`co\
+    ntent`\n\n\x0c\n\x05\x04\n\x02\x01\x04\x12\x03{\x02\n\n\x0c\n\x05\x04\n\
+    \x02\x01\x05\x12\x03{\x0b\x11\n\x0c\n\x05\x04\n\x02\x01\x01\x12\x03{\x12\
+    \x16\n\x0c\n\x05\x04\n\x02\x01\x03\x12\x03{\x19\x1a\n\"\n\x04\x04\n\x02\
+    \x02\x12\x03}\x02\x1e\x1a\x15\x20Lines\x20of\x20log\x20output\n\n\x0c\n\
+    \x05\x04\n\x02\x02\x04\x12\x03}\x02\n\n\x0c\n\x05\x04\n\x02\x02\x05\x12\
+    \x03}\x0b\x11\n\x0c\n\x05\x04\n\x02\x02\x01\x12\x03}\x12\x19\n\x0c\n\x05\
+    \x04\n\x02\x02\x03\x12\x03}\x1c\x1d\n\x9d\x01\n\x04\x04\n\x02\x03\x12\
+    \x04\x81\x01\x02\x20\x1a\x8e\x01\x20While\x20we\x20need\x20to\x20poll\
+    \x20for\x20logs,\x20this\x20serves\x20as\x20an\x20indicator\x20to\n\x20c\
+    lients\x20if\x20they\x20need\x20to\x20continue\x20polling\x20to\x20recei\
+    ve\x20more\x20logs,\x20or\n\x20can\x20stop.\n\n\r\n\x05\x04\n\x02\x03\
+    \x04\x12\x04\x81\x01\x02\n\n\r\n\x05\x04\n\x02\x03\x05\x12\x04\x81\x01\
+    \x0b\x0f\n\r\n\x05\x04\n\x02\x03\x01\x12\x04\x81\x01\x10\x1b\n\r\n\x05\
+    \x04\n\x02\x03\x03\x12\x04\x81\x01\x1e\x1f\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -1241,6 +1241,7 @@ pub enum ErrCode {
     VCS_CLONE = 1003,
     BUILD = 1004,
     POST_PROCESSOR = 1005,
+    BUILD_TIMEOUT = 1006,
 }
 
 impl ::protobuf::ProtobufEnum for ErrCode {
@@ -1268,6 +1269,7 @@ impl ::protobuf::ProtobufEnum for ErrCode {
             1003 => ::std::option::Option::Some(ErrCode::VCS_CLONE),
             1004 => ::std::option::Option::Some(ErrCode::BUILD),
             1005 => ::std::option::Option::Some(ErrCode::POST_PROCESSOR),
+            1006 => ::std::option::Option::Some(ErrCode::BUILD_TIMEOUT),
             _ => ::std::option::Option::None
         }
     }
@@ -1292,6 +1294,7 @@ impl ::protobuf::ProtobufEnum for ErrCode {
             ErrCode::VCS_CLONE,
             ErrCode::BUILD,
             ErrCode::POST_PROCESSOR,
+            ErrCode::BUILD_TIMEOUT,
         ];
         values
     }
@@ -1329,7 +1332,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x06\n\x04Ping\"\x06\n\x04Pong*[\n\x08Protocol\x12\x07\n\x03Net\x10\0\
     \x12\x0c\n\x08RouteSrv\x10\x01\x12\x0e\n\nSessionSrv\x10\x02\x12\r\n\tOr\
     iginSrv\x10\x03\x12\n\n\x06JobSrv\x10\x04\x12\r\n\tScheduler\x10\x05*\
-    \xca\x02\n\x07ErrCode\x12\x07\n\x03BUG\x10\0\x12\x0b\n\x07TIMEOUT\x10\
+    \xde\x02\n\x07ErrCode\x12\x07\n\x03BUG\x10\0\x12\x0b\n\x07TIMEOUT\x10\
     \x01\x12\x13\n\x0fREMOTE_REJECTED\x10\x02\x12\x14\n\x10BAD_REMOTE_REPLY\
     \x10\x03\x12\x14\n\x10ENTITY_NOT_FOUND\x10\x04\x12\x0c\n\x08NO_SHARD\x10\
     \x06\x12\x11\n\rACCESS_DENIED\x10\x07\x12\x13\n\x0fSESSION_EXPIRED\x10\
@@ -1337,90 +1340,93 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \nDATA_STORE\x10\x0b\x12\x0e\n\nAUTH_SCOPE\x10\x0c\x12\x14\n\x0fWORKSPAC\
     E_SETUP\x10\xe8\x07\x12\x15\n\x10SECRET_KEY_FETCH\x10\xe9\x07\x12\x16\n\
     \x11SECRET_KEY_IMPORT\x10\xea\x07\x12\x0e\n\tVCS_CLONE\x10\xeb\x07\x12\n\
-    \n\x05BUILD\x10\xec\x07\x12\x13\n\x0ePOST_PROCESSOR\x10\xed\x07J\x9f\r\n\
-    \x06\x12\x04\0\05\x0f\n\x08\n\x01\x02\x12\x03\0\x08\x0b\n\n\n\x02\x05\0\
-    \x12\x04\x02\0\t\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\r\n\x0b\n\x04\
-    \x05\0\x02\0\x12\x03\x03\x02\n\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\
-    \x02\x05\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x08\t\n\x0b\n\x04\x05\0\
-    \x02\x01\x12\x03\x04\x02\x0f\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\
-    \x02\n\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x04\r\x0e\n\x0b\n\x04\x05\0\
-    \x02\x02\x12\x03\x05\x02\x11\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\
-    \x02\x0c\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x0f\x10\n\x0b\n\x04\
-    \x05\0\x02\x03\x12\x03\x06\x02\x10\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\
-    \x06\x02\x0b\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x06\x0e\x0f\n\x0b\n\
-    \x04\x05\0\x02\x04\x12\x03\x07\x02\r\n\x0c\n\x05\x05\0\x02\x04\x01\x12\
-    \x03\x07\x02\x08\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x07\x0b\x0c\n\x0b\
-    \n\x04\x05\0\x02\x05\x12\x03\x08\x02\x10\n\x0c\n\x05\x05\0\x02\x05\x01\
-    \x12\x03\x08\x02\x0b\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x08\x0e\x0f\n\
-    \n\n\x02\x04\0\x12\x04\x0b\0\x0e\x01\n\n\n\x03\x04\0\x01\x12\x03\x0b\x08\
-    \x11\n\x0b\n\x04\x04\0\x02\0\x12\x03\x0c\x02!\n\x0c\n\x05\x04\0\x02\0\
-    \x04\x12\x03\x0c\x02\n\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x0c\x0b\x13\n\
-    \x0c\n\x05\x04\0\x02\0\x01\x12\x03\x0c\x14\x1c\n\x0c\n\x05\x04\0\x02\0\
-    \x03\x12\x03\x0c\x1f\x20\n\x0b\n\x04\x04\0\x02\x01\x12\x03\r\x02\x1b\n\
-    \x0c\n\x05\x04\0\x02\x01\x04\x12\x03\r\x02\n\n\x0c\n\x05\x04\0\x02\x01\
-    \x05\x12\x03\r\x0b\x11\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\r\x12\x16\n\
-    \x0c\n\x05\x04\0\x02\x01\x03\x12\x03\r\x19\x1a\n\n\n\x02\x04\x01\x12\x04\
-    \x10\0\x14\x01\n\n\n\x03\x04\x01\x01\x12\x03\x10\x08\x0b\n\x0b\n\x04\x04\
-    \x01\x02\0\x12\x03\x11\x02!\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\x11\
-    \x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x11\x0b\x11\n\x0c\n\x05\x04\
-    \x01\x02\0\x01\x12\x03\x11\x12\x1c\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\
-    \x11\x1f\x20\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x12\x02\x1a\n\x0c\n\x05\
-    \x04\x01\x02\x01\x04\x12\x03\x12\x02\n\n\x0c\n\x05\x04\x01\x02\x01\x05\
-    \x12\x03\x12\x0b\x10\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x12\x11\x15\
-    \n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x12\x18\x19\n\x0b\n\x04\x04\x01\
-    \x02\x02\x12\x03\x13\x02$\n\x0c\n\x05\x04\x01\x02\x02\x04\x12\x03\x13\
-    \x02\n\n\x0c\n\x05\x04\x01\x02\x02\x06\x12\x03\x13\x0b\x14\n\x0c\n\x05\
-    \x04\x01\x02\x02\x01\x12\x03\x13\x15\x1f\n\x0c\n\x05\x04\x01\x02\x02\x03\
-    \x12\x03\x13\"#\n\n\n\x02\x05\x01\x12\x04\x16\0,\x01\n\n\n\x03\x05\x01\
-    \x01\x12\x03\x16\x05\x0c\n\x16\n\x04\x05\x01\x02\0\x12\x03\x18\x02\n\x1a\
-    \t\x20Generic\n\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x18\x02\x05\n\x0c\
-    \n\x05\x05\x01\x02\0\x02\x12\x03\x18\x08\t\n\x0b\n\x04\x05\x01\x02\x01\
-    \x12\x03\x19\x02\x0e\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\x19\x02\t\n\
-    \x0c\n\x05\x05\x01\x02\x01\x02\x12\x03\x19\x0c\r\n\x0b\n\x04\x05\x01\x02\
-    \x02\x12\x03\x1a\x02\x16\n\x0c\n\x05\x05\x01\x02\x02\x01\x12\x03\x1a\x02\
-    \x11\n\x0c\n\x05\x05\x01\x02\x02\x02\x12\x03\x1a\x14\x15\n\x0b\n\x04\x05\
-    \x01\x02\x03\x12\x03\x1b\x02\x17\n\x0c\n\x05\x05\x01\x02\x03\x01\x12\x03\
-    \x1b\x02\x12\n\x0c\n\x05\x05\x01\x02\x03\x02\x12\x03\x1b\x15\x16\n\x0b\n\
-    \x04\x05\x01\x02\x04\x12\x03\x1c\x02\x17\n\x0c\n\x05\x05\x01\x02\x04\x01\
-    \x12\x03\x1c\x02\x12\n\x0c\n\x05\x05\x01\x02\x04\x02\x12\x03\x1c\x15\x16\
-    \n\x0b\n\x04\x05\x01\x02\x05\x12\x03\x1d\x02\x0f\n\x0c\n\x05\x05\x01\x02\
-    \x05\x01\x12\x03\x1d\x02\n\n\x0c\n\x05\x05\x01\x02\x05\x02\x12\x03\x1d\r\
-    \x0e\n\x0b\n\x04\x05\x01\x02\x06\x12\x03\x1e\x02\x14\n\x0c\n\x05\x05\x01\
-    \x02\x06\x01\x12\x03\x1e\x02\x0f\n\x0c\n\x05\x05\x01\x02\x06\x02\x12\x03\
-    \x1e\x12\x13\n\x0b\n\x04\x05\x01\x02\x07\x12\x03\x1f\x02\x16\n\x0c\n\x05\
-    \x05\x01\x02\x07\x01\x12\x03\x1f\x02\x11\n\x0c\n\x05\x05\x01\x02\x07\x02\
-    \x12\x03\x1f\x14\x15\n\x0b\n\x04\x05\x01\x02\x08\x12\x03\x20\x02\x16\n\
-    \x0c\n\x05\x05\x01\x02\x08\x01\x12\x03\x20\x02\x11\n\x0c\n\x05\x05\x01\
-    \x02\x08\x02\x12\x03\x20\x14\x15\n\x0b\n\x04\x05\x01\x02\t\x12\x03!\x02\
-    \x0b\n\x0c\n\x05\x05\x01\x02\t\x01\x12\x03!\x02\x05\n\x0c\n\x05\x05\x01\
-    \x02\t\x02\x12\x03!\x08\n\n\x0b\n\x04\x05\x01\x02\n\x12\x03\"\x02\x12\n\
-    \x0c\n\x05\x05\x01\x02\n\x01\x12\x03\"\x02\x0c\n\x0c\n\x05\x05\x01\x02\n\
-    \x02\x12\x03\"\x0f\x11\n\x0b\n\x04\x05\x01\x02\x0b\x12\x03#\x02\x12\n\
-    \x0c\n\x05\x05\x01\x02\x0b\x01\x12\x03#\x02\x0c\n\x0c\n\x05\x05\x01\x02\
-    \x0b\x02\x12\x03#\x0f\x11\n\x15\n\x04\x05\x01\x02\x0c\x12\x03&\x02\x19\
-    \x1a\x08\x20Worker\n\n\x0c\n\x05\x05\x01\x02\x0c\x01\x12\x03&\x02\x11\n\
-    \x0c\n\x05\x05\x01\x02\x0c\x02\x12\x03&\x14\x18\n\x0b\n\x04\x05\x01\x02\
-    \r\x12\x03'\x02\x1a\n\x0c\n\x05\x05\x01\x02\r\x01\x12\x03'\x02\x12\n\x0c\
-    \n\x05\x05\x01\x02\r\x02\x12\x03'\x15\x19\n\x0b\n\x04\x05\x01\x02\x0e\
-    \x12\x03(\x02\x1b\n\x0c\n\x05\x05\x01\x02\x0e\x01\x12\x03(\x02\x13\n\x0c\
-    \n\x05\x05\x01\x02\x0e\x02\x12\x03(\x16\x1a\n\x0b\n\x04\x05\x01\x02\x0f\
-    \x12\x03)\x02\x13\n\x0c\n\x05\x05\x01\x02\x0f\x01\x12\x03)\x02\x0b\n\x0c\
-    \n\x05\x05\x01\x02\x0f\x02\x12\x03)\x0e\x12\n\x0b\n\x04\x05\x01\x02\x10\
-    \x12\x03*\x02\x0f\n\x0c\n\x05\x05\x01\x02\x10\x01\x12\x03*\x02\x07\n\x0c\
-    \n\x05\x05\x01\x02\x10\x02\x12\x03*\n\x0e\n\x0b\n\x04\x05\x01\x02\x11\
-    \x12\x03+\x02\x18\n\x0c\n\x05\x05\x01\x02\x11\x01\x12\x03+\x02\x10\n\x0c\
-    \n\x05\x05\x01\x02\x11\x02\x12\x03+\x13\x17\n\n\n\x02\x04\x02\x12\x04.\0\
-    1\x01\n\n\n\x03\x04\x02\x01\x12\x03.\x08\x10\n\x0b\n\x04\x04\x02\x02\0\
-    \x12\x03/\x02\x1c\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03/\x02\n\n\x0c\n\
-    \x05\x04\x02\x02\0\x06\x12\x03/\x0b\x12\n\x0c\n\x05\x04\x02\x02\0\x01\
-    \x12\x03/\x13\x17\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03/\x1a\x1b\n\x0b\n\
-    \x04\x04\x02\x02\x01\x12\x030\x02\x1a\n\x0c\n\x05\x04\x02\x02\x01\x04\
-    \x12\x030\x02\n\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x030\x0b\x11\n\x0c\n\
-    \x05\x04\x02\x02\x01\x01\x12\x030\x12\x15\n\x0c\n\x05\x04\x02\x02\x01\
-    \x03\x12\x030\x18\x19\n\t\n\x02\x04\x03\x12\x033\0\x10\n\n\n\x03\x04\x03\
-    \x01\x12\x033\x08\r\n\t\n\x02\x04\x04\x12\x034\0\x0f\n\n\n\x03\x04\x04\
-    \x01\x12\x034\x08\x0c\n\t\n\x02\x04\x05\x12\x035\0\x0f\n\n\n\x03\x04\x05\
-    \x01\x12\x035\x08\x0c\
+    \n\x05BUILD\x10\xec\x07\x12\x13\n\x0ePOST_PROCESSOR\x10\xed\x07\x12\x12\
+    \n\rBUILD_TIMEOUT\x10\xee\x07J\xc8\r\n\x06\x12\x04\0\06\x0f\n\x08\n\x01\
+    \x02\x12\x03\0\0\x0c\n\n\n\x02\x05\0\x12\x04\x02\0\t\x01\n\n\n\x03\x05\0\
+    \x01\x12\x03\x02\x05\r\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x02\n\n\x0c\n\
+    \x05\x05\0\x02\0\x01\x12\x03\x03\x02\x05\n\x0c\n\x05\x05\0\x02\0\x02\x12\
+    \x03\x03\x08\t\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x02\x0f\n\x0c\n\x05\
+    \x05\0\x02\x01\x01\x12\x03\x04\x02\n\n\x0c\n\x05\x05\0\x02\x01\x02\x12\
+    \x03\x04\r\x0e\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x02\x11\n\x0c\n\x05\
+    \x05\0\x02\x02\x01\x12\x03\x05\x02\x0c\n\x0c\n\x05\x05\0\x02\x02\x02\x12\
+    \x03\x05\x0f\x10\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\x02\x10\n\x0c\n\
+    \x05\x05\0\x02\x03\x01\x12\x03\x06\x02\x0b\n\x0c\n\x05\x05\0\x02\x03\x02\
+    \x12\x03\x06\x0e\x0f\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x07\x02\r\n\x0c\n\
+    \x05\x05\0\x02\x04\x01\x12\x03\x07\x02\x08\n\x0c\n\x05\x05\0\x02\x04\x02\
+    \x12\x03\x07\x0b\x0c\n\x0b\n\x04\x05\0\x02\x05\x12\x03\x08\x02\x10\n\x0c\
+    \n\x05\x05\0\x02\x05\x01\x12\x03\x08\x02\x0b\n\x0c\n\x05\x05\0\x02\x05\
+    \x02\x12\x03\x08\x0e\x0f\n\n\n\x02\x04\0\x12\x04\x0b\0\x0e\x01\n\n\n\x03\
+    \x04\0\x01\x12\x03\x0b\x08\x11\n\x0b\n\x04\x04\0\x02\0\x12\x03\x0c\x02!\
+    \n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\x0c\x02\n\n\x0c\n\x05\x04\0\x02\0\
+    \x06\x12\x03\x0c\x0b\x13\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x0c\x14\x1c\
+    \n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x0c\x1f\x20\n\x0b\n\x04\x04\0\x02\
+    \x01\x12\x03\r\x02\x1b\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03\r\x02\n\n\
+    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\r\x0b\x11\n\x0c\n\x05\x04\0\x02\x01\
+    \x01\x12\x03\r\x12\x16\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\r\x19\x1a\n\
+    \n\n\x02\x04\x01\x12\x04\x10\0\x14\x01\n\n\n\x03\x04\x01\x01\x12\x03\x10\
+    \x08\x0b\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x11\x02!\n\x0c\n\x05\x04\x01\
+    \x02\0\x04\x12\x03\x11\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x11\
+    \x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x11\x12\x1c\n\x0c\n\x05\
+    \x04\x01\x02\0\x03\x12\x03\x11\x1f\x20\n\x0b\n\x04\x04\x01\x02\x01\x12\
+    \x03\x12\x02\x1a\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03\x12\x02\n\n\x0c\
+    \n\x05\x04\x01\x02\x01\x05\x12\x03\x12\x0b\x10\n\x0c\n\x05\x04\x01\x02\
+    \x01\x01\x12\x03\x12\x11\x15\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x12\
+    \x18\x19\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x13\x02$\n\x0c\n\x05\x04\
+    \x01\x02\x02\x04\x12\x03\x13\x02\n\n\x0c\n\x05\x04\x01\x02\x02\x06\x12\
+    \x03\x13\x0b\x14\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x13\x15\x1f\n\
+    \x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x13\"#\n\n\n\x02\x05\x01\x12\x04\
+    \x16\0-\x01\n\n\n\x03\x05\x01\x01\x12\x03\x16\x05\x0c\n\x16\n\x04\x05\
+    \x01\x02\0\x12\x03\x18\x02\n\x1a\t\x20Generic\n\n\x0c\n\x05\x05\x01\x02\
+    \0\x01\x12\x03\x18\x02\x05\n\x0c\n\x05\x05\x01\x02\0\x02\x12\x03\x18\x08\
+    \t\n\x0b\n\x04\x05\x01\x02\x01\x12\x03\x19\x02\x0e\n\x0c\n\x05\x05\x01\
+    \x02\x01\x01\x12\x03\x19\x02\t\n\x0c\n\x05\x05\x01\x02\x01\x02\x12\x03\
+    \x19\x0c\r\n\x0b\n\x04\x05\x01\x02\x02\x12\x03\x1a\x02\x16\n\x0c\n\x05\
+    \x05\x01\x02\x02\x01\x12\x03\x1a\x02\x11\n\x0c\n\x05\x05\x01\x02\x02\x02\
+    \x12\x03\x1a\x14\x15\n\x0b\n\x04\x05\x01\x02\x03\x12\x03\x1b\x02\x17\n\
+    \x0c\n\x05\x05\x01\x02\x03\x01\x12\x03\x1b\x02\x12\n\x0c\n\x05\x05\x01\
+    \x02\x03\x02\x12\x03\x1b\x15\x16\n\x0b\n\x04\x05\x01\x02\x04\x12\x03\x1c\
+    \x02\x17\n\x0c\n\x05\x05\x01\x02\x04\x01\x12\x03\x1c\x02\x12\n\x0c\n\x05\
+    \x05\x01\x02\x04\x02\x12\x03\x1c\x15\x16\n\x0b\n\x04\x05\x01\x02\x05\x12\
+    \x03\x1d\x02\x0f\n\x0c\n\x05\x05\x01\x02\x05\x01\x12\x03\x1d\x02\n\n\x0c\
+    \n\x05\x05\x01\x02\x05\x02\x12\x03\x1d\r\x0e\n\x0b\n\x04\x05\x01\x02\x06\
+    \x12\x03\x1e\x02\x14\n\x0c\n\x05\x05\x01\x02\x06\x01\x12\x03\x1e\x02\x0f\
+    \n\x0c\n\x05\x05\x01\x02\x06\x02\x12\x03\x1e\x12\x13\n\x0b\n\x04\x05\x01\
+    \x02\x07\x12\x03\x1f\x02\x16\n\x0c\n\x05\x05\x01\x02\x07\x01\x12\x03\x1f\
+    \x02\x11\n\x0c\n\x05\x05\x01\x02\x07\x02\x12\x03\x1f\x14\x15\n\x0b\n\x04\
+    \x05\x01\x02\x08\x12\x03\x20\x02\x16\n\x0c\n\x05\x05\x01\x02\x08\x01\x12\
+    \x03\x20\x02\x11\n\x0c\n\x05\x05\x01\x02\x08\x02\x12\x03\x20\x14\x15\n\
+    \x0b\n\x04\x05\x01\x02\t\x12\x03!\x02\x0b\n\x0c\n\x05\x05\x01\x02\t\x01\
+    \x12\x03!\x02\x05\n\x0c\n\x05\x05\x01\x02\t\x02\x12\x03!\x08\n\n\x0b\n\
+    \x04\x05\x01\x02\n\x12\x03\"\x02\x12\n\x0c\n\x05\x05\x01\x02\n\x01\x12\
+    \x03\"\x02\x0c\n\x0c\n\x05\x05\x01\x02\n\x02\x12\x03\"\x0f\x11\n\x0b\n\
+    \x04\x05\x01\x02\x0b\x12\x03#\x02\x12\n\x0c\n\x05\x05\x01\x02\x0b\x01\
+    \x12\x03#\x02\x0c\n\x0c\n\x05\x05\x01\x02\x0b\x02\x12\x03#\x0f\x11\n\x15\
+    \n\x04\x05\x01\x02\x0c\x12\x03&\x02\x19\x1a\x08\x20Worker\n\n\x0c\n\x05\
+    \x05\x01\x02\x0c\x01\x12\x03&\x02\x11\n\x0c\n\x05\x05\x01\x02\x0c\x02\
+    \x12\x03&\x14\x18\n\x0b\n\x04\x05\x01\x02\r\x12\x03'\x02\x1a\n\x0c\n\x05\
+    \x05\x01\x02\r\x01\x12\x03'\x02\x12\n\x0c\n\x05\x05\x01\x02\r\x02\x12\
+    \x03'\x15\x19\n\x0b\n\x04\x05\x01\x02\x0e\x12\x03(\x02\x1b\n\x0c\n\x05\
+    \x05\x01\x02\x0e\x01\x12\x03(\x02\x13\n\x0c\n\x05\x05\x01\x02\x0e\x02\
+    \x12\x03(\x16\x1a\n\x0b\n\x04\x05\x01\x02\x0f\x12\x03)\x02\x13\n\x0c\n\
+    \x05\x05\x01\x02\x0f\x01\x12\x03)\x02\x0b\n\x0c\n\x05\x05\x01\x02\x0f\
+    \x02\x12\x03)\x0e\x12\n\x0b\n\x04\x05\x01\x02\x10\x12\x03*\x02\x0f\n\x0c\
+    \n\x05\x05\x01\x02\x10\x01\x12\x03*\x02\x07\n\x0c\n\x05\x05\x01\x02\x10\
+    \x02\x12\x03*\n\x0e\n\x0b\n\x04\x05\x01\x02\x11\x12\x03+\x02\x18\n\x0c\n\
+    \x05\x05\x01\x02\x11\x01\x12\x03+\x02\x10\n\x0c\n\x05\x05\x01\x02\x11\
+    \x02\x12\x03+\x13\x17\n\x0b\n\x04\x05\x01\x02\x12\x12\x03,\x02\x17\n\x0c\
+    \n\x05\x05\x01\x02\x12\x01\x12\x03,\x02\x0f\n\x0c\n\x05\x05\x01\x02\x12\
+    \x02\x12\x03,\x12\x16\n\n\n\x02\x04\x02\x12\x04/\02\x01\n\n\n\x03\x04\
+    \x02\x01\x12\x03/\x08\x10\n\x0b\n\x04\x04\x02\x02\0\x12\x030\x02\x1c\n\
+    \x0c\n\x05\x04\x02\x02\0\x04\x12\x030\x02\n\n\x0c\n\x05\x04\x02\x02\0\
+    \x06\x12\x030\x0b\x12\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x030\x13\x17\n\
+    \x0c\n\x05\x04\x02\x02\0\x03\x12\x030\x1a\x1b\n\x0b\n\x04\x04\x02\x02\
+    \x01\x12\x031\x02\x1a\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\x031\x02\n\n\
+    \x0c\n\x05\x04\x02\x02\x01\x05\x12\x031\x0b\x11\n\x0c\n\x05\x04\x02\x02\
+    \x01\x01\x12\x031\x12\x15\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x031\x18\
+    \x19\n\t\n\x02\x04\x03\x12\x034\0\x10\n\n\n\x03\x04\x03\x01\x12\x034\x08\
+    \r\n\t\n\x02\x04\x04\x12\x035\0\x0f\n\n\n\x03\x04\x04\x01\x12\x035\x08\
+    \x0c\n\t\n\x02\x04\x05\x12\x036\0\x0f\n\n\n\x03\x04\x05\x01\x12\x036\x08\
+    \x0c\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -3545,6 +3545,7 @@ pub struct OriginChannelPackageGet {
     // message fields
     name: ::protobuf::SingularField<::std::string::String>,
     ident: ::protobuf::SingularPtrField<OriginPackageIdent>,
+    target: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -3652,6 +3653,50 @@ impl OriginChannelPackageGet {
     fn mut_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<OriginPackageIdent> {
         &mut self.ident
     }
+
+    // optional string target = 3;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        self.target.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_target(&self) -> &str {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.target
+    }
+
+    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.target
+    }
 }
 
 impl ::protobuf::Message for OriginChannelPackageGet {
@@ -3674,6 +3719,9 @@ impl ::protobuf::Message for OriginChannelPackageGet {
                 2 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ident)?;
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -3693,6 +3741,9 @@ impl ::protobuf::Message for OriginChannelPackageGet {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if let Some(ref v) = self.target.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3707,6 +3758,9 @@ impl ::protobuf::Message for OriginChannelPackageGet {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_string(3, &v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3761,6 +3815,11 @@ impl ::protobuf::MessageStatic for OriginChannelPackageGet {
                     OriginChannelPackageGet::get_ident_for_reflect,
                     OriginChannelPackageGet::mut_ident_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "target",
+                    OriginChannelPackageGet::get_target_for_reflect,
+                    OriginChannelPackageGet::mut_target_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<OriginChannelPackageGet>(
                     "OriginChannelPackageGet",
                     fields,
@@ -3775,6 +3834,7 @@ impl ::protobuf::Clear for OriginChannelPackageGet {
     fn clear(&mut self) {
         self.clear_name();
         self.clear_ident();
+        self.clear_target();
         self.unknown_fields.clear();
     }
 }
@@ -7153,6 +7213,11 @@ pub struct OriginPackage {
     exposes: ::std::vec::Vec<u32>,
     config: ::protobuf::SingularField<::std::string::String>,
     target: ::protobuf::SingularField<::std::string::String>,
+    yanked: ::std::option::Option<bool>,
+    description: ::protobuf::SingularField<::std::string::String>,
+    licenses: ::protobuf::RepeatedField<::std::string::String>,
+    upstream_url: ::protobuf::SingularField<::std::string::String>,
+    maintainer: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -7572,6 +7637,198 @@ impl OriginPackage {
     fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
         &mut self.target
     }
+
+    // optional bool yanked = 12;
+
+    pub fn clear_yanked(&mut self) {
+        self.yanked = ::std::option::Option::None;
+    }
+
+    pub fn has_yanked(&self) -> bool {
+        self.yanked.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_yanked(&mut self, v: bool) {
+        self.yanked = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_yanked(&self) -> bool {
+        self.yanked.unwrap_or(false)
+    }
+
+    fn get_yanked_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.yanked
+    }
+
+    fn mut_yanked_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.yanked
+    }
+
+    // optional string description = 13;
+
+    pub fn clear_description(&mut self) {
+        self.description.clear();
+    }
+
+    pub fn has_description(&self) -> bool {
+        self.description.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        if self.description.is_none() {
+            self.description.set_default();
+        }
+        self.description.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        self.description.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_description(&self) -> &str {
+        match self.description.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_description_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.description
+    }
+
+    fn mut_description_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.description
+    }
+
+    // repeated string licenses = 14;
+
+    pub fn clear_licenses(&mut self) {
+        self.licenses.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_licenses(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.licenses = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_licenses(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.licenses
+    }
+
+    // Take field
+    pub fn take_licenses(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.licenses, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_licenses(&self) -> &[::std::string::String] {
+        &self.licenses
+    }
+
+    fn get_licenses_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.licenses
+    }
+
+    fn mut_licenses_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.licenses
+    }
+
+    // optional string upstream_url = 15;
+
+    pub fn clear_upstream_url(&mut self) {
+        self.upstream_url.clear();
+    }
+
+    pub fn has_upstream_url(&self) -> bool {
+        self.upstream_url.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_upstream_url(&mut self, v: ::std::string::String) {
+        self.upstream_url = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_upstream_url(&mut self) -> &mut ::std::string::String {
+        if self.upstream_url.is_none() {
+            self.upstream_url.set_default();
+        }
+        self.upstream_url.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_upstream_url(&mut self) -> ::std::string::String {
+        self.upstream_url.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_upstream_url(&self) -> &str {
+        match self.upstream_url.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_upstream_url_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.upstream_url
+    }
+
+    fn mut_upstream_url_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.upstream_url
+    }
+
+    // optional string maintainer = 16;
+
+    pub fn clear_maintainer(&mut self) {
+        self.maintainer.clear();
+    }
+
+    pub fn has_maintainer(&self) -> bool {
+        self.maintainer.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_maintainer(&mut self, v: ::std::string::String) {
+        self.maintainer = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_maintainer(&mut self) -> &mut ::std::string::String {
+        if self.maintainer.is_none() {
+            self.maintainer.set_default();
+        }
+        self.maintainer.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_maintainer(&mut self) -> ::std::string::String {
+        self.maintainer.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_maintainer(&self) -> &str {
+        match self.maintainer.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_maintainer_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.maintainer
+    }
+
+    fn mut_maintainer_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.maintainer
+    }
 }
 
 impl ::protobuf::Message for OriginPackage {
@@ -7643,6 +7900,25 @@ impl ::protobuf::Message for OriginPackage {
                 11 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
                 },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.yanked = ::std::option::Option::Some(tmp);
+                },
+                13 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.description)?;
+                },
+                14 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.licenses)?;
+                },
+                15 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.upstream_url)?;
+                },
+                16 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.maintainer)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -7691,6 +7967,21 @@ impl ::protobuf::Message for OriginPackage {
         if let Some(ref v) = self.target.as_ref() {
             my_size += ::protobuf::rt::string_size(11, &v);
         }
+        if let Some(v) = self.yanked {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.description.as_ref() {
+            my_size += ::protobuf::rt::string_size(13, &v);
+        }
+        for value in &self.licenses {
+            my_size += ::protobuf::rt::string_size(14, &value);
+        };
+        if let Some(ref v) = self.upstream_url.as_ref() {
+            my_size += ::protobuf::rt::string_size(15, &v);
+        }
+        if let Some(ref v) = self.maintainer.as_ref() {
+            my_size += ::protobuf::rt::string_size(16, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -7741,6 +8032,21 @@ impl ::protobuf::Message for OriginPackage {
         if let Some(ref v) = self.target.as_ref() {
             os.write_string(11, &v)?;
         }
+        if let Some(v) = self.yanked {
+            os.write_bool(12, v)?;
+        }
+        if let Some(ref v) = self.description.as_ref() {
+            os.write_string(13, &v)?;
+        }
+        for v in &self.licenses {
+            os.write_string(14, &v)?;
+        };
+        if let Some(ref v) = self.upstream_url.as_ref() {
+            os.write_string(15, &v)?;
+        }
+        if let Some(ref v) = self.maintainer.as_ref() {
+            os.write_string(16, &v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -7840,6 +8146,31 @@ impl ::protobuf::MessageStatic for OriginPackage {
                     OriginPackage::get_target_for_reflect,
                     OriginPackage::mut_target_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "yanked",
+                    OriginPackage::get_yanked_for_reflect,
+                    OriginPackage::mut_yanked_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "description",
+                    OriginPackage::get_description_for_reflect,
+                    OriginPackage::mut_description_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "licenses",
+                    OriginPackage::get_licenses_for_reflect,
+                    OriginPackage::mut_licenses_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "upstream_url",
+                    OriginPackage::get_upstream_url_for_reflect,
+                    OriginPackage::mut_upstream_url_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "maintainer",
+                    OriginPackage::get_maintainer_for_reflect,
+                    OriginPackage::mut_maintainer_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<OriginPackage>(
                     "OriginPackage",
                     fields,
@@ -7863,6 +8194,11 @@ impl ::protobuf::Clear for OriginPackage {
         self.clear_exposes();
         self.clear_config();
         self.clear_target();
+        self.clear_yanked();
+        self.clear_description();
+        self.clear_licenses();
+        self.clear_upstream_url();
+        self.clear_maintainer();
         self.unknown_fields.clear();
     }
 }
@@ -8671,6 +9007,10 @@ pub struct OriginPackageCreate {
     exposes: ::std::vec::Vec<u32>,
     config: ::protobuf::SingularField<::std::string::String>,
     target: ::protobuf::SingularField<::std::string::String>,
+    description: ::protobuf::SingularField<::std::string::String>,
+    licenses: ::protobuf::RepeatedField<::std::string::String>,
+    upstream_url: ::protobuf::SingularField<::std::string::String>,
+    maintainer: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -9063,69 +9403,246 @@ impl OriginPackageCreate {
     fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
         &mut self.target
     }
-}
 
-impl ::protobuf::Message for OriginPackageCreate {
-    fn is_initialized(&self) -> bool {
-        for v in &self.ident {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.deps {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.tdeps {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        true
+    // optional string description = 11;
+
+    pub fn clear_description(&mut self) {
+        self.description.clear();
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.owner_id = ::std::option::Option::Some(tmp);
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.origin_id = ::std::option::Option::Some(tmp);
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ident)?;
-                },
-                4 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.checksum)?;
-                },
-                5 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.manifest)?;
-                },
-                6 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.deps)?;
-                },
-                7 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.tdeps)?;
-                },
-                8 => {
-                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.exposes)?;
-                },
-                9 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.config)?;
-                },
-                10 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
+    pub fn has_description(&self) -> bool {
+        self.description.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        if self.description.is_none() {
+            self.description.set_default();
+        }
+        self.description.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        self.description.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_description(&self) -> &str {
+        match self.description.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_description_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.description
+    }
+
+    fn mut_description_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.description
+    }
+
+    // repeated string licenses = 12;
+
+    pub fn clear_licenses(&mut self) {
+        self.licenses.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_licenses(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.licenses = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_licenses(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.licenses
+    }
+
+    // Take field
+    pub fn take_licenses(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.licenses, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_licenses(&self) -> &[::std::string::String] {
+        &self.licenses
+    }
+
+    fn get_licenses_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.licenses
+    }
+
+    fn mut_licenses_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.licenses
+    }
+
+    // optional string upstream_url = 13;
+
+    pub fn clear_upstream_url(&mut self) {
+        self.upstream_url.clear();
+    }
+
+    pub fn has_upstream_url(&self) -> bool {
+        self.upstream_url.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_upstream_url(&mut self, v: ::std::string::String) {
+        self.upstream_url = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_upstream_url(&mut self) -> &mut ::std::string::String {
+        if self.upstream_url.is_none() {
+            self.upstream_url.set_default();
+        }
+        self.upstream_url.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_upstream_url(&mut self) -> ::std::string::String {
+        self.upstream_url.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_upstream_url(&self) -> &str {
+        match self.upstream_url.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_upstream_url_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.upstream_url
+    }
+
+    fn mut_upstream_url_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.upstream_url
+    }
+
+    // optional string maintainer = 14;
+
+    pub fn clear_maintainer(&mut self) {
+        self.maintainer.clear();
+    }
+
+    pub fn has_maintainer(&self) -> bool {
+        self.maintainer.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_maintainer(&mut self, v: ::std::string::String) {
+        self.maintainer = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_maintainer(&mut self) -> &mut ::std::string::String {
+        if self.maintainer.is_none() {
+            self.maintainer.set_default();
+        }
+        self.maintainer.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_maintainer(&mut self) -> ::std::string::String {
+        self.maintainer.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_maintainer(&self) -> &str {
+        match self.maintainer.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_maintainer_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.maintainer
+    }
+
+    fn mut_maintainer_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.maintainer
+    }
+}
+
+impl ::protobuf::Message for OriginPackageCreate {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ident {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.deps {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.tdeps {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ident)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.checksum)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.manifest)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.deps)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.tdeps)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.exposes)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.config)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.description)?;
+                },
+                12 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.licenses)?;
+                },
+                13 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.upstream_url)?;
+                },
+                14 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.maintainer)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -9172,6 +9689,18 @@ impl ::protobuf::Message for OriginPackageCreate {
         if let Some(ref v) = self.target.as_ref() {
             my_size += ::protobuf::rt::string_size(10, &v);
         }
+        if let Some(ref v) = self.description.as_ref() {
+            my_size += ::protobuf::rt::string_size(11, &v);
+        }
+        for value in &self.licenses {
+            my_size += ::protobuf::rt::string_size(12, &value);
+        };
+        if let Some(ref v) = self.upstream_url.as_ref() {
+            my_size += ::protobuf::rt::string_size(13, &v);
+        }
+        if let Some(ref v) = self.maintainer.as_ref() {
+            my_size += ::protobuf::rt::string_size(14, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -9219,6 +9748,18 @@ impl ::protobuf::Message for OriginPackageCreate {
         if let Some(ref v) = self.target.as_ref() {
             os.write_string(10, &v)?;
         }
+        if let Some(ref v) = self.description.as_ref() {
+            os.write_string(11, &v)?;
+        }
+        for v in &self.licenses {
+            os.write_string(12, &v)?;
+        };
+        if let Some(ref v) = self.upstream_url.as_ref() {
+            os.write_string(13, &v)?;
+        }
+        if let Some(ref v) = self.maintainer.as_ref() {
+            os.write_string(14, &v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -9313,6 +9854,26 @@ impl ::protobuf::MessageStatic for OriginPackageCreate {
                     OriginPackageCreate::get_target_for_reflect,
                     OriginPackageCreate::mut_target_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "description",
+                    OriginPackageCreate::get_description_for_reflect,
+                    OriginPackageCreate::mut_description_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "licenses",
+                    OriginPackageCreate::get_licenses_for_reflect,
+                    OriginPackageCreate::mut_licenses_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "upstream_url",
+                    OriginPackageCreate::get_upstream_url_for_reflect,
+                    OriginPackageCreate::mut_upstream_url_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "maintainer",
+                    OriginPackageCreate::get_maintainer_for_reflect,
+                    OriginPackageCreate::mut_maintainer_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<OriginPackageCreate>(
                     "OriginPackageCreate",
                     fields,
@@ -9335,6 +9896,10 @@ impl ::protobuf::Clear for OriginPackageCreate {
         self.clear_exposes();
         self.clear_config();
         self.clear_target();
+        self.clear_description();
+        self.clear_licenses();
+        self.clear_upstream_url();
+        self.clear_maintainer();
         self.unknown_fields.clear();
     }
 }
@@ -9355,6 +9920,7 @@ impl ::protobuf::reflect::ProtobufValue for OriginPackageCreate {
 pub struct OriginPackageGet {
     // message fields
     ident: ::protobuf::SingularPtrField<OriginPackageIdent>,
+    target: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -9418,6 +9984,50 @@ impl OriginPackageGet {
     fn mut_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<OriginPackageIdent> {
         &mut self.ident
     }
+
+    // optional string target = 2;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        self.target.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_target(&self) -> &str {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.target
+    }
+
+    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.target
+    }
 }
 
 impl ::protobuf::Message for OriginPackageGet {
@@ -9437,6 +10047,9 @@ impl ::protobuf::Message for OriginPackageGet {
                 1 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ident)?;
                 },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -9453,6 +10066,9 @@ impl ::protobuf::Message for OriginPackageGet {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if let Some(ref v) = self.target.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -9464,6 +10080,9 @@ impl ::protobuf::Message for OriginPackageGet {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_string(2, &v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -9513,6 +10132,11 @@ impl ::protobuf::MessageStatic for OriginPackageGet {
                     OriginPackageGet::get_ident_for_reflect,
                     OriginPackageGet::mut_ident_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "target",
+                    OriginPackageGet::get_target_for_reflect,
+                    OriginPackageGet::mut_target_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<OriginPackageGet>(
                     "OriginPackageGet",
                     fields,
@@ -9526,6 +10150,7 @@ impl ::protobuf::MessageStatic for OriginPackageGet {
 impl ::protobuf::Clear for OriginPackageGet {
     fn clear(&mut self) {
         self.clear_ident();
+        self.clear_target();
         self.unknown_fields.clear();
     }
 }
@@ -11020,208 +11645,137 @@ impl ::protobuf::reflect::ProtobufValue for OriginPackageDemote {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct OriginPackageSearchRequest {
+pub struct OriginPackageYank {
     // message fields
-    origin: ::protobuf::SingularField<::std::string::String>,
-    query: ::protobuf::SingularField<::std::string::String>,
-    start: ::std::option::Option<u64>,
-    stop: ::std::option::Option<u64>,
-    distinct: ::std::option::Option<bool>,
+    package_id: ::std::option::Option<u64>,
+    ident: ::protobuf::SingularPtrField<OriginPackageIdent>,
+    yanked: ::std::option::Option<bool>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginPackageSearchRequest {}
+unsafe impl ::std::marker::Sync for OriginPackageYank {}
 
-impl OriginPackageSearchRequest {
-    pub fn new() -> OriginPackageSearchRequest {
+impl OriginPackageYank {
+    pub fn new() -> OriginPackageYank {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginPackageSearchRequest {
-        static mut instance: ::protobuf::lazy::Lazy<OriginPackageSearchRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginPackageYank {
+        static mut instance: ::protobuf::lazy::Lazy<OriginPackageYank> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginPackageSearchRequest,
+            ptr: 0 as *const OriginPackageYank,
         };
         unsafe {
-            instance.get(OriginPackageSearchRequest::new)
+            instance.get(OriginPackageYank::new)
         }
     }
 
-    // optional string origin = 1;
+    // optional uint64 package_id = 1;
 
-    pub fn clear_origin(&mut self) {
-        self.origin.clear();
+    pub fn clear_package_id(&mut self) {
+        self.package_id = ::std::option::Option::None;
     }
 
-    pub fn has_origin(&self) -> bool {
-        self.origin.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_origin(&mut self, v: ::std::string::String) {
-        self.origin = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
-        if self.origin.is_none() {
-            self.origin.set_default();
-        }
-        self.origin.as_mut().unwrap()
+    pub fn has_package_id(&self) -> bool {
+        self.package_id.is_some()
     }
 
-    // Take field
-    pub fn take_origin(&mut self) -> ::std::string::String {
-        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    // Param is passed by value, moved
+    pub fn set_package_id(&mut self, v: u64) {
+        self.package_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_origin(&self) -> &str {
-        match self.origin.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_package_id(&self) -> u64 {
+        self.package_id.unwrap_or(0)
     }
 
-    fn get_origin_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.origin
+    fn get_package_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.package_id
     }
 
-    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.origin
+    fn mut_package_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.package_id
     }
 
-    // optional string query = 2;
+    // optional .originsrv.OriginPackageIdent ident = 2;
 
-    pub fn clear_query(&mut self) {
-        self.query.clear();
+    pub fn clear_ident(&mut self) {
+        self.ident.clear();
     }
 
-    pub fn has_query(&self) -> bool {
-        self.query.is_some()
+    pub fn has_ident(&self) -> bool {
+        self.ident.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_query(&mut self, v: ::std::string::String) {
-        self.query = ::protobuf::SingularField::some(v);
+    pub fn set_ident(&mut self, v: OriginPackageIdent) {
+        self.ident = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_query(&mut self) -> &mut ::std::string::String {
-        if self.query.is_none() {
-            self.query.set_default();
+    pub fn mut_ident(&mut self) -> &mut OriginPackageIdent {
+        if self.ident.is_none() {
+            self.ident.set_default();
         }
-        self.query.as_mut().unwrap()
+        self.ident.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_query(&mut self) -> ::std::string::String {
-        self.query.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_query(&self) -> &str {
-        match self.query.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
-
-    fn get_query_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.query
-    }
-
-    fn mut_query_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.query
-    }
-
-    // optional uint64 start = 3;
-
-    pub fn clear_start(&mut self) {
-        self.start = ::std::option::Option::None;
-    }
-
-    pub fn has_start(&self) -> bool {
-        self.start.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_start(&mut self, v: u64) {
-        self.start = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_start(&self) -> u64 {
-        self.start.unwrap_or(0)
-    }
-
-    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.start
-    }
-
-    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.start
-    }
-
-    // optional uint64 stop = 4;
-
-    pub fn clear_stop(&mut self) {
-        self.stop = ::std::option::Option::None;
-    }
-
-    pub fn has_stop(&self) -> bool {
-        self.stop.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_stop(&mut self, v: u64) {
-        self.stop = ::std::option::Option::Some(v);
+    pub fn take_ident(&mut self) -> OriginPackageIdent {
+        self.ident.take().unwrap_or_else(|| OriginPackageIdent::new())
     }
 
-    pub fn get_stop(&self) -> u64 {
-        self.stop.unwrap_or(0)
+    pub fn get_ident(&self) -> &OriginPackageIdent {
+        self.ident.as_ref().unwrap_or_else(|| OriginPackageIdent::default_instance())
     }
 
-    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.stop
+    fn get_ident_for_reflect(&self) -> &::protobuf::SingularPtrField<OriginPackageIdent> {
+        &self.ident
     }
 
-    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.stop
+    fn mut_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<OriginPackageIdent> {
+        &mut self.ident
     }
 
-    // optional bool distinct = 5;
+    // optional bool yanked = 3;
 
-    pub fn clear_distinct(&mut self) {
-        self.distinct = ::std::option::Option::None;
+    pub fn clear_yanked(&mut self) {
+        self.yanked = ::std::option::Option::None;
     }
 
-    pub fn has_distinct(&self) -> bool {
-        self.distinct.is_some()
+    pub fn has_yanked(&self) -> bool {
+        self.yanked.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_distinct(&mut self, v: bool) {
-        self.distinct = ::std::option::Option::Some(v);
+    pub fn set_yanked(&mut self, v: bool) {
+        self.yanked = ::std::option::Option::Some(v);
     }
 
-    pub fn get_distinct(&self) -> bool {
-        self.distinct.unwrap_or(false)
+    pub fn get_yanked(&self) -> bool {
+        self.yanked.unwrap_or(false)
     }
 
-    fn get_distinct_for_reflect(&self) -> &::std::option::Option<bool> {
-        &self.distinct
+    fn get_yanked_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.yanked
     }
 
-    fn mut_distinct_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
-        &mut self.distinct
+    fn mut_yanked_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.yanked
     }
 }
 
-impl ::protobuf::Message for OriginPackageSearchRequest {
+impl ::protobuf::Message for OriginPackageYank {
     fn is_initialized(&self) -> bool {
+        for v in &self.ident {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -11230,31 +11784,21 @@ impl ::protobuf::Message for OriginPackageSearchRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.query)?;
-                },
-                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.start = ::std::option::Option::Some(tmp);
+                    self.package_id = ::std::option::Option::Some(tmp);
                 },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.stop = ::std::option::Option::Some(tmp);
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ident)?;
                 },
-                5 => {
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_bool()?;
-                    self.distinct = ::std::option::Option::Some(tmp);
+                    self.yanked = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -11268,19 +11812,14 @@ impl ::protobuf::Message for OriginPackageSearchRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.origin.as_ref() {
-            my_size += ::protobuf::rt::string_size(1, &v);
-        }
-        if let Some(ref v) = self.query.as_ref() {
-            my_size += ::protobuf::rt::string_size(2, &v);
-        }
-        if let Some(v) = self.start {
-            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(v) = self.package_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(v) = self.stop {
-            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(ref v) = self.ident.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
-        if let Some(v) = self.distinct {
+        if let Some(v) = self.yanked {
             my_size += 2;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -11289,20 +11828,16 @@ impl ::protobuf::Message for OriginPackageSearchRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.origin.as_ref() {
-            os.write_string(1, &v)?;
-        }
-        if let Some(ref v) = self.query.as_ref() {
-            os.write_string(2, &v)?;
-        }
-        if let Some(v) = self.start {
-            os.write_uint64(3, v)?;
+        if let Some(v) = self.package_id {
+            os.write_uint64(1, v)?;
         }
-        if let Some(v) = self.stop {
-            os.write_uint64(4, v)?;
+        if let Some(ref v) = self.ident.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
-        if let Some(v) = self.distinct {
-            os.write_bool(5, v)?;
+        if let Some(v) = self.yanked {
+            os.write_bool(3, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -11335,12 +11870,12 @@ impl ::protobuf::Message for OriginPackageSearchRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginPackageSearchRequest {
-    fn new() -> OriginPackageSearchRequest {
-        OriginPackageSearchRequest::new()
+impl ::protobuf::MessageStatic for OriginPackageYank {
+    fn new() -> OriginPackageYank {
+        OriginPackageYank::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginPackageSearchRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginPackageYank>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -11348,33 +11883,23 @@ impl ::protobuf::MessageStatic for OriginPackageSearchRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "origin",
-                    OriginPackageSearchRequest::get_origin_for_reflect,
-                    OriginPackageSearchRequest::mut_origin_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "query",
-                    OriginPackageSearchRequest::get_query_for_reflect,
-                    OriginPackageSearchRequest::mut_query_for_reflect,
-                ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "start",
-                    OriginPackageSearchRequest::get_start_for_reflect,
-                    OriginPackageSearchRequest::mut_start_for_reflect,
+                    "package_id",
+                    OriginPackageYank::get_package_id_for_reflect,
+                    OriginPackageYank::mut_package_id_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "stop",
-                    OriginPackageSearchRequest::get_stop_for_reflect,
-                    OriginPackageSearchRequest::mut_stop_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OriginPackageIdent>>(
+                    "ident",
+                    OriginPackageYank::get_ident_for_reflect,
+                    OriginPackageYank::mut_ident_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "distinct",
-                    OriginPackageSearchRequest::get_distinct_for_reflect,
-                    OriginPackageSearchRequest::mut_distinct_for_reflect,
+                    "yanked",
+                    OriginPackageYank::get_yanked_for_reflect,
+                    OriginPackageYank::mut_yanked_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginPackageSearchRequest>(
-                    "OriginPackageSearchRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginPackageYank>(
+                    "OriginPackageYank",
                     fields,
                     file_descriptor_proto()
                 )
@@ -11383,159 +11908,131 @@ impl ::protobuf::MessageStatic for OriginPackageSearchRequest {
     }
 }
 
-impl ::protobuf::Clear for OriginPackageSearchRequest {
+impl ::protobuf::Clear for OriginPackageYank {
     fn clear(&mut self) {
-        self.clear_origin();
-        self.clear_query();
-        self.clear_start();
-        self.clear_stop();
-        self.clear_distinct();
+        self.clear_package_id();
+        self.clear_ident();
+        self.clear_yanked();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for OriginPackageSearchRequest {
+impl ::std::fmt::Debug for OriginPackageYank {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OriginPackageSearchRequest {
+impl ::protobuf::reflect::ProtobufValue for OriginPackageYank {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct OriginPackageUniqueListRequest {
+pub struct OriginPackageDelete {
     // message fields
-    origin: ::protobuf::SingularField<::std::string::String>,
-    start: ::std::option::Option<u64>,
-    stop: ::std::option::Option<u64>,
+    package_id: ::std::option::Option<u64>,
+    ident: ::protobuf::SingularPtrField<OriginPackageIdent>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginPackageUniqueListRequest {}
+unsafe impl ::std::marker::Sync for OriginPackageDelete {}
 
-impl OriginPackageUniqueListRequest {
-    pub fn new() -> OriginPackageUniqueListRequest {
+impl OriginPackageDelete {
+    pub fn new() -> OriginPackageDelete {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginPackageUniqueListRequest {
-        static mut instance: ::protobuf::lazy::Lazy<OriginPackageUniqueListRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginPackageDelete {
+        static mut instance: ::protobuf::lazy::Lazy<OriginPackageDelete> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginPackageUniqueListRequest,
+            ptr: 0 as *const OriginPackageDelete,
         };
         unsafe {
-            instance.get(OriginPackageUniqueListRequest::new)
+            instance.get(OriginPackageDelete::new)
         }
     }
 
-    // optional string origin = 1;
+    // optional uint64 package_id = 1;
 
-    pub fn clear_origin(&mut self) {
-        self.origin.clear();
+    pub fn clear_package_id(&mut self) {
+        self.package_id = ::std::option::Option::None;
     }
 
-    pub fn has_origin(&self) -> bool {
-        self.origin.is_some()
+    pub fn has_package_id(&self) -> bool {
+        self.package_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin(&mut self, v: ::std::string::String) {
-        self.origin = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
-        if self.origin.is_none() {
-            self.origin.set_default();
-        }
-        self.origin.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_origin(&mut self) -> ::std::string::String {
-        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn set_package_id(&mut self, v: u64) {
+        self.package_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_origin(&self) -> &str {
-        match self.origin.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_package_id(&self) -> u64 {
+        self.package_id.unwrap_or(0)
     }
 
-    fn get_origin_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.origin
+    fn get_package_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.package_id
     }
 
-    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.origin
+    fn mut_package_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.package_id
     }
 
-    // optional uint64 start = 2;
+    // optional .originsrv.OriginPackageIdent ident = 2;
 
-    pub fn clear_start(&mut self) {
-        self.start = ::std::option::Option::None;
+    pub fn clear_ident(&mut self) {
+        self.ident.clear();
     }
 
-    pub fn has_start(&self) -> bool {
-        self.start.is_some()
+    pub fn has_ident(&self) -> bool {
+        self.ident.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_start(&mut self, v: u64) {
-        self.start = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_start(&self) -> u64 {
-        self.start.unwrap_or(0)
-    }
-
-    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.start
-    }
-
-    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.start
-    }
-
-    // optional uint64 stop = 3;
-
-    pub fn clear_stop(&mut self) {
-        self.stop = ::std::option::Option::None;
+    pub fn set_ident(&mut self, v: OriginPackageIdent) {
+        self.ident = ::protobuf::SingularPtrField::some(v);
     }
 
-    pub fn has_stop(&self) -> bool {
-        self.stop.is_some()
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ident(&mut self) -> &mut OriginPackageIdent {
+        if self.ident.is_none() {
+            self.ident.set_default();
+        }
+        self.ident.as_mut().unwrap()
     }
 
-    // Param is passed by value, moved
-    pub fn set_stop(&mut self, v: u64) {
-        self.stop = ::std::option::Option::Some(v);
+    // Take field
+    pub fn take_ident(&mut self) -> OriginPackageIdent {
+        self.ident.take().unwrap_or_else(|| OriginPackageIdent::new())
     }
 
-    pub fn get_stop(&self) -> u64 {
-        self.stop.unwrap_or(0)
+    pub fn get_ident(&self) -> &OriginPackageIdent {
+        self.ident.as_ref().unwrap_or_else(|| OriginPackageIdent::default_instance())
     }
 
-    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.stop
+    fn get_ident_for_reflect(&self) -> &::protobuf::SingularPtrField<OriginPackageIdent> {
+        &self.ident
     }
 
-    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.stop
+    fn mut_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<OriginPackageIdent> {
+        &mut self.ident
     }
 }
 
-impl ::protobuf::Message for OriginPackageUniqueListRequest {
+impl ::protobuf::Message for OriginPackageDelete {
     fn is_initialized(&self) -> bool {
+        for v in &self.ident {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -11544,21 +12041,14 @@ impl ::protobuf::Message for OriginPackageUniqueListRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
-                },
-                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.start = ::std::option::Option::Some(tmp);
+                    self.package_id = ::std::option::Option::Some(tmp);
                 },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.stop = ::std::option::Option::Some(tmp);
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ident)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -11572,14 +12062,12 @@ impl ::protobuf::Message for OriginPackageUniqueListRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.origin.as_ref() {
-            my_size += ::protobuf::rt::string_size(1, &v);
-        }
-        if let Some(v) = self.start {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(v) = self.package_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(v) = self.stop {
-            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(ref v) = self.ident.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -11587,14 +12075,13 @@ impl ::protobuf::Message for OriginPackageUniqueListRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.origin.as_ref() {
-            os.write_string(1, &v)?;
-        }
-        if let Some(v) = self.start {
-            os.write_uint64(2, v)?;
+        if let Some(v) = self.package_id {
+            os.write_uint64(1, v)?;
         }
-        if let Some(v) = self.stop {
-            os.write_uint64(3, v)?;
+        if let Some(ref v) = self.ident.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -11627,12 +12114,12 @@ impl ::protobuf::Message for OriginPackageUniqueListRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginPackageUniqueListRequest {
-    fn new() -> OriginPackageUniqueListRequest {
-        OriginPackageUniqueListRequest::new()
+impl ::protobuf::MessageStatic for OriginPackageDelete {
+    fn new() -> OriginPackageDelete {
+        OriginPackageDelete::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginPackageUniqueListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginPackageDelete>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -11640,23 +12127,18 @@ impl ::protobuf::MessageStatic for OriginPackageUniqueListRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "origin",
-                    OriginPackageUniqueListRequest::get_origin_for_reflect,
-                    OriginPackageUniqueListRequest::mut_origin_for_reflect,
-                ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "start",
-                    OriginPackageUniqueListRequest::get_start_for_reflect,
-                    OriginPackageUniqueListRequest::mut_start_for_reflect,
+                    "package_id",
+                    OriginPackageDelete::get_package_id_for_reflect,
+                    OriginPackageDelete::mut_package_id_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "stop",
-                    OriginPackageUniqueListRequest::get_stop_for_reflect,
-                    OriginPackageUniqueListRequest::mut_stop_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OriginPackageIdent>>(
+                    "ident",
+                    OriginPackageDelete::get_ident_for_reflect,
+                    OriginPackageDelete::mut_ident_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginPackageUniqueListRequest>(
-                    "OriginPackageUniqueListRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginPackageDelete>(
+                    "OriginPackageDelete",
                     fields,
                     file_descriptor_proto()
                 )
@@ -11665,255 +12147,139 @@ impl ::protobuf::MessageStatic for OriginPackageUniqueListRequest {
     }
 }
 
-impl ::protobuf::Clear for OriginPackageUniqueListRequest {
+impl ::protobuf::Clear for OriginPackageDelete {
     fn clear(&mut self) {
-        self.clear_origin();
-        self.clear_start();
-        self.clear_stop();
+        self.clear_package_id();
+        self.clear_ident();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for OriginPackageUniqueListRequest {
+impl ::std::fmt::Debug for OriginPackageDelete {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OriginPackageUniqueListRequest {
+impl ::protobuf::reflect::ProtobufValue for OriginPackageDelete {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct OriginPackageUniqueListResponse {
+pub struct OriginPackageDependentsGet {
     // message fields
-    start: ::std::option::Option<u64>,
-    stop: ::std::option::Option<u64>,
-    count: ::std::option::Option<u64>,
-    idents: ::protobuf::RepeatedField<OriginPackageIdent>,
+    ident: ::protobuf::SingularPtrField<OriginPackageIdent>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginPackageUniqueListResponse {}
+unsafe impl ::std::marker::Sync for OriginPackageDependentsGet {}
 
-impl OriginPackageUniqueListResponse {
-    pub fn new() -> OriginPackageUniqueListResponse {
+impl OriginPackageDependentsGet {
+    pub fn new() -> OriginPackageDependentsGet {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginPackageUniqueListResponse {
-        static mut instance: ::protobuf::lazy::Lazy<OriginPackageUniqueListResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginPackageDependentsGet {
+        static mut instance: ::protobuf::lazy::Lazy<OriginPackageDependentsGet> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginPackageUniqueListResponse,
+            ptr: 0 as *const OriginPackageDependentsGet,
         };
         unsafe {
-            instance.get(OriginPackageUniqueListResponse::new)
+            instance.get(OriginPackageDependentsGet::new)
         }
     }
 
-    // optional uint64 start = 1;
+    // optional .originsrv.OriginPackageIdent ident = 1;
 
-    pub fn clear_start(&mut self) {
-        self.start = ::std::option::Option::None;
+    pub fn clear_ident(&mut self) {
+        self.ident.clear();
     }
 
-    pub fn has_start(&self) -> bool {
-        self.start.is_some()
+    pub fn has_ident(&self) -> bool {
+        self.ident.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_start(&mut self, v: u64) {
-        self.start = ::std::option::Option::Some(v);
+    pub fn set_ident(&mut self, v: OriginPackageIdent) {
+        self.ident = ::protobuf::SingularPtrField::some(v);
     }
 
-    pub fn get_start(&self) -> u64 {
-        self.start.unwrap_or(0)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ident(&mut self) -> &mut OriginPackageIdent {
+        if self.ident.is_none() {
+            self.ident.set_default();
+        }
+        self.ident.as_mut().unwrap()
     }
 
-    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.start
+    // Take field
+    pub fn take_ident(&mut self) -> OriginPackageIdent {
+        self.ident.take().unwrap_or_else(|| OriginPackageIdent::new())
     }
 
-    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.start
+    pub fn get_ident(&self) -> &OriginPackageIdent {
+        self.ident.as_ref().unwrap_or_else(|| OriginPackageIdent::default_instance())
     }
 
-    // optional uint64 stop = 2;
-
-    pub fn clear_stop(&mut self) {
-        self.stop = ::std::option::Option::None;
+    fn get_ident_for_reflect(&self) -> &::protobuf::SingularPtrField<OriginPackageIdent> {
+        &self.ident
     }
 
-    pub fn has_stop(&self) -> bool {
-        self.stop.is_some()
+    fn mut_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<OriginPackageIdent> {
+        &mut self.ident
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_stop(&mut self, v: u64) {
-        self.stop = ::std::option::Option::Some(v);
+impl ::protobuf::Message for OriginPackageDependentsGet {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ident {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
     }
 
-    pub fn get_stop(&self) -> u64 {
-        self.stop.unwrap_or(0)
-    }
-
-    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.stop
-    }
-
-    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.stop
-    }
-
-    // optional uint64 count = 3;
-
-    pub fn clear_count(&mut self) {
-        self.count = ::std::option::Option::None;
-    }
-
-    pub fn has_count(&self) -> bool {
-        self.count.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_count(&mut self, v: u64) {
-        self.count = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_count(&self) -> u64 {
-        self.count.unwrap_or(0)
-    }
-
-    fn get_count_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.count
-    }
-
-    fn mut_count_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.count
-    }
-
-    // repeated .originsrv.OriginPackageIdent idents = 4;
-
-    pub fn clear_idents(&mut self) {
-        self.idents.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_idents(&mut self, v: ::protobuf::RepeatedField<OriginPackageIdent>) {
-        self.idents = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_idents(&mut self) -> &mut ::protobuf::RepeatedField<OriginPackageIdent> {
-        &mut self.idents
-    }
-
-    // Take field
-    pub fn take_idents(&mut self) -> ::protobuf::RepeatedField<OriginPackageIdent> {
-        ::std::mem::replace(&mut self.idents, ::protobuf::RepeatedField::new())
-    }
-
-    pub fn get_idents(&self) -> &[OriginPackageIdent] {
-        &self.idents
-    }
-
-    fn get_idents_for_reflect(&self) -> &::protobuf::RepeatedField<OriginPackageIdent> {
-        &self.idents
-    }
-
-    fn mut_idents_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<OriginPackageIdent> {
-        &mut self.idents
-    }
-}
-
-impl ::protobuf::Message for OriginPackageUniqueListResponse {
-    fn is_initialized(&self) -> bool {
-        for v in &self.idents {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        true
-    }
-
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.start = ::std::option::Option::Some(tmp);
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.stop = ::std::option::Option::Some(tmp);
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.count = ::std::option::Option::Some(tmp);
-                },
-                4 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.idents)?;
-                },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ident)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.start {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(v) = self.stop {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(v) = self.count {
-            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        for value in &self.idents {
-            let len = value.compute_size();
+        if let Some(ref v) = self.ident.as_ref() {
+            let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.start {
-            os.write_uint64(1, v)?;
-        }
-        if let Some(v) = self.stop {
-            os.write_uint64(2, v)?;
-        }
-        if let Some(v) = self.count {
-            os.write_uint64(3, v)?;
-        }
-        for v in &self.idents {
-            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+        if let Some(ref v) = self.ident.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
-        };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -11945,12 +12311,12 @@ impl ::protobuf::Message for OriginPackageUniqueListResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginPackageUniqueListResponse {
-    fn new() -> OriginPackageUniqueListResponse {
-        OriginPackageUniqueListResponse::new()
+impl ::protobuf::MessageStatic for OriginPackageDependentsGet {
+    fn new() -> OriginPackageDependentsGet {
+        OriginPackageDependentsGet::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginPackageUniqueListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginPackageDependentsGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -11958,28 +12324,13 @@ impl ::protobuf::MessageStatic for OriginPackageUniqueListResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "start",
-                    OriginPackageUniqueListResponse::get_start_for_reflect,
-                    OriginPackageUniqueListResponse::mut_start_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "stop",
-                    OriginPackageUniqueListResponse::get_stop_for_reflect,
-                    OriginPackageUniqueListResponse::mut_stop_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "count",
-                    OriginPackageUniqueListResponse::get_count_for_reflect,
-                    OriginPackageUniqueListResponse::mut_count_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OriginPackageIdent>>(
-                    "idents",
-                    OriginPackageUniqueListResponse::get_idents_for_reflect,
-                    OriginPackageUniqueListResponse::mut_idents_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OriginPackageIdent>>(
+                    "ident",
+                    OriginPackageDependentsGet::get_ident_for_reflect,
+                    OriginPackageDependentsGet::mut_ident_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginPackageUniqueListResponse>(
-                    "OriginPackageUniqueListResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginPackageDependentsGet>(
+                    "OriginPackageDependentsGet",
                     fields,
                     file_descriptor_proto()
                 )
@@ -11988,53 +12339,54 @@ impl ::protobuf::MessageStatic for OriginPackageUniqueListResponse {
     }
 }
 
-impl ::protobuf::Clear for OriginPackageUniqueListResponse {
+impl ::protobuf::Clear for OriginPackageDependentsGet {
     fn clear(&mut self) {
-        self.clear_start();
-        self.clear_stop();
-        self.clear_count();
-        self.clear_idents();
+        self.clear_ident();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for OriginPackageUniqueListResponse {
+impl ::std::fmt::Debug for OriginPackageDependentsGet {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OriginPackageUniqueListResponse {
+impl ::protobuf::reflect::ProtobufValue for OriginPackageDependentsGet {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct OriginPackageVersionListRequest {
+pub struct OriginPackageSearchRequest {
     // message fields
     origin: ::protobuf::SingularField<::std::string::String>,
-    name: ::protobuf::SingularField<::std::string::String>,
+    query: ::protobuf::SingularField<::std::string::String>,
+    start: ::std::option::Option<u64>,
+    stop: ::std::option::Option<u64>,
+    distinct: ::std::option::Option<bool>,
+    license: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginPackageVersionListRequest {}
+unsafe impl ::std::marker::Sync for OriginPackageSearchRequest {}
 
-impl OriginPackageVersionListRequest {
-    pub fn new() -> OriginPackageVersionListRequest {
+impl OriginPackageSearchRequest {
+    pub fn new() -> OriginPackageSearchRequest {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginPackageVersionListRequest {
-        static mut instance: ::protobuf::lazy::Lazy<OriginPackageVersionListRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginPackageSearchRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginPackageSearchRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginPackageVersionListRequest,
+            ptr: 0 as *const OriginPackageSearchRequest,
         };
         unsafe {
-            instance.get(OriginPackageVersionListRequest::new)
+            instance.get(OriginPackageSearchRequest::new)
         }
     }
 
@@ -12082,67 +12434,216 @@ impl OriginPackageVersionListRequest {
         &mut self.origin
     }
 
-    // optional string name = 2;
+    // optional string query = 2;
 
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+    pub fn clear_query(&mut self) {
+        self.query.clear();
     }
 
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
+    pub fn has_query(&self) -> bool {
+        self.query.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
+    pub fn set_query(&mut self, v: ::std::string::String) {
+        self.query = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
+    pub fn mut_query(&mut self) -> &mut ::std::string::String {
+        if self.query.is_none() {
+            self.query.set_default();
         }
-        self.name.as_mut().unwrap()
+        self.query.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_query(&mut self) -> ::std::string::String {
+        self.query.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
+    pub fn get_query(&self) -> &str {
+        match self.query.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.name
+    fn get_query_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.query
     }
 
-    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.name
+    fn mut_query_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.query
     }
-}
 
-impl ::protobuf::Message for OriginPackageVersionListRequest {
-    fn is_initialized(&self) -> bool {
-        true
+    // optional uint64 start = 3;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
-                },
-                _ => {
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.start
+    }
+
+    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.start
+    }
+
+    // optional uint64 stop = 4;
+
+    pub fn clear_stop(&mut self) {
+        self.stop = ::std::option::Option::None;
+    }
+
+    pub fn has_stop(&self) -> bool {
+        self.stop.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stop(&mut self, v: u64) {
+        self.stop = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_stop(&self) -> u64 {
+        self.stop.unwrap_or(0)
+    }
+
+    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.stop
+    }
+
+    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.stop
+    }
+
+    // optional bool distinct = 5;
+
+    pub fn clear_distinct(&mut self) {
+        self.distinct = ::std::option::Option::None;
+    }
+
+    pub fn has_distinct(&self) -> bool {
+        self.distinct.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_distinct(&mut self, v: bool) {
+        self.distinct = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_distinct(&self) -> bool {
+        self.distinct.unwrap_or(false)
+    }
+
+    fn get_distinct_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.distinct
+    }
+
+    fn mut_distinct_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.distinct
+    }
+
+    // optional string license = 6;
+
+    pub fn clear_license(&mut self) {
+        self.license.clear();
+    }
+
+    pub fn has_license(&self) -> bool {
+        self.license.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_license(&mut self, v: ::std::string::String) {
+        self.license = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_license(&mut self) -> &mut ::std::string::String {
+        if self.license.is_none() {
+            self.license.set_default();
+        }
+        self.license.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_license(&mut self) -> ::std::string::String {
+        self.license.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_license(&self) -> &str {
+        match self.license.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_license_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.license
+    }
+
+    fn mut_license_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.license
+    }
+}
+
+impl ::protobuf::Message for OriginPackageSearchRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.query)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.stop = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.distinct = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.license)?;
+                },
+                _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
@@ -12157,9 +12658,21 @@ impl ::protobuf::Message for OriginPackageVersionListRequest {
         if let Some(ref v) = self.origin.as_ref() {
             my_size += ::protobuf::rt::string_size(1, &v);
         }
-        if let Some(ref v) = self.name.as_ref() {
+        if let Some(ref v) = self.query.as_ref() {
             my_size += ::protobuf::rt::string_size(2, &v);
         }
+        if let Some(v) = self.start {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.stop {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.distinct {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.license.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -12169,9 +12682,21 @@ impl ::protobuf::Message for OriginPackageVersionListRequest {
         if let Some(ref v) = self.origin.as_ref() {
             os.write_string(1, &v)?;
         }
-        if let Some(ref v) = self.name.as_ref() {
+        if let Some(ref v) = self.query.as_ref() {
             os.write_string(2, &v)?;
         }
+        if let Some(v) = self.start {
+            os.write_uint64(3, v)?;
+        }
+        if let Some(v) = self.stop {
+            os.write_uint64(4, v)?;
+        }
+        if let Some(v) = self.distinct {
+            os.write_bool(5, v)?;
+        }
+        if let Some(ref v) = self.license.as_ref() {
+            os.write_string(6, &v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -12203,12 +12728,12 @@ impl ::protobuf::Message for OriginPackageVersionListRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginPackageVersionListRequest {
-    fn new() -> OriginPackageVersionListRequest {
-        OriginPackageVersionListRequest::new()
+impl ::protobuf::MessageStatic for OriginPackageSearchRequest {
+    fn new() -> OriginPackageSearchRequest {
+        OriginPackageSearchRequest::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginPackageVersionListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginPackageSearchRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -12218,16 +12743,36 @@ impl ::protobuf::MessageStatic for OriginPackageVersionListRequest {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
                     "origin",
-                    OriginPackageVersionListRequest::get_origin_for_reflect,
-                    OriginPackageVersionListRequest::mut_origin_for_reflect,
+                    OriginPackageSearchRequest::get_origin_for_reflect,
+                    OriginPackageSearchRequest::mut_origin_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    OriginPackageVersionListRequest::get_name_for_reflect,
-                    OriginPackageVersionListRequest::mut_name_for_reflect,
+                    "query",
+                    OriginPackageSearchRequest::get_query_for_reflect,
+                    OriginPackageSearchRequest::mut_query_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginPackageVersionListRequest>(
-                    "OriginPackageVersionListRequest",
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start",
+                    OriginPackageSearchRequest::get_start_for_reflect,
+                    OriginPackageSearchRequest::mut_start_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "stop",
+                    OriginPackageSearchRequest::get_stop_for_reflect,
+                    OriginPackageSearchRequest::mut_stop_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "distinct",
+                    OriginPackageSearchRequest::get_distinct_for_reflect,
+                    OriginPackageSearchRequest::mut_distinct_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "license",
+                    OriginPackageSearchRequest::get_license_for_reflect,
+                    OriginPackageSearchRequest::mut_license_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginPackageSearchRequest>(
+                    "OriginPackageSearchRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -12236,94 +12781,160 @@ impl ::protobuf::MessageStatic for OriginPackageVersionListRequest {
     }
 }
 
-impl ::protobuf::Clear for OriginPackageVersionListRequest {
+impl ::protobuf::Clear for OriginPackageSearchRequest {
     fn clear(&mut self) {
         self.clear_origin();
-        self.clear_name();
+        self.clear_query();
+        self.clear_start();
+        self.clear_stop();
+        self.clear_distinct();
+        self.clear_license();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for OriginPackageVersionListRequest {
+impl ::std::fmt::Debug for OriginPackageSearchRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OriginPackageVersionListRequest {
+impl ::protobuf::reflect::ProtobufValue for OriginPackageSearchRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct OriginPackageVersionListResponse {
+pub struct OriginPackageUniqueListRequest {
     // message fields
-    versions: ::protobuf::RepeatedField<OriginPackageVersion>,
+    origin: ::protobuf::SingularField<::std::string::String>,
+    start: ::std::option::Option<u64>,
+    stop: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginPackageVersionListResponse {}
+unsafe impl ::std::marker::Sync for OriginPackageUniqueListRequest {}
 
-impl OriginPackageVersionListResponse {
-    pub fn new() -> OriginPackageVersionListResponse {
+impl OriginPackageUniqueListRequest {
+    pub fn new() -> OriginPackageUniqueListRequest {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginPackageVersionListResponse {
-        static mut instance: ::protobuf::lazy::Lazy<OriginPackageVersionListResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginPackageUniqueListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginPackageUniqueListRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginPackageVersionListResponse,
+            ptr: 0 as *const OriginPackageUniqueListRequest,
         };
         unsafe {
-            instance.get(OriginPackageVersionListResponse::new)
+            instance.get(OriginPackageUniqueListRequest::new)
         }
     }
 
-    // repeated .originsrv.OriginPackageVersion versions = 1;
+    // optional string origin = 1;
 
-    pub fn clear_versions(&mut self) {
-        self.versions.clear();
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
     }
 
-    // Param is passed by value, moved
-    pub fn set_versions(&mut self, v: ::protobuf::RepeatedField<OriginPackageVersion>) {
-        self.versions = v;
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_versions(&mut self) -> &mut ::protobuf::RepeatedField<OriginPackageVersion> {
-        &mut self.versions
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
     }
 
-    // Take field
-    pub fn take_versions(&mut self) -> ::protobuf::RepeatedField<OriginPackageVersion> {
-        ::std::mem::replace(&mut self.versions, ::protobuf::RepeatedField::new())
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
     }
 
-    pub fn get_versions(&self) -> &[OriginPackageVersion] {
-        &self.versions
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    fn get_versions_for_reflect(&self) -> &::protobuf::RepeatedField<OriginPackageVersion> {
-        &self.versions
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    fn mut_versions_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<OriginPackageVersion> {
-        &mut self.versions
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.origin
+    }
+
+    // optional uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.start
+    }
+
+    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.start
+    }
+
+    // optional uint64 stop = 3;
+
+    pub fn clear_stop(&mut self) {
+        self.stop = ::std::option::Option::None;
+    }
+
+    pub fn has_stop(&self) -> bool {
+        self.stop.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stop(&mut self, v: u64) {
+        self.stop = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_stop(&self) -> u64 {
+        self.stop.unwrap_or(0)
+    }
+
+    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.stop
+    }
+
+    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.stop
     }
 }
 
-impl ::protobuf::Message for OriginPackageVersionListResponse {
+impl ::protobuf::Message for OriginPackageUniqueListRequest {
     fn is_initialized(&self) -> bool {
-        for v in &self.versions {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -12332,7 +12943,21 @@ impl ::protobuf::Message for OriginPackageVersionListResponse {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.versions)?;
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.stop = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -12346,21 +12971,30 @@ impl ::protobuf::Message for OriginPackageVersionListResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in &self.versions {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
+        if let Some(ref v) = self.origin.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.start {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.stop {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        for v in &self.versions {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(v) = self.start {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.stop {
+            os.write_uint64(3, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -12392,12 +13026,12 @@ impl ::protobuf::Message for OriginPackageVersionListResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginPackageVersionListResponse {
-    fn new() -> OriginPackageVersionListResponse {
-        OriginPackageVersionListResponse::new()
+impl ::protobuf::MessageStatic for OriginPackageUniqueListRequest {
+    fn new() -> OriginPackageUniqueListRequest {
+        OriginPackageUniqueListRequest::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginPackageVersionListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginPackageUniqueListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -12405,13 +13039,23 @@ impl ::protobuf::MessageStatic for OriginPackageVersionListResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OriginPackageVersion>>(
-                    "versions",
-                    OriginPackageVersionListResponse::get_versions_for_reflect,
-                    OriginPackageVersionListResponse::mut_versions_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "origin",
+                    OriginPackageUniqueListRequest::get_origin_for_reflect,
+                    OriginPackageUniqueListRequest::mut_origin_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginPackageVersionListResponse>(
-                    "OriginPackageVersionListResponse",
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start",
+                    OriginPackageUniqueListRequest::get_start_for_reflect,
+                    OriginPackageUniqueListRequest::mut_start_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "stop",
+                    OriginPackageUniqueListRequest::get_stop_for_reflect,
+                    OriginPackageUniqueListRequest::mut_stop_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginPackageUniqueListRequest>(
+                    "OriginPackageUniqueListRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -12420,407 +13064,1806 @@ impl ::protobuf::MessageStatic for OriginPackageVersionListResponse {
     }
 }
 
-impl ::protobuf::Clear for OriginPackageVersionListResponse {
+impl ::protobuf::Clear for OriginPackageUniqueListRequest {
     fn clear(&mut self) {
-        self.clear_versions();
+        self.clear_origin();
+        self.clear_start();
+        self.clear_stop();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for OriginPackageVersionListResponse {
+impl ::std::fmt::Debug for OriginPackageUniqueListRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OriginPackageVersionListResponse {
+impl ::protobuf::reflect::ProtobufValue for OriginPackageUniqueListRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct OriginProject {
+pub struct OriginPackageUniqueListResponse {
     // message fields
-    id: ::std::option::Option<u64>,
-    origin_id: ::std::option::Option<u64>,
-    origin_name: ::protobuf::SingularField<::std::string::String>,
-    package_name: ::protobuf::SingularField<::std::string::String>,
-    name: ::protobuf::SingularField<::std::string::String>,
-    plan_path: ::protobuf::SingularField<::std::string::String>,
-    owner_id: ::std::option::Option<u64>,
-    vcs_type: ::protobuf::SingularField<::std::string::String>,
-    vcs_data: ::protobuf::SingularField<::std::string::String>,
+    start: ::std::option::Option<u64>,
+    stop: ::std::option::Option<u64>,
+    count: ::std::option::Option<u64>,
+    idents: ::protobuf::RepeatedField<OriginPackageIdent>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginProject {}
+unsafe impl ::std::marker::Sync for OriginPackageUniqueListResponse {}
 
-impl OriginProject {
-    pub fn new() -> OriginProject {
+impl OriginPackageUniqueListResponse {
+    pub fn new() -> OriginPackageUniqueListResponse {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginProject {
-        static mut instance: ::protobuf::lazy::Lazy<OriginProject> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginPackageUniqueListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OriginPackageUniqueListResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginProject,
+            ptr: 0 as *const OriginPackageUniqueListResponse,
         };
         unsafe {
-            instance.get(OriginProject::new)
+            instance.get(OriginPackageUniqueListResponse::new)
         }
     }
 
-    // optional uint64 id = 1;
+    // optional uint64 start = 1;
 
-    pub fn clear_id(&mut self) {
-        self.id = ::std::option::Option::None;
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
     }
 
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = ::std::option::Option::Some(v);
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
     }
 
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
     }
 
-    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.id
+    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.start
     }
 
-    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.id
+    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.start
     }
 
-    // optional uint64 origin_id = 2;
+    // optional uint64 stop = 2;
 
-    pub fn clear_origin_id(&mut self) {
-        self.origin_id = ::std::option::Option::None;
+    pub fn clear_stop(&mut self) {
+        self.stop = ::std::option::Option::None;
     }
 
-    pub fn has_origin_id(&self) -> bool {
-        self.origin_id.is_some()
+    pub fn has_stop(&self) -> bool {
+        self.stop.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_id = ::std::option::Option::Some(v);
+    pub fn set_stop(&mut self, v: u64) {
+        self.stop = ::std::option::Option::Some(v);
     }
 
-    pub fn get_origin_id(&self) -> u64 {
-        self.origin_id.unwrap_or(0)
+    pub fn get_stop(&self) -> u64 {
+        self.stop.unwrap_or(0)
     }
 
-    fn get_origin_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.origin_id
+    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.stop
     }
 
-    fn mut_origin_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.origin_id
+    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.stop
     }
 
-    // optional string origin_name = 3;
+    // optional uint64 count = 3;
 
-    pub fn clear_origin_name(&mut self) {
-        self.origin_name.clear();
+    pub fn clear_count(&mut self) {
+        self.count = ::std::option::Option::None;
+    }
+
+    pub fn has_count(&self) -> bool {
+        self.count.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u64) {
+        self.count = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_count(&self) -> u64 {
+        self.count.unwrap_or(0)
+    }
+
+    fn get_count_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.count
+    }
+
+    fn mut_count_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.count
+    }
+
+    // repeated .originsrv.OriginPackageIdent idents = 4;
+
+    pub fn clear_idents(&mut self) {
+        self.idents.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_idents(&mut self, v: ::protobuf::RepeatedField<OriginPackageIdent>) {
+        self.idents = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_idents(&mut self) -> &mut ::protobuf::RepeatedField<OriginPackageIdent> {
+        &mut self.idents
+    }
+
+    // Take field
+    pub fn take_idents(&mut self) -> ::protobuf::RepeatedField<OriginPackageIdent> {
+        ::std::mem::replace(&mut self.idents, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_idents(&self) -> &[OriginPackageIdent] {
+        &self.idents
+    }
+
+    fn get_idents_for_reflect(&self) -> &::protobuf::RepeatedField<OriginPackageIdent> {
+        &self.idents
+    }
+
+    fn mut_idents_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<OriginPackageIdent> {
+        &mut self.idents
+    }
+}
+
+impl ::protobuf::Message for OriginPackageUniqueListResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.idents {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.stop = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.count = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.idents)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.start {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.stop {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.count {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.idents {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.start {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.stop {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.count {
+            os.write_uint64(3, v)?;
+        }
+        for v in &self.idents {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginPackageUniqueListResponse {
+    fn new() -> OriginPackageUniqueListResponse {
+        OriginPackageUniqueListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginPackageUniqueListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start",
+                    OriginPackageUniqueListResponse::get_start_for_reflect,
+                    OriginPackageUniqueListResponse::mut_start_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "stop",
+                    OriginPackageUniqueListResponse::get_stop_for_reflect,
+                    OriginPackageUniqueListResponse::mut_stop_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "count",
+                    OriginPackageUniqueListResponse::get_count_for_reflect,
+                    OriginPackageUniqueListResponse::mut_count_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OriginPackageIdent>>(
+                    "idents",
+                    OriginPackageUniqueListResponse::get_idents_for_reflect,
+                    OriginPackageUniqueListResponse::mut_idents_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginPackageUniqueListResponse>(
+                    "OriginPackageUniqueListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginPackageUniqueListResponse {
+    fn clear(&mut self) {
+        self.clear_start();
+        self.clear_stop();
+        self.clear_count();
+        self.clear_idents();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OriginPackageUniqueListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OriginPackageUniqueListResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct OriginPackageVersionListRequest {
+    // message fields
+    origin: ::protobuf::SingularField<::std::string::String>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginPackageVersionListRequest {}
+
+impl OriginPackageVersionListRequest {
+    pub fn new() -> OriginPackageVersionListRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginPackageVersionListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginPackageVersionListRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginPackageVersionListRequest,
+        };
+        unsafe {
+            instance.get(OriginPackageVersionListRequest::new)
+        }
+    }
+
+    // optional string origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.origin
+    }
+
+    // optional string name = 2;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
+    }
+
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
+    }
+}
+
+impl ::protobuf::Message for OriginPackageVersionListRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.origin.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginPackageVersionListRequest {
+    fn new() -> OriginPackageVersionListRequest {
+        OriginPackageVersionListRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginPackageVersionListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "origin",
+                    OriginPackageVersionListRequest::get_origin_for_reflect,
+                    OriginPackageVersionListRequest::mut_origin_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    OriginPackageVersionListRequest::get_name_for_reflect,
+                    OriginPackageVersionListRequest::mut_name_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginPackageVersionListRequest>(
+                    "OriginPackageVersionListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginPackageVersionListRequest {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.clear_name();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OriginPackageVersionListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OriginPackageVersionListRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct OriginPackageVersionListResponse {
+    // message fields
+    versions: ::protobuf::RepeatedField<OriginPackageVersion>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginPackageVersionListResponse {}
+
+impl OriginPackageVersionListResponse {
+    pub fn new() -> OriginPackageVersionListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginPackageVersionListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OriginPackageVersionListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginPackageVersionListResponse,
+        };
+        unsafe {
+            instance.get(OriginPackageVersionListResponse::new)
+        }
+    }
+
+    // repeated .originsrv.OriginPackageVersion versions = 1;
+
+    pub fn clear_versions(&mut self) {
+        self.versions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_versions(&mut self, v: ::protobuf::RepeatedField<OriginPackageVersion>) {
+        self.versions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_versions(&mut self) -> &mut ::protobuf::RepeatedField<OriginPackageVersion> {
+        &mut self.versions
+    }
+
+    // Take field
+    pub fn take_versions(&mut self) -> ::protobuf::RepeatedField<OriginPackageVersion> {
+        ::std::mem::replace(&mut self.versions, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_versions(&self) -> &[OriginPackageVersion] {
+        &self.versions
+    }
+
+    fn get_versions_for_reflect(&self) -> &::protobuf::RepeatedField<OriginPackageVersion> {
+        &self.versions
+    }
+
+    fn mut_versions_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<OriginPackageVersion> {
+        &mut self.versions
+    }
+}
+
+impl ::protobuf::Message for OriginPackageVersionListResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.versions {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.versions)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.versions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.versions {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginPackageVersionListResponse {
+    fn new() -> OriginPackageVersionListResponse {
+        OriginPackageVersionListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginPackageVersionListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OriginPackageVersion>>(
+                    "versions",
+                    OriginPackageVersionListResponse::get_versions_for_reflect,
+                    OriginPackageVersionListResponse::mut_versions_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginPackageVersionListResponse>(
+                    "OriginPackageVersionListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginPackageVersionListResponse {
+    fn clear(&mut self) {
+        self.clear_versions();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OriginPackageVersionListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OriginPackageVersionListResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct OriginProject {
+    // message fields
+    id: ::std::option::Option<u64>,
+    origin_id: ::std::option::Option<u64>,
+    origin_name: ::protobuf::SingularField<::std::string::String>,
+    package_name: ::protobuf::SingularField<::std::string::String>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    plan_path: ::protobuf::SingularField<::std::string::String>,
+    owner_id: ::std::option::Option<u64>,
+    vcs_type: ::protobuf::SingularField<::std::string::String>,
+    vcs_data: ::protobuf::SingularField<::std::string::String>,
+    build_timeout: ::std::option::Option<u64>,
+    webhook_secret: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginProject {}
+
+impl OriginProject {
+    pub fn new() -> OriginProject {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginProject {
+        static mut instance: ::protobuf::lazy::Lazy<OriginProject> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginProject,
+        };
+        unsafe {
+            instance.get(OriginProject::new)
+        }
+    }
+
+    // optional uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
+    }
+
+    // optional uint64 origin_id = 2;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    fn get_origin_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.origin_id
+    }
+
+    fn mut_origin_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.origin_id
+    }
+
+    // optional string origin_name = 3;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
+    }
+
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
+        }
+        self.origin_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_origin_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.origin_name
+    }
+
+    fn mut_origin_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.origin_name
+    }
+
+    // optional string package_name = 4;
+
+    pub fn clear_package_name(&mut self) {
+        self.package_name.clear();
+    }
+
+    pub fn has_package_name(&self) -> bool {
+        self.package_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_package_name(&mut self, v: ::std::string::String) {
+        self.package_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_package_name(&mut self) -> &mut ::std::string::String {
+        if self.package_name.is_none() {
+            self.package_name.set_default();
+        }
+        self.package_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_package_name(&mut self) -> ::std::string::String {
+        self.package_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_package_name(&self) -> &str {
+        match self.package_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_package_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.package_name
+    }
+
+    fn mut_package_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.package_name
+    }
+
+    // optional string name = 5;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
+    }
+
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
+    }
+
+    // optional string plan_path = 6;
+
+    pub fn clear_plan_path(&mut self) {
+        self.plan_path.clear();
+    }
+
+    pub fn has_plan_path(&self) -> bool {
+        self.plan_path.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_plan_path(&mut self, v: ::std::string::String) {
+        self.plan_path = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_plan_path(&mut self) -> &mut ::std::string::String {
+        if self.plan_path.is_none() {
+            self.plan_path.set_default();
+        }
+        self.plan_path.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_plan_path(&mut self) -> ::std::string::String {
+        self.plan_path.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_plan_path(&self) -> &str {
+        match self.plan_path.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_plan_path_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.plan_path
+    }
+
+    fn mut_plan_path_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.plan_path
+    }
+
+    // optional uint64 owner_id = 7;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    fn get_owner_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.owner_id
+    }
+
+    fn mut_owner_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.owner_id
+    }
+
+    // optional string vcs_type = 8;
+
+    pub fn clear_vcs_type(&mut self) {
+        self.vcs_type.clear();
+    }
+
+    pub fn has_vcs_type(&self) -> bool {
+        self.vcs_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vcs_type(&mut self, v: ::std::string::String) {
+        self.vcs_type = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vcs_type(&mut self) -> &mut ::std::string::String {
+        if self.vcs_type.is_none() {
+            self.vcs_type.set_default();
+        }
+        self.vcs_type.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_vcs_type(&mut self) -> ::std::string::String {
+        self.vcs_type.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_vcs_type(&self) -> &str {
+        match self.vcs_type.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_vcs_type_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.vcs_type
+    }
+
+    fn mut_vcs_type_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.vcs_type
+    }
+
+    // optional string vcs_data = 9;
+
+    pub fn clear_vcs_data(&mut self) {
+        self.vcs_data.clear();
+    }
+
+    pub fn has_vcs_data(&self) -> bool {
+        self.vcs_data.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vcs_data(&mut self, v: ::std::string::String) {
+        self.vcs_data = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vcs_data(&mut self) -> &mut ::std::string::String {
+        if self.vcs_data.is_none() {
+            self.vcs_data.set_default();
+        }
+        self.vcs_data.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_vcs_data(&mut self) -> ::std::string::String {
+        self.vcs_data.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_vcs_data(&self) -> &str {
+        match self.vcs_data.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_vcs_data_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.vcs_data
+    }
+
+    fn mut_vcs_data_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.vcs_data
+    }
+
+    // optional uint64 build_timeout = 10;
+
+    pub fn clear_build_timeout(&mut self) {
+        self.build_timeout = ::std::option::Option::None;
+    }
+
+    pub fn has_build_timeout(&self) -> bool {
+        self.build_timeout.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_timeout(&mut self, v: u64) {
+        self.build_timeout = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_build_timeout(&self) -> u64 {
+        self.build_timeout.unwrap_or(0)
+    }
+
+    fn get_build_timeout_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.build_timeout
+    }
+
+    fn mut_build_timeout_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.build_timeout
+    }
+
+    // optional string webhook_secret = 11;
+
+    pub fn clear_webhook_secret(&mut self) {
+        self.webhook_secret.clear();
+    }
+
+    pub fn has_webhook_secret(&self) -> bool {
+        self.webhook_secret.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_webhook_secret(&mut self, v: ::std::string::String) {
+        self.webhook_secret = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_webhook_secret(&mut self) -> &mut ::std::string::String {
+        if self.webhook_secret.is_none() {
+            self.webhook_secret.set_default();
+        }
+        self.webhook_secret.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_webhook_secret(&mut self) -> ::std::string::String {
+        self.webhook_secret.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_webhook_secret(&self) -> &str {
+        match self.webhook_secret.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_webhook_secret_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.webhook_secret
+    }
+
+    fn mut_webhook_secret_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.webhook_secret
+    }
+}
+
+impl ::protobuf::Message for OriginProject {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.package_name)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.plan_path)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.vcs_type)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.vcs_data)?;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.build_timeout = ::std::option::Option::Some(tmp);
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.webhook_secret)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.origin_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.origin_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(ref v) = self.package_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        if let Some(ref v) = self.plan_path.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
+        if let Some(v) = self.owner_id {
+            my_size += ::protobuf::rt::value_size(7, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.vcs_type.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v);
+        }
+        if let Some(ref v) = self.vcs_data.as_ref() {
+            my_size += ::protobuf::rt::string_size(9, &v);
+        }
+        if let Some(v) = self.build_timeout {
+            my_size += ::protobuf::rt::value_size(10, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.webhook_secret.as_ref() {
+            my_size += ::protobuf::rt::string_size(11, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.origin_id {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(ref v) = self.origin_name.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(ref v) = self.package_name.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(5, &v)?;
+        }
+        if let Some(ref v) = self.plan_path.as_ref() {
+            os.write_string(6, &v)?;
+        }
+        if let Some(v) = self.owner_id {
+            os.write_uint64(7, v)?;
+        }
+        if let Some(ref v) = self.vcs_type.as_ref() {
+            os.write_string(8, &v)?;
+        }
+        if let Some(ref v) = self.vcs_data.as_ref() {
+            os.write_string(9, &v)?;
+        }
+        if let Some(v) = self.build_timeout {
+            os.write_uint64(10, v)?;
+        }
+        if let Some(ref v) = self.webhook_secret.as_ref() {
+            os.write_string(11, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginProject {
+    fn new() -> OriginProject {
+        OriginProject::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginProject>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    OriginProject::get_id_for_reflect,
+                    OriginProject::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "origin_id",
+                    OriginProject::get_origin_id_for_reflect,
+                    OriginProject::mut_origin_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "origin_name",
+                    OriginProject::get_origin_name_for_reflect,
+                    OriginProject::mut_origin_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "package_name",
+                    OriginProject::get_package_name_for_reflect,
+                    OriginProject::mut_package_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    OriginProject::get_name_for_reflect,
+                    OriginProject::mut_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "plan_path",
+                    OriginProject::get_plan_path_for_reflect,
+                    OriginProject::mut_plan_path_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "owner_id",
+                    OriginProject::get_owner_id_for_reflect,
+                    OriginProject::mut_owner_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "vcs_type",
+                    OriginProject::get_vcs_type_for_reflect,
+                    OriginProject::mut_vcs_type_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "vcs_data",
+                    OriginProject::get_vcs_data_for_reflect,
+                    OriginProject::mut_vcs_data_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "build_timeout",
+                    OriginProject::get_build_timeout_for_reflect,
+                    OriginProject::mut_build_timeout_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "webhook_secret",
+                    OriginProject::get_webhook_secret_for_reflect,
+                    OriginProject::mut_webhook_secret_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginProject>(
+                    "OriginProject",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
     }
+}
 
-    pub fn has_origin_name(&self) -> bool {
-        self.origin_name.is_some()
+impl ::protobuf::Clear for OriginProject {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_origin_id();
+        self.clear_origin_name();
+        self.clear_package_name();
+        self.clear_name();
+        self.clear_plan_path();
+        self.clear_owner_id();
+        self.clear_vcs_type();
+        self.clear_vcs_data();
+        self.clear_build_timeout();
+        self.clear_webhook_secret();
+        self.unknown_fields.clear();
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_origin_name(&mut self, v: ::std::string::String) {
-        self.origin_name = ::protobuf::SingularField::some(v);
+impl ::std::fmt::Debug for OriginProject {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
-        if self.origin_name.is_none() {
-            self.origin_name.set_default();
-        }
-        self.origin_name.as_mut().unwrap()
+impl ::protobuf::reflect::ProtobufValue for OriginProject {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
+}
 
-    // Take field
-    pub fn take_origin_name(&mut self) -> ::std::string::String {
-        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
-    }
+#[derive(PartialEq,Clone,Default)]
+pub struct OriginProjectCreate {
+    // message fields
+    project: ::protobuf::SingularPtrField<OriginProject>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
 
-    pub fn get_origin_name(&self) -> &str {
-        match self.origin_name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginProjectCreate {}
 
-    fn get_origin_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.origin_name
+impl OriginProjectCreate {
+    pub fn new() -> OriginProjectCreate {
+        ::std::default::Default::default()
     }
 
-    fn mut_origin_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.origin_name
+    pub fn default_instance() -> &'static OriginProjectCreate {
+        static mut instance: ::protobuf::lazy::Lazy<OriginProjectCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginProjectCreate,
+        };
+        unsafe {
+            instance.get(OriginProjectCreate::new)
+        }
     }
 
-    // optional string package_name = 4;
+    // optional .originsrv.OriginProject project = 1;
 
-    pub fn clear_package_name(&mut self) {
-        self.package_name.clear();
+    pub fn clear_project(&mut self) {
+        self.project.clear();
     }
 
-    pub fn has_package_name(&self) -> bool {
-        self.package_name.is_some()
+    pub fn has_project(&self) -> bool {
+        self.project.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_package_name(&mut self, v: ::std::string::String) {
-        self.package_name = ::protobuf::SingularField::some(v);
+    pub fn set_project(&mut self, v: OriginProject) {
+        self.project = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_package_name(&mut self) -> &mut ::std::string::String {
-        if self.package_name.is_none() {
-            self.package_name.set_default();
+    pub fn mut_project(&mut self) -> &mut OriginProject {
+        if self.project.is_none() {
+            self.project.set_default();
         }
-        self.package_name.as_mut().unwrap()
+        self.project.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_package_name(&mut self) -> ::std::string::String {
-        self.package_name.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_package_name(&self) -> &str {
-        match self.package_name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
-
-    fn get_package_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.package_name
+    pub fn take_project(&mut self) -> OriginProject {
+        self.project.take().unwrap_or_else(|| OriginProject::new())
     }
 
-    fn mut_package_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.package_name
+    pub fn get_project(&self) -> &OriginProject {
+        self.project.as_ref().unwrap_or_else(|| OriginProject::default_instance())
     }
 
-    // optional string name = 5;
-
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+    fn get_project_for_reflect(&self) -> &::protobuf::SingularPtrField<OriginProject> {
+        &self.project
     }
 
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
+    fn mut_project_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<OriginProject> {
+        &mut self.project
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
+impl ::protobuf::Message for OriginProjectCreate {
+    fn is_initialized(&self) -> bool {
+        for v in &self.project {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.project)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
         }
-        self.name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+        ::std::result::Result::Ok(())
     }
 
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
-            Some(v) => &v,
-            None => "",
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.project.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.name
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.project.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.name
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    // optional string plan_path = 6;
-
-    pub fn clear_plan_path(&mut self) {
-        self.plan_path.clear();
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
     }
 
-    pub fn has_plan_path(&self) -> bool {
-        self.plan_path.is_some()
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
 
-    // Param is passed by value, moved
-    pub fn set_plan_path(&mut self, v: ::std::string::String) {
-        self.plan_path = ::protobuf::SingularField::some(v);
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
     }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_plan_path(&mut self) -> &mut ::std::string::String {
-        if self.plan_path.is_none() {
-            self.plan_path.set_default();
-        }
-        self.plan_path.as_mut().unwrap()
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
     }
-
-    // Take field
-    pub fn take_plan_path(&mut self) -> ::std::string::String {
-        self.plan_path.take().unwrap_or_else(|| ::std::string::String::new())
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
     }
 
-    pub fn get_plan_path(&self) -> &str {
-        match self.plan_path.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
+}
 
-    fn get_plan_path_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.plan_path
+impl ::protobuf::MessageStatic for OriginProjectCreate {
+    fn new() -> OriginProjectCreate {
+        OriginProjectCreate::new()
     }
 
-    fn mut_plan_path_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.plan_path
+    fn descriptor_static(_: ::std::option::Option<OriginProjectCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OriginProject>>(
+                    "project",
+                    OriginProjectCreate::get_project_for_reflect,
+                    OriginProjectCreate::mut_project_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginProjectCreate>(
+                    "OriginProjectCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
     }
+}
 
-    // optional uint64 owner_id = 7;
-
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = ::std::option::Option::None;
+impl ::protobuf::Clear for OriginProjectCreate {
+    fn clear(&mut self) {
+        self.clear_project();
+        self.unknown_fields.clear();
     }
+}
 
-    pub fn has_owner_id(&self) -> bool {
-        self.owner_id.is_some()
+impl ::std::fmt::Debug for OriginProjectCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u64) {
-        self.owner_id = ::std::option::Option::Some(v);
+impl ::protobuf::reflect::ProtobufValue for OriginProjectCreate {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
+}
 
-    pub fn get_owner_id(&self) -> u64 {
-        self.owner_id.unwrap_or(0)
-    }
+#[derive(PartialEq,Clone,Default)]
+pub struct OriginProjectDelete {
+    // message fields
+    name: ::protobuf::SingularField<::std::string::String>,
+    requestor_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
 
-    fn get_owner_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.owner_id
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginProjectDelete {}
+
+impl OriginProjectDelete {
+    pub fn new() -> OriginProjectDelete {
+        ::std::default::Default::default()
     }
 
-    fn mut_owner_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.owner_id
+    pub fn default_instance() -> &'static OriginProjectDelete {
+        static mut instance: ::protobuf::lazy::Lazy<OriginProjectDelete> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginProjectDelete,
+        };
+        unsafe {
+            instance.get(OriginProjectDelete::new)
+        }
     }
 
-    // optional string vcs_type = 8;
+    // optional string name = 1;
 
-    pub fn clear_vcs_type(&mut self) {
-        self.vcs_type.clear();
+    pub fn clear_name(&mut self) {
+        self.name.clear();
     }
 
-    pub fn has_vcs_type(&self) -> bool {
-        self.vcs_type.is_some()
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_vcs_type(&mut self, v: ::std::string::String) {
-        self.vcs_type = ::protobuf::SingularField::some(v);
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_vcs_type(&mut self) -> &mut ::std::string::String {
-        if self.vcs_type.is_none() {
-            self.vcs_type.set_default();
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
         }
-        self.vcs_type.as_mut().unwrap()
+        self.name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_vcs_type(&mut self) -> ::std::string::String {
-        self.vcs_type.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_vcs_type(&self) -> &str {
-        match self.vcs_type.as_ref() {
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    fn get_vcs_type_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.vcs_type
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
     }
 
-    fn mut_vcs_type_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.vcs_type
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
     }
 
-    // optional string vcs_data = 9;
+    // optional uint64 requestor_id = 2;
 
-    pub fn clear_vcs_data(&mut self) {
-        self.vcs_data.clear();
+    pub fn clear_requestor_id(&mut self) {
+        self.requestor_id = ::std::option::Option::None;
     }
 
-    pub fn has_vcs_data(&self) -> bool {
-        self.vcs_data.is_some()
+    pub fn has_requestor_id(&self) -> bool {
+        self.requestor_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_vcs_data(&mut self, v: ::std::string::String) {
-        self.vcs_data = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_vcs_data(&mut self) -> &mut ::std::string::String {
-        if self.vcs_data.is_none() {
-            self.vcs_data.set_default();
-        }
-        self.vcs_data.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_vcs_data(&mut self) -> ::std::string::String {
-        self.vcs_data.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn set_requestor_id(&mut self, v: u64) {
+        self.requestor_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_vcs_data(&self) -> &str {
-        match self.vcs_data.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_requestor_id(&self) -> u64 {
+        self.requestor_id.unwrap_or(0)
     }
 
-    fn get_vcs_data_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.vcs_data
+    fn get_requestor_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.requestor_id
     }
 
-    fn mut_vcs_data_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.vcs_data
+    fn mut_requestor_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.requestor_id
     }
 }
 
-impl ::protobuf::Message for OriginProject {
+impl ::protobuf::Message for OriginProjectDelete {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -12830,43 +14873,14 @@ impl ::protobuf::Message for OriginProject {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.id = ::std::option::Option::Some(tmp);
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.origin_id = ::std::option::Option::Some(tmp);
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name)?;
-                },
-                4 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.package_name)?;
-                },
-                5 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
                 },
-                6 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.plan_path)?;
-                },
-                7 => {
+                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.owner_id = ::std::option::Option::Some(tmp);
-                },
-                8 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.vcs_type)?;
-                },
-                9 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.vcs_data)?;
+                    self.requestor_id = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -12880,32 +14894,11 @@ impl ::protobuf::Message for OriginProject {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.id {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(v) = self.origin_id {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(ref v) = self.origin_name.as_ref() {
-            my_size += ::protobuf::rt::string_size(3, &v);
-        }
-        if let Some(ref v) = self.package_name.as_ref() {
-            my_size += ::protobuf::rt::string_size(4, &v);
-        }
         if let Some(ref v) = self.name.as_ref() {
-            my_size += ::protobuf::rt::string_size(5, &v);
-        }
-        if let Some(ref v) = self.plan_path.as_ref() {
-            my_size += ::protobuf::rt::string_size(6, &v);
-        }
-        if let Some(v) = self.owner_id {
-            my_size += ::protobuf::rt::value_size(7, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(ref v) = self.vcs_type.as_ref() {
-            my_size += ::protobuf::rt::string_size(8, &v);
+            my_size += ::protobuf::rt::string_size(1, &v);
         }
-        if let Some(ref v) = self.vcs_data.as_ref() {
-            my_size += ::protobuf::rt::string_size(9, &v);
+        if let Some(v) = self.requestor_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -12913,32 +14906,11 @@ impl ::protobuf::Message for OriginProject {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.id {
-            os.write_uint64(1, v)?;
-        }
-        if let Some(v) = self.origin_id {
-            os.write_uint64(2, v)?;
-        }
-        if let Some(ref v) = self.origin_name.as_ref() {
-            os.write_string(3, &v)?;
-        }
-        if let Some(ref v) = self.package_name.as_ref() {
-            os.write_string(4, &v)?;
-        }
         if let Some(ref v) = self.name.as_ref() {
-            os.write_string(5, &v)?;
-        }
-        if let Some(ref v) = self.plan_path.as_ref() {
-            os.write_string(6, &v)?;
-        }
-        if let Some(v) = self.owner_id {
-            os.write_uint64(7, v)?;
-        }
-        if let Some(ref v) = self.vcs_type.as_ref() {
-            os.write_string(8, &v)?;
+            os.write_string(1, &v)?;
         }
-        if let Some(ref v) = self.vcs_data.as_ref() {
-            os.write_string(9, &v)?;
+        if let Some(v) = self.requestor_id {
+            os.write_uint64(2, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -12971,12 +14943,12 @@ impl ::protobuf::Message for OriginProject {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginProject {
-    fn new() -> OriginProject {
-        OriginProject::new()
+impl ::protobuf::MessageStatic for OriginProjectDelete {
+    fn new() -> OriginProjectDelete {
+        OriginProjectDelete::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginProject>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginProjectDelete>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -12984,53 +14956,18 @@ impl ::protobuf::MessageStatic for OriginProject {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "id",
-                    OriginProject::get_id_for_reflect,
-                    OriginProject::mut_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "origin_id",
-                    OriginProject::get_origin_id_for_reflect,
-                    OriginProject::mut_origin_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "origin_name",
-                    OriginProject::get_origin_name_for_reflect,
-                    OriginProject::mut_origin_name_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "package_name",
-                    OriginProject::get_package_name_for_reflect,
-                    OriginProject::mut_package_name_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    OriginProject::get_name_for_reflect,
-                    OriginProject::mut_name_for_reflect,
-                ));
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "plan_path",
-                    OriginProject::get_plan_path_for_reflect,
-                    OriginProject::mut_plan_path_for_reflect,
+                    "name",
+                    OriginProjectDelete::get_name_for_reflect,
+                    OriginProjectDelete::mut_name_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "owner_id",
-                    OriginProject::get_owner_id_for_reflect,
-                    OriginProject::mut_owner_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "vcs_type",
-                    OriginProject::get_vcs_type_for_reflect,
-                    OriginProject::mut_vcs_type_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "vcs_data",
-                    OriginProject::get_vcs_data_for_reflect,
-                    OriginProject::mut_vcs_data_for_reflect,
+                    "requestor_id",
+                    OriginProjectDelete::get_requestor_id_for_reflect,
+                    OriginProjectDelete::mut_requestor_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginProject>(
-                    "OriginProject",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginProjectDelete>(
+                    "OriginProjectDelete",
                     fields,
                     file_descriptor_proto()
                 )
@@ -13039,109 +14976,100 @@ impl ::protobuf::MessageStatic for OriginProject {
     }
 }
 
-impl ::protobuf::Clear for OriginProject {
+impl ::protobuf::Clear for OriginProjectDelete {
     fn clear(&mut self) {
-        self.clear_id();
-        self.clear_origin_id();
-        self.clear_origin_name();
-        self.clear_package_name();
         self.clear_name();
-        self.clear_plan_path();
-        self.clear_owner_id();
-        self.clear_vcs_type();
-        self.clear_vcs_data();
+        self.clear_requestor_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for OriginProject {
+impl ::std::fmt::Debug for OriginProjectDelete {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OriginProject {
+impl ::protobuf::reflect::ProtobufValue for OriginProjectDelete {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct OriginProjectCreate {
+pub struct OriginProjectGet {
     // message fields
-    project: ::protobuf::SingularPtrField<OriginProject>,
+    name: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginProjectCreate {}
+unsafe impl ::std::marker::Sync for OriginProjectGet {}
 
-impl OriginProjectCreate {
-    pub fn new() -> OriginProjectCreate {
+impl OriginProjectGet {
+    pub fn new() -> OriginProjectGet {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginProjectCreate {
-        static mut instance: ::protobuf::lazy::Lazy<OriginProjectCreate> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginProjectGet {
+        static mut instance: ::protobuf::lazy::Lazy<OriginProjectGet> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginProjectCreate,
+            ptr: 0 as *const OriginProjectGet,
         };
         unsafe {
-            instance.get(OriginProjectCreate::new)
+            instance.get(OriginProjectGet::new)
         }
     }
 
-    // optional .originsrv.OriginProject project = 1;
+    // optional string name = 1;
 
-    pub fn clear_project(&mut self) {
-        self.project.clear();
+    pub fn clear_name(&mut self) {
+        self.name.clear();
     }
 
-    pub fn has_project(&self) -> bool {
-        self.project.is_some()
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_project(&mut self, v: OriginProject) {
-        self.project = ::protobuf::SingularPtrField::some(v);
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_project(&mut self) -> &mut OriginProject {
-        if self.project.is_none() {
-            self.project.set_default();
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
         }
-        self.project.as_mut().unwrap()
+        self.name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_project(&mut self) -> OriginProject {
-        self.project.take().unwrap_or_else(|| OriginProject::new())
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_project(&self) -> &OriginProject {
-        self.project.as_ref().unwrap_or_else(|| OriginProject::default_instance())
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    fn get_project_for_reflect(&self) -> &::protobuf::SingularPtrField<OriginProject> {
-        &self.project
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
     }
 
-    fn mut_project_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<OriginProject> {
-        &mut self.project
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
     }
 }
 
-impl ::protobuf::Message for OriginProjectCreate {
+impl ::protobuf::Message for OriginProjectGet {
     fn is_initialized(&self) -> bool {
-        for v in &self.project {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -13150,7 +15078,7 @@ impl ::protobuf::Message for OriginProjectCreate {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.project)?;
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -13164,9 +15092,8 @@ impl ::protobuf::Message for OriginProjectCreate {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.project.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -13174,10 +15101,8 @@ impl ::protobuf::Message for OriginProjectCreate {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.project.as_ref() {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(1, &v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -13210,12 +15135,12 @@ impl ::protobuf::Message for OriginProjectCreate {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginProjectCreate {
-    fn new() -> OriginProjectCreate {
-        OriginProjectCreate::new()
+impl ::protobuf::MessageStatic for OriginProjectGet {
+    fn new() -> OriginProjectGet {
+        OriginProjectGet::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginProjectCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginProjectGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -13223,13 +15148,13 @@ impl ::protobuf::MessageStatic for OriginProjectCreate {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OriginProject>>(
-                    "project",
-                    OriginProjectCreate::get_project_for_reflect,
-                    OriginProjectCreate::mut_project_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    OriginProjectGet::get_name_for_reflect,
+                    OriginProjectGet::mut_name_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginProjectCreate>(
-                    "OriginProjectCreate",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginProjectGet>(
+                    "OriginProjectGet",
                     fields,
                     file_descriptor_proto()
                 )
@@ -13238,127 +15163,129 @@ impl ::protobuf::MessageStatic for OriginProjectCreate {
     }
 }
 
-impl ::protobuf::Clear for OriginProjectCreate {
+impl ::protobuf::Clear for OriginProjectGet {
     fn clear(&mut self) {
-        self.clear_project();
+        self.clear_name();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for OriginProjectCreate {
+impl ::std::fmt::Debug for OriginProjectGet {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OriginProjectCreate {
+impl ::protobuf::reflect::ProtobufValue for OriginProjectGet {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct OriginProjectDelete {
+pub struct OriginProjectUpdate {
     // message fields
-    name: ::protobuf::SingularField<::std::string::String>,
     requestor_id: ::std::option::Option<u64>,
+    project: ::protobuf::SingularPtrField<OriginProject>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginProjectDelete {}
+unsafe impl ::std::marker::Sync for OriginProjectUpdate {}
 
-impl OriginProjectDelete {
-    pub fn new() -> OriginProjectDelete {
+impl OriginProjectUpdate {
+    pub fn new() -> OriginProjectUpdate {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginProjectDelete {
-        static mut instance: ::protobuf::lazy::Lazy<OriginProjectDelete> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginProjectUpdate {
+        static mut instance: ::protobuf::lazy::Lazy<OriginProjectUpdate> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginProjectDelete,
+            ptr: 0 as *const OriginProjectUpdate,
         };
         unsafe {
-            instance.get(OriginProjectDelete::new)
+            instance.get(OriginProjectUpdate::new)
         }
     }
 
-    // optional string name = 1;
+    // optional uint64 requestor_id = 1;
 
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+    pub fn clear_requestor_id(&mut self) {
+        self.requestor_id = ::std::option::Option::None;
     }
 
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
+    pub fn has_requestor_id(&self) -> bool {
+        self.requestor_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
+    pub fn set_requestor_id(&mut self, v: u64) {
+        self.requestor_id = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
-        }
-        self.name.as_mut().unwrap()
+    pub fn get_requestor_id(&self) -> u64 {
+        self.requestor_id.unwrap_or(0)
     }
 
-    // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    fn get_requestor_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.requestor_id
     }
 
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    fn mut_requestor_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.requestor_id
     }
 
-    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.name
-    }
+    // optional .originsrv.OriginProject project = 2;
 
-    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.name
+    pub fn clear_project(&mut self) {
+        self.project.clear();
     }
 
-    // optional uint64 requestor_id = 2;
+    pub fn has_project(&self) -> bool {
+        self.project.is_some()
+    }
 
-    pub fn clear_requestor_id(&mut self) {
-        self.requestor_id = ::std::option::Option::None;
+    // Param is passed by value, moved
+    pub fn set_project(&mut self, v: OriginProject) {
+        self.project = ::protobuf::SingularPtrField::some(v);
     }
 
-    pub fn has_requestor_id(&self) -> bool {
-        self.requestor_id.is_some()
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_project(&mut self) -> &mut OriginProject {
+        if self.project.is_none() {
+            self.project.set_default();
+        }
+        self.project.as_mut().unwrap()
     }
 
-    // Param is passed by value, moved
-    pub fn set_requestor_id(&mut self, v: u64) {
-        self.requestor_id = ::std::option::Option::Some(v);
+    // Take field
+    pub fn take_project(&mut self) -> OriginProject {
+        self.project.take().unwrap_or_else(|| OriginProject::new())
     }
 
-    pub fn get_requestor_id(&self) -> u64 {
-        self.requestor_id.unwrap_or(0)
+    pub fn get_project(&self) -> &OriginProject {
+        self.project.as_ref().unwrap_or_else(|| OriginProject::default_instance())
     }
 
-    fn get_requestor_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.requestor_id
+    fn get_project_for_reflect(&self) -> &::protobuf::SingularPtrField<OriginProject> {
+        &self.project
     }
 
-    fn mut_requestor_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.requestor_id
+    fn mut_project_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<OriginProject> {
+        &mut self.project
     }
 }
 
-impl ::protobuf::Message for OriginProjectDelete {
+impl ::protobuf::Message for OriginProjectUpdate {
     fn is_initialized(&self) -> bool {
+        for v in &self.project {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -13367,15 +15294,15 @@ impl ::protobuf::Message for OriginProjectDelete {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
-                },
-                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
                     self.requestor_id = ::std::option::Option::Some(tmp);
                 },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.project)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -13388,11 +15315,12 @@ impl ::protobuf::Message for OriginProjectDelete {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.name.as_ref() {
-            my_size += ::protobuf::rt::string_size(1, &v);
-        }
         if let Some(v) = self.requestor_id {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.project.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -13400,11 +15328,13 @@ impl ::protobuf::Message for OriginProjectDelete {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.name.as_ref() {
-            os.write_string(1, &v)?;
-        }
         if let Some(v) = self.requestor_id {
-            os.write_uint64(2, v)?;
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.project.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -13437,12 +15367,12 @@ impl ::protobuf::Message for OriginProjectDelete {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginProjectDelete {
-    fn new() -> OriginProjectDelete {
-        OriginProjectDelete::new()
+impl ::protobuf::MessageStatic for OriginProjectUpdate {
+    fn new() -> OriginProjectUpdate {
+        OriginProjectUpdate::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginProjectDelete>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginProjectUpdate>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -13450,18 +15380,18 @@ impl ::protobuf::MessageStatic for OriginProjectDelete {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    OriginProjectDelete::get_name_for_reflect,
-                    OriginProjectDelete::mut_name_for_reflect,
-                ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "requestor_id",
-                    OriginProjectDelete::get_requestor_id_for_reflect,
-                    OriginProjectDelete::mut_requestor_id_for_reflect,
+                    OriginProjectUpdate::get_requestor_id_for_reflect,
+                    OriginProjectUpdate::mut_requestor_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginProjectDelete>(
-                    "OriginProjectDelete",
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OriginProject>>(
+                    "project",
+                    OriginProjectUpdate::get_project_for_reflect,
+                    OriginProjectUpdate::mut_project_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginProjectUpdate>(
+                    "OriginProjectUpdate",
                     fields,
                     file_descriptor_proto()
                 )
@@ -13470,99 +15400,133 @@ impl ::protobuf::MessageStatic for OriginProjectDelete {
     }
 }
 
-impl ::protobuf::Clear for OriginProjectDelete {
+impl ::protobuf::Clear for OriginProjectUpdate {
     fn clear(&mut self) {
-        self.clear_name();
         self.clear_requestor_id();
+        self.clear_project();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for OriginProjectDelete {
+impl ::std::fmt::Debug for OriginProjectUpdate {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OriginProjectDelete {
+impl ::protobuf::reflect::ProtobufValue for OriginProjectUpdate {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct OriginProjectGet {
+pub struct OriginProjectListRequest {
     // message fields
-    name: ::protobuf::SingularField<::std::string::String>,
+    origin: ::protobuf::SingularField<::std::string::String>,
+    vcs_data: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginProjectGet {}
+unsafe impl ::std::marker::Sync for OriginProjectListRequest {}
 
-impl OriginProjectGet {
-    pub fn new() -> OriginProjectGet {
+impl OriginProjectListRequest {
+    pub fn new() -> OriginProjectListRequest {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginProjectGet {
-        static mut instance: ::protobuf::lazy::Lazy<OriginProjectGet> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginProjectListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginProjectListRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginProjectGet,
+            ptr: 0 as *const OriginProjectListRequest,
         };
         unsafe {
-            instance.get(OriginProjectGet::new)
+            instance.get(OriginProjectListRequest::new)
         }
     }
 
-    // optional string name = 1;
+    // optional string origin = 1;
 
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
     }
 
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
         }
-        self.name.as_mut().unwrap()
+        self.origin.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.name
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.origin
     }
 
-    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.name
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.origin
+    }
+
+    // repeated string vcs_data = 2;
+
+    pub fn clear_vcs_data(&mut self) {
+        self.vcs_data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vcs_data(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.vcs_data = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_vcs_data(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.vcs_data
+    }
+
+    // Take field
+    pub fn take_vcs_data(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.vcs_data, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_vcs_data(&self) -> &[::std::string::String] {
+        &self.vcs_data
+    }
+
+    fn get_vcs_data_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.vcs_data
+    }
+
+    fn mut_vcs_data_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.vcs_data
     }
 }
 
-impl ::protobuf::Message for OriginProjectGet {
+impl ::protobuf::Message for OriginProjectListRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -13572,7 +15536,10 @@ impl ::protobuf::Message for OriginProjectGet {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.vcs_data)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -13586,18 +15553,24 @@ impl ::protobuf::Message for OriginProjectGet {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.name.as_ref() {
+        if let Some(ref v) = self.origin.as_ref() {
             my_size += ::protobuf::rt::string_size(1, &v);
         }
+        for value in &self.vcs_data {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.name.as_ref() {
+        if let Some(ref v) = self.origin.as_ref() {
             os.write_string(1, &v)?;
         }
+        for v in &self.vcs_data {
+            os.write_string(2, &v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -13629,12 +15602,12 @@ impl ::protobuf::Message for OriginProjectGet {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginProjectGet {
-    fn new() -> OriginProjectGet {
-        OriginProjectGet::new()
+impl ::protobuf::MessageStatic for OriginProjectListRequest {
+    fn new() -> OriginProjectListRequest {
+        OriginProjectListRequest::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginProjectGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginProjectListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -13643,12 +15616,17 @@ impl ::protobuf::MessageStatic for OriginProjectGet {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    OriginProjectGet::get_name_for_reflect,
-                    OriginProjectGet::mut_name_for_reflect,
+                    "origin",
+                    OriginProjectListRequest::get_origin_for_reflect,
+                    OriginProjectListRequest::mut_origin_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginProjectGet>(
-                    "OriginProjectGet",
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "vcs_data",
+                    OriginProjectListRequest::get_vcs_data_for_reflect,
+                    OriginProjectListRequest::mut_vcs_data_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginProjectListRequest>(
+                    "OriginProjectListRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -13657,125 +15635,118 @@ impl ::protobuf::MessageStatic for OriginProjectGet {
     }
 }
 
-impl ::protobuf::Clear for OriginProjectGet {
+impl ::protobuf::Clear for OriginProjectListRequest {
     fn clear(&mut self) {
-        self.clear_name();
+        self.clear_origin();
+        self.clear_vcs_data();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for OriginProjectGet {
+impl ::std::fmt::Debug for OriginProjectListRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OriginProjectGet {
+impl ::protobuf::reflect::ProtobufValue for OriginProjectListRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct OriginProjectUpdate {
+pub struct OriginProjectListResponse {
     // message fields
-    requestor_id: ::std::option::Option<u64>,
-    project: ::protobuf::SingularPtrField<OriginProject>,
+    origin_id: ::std::option::Option<u64>,
+    projects: ::protobuf::RepeatedField<OriginProject>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginProjectUpdate {}
+unsafe impl ::std::marker::Sync for OriginProjectListResponse {}
 
-impl OriginProjectUpdate {
-    pub fn new() -> OriginProjectUpdate {
+impl OriginProjectListResponse {
+    pub fn new() -> OriginProjectListResponse {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginProjectUpdate {
-        static mut instance: ::protobuf::lazy::Lazy<OriginProjectUpdate> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginProjectListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OriginProjectListResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginProjectUpdate,
+            ptr: 0 as *const OriginProjectListResponse,
         };
         unsafe {
-            instance.get(OriginProjectUpdate::new)
+            instance.get(OriginProjectListResponse::new)
         }
     }
 
-    // optional uint64 requestor_id = 1;
+    // optional uint64 origin_id = 1;
 
-    pub fn clear_requestor_id(&mut self) {
-        self.requestor_id = ::std::option::Option::None;
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    pub fn has_requestor_id(&self) -> bool {
-        self.requestor_id.is_some()
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_requestor_id(&mut self, v: u64) {
-        self.requestor_id = ::std::option::Option::Some(v);
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_requestor_id(&self) -> u64 {
-        self.requestor_id.unwrap_or(0)
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
     }
 
-    fn get_requestor_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.requestor_id
+    fn get_origin_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.origin_id
     }
 
-    fn mut_requestor_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.requestor_id
+    fn mut_origin_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.origin_id
     }
 
-    // optional .originsrv.OriginProject project = 2;
-
-    pub fn clear_project(&mut self) {
-        self.project.clear();
-    }
+    // repeated .originsrv.OriginProject projects = 2;
 
-    pub fn has_project(&self) -> bool {
-        self.project.is_some()
+    pub fn clear_projects(&mut self) {
+        self.projects.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_project(&mut self, v: OriginProject) {
-        self.project = ::protobuf::SingularPtrField::some(v);
+    pub fn set_projects(&mut self, v: ::protobuf::RepeatedField<OriginProject>) {
+        self.projects = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_project(&mut self) -> &mut OriginProject {
-        if self.project.is_none() {
-            self.project.set_default();
-        }
-        self.project.as_mut().unwrap()
+    pub fn mut_projects(&mut self) -> &mut ::protobuf::RepeatedField<OriginProject> {
+        &mut self.projects
     }
 
     // Take field
-    pub fn take_project(&mut self) -> OriginProject {
-        self.project.take().unwrap_or_else(|| OriginProject::new())
+    pub fn take_projects(&mut self) -> ::protobuf::RepeatedField<OriginProject> {
+        ::std::mem::replace(&mut self.projects, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_project(&self) -> &OriginProject {
-        self.project.as_ref().unwrap_or_else(|| OriginProject::default_instance())
+    pub fn get_projects(&self) -> &[OriginProject] {
+        &self.projects
     }
 
-    fn get_project_for_reflect(&self) -> &::protobuf::SingularPtrField<OriginProject> {
-        &self.project
+    fn get_projects_for_reflect(&self) -> &::protobuf::RepeatedField<OriginProject> {
+        &self.projects
     }
 
-    fn mut_project_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<OriginProject> {
-        &mut self.project
+    fn mut_projects_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<OriginProject> {
+        &mut self.projects
     }
 }
 
-impl ::protobuf::Message for OriginProjectUpdate {
+impl ::protobuf::Message for OriginProjectListResponse {
     fn is_initialized(&self) -> bool {
-        for v in &self.project {
+        for v in &self.projects {
             if !v.is_initialized() {
                 return false;
             }
@@ -13792,10 +15763,10 @@ impl ::protobuf::Message for OriginProjectUpdate {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.requestor_id = ::std::option::Option::Some(tmp);
+                    self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.project)?;
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.projects)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -13809,27 +15780,27 @@ impl ::protobuf::Message for OriginProjectUpdate {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.requestor_id {
+        if let Some(v) = self.origin_id {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(ref v) = self.project.as_ref() {
-            let len = v.compute_size();
+        for value in &self.projects {
+            let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        }
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.requestor_id {
+        if let Some(v) = self.origin_id {
             os.write_uint64(1, v)?;
         }
-        if let Some(ref v) = self.project.as_ref() {
+        for v in &self.projects {
             os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
-        }
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -13861,12 +15832,12 @@ impl ::protobuf::Message for OriginProjectUpdate {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginProjectUpdate {
-    fn new() -> OriginProjectUpdate {
-        OriginProjectUpdate::new()
+impl ::protobuf::MessageStatic for OriginProjectListResponse {
+    fn new() -> OriginProjectListResponse {
+        OriginProjectListResponse::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginProjectUpdate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginProjectListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -13875,17 +15846,17 @@ impl ::protobuf::MessageStatic for OriginProjectUpdate {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "requestor_id",
-                    OriginProjectUpdate::get_requestor_id_for_reflect,
-                    OriginProjectUpdate::mut_requestor_id_for_reflect,
+                    "origin_id",
+                    OriginProjectListResponse::get_origin_id_for_reflect,
+                    OriginProjectListResponse::mut_origin_id_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OriginProject>>(
-                    "project",
-                    OriginProjectUpdate::get_project_for_reflect,
-                    OriginProjectUpdate::mut_project_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OriginProject>>(
+                    "projects",
+                    OriginProjectListResponse::get_projects_for_reflect,
+                    OriginProjectListResponse::mut_projects_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginProjectUpdate>(
-                    "OriginProjectUpdate",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginProjectListResponse>(
+                    "OriginProjectListResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -13894,21 +15865,21 @@ impl ::protobuf::MessageStatic for OriginProjectUpdate {
     }
 }
 
-impl ::protobuf::Clear for OriginProjectUpdate {
+impl ::protobuf::Clear for OriginProjectListResponse {
     fn clear(&mut self) {
-        self.clear_requestor_id();
-        self.clear_project();
+        self.clear_origin_id();
+        self.clear_projects();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for OriginProjectUpdate {
+impl ::std::fmt::Debug for OriginProjectListResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OriginProjectUpdate {
+impl ::protobuf::reflect::ProtobufValue for OriginProjectListResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -16849,227 +18820,249 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     hannelListRequest\x12\x1b\n\torigin_id\x18\x01\x20\x01(\x04R\x08originId\
     \"n\n\x19OriginChannelListResponse\x12\x1b\n\torigin_id\x18\x01\x20\x01(\
     \x04R\x08originId\x124\n\x08channels\x18\x02\x20\x03(\x0b2\x18.originsrv\
-    .OriginChannelR\x08channels\"b\n\x17OriginChannelPackageGet\x12\x12\n\
+    .OriginChannelR\x08channels\"z\n\x17OriginChannelPackageGet\x12\x12\n\
     \x04name\x18\x01\x20\x01(\tR\x04name\x123\n\x05ident\x18\x02\x20\x01(\
-    \x0b2\x1d.originsrv.OriginPackageIdentR\x05ident\"\x80\x01\n\x1dOriginCh\
-    annelPackageLatestGet\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x123\
-    \n\x05ident\x18\x02\x20\x01(\x0b2\x1d.originsrv.OriginPackageIdentR\x05i\
-    dent\x12\x16\n\x06target\x18\x03\x20\x01(\tR\x06target\"\x94\x01\n\x1fOr\
-    iginChannelPackageListRequest\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04n\
-    ame\x123\n\x05ident\x18\x02\x20\x01(\x0b2\x1d.originsrv.OriginPackageIde\
-    ntR\x05ident\x12\x14\n\x05start\x18\x03\x20\x01(\x04R\x05start\x12\x12\n\
-    \x04stop\x18\x04\x20\x01(\x04R\x04stop\"B\n\x13OriginChannelDelete\x12\
-    \x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\x1b\n\torigin_id\x18\x02\
-    \x20\x01(\x04R\x08originId\"\xbd\x01\n\x10OriginInvitation\x12\x0e\n\x02\
-    id\x18\x01\x20\x01(\x04R\x02id\x12\x1d\n\naccount_id\x18\x02\x20\x01(\
-    \x04R\taccountId\x12!\n\x0caccount_name\x18\x03\x20\x01(\tR\x0baccountNa\
-    me\x12\x1b\n\torigin_id\x18\x04\x20\x01(\x04R\x08originId\x12\x1f\n\x0bo\
-    rigin_name\x18\x05\x20\x01(\tR\noriginName\x12\x19\n\x08owner_id\x18\x06\
-    \x20\x01(\x04R\x07ownerId\"\x94\x01\n\x1dOriginInvitationAcceptRequest\
-    \x12\x1d\n\naccount_id\x18\x01\x20\x01(\x04R\taccountId\x12\x1b\n\tinvit\
-    e_id\x18\x02\x20\x01(\x04R\x08inviteId\x12\x1f\n\x0borigin_name\x18\x03\
-    \x20\x01(\tR\noriginName\x12\x16\n\x06ignore\x18\x04\x20\x01(\x08R\x06ig\
-    nore\"\xb3\x01\n\x16OriginInvitationCreate\x12\x1d\n\naccount_id\x18\x01\
-    \x20\x01(\x04R\taccountId\x12!\n\x0caccount_name\x18\x02\x20\x01(\tR\x0b\
-    accountName\x12\x1b\n\torigin_id\x18\x03\x20\x01(\x04R\x08originId\x12\
-    \x1f\n\x0borigin_name\x18\x04\x20\x01(\tR\noriginName\x12\x19\n\x08owner\
-    _id\x18\x05\x20\x01(\x04R\x07ownerId\":\n\x1bOriginInvitationListRequest\
-    \x12\x1b\n\torigin_id\x18\x01\x20\x01(\x04R\x08originId\"z\n\x1cOriginIn\
-    vitationListResponse\x12\x1b\n\torigin_id\x18\x01\x20\x01(\x04R\x08origi\
-    nId\x12=\n\x0binvitations\x18\x02\x20\x03(\x0b2\x1b.originsrv.OriginInvi\
-    tationR\x0binvitations\"`\n\x0eOriginKeyIdent\x12\x16\n\x06origin\x18\
-    \x01\x20\x01(\tR\x06origin\x12\x1a\n\x08revision\x18\x02\x20\x01(\tR\x08\
-    revision\x12\x1a\n\x08location\x18\x03\x20\x01(\tR\x08location\"6\n\x17O\
-    riginMemberListRequest\x12\x1b\n\torigin_id\x18\x01\x20\x01(\x04R\x08ori\
-    ginId\"Q\n\x18OriginMemberListResponse\x12\x1b\n\torigin_id\x18\x01\x20\
-    \x01(\x04R\x08originId\x12\x18\n\x07members\x18\x02\x20\x03(\tR\x07membe\
-    rs\"J\n\x12OriginMemberRemove\x12\x1b\n\torigin_id\x18\x01\x20\x01(\x04R\
-    \x08originId\x12\x17\n\x07user_id\x18\x02\x20\x01(\x04R\x06userId\"\xfa\
-    \x02\n\rOriginPackage\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\
-    \x19\n\x08owner_id\x18\x02\x20\x01(\x04R\x07ownerId\x12\x1b\n\torigin_id\
-    \x18\x03\x20\x01(\x04R\x08originId\x123\n\x05ident\x18\x04\x20\x01(\x0b2\
-    \x1d.originsrv.OriginPackageIdentR\x05ident\x12\x1a\n\x08checksum\x18\
-    \x05\x20\x01(\tR\x08checksum\x12\x1a\n\x08manifest\x18\x06\x20\x01(\tR\
-    \x08manifest\x121\n\x04deps\x18\x07\x20\x03(\x0b2\x1d.originsrv.OriginPa\
-    ckageIdentR\x04deps\x123\n\x05tdeps\x18\x08\x20\x03(\x0b2\x1d.originsrv.\
-    OriginPackageIdentR\x05tdeps\x12\x1c\n\x07exposes\x18\t\x20\x03(\rR\x07e\
-    xposesB\x02\x10\x01\x12\x16\n\x06config\x18\n\x20\x01(\tR\x06config\x12\
-    \x16\n\x06target\x18\x0b\x20\x01(\tR\x06target\"t\n\x12OriginPackageIden\
-    t\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06origin\x12\x12\n\x04name\
-    \x18\x02\x20\x01(\tR\x04name\x12\x18\n\x07version\x18\x03\x20\x01(\tR\
-    \x07version\x12\x18\n\x07release\x18\x04\x20\x01(\tR\x07release\"\x99\
-    \x01\n\x14OriginPackageVersion\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\
-    \x06origin\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x18\n\x07ve\
-    rsion\x18\x03\x20\x01(\tR\x07version\x12#\n\rrelease_count\x18\x04\x20\
-    \x01(\x04R\x0creleaseCount\x12\x16\n\x06latest\x18\x05\x20\x01(\tR\x06la\
-    test\"\xf0\x02\n\x13OriginPackageCreate\x12\x19\n\x08owner_id\x18\x01\
-    \x20\x01(\x04R\x07ownerId\x12\x1b\n\torigin_id\x18\x02\x20\x01(\x04R\x08\
-    originId\x123\n\x05ident\x18\x03\x20\x01(\x0b2\x1d.originsrv.OriginPacka\
-    geIdentR\x05ident\x12\x1a\n\x08checksum\x18\x04\x20\x01(\tR\x08checksum\
-    \x12\x1a\n\x08manifest\x18\x05\x20\x01(\tR\x08manifest\x121\n\x04deps\
-    \x18\x06\x20\x03(\x0b2\x1d.originsrv.OriginPackageIdentR\x04deps\x123\n\
-    \x05tdeps\x18\x07\x20\x03(\x0b2\x1d.originsrv.OriginPackageIdentR\x05tde\
-    ps\x12\x1c\n\x07exposes\x18\x08\x20\x03(\rR\x07exposesB\x02\x10\x01\x12\
-    \x16\n\x06config\x18\t\x20\x01(\tR\x06config\x12\x16\n\x06target\x18\n\
-    \x20\x01(\tR\x06target\"G\n\x10OriginPackageGet\x123\n\x05ident\x18\x01\
-    \x20\x01(\x0b2\x1d.originsrv.OriginPackageIdentR\x05ident\"e\n\x16Origin\
-    PackageLatestGet\x123\n\x05ident\x18\x01\x20\x01(\x0b2\x1d.originsrv.Ori\
-    ginPackageIdentR\x05ident\x12\x16\n\x06target\x18\x02\x20\x01(\tR\x06tar\
-    get\"\x95\x01\n\x18OriginPackageListRequest\x123\n\x05ident\x18\x01\x20\
-    \x01(\x0b2\x1d.originsrv.OriginPackageIdentR\x05ident\x12\x14\n\x05start\
-    \x18\x02\x20\x01(\x04R\x05start\x12\x12\n\x04stop\x18\x03\x20\x01(\x04R\
-    \x04stop\x12\x1a\n\x08distinct\x18\x04\x20\x01(\x08R\x08distinct\"\x92\
-    \x01\n\x19OriginPackageListResponse\x12\x14\n\x05start\x18\x01\x20\x01(\
-    \x04R\x05start\x12\x12\n\x04stop\x18\x02\x20\x01(\x04R\x04stop\x12\x14\n\
-    \x05count\x18\x03\x20\x01(\x04R\x05count\x125\n\x06idents\x18\x04\x20\
-    \x03(\x0b2\x1d.originsrv.OriginPackageIdentR\x06idents\"\x89\x01\n\x14Or\
-    iginPackagePromote\x12\x1d\n\nchannel_id\x18\x01\x20\x01(\x04R\tchannelI\
-    d\x12\x1d\n\npackage_id\x18\x02\x20\x01(\x04R\tpackageId\x123\n\x05ident\
-    \x18\x03\x20\x01(\x0b2\x1d.originsrv.OriginPackageIdentR\x05ident\"\x88\
-    \x01\n\x13OriginPackageDemote\x12\x1d\n\nchannel_id\x18\x01\x20\x01(\x04\
-    R\tchannelId\x12\x1d\n\npackage_id\x18\x02\x20\x01(\x04R\tpackageId\x123\
-    \n\x05ident\x18\x03\x20\x01(\x0b2\x1d.originsrv.OriginPackageIdentR\x05i\
-    dent\"\x90\x01\n\x1aOriginPackageSearchRequest\x12\x16\n\x06origin\x18\
-    \x01\x20\x01(\tR\x06origin\x12\x14\n\x05query\x18\x02\x20\x01(\tR\x05que\
-    ry\x12\x14\n\x05start\x18\x03\x20\x01(\x04R\x05start\x12\x12\n\x04stop\
-    \x18\x04\x20\x01(\x04R\x04stop\x12\x1a\n\x08distinct\x18\x05\x20\x01(\
-    \x08R\x08distinct\"b\n\x1eOriginPackageUniqueListRequest\x12\x16\n\x06or\
-    igin\x18\x01\x20\x01(\tR\x06origin\x12\x14\n\x05start\x18\x02\x20\x01(\
-    \x04R\x05start\x12\x12\n\x04stop\x18\x03\x20\x01(\x04R\x04stop\"\x98\x01\
-    \n\x1fOriginPackageUniqueListResponse\x12\x14\n\x05start\x18\x01\x20\x01\
-    (\x04R\x05start\x12\x12\n\x04stop\x18\x02\x20\x01(\x04R\x04stop\x12\x14\
-    \n\x05count\x18\x03\x20\x01(\x04R\x05count\x125\n\x06idents\x18\x04\x20\
-    \x03(\x0b2\x1d.originsrv.OriginPackageIdentR\x06idents\"M\n\x1fOriginPac\
-    kageVersionListRequest\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06origin\
-    \x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\"_\n\x20OriginPackageVers\
-    ionListResponse\x12;\n\x08versions\x18\x01\x20\x03(\x0b2\x1f.originsrv.O\
-    riginPackageVersionR\x08versions\"\x82\x02\n\rOriginProject\x12\x0e\n\
-    \x02id\x18\x01\x20\x01(\x04R\x02id\x12\x1b\n\torigin_id\x18\x02\x20\x01(\
-    \x04R\x08originId\x12\x1f\n\x0borigin_name\x18\x03\x20\x01(\tR\noriginNa\
-    me\x12!\n\x0cpackage_name\x18\x04\x20\x01(\tR\x0bpackageName\x12\x12\n\
-    \x04name\x18\x05\x20\x01(\tR\x04name\x12\x1b\n\tplan_path\x18\x06\x20\
-    \x01(\tR\x08planPath\x12\x19\n\x08owner_id\x18\x07\x20\x01(\x04R\x07owne\
-    rId\x12\x19\n\x08vcs_type\x18\x08\x20\x01(\tR\x07vcsType\x12\x19\n\x08vc\
-    s_data\x18\t\x20\x01(\tR\x07vcsData\"I\n\x13OriginProjectCreate\x122\n\
-    \x07project\x18\x01\x20\x01(\x0b2\x18.originsrv.OriginProjectR\x07projec\
-    t\"L\n\x13OriginProjectDelete\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04n\
-    ame\x12!\n\x0crequestor_id\x18\x02\x20\x01(\x04R\x0brequestorId\"&\n\x10\
-    OriginProjectGet\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\"l\n\x13O\
-    riginProjectUpdate\x12!\n\x0crequestor_id\x18\x01\x20\x01(\x04R\x0breque\
-    storId\x122\n\x07project\x18\x02\x20\x01(\x0b2\x18.originsrv.OriginProje\
-    ctR\x07project\"\x9d\x01\n\x0fOriginPublicKey\x12\x0e\n\x02id\x18\x01\
-    \x20\x01(\x04R\x02id\x12\x1b\n\torigin_id\x18\x02\x20\x01(\x04R\x08origi\
-    nId\x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04name\x12\x1a\n\x08revision\
-    \x18\x04\x20\x01(\tR\x08revision\x12\x12\n\x04body\x18\x05\x20\x01(\x0cR\
-    \x04body\x12\x19\n\x08owner_id\x18\x06\x20\x01(\x04R\x07ownerId\"\x93\
-    \x01\n\x15OriginPublicKeyCreate\x12\x1b\n\torigin_id\x18\x01\x20\x01(\
-    \x04R\x08originId\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x1a\
-    \n\x08revision\x18\x03\x20\x01(\tR\x08revision\x12\x12\n\x04body\x18\x04\
-    \x20\x01(\x0cR\x04body\x12\x19\n\x08owner_id\x18\x05\x20\x01(\x04R\x07ow\
-    nerId\"c\n\x12OriginPublicKeyGet\x12\x19\n\x08owner_id\x18\x01\x20\x01(\
-    \x04R\x07ownerId\x12\x16\n\x06origin\x18\x02\x20\x01(\tR\x06origin\x12\
-    \x1a\n\x08revision\x18\x03\x20\x01(\tR\x08revision\"M\n\x18OriginPublicK\
-    eyLatestGet\x12\x19\n\x08owner_id\x18\x01\x20\x01(\x04R\x07ownerId\x12\
-    \x16\n\x06origin\x18\x02\x20\x01(\tR\x06origin\"T\n\x1aOriginPublicKeyLi\
-    stRequest\x12\x19\n\x08owner_id\x18\x01\x20\x01(\x04R\x07ownerId\x12\x1b\
-    \n\torigin_id\x18\x02\x20\x01(\x04R\x08originId\"j\n\x1bOriginPublicKeyL\
-    istResponse\x12\x1b\n\torigin_id\x18\x01\x20\x01(\x04R\x08originId\x12.\
-    \n\x04keys\x18\x02\x20\x03(\x0b2\x1a.originsrv.OriginPublicKeyR\x04keys\
-    \"\x9d\x01\n\x0fOriginSecretKey\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\
-    \x02id\x12\x1b\n\torigin_id\x18\x02\x20\x01(\x04R\x08originId\x12\x12\n\
-    \x04name\x18\x03\x20\x01(\tR\x04name\x12\x1a\n\x08revision\x18\x04\x20\
-    \x01(\tR\x08revision\x12\x12\n\x04body\x18\x05\x20\x01(\x0cR\x04body\x12\
-    \x19\n\x08owner_id\x18\x06\x20\x01(\x04R\x07ownerId\"\x93\x01\n\x15Origi\
-    nSecretKeyCreate\x12\x1b\n\torigin_id\x18\x01\x20\x01(\x04R\x08originId\
-    \x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x1a\n\x08revision\x18\
-    \x03\x20\x01(\tR\x08revision\x12\x12\n\x04body\x18\x04\x20\x01(\x0cR\x04\
-    body\x12\x19\n\x08owner_id\x18\x05\x20\x01(\x04R\x07ownerId\"G\n\x12Orig\
-    inSecretKeyGet\x12\x19\n\x08owner_id\x18\x01\x20\x01(\x04R\x07ownerId\
-    \x12\x16\n\x06origin\x18\x02\x20\x01(\tR\x06originJ\xd9s\n\x07\x12\x05\0\
-    \0\xe8\x02\x01\n\x08\n\x01\x02\x12\x03\0\x08\x11\n\x15\n\x02\x04\0\x12\
-    \x04\x03\0\x05\x01\x1a\t\x20Account\n\n\n\n\x03\x04\0\x01\x12\x03\x03\
-    \x08$\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x02!\n\x0c\n\x05\x04\0\x02\0\
-    \x04\x12\x03\x04\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x0b\x11\n\
-    \x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x12\x1c\n\x0c\n\x05\x04\0\x02\0\
-    \x03\x12\x03\x04\x1f\x20\n\n\n\x02\x04\x01\x12\x04\x07\0\n\x01\n\n\n\x03\
-    \x04\x01\x01\x12\x03\x07\x08%\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x08\x02!\
-    \n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\x08\x02\n\n\x0c\n\x05\x04\x01\x02\
-    \0\x05\x12\x03\x08\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x08\x12\
-    \x1c\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x08\x1f\x20\n\x0b\n\x04\x04\
-    \x01\x02\x01\x12\x03\t\x02,\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03\t\
-    \x02\n\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03\t\x0b\x1b\n\x0c\n\x05\x04\
-    \x01\x02\x01\x01\x12\x03\t\x1c'\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\
-    \t*+\n\n\n\x02\x04\x02\x12\x04\x0c\0\x15\x01\n\n\n\x03\x04\x02\x01\x12\
-    \x03\x0c\x08\x20\n\x0c\n\x04\x04\x02\x08\0\x12\x04\r\x02\x10\x03\n\x0c\n\
-    \x05\x04\x02\x08\0\x01\x12\x03\r\x08\x14\n\x0b\n\x04\x04\x02\x02\0\x12\
-    \x03\x0e\x04\x1a\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0e\x04\n\n\x0c\n\
-    \x05\x04\x02\x02\0\x01\x12\x03\x0e\x0b\x15\n\x0c\n\x05\x04\x02\x02\0\x03\
-    \x12\x03\x0e\x18\x19\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0f\x04\x1c\n\
-    \x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\x04\x02\x02\
-    \x01\x01\x12\x03\x0f\x0b\x17\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0f\
-    \x1a\x1b\n\x0c\n\x04\x04\x02\x08\x01\x12\x04\x11\x02\x14\x03\n\x0c\n\x05\
-    \x04\x02\x08\x01\x01\x12\x03\x11\x08\x13\n\x0b\n\x04\x04\x02\x02\x02\x12\
-    \x03\x12\x04\x19\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x12\x04\n\n\x0c\
-    \n\x05\x04\x02\x02\x02\x01\x12\x03\x12\x0b\x14\n\x0c\n\x05\x04\x02\x02\
-    \x02\x03\x12\x03\x12\x17\x18\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x13\x04\
-    \x1b\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\
-    \x02\x02\x03\x01\x12\x03\x13\x0b\x16\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\
-    \x03\x13\x19\x1a\n\n\n\x02\x04\x03\x12\x04\x17\0\x19\x01\n\n\n\x03\x04\
-    \x03\x01\x12\x03\x17\x08!\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x18\x02\x1f\
-    \n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x18\x02\n\n\x0c\n\x05\x04\x03\x02\
-    \0\x05\x12\x03\x18\x0b\x0f\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x18\x10\
-    \x1a\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x18\x1d\x1e\n\x14\n\x02\x04\
-    \x04\x12\x04\x1c\0!\x01\x1a\x08\x20Origin\n\n\n\n\x03\x04\x04\x01\x12\
-    \x03\x1c\x08\x0e\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1d\x02\x19\n\x0c\n\
-    \x05\x04\x04\x02\0\x04\x12\x03\x1d\x02\n\n\x0c\n\x05\x04\x04\x02\0\x05\
-    \x12\x03\x1d\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x1d\x12\x14\n\
-    \x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1d\x17\x18\n\x0b\n\x04\x04\x04\x02\
-    \x01\x12\x03\x1e\x02\x1b\n\x0c\n\x05\x04\x04\x02\x01\x04\x12\x03\x1e\x02\
-    \n\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03\x1e\x0b\x11\n\x0c\n\x05\x04\
-    \x04\x02\x01\x01\x12\x03\x1e\x12\x16\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\
-    \x03\x1e\x19\x1a\n\x0b\n\x04\x04\x04\x02\x02\x12\x03\x1f\x02\x1f\n\x0c\n\
-    \x05\x04\x04\x02\x02\x04\x12\x03\x1f\x02\n\n\x0c\n\x05\x04\x04\x02\x02\
-    \x05\x12\x03\x1f\x0b\x11\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03\x1f\x12\
-    \x1a\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03\x1f\x1d\x1e\n\x0b\n\x04\x04\
-    \x04\x02\x03\x12\x03\x20\x02'\n\x0c\n\x05\x04\x04\x02\x03\x04\x12\x03\
-    \x20\x02\n\n\x0c\n\x05\x04\x04\x02\x03\x05\x12\x03\x20\x0b\x11\n\x0c\n\
-    \x05\x04\x04\x02\x03\x01\x12\x03\x20\x12\"\n\x0c\n\x05\x04\x04\x02\x03\
-    \x03\x12\x03\x20%&\n\n\n\x02\x04\x05\x12\x04#\0'\x01\n\n\n\x03\x04\x05\
-    \x01\x12\x03#\x08\x14\n\x0b\n\x04\x04\x05\x02\0\x12\x03$\x02\x1b\n\x0c\n\
-    \x05\x04\x05\x02\0\x04\x12\x03$\x02\n\n\x0c\n\x05\x04\x05\x02\0\x05\x12\
-    \x03$\x0b\x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03$\x12\x16\n\x0c\n\x05\
-    \x04\x05\x02\0\x03\x12\x03$\x19\x1a\n\x0b\n\x04\x04\x05\x02\x01\x12\x03%\
-    \x02\x1f\n\x0c\n\x05\x04\x05\x02\x01\x04\x12\x03%\x02\n\n\x0c\n\x05\x04\
-    \x05\x02\x01\x05\x12\x03%\x0b\x11\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\
-    \x03%\x12\x1a\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03%\x1d\x1e\n\x0b\n\
-    \x04\x04\x05\x02\x02\x12\x03&\x02!\n\x0c\n\x05\x04\x05\x02\x02\x04\x12\
-    \x03&\x02\n\n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x03&\x0b\x11\n\x0c\n\x05\
-    \x04\x05\x02\x02\x01\x12\x03&\x12\x1c\n\x0c\n\x05\x04\x05\x02\x02\x03\
-    \x12\x03&\x1f\x20\n\n\n\x02\x04\x06\x12\x04)\0+\x01\n\n\n\x03\x04\x06\
-    \x01\x12\x03)\x08\x14\n\x0b\n\x04\x04\x06\x02\0\x12\x03*\x02\x1b\n\x0c\n\
-    \x05\x04\x06\x02\0\x04\x12\x03*\x02\n\n\x0c\n\x05\x04\x06\x02\0\x05\x12\
-    \x03*\x0b\x11\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03*\x12\x16\n\x0c\n\x05\
-    \x04\x06\x02\0\x03\x12\x03*\x19\x1a\n\n\n\x02\x04\x07\x12\x04-\0/\x01\n\
-    \n\n\x03\x04\x07\x01\x12\x03-\x08\x11\n\x0b\n\x04\x04\x07\x02\0\x12\x03.\
-    \x02\x1b\n\x0c\n\x05\x04\x07\x02\0\x04\x12\x03.\x02\n\n\x0c\n\x05\x04\
-    \x07\x02\0\x05\x12\x03.\x0b\x11\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03.\
-    \x12\x16\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03.\x19\x1a\n\x1c\n\x02\x04\
-    \x08\x12\x042\07\x01\x1a\x10\x20Origin\x20Channel\n\n\n\n\x03\x04\x08\
-    \x01\x12\x032\x08\x15\n\x0b\n\x04\x04\x08\x02\0\x12\x033\x02\x19\n\x0c\n\
-    \x05\x04\x08\x02\0\x04\x12\x033\x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\
-    \x033\x0b\x11\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x033\x12\x14\n\x0c\n\x05\
-    \x04\x08\x02\0\x03\x12\x033\x17\x18\n\x0b\n\x04\x04\x08\x02\x01\x12\x034\
-    \x02\x20\n\x0c\n\x05\x04\x08\x02\x01\x04\x12\x034\x02\n\n\x0c\n\x05\x04\
-    \x08\x02\x01\x05\x12\x034\x0b\x11\n\x0c\n\x05\x04\x08\x02\x01\x01\x12\
-    \x034\x12\x1b\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x034\x1e\x1f\n\x0b\n\
-    \x04\x04\x08\x02\x02\x12\x035\x02\x1b\n\x0c\n\x05\x04\x08\x02\x02\x04\
-    \x12\x035\x02\n\n\x0c\n\x05\x04\x08\x02\x02\x05\x12\x035\x0b\x11\n\x0c\n\
-    \x05\x04\x08\x02\x02\x01\x12\x035\x12\x16\n\x0c\n\x05\x04\x08\x02\x02\
-    \x03\x12\x035\x19\x1a\n\x0b\n\x04\x04\x08\x02\x03\x12\x036\x02\x1f\n\x0c\
-    \n\x05\x04\x08\x02\x03\x04\x12\x036\x02\n\n\x0c\n\x05\x04\x08\x02\x03\
-    \x05\x12\x036\x0b\x11\n\x0c\n\x05\x04\x08\x02\x03\x01\x12\x036\x12\x1a\n\
-    \x0c\n\x05\x04\x08\x02\x03\x03\x12\x036\x1d\x1e\n\n\n\x02\x04\t\x12\x049\
-    \0<\x01\n\n\n\x03\x04\t\x01\x12\x039\x08\x1a\n\x0b\n\x04\x04\t\x02\0\x12\
+    \x0b2\x1d.originsrv.OriginPackageIdentR\x05ident\x12\x16\n\x06target\x18\
+    \x03\x20\x01(\tR\x06target\"\x80\x01\n\x1dOriginChannelPackageLatestGet\
+    \x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x123\n\x05ident\x18\x02\
+    \x20\x01(\x0b2\x1d.originsrv.OriginPackageIdentR\x05ident\x12\x16\n\x06t\
+    arget\x18\x03\x20\x01(\tR\x06target\"\x94\x01\n\x1fOriginChannelPackageL\
+    istRequest\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x123\n\x05ident\
+    \x18\x02\x20\x01(\x0b2\x1d.originsrv.OriginPackageIdentR\x05ident\x12\
+    \x14\n\x05start\x18\x03\x20\x01(\x04R\x05start\x12\x12\n\x04stop\x18\x04\
+    \x20\x01(\x04R\x04stop\"B\n\x13OriginChannelDelete\x12\x0e\n\x02id\x18\
+    \x01\x20\x01(\x04R\x02id\x12\x1b\n\torigin_id\x18\x02\x20\x01(\x04R\x08o\
+    riginId\"\xbd\x01\n\x10OriginInvitation\x12\x0e\n\x02id\x18\x01\x20\x01(\
+    \x04R\x02id\x12\x1d\n\naccount_id\x18\x02\x20\x01(\x04R\taccountId\x12!\
+    \n\x0caccount_name\x18\x03\x20\x01(\tR\x0baccountName\x12\x1b\n\torigin_\
+    id\x18\x04\x20\x01(\x04R\x08originId\x12\x1f\n\x0borigin_name\x18\x05\
+    \x20\x01(\tR\noriginName\x12\x19\n\x08owner_id\x18\x06\x20\x01(\x04R\x07\
+    ownerId\"\x94\x01\n\x1dOriginInvitationAcceptRequest\x12\x1d\n\naccount_\
+    id\x18\x01\x20\x01(\x04R\taccountId\x12\x1b\n\tinvite_id\x18\x02\x20\x01\
+    (\x04R\x08inviteId\x12\x1f\n\x0borigin_name\x18\x03\x20\x01(\tR\noriginN\
+    ame\x12\x16\n\x06ignore\x18\x04\x20\x01(\x08R\x06ignore\"\xb3\x01\n\x16O\
+    riginInvitationCreate\x12\x1d\n\naccount_id\x18\x01\x20\x01(\x04R\taccou\
+    ntId\x12!\n\x0caccount_name\x18\x02\x20\x01(\tR\x0baccountName\x12\x1b\n\
+    \torigin_id\x18\x03\x20\x01(\x04R\x08originId\x12\x1f\n\x0borigin_name\
+    \x18\x04\x20\x01(\tR\noriginName\x12\x19\n\x08owner_id\x18\x05\x20\x01(\
+    \x04R\x07ownerId\":\n\x1bOriginInvitationListRequest\x12\x1b\n\torigin_i\
+    d\x18\x01\x20\x01(\x04R\x08originId\"z\n\x1cOriginInvitationListResponse\
+    \x12\x1b\n\torigin_id\x18\x01\x20\x01(\x04R\x08originId\x12=\n\x0binvita\
+    tions\x18\x02\x20\x03(\x0b2\x1b.originsrv.OriginInvitationR\x0binvitatio\
+    ns\"`\n\x0eOriginKeyIdent\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06ori\
+    gin\x12\x1a\n\x08revision\x18\x02\x20\x01(\tR\x08revision\x12\x1a\n\x08l\
+    ocation\x18\x03\x20\x01(\tR\x08location\"6\n\x17OriginMemberListRequest\
+    \x12\x1b\n\torigin_id\x18\x01\x20\x01(\x04R\x08originId\"Q\n\x18OriginMe\
+    mberListResponse\x12\x1b\n\torigin_id\x18\x01\x20\x01(\x04R\x08originId\
+    \x12\x18\n\x07members\x18\x02\x20\x03(\tR\x07members\"J\n\x12OriginMembe\
+    rRemove\x12\x1b\n\torigin_id\x18\x01\x20\x01(\x04R\x08originId\x12\x17\n\
+    \x07user_id\x18\x02\x20\x01(\x04R\x06userId\"\x93\x04\n\rOriginPackage\
+    \x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\x19\n\x08owner_id\x18\
+    \x02\x20\x01(\x04R\x07ownerId\x12\x1b\n\torigin_id\x18\x03\x20\x01(\x04R\
+    \x08originId\x123\n\x05ident\x18\x04\x20\x01(\x0b2\x1d.originsrv.OriginP\
+    ackageIdentR\x05ident\x12\x1a\n\x08checksum\x18\x05\x20\x01(\tR\x08check\
+    sum\x12\x1a\n\x08manifest\x18\x06\x20\x01(\tR\x08manifest\x121\n\x04deps\
+    \x18\x07\x20\x03(\x0b2\x1d.originsrv.OriginPackageIdentR\x04deps\x123\n\
+    \x05tdeps\x18\x08\x20\x03(\x0b2\x1d.originsrv.OriginPackageIdentR\x05tde\
+    ps\x12\x1c\n\x07exposes\x18\t\x20\x03(\rR\x07exposesB\x02\x10\x01\x12\
+    \x16\n\x06config\x18\n\x20\x01(\tR\x06config\x12\x16\n\x06target\x18\x0b\
+    \x20\x01(\tR\x06target\x12\x16\n\x06yanked\x18\x0c\x20\x01(\x08R\x06yank\
+    ed\x12\x20\n\x0bdescription\x18\r\x20\x01(\tR\x0bdescription\x12\x1a\n\
+    \x08licenses\x18\x0e\x20\x03(\tR\x08licenses\x12!\n\x0cupstream_url\x18\
+    \x0f\x20\x01(\tR\x0bupstreamUrl\x12\x1e\n\nmaintainer\x18\x10\x20\x01(\t\
+    R\nmaintainer\"t\n\x12OriginPackageIdent\x12\x16\n\x06origin\x18\x01\x20\
+    \x01(\tR\x06origin\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x18\
+    \n\x07version\x18\x03\x20\x01(\tR\x07version\x12\x18\n\x07release\x18\
+    \x04\x20\x01(\tR\x07release\"\x99\x01\n\x14OriginPackageVersion\x12\x16\
+    \n\x06origin\x18\x01\x20\x01(\tR\x06origin\x12\x12\n\x04name\x18\x02\x20\
+    \x01(\tR\x04name\x12\x18\n\x07version\x18\x03\x20\x01(\tR\x07version\x12\
+    #\n\rrelease_count\x18\x04\x20\x01(\x04R\x0creleaseCount\x12\x16\n\x06la\
+    test\x18\x05\x20\x01(\tR\x06latest\"\xf1\x03\n\x13OriginPackageCreate\
+    \x12\x19\n\x08owner_id\x18\x01\x20\x01(\x04R\x07ownerId\x12\x1b\n\torigi\
+    n_id\x18\x02\x20\x01(\x04R\x08originId\x123\n\x05ident\x18\x03\x20\x01(\
+    \x0b2\x1d.originsrv.OriginPackageIdentR\x05ident\x12\x1a\n\x08checksum\
+    \x18\x04\x20\x01(\tR\x08checksum\x12\x1a\n\x08manifest\x18\x05\x20\x01(\
+    \tR\x08manifest\x121\n\x04deps\x18\x06\x20\x03(\x0b2\x1d.originsrv.Origi\
+    nPackageIdentR\x04deps\x123\n\x05tdeps\x18\x07\x20\x03(\x0b2\x1d.origins\
+    rv.OriginPackageIdentR\x05tdeps\x12\x1c\n\x07exposes\x18\x08\x20\x03(\rR\
+    \x07exposesB\x02\x10\x01\x12\x16\n\x06config\x18\t\x20\x01(\tR\x06config\
+    \x12\x16\n\x06target\x18\n\x20\x01(\tR\x06target\x12\x20\n\x0bdescriptio\
+    n\x18\x0b\x20\x01(\tR\x0bdescription\x12\x1a\n\x08licenses\x18\x0c\x20\
+    \x03(\tR\x08licenses\x12!\n\x0cupstream_url\x18\r\x20\x01(\tR\x0bupstrea\
+    mUrl\x12\x1e\n\nmaintainer\x18\x0e\x20\x01(\tR\nmaintainer\"_\n\x10Origi\
+    nPackageGet\x123\n\x05ident\x18\x01\x20\x01(\x0b2\x1d.originsrv.OriginPa\
+    ckageIdentR\x05ident\x12\x16\n\x06target\x18\x02\x20\x01(\tR\x06target\"\
+    e\n\x16OriginPackageLatestGet\x123\n\x05ident\x18\x01\x20\x01(\x0b2\x1d.\
+    originsrv.OriginPackageIdentR\x05ident\x12\x16\n\x06target\x18\x02\x20\
+    \x01(\tR\x06target\"\x95\x01\n\x18OriginPackageListRequest\x123\n\x05ide\
+    nt\x18\x01\x20\x01(\x0b2\x1d.originsrv.OriginPackageIdentR\x05ident\x12\
+    \x14\n\x05start\x18\x02\x20\x01(\x04R\x05start\x12\x12\n\x04stop\x18\x03\
+    \x20\x01(\x04R\x04stop\x12\x1a\n\x08distinct\x18\x04\x20\x01(\x08R\x08di\
+    stinct\"\x92\x01\n\x19OriginPackageListResponse\x12\x14\n\x05start\x18\
+    \x01\x20\x01(\x04R\x05start\x12\x12\n\x04stop\x18\x02\x20\x01(\x04R\x04s\
+    top\x12\x14\n\x05count\x18\x03\x20\x01(\x04R\x05count\x125\n\x06idents\
+    \x18\x04\x20\x03(\x0b2\x1d.originsrv.OriginPackageIdentR\x06idents\"\x89\
+    \x01\n\x14OriginPackagePromote\x12\x1d\n\nchannel_id\x18\x01\x20\x01(\
+    \x04R\tchannelId\x12\x1d\n\npackage_id\x18\x02\x20\x01(\x04R\tpackageId\
+    \x123\n\x05ident\x18\x03\x20\x01(\x0b2\x1d.originsrv.OriginPackageIdentR\
+    \x05ident\"\x88\x01\n\x13OriginPackageDemote\x12\x1d\n\nchannel_id\x18\
+    \x01\x20\x01(\x04R\tchannelId\x12\x1d\n\npackage_id\x18\x02\x20\x01(\x04\
+    R\tpackageId\x123\n\x05ident\x18\x03\x20\x01(\x0b2\x1d.originsrv.OriginP\
+    ackageIdentR\x05ident\"\x7f\n\x11OriginPackageYank\x12\x1d\n\npackage_id\
+    \x18\x01\x20\x01(\x04R\tpackageId\x123\n\x05ident\x18\x02\x20\x01(\x0b2\
+    \x1d.originsrv.OriginPackageIdentR\x05ident\x12\x16\n\x06yanked\x18\x03\
+    \x20\x01(\x08R\x06yanked\"i\n\x13OriginPackageDelete\x12\x1d\n\npackage_\
+    id\x18\x01\x20\x01(\x04R\tpackageId\x123\n\x05ident\x18\x02\x20\x01(\x0b\
+    2\x1d.originsrv.OriginPackageIdentR\x05ident\"Q\n\x1aOriginPackageDepend\
+    entsGet\x123\n\x05ident\x18\x01\x20\x01(\x0b2\x1d.originsrv.OriginPackag\
+    eIdentR\x05ident\"\xaa\x01\n\x1aOriginPackageSearchRequest\x12\x16\n\x06\
+    origin\x18\x01\x20\x01(\tR\x06origin\x12\x14\n\x05query\x18\x02\x20\x01(\
+    \tR\x05query\x12\x14\n\x05start\x18\x03\x20\x01(\x04R\x05start\x12\x12\n\
+    \x04stop\x18\x04\x20\x01(\x04R\x04stop\x12\x1a\n\x08distinct\x18\x05\x20\
+    \x01(\x08R\x08distinct\x12\x18\n\x07license\x18\x06\x20\x01(\tR\x07licen\
+    se\"b\n\x1eOriginPackageUniqueListRequest\x12\x16\n\x06origin\x18\x01\
+    \x20\x01(\tR\x06origin\x12\x14\n\x05start\x18\x02\x20\x01(\x04R\x05start\
+    \x12\x12\n\x04stop\x18\x03\x20\x01(\x04R\x04stop\"\x98\x01\n\x1fOriginPa\
+    ckageUniqueListResponse\x12\x14\n\x05start\x18\x01\x20\x01(\x04R\x05star\
+    t\x12\x12\n\x04stop\x18\x02\x20\x01(\x04R\x04stop\x12\x14\n\x05count\x18\
+    \x03\x20\x01(\x04R\x05count\x125\n\x06idents\x18\x04\x20\x03(\x0b2\x1d.o\
+    riginsrv.OriginPackageIdentR\x06idents\"M\n\x1fOriginPackageVersionListR\
+    equest\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06origin\x12\x12\n\x04na\
+    me\x18\x02\x20\x01(\tR\x04name\"_\n\x20OriginPackageVersionListResponse\
+    \x12;\n\x08versions\x18\x01\x20\x03(\x0b2\x1f.originsrv.OriginPackageVer\
+    sionR\x08versions\"\xce\x02\n\rOriginProject\x12\x0e\n\x02id\x18\x01\x20\
+    \x01(\x04R\x02id\x12\x1b\n\torigin_id\x18\x02\x20\x01(\x04R\x08originId\
+    \x12\x1f\n\x0borigin_name\x18\x03\x20\x01(\tR\noriginName\x12!\n\x0cpack\
+    age_name\x18\x04\x20\x01(\tR\x0bpackageName\x12\x12\n\x04name\x18\x05\
+    \x20\x01(\tR\x04name\x12\x1b\n\tplan_path\x18\x06\x20\x01(\tR\x08planPat\
+    h\x12\x19\n\x08owner_id\x18\x07\x20\x01(\x04R\x07ownerId\x12\x19\n\x08vc\
+    s_type\x18\x08\x20\x01(\tR\x07vcsType\x12\x19\n\x08vcs_data\x18\t\x20\
+    \x01(\tR\x07vcsData\x12#\n\rbuild_timeout\x18\n\x20\x01(\x04R\x0cbuildTi\
+    meout\x12%\n\x0ewebhook_secret\x18\x0b\x20\x01(\tR\rwebhookSecret\"I\n\
+    \x13OriginProjectCreate\x122\n\x07project\x18\x01\x20\x01(\x0b2\x18.orig\
+    insrv.OriginProjectR\x07project\"L\n\x13OriginProjectDelete\x12\x12\n\
+    \x04name\x18\x01\x20\x01(\tR\x04name\x12!\n\x0crequestor_id\x18\x02\x20\
+    \x01(\x04R\x0brequestorId\"&\n\x10OriginProjectGet\x12\x12\n\x04name\x18\
+    \x01\x20\x01(\tR\x04name\"l\n\x13OriginProjectUpdate\x12!\n\x0crequestor\
+    _id\x18\x01\x20\x01(\x04R\x0brequestorId\x122\n\x07project\x18\x02\x20\
+    \x01(\x0b2\x18.originsrv.OriginProjectR\x07project\"M\n\x18OriginProject\
+    ListRequest\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06origin\x12\x19\n\
+    \x08vcs_data\x18\x02\x20\x03(\tR\x07vcsData\"n\n\x19OriginProjectListRes\
+    ponse\x12\x1b\n\torigin_id\x18\x01\x20\x01(\x04R\x08originId\x124\n\x08p\
+    rojects\x18\x02\x20\x03(\x0b2\x18.originsrv.OriginProjectR\x08projects\"\
+    \x9d\x01\n\x0fOriginPublicKey\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02i\
+    d\x12\x1b\n\torigin_id\x18\x02\x20\x01(\x04R\x08originId\x12\x12\n\x04na\
+    me\x18\x03\x20\x01(\tR\x04name\x12\x1a\n\x08revision\x18\x04\x20\x01(\tR\
+    \x08revision\x12\x12\n\x04body\x18\x05\x20\x01(\x0cR\x04body\x12\x19\n\
+    \x08owner_id\x18\x06\x20\x01(\x04R\x07ownerId\"\x93\x01\n\x15OriginPubli\
+    cKeyCreate\x12\x1b\n\torigin_id\x18\x01\x20\x01(\x04R\x08originId\x12\
+    \x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x1a\n\x08revision\x18\x03\
+    \x20\x01(\tR\x08revision\x12\x12\n\x04body\x18\x04\x20\x01(\x0cR\x04body\
+    \x12\x19\n\x08owner_id\x18\x05\x20\x01(\x04R\x07ownerId\"c\n\x12OriginPu\
+    blicKeyGet\x12\x19\n\x08owner_id\x18\x01\x20\x01(\x04R\x07ownerId\x12\
+    \x16\n\x06origin\x18\x02\x20\x01(\tR\x06origin\x12\x1a\n\x08revision\x18\
+    \x03\x20\x01(\tR\x08revision\"M\n\x18OriginPublicKeyLatestGet\x12\x19\n\
+    \x08owner_id\x18\x01\x20\x01(\x04R\x07ownerId\x12\x16\n\x06origin\x18\
+    \x02\x20\x01(\tR\x06origin\"T\n\x1aOriginPublicKeyListRequest\x12\x19\n\
+    \x08owner_id\x18\x01\x20\x01(\x04R\x07ownerId\x12\x1b\n\torigin_id\x18\
+    \x02\x20\x01(\x04R\x08originId\"j\n\x1bOriginPublicKeyListResponse\x12\
+    \x1b\n\torigin_id\x18\x01\x20\x01(\x04R\x08originId\x12.\n\x04keys\x18\
+    \x02\x20\x03(\x0b2\x1a.originsrv.OriginPublicKeyR\x04keys\"\x9d\x01\n\
+    \x0fOriginSecretKey\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\x1b\
+    \n\torigin_id\x18\x02\x20\x01(\x04R\x08originId\x12\x12\n\x04name\x18\
+    \x03\x20\x01(\tR\x04name\x12\x1a\n\x08revision\x18\x04\x20\x01(\tR\x08re\
+    vision\x12\x12\n\x04body\x18\x05\x20\x01(\x0cR\x04body\x12\x19\n\x08owne\
+    r_id\x18\x06\x20\x01(\x04R\x07ownerId\"\x93\x01\n\x15OriginSecretKeyCrea\
+    te\x12\x1b\n\torigin_id\x18\x01\x20\x01(\x04R\x08originId\x12\x12\n\x04n\
+    ame\x18\x02\x20\x01(\tR\x04name\x12\x1a\n\x08revision\x18\x03\x20\x01(\t\
+    R\x08revision\x12\x12\n\x04body\x18\x04\x20\x01(\x0cR\x04body\x12\x19\n\
+    \x08owner_id\x18\x05\x20\x01(\x04R\x07ownerId\"G\n\x12OriginSecretKeyGet\
+    \x12\x19\n\x08owner_id\x18\x01\x20\x01(\x04R\x07ownerId\x12\x16\n\x06ori\
+    gin\x18\x02\x20\x01(\tR\x06originJ\x84\x82\x01\n\x07\x12\x05\0\0\x90\x03\
+    \x01\n\x08\n\x01\x02\x12\x03\0\0\x12\n\x15\n\x02\x04\0\x12\x04\x03\0\x05\
+    \x01\x1a\t\x20Account\n\n\n\n\x03\x04\0\x01\x12\x03\x03\x08$\n\x0b\n\x04\
+    \x04\0\x02\0\x12\x03\x04\x02!\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\x04\
+    \x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x0b\x11\n\x0c\n\x05\x04\0\
+    \x02\0\x01\x12\x03\x04\x12\x1c\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x04\
+    \x1f\x20\n\n\n\x02\x04\x01\x12\x04\x07\0\n\x01\n\n\n\x03\x04\x01\x01\x12\
+    \x03\x07\x08%\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x08\x02!\n\x0c\n\x05\x04\
+    \x01\x02\0\x04\x12\x03\x08\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\
+    \x08\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x08\x12\x1c\n\x0c\n\
+    \x05\x04\x01\x02\0\x03\x12\x03\x08\x1f\x20\n\x0b\n\x04\x04\x01\x02\x01\
+    \x12\x03\t\x02,\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03\t\x02\n\n\x0c\n\
+    \x05\x04\x01\x02\x01\x06\x12\x03\t\x0b\x1b\n\x0c\n\x05\x04\x01\x02\x01\
+    \x01\x12\x03\t\x1c'\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\t*+\n\n\n\
+    \x02\x04\x02\x12\x04\x0c\0\x15\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0c\x08\
+    \x20\n\x0c\n\x04\x04\x02\x08\0\x12\x04\r\x02\x10\x03\n\x0c\n\x05\x04\x02\
+    \x08\0\x01\x12\x03\r\x08\x14\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x0e\x04\
+    \x1a\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x02\
+    \x02\0\x01\x12\x03\x0e\x0b\x15\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x0e\
+    \x18\x19\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0f\x04\x1c\n\x0c\n\x05\x04\
+    \x02\x02\x01\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\
+    \x03\x0f\x0b\x17\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0f\x1a\x1b\n\
+    \x0c\n\x04\x04\x02\x08\x01\x12\x04\x11\x02\x14\x03\n\x0c\n\x05\x04\x02\
+    \x08\x01\x01\x12\x03\x11\x08\x13\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x12\
+    \x04\x19\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x12\x04\n\n\x0c\n\x05\
+    \x04\x02\x02\x02\x01\x12\x03\x12\x0b\x14\n\x0c\n\x05\x04\x02\x02\x02\x03\
+    \x12\x03\x12\x17\x18\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x13\x04\x1b\n\
+    \x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x02\x02\
+    \x03\x01\x12\x03\x13\x0b\x16\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x13\
+    \x19\x1a\n\n\n\x02\x04\x03\x12\x04\x17\0\x19\x01\n\n\n\x03\x04\x03\x01\
+    \x12\x03\x17\x08!\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x18\x02\x1f\n\x0c\n\
+    \x05\x04\x03\x02\0\x04\x12\x03\x18\x02\n\n\x0c\n\x05\x04\x03\x02\0\x05\
+    \x12\x03\x18\x0b\x0f\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x18\x10\x1a\n\
+    \x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x18\x1d\x1e\n\x14\n\x02\x04\x04\x12\
+    \x04\x1c\0!\x01\x1a\x08\x20Origin\n\n\n\n\x03\x04\x04\x01\x12\x03\x1c\
+    \x08\x0e\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1d\x02\x19\n\x0c\n\x05\x04\
+    \x04\x02\0\x04\x12\x03\x1d\x02\n\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\
+    \x1d\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x1d\x12\x14\n\x0c\n\
+    \x05\x04\x04\x02\0\x03\x12\x03\x1d\x17\x18\n\x0b\n\x04\x04\x04\x02\x01\
+    \x12\x03\x1e\x02\x1b\n\x0c\n\x05\x04\x04\x02\x01\x04\x12\x03\x1e\x02\n\n\
+    \x0c\n\x05\x04\x04\x02\x01\x05\x12\x03\x1e\x0b\x11\n\x0c\n\x05\x04\x04\
+    \x02\x01\x01\x12\x03\x1e\x12\x16\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\
+    \x1e\x19\x1a\n\x0b\n\x04\x04\x04\x02\x02\x12\x03\x1f\x02\x1f\n\x0c\n\x05\
+    \x04\x04\x02\x02\x04\x12\x03\x1f\x02\n\n\x0c\n\x05\x04\x04\x02\x02\x05\
+    \x12\x03\x1f\x0b\x11\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03\x1f\x12\x1a\
+    \n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03\x1f\x1d\x1e\n\x0b\n\x04\x04\x04\
+    \x02\x03\x12\x03\x20\x02'\n\x0c\n\x05\x04\x04\x02\x03\x04\x12\x03\x20\
+    \x02\n\n\x0c\n\x05\x04\x04\x02\x03\x05\x12\x03\x20\x0b\x11\n\x0c\n\x05\
+    \x04\x04\x02\x03\x01\x12\x03\x20\x12\"\n\x0c\n\x05\x04\x04\x02\x03\x03\
+    \x12\x03\x20%&\n\n\n\x02\x04\x05\x12\x04#\0'\x01\n\n\n\x03\x04\x05\x01\
+    \x12\x03#\x08\x14\n\x0b\n\x04\x04\x05\x02\0\x12\x03$\x02\x1b\n\x0c\n\x05\
+    \x04\x05\x02\0\x04\x12\x03$\x02\n\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03$\
+    \x0b\x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03$\x12\x16\n\x0c\n\x05\x04\
+    \x05\x02\0\x03\x12\x03$\x19\x1a\n\x0b\n\x04\x04\x05\x02\x01\x12\x03%\x02\
+    \x1f\n\x0c\n\x05\x04\x05\x02\x01\x04\x12\x03%\x02\n\n\x0c\n\x05\x04\x05\
+    \x02\x01\x05\x12\x03%\x0b\x11\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03%\
+    \x12\x1a\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03%\x1d\x1e\n\x0b\n\x04\
+    \x04\x05\x02\x02\x12\x03&\x02!\n\x0c\n\x05\x04\x05\x02\x02\x04\x12\x03&\
+    \x02\n\n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x03&\x0b\x11\n\x0c\n\x05\x04\
+    \x05\x02\x02\x01\x12\x03&\x12\x1c\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\
+    \x03&\x1f\x20\n\n\n\x02\x04\x06\x12\x04)\0+\x01\n\n\n\x03\x04\x06\x01\
+    \x12\x03)\x08\x14\n\x0b\n\x04\x04\x06\x02\0\x12\x03*\x02\x1b\n\x0c\n\x05\
+    \x04\x06\x02\0\x04\x12\x03*\x02\n\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03*\
+    \x0b\x11\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03*\x12\x16\n\x0c\n\x05\x04\
+    \x06\x02\0\x03\x12\x03*\x19\x1a\n\n\n\x02\x04\x07\x12\x04-\0/\x01\n\n\n\
+    \x03\x04\x07\x01\x12\x03-\x08\x11\n\x0b\n\x04\x04\x07\x02\0\x12\x03.\x02\
+    \x1b\n\x0c\n\x05\x04\x07\x02\0\x04\x12\x03.\x02\n\n\x0c\n\x05\x04\x07\
+    \x02\0\x05\x12\x03.\x0b\x11\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03.\x12\
+    \x16\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03.\x19\x1a\n\x1c\n\x02\x04\x08\
+    \x12\x042\07\x01\x1a\x10\x20Origin\x20Channel\n\n\n\n\x03\x04\x08\x01\
+    \x12\x032\x08\x15\n\x0b\n\x04\x04\x08\x02\0\x12\x033\x02\x19\n\x0c\n\x05\
+    \x04\x08\x02\0\x04\x12\x033\x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x033\
+    \x0b\x11\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x033\x12\x14\n\x0c\n\x05\x04\
+    \x08\x02\0\x03\x12\x033\x17\x18\n\x0b\n\x04\x04\x08\x02\x01\x12\x034\x02\
+    \x20\n\x0c\n\x05\x04\x08\x02\x01\x04\x12\x034\x02\n\n\x0c\n\x05\x04\x08\
+    \x02\x01\x05\x12\x034\x0b\x11\n\x0c\n\x05\x04\x08\x02\x01\x01\x12\x034\
+    \x12\x1b\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x034\x1e\x1f\n\x0b\n\x04\
+    \x04\x08\x02\x02\x12\x035\x02\x1b\n\x0c\n\x05\x04\x08\x02\x02\x04\x12\
+    \x035\x02\n\n\x0c\n\x05\x04\x08\x02\x02\x05\x12\x035\x0b\x11\n\x0c\n\x05\
+    \x04\x08\x02\x02\x01\x12\x035\x12\x16\n\x0c\n\x05\x04\x08\x02\x02\x03\
+    \x12\x035\x19\x1a\n\x0b\n\x04\x04\x08\x02\x03\x12\x036\x02\x1f\n\x0c\n\
+    \x05\x04\x08\x02\x03\x04\x12\x036\x02\n\n\x0c\n\x05\x04\x08\x02\x03\x05\
+    \x12\x036\x0b\x11\n\x0c\n\x05\x04\x08\x02\x03\x01\x12\x036\x12\x1a\n\x0c\
+    \n\x05\x04\x08\x02\x03\x03\x12\x036\x1d\x1e\n\n\n\x02\x04\t\x12\x049\0<\
+    \x01\n\n\n\x03\x04\t\x01\x12\x039\x08\x1a\n\x0b\n\x04\x04\t\x02\0\x12\
     \x03:\x02\x1d\n\x0c\n\x05\x04\t\x02\0\x04\x12\x03:\x02\n\n\x0c\n\x05\x04\
     \t\x02\0\x05\x12\x03:\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03:\x12\
     \x18\n\x0c\n\x05\x04\t\x02\0\x03\x12\x03:\x1b\x1c\n\x0b\n\x04\x04\t\x02\
@@ -17109,588 +19102,673 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x12\x03P\x02&\n\x0c\n\x05\x04\r\x02\x01\x04\x12\x03P\x02\n\n\x0c\n\x05\
     \x04\r\x02\x01\x06\x12\x03P\x0b\x18\n\x0c\n\x05\x04\r\x02\x01\x01\x12\
     \x03P\x19!\n\x0c\n\x05\x04\r\x02\x01\x03\x12\x03P$%\n\n\n\x02\x04\x0e\
-    \x12\x04S\0V\x01\n\n\n\x03\x04\x0e\x01\x12\x03S\x08\x1f\n\x0b\n\x04\x04\
+    \x12\x04S\0W\x01\n\n\n\x03\x04\x0e\x01\x12\x03S\x08\x1f\n\x0b\n\x04\x04\
     \x0e\x02\0\x12\x03T\x02\x1b\n\x0c\n\x05\x04\x0e\x02\0\x04\x12\x03T\x02\n\
     \n\x0c\n\x05\x04\x0e\x02\0\x05\x12\x03T\x0b\x11\n\x0c\n\x05\x04\x0e\x02\
     \0\x01\x12\x03T\x12\x16\n\x0c\n\x05\x04\x0e\x02\0\x03\x12\x03T\x19\x1a\n\
     \x0b\n\x04\x04\x0e\x02\x01\x12\x03U\x02(\n\x0c\n\x05\x04\x0e\x02\x01\x04\
     \x12\x03U\x02\n\n\x0c\n\x05\x04\x0e\x02\x01\x06\x12\x03U\x0b\x1d\n\x0c\n\
     \x05\x04\x0e\x02\x01\x01\x12\x03U\x1e#\n\x0c\n\x05\x04\x0e\x02\x01\x03\
-    \x12\x03U&'\n\n\n\x02\x04\x0f\x12\x04X\0\\\x01\n\n\n\x03\x04\x0f\x01\x12\
-    \x03X\x08%\n\x0b\n\x04\x04\x0f\x02\0\x12\x03Y\x02\x1b\n\x0c\n\x05\x04\
-    \x0f\x02\0\x04\x12\x03Y\x02\n\n\x0c\n\x05\x04\x0f\x02\0\x05\x12\x03Y\x0b\
-    \x11\n\x0c\n\x05\x04\x0f\x02\0\x01\x12\x03Y\x12\x16\n\x0c\n\x05\x04\x0f\
-    \x02\0\x03\x12\x03Y\x19\x1a\n\x0b\n\x04\x04\x0f\x02\x01\x12\x03Z\x02(\n\
-    \x0c\n\x05\x04\x0f\x02\x01\x04\x12\x03Z\x02\n\n\x0c\n\x05\x04\x0f\x02\
-    \x01\x06\x12\x03Z\x0b\x1d\n\x0c\n\x05\x04\x0f\x02\x01\x01\x12\x03Z\x1e#\
-    \n\x0c\n\x05\x04\x0f\x02\x01\x03\x12\x03Z&'\n\x0b\n\x04\x04\x0f\x02\x02\
-    \x12\x03[\x02\x1d\n\x0c\n\x05\x04\x0f\x02\x02\x04\x12\x03[\x02\n\n\x0c\n\
-    \x05\x04\x0f\x02\x02\x05\x12\x03[\x0b\x11\n\x0c\n\x05\x04\x0f\x02\x02\
-    \x01\x12\x03[\x12\x18\n\x0c\n\x05\x04\x0f\x02\x02\x03\x12\x03[\x1b\x1c\n\
-    \n\n\x02\x04\x10\x12\x04^\0c\x01\n\n\n\x03\x04\x10\x01\x12\x03^\x08'\n\
-    \x0b\n\x04\x04\x10\x02\0\x12\x03_\x02\x1b\n\x0c\n\x05\x04\x10\x02\0\x04\
-    \x12\x03_\x02\n\n\x0c\n\x05\x04\x10\x02\0\x05\x12\x03_\x0b\x11\n\x0c\n\
-    \x05\x04\x10\x02\0\x01\x12\x03_\x12\x16\n\x0c\n\x05\x04\x10\x02\0\x03\
-    \x12\x03_\x19\x1a\n\x0b\n\x04\x04\x10\x02\x01\x12\x03`\x02(\n\x0c\n\x05\
-    \x04\x10\x02\x01\x04\x12\x03`\x02\n\n\x0c\n\x05\x04\x10\x02\x01\x06\x12\
-    \x03`\x0b\x1d\n\x0c\n\x05\x04\x10\x02\x01\x01\x12\x03`\x1e#\n\x0c\n\x05\
-    \x04\x10\x02\x01\x03\x12\x03`&'\n\x0b\n\x04\x04\x10\x02\x02\x12\x03a\x02\
-    \x1c\n\x0c\n\x05\x04\x10\x02\x02\x04\x12\x03a\x02\n\n\x0c\n\x05\x04\x10\
-    \x02\x02\x05\x12\x03a\x0b\x11\n\x0c\n\x05\x04\x10\x02\x02\x01\x12\x03a\
-    \x12\x17\n\x0c\n\x05\x04\x10\x02\x02\x03\x12\x03a\x1a\x1b\n\x0b\n\x04\
-    \x04\x10\x02\x03\x12\x03b\x02\x1b\n\x0c\n\x05\x04\x10\x02\x03\x04\x12\
-    \x03b\x02\n\n\x0c\n\x05\x04\x10\x02\x03\x05\x12\x03b\x0b\x11\n\x0c\n\x05\
-    \x04\x10\x02\x03\x01\x12\x03b\x12\x16\n\x0c\n\x05\x04\x10\x02\x03\x03\
-    \x12\x03b\x19\x1a\n\n\n\x02\x04\x11\x12\x04e\0h\x01\n\n\n\x03\x04\x11\
-    \x01\x12\x03e\x08\x1b\n\x0b\n\x04\x04\x11\x02\0\x12\x03f\x02\x19\n\x0c\n\
-    \x05\x04\x11\x02\0\x04\x12\x03f\x02\n\n\x0c\n\x05\x04\x11\x02\0\x05\x12\
-    \x03f\x0b\x11\n\x0c\n\x05\x04\x11\x02\0\x01\x12\x03f\x12\x14\n\x0c\n\x05\
-    \x04\x11\x02\0\x03\x12\x03f\x17\x18\n\x0b\n\x04\x04\x11\x02\x01\x12\x03g\
-    \x02\x20\n\x0c\n\x05\x04\x11\x02\x01\x04\x12\x03g\x02\n\n\x0c\n\x05\x04\
-    \x11\x02\x01\x05\x12\x03g\x0b\x11\n\x0c\n\x05\x04\x11\x02\x01\x01\x12\
-    \x03g\x12\x1b\n\x0c\n\x05\x04\x11\x02\x01\x03\x12\x03g\x1e\x1f\n\x1f\n\
-    \x02\x04\x12\x12\x04k\0r\x01\x1a\x13\x20Origin\x20Invitation\n\n\n\n\x03\
-    \x04\x12\x01\x12\x03k\x08\x18\n\x0b\n\x04\x04\x12\x02\0\x12\x03l\x02\x19\
-    \n\x0c\n\x05\x04\x12\x02\0\x04\x12\x03l\x02\n\n\x0c\n\x05\x04\x12\x02\0\
-    \x05\x12\x03l\x0b\x11\n\x0c\n\x05\x04\x12\x02\0\x01\x12\x03l\x12\x14\n\
-    \x0c\n\x05\x04\x12\x02\0\x03\x12\x03l\x17\x18\n\x0b\n\x04\x04\x12\x02\
-    \x01\x12\x03m\x02!\n\x0c\n\x05\x04\x12\x02\x01\x04\x12\x03m\x02\n\n\x0c\
-    \n\x05\x04\x12\x02\x01\x05\x12\x03m\x0b\x11\n\x0c\n\x05\x04\x12\x02\x01\
-    \x01\x12\x03m\x12\x1c\n\x0c\n\x05\x04\x12\x02\x01\x03\x12\x03m\x1f\x20\n\
-    \x0b\n\x04\x04\x12\x02\x02\x12\x03n\x02#\n\x0c\n\x05\x04\x12\x02\x02\x04\
-    \x12\x03n\x02\n\n\x0c\n\x05\x04\x12\x02\x02\x05\x12\x03n\x0b\x11\n\x0c\n\
-    \x05\x04\x12\x02\x02\x01\x12\x03n\x12\x1e\n\x0c\n\x05\x04\x12\x02\x02\
-    \x03\x12\x03n!\"\n\x0b\n\x04\x04\x12\x02\x03\x12\x03o\x02\x20\n\x0c\n\
-    \x05\x04\x12\x02\x03\x04\x12\x03o\x02\n\n\x0c\n\x05\x04\x12\x02\x03\x05\
-    \x12\x03o\x0b\x11\n\x0c\n\x05\x04\x12\x02\x03\x01\x12\x03o\x12\x1b\n\x0c\
-    \n\x05\x04\x12\x02\x03\x03\x12\x03o\x1e\x1f\n\x0b\n\x04\x04\x12\x02\x04\
-    \x12\x03p\x02\"\n\x0c\n\x05\x04\x12\x02\x04\x04\x12\x03p\x02\n\n\x0c\n\
-    \x05\x04\x12\x02\x04\x05\x12\x03p\x0b\x11\n\x0c\n\x05\x04\x12\x02\x04\
-    \x01\x12\x03p\x12\x1d\n\x0c\n\x05\x04\x12\x02\x04\x03\x12\x03p\x20!\n\
-    \x0b\n\x04\x04\x12\x02\x05\x12\x03q\x02\x1f\n\x0c\n\x05\x04\x12\x02\x05\
-    \x04\x12\x03q\x02\n\n\x0c\n\x05\x04\x12\x02\x05\x05\x12\x03q\x0b\x11\n\
-    \x0c\n\x05\x04\x12\x02\x05\x01\x12\x03q\x12\x1a\n\x0c\n\x05\x04\x12\x02\
-    \x05\x03\x12\x03q\x1d\x1e\n\n\n\x02\x04\x13\x12\x04t\0y\x01\n\n\n\x03\
-    \x04\x13\x01\x12\x03t\x08%\n\x0b\n\x04\x04\x13\x02\0\x12\x03u\x02!\n\x0c\
-    \n\x05\x04\x13\x02\0\x04\x12\x03u\x02\n\n\x0c\n\x05\x04\x13\x02\0\x05\
-    \x12\x03u\x0b\x11\n\x0c\n\x05\x04\x13\x02\0\x01\x12\x03u\x12\x1c\n\x0c\n\
-    \x05\x04\x13\x02\0\x03\x12\x03u\x1f\x20\n\x0b\n\x04\x04\x13\x02\x01\x12\
-    \x03v\x02\x20\n\x0c\n\x05\x04\x13\x02\x01\x04\x12\x03v\x02\n\n\x0c\n\x05\
-    \x04\x13\x02\x01\x05\x12\x03v\x0b\x11\n\x0c\n\x05\x04\x13\x02\x01\x01\
-    \x12\x03v\x12\x1b\n\x0c\n\x05\x04\x13\x02\x01\x03\x12\x03v\x1e\x1f\n\x0b\
-    \n\x04\x04\x13\x02\x02\x12\x03w\x02\"\n\x0c\n\x05\x04\x13\x02\x02\x04\
-    \x12\x03w\x02\n\n\x0c\n\x05\x04\x13\x02\x02\x05\x12\x03w\x0b\x11\n\x0c\n\
-    \x05\x04\x13\x02\x02\x01\x12\x03w\x12\x1d\n\x0c\n\x05\x04\x13\x02\x02\
-    \x03\x12\x03w\x20!\n\x0b\n\x04\x04\x13\x02\x03\x12\x03x\x02\x1b\n\x0c\n\
-    \x05\x04\x13\x02\x03\x04\x12\x03x\x02\n\n\x0c\n\x05\x04\x13\x02\x03\x05\
-    \x12\x03x\x0b\x0f\n\x0c\n\x05\x04\x13\x02\x03\x01\x12\x03x\x10\x16\n\x0c\
-    \n\x05\x04\x13\x02\x03\x03\x12\x03x\x19\x1a\n\x0b\n\x02\x04\x14\x12\x05{\
-    \0\x81\x01\x01\n\n\n\x03\x04\x14\x01\x12\x03{\x08\x1e\n\x0b\n\x04\x04\
-    \x14\x02\0\x12\x03|\x02!\n\x0c\n\x05\x04\x14\x02\0\x04\x12\x03|\x02\n\n\
-    \x0c\n\x05\x04\x14\x02\0\x05\x12\x03|\x0b\x11\n\x0c\n\x05\x04\x14\x02\0\
-    \x01\x12\x03|\x12\x1c\n\x0c\n\x05\x04\x14\x02\0\x03\x12\x03|\x1f\x20\n\
-    \x0b\n\x04\x04\x14\x02\x01\x12\x03}\x02#\n\x0c\n\x05\x04\x14\x02\x01\x04\
-    \x12\x03}\x02\n\n\x0c\n\x05\x04\x14\x02\x01\x05\x12\x03}\x0b\x11\n\x0c\n\
-    \x05\x04\x14\x02\x01\x01\x12\x03}\x12\x1e\n\x0c\n\x05\x04\x14\x02\x01\
-    \x03\x12\x03}!\"\n\x0b\n\x04\x04\x14\x02\x02\x12\x03~\x02\x20\n\x0c\n\
-    \x05\x04\x14\x02\x02\x04\x12\x03~\x02\n\n\x0c\n\x05\x04\x14\x02\x02\x05\
-    \x12\x03~\x0b\x11\n\x0c\n\x05\x04\x14\x02\x02\x01\x12\x03~\x12\x1b\n\x0c\
-    \n\x05\x04\x14\x02\x02\x03\x12\x03~\x1e\x1f\n\x0b\n\x04\x04\x14\x02\x03\
-    \x12\x03\x7f\x02\"\n\x0c\n\x05\x04\x14\x02\x03\x04\x12\x03\x7f\x02\n\n\
-    \x0c\n\x05\x04\x14\x02\x03\x05\x12\x03\x7f\x0b\x11\n\x0c\n\x05\x04\x14\
-    \x02\x03\x01\x12\x03\x7f\x12\x1d\n\x0c\n\x05\x04\x14\x02\x03\x03\x12\x03\
-    \x7f\x20!\n\x0c\n\x04\x04\x14\x02\x04\x12\x04\x80\x01\x02\x1f\n\r\n\x05\
-    \x04\x14\x02\x04\x04\x12\x04\x80\x01\x02\n\n\r\n\x05\x04\x14\x02\x04\x05\
-    \x12\x04\x80\x01\x0b\x11\n\r\n\x05\x04\x14\x02\x04\x01\x12\x04\x80\x01\
-    \x12\x1a\n\r\n\x05\x04\x14\x02\x04\x03\x12\x04\x80\x01\x1d\x1e\n\x0c\n\
-    \x02\x04\x15\x12\x06\x83\x01\0\x85\x01\x01\n\x0b\n\x03\x04\x15\x01\x12\
-    \x04\x83\x01\x08#\n\x0c\n\x04\x04\x15\x02\0\x12\x04\x84\x01\x02\x20\n\r\
-    \n\x05\x04\x15\x02\0\x04\x12\x04\x84\x01\x02\n\n\r\n\x05\x04\x15\x02\0\
-    \x05\x12\x04\x84\x01\x0b\x11\n\r\n\x05\x04\x15\x02\0\x01\x12\x04\x84\x01\
-    \x12\x1b\n\r\n\x05\x04\x15\x02\0\x03\x12\x04\x84\x01\x1e\x1f\n\x0c\n\x02\
-    \x04\x16\x12\x06\x87\x01\0\x8a\x01\x01\n\x0b\n\x03\x04\x16\x01\x12\x04\
-    \x87\x01\x08$\n\x0c\n\x04\x04\x16\x02\0\x12\x04\x88\x01\x02\x20\n\r\n\
-    \x05\x04\x16\x02\0\x04\x12\x04\x88\x01\x02\n\n\r\n\x05\x04\x16\x02\0\x05\
-    \x12\x04\x88\x01\x0b\x11\n\r\n\x05\x04\x16\x02\0\x01\x12\x04\x88\x01\x12\
-    \x1b\n\r\n\x05\x04\x16\x02\0\x03\x12\x04\x88\x01\x1e\x1f\n\x0c\n\x04\x04\
-    \x16\x02\x01\x12\x04\x89\x01\x02,\n\r\n\x05\x04\x16\x02\x01\x04\x12\x04\
-    \x89\x01\x02\n\n\r\n\x05\x04\x16\x02\x01\x06\x12\x04\x89\x01\x0b\x1b\n\r\
-    \n\x05\x04\x16\x02\x01\x01\x12\x04\x89\x01\x1c'\n\r\n\x05\x04\x16\x02\
-    \x01\x03\x12\x04\x89\x01*+\n\x0c\n\x02\x04\x17\x12\x06\x8c\x01\0\x90\x01\
-    \x01\n\x0b\n\x03\x04\x17\x01\x12\x04\x8c\x01\x08\x16\n\x0c\n\x04\x04\x17\
-    \x02\0\x12\x04\x8d\x01\x02\x1d\n\r\n\x05\x04\x17\x02\0\x04\x12\x04\x8d\
-    \x01\x02\n\n\r\n\x05\x04\x17\x02\0\x05\x12\x04\x8d\x01\x0b\x11\n\r\n\x05\
-    \x04\x17\x02\0\x01\x12\x04\x8d\x01\x12\x18\n\r\n\x05\x04\x17\x02\0\x03\
-    \x12\x04\x8d\x01\x1b\x1c\n\x0c\n\x04\x04\x17\x02\x01\x12\x04\x8e\x01\x02\
-    \x1f\n\r\n\x05\x04\x17\x02\x01\x04\x12\x04\x8e\x01\x02\n\n\r\n\x05\x04\
-    \x17\x02\x01\x05\x12\x04\x8e\x01\x0b\x11\n\r\n\x05\x04\x17\x02\x01\x01\
-    \x12\x04\x8e\x01\x12\x1a\n\r\n\x05\x04\x17\x02\x01\x03\x12\x04\x8e\x01\
-    \x1d\x1e\n\x0c\n\x04\x04\x17\x02\x02\x12\x04\x8f\x01\x02\x1f\n\r\n\x05\
-    \x04\x17\x02\x02\x04\x12\x04\x8f\x01\x02\n\n\r\n\x05\x04\x17\x02\x02\x05\
-    \x12\x04\x8f\x01\x0b\x11\n\r\n\x05\x04\x17\x02\x02\x01\x12\x04\x8f\x01\
-    \x12\x1a\n\r\n\x05\x04\x17\x02\x02\x03\x12\x04\x8f\x01\x1d\x1e\n\x1d\n\
-    \x02\x04\x18\x12\x06\x93\x01\0\x95\x01\x01\x1a\x0f\x20Origin\x20Member\n\
-    \n\x0b\n\x03\x04\x18\x01\x12\x04\x93\x01\x08\x1f\n\x0c\n\x04\x04\x18\x02\
-    \0\x12\x04\x94\x01\x02\x20\n\r\n\x05\x04\x18\x02\0\x04\x12\x04\x94\x01\
-    \x02\n\n\r\n\x05\x04\x18\x02\0\x05\x12\x04\x94\x01\x0b\x11\n\r\n\x05\x04\
-    \x18\x02\0\x01\x12\x04\x94\x01\x12\x1b\n\r\n\x05\x04\x18\x02\0\x03\x12\
-    \x04\x94\x01\x1e\x1f\n\x0c\n\x02\x04\x19\x12\x06\x97\x01\0\x9a\x01\x01\n\
-    \x0b\n\x03\x04\x19\x01\x12\x04\x97\x01\x08\x20\n\x0c\n\x04\x04\x19\x02\0\
-    \x12\x04\x98\x01\x02\x20\n\r\n\x05\x04\x19\x02\0\x04\x12\x04\x98\x01\x02\
-    \n\n\r\n\x05\x04\x19\x02\0\x05\x12\x04\x98\x01\x0b\x11\n\r\n\x05\x04\x19\
-    \x02\0\x01\x12\x04\x98\x01\x12\x1b\n\r\n\x05\x04\x19\x02\0\x03\x12\x04\
-    \x98\x01\x1e\x1f\n\x0c\n\x04\x04\x19\x02\x01\x12\x04\x99\x01\x02\x1e\n\r\
-    \n\x05\x04\x19\x02\x01\x04\x12\x04\x99\x01\x02\n\n\r\n\x05\x04\x19\x02\
-    \x01\x05\x12\x04\x99\x01\x0b\x11\n\r\n\x05\x04\x19\x02\x01\x01\x12\x04\
-    \x99\x01\x12\x19\n\r\n\x05\x04\x19\x02\x01\x03\x12\x04\x99\x01\x1c\x1d\n\
-    \x0c\n\x02\x04\x1a\x12\x06\x9c\x01\0\x9f\x01\x01\n\x0b\n\x03\x04\x1a\x01\
-    \x12\x04\x9c\x01\x08\x1a\n\x0c\n\x04\x04\x1a\x02\0\x12\x04\x9d\x01\x02\
-    \x20\n\r\n\x05\x04\x1a\x02\0\x04\x12\x04\x9d\x01\x02\n\n\r\n\x05\x04\x1a\
-    \x02\0\x05\x12\x04\x9d\x01\x0b\x11\n\r\n\x05\x04\x1a\x02\0\x01\x12\x04\
-    \x9d\x01\x12\x1b\n\r\n\x05\x04\x1a\x02\0\x03\x12\x04\x9d\x01\x1e\x1f\n\
-    \x0c\n\x04\x04\x1a\x02\x01\x12\x04\x9e\x01\x02\x1e\n\r\n\x05\x04\x1a\x02\
-    \x01\x04\x12\x04\x9e\x01\x02\n\n\r\n\x05\x04\x1a\x02\x01\x05\x12\x04\x9e\
-    \x01\x0b\x11\n\r\n\x05\x04\x1a\x02\x01\x01\x12\x04\x9e\x01\x12\x19\n\r\n\
-    \x05\x04\x1a\x02\x01\x03\x12\x04\x9e\x01\x1c\x1d\n\x1e\n\x02\x04\x1b\x12\
-    \x06\xa2\x01\0\xae\x01\x01\x1a\x10\x20Origin\x20Package\n\n\x0b\n\x03\
-    \x04\x1b\x01\x12\x04\xa2\x01\x08\x15\n\x0c\n\x04\x04\x1b\x02\0\x12\x04\
-    \xa3\x01\x02\x19\n\r\n\x05\x04\x1b\x02\0\x04\x12\x04\xa3\x01\x02\n\n\r\n\
-    \x05\x04\x1b\x02\0\x05\x12\x04\xa3\x01\x0b\x11\n\r\n\x05\x04\x1b\x02\0\
-    \x01\x12\x04\xa3\x01\x12\x14\n\r\n\x05\x04\x1b\x02\0\x03\x12\x04\xa3\x01\
-    \x17\x18\n\x0c\n\x04\x04\x1b\x02\x01\x12\x04\xa4\x01\x02\x1f\n\r\n\x05\
-    \x04\x1b\x02\x01\x04\x12\x04\xa4\x01\x02\n\n\r\n\x05\x04\x1b\x02\x01\x05\
-    \x12\x04\xa4\x01\x0b\x11\n\r\n\x05\x04\x1b\x02\x01\x01\x12\x04\xa4\x01\
-    \x12\x1a\n\r\n\x05\x04\x1b\x02\x01\x03\x12\x04\xa4\x01\x1d\x1e\n\x0c\n\
-    \x04\x04\x1b\x02\x02\x12\x04\xa5\x01\x02\x20\n\r\n\x05\x04\x1b\x02\x02\
-    \x04\x12\x04\xa5\x01\x02\n\n\r\n\x05\x04\x1b\x02\x02\x05\x12\x04\xa5\x01\
-    \x0b\x11\n\r\n\x05\x04\x1b\x02\x02\x01\x12\x04\xa5\x01\x12\x1b\n\r\n\x05\
-    \x04\x1b\x02\x02\x03\x12\x04\xa5\x01\x1e\x1f\n\x0c\n\x04\x04\x1b\x02\x03\
-    \x12\x04\xa6\x01\x02(\n\r\n\x05\x04\x1b\x02\x03\x04\x12\x04\xa6\x01\x02\
-    \n\n\r\n\x05\x04\x1b\x02\x03\x06\x12\x04\xa6\x01\x0b\x1d\n\r\n\x05\x04\
-    \x1b\x02\x03\x01\x12\x04\xa6\x01\x1e#\n\r\n\x05\x04\x1b\x02\x03\x03\x12\
-    \x04\xa6\x01&'\n\x0c\n\x04\x04\x1b\x02\x04\x12\x04\xa7\x01\x02\x1f\n\r\n\
-    \x05\x04\x1b\x02\x04\x04\x12\x04\xa7\x01\x02\n\n\r\n\x05\x04\x1b\x02\x04\
-    \x05\x12\x04\xa7\x01\x0b\x11\n\r\n\x05\x04\x1b\x02\x04\x01\x12\x04\xa7\
-    \x01\x12\x1a\n\r\n\x05\x04\x1b\x02\x04\x03\x12\x04\xa7\x01\x1d\x1e\n\x0c\
-    \n\x04\x04\x1b\x02\x05\x12\x04\xa8\x01\x02\x1f\n\r\n\x05\x04\x1b\x02\x05\
-    \x04\x12\x04\xa8\x01\x02\n\n\r\n\x05\x04\x1b\x02\x05\x05\x12\x04\xa8\x01\
-    \x0b\x11\n\r\n\x05\x04\x1b\x02\x05\x01\x12\x04\xa8\x01\x12\x1a\n\r\n\x05\
-    \x04\x1b\x02\x05\x03\x12\x04\xa8\x01\x1d\x1e\n\x0c\n\x04\x04\x1b\x02\x06\
-    \x12\x04\xa9\x01\x02'\n\r\n\x05\x04\x1b\x02\x06\x04\x12\x04\xa9\x01\x02\
-    \n\n\r\n\x05\x04\x1b\x02\x06\x06\x12\x04\xa9\x01\x0b\x1d\n\r\n\x05\x04\
-    \x1b\x02\x06\x01\x12\x04\xa9\x01\x1e\"\n\r\n\x05\x04\x1b\x02\x06\x03\x12\
-    \x04\xa9\x01%&\n\x0c\n\x04\x04\x1b\x02\x07\x12\x04\xaa\x01\x02(\n\r\n\
-    \x05\x04\x1b\x02\x07\x04\x12\x04\xaa\x01\x02\n\n\r\n\x05\x04\x1b\x02\x07\
-    \x06\x12\x04\xaa\x01\x0b\x1d\n\r\n\x05\x04\x1b\x02\x07\x01\x12\x04\xaa\
-    \x01\x1e#\n\r\n\x05\x04\x1b\x02\x07\x03\x12\x04\xaa\x01&'\n\x0c\n\x04\
-    \x04\x1b\x02\x08\x12\x04\xab\x01\x02,\n\r\n\x05\x04\x1b\x02\x08\x04\x12\
-    \x04\xab\x01\x02\n\n\r\n\x05\x04\x1b\x02\x08\x05\x12\x04\xab\x01\x0b\x11\
-    \n\r\n\x05\x04\x1b\x02\x08\x01\x12\x04\xab\x01\x12\x19\n\r\n\x05\x04\x1b\
-    \x02\x08\x03\x12\x04\xab\x01\x1c\x1d\n\r\n\x05\x04\x1b\x02\x08\x08\x12\
-    \x04\xab\x01\x1e+\n\x10\n\x08\x04\x1b\x02\x08\x08\xe7\x07\0\x12\x04\xab\
-    \x01\x1f*\n\x11\n\t\x04\x1b\x02\x08\x08\xe7\x07\0\x02\x12\x04\xab\x01\
-    \x1f%\n\x12\n\n\x04\x1b\x02\x08\x08\xe7\x07\0\x02\0\x12\x04\xab\x01\x1f%\
-    \n\x13\n\x0b\x04\x1b\x02\x08\x08\xe7\x07\0\x02\0\x01\x12\x04\xab\x01\x1f\
-    %\n\x11\n\t\x04\x1b\x02\x08\x08\xe7\x07\0\x03\x12\x04\xab\x01&*\n\x0c\n\
-    \x04\x04\x1b\x02\t\x12\x04\xac\x01\x02\x1e\n\r\n\x05\x04\x1b\x02\t\x04\
-    \x12\x04\xac\x01\x02\n\n\r\n\x05\x04\x1b\x02\t\x05\x12\x04\xac\x01\x0b\
-    \x11\n\r\n\x05\x04\x1b\x02\t\x01\x12\x04\xac\x01\x12\x18\n\r\n\x05\x04\
-    \x1b\x02\t\x03\x12\x04\xac\x01\x1b\x1d\n\x0c\n\x04\x04\x1b\x02\n\x12\x04\
-    \xad\x01\x02\x1e\n\r\n\x05\x04\x1b\x02\n\x04\x12\x04\xad\x01\x02\n\n\r\n\
-    \x05\x04\x1b\x02\n\x05\x12\x04\xad\x01\x0b\x11\n\r\n\x05\x04\x1b\x02\n\
-    \x01\x12\x04\xad\x01\x12\x18\n\r\n\x05\x04\x1b\x02\n\x03\x12\x04\xad\x01\
-    \x1b\x1d\n\x0c\n\x02\x04\x1c\x12\x06\xb0\x01\0\xb5\x01\x01\n\x0b\n\x03\
-    \x04\x1c\x01\x12\x04\xb0\x01\x08\x1a\n\x0c\n\x04\x04\x1c\x02\0\x12\x04\
-    \xb1\x01\x02\x1d\n\r\n\x05\x04\x1c\x02\0\x04\x12\x04\xb1\x01\x02\n\n\r\n\
-    \x05\x04\x1c\x02\0\x05\x12\x04\xb1\x01\x0b\x11\n\r\n\x05\x04\x1c\x02\0\
-    \x01\x12\x04\xb1\x01\x12\x18\n\r\n\x05\x04\x1c\x02\0\x03\x12\x04\xb1\x01\
-    \x1b\x1c\n\x0c\n\x04\x04\x1c\x02\x01\x12\x04\xb2\x01\x02\x1b\n\r\n\x05\
-    \x04\x1c\x02\x01\x04\x12\x04\xb2\x01\x02\n\n\r\n\x05\x04\x1c\x02\x01\x05\
-    \x12\x04\xb2\x01\x0b\x11\n\r\n\x05\x04\x1c\x02\x01\x01\x12\x04\xb2\x01\
-    \x12\x16\n\r\n\x05\x04\x1c\x02\x01\x03\x12\x04\xb2\x01\x19\x1a\n\x0c\n\
-    \x04\x04\x1c\x02\x02\x12\x04\xb3\x01\x02\x1e\n\r\n\x05\x04\x1c\x02\x02\
-    \x04\x12\x04\xb3\x01\x02\n\n\r\n\x05\x04\x1c\x02\x02\x05\x12\x04\xb3\x01\
-    \x0b\x11\n\r\n\x05\x04\x1c\x02\x02\x01\x12\x04\xb3\x01\x12\x19\n\r\n\x05\
-    \x04\x1c\x02\x02\x03\x12\x04\xb3\x01\x1c\x1d\n\x0c\n\x04\x04\x1c\x02\x03\
-    \x12\x04\xb4\x01\x02\x1e\n\r\n\x05\x04\x1c\x02\x03\x04\x12\x04\xb4\x01\
-    \x02\n\n\r\n\x05\x04\x1c\x02\x03\x05\x12\x04\xb4\x01\x0b\x11\n\r\n\x05\
-    \x04\x1c\x02\x03\x01\x12\x04\xb4\x01\x12\x19\n\r\n\x05\x04\x1c\x02\x03\
-    \x03\x12\x04\xb4\x01\x1c\x1d\n\x0c\n\x02\x04\x1d\x12\x06\xb7\x01\0\xbd\
-    \x01\x01\n\x0b\n\x03\x04\x1d\x01\x12\x04\xb7\x01\x08\x1c\n\x0c\n\x04\x04\
-    \x1d\x02\0\x12\x04\xb8\x01\x02\x1d\n\r\n\x05\x04\x1d\x02\0\x04\x12\x04\
-    \xb8\x01\x02\n\n\r\n\x05\x04\x1d\x02\0\x05\x12\x04\xb8\x01\x0b\x11\n\r\n\
-    \x05\x04\x1d\x02\0\x01\x12\x04\xb8\x01\x12\x18\n\r\n\x05\x04\x1d\x02\0\
-    \x03\x12\x04\xb8\x01\x1b\x1c\n\x0c\n\x04\x04\x1d\x02\x01\x12\x04\xb9\x01\
-    \x02\x1b\n\r\n\x05\x04\x1d\x02\x01\x04\x12\x04\xb9\x01\x02\n\n\r\n\x05\
-    \x04\x1d\x02\x01\x05\x12\x04\xb9\x01\x0b\x11\n\r\n\x05\x04\x1d\x02\x01\
-    \x01\x12\x04\xb9\x01\x12\x16\n\r\n\x05\x04\x1d\x02\x01\x03\x12\x04\xb9\
-    \x01\x19\x1a\n\x0c\n\x04\x04\x1d\x02\x02\x12\x04\xba\x01\x02\x1e\n\r\n\
-    \x05\x04\x1d\x02\x02\x04\x12\x04\xba\x01\x02\n\n\r\n\x05\x04\x1d\x02\x02\
-    \x05\x12\x04\xba\x01\x0b\x11\n\r\n\x05\x04\x1d\x02\x02\x01\x12\x04\xba\
-    \x01\x12\x19\n\r\n\x05\x04\x1d\x02\x02\x03\x12\x04\xba\x01\x1c\x1d\n\x0c\
-    \n\x04\x04\x1d\x02\x03\x12\x04\xbb\x01\x02$\n\r\n\x05\x04\x1d\x02\x03\
-    \x04\x12\x04\xbb\x01\x02\n\n\r\n\x05\x04\x1d\x02\x03\x05\x12\x04\xbb\x01\
-    \x0b\x11\n\r\n\x05\x04\x1d\x02\x03\x01\x12\x04\xbb\x01\x12\x1f\n\r\n\x05\
-    \x04\x1d\x02\x03\x03\x12\x04\xbb\x01\"#\n\x0c\n\x04\x04\x1d\x02\x04\x12\
-    \x04\xbc\x01\x02\x1d\n\r\n\x05\x04\x1d\x02\x04\x04\x12\x04\xbc\x01\x02\n\
-    \n\r\n\x05\x04\x1d\x02\x04\x05\x12\x04\xbc\x01\x0b\x11\n\r\n\x05\x04\x1d\
-    \x02\x04\x01\x12\x04\xbc\x01\x12\x18\n\r\n\x05\x04\x1d\x02\x04\x03\x12\
-    \x04\xbc\x01\x1b\x1c\n\x0c\n\x02\x04\x1e\x12\x06\xbf\x01\0\xca\x01\x01\n\
-    \x0b\n\x03\x04\x1e\x01\x12\x04\xbf\x01\x08\x1b\n\x0c\n\x04\x04\x1e\x02\0\
-    \x12\x04\xc0\x01\x02\x1f\n\r\n\x05\x04\x1e\x02\0\x04\x12\x04\xc0\x01\x02\
-    \n\n\r\n\x05\x04\x1e\x02\0\x05\x12\x04\xc0\x01\x0b\x11\n\r\n\x05\x04\x1e\
-    \x02\0\x01\x12\x04\xc0\x01\x12\x1a\n\r\n\x05\x04\x1e\x02\0\x03\x12\x04\
-    \xc0\x01\x1d\x1e\n\x0c\n\x04\x04\x1e\x02\x01\x12\x04\xc1\x01\x02\x20\n\r\
-    \n\x05\x04\x1e\x02\x01\x04\x12\x04\xc1\x01\x02\n\n\r\n\x05\x04\x1e\x02\
-    \x01\x05\x12\x04\xc1\x01\x0b\x11\n\r\n\x05\x04\x1e\x02\x01\x01\x12\x04\
-    \xc1\x01\x12\x1b\n\r\n\x05\x04\x1e\x02\x01\x03\x12\x04\xc1\x01\x1e\x1f\n\
-    \x0c\n\x04\x04\x1e\x02\x02\x12\x04\xc2\x01\x02(\n\r\n\x05\x04\x1e\x02\
-    \x02\x04\x12\x04\xc2\x01\x02\n\n\r\n\x05\x04\x1e\x02\x02\x06\x12\x04\xc2\
-    \x01\x0b\x1d\n\r\n\x05\x04\x1e\x02\x02\x01\x12\x04\xc2\x01\x1e#\n\r\n\
-    \x05\x04\x1e\x02\x02\x03\x12\x04\xc2\x01&'\n\x0c\n\x04\x04\x1e\x02\x03\
-    \x12\x04\xc3\x01\x02\x1f\n\r\n\x05\x04\x1e\x02\x03\x04\x12\x04\xc3\x01\
-    \x02\n\n\r\n\x05\x04\x1e\x02\x03\x05\x12\x04\xc3\x01\x0b\x11\n\r\n\x05\
-    \x04\x1e\x02\x03\x01\x12\x04\xc3\x01\x12\x1a\n\r\n\x05\x04\x1e\x02\x03\
-    \x03\x12\x04\xc3\x01\x1d\x1e\n\x0c\n\x04\x04\x1e\x02\x04\x12\x04\xc4\x01\
-    \x02\x1f\n\r\n\x05\x04\x1e\x02\x04\x04\x12\x04\xc4\x01\x02\n\n\r\n\x05\
-    \x04\x1e\x02\x04\x05\x12\x04\xc4\x01\x0b\x11\n\r\n\x05\x04\x1e\x02\x04\
-    \x01\x12\x04\xc4\x01\x12\x1a\n\r\n\x05\x04\x1e\x02\x04\x03\x12\x04\xc4\
-    \x01\x1d\x1e\n\x0c\n\x04\x04\x1e\x02\x05\x12\x04\xc5\x01\x02'\n\r\n\x05\
-    \x04\x1e\x02\x05\x04\x12\x04\xc5\x01\x02\n\n\r\n\x05\x04\x1e\x02\x05\x06\
-    \x12\x04\xc5\x01\x0b\x1d\n\r\n\x05\x04\x1e\x02\x05\x01\x12\x04\xc5\x01\
-    \x1e\"\n\r\n\x05\x04\x1e\x02\x05\x03\x12\x04\xc5\x01%&\n\x0c\n\x04\x04\
-    \x1e\x02\x06\x12\x04\xc6\x01\x02(\n\r\n\x05\x04\x1e\x02\x06\x04\x12\x04\
-    \xc6\x01\x02\n\n\r\n\x05\x04\x1e\x02\x06\x06\x12\x04\xc6\x01\x0b\x1d\n\r\
-    \n\x05\x04\x1e\x02\x06\x01\x12\x04\xc6\x01\x1e#\n\r\n\x05\x04\x1e\x02\
-    \x06\x03\x12\x04\xc6\x01&'\n\x0c\n\x04\x04\x1e\x02\x07\x12\x04\xc7\x01\
-    \x02,\n\r\n\x05\x04\x1e\x02\x07\x04\x12\x04\xc7\x01\x02\n\n\r\n\x05\x04\
-    \x1e\x02\x07\x05\x12\x04\xc7\x01\x0b\x11\n\r\n\x05\x04\x1e\x02\x07\x01\
-    \x12\x04\xc7\x01\x12\x19\n\r\n\x05\x04\x1e\x02\x07\x03\x12\x04\xc7\x01\
-    \x1c\x1d\n\r\n\x05\x04\x1e\x02\x07\x08\x12\x04\xc7\x01\x1e+\n\x10\n\x08\
-    \x04\x1e\x02\x07\x08\xe7\x07\0\x12\x04\xc7\x01\x1f*\n\x11\n\t\x04\x1e\
-    \x02\x07\x08\xe7\x07\0\x02\x12\x04\xc7\x01\x1f%\n\x12\n\n\x04\x1e\x02\
-    \x07\x08\xe7\x07\0\x02\0\x12\x04\xc7\x01\x1f%\n\x13\n\x0b\x04\x1e\x02\
-    \x07\x08\xe7\x07\0\x02\0\x01\x12\x04\xc7\x01\x1f%\n\x11\n\t\x04\x1e\x02\
-    \x07\x08\xe7\x07\0\x03\x12\x04\xc7\x01&*\n\x0c\n\x04\x04\x1e\x02\x08\x12\
-    \x04\xc8\x01\x02\x1d\n\r\n\x05\x04\x1e\x02\x08\x04\x12\x04\xc8\x01\x02\n\
-    \n\r\n\x05\x04\x1e\x02\x08\x05\x12\x04\xc8\x01\x0b\x11\n\r\n\x05\x04\x1e\
-    \x02\x08\x01\x12\x04\xc8\x01\x12\x18\n\r\n\x05\x04\x1e\x02\x08\x03\x12\
-    \x04\xc8\x01\x1b\x1c\n\x0c\n\x04\x04\x1e\x02\t\x12\x04\xc9\x01\x02\x1e\n\
-    \r\n\x05\x04\x1e\x02\t\x04\x12\x04\xc9\x01\x02\n\n\r\n\x05\x04\x1e\x02\t\
-    \x05\x12\x04\xc9\x01\x0b\x11\n\r\n\x05\x04\x1e\x02\t\x01\x12\x04\xc9\x01\
-    \x12\x18\n\r\n\x05\x04\x1e\x02\t\x03\x12\x04\xc9\x01\x1b\x1d\n\x0c\n\x02\
-    \x04\x1f\x12\x06\xcc\x01\0\xce\x01\x01\n\x0b\n\x03\x04\x1f\x01\x12\x04\
-    \xcc\x01\x08\x18\n\x0c\n\x04\x04\x1f\x02\0\x12\x04\xcd\x01\x02(\n\r\n\
-    \x05\x04\x1f\x02\0\x04\x12\x04\xcd\x01\x02\n\n\r\n\x05\x04\x1f\x02\0\x06\
-    \x12\x04\xcd\x01\x0b\x1d\n\r\n\x05\x04\x1f\x02\0\x01\x12\x04\xcd\x01\x1e\
-    #\n\r\n\x05\x04\x1f\x02\0\x03\x12\x04\xcd\x01&'\n\x0c\n\x02\x04\x20\x12\
-    \x06\xd0\x01\0\xd3\x01\x01\n\x0b\n\x03\x04\x20\x01\x12\x04\xd0\x01\x08\
-    \x1e\n\x0c\n\x04\x04\x20\x02\0\x12\x04\xd1\x01\x02(\n\r\n\x05\x04\x20\
-    \x02\0\x04\x12\x04\xd1\x01\x02\n\n\r\n\x05\x04\x20\x02\0\x06\x12\x04\xd1\
-    \x01\x0b\x1d\n\r\n\x05\x04\x20\x02\0\x01\x12\x04\xd1\x01\x1e#\n\r\n\x05\
-    \x04\x20\x02\0\x03\x12\x04\xd1\x01&'\n\x0c\n\x04\x04\x20\x02\x01\x12\x04\
-    \xd2\x01\x02\x1d\n\r\n\x05\x04\x20\x02\x01\x04\x12\x04\xd2\x01\x02\n\n\r\
-    \n\x05\x04\x20\x02\x01\x05\x12\x04\xd2\x01\x0b\x11\n\r\n\x05\x04\x20\x02\
-    \x01\x01\x12\x04\xd2\x01\x12\x18\n\r\n\x05\x04\x20\x02\x01\x03\x12\x04\
-    \xd2\x01\x1b\x1c\n\x0c\n\x02\x04!\x12\x06\xd5\x01\0\xda\x01\x01\n\x0b\n\
-    \x03\x04!\x01\x12\x04\xd5\x01\x08\x20\n\x0c\n\x04\x04!\x02\0\x12\x04\xd6\
-    \x01\x02(\n\r\n\x05\x04!\x02\0\x04\x12\x04\xd6\x01\x02\n\n\r\n\x05\x04!\
-    \x02\0\x06\x12\x04\xd6\x01\x0b\x1d\n\r\n\x05\x04!\x02\0\x01\x12\x04\xd6\
-    \x01\x1e#\n\r\n\x05\x04!\x02\0\x03\x12\x04\xd6\x01&'\n\x0c\n\x04\x04!\
-    \x02\x01\x12\x04\xd7\x01\x02\x1c\n\r\n\x05\x04!\x02\x01\x04\x12\x04\xd7\
-    \x01\x02\n\n\r\n\x05\x04!\x02\x01\x05\x12\x04\xd7\x01\x0b\x11\n\r\n\x05\
-    \x04!\x02\x01\x01\x12\x04\xd7\x01\x12\x17\n\r\n\x05\x04!\x02\x01\x03\x12\
-    \x04\xd7\x01\x1a\x1b\n\x0c\n\x04\x04!\x02\x02\x12\x04\xd8\x01\x02\x1b\n\
-    \r\n\x05\x04!\x02\x02\x04\x12\x04\xd8\x01\x02\n\n\r\n\x05\x04!\x02\x02\
-    \x05\x12\x04\xd8\x01\x0b\x11\n\r\n\x05\x04!\x02\x02\x01\x12\x04\xd8\x01\
-    \x12\x16\n\r\n\x05\x04!\x02\x02\x03\x12\x04\xd8\x01\x19\x1a\n\x0c\n\x04\
-    \x04!\x02\x03\x12\x04\xd9\x01\x02\x1d\n\r\n\x05\x04!\x02\x03\x04\x12\x04\
-    \xd9\x01\x02\n\n\r\n\x05\x04!\x02\x03\x05\x12\x04\xd9\x01\x0b\x0f\n\r\n\
-    \x05\x04!\x02\x03\x01\x12\x04\xd9\x01\x10\x18\n\r\n\x05\x04!\x02\x03\x03\
-    \x12\x04\xd9\x01\x1b\x1c\n\x0c\n\x02\x04\"\x12\x06\xdc\x01\0\xe1\x01\x01\
-    \n\x0b\n\x03\x04\"\x01\x12\x04\xdc\x01\x08!\n\x0c\n\x04\x04\"\x02\0\x12\
-    \x04\xdd\x01\x02\x1c\n\r\n\x05\x04\"\x02\0\x04\x12\x04\xdd\x01\x02\n\n\r\
-    \n\x05\x04\"\x02\0\x05\x12\x04\xdd\x01\x0b\x11\n\r\n\x05\x04\"\x02\0\x01\
-    \x12\x04\xdd\x01\x12\x17\n\r\n\x05\x04\"\x02\0\x03\x12\x04\xdd\x01\x1a\
-    \x1b\n\x0c\n\x04\x04\"\x02\x01\x12\x04\xde\x01\x02\x1b\n\r\n\x05\x04\"\
-    \x02\x01\x04\x12\x04\xde\x01\x02\n\n\r\n\x05\x04\"\x02\x01\x05\x12\x04\
-    \xde\x01\x0b\x11\n\r\n\x05\x04\"\x02\x01\x01\x12\x04\xde\x01\x12\x16\n\r\
-    \n\x05\x04\"\x02\x01\x03\x12\x04\xde\x01\x19\x1a\n\x0c\n\x04\x04\"\x02\
-    \x02\x12\x04\xdf\x01\x02\x1c\n\r\n\x05\x04\"\x02\x02\x04\x12\x04\xdf\x01\
-    \x02\n\n\r\n\x05\x04\"\x02\x02\x05\x12\x04\xdf\x01\x0b\x11\n\r\n\x05\x04\
-    \"\x02\x02\x01\x12\x04\xdf\x01\x12\x17\n\r\n\x05\x04\"\x02\x02\x03\x12\
-    \x04\xdf\x01\x1a\x1b\n\x0c\n\x04\x04\"\x02\x03\x12\x04\xe0\x01\x02)\n\r\
-    \n\x05\x04\"\x02\x03\x04\x12\x04\xe0\x01\x02\n\n\r\n\x05\x04\"\x02\x03\
-    \x06\x12\x04\xe0\x01\x0b\x1d\n\r\n\x05\x04\"\x02\x03\x01\x12\x04\xe0\x01\
-    \x1e$\n\r\n\x05\x04\"\x02\x03\x03\x12\x04\xe0\x01'(\n\x0c\n\x02\x04#\x12\
-    \x06\xe3\x01\0\xe7\x01\x01\n\x0b\n\x03\x04#\x01\x12\x04\xe3\x01\x08\x1c\
-    \n\x0c\n\x04\x04#\x02\0\x12\x04\xe4\x01\x02!\n\r\n\x05\x04#\x02\0\x04\
-    \x12\x04\xe4\x01\x02\n\n\r\n\x05\x04#\x02\0\x05\x12\x04\xe4\x01\x0b\x11\
-    \n\r\n\x05\x04#\x02\0\x01\x12\x04\xe4\x01\x12\x1c\n\r\n\x05\x04#\x02\0\
-    \x03\x12\x04\xe4\x01\x1f\x20\n\x0c\n\x04\x04#\x02\x01\x12\x04\xe5\x01\
-    \x02!\n\r\n\x05\x04#\x02\x01\x04\x12\x04\xe5\x01\x02\n\n\r\n\x05\x04#\
-    \x02\x01\x05\x12\x04\xe5\x01\x0b\x11\n\r\n\x05\x04#\x02\x01\x01\x12\x04\
-    \xe5\x01\x12\x1c\n\r\n\x05\x04#\x02\x01\x03\x12\x04\xe5\x01\x1f\x20\n\
-    \x0c\n\x04\x04#\x02\x02\x12\x04\xe6\x01\x02(\n\r\n\x05\x04#\x02\x02\x04\
-    \x12\x04\xe6\x01\x02\n\n\r\n\x05\x04#\x02\x02\x06\x12\x04\xe6\x01\x0b\
-    \x1d\n\r\n\x05\x04#\x02\x02\x01\x12\x04\xe6\x01\x1e#\n\r\n\x05\x04#\x02\
-    \x02\x03\x12\x04\xe6\x01&'\n\x0c\n\x02\x04$\x12\x06\xe9\x01\0\xed\x01\
-    \x01\n\x0b\n\x03\x04$\x01\x12\x04\xe9\x01\x08\x1b\n\x0c\n\x04\x04$\x02\0\
-    \x12\x04\xea\x01\x02!\n\r\n\x05\x04$\x02\0\x04\x12\x04\xea\x01\x02\n\n\r\
-    \n\x05\x04$\x02\0\x05\x12\x04\xea\x01\x0b\x11\n\r\n\x05\x04$\x02\0\x01\
-    \x12\x04\xea\x01\x12\x1c\n\r\n\x05\x04$\x02\0\x03\x12\x04\xea\x01\x1f\
-    \x20\n\x0c\n\x04\x04$\x02\x01\x12\x04\xeb\x01\x02!\n\r\n\x05\x04$\x02\
-    \x01\x04\x12\x04\xeb\x01\x02\n\n\r\n\x05\x04$\x02\x01\x05\x12\x04\xeb\
-    \x01\x0b\x11\n\r\n\x05\x04$\x02\x01\x01\x12\x04\xeb\x01\x12\x1c\n\r\n\
-    \x05\x04$\x02\x01\x03\x12\x04\xeb\x01\x1f\x20\n\x0c\n\x04\x04$\x02\x02\
-    \x12\x04\xec\x01\x02(\n\r\n\x05\x04$\x02\x02\x04\x12\x04\xec\x01\x02\n\n\
-    \r\n\x05\x04$\x02\x02\x06\x12\x04\xec\x01\x0b\x1d\n\r\n\x05\x04$\x02\x02\
-    \x01\x12\x04\xec\x01\x1e#\n\r\n\x05\x04$\x02\x02\x03\x12\x04\xec\x01&'\n\
-    \x0c\n\x02\x04%\x12\x06\xef\x01\0\xf5\x01\x01\n\x0b\n\x03\x04%\x01\x12\
-    \x04\xef\x01\x08\"\n\x0c\n\x04\x04%\x02\0\x12\x04\xf0\x01\x02\x1d\n\r\n\
-    \x05\x04%\x02\0\x04\x12\x04\xf0\x01\x02\n\n\r\n\x05\x04%\x02\0\x05\x12\
-    \x04\xf0\x01\x0b\x11\n\r\n\x05\x04%\x02\0\x01\x12\x04\xf0\x01\x12\x18\n\
-    \r\n\x05\x04%\x02\0\x03\x12\x04\xf0\x01\x1b\x1c\n\x0c\n\x04\x04%\x02\x01\
-    \x12\x04\xf1\x01\x02\x1c\n\r\n\x05\x04%\x02\x01\x04\x12\x04\xf1\x01\x02\
-    \n\n\r\n\x05\x04%\x02\x01\x05\x12\x04\xf1\x01\x0b\x11\n\r\n\x05\x04%\x02\
-    \x01\x01\x12\x04\xf1\x01\x12\x17\n\r\n\x05\x04%\x02\x01\x03\x12\x04\xf1\
-    \x01\x1a\x1b\n\x0c\n\x04\x04%\x02\x02\x12\x04\xf2\x01\x02\x1c\n\r\n\x05\
-    \x04%\x02\x02\x04\x12\x04\xf2\x01\x02\n\n\r\n\x05\x04%\x02\x02\x05\x12\
-    \x04\xf2\x01\x0b\x11\n\r\n\x05\x04%\x02\x02\x01\x12\x04\xf2\x01\x12\x17\
-    \n\r\n\x05\x04%\x02\x02\x03\x12\x04\xf2\x01\x1a\x1b\n\x0c\n\x04\x04%\x02\
-    \x03\x12\x04\xf3\x01\x02\x1b\n\r\n\x05\x04%\x02\x03\x04\x12\x04\xf3\x01\
-    \x02\n\n\r\n\x05\x04%\x02\x03\x05\x12\x04\xf3\x01\x0b\x11\n\r\n\x05\x04%\
-    \x02\x03\x01\x12\x04\xf3\x01\x12\x16\n\r\n\x05\x04%\x02\x03\x03\x12\x04\
-    \xf3\x01\x19\x1a\n\x0c\n\x04\x04%\x02\x04\x12\x04\xf4\x01\x02\x1d\n\r\n\
-    \x05\x04%\x02\x04\x04\x12\x04\xf4\x01\x02\n\n\r\n\x05\x04%\x02\x04\x05\
-    \x12\x04\xf4\x01\x0b\x0f\n\r\n\x05\x04%\x02\x04\x01\x12\x04\xf4\x01\x10\
-    \x18\n\r\n\x05\x04%\x02\x04\x03\x12\x04\xf4\x01\x1b\x1c\n\x0c\n\x02\x04&\
-    \x12\x06\xf7\x01\0\xfb\x01\x01\n\x0b\n\x03\x04&\x01\x12\x04\xf7\x01\x08&\
-    \n\x0c\n\x04\x04&\x02\0\x12\x04\xf8\x01\x02\x1d\n\r\n\x05\x04&\x02\0\x04\
-    \x12\x04\xf8\x01\x02\n\n\r\n\x05\x04&\x02\0\x05\x12\x04\xf8\x01\x0b\x11\
-    \n\r\n\x05\x04&\x02\0\x01\x12\x04\xf8\x01\x12\x18\n\r\n\x05\x04&\x02\0\
-    \x03\x12\x04\xf8\x01\x1b\x1c\n\x0c\n\x04\x04&\x02\x01\x12\x04\xf9\x01\
-    \x02\x1c\n\r\n\x05\x04&\x02\x01\x04\x12\x04\xf9\x01\x02\n\n\r\n\x05\x04&\
-    \x02\x01\x05\x12\x04\xf9\x01\x0b\x11\n\r\n\x05\x04&\x02\x01\x01\x12\x04\
-    \xf9\x01\x12\x17\n\r\n\x05\x04&\x02\x01\x03\x12\x04\xf9\x01\x1a\x1b\n\
-    \x0c\n\x04\x04&\x02\x02\x12\x04\xfa\x01\x02\x1b\n\r\n\x05\x04&\x02\x02\
-    \x04\x12\x04\xfa\x01\x02\n\n\r\n\x05\x04&\x02\x02\x05\x12\x04\xfa\x01\
-    \x0b\x11\n\r\n\x05\x04&\x02\x02\x01\x12\x04\xfa\x01\x12\x16\n\r\n\x05\
-    \x04&\x02\x02\x03\x12\x04\xfa\x01\x19\x1a\n\x0c\n\x02\x04'\x12\x06\xfd\
-    \x01\0\x82\x02\x01\n\x0b\n\x03\x04'\x01\x12\x04\xfd\x01\x08'\n\x0c\n\x04\
-    \x04'\x02\0\x12\x04\xfe\x01\x02\x1c\n\r\n\x05\x04'\x02\0\x04\x12\x04\xfe\
-    \x01\x02\n\n\r\n\x05\x04'\x02\0\x05\x12\x04\xfe\x01\x0b\x11\n\r\n\x05\
-    \x04'\x02\0\x01\x12\x04\xfe\x01\x12\x17\n\r\n\x05\x04'\x02\0\x03\x12\x04\
-    \xfe\x01\x1a\x1b\n\x0c\n\x04\x04'\x02\x01\x12\x04\xff\x01\x02\x1b\n\r\n\
-    \x05\x04'\x02\x01\x04\x12\x04\xff\x01\x02\n\n\r\n\x05\x04'\x02\x01\x05\
-    \x12\x04\xff\x01\x0b\x11\n\r\n\x05\x04'\x02\x01\x01\x12\x04\xff\x01\x12\
-    \x16\n\r\n\x05\x04'\x02\x01\x03\x12\x04\xff\x01\x19\x1a\n\x0c\n\x04\x04'\
-    \x02\x02\x12\x04\x80\x02\x02\x1c\n\r\n\x05\x04'\x02\x02\x04\x12\x04\x80\
-    \x02\x02\n\n\r\n\x05\x04'\x02\x02\x05\x12\x04\x80\x02\x0b\x11\n\r\n\x05\
-    \x04'\x02\x02\x01\x12\x04\x80\x02\x12\x17\n\r\n\x05\x04'\x02\x02\x03\x12\
-    \x04\x80\x02\x1a\x1b\n\x0c\n\x04\x04'\x02\x03\x12\x04\x81\x02\x02)\n\r\n\
-    \x05\x04'\x02\x03\x04\x12\x04\x81\x02\x02\n\n\r\n\x05\x04'\x02\x03\x06\
-    \x12\x04\x81\x02\x0b\x1d\n\r\n\x05\x04'\x02\x03\x01\x12\x04\x81\x02\x1e$\
-    \n\r\n\x05\x04'\x02\x03\x03\x12\x04\x81\x02'(\n\x0c\n\x02\x04(\x12\x06\
-    \x84\x02\0\x87\x02\x01\n\x0b\n\x03\x04(\x01\x12\x04\x84\x02\x08'\n\x0c\n\
-    \x04\x04(\x02\0\x12\x04\x85\x02\x02\x1d\n\r\n\x05\x04(\x02\0\x04\x12\x04\
-    \x85\x02\x02\n\n\r\n\x05\x04(\x02\0\x05\x12\x04\x85\x02\x0b\x11\n\r\n\
-    \x05\x04(\x02\0\x01\x12\x04\x85\x02\x12\x18\n\r\n\x05\x04(\x02\0\x03\x12\
-    \x04\x85\x02\x1b\x1c\n\x0c\n\x04\x04(\x02\x01\x12\x04\x86\x02\x02\x1b\n\
-    \r\n\x05\x04(\x02\x01\x04\x12\x04\x86\x02\x02\n\n\r\n\x05\x04(\x02\x01\
-    \x05\x12\x04\x86\x02\x0b\x11\n\r\n\x05\x04(\x02\x01\x01\x12\x04\x86\x02\
-    \x12\x16\n\r\n\x05\x04(\x02\x01\x03\x12\x04\x86\x02\x19\x1a\n\x0c\n\x02\
-    \x04)\x12\x06\x89\x02\0\x8b\x02\x01\n\x0b\n\x03\x04)\x01\x12\x04\x89\x02\
-    \x08(\n\x0c\n\x04\x04)\x02\0\x12\x04\x8a\x02\x02-\n\r\n\x05\x04)\x02\0\
-    \x04\x12\x04\x8a\x02\x02\n\n\r\n\x05\x04)\x02\0\x06\x12\x04\x8a\x02\x0b\
-    \x1f\n\r\n\x05\x04)\x02\0\x01\x12\x04\x8a\x02\x20(\n\r\n\x05\x04)\x02\0\
-    \x03\x12\x04\x8a\x02+,\n\x1e\n\x02\x04*\x12\x06\x8e\x02\0\x98\x02\x01\
-    \x1a\x10\x20Origin\x20Project\n\n\x0b\n\x03\x04*\x01\x12\x04\x8e\x02\x08\
-    \x15\n\x0c\n\x04\x04*\x02\0\x12\x04\x8f\x02\x02\x19\n\r\n\x05\x04*\x02\0\
-    \x04\x12\x04\x8f\x02\x02\n\n\r\n\x05\x04*\x02\0\x05\x12\x04\x8f\x02\x0b\
-    \x11\n\r\n\x05\x04*\x02\0\x01\x12\x04\x8f\x02\x12\x14\n\r\n\x05\x04*\x02\
-    \0\x03\x12\x04\x8f\x02\x17\x18\n\x0c\n\x04\x04*\x02\x01\x12\x04\x90\x02\
-    \x02\x20\n\r\n\x05\x04*\x02\x01\x04\x12\x04\x90\x02\x02\n\n\r\n\x05\x04*\
-    \x02\x01\x05\x12\x04\x90\x02\x0b\x11\n\r\n\x05\x04*\x02\x01\x01\x12\x04\
-    \x90\x02\x12\x1b\n\r\n\x05\x04*\x02\x01\x03\x12\x04\x90\x02\x1e\x1f\n\
-    \x0c\n\x04\x04*\x02\x02\x12\x04\x91\x02\x02\"\n\r\n\x05\x04*\x02\x02\x04\
-    \x12\x04\x91\x02\x02\n\n\r\n\x05\x04*\x02\x02\x05\x12\x04\x91\x02\x0b\
-    \x11\n\r\n\x05\x04*\x02\x02\x01\x12\x04\x91\x02\x12\x1d\n\r\n\x05\x04*\
-    \x02\x02\x03\x12\x04\x91\x02\x20!\n\x0c\n\x04\x04*\x02\x03\x12\x04\x92\
-    \x02\x02#\n\r\n\x05\x04*\x02\x03\x04\x12\x04\x92\x02\x02\n\n\r\n\x05\x04\
-    *\x02\x03\x05\x12\x04\x92\x02\x0b\x11\n\r\n\x05\x04*\x02\x03\x01\x12\x04\
-    \x92\x02\x12\x1e\n\r\n\x05\x04*\x02\x03\x03\x12\x04\x92\x02!\"\n\x0c\n\
-    \x04\x04*\x02\x04\x12\x04\x93\x02\x02\x1b\n\r\n\x05\x04*\x02\x04\x04\x12\
-    \x04\x93\x02\x02\n\n\r\n\x05\x04*\x02\x04\x05\x12\x04\x93\x02\x0b\x11\n\
-    \r\n\x05\x04*\x02\x04\x01\x12\x04\x93\x02\x12\x16\n\r\n\x05\x04*\x02\x04\
-    \x03\x12\x04\x93\x02\x19\x1a\n\x0c\n\x04\x04*\x02\x05\x12\x04\x94\x02\
-    \x02\x20\n\r\n\x05\x04*\x02\x05\x04\x12\x04\x94\x02\x02\n\n\r\n\x05\x04*\
-    \x02\x05\x05\x12\x04\x94\x02\x0b\x11\n\r\n\x05\x04*\x02\x05\x01\x12\x04\
-    \x94\x02\x12\x1b\n\r\n\x05\x04*\x02\x05\x03\x12\x04\x94\x02\x1e\x1f\n\
-    \x0c\n\x04\x04*\x02\x06\x12\x04\x95\x02\x02\x1f\n\r\n\x05\x04*\x02\x06\
-    \x04\x12\x04\x95\x02\x02\n\n\r\n\x05\x04*\x02\x06\x05\x12\x04\x95\x02\
-    \x0b\x11\n\r\n\x05\x04*\x02\x06\x01\x12\x04\x95\x02\x12\x1a\n\r\n\x05\
-    \x04*\x02\x06\x03\x12\x04\x95\x02\x1d\x1e\n\x0c\n\x04\x04*\x02\x07\x12\
-    \x04\x96\x02\x02\x1f\n\r\n\x05\x04*\x02\x07\x04\x12\x04\x96\x02\x02\n\n\
-    \r\n\x05\x04*\x02\x07\x05\x12\x04\x96\x02\x0b\x11\n\r\n\x05\x04*\x02\x07\
-    \x01\x12\x04\x96\x02\x12\x1a\n\r\n\x05\x04*\x02\x07\x03\x12\x04\x96\x02\
-    \x1d\x1e\n\x0c\n\x04\x04*\x02\x08\x12\x04\x97\x02\x02\x1f\n\r\n\x05\x04*\
-    \x02\x08\x04\x12\x04\x97\x02\x02\n\n\r\n\x05\x04*\x02\x08\x05\x12\x04\
-    \x97\x02\x0b\x11\n\r\n\x05\x04*\x02\x08\x01\x12\x04\x97\x02\x12\x1a\n\r\
-    \n\x05\x04*\x02\x08\x03\x12\x04\x97\x02\x1d\x1e\n\x0c\n\x02\x04+\x12\x06\
-    \x9a\x02\0\x9c\x02\x01\n\x0b\n\x03\x04+\x01\x12\x04\x9a\x02\x08\x1b\n\
-    \x0c\n\x04\x04+\x02\0\x12\x04\x9b\x02\x02%\n\r\n\x05\x04+\x02\0\x04\x12\
-    \x04\x9b\x02\x02\n\n\r\n\x05\x04+\x02\0\x06\x12\x04\x9b\x02\x0b\x18\n\r\
-    \n\x05\x04+\x02\0\x01\x12\x04\x9b\x02\x19\x20\n\r\n\x05\x04+\x02\0\x03\
-    \x12\x04\x9b\x02#$\n\x0c\n\x02\x04,\x12\x06\x9e\x02\0\xa1\x02\x01\n\x0b\
-    \n\x03\x04,\x01\x12\x04\x9e\x02\x08\x1b\n\x0c\n\x04\x04,\x02\0\x12\x04\
-    \x9f\x02\x02\x1b\n\r\n\x05\x04,\x02\0\x04\x12\x04\x9f\x02\x02\n\n\r\n\
-    \x05\x04,\x02\0\x05\x12\x04\x9f\x02\x0b\x11\n\r\n\x05\x04,\x02\0\x01\x12\
-    \x04\x9f\x02\x12\x16\n\r\n\x05\x04,\x02\0\x03\x12\x04\x9f\x02\x19\x1a\n\
-    \x0c\n\x04\x04,\x02\x01\x12\x04\xa0\x02\x02#\n\r\n\x05\x04,\x02\x01\x04\
-    \x12\x04\xa0\x02\x02\n\n\r\n\x05\x04,\x02\x01\x05\x12\x04\xa0\x02\x0b\
-    \x11\n\r\n\x05\x04,\x02\x01\x01\x12\x04\xa0\x02\x12\x1e\n\r\n\x05\x04,\
-    \x02\x01\x03\x12\x04\xa0\x02!\"\n\x0c\n\x02\x04-\x12\x06\xa3\x02\0\xa5\
-    \x02\x01\n\x0b\n\x03\x04-\x01\x12\x04\xa3\x02\x08\x18\n\x0c\n\x04\x04-\
-    \x02\0\x12\x04\xa4\x02\x02\x1b\n\r\n\x05\x04-\x02\0\x04\x12\x04\xa4\x02\
-    \x02\n\n\r\n\x05\x04-\x02\0\x05\x12\x04\xa4\x02\x0b\x11\n\r\n\x05\x04-\
-    \x02\0\x01\x12\x04\xa4\x02\x12\x16\n\r\n\x05\x04-\x02\0\x03\x12\x04\xa4\
-    \x02\x19\x1a\n\x0c\n\x02\x04.\x12\x06\xa7\x02\0\xaa\x02\x01\n\x0b\n\x03\
-    \x04.\x01\x12\x04\xa7\x02\x08\x1b\n\x0c\n\x04\x04.\x02\0\x12\x04\xa8\x02\
-    \x02#\n\r\n\x05\x04.\x02\0\x04\x12\x04\xa8\x02\x02\n\n\r\n\x05\x04.\x02\
-    \0\x05\x12\x04\xa8\x02\x0b\x11\n\r\n\x05\x04.\x02\0\x01\x12\x04\xa8\x02\
-    \x12\x1e\n\r\n\x05\x04.\x02\0\x03\x12\x04\xa8\x02!\"\n\x0c\n\x04\x04.\
-    \x02\x01\x12\x04\xa9\x02\x02%\n\r\n\x05\x04.\x02\x01\x04\x12\x04\xa9\x02\
-    \x02\n\n\r\n\x05\x04.\x02\x01\x06\x12\x04\xa9\x02\x0b\x18\n\r\n\x05\x04.\
-    \x02\x01\x01\x12\x04\xa9\x02\x19\x20\n\r\n\x05\x04.\x02\x01\x03\x12\x04\
-    \xa9\x02#$\n!\n\x02\x04/\x12\x06\xad\x02\0\xb4\x02\x01\x1a\x13\x20Origin\
-    \x20Public\x20Key\n\n\x0b\n\x03\x04/\x01\x12\x04\xad\x02\x08\x17\n\x0c\n\
-    \x04\x04/\x02\0\x12\x04\xae\x02\x02\x19\n\r\n\x05\x04/\x02\0\x04\x12\x04\
-    \xae\x02\x02\n\n\r\n\x05\x04/\x02\0\x05\x12\x04\xae\x02\x0b\x11\n\r\n\
-    \x05\x04/\x02\0\x01\x12\x04\xae\x02\x12\x14\n\r\n\x05\x04/\x02\0\x03\x12\
-    \x04\xae\x02\x17\x18\n\x0c\n\x04\x04/\x02\x01\x12\x04\xaf\x02\x02\x20\n\
-    \r\n\x05\x04/\x02\x01\x04\x12\x04\xaf\x02\x02\n\n\r\n\x05\x04/\x02\x01\
-    \x05\x12\x04\xaf\x02\x0b\x11\n\r\n\x05\x04/\x02\x01\x01\x12\x04\xaf\x02\
-    \x12\x1b\n\r\n\x05\x04/\x02\x01\x03\x12\x04\xaf\x02\x1e\x1f\n\x0c\n\x04\
-    \x04/\x02\x02\x12\x04\xb0\x02\x02\x1b\n\r\n\x05\x04/\x02\x02\x04\x12\x04\
-    \xb0\x02\x02\n\n\r\n\x05\x04/\x02\x02\x05\x12\x04\xb0\x02\x0b\x11\n\r\n\
-    \x05\x04/\x02\x02\x01\x12\x04\xb0\x02\x12\x16\n\r\n\x05\x04/\x02\x02\x03\
-    \x12\x04\xb0\x02\x19\x1a\n\x0c\n\x04\x04/\x02\x03\x12\x04\xb1\x02\x02\
-    \x1f\n\r\n\x05\x04/\x02\x03\x04\x12\x04\xb1\x02\x02\n\n\r\n\x05\x04/\x02\
-    \x03\x05\x12\x04\xb1\x02\x0b\x11\n\r\n\x05\x04/\x02\x03\x01\x12\x04\xb1\
-    \x02\x12\x1a\n\r\n\x05\x04/\x02\x03\x03\x12\x04\xb1\x02\x1d\x1e\n\x0c\n\
-    \x04\x04/\x02\x04\x12\x04\xb2\x02\x02\x1a\n\r\n\x05\x04/\x02\x04\x04\x12\
-    \x04\xb2\x02\x02\n\n\r\n\x05\x04/\x02\x04\x05\x12\x04\xb2\x02\x0b\x10\n\
-    \r\n\x05\x04/\x02\x04\x01\x12\x04\xb2\x02\x11\x15\n\r\n\x05\x04/\x02\x04\
-    \x03\x12\x04\xb2\x02\x18\x19\n\x0c\n\x04\x04/\x02\x05\x12\x04\xb3\x02\
-    \x02\x1f\n\r\n\x05\x04/\x02\x05\x04\x12\x04\xb3\x02\x02\n\n\r\n\x05\x04/\
-    \x02\x05\x05\x12\x04\xb3\x02\x0b\x11\n\r\n\x05\x04/\x02\x05\x01\x12\x04\
-    \xb3\x02\x12\x1a\n\r\n\x05\x04/\x02\x05\x03\x12\x04\xb3\x02\x1d\x1e\n\
-    \x0c\n\x02\x040\x12\x06\xb6\x02\0\xbc\x02\x01\n\x0b\n\x03\x040\x01\x12\
-    \x04\xb6\x02\x08\x1d\n\x0c\n\x04\x040\x02\0\x12\x04\xb7\x02\x02\x20\n\r\
-    \n\x05\x040\x02\0\x04\x12\x04\xb7\x02\x02\n\n\r\n\x05\x040\x02\0\x05\x12\
-    \x04\xb7\x02\x0b\x11\n\r\n\x05\x040\x02\0\x01\x12\x04\xb7\x02\x12\x1b\n\
-    \r\n\x05\x040\x02\0\x03\x12\x04\xb7\x02\x1e\x1f\n\x0c\n\x04\x040\x02\x01\
-    \x12\x04\xb8\x02\x02\x1b\n\r\n\x05\x040\x02\x01\x04\x12\x04\xb8\x02\x02\
-    \n\n\r\n\x05\x040\x02\x01\x05\x12\x04\xb8\x02\x0b\x11\n\r\n\x05\x040\x02\
-    \x01\x01\x12\x04\xb8\x02\x12\x16\n\r\n\x05\x040\x02\x01\x03\x12\x04\xb8\
-    \x02\x19\x1a\n\x0c\n\x04\x040\x02\x02\x12\x04\xb9\x02\x02\x1f\n\r\n\x05\
-    \x040\x02\x02\x04\x12\x04\xb9\x02\x02\n\n\r\n\x05\x040\x02\x02\x05\x12\
-    \x04\xb9\x02\x0b\x11\n\r\n\x05\x040\x02\x02\x01\x12\x04\xb9\x02\x12\x1a\
-    \n\r\n\x05\x040\x02\x02\x03\x12\x04\xb9\x02\x1d\x1e\n\x0c\n\x04\x040\x02\
-    \x03\x12\x04\xba\x02\x02\x1a\n\r\n\x05\x040\x02\x03\x04\x12\x04\xba\x02\
-    \x02\n\n\r\n\x05\x040\x02\x03\x05\x12\x04\xba\x02\x0b\x10\n\r\n\x05\x040\
-    \x02\x03\x01\x12\x04\xba\x02\x11\x15\n\r\n\x05\x040\x02\x03\x03\x12\x04\
-    \xba\x02\x18\x19\n\x0c\n\x04\x040\x02\x04\x12\x04\xbb\x02\x02\x1f\n\r\n\
-    \x05\x040\x02\x04\x04\x12\x04\xbb\x02\x02\n\n\r\n\x05\x040\x02\x04\x05\
-    \x12\x04\xbb\x02\x0b\x11\n\r\n\x05\x040\x02\x04\x01\x12\x04\xbb\x02\x12\
-    \x1a\n\r\n\x05\x040\x02\x04\x03\x12\x04\xbb\x02\x1d\x1e\n\x0c\n\x02\x041\
-    \x12\x06\xbe\x02\0\xc2\x02\x01\n\x0b\n\x03\x041\x01\x12\x04\xbe\x02\x08\
-    \x1a\n\x0c\n\x04\x041\x02\0\x12\x04\xbf\x02\x02\x1f\n\r\n\x05\x041\x02\0\
-    \x04\x12\x04\xbf\x02\x02\n\n\r\n\x05\x041\x02\0\x05\x12\x04\xbf\x02\x0b\
-    \x11\n\r\n\x05\x041\x02\0\x01\x12\x04\xbf\x02\x12\x1a\n\r\n\x05\x041\x02\
-    \0\x03\x12\x04\xbf\x02\x1d\x1e\n\x0c\n\x04\x041\x02\x01\x12\x04\xc0\x02\
-    \x02\x1d\n\r\n\x05\x041\x02\x01\x04\x12\x04\xc0\x02\x02\n\n\r\n\x05\x041\
-    \x02\x01\x05\x12\x04\xc0\x02\x0b\x11\n\r\n\x05\x041\x02\x01\x01\x12\x04\
-    \xc0\x02\x12\x18\n\r\n\x05\x041\x02\x01\x03\x12\x04\xc0\x02\x1b\x1c\n\
-    \x0c\n\x04\x041\x02\x02\x12\x04\xc1\x02\x02\x1f\n\r\n\x05\x041\x02\x02\
-    \x04\x12\x04\xc1\x02\x02\n\n\r\n\x05\x041\x02\x02\x05\x12\x04\xc1\x02\
-    \x0b\x11\n\r\n\x05\x041\x02\x02\x01\x12\x04\xc1\x02\x12\x1a\n\r\n\x05\
-    \x041\x02\x02\x03\x12\x04\xc1\x02\x1d\x1e\n\x0c\n\x02\x042\x12\x06\xc4\
-    \x02\0\xc7\x02\x01\n\x0b\n\x03\x042\x01\x12\x04\xc4\x02\x08\x20\n\x0c\n\
-    \x04\x042\x02\0\x12\x04\xc5\x02\x02\x1f\n\r\n\x05\x042\x02\0\x04\x12\x04\
-    \xc5\x02\x02\n\n\r\n\x05\x042\x02\0\x05\x12\x04\xc5\x02\x0b\x11\n\r\n\
-    \x05\x042\x02\0\x01\x12\x04\xc5\x02\x12\x1a\n\r\n\x05\x042\x02\0\x03\x12\
-    \x04\xc5\x02\x1d\x1e\n\x0c\n\x04\x042\x02\x01\x12\x04\xc6\x02\x02\x1d\n\
-    \r\n\x05\x042\x02\x01\x04\x12\x04\xc6\x02\x02\n\n\r\n\x05\x042\x02\x01\
-    \x05\x12\x04\xc6\x02\x0b\x11\n\r\n\x05\x042\x02\x01\x01\x12\x04\xc6\x02\
-    \x12\x18\n\r\n\x05\x042\x02\x01\x03\x12\x04\xc6\x02\x1b\x1c\n\x0c\n\x02\
-    \x043\x12\x06\xc9\x02\0\xcc\x02\x01\n\x0b\n\x03\x043\x01\x12\x04\xc9\x02\
-    \x08\"\n\x0c\n\x04\x043\x02\0\x12\x04\xca\x02\x02\x1f\n\r\n\x05\x043\x02\
-    \0\x04\x12\x04\xca\x02\x02\n\n\r\n\x05\x043\x02\0\x05\x12\x04\xca\x02\
-    \x0b\x11\n\r\n\x05\x043\x02\0\x01\x12\x04\xca\x02\x12\x1a\n\r\n\x05\x043\
-    \x02\0\x03\x12\x04\xca\x02\x1d\x1e\n\x0c\n\x04\x043\x02\x01\x12\x04\xcb\
-    \x02\x02\x20\n\r\n\x05\x043\x02\x01\x04\x12\x04\xcb\x02\x02\n\n\r\n\x05\
-    \x043\x02\x01\x05\x12\x04\xcb\x02\x0b\x11\n\r\n\x05\x043\x02\x01\x01\x12\
-    \x04\xcb\x02\x12\x1b\n\r\n\x05\x043\x02\x01\x03\x12\x04\xcb\x02\x1e\x1f\
-    \n\x0c\n\x02\x044\x12\x06\xce\x02\0\xd1\x02\x01\n\x0b\n\x03\x044\x01\x12\
-    \x04\xce\x02\x08#\n\x0c\n\x04\x044\x02\0\x12\x04\xcf\x02\x02\x20\n\r\n\
-    \x05\x044\x02\0\x04\x12\x04\xcf\x02\x02\n\n\r\n\x05\x044\x02\0\x05\x12\
-    \x04\xcf\x02\x0b\x11\n\r\n\x05\x044\x02\0\x01\x12\x04\xcf\x02\x12\x1b\n\
-    \r\n\x05\x044\x02\0\x03\x12\x04\xcf\x02\x1e\x1f\n\x0c\n\x04\x044\x02\x01\
-    \x12\x04\xd0\x02\x02$\n\r\n\x05\x044\x02\x01\x04\x12\x04\xd0\x02\x02\n\n\
-    \r\n\x05\x044\x02\x01\x06\x12\x04\xd0\x02\x0b\x1a\n\r\n\x05\x044\x02\x01\
-    \x01\x12\x04\xd0\x02\x1b\x1f\n\r\n\x05\x044\x02\x01\x03\x12\x04\xd0\x02\
-    \"#\n!\n\x02\x045\x12\x06\xd4\x02\0\xdb\x02\x01\x1a\x13\x20Origin\x20Sec\
-    ret\x20Key\n\n\x0b\n\x03\x045\x01\x12\x04\xd4\x02\x08\x17\n\x0c\n\x04\
-    \x045\x02\0\x12\x04\xd5\x02\x02\x19\n\r\n\x05\x045\x02\0\x04\x12\x04\xd5\
-    \x02\x02\n\n\r\n\x05\x045\x02\0\x05\x12\x04\xd5\x02\x0b\x11\n\r\n\x05\
-    \x045\x02\0\x01\x12\x04\xd5\x02\x12\x14\n\r\n\x05\x045\x02\0\x03\x12\x04\
-    \xd5\x02\x17\x18\n\x0c\n\x04\x045\x02\x01\x12\x04\xd6\x02\x02\x20\n\r\n\
-    \x05\x045\x02\x01\x04\x12\x04\xd6\x02\x02\n\n\r\n\x05\x045\x02\x01\x05\
-    \x12\x04\xd6\x02\x0b\x11\n\r\n\x05\x045\x02\x01\x01\x12\x04\xd6\x02\x12\
-    \x1b\n\r\n\x05\x045\x02\x01\x03\x12\x04\xd6\x02\x1e\x1f\n\x0c\n\x04\x045\
-    \x02\x02\x12\x04\xd7\x02\x02\x1b\n\r\n\x05\x045\x02\x02\x04\x12\x04\xd7\
-    \x02\x02\n\n\r\n\x05\x045\x02\x02\x05\x12\x04\xd7\x02\x0b\x11\n\r\n\x05\
-    \x045\x02\x02\x01\x12\x04\xd7\x02\x12\x16\n\r\n\x05\x045\x02\x02\x03\x12\
-    \x04\xd7\x02\x19\x1a\n\x0c\n\x04\x045\x02\x03\x12\x04\xd8\x02\x02\x1f\n\
-    \r\n\x05\x045\x02\x03\x04\x12\x04\xd8\x02\x02\n\n\r\n\x05\x045\x02\x03\
-    \x05\x12\x04\xd8\x02\x0b\x11\n\r\n\x05\x045\x02\x03\x01\x12\x04\xd8\x02\
-    \x12\x1a\n\r\n\x05\x045\x02\x03\x03\x12\x04\xd8\x02\x1d\x1e\n\x0c\n\x04\
-    \x045\x02\x04\x12\x04\xd9\x02\x02\x1a\n\r\n\x05\x045\x02\x04\x04\x12\x04\
-    \xd9\x02\x02\n\n\r\n\x05\x045\x02\x04\x05\x12\x04\xd9\x02\x0b\x10\n\r\n\
-    \x05\x045\x02\x04\x01\x12\x04\xd9\x02\x11\x15\n\r\n\x05\x045\x02\x04\x03\
-    \x12\x04\xd9\x02\x18\x19\n\x0c\n\x04\x045\x02\x05\x12\x04\xda\x02\x02\
-    \x1f\n\r\n\x05\x045\x02\x05\x04\x12\x04\xda\x02\x02\n\n\r\n\x05\x045\x02\
-    \x05\x05\x12\x04\xda\x02\x0b\x11\n\r\n\x05\x045\x02\x05\x01\x12\x04\xda\
-    \x02\x12\x1a\n\r\n\x05\x045\x02\x05\x03\x12\x04\xda\x02\x1d\x1e\n\x0c\n\
-    \x02\x046\x12\x06\xdd\x02\0\xe3\x02\x01\n\x0b\n\x03\x046\x01\x12\x04\xdd\
-    \x02\x08\x1d\n\x0c\n\x04\x046\x02\0\x12\x04\xde\x02\x02\x20\n\r\n\x05\
-    \x046\x02\0\x04\x12\x04\xde\x02\x02\n\n\r\n\x05\x046\x02\0\x05\x12\x04\
-    \xde\x02\x0b\x11\n\r\n\x05\x046\x02\0\x01\x12\x04\xde\x02\x12\x1b\n\r\n\
-    \x05\x046\x02\0\x03\x12\x04\xde\x02\x1e\x1f\n\x0c\n\x04\x046\x02\x01\x12\
-    \x04\xdf\x02\x02\x1b\n\r\n\x05\x046\x02\x01\x04\x12\x04\xdf\x02\x02\n\n\
-    \r\n\x05\x046\x02\x01\x05\x12\x04\xdf\x02\x0b\x11\n\r\n\x05\x046\x02\x01\
-    \x01\x12\x04\xdf\x02\x12\x16\n\r\n\x05\x046\x02\x01\x03\x12\x04\xdf\x02\
-    \x19\x1a\n\x0c\n\x04\x046\x02\x02\x12\x04\xe0\x02\x02\x1f\n\r\n\x05\x046\
-    \x02\x02\x04\x12\x04\xe0\x02\x02\n\n\r\n\x05\x046\x02\x02\x05\x12\x04\
-    \xe0\x02\x0b\x11\n\r\n\x05\x046\x02\x02\x01\x12\x04\xe0\x02\x12\x1a\n\r\
-    \n\x05\x046\x02\x02\x03\x12\x04\xe0\x02\x1d\x1e\n\x0c\n\x04\x046\x02\x03\
-    \x12\x04\xe1\x02\x02\x1a\n\r\n\x05\x046\x02\x03\x04\x12\x04\xe1\x02\x02\
-    \n\n\r\n\x05\x046\x02\x03\x05\x12\x04\xe1\x02\x0b\x10\n\r\n\x05\x046\x02\
-    \x03\x01\x12\x04\xe1\x02\x11\x15\n\r\n\x05\x046\x02\x03\x03\x12\x04\xe1\
-    \x02\x18\x19\n\x0c\n\x04\x046\x02\x04\x12\x04\xe2\x02\x02\x1f\n\r\n\x05\
-    \x046\x02\x04\x04\x12\x04\xe2\x02\x02\n\n\r\n\x05\x046\x02\x04\x05\x12\
-    \x04\xe2\x02\x0b\x11\n\r\n\x05\x046\x02\x04\x01\x12\x04\xe2\x02\x12\x1a\
-    \n\r\n\x05\x046\x02\x04\x03\x12\x04\xe2\x02\x1d\x1e\n\x0c\n\x02\x047\x12\
-    \x06\xe5\x02\0\xe8\x02\x01\n\x0b\n\x03\x047\x01\x12\x04\xe5\x02\x08\x1a\
-    \n\x0c\n\x04\x047\x02\0\x12\x04\xe6\x02\x02\x1f\n\r\n\x05\x047\x02\0\x04\
-    \x12\x04\xe6\x02\x02\n\n\r\n\x05\x047\x02\0\x05\x12\x04\xe6\x02\x0b\x11\
-    \n\r\n\x05\x047\x02\0\x01\x12\x04\xe6\x02\x12\x1a\n\r\n\x05\x047\x02\0\
-    \x03\x12\x04\xe6\x02\x1d\x1e\n\x0c\n\x04\x047\x02\x01\x12\x04\xe7\x02\
-    \x02\x1d\n\r\n\x05\x047\x02\x01\x04\x12\x04\xe7\x02\x02\n\n\r\n\x05\x047\
-    \x02\x01\x05\x12\x04\xe7\x02\x0b\x11\n\r\n\x05\x047\x02\x01\x01\x12\x04\
-    \xe7\x02\x12\x18\n\r\n\x05\x047\x02\x01\x03\x12\x04\xe7\x02\x1b\x1c\
+    \x12\x03U&'\n\x0b\n\x04\x04\x0e\x02\x02\x12\x03V\x02\x1d\n\x0c\n\x05\x04\
+    \x0e\x02\x02\x04\x12\x03V\x02\n\n\x0c\n\x05\x04\x0e\x02\x02\x05\x12\x03V\
+    \x0b\x11\n\x0c\n\x05\x04\x0e\x02\x02\x01\x12\x03V\x12\x18\n\x0c\n\x05\
+    \x04\x0e\x02\x02\x03\x12\x03V\x1b\x1c\n\n\n\x02\x04\x0f\x12\x04Y\0]\x01\
+    \n\n\n\x03\x04\x0f\x01\x12\x03Y\x08%\n\x0b\n\x04\x04\x0f\x02\0\x12\x03Z\
+    \x02\x1b\n\x0c\n\x05\x04\x0f\x02\0\x04\x12\x03Z\x02\n\n\x0c\n\x05\x04\
+    \x0f\x02\0\x05\x12\x03Z\x0b\x11\n\x0c\n\x05\x04\x0f\x02\0\x01\x12\x03Z\
+    \x12\x16\n\x0c\n\x05\x04\x0f\x02\0\x03\x12\x03Z\x19\x1a\n\x0b\n\x04\x04\
+    \x0f\x02\x01\x12\x03[\x02(\n\x0c\n\x05\x04\x0f\x02\x01\x04\x12\x03[\x02\
+    \n\n\x0c\n\x05\x04\x0f\x02\x01\x06\x12\x03[\x0b\x1d\n\x0c\n\x05\x04\x0f\
+    \x02\x01\x01\x12\x03[\x1e#\n\x0c\n\x05\x04\x0f\x02\x01\x03\x12\x03[&'\n\
+    \x0b\n\x04\x04\x0f\x02\x02\x12\x03\\\x02\x1d\n\x0c\n\x05\x04\x0f\x02\x02\
+    \x04\x12\x03\\\x02\n\n\x0c\n\x05\x04\x0f\x02\x02\x05\x12\x03\\\x0b\x11\n\
+    \x0c\n\x05\x04\x0f\x02\x02\x01\x12\x03\\\x12\x18\n\x0c\n\x05\x04\x0f\x02\
+    \x02\x03\x12\x03\\\x1b\x1c\n\n\n\x02\x04\x10\x12\x04_\0d\x01\n\n\n\x03\
+    \x04\x10\x01\x12\x03_\x08'\n\x0b\n\x04\x04\x10\x02\0\x12\x03`\x02\x1b\n\
+    \x0c\n\x05\x04\x10\x02\0\x04\x12\x03`\x02\n\n\x0c\n\x05\x04\x10\x02\0\
+    \x05\x12\x03`\x0b\x11\n\x0c\n\x05\x04\x10\x02\0\x01\x12\x03`\x12\x16\n\
+    \x0c\n\x05\x04\x10\x02\0\x03\x12\x03`\x19\x1a\n\x0b\n\x04\x04\x10\x02\
+    \x01\x12\x03a\x02(\n\x0c\n\x05\x04\x10\x02\x01\x04\x12\x03a\x02\n\n\x0c\
+    \n\x05\x04\x10\x02\x01\x06\x12\x03a\x0b\x1d\n\x0c\n\x05\x04\x10\x02\x01\
+    \x01\x12\x03a\x1e#\n\x0c\n\x05\x04\x10\x02\x01\x03\x12\x03a&'\n\x0b\n\
+    \x04\x04\x10\x02\x02\x12\x03b\x02\x1c\n\x0c\n\x05\x04\x10\x02\x02\x04\
+    \x12\x03b\x02\n\n\x0c\n\x05\x04\x10\x02\x02\x05\x12\x03b\x0b\x11\n\x0c\n\
+    \x05\x04\x10\x02\x02\x01\x12\x03b\x12\x17\n\x0c\n\x05\x04\x10\x02\x02\
+    \x03\x12\x03b\x1a\x1b\n\x0b\n\x04\x04\x10\x02\x03\x12\x03c\x02\x1b\n\x0c\
+    \n\x05\x04\x10\x02\x03\x04\x12\x03c\x02\n\n\x0c\n\x05\x04\x10\x02\x03\
+    \x05\x12\x03c\x0b\x11\n\x0c\n\x05\x04\x10\x02\x03\x01\x12\x03c\x12\x16\n\
+    \x0c\n\x05\x04\x10\x02\x03\x03\x12\x03c\x19\x1a\n\n\n\x02\x04\x11\x12\
+    \x04f\0i\x01\n\n\n\x03\x04\x11\x01\x12\x03f\x08\x1b\n\x0b\n\x04\x04\x11\
+    \x02\0\x12\x03g\x02\x19\n\x0c\n\x05\x04\x11\x02\0\x04\x12\x03g\x02\n\n\
+    \x0c\n\x05\x04\x11\x02\0\x05\x12\x03g\x0b\x11\n\x0c\n\x05\x04\x11\x02\0\
+    \x01\x12\x03g\x12\x14\n\x0c\n\x05\x04\x11\x02\0\x03\x12\x03g\x17\x18\n\
+    \x0b\n\x04\x04\x11\x02\x01\x12\x03h\x02\x20\n\x0c\n\x05\x04\x11\x02\x01\
+    \x04\x12\x03h\x02\n\n\x0c\n\x05\x04\x11\x02\x01\x05\x12\x03h\x0b\x11\n\
+    \x0c\n\x05\x04\x11\x02\x01\x01\x12\x03h\x12\x1b\n\x0c\n\x05\x04\x11\x02\
+    \x01\x03\x12\x03h\x1e\x1f\n\x1f\n\x02\x04\x12\x12\x04l\0s\x01\x1a\x13\
+    \x20Origin\x20Invitation\n\n\n\n\x03\x04\x12\x01\x12\x03l\x08\x18\n\x0b\
+    \n\x04\x04\x12\x02\0\x12\x03m\x02\x19\n\x0c\n\x05\x04\x12\x02\0\x04\x12\
+    \x03m\x02\n\n\x0c\n\x05\x04\x12\x02\0\x05\x12\x03m\x0b\x11\n\x0c\n\x05\
+    \x04\x12\x02\0\x01\x12\x03m\x12\x14\n\x0c\n\x05\x04\x12\x02\0\x03\x12\
+    \x03m\x17\x18\n\x0b\n\x04\x04\x12\x02\x01\x12\x03n\x02!\n\x0c\n\x05\x04\
+    \x12\x02\x01\x04\x12\x03n\x02\n\n\x0c\n\x05\x04\x12\x02\x01\x05\x12\x03n\
+    \x0b\x11\n\x0c\n\x05\x04\x12\x02\x01\x01\x12\x03n\x12\x1c\n\x0c\n\x05\
+    \x04\x12\x02\x01\x03\x12\x03n\x1f\x20\n\x0b\n\x04\x04\x12\x02\x02\x12\
+    \x03o\x02#\n\x0c\n\x05\x04\x12\x02\x02\x04\x12\x03o\x02\n\n\x0c\n\x05\
+    \x04\x12\x02\x02\x05\x12\x03o\x0b\x11\n\x0c\n\x05\x04\x12\x02\x02\x01\
+    \x12\x03o\x12\x1e\n\x0c\n\x05\x04\x12\x02\x02\x03\x12\x03o!\"\n\x0b\n\
+    \x04\x04\x12\x02\x03\x12\x03p\x02\x20\n\x0c\n\x05\x04\x12\x02\x03\x04\
+    \x12\x03p\x02\n\n\x0c\n\x05\x04\x12\x02\x03\x05\x12\x03p\x0b\x11\n\x0c\n\
+    \x05\x04\x12\x02\x03\x01\x12\x03p\x12\x1b\n\x0c\n\x05\x04\x12\x02\x03\
+    \x03\x12\x03p\x1e\x1f\n\x0b\n\x04\x04\x12\x02\x04\x12\x03q\x02\"\n\x0c\n\
+    \x05\x04\x12\x02\x04\x04\x12\x03q\x02\n\n\x0c\n\x05\x04\x12\x02\x04\x05\
+    \x12\x03q\x0b\x11\n\x0c\n\x05\x04\x12\x02\x04\x01\x12\x03q\x12\x1d\n\x0c\
+    \n\x05\x04\x12\x02\x04\x03\x12\x03q\x20!\n\x0b\n\x04\x04\x12\x02\x05\x12\
+    \x03r\x02\x1f\n\x0c\n\x05\x04\x12\x02\x05\x04\x12\x03r\x02\n\n\x0c\n\x05\
+    \x04\x12\x02\x05\x05\x12\x03r\x0b\x11\n\x0c\n\x05\x04\x12\x02\x05\x01\
+    \x12\x03r\x12\x1a\n\x0c\n\x05\x04\x12\x02\x05\x03\x12\x03r\x1d\x1e\n\n\n\
+    \x02\x04\x13\x12\x04u\0z\x01\n\n\n\x03\x04\x13\x01\x12\x03u\x08%\n\x0b\n\
+    \x04\x04\x13\x02\0\x12\x03v\x02!\n\x0c\n\x05\x04\x13\x02\0\x04\x12\x03v\
+    \x02\n\n\x0c\n\x05\x04\x13\x02\0\x05\x12\x03v\x0b\x11\n\x0c\n\x05\x04\
+    \x13\x02\0\x01\x12\x03v\x12\x1c\n\x0c\n\x05\x04\x13\x02\0\x03\x12\x03v\
+    \x1f\x20\n\x0b\n\x04\x04\x13\x02\x01\x12\x03w\x02\x20\n\x0c\n\x05\x04\
+    \x13\x02\x01\x04\x12\x03w\x02\n\n\x0c\n\x05\x04\x13\x02\x01\x05\x12\x03w\
+    \x0b\x11\n\x0c\n\x05\x04\x13\x02\x01\x01\x12\x03w\x12\x1b\n\x0c\n\x05\
+    \x04\x13\x02\x01\x03\x12\x03w\x1e\x1f\n\x0b\n\x04\x04\x13\x02\x02\x12\
+    \x03x\x02\"\n\x0c\n\x05\x04\x13\x02\x02\x04\x12\x03x\x02\n\n\x0c\n\x05\
+    \x04\x13\x02\x02\x05\x12\x03x\x0b\x11\n\x0c\n\x05\x04\x13\x02\x02\x01\
+    \x12\x03x\x12\x1d\n\x0c\n\x05\x04\x13\x02\x02\x03\x12\x03x\x20!\n\x0b\n\
+    \x04\x04\x13\x02\x03\x12\x03y\x02\x1b\n\x0c\n\x05\x04\x13\x02\x03\x04\
+    \x12\x03y\x02\n\n\x0c\n\x05\x04\x13\x02\x03\x05\x12\x03y\x0b\x0f\n\x0c\n\
+    \x05\x04\x13\x02\x03\x01\x12\x03y\x10\x16\n\x0c\n\x05\x04\x13\x02\x03\
+    \x03\x12\x03y\x19\x1a\n\x0b\n\x02\x04\x14\x12\x05|\0\x82\x01\x01\n\n\n\
+    \x03\x04\x14\x01\x12\x03|\x08\x1e\n\x0b\n\x04\x04\x14\x02\0\x12\x03}\x02\
+    !\n\x0c\n\x05\x04\x14\x02\0\x04\x12\x03}\x02\n\n\x0c\n\x05\x04\x14\x02\0\
+    \x05\x12\x03}\x0b\x11\n\x0c\n\x05\x04\x14\x02\0\x01\x12\x03}\x12\x1c\n\
+    \x0c\n\x05\x04\x14\x02\0\x03\x12\x03}\x1f\x20\n\x0b\n\x04\x04\x14\x02\
+    \x01\x12\x03~\x02#\n\x0c\n\x05\x04\x14\x02\x01\x04\x12\x03~\x02\n\n\x0c\
+    \n\x05\x04\x14\x02\x01\x05\x12\x03~\x0b\x11\n\x0c\n\x05\x04\x14\x02\x01\
+    \x01\x12\x03~\x12\x1e\n\x0c\n\x05\x04\x14\x02\x01\x03\x12\x03~!\"\n\x0b\
+    \n\x04\x04\x14\x02\x02\x12\x03\x7f\x02\x20\n\x0c\n\x05\x04\x14\x02\x02\
+    \x04\x12\x03\x7f\x02\n\n\x0c\n\x05\x04\x14\x02\x02\x05\x12\x03\x7f\x0b\
+    \x11\n\x0c\n\x05\x04\x14\x02\x02\x01\x12\x03\x7f\x12\x1b\n\x0c\n\x05\x04\
+    \x14\x02\x02\x03\x12\x03\x7f\x1e\x1f\n\x0c\n\x04\x04\x14\x02\x03\x12\x04\
+    \x80\x01\x02\"\n\r\n\x05\x04\x14\x02\x03\x04\x12\x04\x80\x01\x02\n\n\r\n\
+    \x05\x04\x14\x02\x03\x05\x12\x04\x80\x01\x0b\x11\n\r\n\x05\x04\x14\x02\
+    \x03\x01\x12\x04\x80\x01\x12\x1d\n\r\n\x05\x04\x14\x02\x03\x03\x12\x04\
+    \x80\x01\x20!\n\x0c\n\x04\x04\x14\x02\x04\x12\x04\x81\x01\x02\x1f\n\r\n\
+    \x05\x04\x14\x02\x04\x04\x12\x04\x81\x01\x02\n\n\r\n\x05\x04\x14\x02\x04\
+    \x05\x12\x04\x81\x01\x0b\x11\n\r\n\x05\x04\x14\x02\x04\x01\x12\x04\x81\
+    \x01\x12\x1a\n\r\n\x05\x04\x14\x02\x04\x03\x12\x04\x81\x01\x1d\x1e\n\x0c\
+    \n\x02\x04\x15\x12\x06\x84\x01\0\x86\x01\x01\n\x0b\n\x03\x04\x15\x01\x12\
+    \x04\x84\x01\x08#\n\x0c\n\x04\x04\x15\x02\0\x12\x04\x85\x01\x02\x20\n\r\
+    \n\x05\x04\x15\x02\0\x04\x12\x04\x85\x01\x02\n\n\r\n\x05\x04\x15\x02\0\
+    \x05\x12\x04\x85\x01\x0b\x11\n\r\n\x05\x04\x15\x02\0\x01\x12\x04\x85\x01\
+    \x12\x1b\n\r\n\x05\x04\x15\x02\0\x03\x12\x04\x85\x01\x1e\x1f\n\x0c\n\x02\
+    \x04\x16\x12\x06\x88\x01\0\x8b\x01\x01\n\x0b\n\x03\x04\x16\x01\x12\x04\
+    \x88\x01\x08$\n\x0c\n\x04\x04\x16\x02\0\x12\x04\x89\x01\x02\x20\n\r\n\
+    \x05\x04\x16\x02\0\x04\x12\x04\x89\x01\x02\n\n\r\n\x05\x04\x16\x02\0\x05\
+    \x12\x04\x89\x01\x0b\x11\n\r\n\x05\x04\x16\x02\0\x01\x12\x04\x89\x01\x12\
+    \x1b\n\r\n\x05\x04\x16\x02\0\x03\x12\x04\x89\x01\x1e\x1f\n\x0c\n\x04\x04\
+    \x16\x02\x01\x12\x04\x8a\x01\x02,\n\r\n\x05\x04\x16\x02\x01\x04\x12\x04\
+    \x8a\x01\x02\n\n\r\n\x05\x04\x16\x02\x01\x06\x12\x04\x8a\x01\x0b\x1b\n\r\
+    \n\x05\x04\x16\x02\x01\x01\x12\x04\x8a\x01\x1c'\n\r\n\x05\x04\x16\x02\
+    \x01\x03\x12\x04\x8a\x01*+\n\x0c\n\x02\x04\x17\x12\x06\x8d\x01\0\x91\x01\
+    \x01\n\x0b\n\x03\x04\x17\x01\x12\x04\x8d\x01\x08\x16\n\x0c\n\x04\x04\x17\
+    \x02\0\x12\x04\x8e\x01\x02\x1d\n\r\n\x05\x04\x17\x02\0\x04\x12\x04\x8e\
+    \x01\x02\n\n\r\n\x05\x04\x17\x02\0\x05\x12\x04\x8e\x01\x0b\x11\n\r\n\x05\
+    \x04\x17\x02\0\x01\x12\x04\x8e\x01\x12\x18\n\r\n\x05\x04\x17\x02\0\x03\
+    \x12\x04\x8e\x01\x1b\x1c\n\x0c\n\x04\x04\x17\x02\x01\x12\x04\x8f\x01\x02\
+    \x1f\n\r\n\x05\x04\x17\x02\x01\x04\x12\x04\x8f\x01\x02\n\n\r\n\x05\x04\
+    \x17\x02\x01\x05\x12\x04\x8f\x01\x0b\x11\n\r\n\x05\x04\x17\x02\x01\x01\
+    \x12\x04\x8f\x01\x12\x1a\n\r\n\x05\x04\x17\x02\x01\x03\x12\x04\x8f\x01\
+    \x1d\x1e\n\x0c\n\x04\x04\x17\x02\x02\x12\x04\x90\x01\x02\x1f\n\r\n\x05\
+    \x04\x17\x02\x02\x04\x12\x04\x90\x01\x02\n\n\r\n\x05\x04\x17\x02\x02\x05\
+    \x12\x04\x90\x01\x0b\x11\n\r\n\x05\x04\x17\x02\x02\x01\x12\x04\x90\x01\
+    \x12\x1a\n\r\n\x05\x04\x17\x02\x02\x03\x12\x04\x90\x01\x1d\x1e\n\x1d\n\
+    \x02\x04\x18\x12\x06\x94\x01\0\x96\x01\x01\x1a\x0f\x20Origin\x20Member\n\
+    \n\x0b\n\x03\x04\x18\x01\x12\x04\x94\x01\x08\x1f\n\x0c\n\x04\x04\x18\x02\
+    \0\x12\x04\x95\x01\x02\x20\n\r\n\x05\x04\x18\x02\0\x04\x12\x04\x95\x01\
+    \x02\n\n\r\n\x05\x04\x18\x02\0\x05\x12\x04\x95\x01\x0b\x11\n\r\n\x05\x04\
+    \x18\x02\0\x01\x12\x04\x95\x01\x12\x1b\n\r\n\x05\x04\x18\x02\0\x03\x12\
+    \x04\x95\x01\x1e\x1f\n\x0c\n\x02\x04\x19\x12\x06\x98\x01\0\x9b\x01\x01\n\
+    \x0b\n\x03\x04\x19\x01\x12\x04\x98\x01\x08\x20\n\x0c\n\x04\x04\x19\x02\0\
+    \x12\x04\x99\x01\x02\x20\n\r\n\x05\x04\x19\x02\0\x04\x12\x04\x99\x01\x02\
+    \n\n\r\n\x05\x04\x19\x02\0\x05\x12\x04\x99\x01\x0b\x11\n\r\n\x05\x04\x19\
+    \x02\0\x01\x12\x04\x99\x01\x12\x1b\n\r\n\x05\x04\x19\x02\0\x03\x12\x04\
+    \x99\x01\x1e\x1f\n\x0c\n\x04\x04\x19\x02\x01\x12\x04\x9a\x01\x02\x1e\n\r\
+    \n\x05\x04\x19\x02\x01\x04\x12\x04\x9a\x01\x02\n\n\r\n\x05\x04\x19\x02\
+    \x01\x05\x12\x04\x9a\x01\x0b\x11\n\r\n\x05\x04\x19\x02\x01\x01\x12\x04\
+    \x9a\x01\x12\x19\n\r\n\x05\x04\x19\x02\x01\x03\x12\x04\x9a\x01\x1c\x1d\n\
+    \x0c\n\x02\x04\x1a\x12\x06\x9d\x01\0\xa0\x01\x01\n\x0b\n\x03\x04\x1a\x01\
+    \x12\x04\x9d\x01\x08\x1a\n\x0c\n\x04\x04\x1a\x02\0\x12\x04\x9e\x01\x02\
+    \x20\n\r\n\x05\x04\x1a\x02\0\x04\x12\x04\x9e\x01\x02\n\n\r\n\x05\x04\x1a\
+    \x02\0\x05\x12\x04\x9e\x01\x0b\x11\n\r\n\x05\x04\x1a\x02\0\x01\x12\x04\
+    \x9e\x01\x12\x1b\n\r\n\x05\x04\x1a\x02\0\x03\x12\x04\x9e\x01\x1e\x1f\n\
+    \x0c\n\x04\x04\x1a\x02\x01\x12\x04\x9f\x01\x02\x1e\n\r\n\x05\x04\x1a\x02\
+    \x01\x04\x12\x04\x9f\x01\x02\n\n\r\n\x05\x04\x1a\x02\x01\x05\x12\x04\x9f\
+    \x01\x0b\x11\n\r\n\x05\x04\x1a\x02\x01\x01\x12\x04\x9f\x01\x12\x19\n\r\n\
+    \x05\x04\x1a\x02\x01\x03\x12\x04\x9f\x01\x1c\x1d\n\x1e\n\x02\x04\x1b\x12\
+    \x06\xa3\x01\0\xb4\x01\x01\x1a\x10\x20Origin\x20Package\n\n\x0b\n\x03\
+    \x04\x1b\x01\x12\x04\xa3\x01\x08\x15\n\x0c\n\x04\x04\x1b\x02\0\x12\x04\
+    \xa4\x01\x02\x19\n\r\n\x05\x04\x1b\x02\0\x04\x12\x04\xa4\x01\x02\n\n\r\n\
+    \x05\x04\x1b\x02\0\x05\x12\x04\xa4\x01\x0b\x11\n\r\n\x05\x04\x1b\x02\0\
+    \x01\x12\x04\xa4\x01\x12\x14\n\r\n\x05\x04\x1b\x02\0\x03\x12\x04\xa4\x01\
+    \x17\x18\n\x0c\n\x04\x04\x1b\x02\x01\x12\x04\xa5\x01\x02\x1f\n\r\n\x05\
+    \x04\x1b\x02\x01\x04\x12\x04\xa5\x01\x02\n\n\r\n\x05\x04\x1b\x02\x01\x05\
+    \x12\x04\xa5\x01\x0b\x11\n\r\n\x05\x04\x1b\x02\x01\x01\x12\x04\xa5\x01\
+    \x12\x1a\n\r\n\x05\x04\x1b\x02\x01\x03\x12\x04\xa5\x01\x1d\x1e\n\x0c\n\
+    \x04\x04\x1b\x02\x02\x12\x04\xa6\x01\x02\x20\n\r\n\x05\x04\x1b\x02\x02\
+    \x04\x12\x04\xa6\x01\x02\n\n\r\n\x05\x04\x1b\x02\x02\x05\x12\x04\xa6\x01\
+    \x0b\x11\n\r\n\x05\x04\x1b\x02\x02\x01\x12\x04\xa6\x01\x12\x1b\n\r\n\x05\
+    \x04\x1b\x02\x02\x03\x12\x04\xa6\x01\x1e\x1f\n\x0c\n\x04\x04\x1b\x02\x03\
+    \x12\x04\xa7\x01\x02(\n\r\n\x05\x04\x1b\x02\x03\x04\x12\x04\xa7\x01\x02\
+    \n\n\r\n\x05\x04\x1b\x02\x03\x06\x12\x04\xa7\x01\x0b\x1d\n\r\n\x05\x04\
+    \x1b\x02\x03\x01\x12\x04\xa7\x01\x1e#\n\r\n\x05\x04\x1b\x02\x03\x03\x12\
+    \x04\xa7\x01&'\n\x0c\n\x04\x04\x1b\x02\x04\x12\x04\xa8\x01\x02\x1f\n\r\n\
+    \x05\x04\x1b\x02\x04\x04\x12\x04\xa8\x01\x02\n\n\r\n\x05\x04\x1b\x02\x04\
+    \x05\x12\x04\xa8\x01\x0b\x11\n\r\n\x05\x04\x1b\x02\x04\x01\x12\x04\xa8\
+    \x01\x12\x1a\n\r\n\x05\x04\x1b\x02\x04\x03\x12\x04\xa8\x01\x1d\x1e\n\x0c\
+    \n\x04\x04\x1b\x02\x05\x12\x04\xa9\x01\x02\x1f\n\r\n\x05\x04\x1b\x02\x05\
+    \x04\x12\x04\xa9\x01\x02\n\n\r\n\x05\x04\x1b\x02\x05\x05\x12\x04\xa9\x01\
+    \x0b\x11\n\r\n\x05\x04\x1b\x02\x05\x01\x12\x04\xa9\x01\x12\x1a\n\r\n\x05\
+    \x04\x1b\x02\x05\x03\x12\x04\xa9\x01\x1d\x1e\n\x0c\n\x04\x04\x1b\x02\x06\
+    \x12\x04\xaa\x01\x02'\n\r\n\x05\x04\x1b\x02\x06\x04\x12\x04\xaa\x01\x02\
+    \n\n\r\n\x05\x04\x1b\x02\x06\x06\x12\x04\xaa\x01\x0b\x1d\n\r\n\x05\x04\
+    \x1b\x02\x06\x01\x12\x04\xaa\x01\x1e\"\n\r\n\x05\x04\x1b\x02\x06\x03\x12\
+    \x04\xaa\x01%&\n\x0c\n\x04\x04\x1b\x02\x07\x12\x04\xab\x01\x02(\n\r\n\
+    \x05\x04\x1b\x02\x07\x04\x12\x04\xab\x01\x02\n\n\r\n\x05\x04\x1b\x02\x07\
+    \x06\x12\x04\xab\x01\x0b\x1d\n\r\n\x05\x04\x1b\x02\x07\x01\x12\x04\xab\
+    \x01\x1e#\n\r\n\x05\x04\x1b\x02\x07\x03\x12\x04\xab\x01&'\n\x0c\n\x04\
+    \x04\x1b\x02\x08\x12\x04\xac\x01\x02,\n\r\n\x05\x04\x1b\x02\x08\x04\x12\
+    \x04\xac\x01\x02\n\n\r\n\x05\x04\x1b\x02\x08\x05\x12\x04\xac\x01\x0b\x11\
+    \n\r\n\x05\x04\x1b\x02\x08\x01\x12\x04\xac\x01\x12\x19\n\r\n\x05\x04\x1b\
+    \x02\x08\x03\x12\x04\xac\x01\x1c\x1d\n\r\n\x05\x04\x1b\x02\x08\x08\x12\
+    \x04\xac\x01\x1e+\n\x0e\n\x06\x04\x1b\x02\x08\x08\x02\x12\x04\xac\x01\
+    \x1f*\n\x0c\n\x04\x04\x1b\x02\t\x12\x04\xad\x01\x02\x1e\n\r\n\x05\x04\
+    \x1b\x02\t\x04\x12\x04\xad\x01\x02\n\n\r\n\x05\x04\x1b\x02\t\x05\x12\x04\
+    \xad\x01\x0b\x11\n\r\n\x05\x04\x1b\x02\t\x01\x12\x04\xad\x01\x12\x18\n\r\
+    \n\x05\x04\x1b\x02\t\x03\x12\x04\xad\x01\x1b\x1d\n\x0c\n\x04\x04\x1b\x02\
+    \n\x12\x04\xae\x01\x02\x1e\n\r\n\x05\x04\x1b\x02\n\x04\x12\x04\xae\x01\
+    \x02\n\n\r\n\x05\x04\x1b\x02\n\x05\x12\x04\xae\x01\x0b\x11\n\r\n\x05\x04\
+    \x1b\x02\n\x01\x12\x04\xae\x01\x12\x18\n\r\n\x05\x04\x1b\x02\n\x03\x12\
+    \x04\xae\x01\x1b\x1d\n\x0c\n\x04\x04\x1b\x02\x0b\x12\x04\xaf\x01\x02\x1c\
+    \n\r\n\x05\x04\x1b\x02\x0b\x04\x12\x04\xaf\x01\x02\n\n\r\n\x05\x04\x1b\
+    \x02\x0b\x05\x12\x04\xaf\x01\x0b\x0f\n\r\n\x05\x04\x1b\x02\x0b\x01\x12\
+    \x04\xaf\x01\x10\x16\n\r\n\x05\x04\x1b\x02\x0b\x03\x12\x04\xaf\x01\x19\
+    \x1b\n\x0c\n\x04\x04\x1b\x02\x0c\x12\x04\xb0\x01\x02#\n\r\n\x05\x04\x1b\
+    \x02\x0c\x04\x12\x04\xb0\x01\x02\n\n\r\n\x05\x04\x1b\x02\x0c\x05\x12\x04\
+    \xb0\x01\x0b\x11\n\r\n\x05\x04\x1b\x02\x0c\x01\x12\x04\xb0\x01\x12\x1d\n\
+    \r\n\x05\x04\x1b\x02\x0c\x03\x12\x04\xb0\x01\x20\"\n\x0c\n\x04\x04\x1b\
+    \x02\r\x12\x04\xb1\x01\x02\x20\n\r\n\x05\x04\x1b\x02\r\x04\x12\x04\xb1\
+    \x01\x02\n\n\r\n\x05\x04\x1b\x02\r\x05\x12\x04\xb1\x01\x0b\x11\n\r\n\x05\
+    \x04\x1b\x02\r\x01\x12\x04\xb1\x01\x12\x1a\n\r\n\x05\x04\x1b\x02\r\x03\
+    \x12\x04\xb1\x01\x1d\x1f\n\x0c\n\x04\x04\x1b\x02\x0e\x12\x04\xb2\x01\x02\
+    $\n\r\n\x05\x04\x1b\x02\x0e\x04\x12\x04\xb2\x01\x02\n\n\r\n\x05\x04\x1b\
+    \x02\x0e\x05\x12\x04\xb2\x01\x0b\x11\n\r\n\x05\x04\x1b\x02\x0e\x01\x12\
+    \x04\xb2\x01\x12\x1e\n\r\n\x05\x04\x1b\x02\x0e\x03\x12\x04\xb2\x01!#\n\
+    \x0c\n\x04\x04\x1b\x02\x0f\x12\x04\xb3\x01\x02\"\n\r\n\x05\x04\x1b\x02\
+    \x0f\x04\x12\x04\xb3\x01\x02\n\n\r\n\x05\x04\x1b\x02\x0f\x05\x12\x04\xb3\
+    \x01\x0b\x11\n\r\n\x05\x04\x1b\x02\x0f\x01\x12\x04\xb3\x01\x12\x1c\n\r\n\
+    \x05\x04\x1b\x02\x0f\x03\x12\x04\xb3\x01\x1f!\n\x0c\n\x02\x04\x1c\x12\
+    \x06\xb6\x01\0\xbb\x01\x01\n\x0b\n\x03\x04\x1c\x01\x12\x04\xb6\x01\x08\
+    \x1a\n\x0c\n\x04\x04\x1c\x02\0\x12\x04\xb7\x01\x02\x1d\n\r\n\x05\x04\x1c\
+    \x02\0\x04\x12\x04\xb7\x01\x02\n\n\r\n\x05\x04\x1c\x02\0\x05\x12\x04\xb7\
+    \x01\x0b\x11\n\r\n\x05\x04\x1c\x02\0\x01\x12\x04\xb7\x01\x12\x18\n\r\n\
+    \x05\x04\x1c\x02\0\x03\x12\x04\xb7\x01\x1b\x1c\n\x0c\n\x04\x04\x1c\x02\
+    \x01\x12\x04\xb8\x01\x02\x1b\n\r\n\x05\x04\x1c\x02\x01\x04\x12\x04\xb8\
+    \x01\x02\n\n\r\n\x05\x04\x1c\x02\x01\x05\x12\x04\xb8\x01\x0b\x11\n\r\n\
+    \x05\x04\x1c\x02\x01\x01\x12\x04\xb8\x01\x12\x16\n\r\n\x05\x04\x1c\x02\
+    \x01\x03\x12\x04\xb8\x01\x19\x1a\n\x0c\n\x04\x04\x1c\x02\x02\x12\x04\xb9\
+    \x01\x02\x1e\n\r\n\x05\x04\x1c\x02\x02\x04\x12\x04\xb9\x01\x02\n\n\r\n\
+    \x05\x04\x1c\x02\x02\x05\x12\x04\xb9\x01\x0b\x11\n\r\n\x05\x04\x1c\x02\
+    \x02\x01\x12\x04\xb9\x01\x12\x19\n\r\n\x05\x04\x1c\x02\x02\x03\x12\x04\
+    \xb9\x01\x1c\x1d\n\x0c\n\x04\x04\x1c\x02\x03\x12\x04\xba\x01\x02\x1e\n\r\
+    \n\x05\x04\x1c\x02\x03\x04\x12\x04\xba\x01\x02\n\n\r\n\x05\x04\x1c\x02\
+    \x03\x05\x12\x04\xba\x01\x0b\x11\n\r\n\x05\x04\x1c\x02\x03\x01\x12\x04\
+    \xba\x01\x12\x19\n\r\n\x05\x04\x1c\x02\x03\x03\x12\x04\xba\x01\x1c\x1d\n\
+    \x0c\n\x02\x04\x1d\x12\x06\xbd\x01\0\xc3\x01\x01\n\x0b\n\x03\x04\x1d\x01\
+    \x12\x04\xbd\x01\x08\x1c\n\x0c\n\x04\x04\x1d\x02\0\x12\x04\xbe\x01\x02\
+    \x1d\n\r\n\x05\x04\x1d\x02\0\x04\x12\x04\xbe\x01\x02\n\n\r\n\x05\x04\x1d\
+    \x02\0\x05\x12\x04\xbe\x01\x0b\x11\n\r\n\x05\x04\x1d\x02\0\x01\x12\x04\
+    \xbe\x01\x12\x18\n\r\n\x05\x04\x1d\x02\0\x03\x12\x04\xbe\x01\x1b\x1c\n\
+    \x0c\n\x04\x04\x1d\x02\x01\x12\x04\xbf\x01\x02\x1b\n\r\n\x05\x04\x1d\x02\
+    \x01\x04\x12\x04\xbf\x01\x02\n\n\r\n\x05\x04\x1d\x02\x01\x05\x12\x04\xbf\
+    \x01\x0b\x11\n\r\n\x05\x04\x1d\x02\x01\x01\x12\x04\xbf\x01\x12\x16\n\r\n\
+    \x05\x04\x1d\x02\x01\x03\x12\x04\xbf\x01\x19\x1a\n\x0c\n\x04\x04\x1d\x02\
+    \x02\x12\x04\xc0\x01\x02\x1e\n\r\n\x05\x04\x1d\x02\x02\x04\x12\x04\xc0\
+    \x01\x02\n\n\r\n\x05\x04\x1d\x02\x02\x05\x12\x04\xc0\x01\x0b\x11\n\r\n\
+    \x05\x04\x1d\x02\x02\x01\x12\x04\xc0\x01\x12\x19\n\r\n\x05\x04\x1d\x02\
+    \x02\x03\x12\x04\xc0\x01\x1c\x1d\n\x0c\n\x04\x04\x1d\x02\x03\x12\x04\xc1\
+    \x01\x02$\n\r\n\x05\x04\x1d\x02\x03\x04\x12\x04\xc1\x01\x02\n\n\r\n\x05\
+    \x04\x1d\x02\x03\x05\x12\x04\xc1\x01\x0b\x11\n\r\n\x05\x04\x1d\x02\x03\
+    \x01\x12\x04\xc1\x01\x12\x1f\n\r\n\x05\x04\x1d\x02\x03\x03\x12\x04\xc1\
+    \x01\"#\n\x0c\n\x04\x04\x1d\x02\x04\x12\x04\xc2\x01\x02\x1d\n\r\n\x05\
+    \x04\x1d\x02\x04\x04\x12\x04\xc2\x01\x02\n\n\r\n\x05\x04\x1d\x02\x04\x05\
+    \x12\x04\xc2\x01\x0b\x11\n\r\n\x05\x04\x1d\x02\x04\x01\x12\x04\xc2\x01\
+    \x12\x18\n\r\n\x05\x04\x1d\x02\x04\x03\x12\x04\xc2\x01\x1b\x1c\n\x0c\n\
+    \x02\x04\x1e\x12\x06\xc5\x01\0\xd4\x01\x01\n\x0b\n\x03\x04\x1e\x01\x12\
+    \x04\xc5\x01\x08\x1b\n\x0c\n\x04\x04\x1e\x02\0\x12\x04\xc6\x01\x02\x1f\n\
+    \r\n\x05\x04\x1e\x02\0\x04\x12\x04\xc6\x01\x02\n\n\r\n\x05\x04\x1e\x02\0\
+    \x05\x12\x04\xc6\x01\x0b\x11\n\r\n\x05\x04\x1e\x02\0\x01\x12\x04\xc6\x01\
+    \x12\x1a\n\r\n\x05\x04\x1e\x02\0\x03\x12\x04\xc6\x01\x1d\x1e\n\x0c\n\x04\
+    \x04\x1e\x02\x01\x12\x04\xc7\x01\x02\x20\n\r\n\x05\x04\x1e\x02\x01\x04\
+    \x12\x04\xc7\x01\x02\n\n\r\n\x05\x04\x1e\x02\x01\x05\x12\x04\xc7\x01\x0b\
+    \x11\n\r\n\x05\x04\x1e\x02\x01\x01\x12\x04\xc7\x01\x12\x1b\n\r\n\x05\x04\
+    \x1e\x02\x01\x03\x12\x04\xc7\x01\x1e\x1f\n\x0c\n\x04\x04\x1e\x02\x02\x12\
+    \x04\xc8\x01\x02(\n\r\n\x05\x04\x1e\x02\x02\x04\x12\x04\xc8\x01\x02\n\n\
+    \r\n\x05\x04\x1e\x02\x02\x06\x12\x04\xc8\x01\x0b\x1d\n\r\n\x05\x04\x1e\
+    \x02\x02\x01\x12\x04\xc8\x01\x1e#\n\r\n\x05\x04\x1e\x02\x02\x03\x12\x04\
+    \xc8\x01&'\n\x0c\n\x04\x04\x1e\x02\x03\x12\x04\xc9\x01\x02\x1f\n\r\n\x05\
+    \x04\x1e\x02\x03\x04\x12\x04\xc9\x01\x02\n\n\r\n\x05\x04\x1e\x02\x03\x05\
+    \x12\x04\xc9\x01\x0b\x11\n\r\n\x05\x04\x1e\x02\x03\x01\x12\x04\xc9\x01\
+    \x12\x1a\n\r\n\x05\x04\x1e\x02\x03\x03\x12\x04\xc9\x01\x1d\x1e\n\x0c\n\
+    \x04\x04\x1e\x02\x04\x12\x04\xca\x01\x02\x1f\n\r\n\x05\x04\x1e\x02\x04\
+    \x04\x12\x04\xca\x01\x02\n\n\r\n\x05\x04\x1e\x02\x04\x05\x12\x04\xca\x01\
+    \x0b\x11\n\r\n\x05\x04\x1e\x02\x04\x01\x12\x04\xca\x01\x12\x1a\n\r\n\x05\
+    \x04\x1e\x02\x04\x03\x12\x04\xca\x01\x1d\x1e\n\x0c\n\x04\x04\x1e\x02\x05\
+    \x12\x04\xcb\x01\x02'\n\r\n\x05\x04\x1e\x02\x05\x04\x12\x04\xcb\x01\x02\
+    \n\n\r\n\x05\x04\x1e\x02\x05\x06\x12\x04\xcb\x01\x0b\x1d\n\r\n\x05\x04\
+    \x1e\x02\x05\x01\x12\x04\xcb\x01\x1e\"\n\r\n\x05\x04\x1e\x02\x05\x03\x12\
+    \x04\xcb\x01%&\n\x0c\n\x04\x04\x1e\x02\x06\x12\x04\xcc\x01\x02(\n\r\n\
+    \x05\x04\x1e\x02\x06\x04\x12\x04\xcc\x01\x02\n\n\r\n\x05\x04\x1e\x02\x06\
+    \x06\x12\x04\xcc\x01\x0b\x1d\n\r\n\x05\x04\x1e\x02\x06\x01\x12\x04\xcc\
+    \x01\x1e#\n\r\n\x05\x04\x1e\x02\x06\x03\x12\x04\xcc\x01&'\n\x0c\n\x04\
+    \x04\x1e\x02\x07\x12\x04\xcd\x01\x02,\n\r\n\x05\x04\x1e\x02\x07\x04\x12\
+    \x04\xcd\x01\x02\n\n\r\n\x05\x04\x1e\x02\x07\x05\x12\x04\xcd\x01\x0b\x11\
+    \n\r\n\x05\x04\x1e\x02\x07\x01\x12\x04\xcd\x01\x12\x19\n\r\n\x05\x04\x1e\
+    \x02\x07\x03\x12\x04\xcd\x01\x1c\x1d\n\r\n\x05\x04\x1e\x02\x07\x08\x12\
+    \x04\xcd\x01\x1e+\n\x0e\n\x06\x04\x1e\x02\x07\x08\x02\x12\x04\xcd\x01\
+    \x1f*\n\x0c\n\x04\x04\x1e\x02\x08\x12\x04\xce\x01\x02\x1d\n\r\n\x05\x04\
+    \x1e\x02\x08\x04\x12\x04\xce\x01\x02\n\n\r\n\x05\x04\x1e\x02\x08\x05\x12\
+    \x04\xce\x01\x0b\x11\n\r\n\x05\x04\x1e\x02\x08\x01\x12\x04\xce\x01\x12\
+    \x18\n\r\n\x05\x04\x1e\x02\x08\x03\x12\x04\xce\x01\x1b\x1c\n\x0c\n\x04\
+    \x04\x1e\x02\t\x12\x04\xcf\x01\x02\x1e\n\r\n\x05\x04\x1e\x02\t\x04\x12\
+    \x04\xcf\x01\x02\n\n\r\n\x05\x04\x1e\x02\t\x05\x12\x04\xcf\x01\x0b\x11\n\
+    \r\n\x05\x04\x1e\x02\t\x01\x12\x04\xcf\x01\x12\x18\n\r\n\x05\x04\x1e\x02\
+    \t\x03\x12\x04\xcf\x01\x1b\x1d\n\x0c\n\x04\x04\x1e\x02\n\x12\x04\xd0\x01\
+    \x02#\n\r\n\x05\x04\x1e\x02\n\x04\x12\x04\xd0\x01\x02\n\n\r\n\x05\x04\
+    \x1e\x02\n\x05\x12\x04\xd0\x01\x0b\x11\n\r\n\x05\x04\x1e\x02\n\x01\x12\
+    \x04\xd0\x01\x12\x1d\n\r\n\x05\x04\x1e\x02\n\x03\x12\x04\xd0\x01\x20\"\n\
+    \x0c\n\x04\x04\x1e\x02\x0b\x12\x04\xd1\x01\x02\x20\n\r\n\x05\x04\x1e\x02\
+    \x0b\x04\x12\x04\xd1\x01\x02\n\n\r\n\x05\x04\x1e\x02\x0b\x05\x12\x04\xd1\
+    \x01\x0b\x11\n\r\n\x05\x04\x1e\x02\x0b\x01\x12\x04\xd1\x01\x12\x1a\n\r\n\
+    \x05\x04\x1e\x02\x0b\x03\x12\x04\xd1\x01\x1d\x1f\n\x0c\n\x04\x04\x1e\x02\
+    \x0c\x12\x04\xd2\x01\x02$\n\r\n\x05\x04\x1e\x02\x0c\x04\x12\x04\xd2\x01\
+    \x02\n\n\r\n\x05\x04\x1e\x02\x0c\x05\x12\x04\xd2\x01\x0b\x11\n\r\n\x05\
+    \x04\x1e\x02\x0c\x01\x12\x04\xd2\x01\x12\x1e\n\r\n\x05\x04\x1e\x02\x0c\
+    \x03\x12\x04\xd2\x01!#\n\x0c\n\x04\x04\x1e\x02\r\x12\x04\xd3\x01\x02\"\n\
+    \r\n\x05\x04\x1e\x02\r\x04\x12\x04\xd3\x01\x02\n\n\r\n\x05\x04\x1e\x02\r\
+    \x05\x12\x04\xd3\x01\x0b\x11\n\r\n\x05\x04\x1e\x02\r\x01\x12\x04\xd3\x01\
+    \x12\x1c\n\r\n\x05\x04\x1e\x02\r\x03\x12\x04\xd3\x01\x1f!\n\x0c\n\x02\
+    \x04\x1f\x12\x06\xd6\x01\0\xd9\x01\x01\n\x0b\n\x03\x04\x1f\x01\x12\x04\
+    \xd6\x01\x08\x18\n\x0c\n\x04\x04\x1f\x02\0\x12\x04\xd7\x01\x02(\n\r\n\
+    \x05\x04\x1f\x02\0\x04\x12\x04\xd7\x01\x02\n\n\r\n\x05\x04\x1f\x02\0\x06\
+    \x12\x04\xd7\x01\x0b\x1d\n\r\n\x05\x04\x1f\x02\0\x01\x12\x04\xd7\x01\x1e\
+    #\n\r\n\x05\x04\x1f\x02\0\x03\x12\x04\xd7\x01&'\n\x0c\n\x04\x04\x1f\x02\
+    \x01\x12\x04\xd8\x01\x02\x1d\n\r\n\x05\x04\x1f\x02\x01\x04\x12\x04\xd8\
+    \x01\x02\n\n\r\n\x05\x04\x1f\x02\x01\x05\x12\x04\xd8\x01\x0b\x11\n\r\n\
+    \x05\x04\x1f\x02\x01\x01\x12\x04\xd8\x01\x12\x18\n\r\n\x05\x04\x1f\x02\
+    \x01\x03\x12\x04\xd8\x01\x1b\x1c\n\x0c\n\x02\x04\x20\x12\x06\xdb\x01\0\
+    \xde\x01\x01\n\x0b\n\x03\x04\x20\x01\x12\x04\xdb\x01\x08\x1e\n\x0c\n\x04\
+    \x04\x20\x02\0\x12\x04\xdc\x01\x02(\n\r\n\x05\x04\x20\x02\0\x04\x12\x04\
+    \xdc\x01\x02\n\n\r\n\x05\x04\x20\x02\0\x06\x12\x04\xdc\x01\x0b\x1d\n\r\n\
+    \x05\x04\x20\x02\0\x01\x12\x04\xdc\x01\x1e#\n\r\n\x05\x04\x20\x02\0\x03\
+    \x12\x04\xdc\x01&'\n\x0c\n\x04\x04\x20\x02\x01\x12\x04\xdd\x01\x02\x1d\n\
+    \r\n\x05\x04\x20\x02\x01\x04\x12\x04\xdd\x01\x02\n\n\r\n\x05\x04\x20\x02\
+    \x01\x05\x12\x04\xdd\x01\x0b\x11\n\r\n\x05\x04\x20\x02\x01\x01\x12\x04\
+    \xdd\x01\x12\x18\n\r\n\x05\x04\x20\x02\x01\x03\x12\x04\xdd\x01\x1b\x1c\n\
+    \x0c\n\x02\x04!\x12\x06\xe0\x01\0\xe5\x01\x01\n\x0b\n\x03\x04!\x01\x12\
+    \x04\xe0\x01\x08\x20\n\x0c\n\x04\x04!\x02\0\x12\x04\xe1\x01\x02(\n\r\n\
+    \x05\x04!\x02\0\x04\x12\x04\xe1\x01\x02\n\n\r\n\x05\x04!\x02\0\x06\x12\
+    \x04\xe1\x01\x0b\x1d\n\r\n\x05\x04!\x02\0\x01\x12\x04\xe1\x01\x1e#\n\r\n\
+    \x05\x04!\x02\0\x03\x12\x04\xe1\x01&'\n\x0c\n\x04\x04!\x02\x01\x12\x04\
+    \xe2\x01\x02\x1c\n\r\n\x05\x04!\x02\x01\x04\x12\x04\xe2\x01\x02\n\n\r\n\
+    \x05\x04!\x02\x01\x05\x12\x04\xe2\x01\x0b\x11\n\r\n\x05\x04!\x02\x01\x01\
+    \x12\x04\xe2\x01\x12\x17\n\r\n\x05\x04!\x02\x01\x03\x12\x04\xe2\x01\x1a\
+    \x1b\n\x0c\n\x04\x04!\x02\x02\x12\x04\xe3\x01\x02\x1b\n\r\n\x05\x04!\x02\
+    \x02\x04\x12\x04\xe3\x01\x02\n\n\r\n\x05\x04!\x02\x02\x05\x12\x04\xe3\
+    \x01\x0b\x11\n\r\n\x05\x04!\x02\x02\x01\x12\x04\xe3\x01\x12\x16\n\r\n\
+    \x05\x04!\x02\x02\x03\x12\x04\xe3\x01\x19\x1a\n\x0c\n\x04\x04!\x02\x03\
+    \x12\x04\xe4\x01\x02\x1d\n\r\n\x05\x04!\x02\x03\x04\x12\x04\xe4\x01\x02\
+    \n\n\r\n\x05\x04!\x02\x03\x05\x12\x04\xe4\x01\x0b\x0f\n\r\n\x05\x04!\x02\
+    \x03\x01\x12\x04\xe4\x01\x10\x18\n\r\n\x05\x04!\x02\x03\x03\x12\x04\xe4\
+    \x01\x1b\x1c\n\x0c\n\x02\x04\"\x12\x06\xe7\x01\0\xec\x01\x01\n\x0b\n\x03\
+    \x04\"\x01\x12\x04\xe7\x01\x08!\n\x0c\n\x04\x04\"\x02\0\x12\x04\xe8\x01\
+    \x02\x1c\n\r\n\x05\x04\"\x02\0\x04\x12\x04\xe8\x01\x02\n\n\r\n\x05\x04\"\
+    \x02\0\x05\x12\x04\xe8\x01\x0b\x11\n\r\n\x05\x04\"\x02\0\x01\x12\x04\xe8\
+    \x01\x12\x17\n\r\n\x05\x04\"\x02\0\x03\x12\x04\xe8\x01\x1a\x1b\n\x0c\n\
+    \x04\x04\"\x02\x01\x12\x04\xe9\x01\x02\x1b\n\r\n\x05\x04\"\x02\x01\x04\
+    \x12\x04\xe9\x01\x02\n\n\r\n\x05\x04\"\x02\x01\x05\x12\x04\xe9\x01\x0b\
+    \x11\n\r\n\x05\x04\"\x02\x01\x01\x12\x04\xe9\x01\x12\x16\n\r\n\x05\x04\"\
+    \x02\x01\x03\x12\x04\xe9\x01\x19\x1a\n\x0c\n\x04\x04\"\x02\x02\x12\x04\
+    \xea\x01\x02\x1c\n\r\n\x05\x04\"\x02\x02\x04\x12\x04\xea\x01\x02\n\n\r\n\
+    \x05\x04\"\x02\x02\x05\x12\x04\xea\x01\x0b\x11\n\r\n\x05\x04\"\x02\x02\
+    \x01\x12\x04\xea\x01\x12\x17\n\r\n\x05\x04\"\x02\x02\x03\x12\x04\xea\x01\
+    \x1a\x1b\n\x0c\n\x04\x04\"\x02\x03\x12\x04\xeb\x01\x02)\n\r\n\x05\x04\"\
+    \x02\x03\x04\x12\x04\xeb\x01\x02\n\n\r\n\x05\x04\"\x02\x03\x06\x12\x04\
+    \xeb\x01\x0b\x1d\n\r\n\x05\x04\"\x02\x03\x01\x12\x04\xeb\x01\x1e$\n\r\n\
+    \x05\x04\"\x02\x03\x03\x12\x04\xeb\x01'(\n\x0c\n\x02\x04#\x12\x06\xee\
+    \x01\0\xf2\x01\x01\n\x0b\n\x03\x04#\x01\x12\x04\xee\x01\x08\x1c\n\x0c\n\
+    \x04\x04#\x02\0\x12\x04\xef\x01\x02!\n\r\n\x05\x04#\x02\0\x04\x12\x04\
+    \xef\x01\x02\n\n\r\n\x05\x04#\x02\0\x05\x12\x04\xef\x01\x0b\x11\n\r\n\
+    \x05\x04#\x02\0\x01\x12\x04\xef\x01\x12\x1c\n\r\n\x05\x04#\x02\0\x03\x12\
+    \x04\xef\x01\x1f\x20\n\x0c\n\x04\x04#\x02\x01\x12\x04\xf0\x01\x02!\n\r\n\
+    \x05\x04#\x02\x01\x04\x12\x04\xf0\x01\x02\n\n\r\n\x05\x04#\x02\x01\x05\
+    \x12\x04\xf0\x01\x0b\x11\n\r\n\x05\x04#\x02\x01\x01\x12\x04\xf0\x01\x12\
+    \x1c\n\r\n\x05\x04#\x02\x01\x03\x12\x04\xf0\x01\x1f\x20\n\x0c\n\x04\x04#\
+    \x02\x02\x12\x04\xf1\x01\x02(\n\r\n\x05\x04#\x02\x02\x04\x12\x04\xf1\x01\
+    \x02\n\n\r\n\x05\x04#\x02\x02\x06\x12\x04\xf1\x01\x0b\x1d\n\r\n\x05\x04#\
+    \x02\x02\x01\x12\x04\xf1\x01\x1e#\n\r\n\x05\x04#\x02\x02\x03\x12\x04\xf1\
+    \x01&'\n\x0c\n\x02\x04$\x12\x06\xf4\x01\0\xf8\x01\x01\n\x0b\n\x03\x04$\
+    \x01\x12\x04\xf4\x01\x08\x1b\n\x0c\n\x04\x04$\x02\0\x12\x04\xf5\x01\x02!\
+    \n\r\n\x05\x04$\x02\0\x04\x12\x04\xf5\x01\x02\n\n\r\n\x05\x04$\x02\0\x05\
+    \x12\x04\xf5\x01\x0b\x11\n\r\n\x05\x04$\x02\0\x01\x12\x04\xf5\x01\x12\
+    \x1c\n\r\n\x05\x04$\x02\0\x03\x12\x04\xf5\x01\x1f\x20\n\x0c\n\x04\x04$\
+    \x02\x01\x12\x04\xf6\x01\x02!\n\r\n\x05\x04$\x02\x01\x04\x12\x04\xf6\x01\
+    \x02\n\n\r\n\x05\x04$\x02\x01\x05\x12\x04\xf6\x01\x0b\x11\n\r\n\x05\x04$\
+    \x02\x01\x01\x12\x04\xf6\x01\x12\x1c\n\r\n\x05\x04$\x02\x01\x03\x12\x04\
+    \xf6\x01\x1f\x20\n\x0c\n\x04\x04$\x02\x02\x12\x04\xf7\x01\x02(\n\r\n\x05\
+    \x04$\x02\x02\x04\x12\x04\xf7\x01\x02\n\n\r\n\x05\x04$\x02\x02\x06\x12\
+    \x04\xf7\x01\x0b\x1d\n\r\n\x05\x04$\x02\x02\x01\x12\x04\xf7\x01\x1e#\n\r\
+    \n\x05\x04$\x02\x02\x03\x12\x04\xf7\x01&'\n\x0c\n\x02\x04%\x12\x06\xfa\
+    \x01\0\xfe\x01\x01\n\x0b\n\x03\x04%\x01\x12\x04\xfa\x01\x08\x19\n\x0c\n\
+    \x04\x04%\x02\0\x12\x04\xfb\x01\x02!\n\r\n\x05\x04%\x02\0\x04\x12\x04\
+    \xfb\x01\x02\n\n\r\n\x05\x04%\x02\0\x05\x12\x04\xfb\x01\x0b\x11\n\r\n\
+    \x05\x04%\x02\0\x01\x12\x04\xfb\x01\x12\x1c\n\r\n\x05\x04%\x02\0\x03\x12\
+    \x04\xfb\x01\x1f\x20\n\x0c\n\x04\x04%\x02\x01\x12\x04\xfc\x01\x02(\n\r\n\
+    \x05\x04%\x02\x01\x04\x12\x04\xfc\x01\x02\n\n\r\n\x05\x04%\x02\x01\x06\
+    \x12\x04\xfc\x01\x0b\x1d\n\r\n\x05\x04%\x02\x01\x01\x12\x04\xfc\x01\x1e#\
+    \n\r\n\x05\x04%\x02\x01\x03\x12\x04\xfc\x01&'\n\x0c\n\x04\x04%\x02\x02\
+    \x12\x04\xfd\x01\x02\x1b\n\r\n\x05\x04%\x02\x02\x04\x12\x04\xfd\x01\x02\
+    \n\n\r\n\x05\x04%\x02\x02\x05\x12\x04\xfd\x01\x0b\x0f\n\r\n\x05\x04%\x02\
+    \x02\x01\x12\x04\xfd\x01\x10\x16\n\r\n\x05\x04%\x02\x02\x03\x12\x04\xfd\
+    \x01\x19\x1a\n\x0c\n\x02\x04&\x12\x06\x80\x02\0\x83\x02\x01\n\x0b\n\x03\
+    \x04&\x01\x12\x04\x80\x02\x08\x1b\n\x0c\n\x04\x04&\x02\0\x12\x04\x81\x02\
+    \x02!\n\r\n\x05\x04&\x02\0\x04\x12\x04\x81\x02\x02\n\n\r\n\x05\x04&\x02\
+    \0\x05\x12\x04\x81\x02\x0b\x11\n\r\n\x05\x04&\x02\0\x01\x12\x04\x81\x02\
+    \x12\x1c\n\r\n\x05\x04&\x02\0\x03\x12\x04\x81\x02\x1f\x20\n\x0c\n\x04\
+    \x04&\x02\x01\x12\x04\x82\x02\x02(\n\r\n\x05\x04&\x02\x01\x04\x12\x04\
+    \x82\x02\x02\n\n\r\n\x05\x04&\x02\x01\x06\x12\x04\x82\x02\x0b\x1d\n\r\n\
+    \x05\x04&\x02\x01\x01\x12\x04\x82\x02\x1e#\n\r\n\x05\x04&\x02\x01\x03\
+    \x12\x04\x82\x02&'\n\x0c\n\x02\x04'\x12\x06\x85\x02\0\x87\x02\x01\n\x0b\
+    \n\x03\x04'\x01\x12\x04\x85\x02\x08\"\n\x0c\n\x04\x04'\x02\0\x12\x04\x86\
+    \x02\x02(\n\r\n\x05\x04'\x02\0\x04\x12\x04\x86\x02\x02\n\n\r\n\x05\x04'\
+    \x02\0\x06\x12\x04\x86\x02\x0b\x1d\n\r\n\x05\x04'\x02\0\x01\x12\x04\x86\
+    \x02\x1e#\n\r\n\x05\x04'\x02\0\x03\x12\x04\x86\x02&'\n\x0c\n\x02\x04(\
+    \x12\x06\x89\x02\0\x90\x02\x01\n\x0b\n\x03\x04(\x01\x12\x04\x89\x02\x08\
+    \"\n\x0c\n\x04\x04(\x02\0\x12\x04\x8a\x02\x02\x1d\n\r\n\x05\x04(\x02\0\
+    \x04\x12\x04\x8a\x02\x02\n\n\r\n\x05\x04(\x02\0\x05\x12\x04\x8a\x02\x0b\
+    \x11\n\r\n\x05\x04(\x02\0\x01\x12\x04\x8a\x02\x12\x18\n\r\n\x05\x04(\x02\
+    \0\x03\x12\x04\x8a\x02\x1b\x1c\n\x0c\n\x04\x04(\x02\x01\x12\x04\x8b\x02\
+    \x02\x1c\n\r\n\x05\x04(\x02\x01\x04\x12\x04\x8b\x02\x02\n\n\r\n\x05\x04(\
+    \x02\x01\x05\x12\x04\x8b\x02\x0b\x11\n\r\n\x05\x04(\x02\x01\x01\x12\x04\
+    \x8b\x02\x12\x17\n\r\n\x05\x04(\x02\x01\x03\x12\x04\x8b\x02\x1a\x1b\n\
+    \x0c\n\x04\x04(\x02\x02\x12\x04\x8c\x02\x02\x1c\n\r\n\x05\x04(\x02\x02\
+    \x04\x12\x04\x8c\x02\x02\n\n\r\n\x05\x04(\x02\x02\x05\x12\x04\x8c\x02\
+    \x0b\x11\n\r\n\x05\x04(\x02\x02\x01\x12\x04\x8c\x02\x12\x17\n\r\n\x05\
+    \x04(\x02\x02\x03\x12\x04\x8c\x02\x1a\x1b\n\x0c\n\x04\x04(\x02\x03\x12\
+    \x04\x8d\x02\x02\x1b\n\r\n\x05\x04(\x02\x03\x04\x12\x04\x8d\x02\x02\n\n\
+    \r\n\x05\x04(\x02\x03\x05\x12\x04\x8d\x02\x0b\x11\n\r\n\x05\x04(\x02\x03\
+    \x01\x12\x04\x8d\x02\x12\x16\n\r\n\x05\x04(\x02\x03\x03\x12\x04\x8d\x02\
+    \x19\x1a\n\x0c\n\x04\x04(\x02\x04\x12\x04\x8e\x02\x02\x1d\n\r\n\x05\x04(\
+    \x02\x04\x04\x12\x04\x8e\x02\x02\n\n\r\n\x05\x04(\x02\x04\x05\x12\x04\
+    \x8e\x02\x0b\x0f\n\r\n\x05\x04(\x02\x04\x01\x12\x04\x8e\x02\x10\x18\n\r\
+    \n\x05\x04(\x02\x04\x03\x12\x04\x8e\x02\x1b\x1c\n\x0c\n\x04\x04(\x02\x05\
+    \x12\x04\x8f\x02\x02\x1e\n\r\n\x05\x04(\x02\x05\x04\x12\x04\x8f\x02\x02\
+    \n\n\r\n\x05\x04(\x02\x05\x05\x12\x04\x8f\x02\x0b\x11\n\r\n\x05\x04(\x02\
+    \x05\x01\x12\x04\x8f\x02\x12\x19\n\r\n\x05\x04(\x02\x05\x03\x12\x04\x8f\
+    \x02\x1c\x1d\n\x0c\n\x02\x04)\x12\x06\x92\x02\0\x96\x02\x01\n\x0b\n\x03\
+    \x04)\x01\x12\x04\x92\x02\x08&\n\x0c\n\x04\x04)\x02\0\x12\x04\x93\x02\
+    \x02\x1d\n\r\n\x05\x04)\x02\0\x04\x12\x04\x93\x02\x02\n\n\r\n\x05\x04)\
+    \x02\0\x05\x12\x04\x93\x02\x0b\x11\n\r\n\x05\x04)\x02\0\x01\x12\x04\x93\
+    \x02\x12\x18\n\r\n\x05\x04)\x02\0\x03\x12\x04\x93\x02\x1b\x1c\n\x0c\n\
+    \x04\x04)\x02\x01\x12\x04\x94\x02\x02\x1c\n\r\n\x05\x04)\x02\x01\x04\x12\
+    \x04\x94\x02\x02\n\n\r\n\x05\x04)\x02\x01\x05\x12\x04\x94\x02\x0b\x11\n\
+    \r\n\x05\x04)\x02\x01\x01\x12\x04\x94\x02\x12\x17\n\r\n\x05\x04)\x02\x01\
+    \x03\x12\x04\x94\x02\x1a\x1b\n\x0c\n\x04\x04)\x02\x02\x12\x04\x95\x02\
+    \x02\x1b\n\r\n\x05\x04)\x02\x02\x04\x12\x04\x95\x02\x02\n\n\r\n\x05\x04)\
+    \x02\x02\x05\x12\x04\x95\x02\x0b\x11\n\r\n\x05\x04)\x02\x02\x01\x12\x04\
+    \x95\x02\x12\x16\n\r\n\x05\x04)\x02\x02\x03\x12\x04\x95\x02\x19\x1a\n\
+    \x0c\n\x02\x04*\x12\x06\x98\x02\0\x9d\x02\x01\n\x0b\n\x03\x04*\x01\x12\
+    \x04\x98\x02\x08'\n\x0c\n\x04\x04*\x02\0\x12\x04\x99\x02\x02\x1c\n\r\n\
+    \x05\x04*\x02\0\x04\x12\x04\x99\x02\x02\n\n\r\n\x05\x04*\x02\0\x05\x12\
+    \x04\x99\x02\x0b\x11\n\r\n\x05\x04*\x02\0\x01\x12\x04\x99\x02\x12\x17\n\
+    \r\n\x05\x04*\x02\0\x03\x12\x04\x99\x02\x1a\x1b\n\x0c\n\x04\x04*\x02\x01\
+    \x12\x04\x9a\x02\x02\x1b\n\r\n\x05\x04*\x02\x01\x04\x12\x04\x9a\x02\x02\
+    \n\n\r\n\x05\x04*\x02\x01\x05\x12\x04\x9a\x02\x0b\x11\n\r\n\x05\x04*\x02\
+    \x01\x01\x12\x04\x9a\x02\x12\x16\n\r\n\x05\x04*\x02\x01\x03\x12\x04\x9a\
+    \x02\x19\x1a\n\x0c\n\x04\x04*\x02\x02\x12\x04\x9b\x02\x02\x1c\n\r\n\x05\
+    \x04*\x02\x02\x04\x12\x04\x9b\x02\x02\n\n\r\n\x05\x04*\x02\x02\x05\x12\
+    \x04\x9b\x02\x0b\x11\n\r\n\x05\x04*\x02\x02\x01\x12\x04\x9b\x02\x12\x17\
+    \n\r\n\x05\x04*\x02\x02\x03\x12\x04\x9b\x02\x1a\x1b\n\x0c\n\x04\x04*\x02\
+    \x03\x12\x04\x9c\x02\x02)\n\r\n\x05\x04*\x02\x03\x04\x12\x04\x9c\x02\x02\
+    \n\n\r\n\x05\x04*\x02\x03\x06\x12\x04\x9c\x02\x0b\x1d\n\r\n\x05\x04*\x02\
+    \x03\x01\x12\x04\x9c\x02\x1e$\n\r\n\x05\x04*\x02\x03\x03\x12\x04\x9c\x02\
+    '(\n\x0c\n\x02\x04+\x12\x06\x9f\x02\0\xa2\x02\x01\n\x0b\n\x03\x04+\x01\
+    \x12\x04\x9f\x02\x08'\n\x0c\n\x04\x04+\x02\0\x12\x04\xa0\x02\x02\x1d\n\r\
+    \n\x05\x04+\x02\0\x04\x12\x04\xa0\x02\x02\n\n\r\n\x05\x04+\x02\0\x05\x12\
+    \x04\xa0\x02\x0b\x11\n\r\n\x05\x04+\x02\0\x01\x12\x04\xa0\x02\x12\x18\n\
+    \r\n\x05\x04+\x02\0\x03\x12\x04\xa0\x02\x1b\x1c\n\x0c\n\x04\x04+\x02\x01\
+    \x12\x04\xa1\x02\x02\x1b\n\r\n\x05\x04+\x02\x01\x04\x12\x04\xa1\x02\x02\
+    \n\n\r\n\x05\x04+\x02\x01\x05\x12\x04\xa1\x02\x0b\x11\n\r\n\x05\x04+\x02\
+    \x01\x01\x12\x04\xa1\x02\x12\x16\n\r\n\x05\x04+\x02\x01\x03\x12\x04\xa1\
+    \x02\x19\x1a\n\x0c\n\x02\x04,\x12\x06\xa4\x02\0\xa6\x02\x01\n\x0b\n\x03\
+    \x04,\x01\x12\x04\xa4\x02\x08(\n\x0c\n\x04\x04,\x02\0\x12\x04\xa5\x02\
+    \x02-\n\r\n\x05\x04,\x02\0\x04\x12\x04\xa5\x02\x02\n\n\r\n\x05\x04,\x02\
+    \0\x06\x12\x04\xa5\x02\x0b\x1f\n\r\n\x05\x04,\x02\0\x01\x12\x04\xa5\x02\
+    \x20(\n\r\n\x05\x04,\x02\0\x03\x12\x04\xa5\x02+,\n\x1e\n\x02\x04-\x12\
+    \x06\xa9\x02\0\xb5\x02\x01\x1a\x10\x20Origin\x20Project\n\n\x0b\n\x03\
+    \x04-\x01\x12\x04\xa9\x02\x08\x15\n\x0c\n\x04\x04-\x02\0\x12\x04\xaa\x02\
+    \x02\x19\n\r\n\x05\x04-\x02\0\x04\x12\x04\xaa\x02\x02\n\n\r\n\x05\x04-\
+    \x02\0\x05\x12\x04\xaa\x02\x0b\x11\n\r\n\x05\x04-\x02\0\x01\x12\x04\xaa\
+    \x02\x12\x14\n\r\n\x05\x04-\x02\0\x03\x12\x04\xaa\x02\x17\x18\n\x0c\n\
+    \x04\x04-\x02\x01\x12\x04\xab\x02\x02\x20\n\r\n\x05\x04-\x02\x01\x04\x12\
+    \x04\xab\x02\x02\n\n\r\n\x05\x04-\x02\x01\x05\x12\x04\xab\x02\x0b\x11\n\
+    \r\n\x05\x04-\x02\x01\x01\x12\x04\xab\x02\x12\x1b\n\r\n\x05\x04-\x02\x01\
+    \x03\x12\x04\xab\x02\x1e\x1f\n\x0c\n\x04\x04-\x02\x02\x12\x04\xac\x02\
+    \x02\"\n\r\n\x05\x04-\x02\x02\x04\x12\x04\xac\x02\x02\n\n\r\n\x05\x04-\
+    \x02\x02\x05\x12\x04\xac\x02\x0b\x11\n\r\n\x05\x04-\x02\x02\x01\x12\x04\
+    \xac\x02\x12\x1d\n\r\n\x05\x04-\x02\x02\x03\x12\x04\xac\x02\x20!\n\x0c\n\
+    \x04\x04-\x02\x03\x12\x04\xad\x02\x02#\n\r\n\x05\x04-\x02\x03\x04\x12\
+    \x04\xad\x02\x02\n\n\r\n\x05\x04-\x02\x03\x05\x12\x04\xad\x02\x0b\x11\n\
+    \r\n\x05\x04-\x02\x03\x01\x12\x04\xad\x02\x12\x1e\n\r\n\x05\x04-\x02\x03\
+    \x03\x12\x04\xad\x02!\"\n\x0c\n\x04\x04-\x02\x04\x12\x04\xae\x02\x02\x1b\
+    \n\r\n\x05\x04-\x02\x04\x04\x12\x04\xae\x02\x02\n\n\r\n\x05\x04-\x02\x04\
+    \x05\x12\x04\xae\x02\x0b\x11\n\r\n\x05\x04-\x02\x04\x01\x12\x04\xae\x02\
+    \x12\x16\n\r\n\x05\x04-\x02\x04\x03\x12\x04\xae\x02\x19\x1a\n\x0c\n\x04\
+    \x04-\x02\x05\x12\x04\xaf\x02\x02\x20\n\r\n\x05\x04-\x02\x05\x04\x12\x04\
+    \xaf\x02\x02\n\n\r\n\x05\x04-\x02\x05\x05\x12\x04\xaf\x02\x0b\x11\n\r\n\
+    \x05\x04-\x02\x05\x01\x12\x04\xaf\x02\x12\x1b\n\r\n\x05\x04-\x02\x05\x03\
+    \x12\x04\xaf\x02\x1e\x1f\n\x0c\n\x04\x04-\x02\x06\x12\x04\xb0\x02\x02\
+    \x1f\n\r\n\x05\x04-\x02\x06\x04\x12\x04\xb0\x02\x02\n\n\r\n\x05\x04-\x02\
+    \x06\x05\x12\x04\xb0\x02\x0b\x11\n\r\n\x05\x04-\x02\x06\x01\x12\x04\xb0\
+    \x02\x12\x1a\n\r\n\x05\x04-\x02\x06\x03\x12\x04\xb0\x02\x1d\x1e\n\x0c\n\
+    \x04\x04-\x02\x07\x12\x04\xb1\x02\x02\x1f\n\r\n\x05\x04-\x02\x07\x04\x12\
+    \x04\xb1\x02\x02\n\n\r\n\x05\x04-\x02\x07\x05\x12\x04\xb1\x02\x0b\x11\n\
+    \r\n\x05\x04-\x02\x07\x01\x12\x04\xb1\x02\x12\x1a\n\r\n\x05\x04-\x02\x07\
+    \x03\x12\x04\xb1\x02\x1d\x1e\n\x0c\n\x04\x04-\x02\x08\x12\x04\xb2\x02\
+    \x02\x1f\n\r\n\x05\x04-\x02\x08\x04\x12\x04\xb2\x02\x02\n\n\r\n\x05\x04-\
+    \x02\x08\x05\x12\x04\xb2\x02\x0b\x11\n\r\n\x05\x04-\x02\x08\x01\x12\x04\
+    \xb2\x02\x12\x1a\n\r\n\x05\x04-\x02\x08\x03\x12\x04\xb2\x02\x1d\x1e\n\
+    \x0c\n\x04\x04-\x02\t\x12\x04\xb3\x02\x02%\n\r\n\x05\x04-\x02\t\x04\x12\
+    \x04\xb3\x02\x02\n\n\r\n\x05\x04-\x02\t\x05\x12\x04\xb3\x02\x0b\x11\n\r\
+    \n\x05\x04-\x02\t\x01\x12\x04\xb3\x02\x12\x1f\n\r\n\x05\x04-\x02\t\x03\
+    \x12\x04\xb3\x02\"$\n\x0c\n\x04\x04-\x02\n\x12\x04\xb4\x02\x02&\n\r\n\
+    \x05\x04-\x02\n\x04\x12\x04\xb4\x02\x02\n\n\r\n\x05\x04-\x02\n\x05\x12\
+    \x04\xb4\x02\x0b\x11\n\r\n\x05\x04-\x02\n\x01\x12\x04\xb4\x02\x12\x20\n\
+    \r\n\x05\x04-\x02\n\x03\x12\x04\xb4\x02#%\n\x0c\n\x02\x04.\x12\x06\xb7\
+    \x02\0\xb9\x02\x01\n\x0b\n\x03\x04.\x01\x12\x04\xb7\x02\x08\x1b\n\x0c\n\
+    \x04\x04.\x02\0\x12\x04\xb8\x02\x02%\n\r\n\x05\x04.\x02\0\x04\x12\x04\
+    \xb8\x02\x02\n\n\r\n\x05\x04.\x02\0\x06\x12\x04\xb8\x02\x0b\x18\n\r\n\
+    \x05\x04.\x02\0\x01\x12\x04\xb8\x02\x19\x20\n\r\n\x05\x04.\x02\0\x03\x12\
+    \x04\xb8\x02#$\n\x0c\n\x02\x04/\x12\x06\xbb\x02\0\xbe\x02\x01\n\x0b\n\
+    \x03\x04/\x01\x12\x04\xbb\x02\x08\x1b\n\x0c\n\x04\x04/\x02\0\x12\x04\xbc\
+    \x02\x02\x1b\n\r\n\x05\x04/\x02\0\x04\x12\x04\xbc\x02\x02\n\n\r\n\x05\
+    \x04/\x02\0\x05\x12\x04\xbc\x02\x0b\x11\n\r\n\x05\x04/\x02\0\x01\x12\x04\
+    \xbc\x02\x12\x16\n\r\n\x05\x04/\x02\0\x03\x12\x04\xbc\x02\x19\x1a\n\x0c\
+    \n\x04\x04/\x02\x01\x12\x04\xbd\x02\x02#\n\r\n\x05\x04/\x02\x01\x04\x12\
+    \x04\xbd\x02\x02\n\n\r\n\x05\x04/\x02\x01\x05\x12\x04\xbd\x02\x0b\x11\n\
+    \r\n\x05\x04/\x02\x01\x01\x12\x04\xbd\x02\x12\x1e\n\r\n\x05\x04/\x02\x01\
+    \x03\x12\x04\xbd\x02!\"\n\x0c\n\x02\x040\x12\x06\xc0\x02\0\xc2\x02\x01\n\
+    \x0b\n\x03\x040\x01\x12\x04\xc0\x02\x08\x18\n\x0c\n\x04\x040\x02\0\x12\
+    \x04\xc1\x02\x02\x1b\n\r\n\x05\x040\x02\0\x04\x12\x04\xc1\x02\x02\n\n\r\
+    \n\x05\x040\x02\0\x05\x12\x04\xc1\x02\x0b\x11\n\r\n\x05\x040\x02\0\x01\
+    \x12\x04\xc1\x02\x12\x16\n\r\n\x05\x040\x02\0\x03\x12\x04\xc1\x02\x19\
+    \x1a\n\x0c\n\x02\x041\x12\x06\xc4\x02\0\xc7\x02\x01\n\x0b\n\x03\x041\x01\
+    \x12\x04\xc4\x02\x08\x1b\n\x0c\n\x04\x041\x02\0\x12\x04\xc5\x02\x02#\n\r\
+    \n\x05\x041\x02\0\x04\x12\x04\xc5\x02\x02\n\n\r\n\x05\x041\x02\0\x05\x12\
+    \x04\xc5\x02\x0b\x11\n\r\n\x05\x041\x02\0\x01\x12\x04\xc5\x02\x12\x1e\n\
+    \r\n\x05\x041\x02\0\x03\x12\x04\xc5\x02!\"\n\x0c\n\x04\x041\x02\x01\x12\
+    \x04\xc6\x02\x02%\n\r\n\x05\x041\x02\x01\x04\x12\x04\xc6\x02\x02\n\n\r\n\
+    \x05\x041\x02\x01\x06\x12\x04\xc6\x02\x0b\x18\n\r\n\x05\x041\x02\x01\x01\
+    \x12\x04\xc6\x02\x19\x20\n\r\n\x05\x041\x02\x01\x03\x12\x04\xc6\x02#$\nb\
+    \n\x02\x042\x12\x06\xca\x02\0\xcd\x02\x01\x1aT\x20Lists\x20the\x20projec\
+    ts\x20in\x20an\x20origin\x20which\x20are\x20built\x20from\x20any\x20of\
+    \x20the\x20given\x20repositories\n\n\x0b\n\x03\x042\x01\x12\x04\xca\x02\
+    \x08\x20\n\x0c\n\x04\x042\x02\0\x12\x04\xcb\x02\x02\x1d\n\r\n\x05\x042\
+    \x02\0\x04\x12\x04\xcb\x02\x02\n\n\r\n\x05\x042\x02\0\x05\x12\x04\xcb\
+    \x02\x0b\x11\n\r\n\x05\x042\x02\0\x01\x12\x04\xcb\x02\x12\x18\n\r\n\x05\
+    \x042\x02\0\x03\x12\x04\xcb\x02\x1b\x1c\n\x0c\n\x04\x042\x02\x01\x12\x04\
+    \xcc\x02\x02\x1f\n\r\n\x05\x042\x02\x01\x04\x12\x04\xcc\x02\x02\n\n\r\n\
+    \x05\x042\x02\x01\x05\x12\x04\xcc\x02\x0b\x11\n\r\n\x05\x042\x02\x01\x01\
+    \x12\x04\xcc\x02\x12\x1a\n\r\n\x05\x042\x02\x01\x03\x12\x04\xcc\x02\x1d\
+    \x1e\n\x0c\n\x02\x043\x12\x06\xcf\x02\0\xd2\x02\x01\n\x0b\n\x03\x043\x01\
+    \x12\x04\xcf\x02\x08!\n\x0c\n\x04\x043\x02\0\x12\x04\xd0\x02\x02\x20\n\r\
+    \n\x05\x043\x02\0\x04\x12\x04\xd0\x02\x02\n\n\r\n\x05\x043\x02\0\x05\x12\
+    \x04\xd0\x02\x0b\x11\n\r\n\x05\x043\x02\0\x01\x12\x04\xd0\x02\x12\x1b\n\
+    \r\n\x05\x043\x02\0\x03\x12\x04\xd0\x02\x1e\x1f\n\x0c\n\x04\x043\x02\x01\
+    \x12\x04\xd1\x02\x02&\n\r\n\x05\x043\x02\x01\x04\x12\x04\xd1\x02\x02\n\n\
+    \r\n\x05\x043\x02\x01\x06\x12\x04\xd1\x02\x0b\x18\n\r\n\x05\x043\x02\x01\
+    \x01\x12\x04\xd1\x02\x19!\n\r\n\x05\x043\x02\x01\x03\x12\x04\xd1\x02$%\n\
+    !\n\x02\x044\x12\x06\xd5\x02\0\xdc\x02\x01\x1a\x13\x20Origin\x20Public\
+    \x20Key\n\n\x0b\n\x03\x044\x01\x12\x04\xd5\x02\x08\x17\n\x0c\n\x04\x044\
+    \x02\0\x12\x04\xd6\x02\x02\x19\n\r\n\x05\x044\x02\0\x04\x12\x04\xd6\x02\
+    \x02\n\n\r\n\x05\x044\x02\0\x05\x12\x04\xd6\x02\x0b\x11\n\r\n\x05\x044\
+    \x02\0\x01\x12\x04\xd6\x02\x12\x14\n\r\n\x05\x044\x02\0\x03\x12\x04\xd6\
+    \x02\x17\x18\n\x0c\n\x04\x044\x02\x01\x12\x04\xd7\x02\x02\x20\n\r\n\x05\
+    \x044\x02\x01\x04\x12\x04\xd7\x02\x02\n\n\r\n\x05\x044\x02\x01\x05\x12\
+    \x04\xd7\x02\x0b\x11\n\r\n\x05\x044\x02\x01\x01\x12\x04\xd7\x02\x12\x1b\
+    \n\r\n\x05\x044\x02\x01\x03\x12\x04\xd7\x02\x1e\x1f\n\x0c\n\x04\x044\x02\
+    \x02\x12\x04\xd8\x02\x02\x1b\n\r\n\x05\x044\x02\x02\x04\x12\x04\xd8\x02\
+    \x02\n\n\r\n\x05\x044\x02\x02\x05\x12\x04\xd8\x02\x0b\x11\n\r\n\x05\x044\
+    \x02\x02\x01\x12\x04\xd8\x02\x12\x16\n\r\n\x05\x044\x02\x02\x03\x12\x04\
+    \xd8\x02\x19\x1a\n\x0c\n\x04\x044\x02\x03\x12\x04\xd9\x02\x02\x1f\n\r\n\
+    \x05\x044\x02\x03\x04\x12\x04\xd9\x02\x02\n\n\r\n\x05\x044\x02\x03\x05\
+    \x12\x04\xd9\x02\x0b\x11\n\r\n\x05\x044\x02\x03\x01\x12\x04\xd9\x02\x12\
+    \x1a\n\r\n\x05\x044\x02\x03\x03\x12\x04\xd9\x02\x1d\x1e\n\x0c\n\x04\x044\
+    \x02\x04\x12\x04\xda\x02\x02\x1a\n\r\n\x05\x044\x02\x04\x04\x12\x04\xda\
+    \x02\x02\n\n\r\n\x05\x044\x02\x04\x05\x12\x04\xda\x02\x0b\x10\n\r\n\x05\
+    \x044\x02\x04\x01\x12\x04\xda\x02\x11\x15\n\r\n\x05\x044\x02\x04\x03\x12\
+    \x04\xda\x02\x18\x19\n\x0c\n\x04\x044\x02\x05\x12\x04\xdb\x02\x02\x1f\n\
+    \r\n\x05\x044\x02\x05\x04\x12\x04\xdb\x02\x02\n\n\r\n\x05\x044\x02\x05\
+    \x05\x12\x04\xdb\x02\x0b\x11\n\r\n\x05\x044\x02\x05\x01\x12\x04\xdb\x02\
+    \x12\x1a\n\r\n\x05\x044\x02\x05\x03\x12\x04\xdb\x02\x1d\x1e\n\x0c\n\x02\
+    \x045\x12\x06\xde\x02\0\xe4\x02\x01\n\x0b\n\x03\x045\x01\x12\x04\xde\x02\
+    \x08\x1d\n\x0c\n\x04\x045\x02\0\x12\x04\xdf\x02\x02\x20\n\r\n\x05\x045\
+    \x02\0\x04\x12\x04\xdf\x02\x02\n\n\r\n\x05\x045\x02\0\x05\x12\x04\xdf\
+    \x02\x0b\x11\n\r\n\x05\x045\x02\0\x01\x12\x04\xdf\x02\x12\x1b\n\r\n\x05\
+    \x045\x02\0\x03\x12\x04\xdf\x02\x1e\x1f\n\x0c\n\x04\x045\x02\x01\x12\x04\
+    \xe0\x02\x02\x1b\n\r\n\x05\x045\x02\x01\x04\x12\x04\xe0\x02\x02\n\n\r\n\
+    \x05\x045\x02\x01\x05\x12\x04\xe0\x02\x0b\x11\n\r\n\x05\x045\x02\x01\x01\
+    \x12\x04\xe0\x02\x12\x16\n\r\n\x05\x045\x02\x01\x03\x12\x04\xe0\x02\x19\
+    \x1a\n\x0c\n\x04\x045\x02\x02\x12\x04\xe1\x02\x02\x1f\n\r\n\x05\x045\x02\
+    \x02\x04\x12\x04\xe1\x02\x02\n\n\r\n\x05\x045\x02\x02\x05\x12\x04\xe1\
+    \x02\x0b\x11\n\r\n\x05\x045\x02\x02\x01\x12\x04\xe1\x02\x12\x1a\n\r\n\
+    \x05\x045\x02\x02\x03\x12\x04\xe1\x02\x1d\x1e\n\x0c\n\x04\x045\x02\x03\
+    \x12\x04\xe2\x02\x02\x1a\n\r\n\x05\x045\x02\x03\x04\x12\x04\xe2\x02\x02\
+    \n\n\r\n\x05\x045\x02\x03\x05\x12\x04\xe2\x02\x0b\x10\n\r\n\x05\x045\x02\
+    \x03\x01\x12\x04\xe2\x02\x11\x15\n\r\n\x05\x045\x02\x03\x03\x12\x04\xe2\
+    \x02\x18\x19\n\x0c\n\x04\x045\x02\x04\x12\x04\xe3\x02\x02\x1f\n\r\n\x05\
+    \x045\x02\x04\x04\x12\x04\xe3\x02\x02\n\n\r\n\x05\x045\x02\x04\x05\x12\
+    \x04\xe3\x02\x0b\x11\n\r\n\x05\x045\x02\x04\x01\x12\x04\xe3\x02\x12\x1a\
+    \n\r\n\x05\x045\x02\x04\x03\x12\x04\xe3\x02\x1d\x1e\n\x0c\n\x02\x046\x12\
+    \x06\xe6\x02\0\xea\x02\x01\n\x0b\n\x03\x046\x01\x12\x04\xe6\x02\x08\x1a\
+    \n\x0c\n\x04\x046\x02\0\x12\x04\xe7\x02\x02\x1f\n\r\n\x05\x046\x02\0\x04\
+    \x12\x04\xe7\x02\x02\n\n\r\n\x05\x046\x02\0\x05\x12\x04\xe7\x02\x0b\x11\
+    \n\r\n\x05\x046\x02\0\x01\x12\x04\xe7\x02\x12\x1a\n\r\n\x05\x046\x02\0\
+    \x03\x12\x04\xe7\x02\x1d\x1e\n\x0c\n\x04\x046\x02\x01\x12\x04\xe8\x02\
+    \x02\x1d\n\r\n\x05\x046\x02\x01\x04\x12\x04\xe8\x02\x02\n\n\r\n\x05\x046\
+    \x02\x01\x05\x12\x04\xe8\x02\x0b\x11\n\r\n\x05\x046\x02\x01\x01\x12\x04\
+    \xe8\x02\x12\x18\n\r\n\x05\x046\x02\x01\x03\x12\x04\xe8\x02\x1b\x1c\n\
+    \x0c\n\x04\x046\x02\x02\x12\x04\xe9\x02\x02\x1f\n\r\n\x05\x046\x02\x02\
+    \x04\x12\x04\xe9\x02\x02\n\n\r\n\x05\x046\x02\x02\x05\x12\x04\xe9\x02\
+    \x0b\x11\n\r\n\x05\x046\x02\x02\x01\x12\x04\xe9\x02\x12\x1a\n\r\n\x05\
+    \x046\x02\x02\x03\x12\x04\xe9\x02\x1d\x1e\n\x0c\n\x02\x047\x12\x06\xec\
+    \x02\0\xef\x02\x01\n\x0b\n\x03\x047\x01\x12\x04\xec\x02\x08\x20\n\x0c\n\
+    \x04\x047\x02\0\x12\x04\xed\x02\x02\x1f\n\r\n\x05\x047\x02\0\x04\x12\x04\
+    \xed\x02\x02\n\n\r\n\x05\x047\x02\0\x05\x12\x04\xed\x02\x0b\x11\n\r\n\
+    \x05\x047\x02\0\x01\x12\x04\xed\x02\x12\x1a\n\r\n\x05\x047\x02\0\x03\x12\
+    \x04\xed\x02\x1d\x1e\n\x0c\n\x04\x047\x02\x01\x12\x04\xee\x02\x02\x1d\n\
+    \r\n\x05\x047\x02\x01\x04\x12\x04\xee\x02\x02\n\n\r\n\x05\x047\x02\x01\
+    \x05\x12\x04\xee\x02\x0b\x11\n\r\n\x05\x047\x02\x01\x01\x12\x04\xee\x02\
+    \x12\x18\n\r\n\x05\x047\x02\x01\x03\x12\x04\xee\x02\x1b\x1c\n\x0c\n\x02\
+    \x048\x12\x06\xf1\x02\0\xf4\x02\x01\n\x0b\n\x03\x048\x01\x12\x04\xf1\x02\
+    \x08\"\n\x0c\n\x04\x048\x02\0\x12\x04\xf2\x02\x02\x1f\n\r\n\x05\x048\x02\
+    \0\x04\x12\x04\xf2\x02\x02\n\n\r\n\x05\x048\x02\0\x05\x12\x04\xf2\x02\
+    \x0b\x11\n\r\n\x05\x048\x02\0\x01\x12\x04\xf2\x02\x12\x1a\n\r\n\x05\x048\
+    \x02\0\x03\x12\x04\xf2\x02\x1d\x1e\n\x0c\n\x04\x048\x02\x01\x12\x04\xf3\
+    \x02\x02\x20\n\r\n\x05\x048\x02\x01\x04\x12\x04\xf3\x02\x02\n\n\r\n\x05\
+    \x048\x02\x01\x05\x12\x04\xf3\x02\x0b\x11\n\r\n\x05\x048\x02\x01\x01\x12\
+    \x04\xf3\x02\x12\x1b\n\r\n\x05\x048\x02\x01\x03\x12\x04\xf3\x02\x1e\x1f\
+    \n\x0c\n\x02\x049\x12\x06\xf6\x02\0\xf9\x02\x01\n\x0b\n\x03\x049\x01\x12\
+    \x04\xf6\x02\x08#\n\x0c\n\x04\x049\x02\0\x12\x04\xf7\x02\x02\x20\n\r\n\
+    \x05\x049\x02\0\x04\x12\x04\xf7\x02\x02\n\n\r\n\x05\x049\x02\0\x05\x12\
+    \x04\xf7\x02\x0b\x11\n\r\n\x05\x049\x02\0\x01\x12\x04\xf7\x02\x12\x1b\n\
+    \r\n\x05\x049\x02\0\x03\x12\x04\xf7\x02\x1e\x1f\n\x0c\n\x04\x049\x02\x01\
+    \x12\x04\xf8\x02\x02$\n\r\n\x05\x049\x02\x01\x04\x12\x04\xf8\x02\x02\n\n\
+    \r\n\x05\x049\x02\x01\x06\x12\x04\xf8\x02\x0b\x1a\n\r\n\x05\x049\x02\x01\
+    \x01\x12\x04\xf8\x02\x1b\x1f\n\r\n\x05\x049\x02\x01\x03\x12\x04\xf8\x02\
+    \"#\n!\n\x02\x04:\x12\x06\xfc\x02\0\x83\x03\x01\x1a\x13\x20Origin\x20Sec\
+    ret\x20Key\n\n\x0b\n\x03\x04:\x01\x12\x04\xfc\x02\x08\x17\n\x0c\n\x04\
+    \x04:\x02\0\x12\x04\xfd\x02\x02\x19\n\r\n\x05\x04:\x02\0\x04\x12\x04\xfd\
+    \x02\x02\n\n\r\n\x05\x04:\x02\0\x05\x12\x04\xfd\x02\x0b\x11\n\r\n\x05\
+    \x04:\x02\0\x01\x12\x04\xfd\x02\x12\x14\n\r\n\x05\x04:\x02\0\x03\x12\x04\
+    \xfd\x02\x17\x18\n\x0c\n\x04\x04:\x02\x01\x12\x04\xfe\x02\x02\x20\n\r\n\
+    \x05\x04:\x02\x01\x04\x12\x04\xfe\x02\x02\n\n\r\n\x05\x04:\x02\x01\x05\
+    \x12\x04\xfe\x02\x0b\x11\n\r\n\x05\x04:\x02\x01\x01\x12\x04\xfe\x02\x12\
+    \x1b\n\r\n\x05\x04:\x02\x01\x03\x12\x04\xfe\x02\x1e\x1f\n\x0c\n\x04\x04:\
+    \x02\x02\x12\x04\xff\x02\x02\x1b\n\r\n\x05\x04:\x02\x02\x04\x12\x04\xff\
+    \x02\x02\n\n\r\n\x05\x04:\x02\x02\x05\x12\x04\xff\x02\x0b\x11\n\r\n\x05\
+    \x04:\x02\x02\x01\x12\x04\xff\x02\x12\x16\n\r\n\x05\x04:\x02\x02\x03\x12\
+    \x04\xff\x02\x19\x1a\n\x0c\n\x04\x04:\x02\x03\x12\x04\x80\x03\x02\x1f\n\
+    \r\n\x05\x04:\x02\x03\x04\x12\x04\x80\x03\x02\n\n\r\n\x05\x04:\x02\x03\
+    \x05\x12\x04\x80\x03\x0b\x11\n\r\n\x05\x04:\x02\x03\x01\x12\x04\x80\x03\
+    \x12\x1a\n\r\n\x05\x04:\x02\x03\x03\x12\x04\x80\x03\x1d\x1e\n\x0c\n\x04\
+    \x04:\x02\x04\x12\x04\x81\x03\x02\x1a\n\r\n\x05\x04:\x02\x04\x04\x12\x04\
+    \x81\x03\x02\n\n\r\n\x05\x04:\x02\x04\x05\x12\x04\x81\x03\x0b\x10\n\r\n\
+    \x05\x04:\x02\x04\x01\x12\x04\x81\x03\x11\x15\n\r\n\x05\x04:\x02\x04\x03\
+    \x12\x04\x81\x03\x18\x19\n\x0c\n\x04\x04:\x02\x05\x12\x04\x82\x03\x02\
+    \x1f\n\r\n\x05\x04:\x02\x05\x04\x12\x04\x82\x03\x02\n\n\r\n\x05\x04:\x02\
+    \x05\x05\x12\x04\x82\x03\x0b\x11\n\r\n\x05\x04:\x02\x05\x01\x12\x04\x82\
+    \x03\x12\x1a\n\r\n\x05\x04:\x02\x05\x03\x12\x04\x82\x03\x1d\x1e\n\x0c\n\
+    \x02\x04;\x12\x06\x85\x03\0\x8b\x03\x01\n\x0b\n\x03\x04;\x01\x12\x04\x85\
+    \x03\x08\x1d\n\x0c\n\x04\x04;\x02\0\x12\x04\x86\x03\x02\x20\n\r\n\x05\
+    \x04;\x02\0\x04\x12\x04\x86\x03\x02\n\n\r\n\x05\x04;\x02\0\x05\x12\x04\
+    \x86\x03\x0b\x11\n\r\n\x05\x04;\x02\0\x01\x12\x04\x86\x03\x12\x1b\n\r\n\
+    \x05\x04;\x02\0\x03\x12\x04\x86\x03\x1e\x1f\n\x0c\n\x04\x04;\x02\x01\x12\
+    \x04\x87\x03\x02\x1b\n\r\n\x05\x04;\x02\x01\x04\x12\x04\x87\x03\x02\n\n\
+    \r\n\x05\x04;\x02\x01\x05\x12\x04\x87\x03\x0b\x11\n\r\n\x05\x04;\x02\x01\
+    \x01\x12\x04\x87\x03\x12\x16\n\r\n\x05\x04;\x02\x01\x03\x12\x04\x87\x03\
+    \x19\x1a\n\x0c\n\x04\x04;\x02\x02\x12\x04\x88\x03\x02\x1f\n\r\n\x05\x04;\
+    \x02\x02\x04\x12\x04\x88\x03\x02\n\n\r\n\x05\x04;\x02\x02\x05\x12\x04\
+    \x88\x03\x0b\x11\n\r\n\x05\x04;\x02\x02\x01\x12\x04\x88\x03\x12\x1a\n\r\
+    \n\x05\x04;\x02\x02\x03\x12\x04\x88\x03\x1d\x1e\n\x0c\n\x04\x04;\x02\x03\
+    \x12\x04\x89\x03\x02\x1a\n\r\n\x05\x04;\x02\x03\x04\x12\x04\x89\x03\x02\
+    \n\n\r\n\x05\x04;\x02\x03\x05\x12\x04\x89\x03\x0b\x10\n\r\n\x05\x04;\x02\
+    \x03\x01\x12\x04\x89\x03\x11\x15\n\r\n\x05\x04;\x02\x03\x03\x12\x04\x89\
+    \x03\x18\x19\n\x0c\n\x04\x04;\x02\x04\x12\x04\x8a\x03\x02\x1f\n\r\n\x05\
+    \x04;\x02\x04\x04\x12\x04\x8a\x03\x02\n\n\r\n\x05\x04;\x02\x04\x05\x12\
+    \x04\x8a\x03\x0b\x11\n\r\n\x05\x04;\x02\x04\x01\x12\x04\x8a\x03\x12\x1a\
+    \n\r\n\x05\x04;\x02\x04\x03\x12\x04\x8a\x03\x1d\x1e\n\x0c\n\x02\x04<\x12\
+    \x06\x8d\x03\0\x90\x03\x01\n\x0b\n\x03\x04<\x01\x12\x04\x8d\x03\x08\x1a\
+    \n\x0c\n\x04\x04<\x02\0\x12\x04\x8e\x03\x02\x1f\n\r\n\x05\x04<\x02\0\x04\
+    \x12\x04\x8e\x03\x02\n\n\r\n\x05\x04<\x02\0\x05\x12\x04\x8e\x03\x0b\x11\
+    \n\r\n\x05\x04<\x02\0\x01\x12\x04\x8e\x03\x12\x1a\n\r\n\x05\x04<\x02\0\
+    \x03\x12\x04\x8e\x03\x1d\x1e\n\x0c\n\x04\x04<\x02\x01\x12\x04\x8f\x03\
+    \x02\x1d\n\r\n\x05\x04<\x02\x01\x04\x12\x04\x8f\x03\x02\n\n\r\n\x05\x04<\
+    \x02\x01\x05\x12\x04\x8f\x03\x0b\x11\n\r\n\x05\x04<\x02\x01\x01\x12\x04\
+    \x8f\x03\x12\x18\n\r\n\x05\x04<\x02\x01\x03\x12\x04\x8f\x03\x1b\x1c\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
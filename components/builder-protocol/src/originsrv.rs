@@ -379,6 +379,10 @@ impl FromArchive for OriginPackage {
         let config = try!(archive.config());
         let checksum = try!(archive.checksum());
         let target = try!(archive.target());
+        let description = try!(archive.description());
+        let licenses = try!(archive.licenses());
+        let upstream_url = try!(archive.upstream_url());
+        let maintainer = try!(archive.maintainer());
 
         let mut package = OriginPackage::new();
         package.set_ident(ident);
@@ -390,6 +394,16 @@ impl FromArchive for OriginPackage {
         if let Some(cfg) = config {
             package.set_config(cfg);
         }
+        if let Some(description) = description {
+            package.set_description(description);
+        }
+        package.set_licenses(licenses.into_iter().collect());
+        if let Some(upstream_url) = upstream_url {
+            package.set_upstream_url(upstream_url);
+        }
+        if let Some(maintainer) = maintainer {
+            package.set_maintainer(maintainer);
+        }
         package.set_checksum(checksum);
         Ok(package)
     }
@@ -406,7 +420,7 @@ impl Serialize for OriginPackage {
     where
         S: Serializer,
     {
-        let mut strukt = try!(serializer.serialize_struct("origin_package", 8));
+        let mut strukt = try!(serializer.serialize_struct("origin_package", 13));
         try!(strukt.serialize_field("ident", self.get_ident()));
         try!(strukt.serialize_field("checksum", self.get_checksum()));
         try!(strukt.serialize_field("manifest", self.get_manifest()));
@@ -415,6 +429,11 @@ impl Serialize for OriginPackage {
         try!(strukt.serialize_field("tdeps", self.get_tdeps()));
         try!(strukt.serialize_field("exposes", self.get_exposes()));
         try!(strukt.serialize_field("config", self.get_config()));
+        try!(strukt.serialize_field("yanked", &self.get_yanked()));
+        try!(strukt.serialize_field("description", self.get_description()));
+        try!(strukt.serialize_field("licenses", self.get_licenses()));
+        try!(strukt.serialize_field("upstream_url", self.get_upstream_url()));
+        try!(strukt.serialize_field("maintainer", self.get_maintainer()));
         strukt.end()
     }
 }
@@ -440,6 +459,10 @@ impl FromArchive for OriginPackageCreate {
         let config = try!(archive.config());
         let checksum = try!(archive.checksum());
         let target = try!(archive.target());
+        let description = try!(archive.description());
+        let licenses = try!(archive.licenses());
+        let upstream_url = try!(archive.upstream_url());
+        let maintainer = try!(archive.maintainer());
 
         let mut package = OriginPackageCreate::new();
         package.set_ident(ident);
@@ -451,6 +474,16 @@ impl FromArchive for OriginPackageCreate {
         if let Some(cfg) = config {
             package.set_config(cfg);
         }
+        if let Some(description) = description {
+            package.set_description(description);
+        }
+        package.set_licenses(licenses.into_iter().collect());
+        if let Some(upstream_url) = upstream_url {
+            package.set_upstream_url(upstream_url);
+        }
+        if let Some(maintainer) = maintainer {
+            package.set_maintainer(maintainer);
+        }
         package.set_checksum(checksum);
         Ok(package)
     }
@@ -721,6 +754,30 @@ impl Routable for OriginPackageDemote {
     }
 }
 
+impl Routable for OriginPackageYank {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_ident().get_origin()))
+    }
+}
+
+impl Routable for OriginPackageDelete {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_ident().get_origin()))
+    }
+}
+
+impl Routable for OriginPackageDependentsGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_ident().get_origin()))
+    }
+}
+
 impl Pageable for OriginPackageSearchRequest {
     fn get_range(&self) -> [u64; 2] {
         [self.get_start(), self.get_stop()]
@@ -773,6 +830,12 @@ impl Serialize for OriginProject {
         ));
         try!(state.serialize_field("vcs_type", self.get_vcs_type()));
         try!(state.serialize_field("vcs_data", self.get_vcs_data()));
+        if self.has_build_timeout() {
+            try!(state.serialize_field(
+                "build_timeout",
+                &self.get_build_timeout(),
+            ));
+        }
         state.end()
     }
 }
@@ -823,6 +886,14 @@ impl Routable for OriginProjectGet {
     }
 }
 
+impl Routable for OriginProjectListRequest {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_origin()))
+    }
+}
+
 impl Routable for OriginProjectUpdate {
     type H = InstaId;
 
@@ -539,6 +539,7 @@ impl DataStore {
             JobState::Complete => "Success",
             JobState::Rejected => "NotStarted", // retry submission
             JobState::Failed => "Failure",
+            JobState::Canceled => "Failure",
             _ => "InProgress",
         };
 
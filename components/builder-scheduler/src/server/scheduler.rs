@@ -412,7 +412,11 @@ impl ScheduleMgr {
 
         match self.datastore.set_group_job_state(&job) {
             Ok(_) => {
-                if job.get_state() == jobsrv::JobState::Failed {
+                // A canceled job leaves its dependents with nothing to build against, same as
+                // a failed one
+                if job.get_state() == jobsrv::JobState::Failed ||
+                    job.get_state() == jobsrv::JobState::Canceled
+                {
                     match self.skip_projects(&group, job.get_project().get_name()) {
                         Ok(_) => (),
                         Err(e) => {
@@ -427,7 +431,8 @@ impl ScheduleMgr {
 
                 match job.get_state() {
                     jobsrv::JobState::Complete |
-                    jobsrv::JobState::Failed => self.update_group_state(job.get_owner_id())?,
+                    jobsrv::JobState::Failed |
+                    jobsrv::JobState::Canceled => self.update_group_state(job.get_owner_id())?,
                     _ => (),
                 }
             }
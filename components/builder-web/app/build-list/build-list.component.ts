@@ -20,6 +20,8 @@ export class BuildListComponent {
 
     iconFor(state) {
         return {
+            CancelPending: "sync",
+            Canceled: "circle-slash",
             Complete: "check",
             Dispatched: "sync",
             Failed: "issue-opened",
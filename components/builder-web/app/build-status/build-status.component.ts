@@ -17,6 +17,8 @@ export class BuildStatusComponent {
 
   iconFor(state) {
       return {
+          CancelPending: "sync",
+          Canceled: "circle-slash",
           Complete: "check",
           Dispatched: "sync",
           Failed: "issue-opened",
@@ -82,6 +82,8 @@ export class BuildComponent implements OnChanges, OnDestroy {
 
     iconFor(state) {
         return {
+            CancelPending: "sync",
+            Canceled: "circle-slash",
             Complete: "check",
             Dispatched: "sync",
             Failed: "issue-opened",
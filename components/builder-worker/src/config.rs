@@ -17,10 +17,16 @@
 use std::net::{IpAddr, Ipv4Addr};
 use std::path::PathBuf;
 
+use hab_core;
 use hab_core::config::ConfigFile;
 use hab_core::url;
+use toml;
 
-use error::Error;
+use error::{Error, Result};
+
+/// The smallest share of a CPU a studio can be limited to. The kernel refuses CFS quotas below
+/// 1ms, which is a hundredth of the scheduling period.
+pub const MIN_STUDIO_CPUS: f64 = 0.01;
 
 #[derive(Clone, Debug, Deserialize)]
 #[serde(default)]
@@ -41,6 +47,11 @@ pub struct Config {
     pub depot_url: String,
     /// List of Job Servers to connect to
     pub jobsrv: JobSrvCfg,
+    /// Seconds a build may run for before it is stopped, unless its project sets a timeout of
+    /// its own. Builds run for as long as they like if it isn't set.
+    pub build_timeout: Option<u64>,
+    /// Resource limits placed on the studio process tree of each build
+    pub studio_limits: StudioLimits,
 }
 
 impl Config {
@@ -66,12 +77,22 @@ impl Default for Config {
             depot_channel: String::from("unstable"),
             depot_url: url::default_depot_url(),
             jobsrv: vec![JobSrvAddr::default()],
+            build_timeout: None,
+            studio_limits: StudioLimits::default(),
         }
     }
 }
 
 impl ConfigFile for Config {
     type Error = Error;
+
+    fn from_raw(raw: &str) -> Result<Self> {
+        let config: Config = try!(toml::from_str(raw).map_err(|e| {
+            hab_core::Error::ConfigFileSyntax(e)
+        }));
+        try!(config.studio_limits.validate());
+        Ok(config)
+    }
 }
 
 pub type JobSrvCfg = Vec<JobSrvAddr>;
@@ -96,6 +117,59 @@ impl Default for JobSrvAddr {
     }
 }
 
+/// CPU and memory caps applied to a build's studio through cgroups. Builds run unconfined
+/// unless at least one limit is set.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct StudioLimits {
+    /// Mount point of the cgroup (v1) controller hierarchies
+    pub cgroup_root: PathBuf,
+    /// Number of CPUs worth of time the studio may use, e.g. 1.5
+    pub cpus: Option<f64>,
+    /// Memory, in megabytes, the studio may use before it is killed by the kernel
+    pub memory_mb: Option<u64>,
+}
+
+impl StudioLimits {
+    pub fn is_empty(&self) -> bool {
+        self.cpus.is_none() && self.memory_mb.is_none()
+    }
+
+    /// Check that the limits can be applied to a cgroup.
+    ///
+    /// # Errors
+    ///
+    /// * If `cpus` is less than `MIN_STUDIO_CPUS`
+    /// * If `memory_mb` is 0
+    pub fn validate(&self) -> Result<()> {
+        if let Some(cpus) = self.cpus {
+            if !(cpus >= MIN_STUDIO_CPUS) {
+                return Err(Error::InvalidStudioLimits(format!(
+                    "cpus must be at least {}, got {}",
+                    MIN_STUDIO_CPUS,
+                    cpus
+                )));
+            }
+        }
+        if self.memory_mb == Some(0) {
+            return Err(Error::InvalidStudioLimits(
+                "memory_mb must be greater than 0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for StudioLimits {
+    fn default() -> Self {
+        StudioLimits {
+            cgroup_root: PathBuf::from("/sys/fs/cgroup"),
+            cpus: None,
+            memory_mb: None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,6 +180,11 @@ mod tests {
         auth_token = "mytoken"
         data_path = "/path/to/data"
         log_path = "/path/to/logs"
+        build_timeout = 600
+
+        [studio_limits]
+        cpus = 1.5
+        memory_mb = 2048
 
         [[jobsrv]]
         host = "1:1:1:1:1:1:1:1"
@@ -127,5 +206,32 @@ mod tests {
         assert_eq!(&format!("{}", config.jobsrv[1].host), "2.2.2.2");
         assert_eq!(config.jobsrv[1].port, 9000);
         assert_eq!(config.jobsrv[1].heartbeat, 5567);
+        assert_eq!(config.build_timeout, Some(600));
+        assert_eq!(
+            &format!("{}", config.studio_limits.cgroup_root.display()),
+            "/sys/fs/cgroup"
+        );
+        assert_eq!(config.studio_limits.cpus, Some(1.5));
+        assert_eq!(config.studio_limits.memory_mb, Some(2048));
+    }
+
+    #[test]
+    fn config_without_build_timeout() {
+        let config = Config::from_raw("auth_token = \"mytoken\"\n").unwrap();
+        assert_eq!(config.build_timeout, None);
+    }
+
+    #[test]
+    fn config_rejects_unusable_studio_limits() {
+        for limit in &["cpus = 0.0", "cpus = 0.001", "cpus = -1.0", "memory_mb = 0"] {
+            let content = format!("[studio_limits]\n{}\n", limit);
+            match Config::from_raw(&content) {
+                Err(Error::InvalidStudioLimits(_)) => (),
+                Err(e) => panic!("Unexpected error for {}, {}", limit, e),
+                Ok(_) => panic!("Accepted {}", limit),
+            }
+        }
+        let config = Config::from_raw("[studio_limits]\ncpus = 0.01\n").unwrap();
+        assert_eq!(config.studio_limits.cpus, Some(MIN_STUDIO_CPUS));
     }
 }
@@ -24,9 +24,13 @@ use zmq;
 
 #[derive(Debug)]
 pub enum Error {
+    BuildCanceled,
     BuildFailure(i32),
+    BuildTimeout(u64),
+    Cgroup(String, io::Error),
     Git(git2::Error),
     HabitatCore(hab_core::Error),
+    InvalidStudioLimits(String),
     IO(io::Error),
     Protobuf(protobuf::ProtobufError),
     UnknownVCS,
@@ -40,11 +44,17 @@ pub type Result<T> = result::Result<T, Error>;
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            Error::BuildCanceled => format!("Build was canceled"),
             Error::BuildFailure(ref e) => {
                 format!("Build studio exited with non-zero exit code, {}", e)
             }
+            Error::BuildTimeout(secs) => format!("Build exceeded its timeout of {} seconds", secs),
+            Error::Cgroup(ref path, ref e) => {
+                format!("Unable to configure studio cgroup at {}, {}", path, e)
+            }
             Error::Git(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
+            Error::InvalidStudioLimits(ref e) => format!("Invalid studio limits, {}", e),
             Error::IO(ref e) => format!("{}", e),
             Error::Protobuf(ref e) => format!("{}", e),
             Error::UnknownVCS => format!("Job requires an unknown VCS"),
@@ -63,9 +73,13 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::BuildCanceled => "Build was canceled",
             Error::BuildFailure(_) => "Build studio exited with a non-zero exit code",
+            Error::BuildTimeout(_) => "Build exceeded its timeout",
+            Error::Cgroup(_, ref err) => err.description(),
             Error::Git(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
+            Error::InvalidStudioLimits(_) => "Invalid studio limits",
             Error::IO(ref err) => err.description(),
             Error::Protobuf(ref err) => err.description(),
             Error::UnknownVCS => "Job requires an unknown VCS",
@@ -20,10 +20,13 @@ extern crate habitat_net as hab_net;
 extern crate git2;
 #[macro_use]
 extern crate lazy_static;
+extern crate libc;
 #[macro_use]
 extern crate log;
 extern crate protobuf;
 extern crate serde;
+#[cfg(test)]
+extern crate tempdir;
 #[macro_use]
 extern crate serde_derive;
 extern crate toml;
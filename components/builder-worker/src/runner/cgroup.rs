@@ -0,0 +1,215 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Confines a studio build to the CPU and memory limits in the worker's `StudioLimits` using
+//! (v1) cgroups.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use config::StudioLimits;
+use error::{Error, Result};
+
+/// Name of the cgroup under each controller hierarchy that holds the per-job cgroups
+const CGROUP_PARENT: &'static str = "habitat-builder";
+/// Length of the CFS scheduling period, in microseconds
+const CPU_PERIOD_US: u64 = 100_000;
+
+/// A set of cgroups, one per limited controller, created for a single job. The cgroups are
+/// removed when this is dropped.
+pub struct Cgroup {
+    paths: Vec<PathBuf>,
+    /// Open `cgroup.procs` files the studio writes itself into before it execs
+    procs: Vec<File>,
+}
+
+impl Cgroup {
+    /// Create and configure the cgroups for a job. Returns `None` if no limits are configured.
+    ///
+    /// # Errors
+    ///
+    /// * If a cgroup cannot be created or its limits cannot be written
+    pub fn create(limits: &StudioLimits, job_id: u64) -> Result<Option<Self>> {
+        if limits.is_empty() {
+            return Ok(None);
+        }
+        let mut cgroup = Cgroup {
+            paths: vec![],
+            procs: vec![],
+        };
+        if let Some(cpus) = limits.cpus {
+            let path = try!(cgroup.add(&limits.cgroup_root, "cpu", job_id));
+            try!(write_value(&path, "cpu.cfs_period_us", CPU_PERIOD_US));
+            try!(write_value(
+                &path,
+                "cpu.cfs_quota_us",
+                (cpus * CPU_PERIOD_US as f64) as u64,
+            ));
+        }
+        if let Some(memory_mb) = limits.memory_mb {
+            let path = try!(cgroup.add(&limits.cgroup_root, "memory", job_id));
+            try!(write_value(
+                &path,
+                "memory.limit_in_bytes",
+                memory_mb * 1024 * 1024,
+            ));
+        }
+        Ok(Some(cgroup))
+    }
+
+    /// Have the process spawned by the given command join these cgroups before it execs, so
+    /// that everything it goes on to start is confined as well.
+    #[cfg(unix)]
+    pub fn join_on_exec(&self, cmd: &mut Command) {
+        use libc;
+        use std::os::unix::io::AsRawFd;
+        use std::os::unix::process::CommandExt;
+
+        let fds: Vec<_> = self.procs.iter().map(|f| f.as_raw_fd()).collect();
+        cmd.before_exec(move || {
+            // Writing a pid of 0 moves the writing process. Only async-signal-safe calls are
+            // allowed between fork and exec, hence the raw writes to already-open files.
+            for fd in &fds {
+                let ret = unsafe { libc::write(*fd, b"0\n".as_ptr() as *const libc::c_void, 2) };
+                if ret < 0 {
+                    return Err(::std::io::Error::last_os_error());
+                }
+            }
+            Ok(())
+        });
+    }
+
+    #[cfg(not(unix))]
+    pub fn join_on_exec(&self, _cmd: &mut Command) {}
+
+    fn add(&mut self, root: &Path, controller: &str, job_id: u64) -> Result<PathBuf> {
+        let path = root.join(controller).join(CGROUP_PARENT).join(
+            job_id.to_string(),
+        );
+        try!(fs::create_dir_all(&path).map_err(|e| {
+            Error::Cgroup(path.display().to_string(), e)
+        }));
+        self.paths.push(path.clone());
+        let procs = path.join("cgroup.procs");
+        let file = try!(OpenOptions::new().write(true).open(&procs).map_err(|e| {
+            Error::Cgroup(procs.display().to_string(), e)
+        }));
+        self.procs.push(file);
+        Ok(path)
+    }
+}
+
+impl Drop for Cgroup {
+    fn drop(&mut self) {
+        self.procs.clear();
+        // A cgroup can only be removed once every process in it has exited
+        for path in &self.paths {
+            if let Err(err) = fs::remove_dir(path) {
+                warn!("Unable to remove cgroup {}, err={}", path.display(), err);
+            }
+        }
+    }
+}
+
+fn write_value(cgroup: &Path, file: &str, value: u64) -> Result<()> {
+    let path = cgroup.join(file);
+    let mut f = try!(OpenOptions::new().write(true).open(&path).map_err(|e| {
+        Error::Cgroup(path.display().to_string(), e)
+    }));
+    try!(write!(f, "{}", value).map_err(|e| {
+        Error::Cgroup(path.display().to_string(), e)
+    }));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+    use std::io::Read;
+    use std::path::Path;
+
+    use tempdir::TempDir;
+
+    use config::StudioLimits;
+    use super::*;
+
+    /// Lay out the files the kernel provides in a new cgroup, which a plain directory lacks.
+    fn controller(root: &Path, controller: &str, files: &[&str]) {
+        let path = root.join(controller).join(CGROUP_PARENT).join("42");
+        fs::create_dir_all(&path).unwrap();
+        File::create(path.join("cgroup.procs")).unwrap();
+        for file in files {
+            File::create(path.join(file)).unwrap();
+        }
+    }
+
+    fn read(root: &Path, controller: &str, file: &str) -> String {
+        let mut value = String::new();
+        File::open(root.join(controller).join(CGROUP_PARENT).join("42").join(file))
+            .unwrap()
+            .read_to_string(&mut value)
+            .unwrap();
+        value
+    }
+
+    fn limits(root: &Path) -> StudioLimits {
+        let mut limits = StudioLimits::default();
+        limits.cgroup_root = root.to_path_buf();
+        limits
+    }
+
+    #[test]
+    fn create_without_limits() {
+        let root = TempDir::new("cgroup").unwrap();
+        assert!(Cgroup::create(&limits(root.path()), 42).unwrap().is_none());
+        assert!(fs::read_dir(root.path()).unwrap().next().is_none());
+    }
+
+    #[test]
+    fn create_writes_limits() {
+        let root = TempDir::new("cgroup").unwrap();
+        controller(
+            root.path(),
+            "cpu",
+            &["cpu.cfs_period_us", "cpu.cfs_quota_us"],
+        );
+        controller(root.path(), "memory", &["memory.limit_in_bytes"]);
+        let mut limits = limits(root.path());
+        limits.cpus = Some(1.5);
+        limits.memory_mb = Some(512);
+
+        let cgroup = Cgroup::create(&limits, 42).unwrap().unwrap();
+        assert_eq!(cgroup.procs.len(), 2);
+        assert_eq!(read(root.path(), "cpu", "cpu.cfs_period_us"), "100000");
+        assert_eq!(read(root.path(), "cpu", "cpu.cfs_quota_us"), "150000");
+        assert_eq!(
+            read(root.path(), "memory", "memory.limit_in_bytes"),
+            "536870912"
+        );
+    }
+
+    #[test]
+    fn create_fails_without_controller() {
+        let root = TempDir::new("cgroup").unwrap();
+        let mut limits = limits(root.path());
+        limits.memory_mb = Some(512);
+        match Cgroup::create(&limits, 42) {
+            Err(Error::Cgroup(path, _)) => assert!(path.ends_with("cgroup.procs")),
+            Err(e) => panic!("Unexpected error {}", e),
+            Ok(_) => panic!("Created a cgroup without a controller"),
+        }
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod cgroup;
 pub mod log_pipe;
 pub mod workspace;
 pub mod postprocessor;
@@ -23,8 +24,9 @@ use std::ops::{Deref, DerefMut};
 use std::path::Path;
 use std::process::{Command, Stdio};
 use std::str::FromStr;
-use std::sync::{mpsc, Arc, RwLock};
+use std::sync::{mpsc, Arc, Mutex, RwLock};
 use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
 use bldr_core::logger::Logger;
 pub use protocol::jobsrv::JobState;
@@ -34,6 +36,7 @@ use hab_core::{crypto, env};
 use hab_core::package::archive::PackageArchive;
 use hab_core::package::install::PackageInstall;
 use hab_core::package::PackageIdent;
+use hab_core::os::process::{self, Signal};
 use hab_net::server::ZMQ_CONTEXT;
 use protobuf::{parse_from_bytes, Message};
 use protocol::jobsrv as proto;
@@ -42,6 +45,7 @@ use protocol::net::{self, ErrCode};
 use zmq;
 
 use {PRODUCT, VERSION};
+use self::cgroup::Cgroup;
 use self::log_pipe::LogPipe;
 use self::postprocessor::PostProcessor;
 use self::workspace::Workspace;
@@ -78,15 +82,16 @@ impl Job {
     }
 
     pub fn vcs(&self) -> vcs::VCS {
-        match self.0.get_project().get_vcs_type() {
-            "git" => {
-                vcs::VCS::new(
-                    String::from(self.0.get_project().get_vcs_type()),
-                    String::from(self.0.get_project().get_vcs_data()),
-                )
-            }
-            _ => panic!("unknown vcs associated with jobs project"),
-        }
+        let vcs_ref = if self.0.has_vcs_ref() {
+            Some(String::from(self.0.get_vcs_ref()))
+        } else {
+            None
+        };
+        vcs::VCS::new(
+            String::from(self.0.get_project().get_vcs_type()),
+            String::from(self.0.get_project().get_vcs_data()),
+            vcs_ref,
+        )
     }
 
     pub fn origin(&self) -> &str {
@@ -120,16 +125,75 @@ impl DerefMut for Job {
     }
 }
 
+/// Shared between the worker's `Server` and the thread running a job so the `Server` can cancel
+/// the job in flight. Canceling kills the studio process tree if a build is underway and flags
+/// the `Runner` to stop and tear down its workspace at the next opportunity.
+#[derive(Clone, Default)]
+pub struct CancelHandle(Arc<Mutex<CancelState>>);
+
+#[derive(Default)]
+struct CancelState {
+    job_id: Option<u64>,
+    studio_pid: Option<u32>,
+    canceled: bool,
+}
+
+impl CancelHandle {
+    /// Cancel the given job. Returns false if that job is not the one currently being run.
+    pub fn cancel(&self, job_id: u64) -> bool {
+        let mut state = self.0.lock().expect("CancelHandle lock poisoned");
+        if state.job_id != Some(job_id) {
+            return false;
+        }
+        debug!("canceling job, id={}", job_id);
+        state.canceled = true;
+        if let Some(pid) = state.studio_pid {
+            kill_studio(pid);
+        }
+        true
+    }
+
+    /// Returns true if the running job has been canceled.
+    pub fn is_canceled(&self) -> bool {
+        self.0.lock().expect("CancelHandle lock poisoned").canceled
+    }
+
+    fn start(&self, job_id: u64) {
+        let mut state = self.0.lock().expect("CancelHandle lock poisoned");
+        *state = CancelState::default();
+        state.job_id = Some(job_id);
+    }
+
+    fn finish(&self) {
+        let mut state = self.0.lock().expect("CancelHandle lock poisoned");
+        *state = CancelState::default();
+    }
+
+    fn studio_started(&self, pid: u32) {
+        let mut state = self.0.lock().expect("CancelHandle lock poisoned");
+        // The job may have been canceled while we were still spawning the studio
+        if state.canceled {
+            kill_studio(pid);
+        }
+        state.studio_pid = Some(pid);
+    }
+
+    fn studio_exited(&self) {
+        self.0.lock().expect("CancelHandle lock poisoned").studio_pid = None;
+    }
+}
+
 pub struct Runner {
     config: Config,
     depot_cli: depot_client::Client,
     log_pipe: Option<LogPipe>,
     workspace: Workspace,
     logger: Logger,
+    cancel: CancelHandle,
 }
 
 impl Runner {
-    pub fn new(job: Job, config: Config) -> Self {
+    pub fn new(job: Job, config: Config, cancel: CancelHandle) -> Self {
         let depot_cli = depot_client::Client::new(&config.depot_url, PRODUCT, VERSION, None)
             .unwrap();
 
@@ -143,6 +207,7 @@ impl Runner {
             depot_cli: depot_cli,
             log_pipe: None,
             logger: logger,
+            cancel: cancel,
         }
     }
 
@@ -195,9 +260,16 @@ impl Runner {
                 return self.fail(net::err(ErrCode::SECRET_KEY_FETCH, "wk:run:3"));
             }
         }
-        if let Some(err) = self.job().vcs().clone(&self.workspace.src()).err() {
-            error!("Unable to clone remote source repository, err={}", err);
-            return self.fail(net::err(ErrCode::VCS_CLONE, "wk:run:4"));
+        if self.cancel.is_canceled() {
+            return self.canceled();
+        }
+        let cloned = clone_source(&self.job().vcs(), &self.workspace.src());
+        match cloned {
+            Ok(sha) => self.workspace.job.set_vcs_sha(sha),
+            Err(err) => return self.fail(err),
+        }
+        if self.cancel.is_canceled() {
+            return self.canceled();
         }
 
         self.workspace.job.set_build_started_at(
@@ -217,6 +289,19 @@ impl Runner {
                 );
                 archive
             }
+            Err(Error::BuildCanceled) => {
+                self.workspace.job.set_build_finished_at(
+                    UTC::now().to_rfc3339(),
+                );
+                return self.canceled();
+            }
+            Err(err @ Error::BuildTimeout(_)) => {
+                self.workspace.job.set_build_finished_at(
+                    UTC::now().to_rfc3339(),
+                );
+                error!("Unable to build in studio, err={}", err);
+                return self.fail(net::err(ErrCode::BUILD_TIMEOUT, "wk:run:7"));
+            }
             Err(err) => {
                 self.workspace.job.set_build_finished_at(
                     UTC::now().to_rfc3339(),
@@ -240,6 +325,9 @@ impl Runner {
         let ident = OriginPackageIdent::from(archive.ident().unwrap());
         self.workspace.job.set_package_ident(ident);
 
+        if self.cancel.is_canceled() {
+            return self.canceled();
+        }
         let mut post_processor = PostProcessor::new(&self.workspace);
         if !post_processor.run(&mut archive, &self.config) {
             // JW TODO: We should shelve the built artifacts and allow a retry on post-processing.
@@ -274,36 +362,44 @@ impl Runner {
         ];
         let command = studio_cmd();
         debug!("building, cmd={:?}, args={:?}", command, args);
-        let mut child = match env::var(RUNNER_DEBUG_ENV) {
+        let mut cmd = Command::new(command);
+        cmd.args(&args)
+            .env_clear()
+            .env("HAB_NONINTERACTIVE", "true")
+            .env("HAB_DEPOT_URL", &self.config.depot_url)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        match env::var(RUNNER_DEBUG_ENV) {
             Ok(val) => {
-                Command::new(command)
-                    .args(&args)
-                    .env_clear()
-                    .env("HAB_NONINTERACTIVE", "true")
-                    .env("HAB_DEPOT_URL", &self.config.depot_url)
-                    .env("DEBUG", val)
-                    .stdout(Stdio::piped())
-                    .stderr(Stdio::piped())
-                    .spawn()
-                    .expect("failed to spawn child")
+                cmd.env("DEBUG", val);
             }
             Err(_) => {
-                Command::new(command)
-                    .args(&args)
-                    .env_clear()
-                    .env("HAB_NONINTERACTIVE", "true")
-                    .env("HAB_DEPOT_URL", &self.config.depot_url)
-                    .env("TERM", "xterm-256color") // Gives us ANSI color codes
-                    .stdout(Stdio::piped())
-                    .stderr(Stdio::piped())
-                    .spawn()
-                    .expect("failed to spawn child")
+                cmd.env("TERM", "xterm-256color"); // Gives us ANSI color codes
             }
-        };
+        }
+        set_process_group(&mut cmd);
+        let cgroup = try!(Cgroup::create(
+            &self.config.studio_limits,
+            self.job().get_id(),
+        ));
+        if let Some(ref cgroup) = cgroup {
+            cgroup.join_on_exec(&mut cmd);
+        }
+        let timeout = self.build_timeout();
+        let mut child = cmd.spawn().expect("failed to spawn child");
+        self.cancel.studio_started(child.id());
+        let watchdog = timeout.map(|secs| Watchdog::start(child.id(), secs));
         self.log_pipe().pipe(&mut child);
         let exit_status = child.wait().expect("failed to wait on child");
+        self.cancel.studio_exited();
+        let timed_out = watchdog.map_or(false, |w| w.stop());
+        drop(cgroup);
         debug!("build complete, status={:?}", exit_status);
-        if exit_status.success() {
+        if self.cancel.is_canceled() {
+            Err(Error::BuildCanceled)
+        } else if timed_out {
+            Err(Error::BuildTimeout(timeout.unwrap()))
+        } else if exit_status.success() {
             try!(fs::rename(
                 self.workspace.src().join("results"),
                 self.workspace.out(),
@@ -314,6 +410,13 @@ impl Runner {
         }
     }
 
+    fn build_timeout(&self) -> Option<u64> {
+        build_timeout(
+            self.job().get_project().get_build_timeout(),
+            self.config.build_timeout,
+        )
+    }
+
     fn complete(mut self) -> Job {
         self.teardown().err().map(|e| error!("{}", e));
         self.workspace.job.set_state(JobState::Complete);
@@ -321,6 +424,13 @@ impl Runner {
         self.workspace.job
     }
 
+    fn canceled(mut self) -> Job {
+        self.teardown().err().map(|e| error!("{}", e));
+        self.workspace.job.set_state(JobState::Canceled);
+        self.logger.log_worker_job(&self.workspace.job);
+        self.workspace.job
+    }
+
     fn fail(mut self, err: net::NetError) -> Job {
         self.teardown().err().map(|e| error!("{}", e));
         self.workspace.job.set_state(JobState::Failed);
@@ -395,6 +505,39 @@ impl Runner {
     }
 }
 
+/// Kills a studio which runs past its build timeout.
+struct Watchdog {
+    tx: mpsc::Sender<()>,
+    handle: JoinHandle<bool>,
+}
+
+impl Watchdog {
+    fn start(pid: u32, secs: u64) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let handle = thread::Builder::new()
+            .name("build-watchdog".to_string())
+            .spawn(move || match rx.recv_timeout(Duration::from_secs(secs)) {
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    warn!("Build timed out after {} seconds, killing studio", secs);
+                    kill_studio(pid);
+                    true
+                }
+                _ => false,
+            })
+            .unwrap();
+        Watchdog {
+            tx: tx,
+            handle: handle,
+        }
+    }
+
+    /// Stop watching the studio. Returns true if the studio was killed for running too long.
+    fn stop(self) -> bool {
+        let _ = self.tx.send(());
+        self.handle.join().unwrap_or(false)
+    }
+}
+
 /// Client for sending and receiving messages to and from the Job Runner
 pub struct RunnerCli {
     sock: zmq::Socket,
@@ -457,16 +600,17 @@ pub struct RunnerMgr {
     sock: zmq::Socket,
     msg: zmq::Message,
     config: Arc<RwLock<Config>>,
+    cancel: CancelHandle,
 }
 
 impl RunnerMgr {
     /// Start the Job Runner
-    pub fn start(config: Arc<RwLock<Config>>) -> Result<JoinHandle<()>> {
+    pub fn start(config: Arc<RwLock<Config>>, cancel: CancelHandle) -> Result<JoinHandle<()>> {
         let (tx, rx) = mpsc::sync_channel(0);
         let handle = thread::Builder::new()
             .name("runner".to_string())
             .spawn(move || {
-                let mut runner = Self::new(config).unwrap();
+                let mut runner = Self::new(config, cancel).unwrap();
                 runner.run(tx).unwrap();
             })
             .unwrap();
@@ -476,12 +620,13 @@ impl RunnerMgr {
         }
     }
 
-    fn new(config: Arc<RwLock<Config>>) -> Result<Self> {
+    fn new(config: Arc<RwLock<Config>>, cancel: CancelHandle) -> Result<Self> {
         let sock = try!((**ZMQ_CONTEXT).as_mut().socket(zmq::DEALER));
         Ok(RunnerMgr {
             sock: sock,
             msg: zmq::Message::new().unwrap(),
             config: config,
+            cancel: cancel,
         })
     }
 
@@ -491,6 +636,9 @@ impl RunnerMgr {
         rz.send(()).unwrap();
         loop {
             let job = try!(self.recv_job());
+            // Register the job before acknowledging it so that a cancel received by the server
+            // right after the ack will find it
+            self.cancel.start(job.get_id());
             try!(self.send_ack(&job));
             try!(self.execute_job(job));
         }
@@ -498,10 +646,15 @@ impl RunnerMgr {
 
     fn execute_job(&mut self, job: Job) -> Result<()> {
         let runner = {
-            Runner::new(job, (*self.config.read().unwrap()).clone())
+            Runner::new(
+                job,
+                (*self.config.read().unwrap()).clone(),
+                self.cancel.clone(),
+            )
         };
         debug!("executing work, job={:?}", runner.job());
         let job = runner.run();
+        self.cancel.finish();
         self.send_complete(&job)
     }
 
@@ -526,6 +679,39 @@ impl RunnerMgr {
     }
 }
 
+/// Start the studio in a process group of its own so that canceling a job can take down every
+/// process the build started.
+#[cfg(unix)]
+fn set_process_group(cmd: &mut Command) {
+    use libc;
+    use std::os::unix::process::CommandExt;
+
+    cmd.before_exec(|| {
+        unsafe {
+            libc::setpgid(0, 0);
+        }
+        Ok(())
+    });
+}
+
+#[cfg(not(unix))]
+fn set_process_group(_cmd: &mut Command) {}
+
+#[cfg(unix)]
+fn kill_studio(pid: u32) {
+    // A negative pid signals the whole process group started by `set_process_group`
+    if let Err(err) = process::signal(-(pid as process::Pid), Signal::KILL) {
+        error!("Unable to kill studio, pid={}, err={}", pid, err);
+    }
+}
+
+#[cfg(not(unix))]
+fn kill_studio(pid: u32) {
+    if let Err(err) = process::signal(pid as process::Pid, Signal::KILL) {
+        error!("Unable to kill studio, pid={}, err={}", pid, err);
+    }
+}
+
 fn studio_cmd() -> String {
     match PackageInstall::load(&STUDIO_PKG, None) {
         Ok(package) => format!("{}/hab-studio", package.paths().unwrap()[0].display()),
@@ -538,6 +724,23 @@ fn studio_cmd() -> String {
     }
 }
 
+/// Clone the job's source repository, returning the SHA of the commit which was checked out.
+fn clone_source(vcs: &vcs::VCS, path: &Path) -> ::std::result::Result<String, net::NetError> {
+    vcs.clone(path).map_err(|err| {
+        error!("Unable to clone remote source repository, err={}", err);
+        net::err(ErrCode::VCS_CLONE, "wk:run:4")
+    })
+}
+
+/// Seconds a build may run for, if it may not run for as long as it likes. The project's own
+/// timeout wins over the worker's default, and a project timeout of 0 means it has none.
+fn build_timeout(project_timeout: u64, worker_timeout: Option<u64>) -> Option<u64> {
+    match project_timeout {
+        0 => worker_timeout.and_then(|secs| if secs > 0 { Some(secs) } else { None }),
+        secs => Some(secs),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -552,4 +755,116 @@ mod tests {
         let job = Job::new(inner);
         assert_eq!(job.origin(), "core");
     }
+
+    #[test]
+    fn vcs_from_job_includes_ref() {
+        let mut inner = jobsrv::Job::new();
+        let mut project = originsrv::OriginProject::new();
+        project.set_vcs_type("git".to_string());
+        project.set_vcs_data("https://github.com/habitat-sh/core-plans.git".to_string());
+        inner.set_project(project);
+        assert_eq!(Job::new(inner.clone()).vcs().vcs_ref, None);
+
+        inner.set_vcs_ref("v1.0.0".to_string());
+        assert_eq!(Job::new(inner).vcs().vcs_ref, Some("v1.0.0".to_string()));
+    }
+
+    #[test]
+    fn build_timeout_prefers_the_project_and_defaults_to_none() {
+        assert_eq!(build_timeout(0, None), None);
+        assert_eq!(build_timeout(0, Some(0)), None);
+        assert_eq!(build_timeout(0, Some(7200)), Some(7200));
+        assert_eq!(build_timeout(600, None), Some(600));
+        assert_eq!(build_timeout(600, Some(7200)), Some(600));
+        assert_eq!(build_timeout(0, Config::default().build_timeout), None);
+    }
+
+    #[test]
+    fn cancel_only_the_running_job() {
+        let cancel = CancelHandle::default();
+        assert!(!cancel.cancel(1));
+        cancel.start(1);
+        assert!(!cancel.cancel(2));
+        assert!(!cancel.is_canceled());
+        assert!(cancel.cancel(1));
+        assert!(cancel.is_canceled());
+        cancel.finish();
+        assert!(!cancel.is_canceled());
+        assert!(!cancel.cancel(1));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn cancel_kills_the_studio_process_group() {
+        use std::io::Read;
+        use std::time::Instant;
+
+        let cancel = CancelHandle::default();
+        cancel.start(1);
+        // The backgrounded sleep stands in for a process started by the build
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg("sleep 30 & sleep 30").stdout(
+            Stdio::piped(),
+        );
+        set_process_group(&mut cmd);
+        let mut child = cmd.spawn().unwrap();
+        cancel.studio_started(child.id());
+        let started = Instant::now();
+        assert!(cancel.cancel(1));
+        assert!(!child.wait().unwrap().success());
+        // Each process in the group holds the pipe open, so it only closes once all are gone
+        let mut out = String::new();
+        child.stdout.take().unwrap().read_to_string(&mut out).unwrap();
+        assert!(started.elapsed() < Duration::from_secs(10));
+        cancel.studio_exited();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn cancel_before_the_studio_starts() {
+        let cancel = CancelHandle::default();
+        cancel.start(1);
+        assert!(cancel.cancel(1));
+        let mut cmd = Command::new("sleep");
+        cmd.arg("30");
+        set_process_group(&mut cmd);
+        let mut child = cmd.spawn().unwrap();
+        cancel.studio_started(child.id());
+        assert!(!child.wait().unwrap().success());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn watchdog_kills_a_studio_which_runs_too_long() {
+        use std::time::Instant;
+
+        let mut cmd = Command::new("sleep");
+        cmd.arg("30");
+        set_process_group(&mut cmd);
+        let mut child = cmd.spawn().unwrap();
+        let started = Instant::now();
+        let watchdog = Watchdog::start(child.id(), 1);
+        assert!(!child.wait().unwrap().success());
+        assert!(watchdog.stop());
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn watchdog_leaves_a_studio_which_finishes_in_time() {
+        let mut cmd = Command::new("sleep");
+        cmd.arg("0");
+        set_process_group(&mut cmd);
+        let mut child = cmd.spawn().unwrap();
+        let watchdog = Watchdog::start(child.id(), 30);
+        assert!(child.wait().unwrap().success());
+        assert!(!watchdog.stop());
+    }
+
+    #[test]
+    fn failed_clone_is_a_vcs_clone_error() {
+        let vcs = vcs::VCS::new("svn".to_string(), "svn://example.com/repo".to_string(), None);
+        let err = clone_source(&vcs, Path::new("/tmp/unknown-vcs")).unwrap_err();
+        assert_eq!(err.get_code(), ErrCode::VCS_CLONE);
+    }
 }
@@ -23,7 +23,7 @@ use config::Config;
 use error::Result;
 use heartbeat::{HeartbeatCli, HeartbeatMgr};
 use log_forwarder::LogForwarder;
-use runner::{RunnerCli, RunnerMgr};
+use runner::{CancelHandle, RunnerCli, RunnerMgr};
 
 enum State {
     Ready,
@@ -42,6 +42,8 @@ pub struct Server {
     fe_sock: zmq::Socket,
     hb_cli: HeartbeatCli,
     runner_cli: RunnerCli,
+    /// Cancels the job being run by the `RunnerMgr`
+    cancel: CancelHandle,
     state: State,
     msg: zmq::Message,
 }
@@ -57,6 +59,7 @@ impl Server {
             fe_sock: fe_sock,
             hb_cli: hb_cli,
             runner_cli: runner_cli,
+            cancel: CancelHandle::default(),
             state: State::default(),
             msg: try!(zmq::Message::new()),
         })
@@ -64,7 +67,7 @@ impl Server {
 
     pub fn run(&mut self) -> Result<()> {
         try!(HeartbeatMgr::start(self.config.clone()));
-        try!(RunnerMgr::start(self.config.clone()));
+        try!(RunnerMgr::start(self.config.clone(), self.cancel.clone()));
         try!(LogForwarder::start(self.config.clone()));
         try!(self.hb_cli.connect());
         try!(self.runner_cli.connect());
@@ -103,7 +106,25 @@ impl Server {
             if fe_msg {
                 try!(self.fe_sock.recv(&mut self.msg, 0));
                 try!(self.fe_sock.recv(&mut self.msg, 0));
+                let mut job: protocol::jobsrv::Job = match parse_from_bytes(&self.msg) {
+                    Ok(job) => job,
+                    Err(err) => {
+                        warn!("dropping malformed message from job server, err={}", err);
+                        fe_msg = false;
+                        continue;
+                    }
+                };
                 match self.state {
+                    // Cancellations of the running job get no reply here; the runner reports
+                    // the job as Canceled once it has stopped working on it.
+                    _ if job.get_state() == protocol::jobsrv::JobState::CancelPending => {
+                        if !self.cancel.cancel(job.get_id()) {
+                            // Nothing to stop, so let the job server stop waiting on us
+                            debug!("canceling a job we aren't running, job={:?}", job);
+                            job.set_state(protocol::jobsrv::JobState::Canceled);
+                            try!(self.fe_sock.send(&job.write_to_bytes().unwrap(), 0));
+                        }
+                    }
                     State::Ready => {
                         try!(self.runner_cli.send(&self.msg));
                         {
@@ -113,9 +134,8 @@ impl Server {
                         try!(self.set_busy());
                     }
                     State::Busy => {
-                        let mut reply: protocol::jobsrv::Job = parse_from_bytes(&self.msg).unwrap();
-                        reply.set_state(protocol::jobsrv::JobState::Rejected);
-                        try!(self.fe_sock.send(&reply.write_to_bytes().unwrap(), 0));
+                        job.set_state(protocol::jobsrv::JobState::Rejected);
+                        try!(self.fe_sock.send(&job.write_to_bytes().unwrap(), 0));
                     }
                 }
                 fe_msg = false;
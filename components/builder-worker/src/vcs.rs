@@ -16,29 +16,83 @@ use std::path::Path;
 
 use git2;
 
-use error::Result;
+use error::{Error, Result};
 
 pub struct VCS {
     pub vcs_type: String,
     pub data: String,
+    /// Branch, tag or commit to check out after cloning. The default branch is used if unset.
+    pub vcs_ref: Option<String>,
 }
 
 impl VCS {
-    pub fn new(vcs_type: String, data: String) -> VCS {
+    pub fn new(vcs_type: String, data: String, vcs_ref: Option<String>) -> VCS {
         VCS {
             vcs_type: vcs_type,
             data: data,
+            vcs_ref: vcs_ref,
         }
     }
 
-    pub fn clone(&self, path: &Path) -> Result<()> {
+    /// Clone the repository into the given path and check out the requested ref, returning the
+    /// SHA of the commit which was checked out.
+    ///
+    /// # Errors
+    ///
+    /// * If the VCS type is unknown
+    /// * If the repository cannot be cloned
+    /// * If the requested ref cannot be found in the repository
+    pub fn clone(&self, path: &Path) -> Result<String> {
         match self.vcs_type.as_ref() {
             "git" => {
                 debug!("cloning git repository, url={}, path={:?}", self.data, path);
-                try!(git2::Repository::clone(&self.data, path));
-                Ok(())
+                let repo = try!(git2::Repository::clone(&self.data, path));
+                let sha = match self.vcs_ref {
+                    Some(ref vcs_ref) => try!(checkout(&repo, vcs_ref)),
+                    None => {
+                        let head = try!(repo.revparse_single("HEAD"));
+                        try!(head.peel(git2::ObjectType::Commit)).id()
+                    }
+                };
+                debug!("checked out git commit, sha={}", sha);
+                Ok(sha.to_string())
             }
-            _ => panic!("Unknown vcs type"),
+            _ => Err(Error::UnknownVCS),
+        }
+    }
+}
+
+/// Check out a branch, tag or commit SHA in a freshly-cloned repository, leaving HEAD detached
+/// at the resolved commit.
+fn checkout(repo: &git2::Repository, vcs_ref: &str) -> Result<git2::Oid> {
+    debug!("checking out git ref, ref={}", vcs_ref);
+    // Only the default branch has a local branch after a clone; any other branch is found
+    // through its remote-tracking branch.
+    let object = match repo.revparse_single(vcs_ref) {
+        Ok(object) => object,
+        Err(_) => try!(repo.revparse_single(&format!("origin/{}", vcs_ref))),
+    };
+    let commit = try!(object.peel(git2::ObjectType::Commit));
+    let mut opts = git2::build::CheckoutBuilder::new();
+    opts.force();
+    try!(repo.checkout_tree(&commit, Some(&mut opts)));
+    try!(repo.set_head_detached(commit.id()));
+    Ok(commit.id())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::VCS;
+    use error::Error;
+
+    #[test]
+    fn clone_with_unknown_vcs_is_an_error() {
+        let vcs = VCS::new("svn".to_string(), "svn://example.com/repo".to_string(), None);
+        match vcs.clone(Path::new("/tmp/unknown-vcs")) {
+            Err(Error::UnknownVCS) => (),
+            _ => panic!("expected an unknown VCS error"),
         }
     }
 }
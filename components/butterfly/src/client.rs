@@ -23,6 +23,7 @@ use zmq;
 use ZMQ_CONTEXT;
 use message;
 use rumor::Rumor;
+use rumor::departure::Departure;
 use rumor::service_config::ServiceConfig;
 use rumor::service_file::ServiceFile;
 use error::{Result, Error};
@@ -91,6 +92,28 @@ impl Client {
         self.send(sf)
     }
 
+    /// Create a service file bundle holding every `(filename, body)` pair in `files` and send it
+    /// to the server. The files are always updated together, under a single incarnation.
+    pub fn send_service_file_bundle<S: Into<String>>(
+        &mut self,
+        service_group: ServiceGroup,
+        name: S,
+        incarnation: u64,
+        files: Vec<(String, Vec<u8>)>,
+        encrypted: bool,
+    ) -> Result<()> {
+        let mut sf = ServiceFile::new_bundle("butterflyclient", service_group, name, files);
+        sf.set_incarnation(incarnation);
+        sf.set_encrypted(encrypted);
+        self.send(sf)
+    }
+
+    /// Create a departure for the given member and send it to the server.
+    pub fn send_departure<S: Into<String>>(&mut self, member_id: S) -> Result<()> {
+        let departure = Departure::new("butterflyclient", member_id);
+        self.send(departure)
+    }
+
     /// Send any `Rumor` to the server.
     pub fn send<T: Rumor>(&mut self, rumor: T) -> Result<()> {
         let bytes = try!(rumor.write_to_bytes());
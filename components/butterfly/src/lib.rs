@@ -46,11 +46,15 @@ extern crate habitat_core;
 extern crate lazy_static;
 #[macro_use]
 extern crate log;
+#[macro_use]
+extern crate prometheus;
 extern crate protobuf;
 extern crate rand;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+#[cfg(test)]
+extern crate tempdir;
 extern crate time;
 extern crate toml;
 extern crate uuid;
@@ -45,6 +45,7 @@ pub enum Health {
     Alive,
     Suspect,
     Confirmed,
+    Departed,
 }
 
 impl Default for Health {
@@ -61,6 +62,7 @@ impl FromStr for Health {
             "alive" => Ok(Health::Alive),
             "suspect" => Ok(Health::Suspect),
             "confirmed" => Ok(Health::Confirmed),
+            "departed" => Ok(Health::Departed),
             _ => Ok(Health::Alive),
         }
     }
@@ -81,6 +83,7 @@ impl From<ProtoMembership_Health> for Health {
             ProtoMembership_Health::ALIVE => Health::Alive,
             ProtoMembership_Health::SUSPECT => Health::Suspect,
             ProtoMembership_Health::CONFIRMED => Health::Confirmed,
+            ProtoMembership_Health::DEPARTED => Health::Departed,
         }
     }
 }
@@ -91,6 +94,7 @@ impl From<Health> for ProtoMembership_Health {
             Health::Alive => ProtoMembership_Health::ALIVE,
             Health::Suspect => ProtoMembership_Health::SUSPECT,
             Health::Confirmed => ProtoMembership_Health::CONFIRMED,
+            Health::Departed => ProtoMembership_Health::DEPARTED,
         }
     }
 }
@@ -101,6 +105,7 @@ impl<'a> From<&'a Health> for ProtoMembership_Health {
             &Health::Alive => ProtoMembership_Health::ALIVE,
             &Health::Suspect => ProtoMembership_Health::SUSPECT,
             &Health::Confirmed => ProtoMembership_Health::CONFIRMED,
+            &Health::Departed => ProtoMembership_Health::DEPARTED,
         }
     }
 }
@@ -112,6 +117,7 @@ impl fmt::Display for Health {
             Health::Alive => "alive",
             Health::Suspect => "suspect",
             Health::Confirmed => "confirmed",
+            Health::Departed => "departed",
         };
         write!(f, "{}", value)
     }
@@ -280,8 +286,15 @@ impl MemberList {
         }
     }
 
-    /// Inserts a member into the member list with the given health.
+    /// Inserts a member into the member list with the given health. Departed members are never
+    /// inserted again.
     pub fn insert(&self, member: Member, health: Health) -> bool {
+        if health == Health::Departed {
+            return self.depart(member.get_id());
+        }
+        if self.is_departed(member.get_id()) {
+            return false;
+        }
         let share_rumor: bool;
         let mut start_suspicion: bool = false;
         let mut stop_suspicion: bool = false;
@@ -425,13 +438,16 @@ impl MemberList {
     /// Updates the health of a member without touching the member itself. Returns true if the
     /// health changed, false otherwise.
     pub fn insert_health_by_id(&self, member_id: &str, health: Health) -> bool {
+        if health == Health::Departed {
+            return self.depart(member_id);
+        }
         if let Some(current_health) =
             self.health
                 .read()
                 .expect("Health read lock is poisoned")
                 .get(member_id)
         {
-            if *current_health == health {
+            if *current_health == health || *current_health == Health::Departed {
                 return false;
             }
         }
@@ -447,6 +463,34 @@ impl MemberList {
         true
     }
 
+    /// Marks a member as departed and evicts it from the member list. Only the health record is
+    /// kept, so that the member is never allowed back in with the same id. Returns true if the
+    /// member had not already departed.
+    pub fn depart(&self, member_id: &str) -> bool {
+        if self.is_departed(member_id) {
+            return false;
+        }
+        self.health
+            .write()
+            .expect("Health write lock is poisoned")
+            .insert(String::from(member_id), Health::Departed);
+        self.suspect
+            .write()
+            .expect("Suspect lock is poisoned")
+            .remove(member_id);
+        self.members
+            .write()
+            .expect("Member list lock is poisoned")
+            .remove(member_id);
+        self.increment_update_counter();
+        true
+    }
+
+    /// Returns true if the member has departed the ring.
+    pub fn is_departed(&self, member_id: &str) -> bool {
+        self.check_health_of_by_id(member_id, Health::Departed)
+    }
+
     /// The same as `insert_health_by_id`, but takes a member rather than an id.
     pub fn insert_health(&self, member: &Member, health: Health) -> bool {
         self.insert_health_by_id(member.get_id(), health)
@@ -666,6 +710,33 @@ mod tests {
             ml.with_members(|m| assert!(ml.check_health_of(m, Health::Alive)));
         }
 
+        #[test]
+        fn depart_evicts_member() {
+            let ml = populated_member_list(1);
+            let member = Member::default();
+            let member_id = String::from(member.get_id());
+            ml.insert(member, Health::Suspect);
+            assert!(ml.depart(&member_id));
+            assert_eq!(ml.len(), 1);
+            assert_eq!(ml.contains_member(&member_id), false);
+            assert_eq!(ml.health_of_by_id(&member_id), Some(Health::Departed));
+            assert_eq!(ml.depart(&member_id), false);
+        }
+
+        #[test]
+        fn departed_member_cannot_rejoin() {
+            let ml = MemberList::new();
+            let mut member = Member::default();
+            let member_id = String::from(member.get_id());
+            ml.insert(member.clone(), Health::Alive);
+            ml.depart(&member_id);
+            member.set_incarnation(10);
+            assert_eq!(ml.insert(member, Health::Alive), false);
+            assert_eq!(ml.insert_health_by_id(&member_id, Health::Alive), false);
+            assert_eq!(ml.contains_member(&member_id), false);
+            assert!(ml.is_departed(&member_id));
+        }
+
         #[test]
         fn pingreq_targets() {
             let ml = populated_member_list(10);
@@ -143,7 +143,7 @@ impl Serialize for swim::Service {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("service", 7)?;
+        let mut strukt = serializer.serialize_struct("service", 8)?;
         let cfg = toml::from_slice(self.get_cfg()).unwrap_or(toml::value::Table::default());
         strukt.serialize_field("member_id", self.get_member_id())?;
         strukt.serialize_field(
@@ -161,6 +161,7 @@ impl Serialize for swim::Service {
             "initialized",
             &self.get_initialized(),
         )?;
+        strukt.serialize_field("health", &self.get_health())?;
         strukt.end()
     }
 }
@@ -193,7 +194,7 @@ impl Serialize for swim::ServiceFile {
     where
         S: Serializer,
     {
-        let mut strukt = try!(serializer.serialize_struct("service_file", 5));
+        let mut strukt = try!(serializer.serialize_struct("service_file", 6));
         try!(strukt.serialize_field(
             "service_group",
             self.get_service_group(),
@@ -208,6 +209,22 @@ impl Serialize for swim::ServiceFile {
             Ok(c) => try!(strukt.serialize_field("body", c)),
             Err(_) => try!(strukt.serialize_field("body", self.get_body())),
         };
+        try!(strukt.serialize_field("bundle", self.get_bundle()));
+        strukt.end()
+    }
+}
+
+impl Serialize for swim::ServiceFileEntry {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = try!(serializer.serialize_struct("service_file_entry", 2));
+        try!(strukt.serialize_field("filename", self.get_filename()));
+        match str::from_utf8(self.get_body()) {
+            Ok(c) => try!(strukt.serialize_field("body", c)),
+            Err(_) => try!(strukt.serialize_field("body", self.get_body())),
+        };
         strukt.end()
     }
 }
@@ -255,6 +272,15 @@ impl Serialize for swim::Membership_Health {
     }
 }
 
+impl Serialize for swim::Service_Health {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
 impl Serialize for swim::Rumor_Type {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
     where
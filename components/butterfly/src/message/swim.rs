@@ -1548,6 +1548,7 @@ pub enum Membership_Health {
     ALIVE = 1,
     SUSPECT = 2,
     CONFIRMED = 3,
+    DEPARTED = 4,
 }
 
 impl ::protobuf::ProtobufEnum for Membership_Health {
@@ -1560,6 +1561,7 @@ impl ::protobuf::ProtobufEnum for Membership_Health {
             1 => ::std::option::Option::Some(Membership_Health::ALIVE),
             2 => ::std::option::Option::Some(Membership_Health::SUSPECT),
             3 => ::std::option::Option::Some(Membership_Health::CONFIRMED),
+            4 => ::std::option::Option::Some(Membership_Health::DEPARTED),
             _ => ::std::option::Option::None,
         }
     }
@@ -1569,6 +1571,7 @@ impl ::protobuf::ProtobufEnum for Membership_Health {
             Membership_Health::ALIVE,
             Membership_Health::SUSPECT,
             Membership_Health::CONFIRMED,
+            Membership_Health::DEPARTED,
         ];
         values
     }
@@ -2130,6 +2133,7 @@ pub struct Service {
     pkg: ::protobuf::SingularField<::std::string::String>,
     cfg: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     sys: ::protobuf::SingularPtrField<SysInfo>,
+    health: ::std::option::Option<Service_Health>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -2433,6 +2437,33 @@ impl Service {
     fn mut_sys_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<SysInfo> {
         &mut self.sys
     }
+
+    // optional .Service.Health health = 13;
+
+    pub fn clear_health(&mut self) {
+        self.health = ::std::option::Option::None;
+    }
+
+    pub fn has_health(&self) -> bool {
+        self.health.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_health(&mut self, v: Service_Health) {
+        self.health = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_health(&self) -> Service_Health {
+        self.health.unwrap_or(Service_Health::UNKNOWN)
+    }
+
+    fn get_health_for_reflect(&self) -> &::std::option::Option<Service_Health> {
+        &self.health
+    }
+
+    fn mut_health_for_reflect(&mut self) -> &mut ::std::option::Option<Service_Health> {
+        &mut self.health
+    }
 }
 
 impl ::protobuf::Message for Service {
@@ -2484,6 +2515,15 @@ impl ::protobuf::Message for Service {
                 12 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.sys)?;
                 }
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(
+                            ::protobuf::rt::unexpected_wire_type(wire_type),
+                        );
+                    };
+                    let tmp = is.read_enum()?;
+                    self.health = ::std::option::Option::Some(tmp);
+                }
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(
                         field_number,
@@ -2523,6 +2563,9 @@ impl ::protobuf::Message for Service {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        if let Some(v) = self.health {
+            my_size += ::protobuf::rt::enum_size(13, v);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2558,6 +2601,9 @@ impl ::protobuf::Message for Service {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
+        if let Some(v) = self.health {
+            os.write_enum(13, v.value())?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2640,6 +2686,11 @@ impl ::protobuf::MessageStatic for Service {
                     Service::get_sys_for_reflect,
                     Service::mut_sys_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Service_Health>>(
+                    "health",
+                    Service::get_health_for_reflect,
+                    Service::mut_health_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Service>(
                     "Service",
                     fields,
@@ -2659,6 +2710,7 @@ impl ::protobuf::Clear for Service {
         self.clear_pkg();
         self.clear_cfg();
         self.clear_sys();
+        self.clear_health();
         self.unknown_fields.clear();
     }
 }
@@ -2675,6 +2727,66 @@ impl ::protobuf::reflect::ProtobufValue for Service {
     }
 }
 
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Service_Health {
+    OK = 0,
+    WARNING = 1,
+    CRITICAL = 2,
+    UNKNOWN = 3,
+}
+
+impl ::protobuf::ProtobufEnum for Service_Health {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Service_Health> {
+        match value {
+            0 => ::std::option::Option::Some(Service_Health::OK),
+            1 => ::std::option::Option::Some(Service_Health::WARNING),
+            2 => ::std::option::Option::Some(Service_Health::CRITICAL),
+            3 => ::std::option::Option::Some(Service_Health::UNKNOWN),
+            _ => ::std::option::Option::None,
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Service_Health] = &[
+            Service_Health::OK,
+            Service_Health::WARNING,
+            Service_Health::CRITICAL,
+            Service_Health::UNKNOWN,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(
+        _: Option<Service_Health>,
+    ) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> =
+            ::protobuf::lazy::Lazy {
+                lock: ::protobuf::lazy::ONCE_INIT,
+                ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+            };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new(
+                    "Service_Health",
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Service_Health {}
+
+impl ::protobuf::reflect::ProtobufValue for Service_Health {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
 #[derive(PartialEq, Clone, Default)]
 pub struct ServiceConfig {
     // message fields
@@ -3047,6 +3159,7 @@ pub struct ServiceFile {
     encrypted: ::std::option::Option<bool>,
     filename: ::protobuf::SingularField<::std::string::String>,
     body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    bundle: ::protobuf::RepeatedField<ServiceFileEntry>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -3261,10 +3374,48 @@ impl ServiceFile {
     fn mut_body_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
         &mut self.body
     }
+
+    // repeated .ServiceFileEntry bundle = 6;
+
+    pub fn clear_bundle(&mut self) {
+        self.bundle.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bundle(&mut self, v: ::protobuf::RepeatedField<ServiceFileEntry>) {
+        self.bundle = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_bundle(&mut self) -> &mut ::protobuf::RepeatedField<ServiceFileEntry> {
+        &mut self.bundle
+    }
+
+    // Take field
+    pub fn take_bundle(&mut self) -> ::protobuf::RepeatedField<ServiceFileEntry> {
+        ::std::mem::replace(&mut self.bundle, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_bundle(&self) -> &[ServiceFileEntry] {
+        &self.bundle
+    }
+
+    fn get_bundle_for_reflect(&self) -> &::protobuf::RepeatedField<ServiceFileEntry> {
+        &self.bundle
+    }
+
+    fn mut_bundle_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<ServiceFileEntry> {
+        &mut self.bundle
+    }
 }
 
 impl ::protobuf::Message for ServiceFile {
     fn is_initialized(&self) -> bool {
+        for v in &self.bundle {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -3306,6 +3457,9 @@ impl ::protobuf::Message for ServiceFile {
                 5 => {
                     ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body)?;
                 }
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.bundle)?;
+                }
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(
                         field_number,
@@ -3338,6 +3492,10 @@ impl ::protobuf::Message for ServiceFile {
         if let Some(v) = self.body.as_ref() {
             my_size += ::protobuf::rt::bytes_size(5, &v);
         };
+        for value in &self.bundle {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3362,6 +3520,14 @@ impl ::protobuf::Message for ServiceFile {
         if let Some(v) = self.body.as_ref() {
             os.write_bytes(5, &v)?;
         };
+        for v in &self.bundle {
+            os.write_tag(
+                6,
+                ::protobuf::wire_format::WireTypeLengthDelimited,
+            )?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3434,6 +3600,11 @@ impl ::protobuf::MessageStatic for ServiceFile {
                     ServiceFile::get_body_for_reflect,
                     ServiceFile::mut_body_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ServiceFileEntry>>(
+                    "bundle",
+                    ServiceFile::get_bundle_for_reflect,
+                    ServiceFile::mut_bundle_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<ServiceFile>(
                     "ServiceFile",
                     fields,
@@ -3451,6 +3622,7 @@ impl ::protobuf::Clear for ServiceFile {
         self.clear_encrypted();
         self.clear_filename();
         self.clear_body();
+        self.clear_bundle();
         self.unknown_fields.clear();
     }
 }
@@ -3468,141 +3640,403 @@ impl ::protobuf::reflect::ProtobufValue for ServiceFile {
 }
 
 #[derive(PartialEq, Clone, Default)]
-pub struct SysInfo {
+pub struct ServiceFileEntry {
     // message fields
-    ip: ::protobuf::SingularField<::std::string::String>,
-    hostname: ::protobuf::SingularField<::std::string::String>,
-    gossip_ip: ::protobuf::SingularField<::std::string::String>,
-    gossip_port: ::std::option::Option<u32>,
-    http_gateway_ip: ::protobuf::SingularField<::std::string::String>,
-    http_gateway_port: ::std::option::Option<u32>,
+    filename: ::protobuf::SingularField<::std::string::String>,
+    body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for SysInfo {}
+unsafe impl ::std::marker::Sync for ServiceFileEntry {}
 
-impl SysInfo {
-    pub fn new() -> SysInfo {
+impl ServiceFileEntry {
+    pub fn new() -> ServiceFileEntry {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static SysInfo {
-        static mut instance: ::protobuf::lazy::Lazy<SysInfo> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static ServiceFileEntry {
+        static mut instance: ::protobuf::lazy::Lazy<ServiceFileEntry> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SysInfo,
+            ptr: 0 as *const ServiceFileEntry,
         };
-        unsafe { instance.get(SysInfo::new) }
+        unsafe { instance.get(ServiceFileEntry::new) }
     }
 
-    // optional string ip = 1;
+    // optional string filename = 1;
 
-    pub fn clear_ip(&mut self) {
-        self.ip.clear();
+    pub fn clear_filename(&mut self) {
+        self.filename.clear();
     }
 
-    pub fn has_ip(&self) -> bool {
-        self.ip.is_some()
+    pub fn has_filename(&self) -> bool {
+        self.filename.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_ip(&mut self, v: ::std::string::String) {
-        self.ip = ::protobuf::SingularField::some(v);
+    pub fn set_filename(&mut self, v: ::std::string::String) {
+        self.filename = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_ip(&mut self) -> &mut ::std::string::String {
-        if self.ip.is_none() {
-            self.ip.set_default();
+    pub fn mut_filename(&mut self) -> &mut ::std::string::String {
+        if self.filename.is_none() {
+            self.filename.set_default();
         };
-        self.ip.as_mut().unwrap()
+        self.filename.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_ip(&mut self) -> ::std::string::String {
-        self.ip.take().unwrap_or_else(
+    pub fn take_filename(&mut self) -> ::std::string::String {
+        self.filename.take().unwrap_or_else(
             || ::std::string::String::new(),
         )
     }
 
-    pub fn get_ip(&self) -> &str {
-        match self.ip.as_ref() {
+    pub fn get_filename(&self) -> &str {
+        match self.filename.as_ref() {
             Some(v) => &v,
-            None => "127.0.0.1",
+            None => "",
         }
     }
 
-    fn get_ip_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.ip
+    fn get_filename_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.filename
     }
 
-    fn mut_ip_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.ip
+    fn mut_filename_for_reflect(
+        &mut self,
+    ) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.filename
     }
 
-    // optional string hostname = 2;
+    // optional bytes body = 2;
 
-    pub fn clear_hostname(&mut self) {
-        self.hostname.clear();
+    pub fn clear_body(&mut self) {
+        self.body.clear();
     }
 
-    pub fn has_hostname(&self) -> bool {
-        self.hostname.is_some()
+    pub fn has_body(&self) -> bool {
+        self.body.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_hostname(&mut self, v: ::std::string::String) {
-        self.hostname = ::protobuf::SingularField::some(v);
+    pub fn set_body(&mut self, v: ::std::vec::Vec<u8>) {
+        self.body = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_hostname(&mut self) -> &mut ::std::string::String {
-        if self.hostname.is_none() {
-            self.hostname.set_default();
+    pub fn mut_body(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.body.is_none() {
+            self.body.set_default();
         };
-        self.hostname.as_mut().unwrap()
+        self.body.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_hostname(&mut self) -> ::std::string::String {
-        self.hostname.take().unwrap_or_else(
-            || ::std::string::String::new(),
-        )
+    pub fn take_body(&mut self) -> ::std::vec::Vec<u8> {
+        self.body.take().unwrap_or_else(|| ::std::vec::Vec::new())
     }
 
-    pub fn get_hostname(&self) -> &str {
-        match self.hostname.as_ref() {
+    pub fn get_body(&self) -> &[u8] {
+        match self.body.as_ref() {
             Some(v) => &v,
-            None => "localhost",
+            None => &[],
         }
     }
 
-    fn get_hostname_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.hostname
+    fn get_body_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.body
     }
 
-    fn mut_hostname_for_reflect(
-        &mut self,
-    ) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.hostname
+    fn mut_body_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.body
     }
+}
 
-    // optional string gossip_ip = 3;
+impl ::protobuf::Message for ServiceFileEntry {
+    fn is_initialized(&self) -> bool {
+        true
+    }
 
-    pub fn clear_gossip_ip(&mut self) {
-        self.gossip_ip.clear();
+    fn merge_from(
+        &mut self,
+        is: &mut ::protobuf::CodedInputStream,
+    ) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.filename)?;
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(
+                        field_number,
+                        wire_type,
+                        is,
+                        self.mut_unknown_fields(),
+                    )?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    pub fn has_gossip_ip(&self) -> bool {
-        self.gossip_ip.is_some()
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.filename.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        };
+        if let Some(v) = self.body.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(2, &v);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Param is passed by value, moved
-    pub fn set_gossip_ip(&mut self, v: ::std::string::String) {
+    fn write_to_with_cached_sizes(
+        &self,
+        os: &mut ::protobuf::CodedOutputStream,
+    ) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.filename.as_ref() {
+            os.write_string(1, &v)?;
+        };
+        if let Some(v) = self.body.as_ref() {
+            os.write_bytes(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServiceFileEntry {
+    fn new() -> ServiceFileEntry {
+        ServiceFileEntry::new()
+    }
+
+    fn descriptor_static(
+        _: ::std::option::Option<ServiceFileEntry>,
+    ) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> =
+            ::protobuf::lazy::Lazy {
+                lock: ::protobuf::lazy::ONCE_INIT,
+                ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+            };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "filename",
+                    ServiceFileEntry::get_filename_for_reflect,
+                    ServiceFileEntry::mut_filename_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "body",
+                    ServiceFileEntry::get_body_for_reflect,
+                    ServiceFileEntry::mut_body_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceFileEntry>(
+                    "ServiceFileEntry",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServiceFileEntry {
+    fn clear(&mut self) {
+        self.clear_filename();
+        self.clear_body();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ServiceFileEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ServiceFileEntry {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct SysInfo {
+    // message fields
+    ip: ::protobuf::SingularField<::std::string::String>,
+    hostname: ::protobuf::SingularField<::std::string::String>,
+    gossip_ip: ::protobuf::SingularField<::std::string::String>,
+    gossip_port: ::std::option::Option<u32>,
+    http_gateway_ip: ::protobuf::SingularField<::std::string::String>,
+    http_gateway_port: ::std::option::Option<u32>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SysInfo {}
+
+impl SysInfo {
+    pub fn new() -> SysInfo {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SysInfo {
+        static mut instance: ::protobuf::lazy::Lazy<SysInfo> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SysInfo,
+        };
+        unsafe { instance.get(SysInfo::new) }
+    }
+
+    // optional string ip = 1;
+
+    pub fn clear_ip(&mut self) {
+        self.ip.clear();
+    }
+
+    pub fn has_ip(&self) -> bool {
+        self.ip.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ip(&mut self, v: ::std::string::String) {
+        self.ip = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ip(&mut self) -> &mut ::std::string::String {
+        if self.ip.is_none() {
+            self.ip.set_default();
+        };
+        self.ip.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ip(&mut self) -> ::std::string::String {
+        self.ip.take().unwrap_or_else(
+            || ::std::string::String::new(),
+        )
+    }
+
+    pub fn get_ip(&self) -> &str {
+        match self.ip.as_ref() {
+            Some(v) => &v,
+            None => "127.0.0.1",
+        }
+    }
+
+    fn get_ip_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.ip
+    }
+
+    fn mut_ip_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.ip
+    }
+
+    // optional string hostname = 2;
+
+    pub fn clear_hostname(&mut self) {
+        self.hostname.clear();
+    }
+
+    pub fn has_hostname(&self) -> bool {
+        self.hostname.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hostname(&mut self, v: ::std::string::String) {
+        self.hostname = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hostname(&mut self) -> &mut ::std::string::String {
+        if self.hostname.is_none() {
+            self.hostname.set_default();
+        };
+        self.hostname.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_hostname(&mut self) -> ::std::string::String {
+        self.hostname.take().unwrap_or_else(
+            || ::std::string::String::new(),
+        )
+    }
+
+    pub fn get_hostname(&self) -> &str {
+        match self.hostname.as_ref() {
+            Some(v) => &v,
+            None => "localhost",
+        }
+    }
+
+    fn get_hostname_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.hostname
+    }
+
+    fn mut_hostname_for_reflect(
+        &mut self,
+    ) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.hostname
+    }
+
+    // optional string gossip_ip = 3;
+
+    pub fn clear_gossip_ip(&mut self) {
+        self.gossip_ip.clear();
+    }
+
+    pub fn has_gossip_ip(&self) -> bool {
+        self.gossip_ip.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gossip_ip(&mut self, v: ::std::string::String) {
         self.gossip_ip = ::protobuf::SingularField::some(v);
     }
 
@@ -4530,6 +4964,7 @@ pub enum Rumor_oneof_payload {
     service_config(ServiceConfig),
     service_file(ServiceFile),
     election(Election),
+    departure(Departure),
 }
 
 impl Rumor {
@@ -4901,6 +5336,56 @@ impl Rumor {
             _ => Election::default_instance(),
         }
     }
+
+    // optional .Departure departure = 9;
+
+    pub fn clear_departure(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_departure(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::departure(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_departure(&mut self, v: Departure) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::departure(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_departure(&mut self) -> &mut Departure {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::departure(_)) = self.payload {
+        } else {
+            self.payload =
+                ::std::option::Option::Some(Rumor_oneof_payload::departure(Departure::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::departure(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_departure(&mut self) -> Departure {
+        if self.has_departure() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::departure(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Departure::new()
+        }
+    }
+
+    pub fn get_departure(&self) -> &Departure {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::departure(ref v)) => v,
+            _ => Departure::default_instance(),
+        }
+    }
 }
 
 impl ::protobuf::Message for Rumor {
@@ -4983,6 +5468,16 @@ impl ::protobuf::Message for Rumor {
                         Rumor_oneof_payload::election(is.read_message()?),
                     );
                 }
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(
+                            ::protobuf::rt::unexpected_wire_type(wire_type),
+                        );
+                    };
+                    self.payload = ::std::option::Option::Some(
+                        Rumor_oneof_payload::departure(is.read_message()?),
+                    );
+                }
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(
                         field_number,
@@ -5031,6 +5526,10 @@ impl ::protobuf::Message for Rumor {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 }
+                &Rumor_oneof_payload::departure(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                }
             };
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -5093,6 +5592,14 @@ impl ::protobuf::Message for Rumor {
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 }
+                &Rumor_oneof_payload::departure(ref v) => {
+                    os.write_tag(
+                        9,
+                        ::protobuf::wire_format::WireTypeLengthDelimited,
+                    )?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                }
             };
         };
         os.write_unknown_fields(self.get_unknown_fields())?;
@@ -5182,6 +5689,11 @@ impl ::protobuf::MessageStatic for Rumor {
                     Rumor::has_election,
                     Rumor::get_election,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Departure>(
+                    "departure",
+                    Rumor::has_departure,
+                    Rumor::get_departure,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Rumor>(
                     "Rumor",
                     fields,
@@ -5202,6 +5714,7 @@ impl ::protobuf::Clear for Rumor {
         self.clear_service_config();
         self.clear_service_file();
         self.clear_election();
+        self.clear_departure();
         self.unknown_fields.clear();
     }
 }
@@ -5228,6 +5741,7 @@ pub enum Rumor_Type {
     Fake = 6,
     Fake2 = 7,
     ElectionUpdate = 8,
+    Departure = 9,
 }
 
 impl ::protobuf::ProtobufEnum for Rumor_Type {
@@ -5245,6 +5759,7 @@ impl ::protobuf::ProtobufEnum for Rumor_Type {
             6 => ::std::option::Option::Some(Rumor_Type::Fake),
             7 => ::std::option::Option::Some(Rumor_Type::Fake2),
             8 => ::std::option::Option::Some(Rumor_Type::ElectionUpdate),
+            9 => ::std::option::Option::Some(Rumor_Type::Departure),
             _ => ::std::option::Option::None,
         }
     }
@@ -5259,6 +5774,7 @@ impl ::protobuf::ProtobufEnum for Rumor_Type {
             Rumor_Type::Fake,
             Rumor_Type::Fake2,
             Rumor_Type::ElectionUpdate,
+            Rumor_Type::Departure,
         ];
         values
     }
@@ -5365,74 +5881,312 @@ impl Wire {
         self.nonce.as_mut().unwrap()
     }
 
-    // Take field
-    pub fn take_nonce(&mut self) -> ::std::vec::Vec<u8> {
-        self.nonce.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    // Take field
+    pub fn take_nonce(&mut self) -> ::std::vec::Vec<u8> {
+        self.nonce.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_nonce(&self) -> &[u8] {
+        match self.nonce.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    fn get_nonce_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.nonce
+    }
+
+    fn mut_nonce_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.nonce
+    }
+
+    // optional bytes payload = 3;
+
+    pub fn clear_payload(&mut self) {
+        self.payload.clear();
+    }
+
+    pub fn has_payload(&self) -> bool {
+        self.payload.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_payload(&mut self, v: ::std::vec::Vec<u8>) {
+        self.payload = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_payload(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.payload.is_none() {
+            self.payload.set_default();
+        };
+        self.payload.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_payload(&mut self) -> ::std::vec::Vec<u8> {
+        self.payload.take().unwrap_or_else(
+            || ::std::vec::Vec::new(),
+        )
+    }
+
+    pub fn get_payload(&self) -> &[u8] {
+        match self.payload.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    fn get_payload_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.payload
+    }
+
+    fn mut_payload_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.payload
+    }
+}
+
+impl ::protobuf::Message for Wire {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(
+        &mut self,
+        is: &mut ::protobuf::CodedInputStream,
+    ) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(
+                            ::protobuf::rt::unexpected_wire_type(wire_type),
+                        );
+                    };
+                    let tmp = is.read_bool()?;
+                    self.encrypted = ::std::option::Option::Some(tmp);
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.nonce)?;
+                }
+                3 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.payload)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(
+                        field_number,
+                        wire_type,
+                        is,
+                        self.mut_unknown_fields(),
+                    )?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.encrypted {
+            my_size += 2;
+        };
+        if let Some(v) = self.nonce.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(2, &v);
+        };
+        if let Some(v) = self.payload.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(3, &v);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(
+        &self,
+        os: &mut ::protobuf::CodedOutputStream,
+    ) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.encrypted {
+            os.write_bool(1, v)?;
+        };
+        if let Some(v) = self.nonce.as_ref() {
+            os.write_bytes(2, &v)?;
+        };
+        if let Some(v) = self.payload.as_ref() {
+            os.write_bytes(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Wire {
+    fn new() -> Wire {
+        Wire::new()
+    }
+
+    fn descriptor_static(
+        _: ::std::option::Option<Wire>,
+    ) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> =
+            ::protobuf::lazy::Lazy {
+                lock: ::protobuf::lazy::ONCE_INIT,
+                ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+            };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "encrypted",
+                    Wire::get_encrypted_for_reflect,
+                    Wire::mut_encrypted_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "nonce",
+                    Wire::get_nonce_for_reflect,
+                    Wire::mut_nonce_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "payload",
+                    Wire::get_payload_for_reflect,
+                    Wire::mut_payload_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Wire>(
+                    "Wire",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Wire {
+    fn clear(&mut self) {
+        self.clear_encrypted();
+        self.clear_nonce();
+        self.clear_payload();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Wire {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    pub fn get_nonce(&self) -> &[u8] {
-        match self.nonce.as_ref() {
-            Some(v) => &v,
-            None => &[],
-        }
+impl ::protobuf::reflect::ProtobufValue for Wire {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
+}
 
-    fn get_nonce_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
-        &self.nonce
+#[derive(PartialEq, Clone, Default)]
+pub struct Departure {
+    // message fields
+    member_id: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Departure {}
+
+impl Departure {
+    pub fn new() -> Departure {
+        ::std::default::Default::default()
     }
 
-    fn mut_nonce_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
-        &mut self.nonce
+    pub fn default_instance() -> &'static Departure {
+        static mut instance: ::protobuf::lazy::Lazy<Departure> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Departure,
+        };
+        unsafe { instance.get(Departure::new) }
     }
 
-    // optional bytes payload = 3;
+    // optional string member_id = 1;
 
-    pub fn clear_payload(&mut self) {
-        self.payload.clear();
+    pub fn clear_member_id(&mut self) {
+        self.member_id.clear();
     }
 
-    pub fn has_payload(&self) -> bool {
-        self.payload.is_some()
+    pub fn has_member_id(&self) -> bool {
+        self.member_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_payload(&mut self, v: ::std::vec::Vec<u8>) {
-        self.payload = ::protobuf::SingularField::some(v);
+    pub fn set_member_id(&mut self, v: ::std::string::String) {
+        self.member_id = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_payload(&mut self) -> &mut ::std::vec::Vec<u8> {
-        if self.payload.is_none() {
-            self.payload.set_default();
+    pub fn mut_member_id(&mut self) -> &mut ::std::string::String {
+        if self.member_id.is_none() {
+            self.member_id.set_default();
         };
-        self.payload.as_mut().unwrap()
+        self.member_id.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_payload(&mut self) -> ::std::vec::Vec<u8> {
-        self.payload.take().unwrap_or_else(
-            || ::std::vec::Vec::new(),
+    pub fn take_member_id(&mut self) -> ::std::string::String {
+        self.member_id.take().unwrap_or_else(
+            || ::std::string::String::new(),
         )
     }
 
-    pub fn get_payload(&self) -> &[u8] {
-        match self.payload.as_ref() {
+    pub fn get_member_id(&self) -> &str {
+        match self.member_id.as_ref() {
             Some(v) => &v,
-            None => &[],
+            None => "",
         }
     }
 
-    fn get_payload_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
-        &self.payload
+    fn get_member_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.member_id
     }
 
-    fn mut_payload_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
-        &mut self.payload
+    fn mut_member_id_for_reflect(
+        &mut self,
+    ) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.member_id
     }
 }
 
-impl ::protobuf::Message for Wire {
+impl ::protobuf::Message for Departure {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -5445,19 +6199,7 @@ impl ::protobuf::Message for Wire {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(
-                            ::protobuf::rt::unexpected_wire_type(wire_type),
-                        );
-                    };
-                    let tmp = is.read_bool()?;
-                    self.encrypted = ::std::option::Option::Some(tmp);
-                }
-                2 => {
-                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.nonce)?;
-                }
-                3 => {
-                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.payload)?;
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.member_id)?;
                 }
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(
@@ -5476,14 +6218,8 @@ impl ::protobuf::Message for Wire {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.encrypted {
-            my_size += 2;
-        };
-        if let Some(v) = self.nonce.as_ref() {
-            my_size += ::protobuf::rt::bytes_size(2, &v);
-        };
-        if let Some(v) = self.payload.as_ref() {
-            my_size += ::protobuf::rt::bytes_size(3, &v);
+        if let Some(v) = self.member_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -5494,14 +6230,8 @@ impl ::protobuf::Message for Wire {
         &self,
         os: &mut ::protobuf::CodedOutputStream,
     ) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.encrypted {
-            os.write_bool(1, v)?;
-        };
-        if let Some(v) = self.nonce.as_ref() {
-            os.write_bytes(2, &v)?;
-        };
-        if let Some(v) = self.payload.as_ref() {
-            os.write_bytes(3, &v)?;
+        if let Some(v) = self.member_id.as_ref() {
+            os.write_string(1, &v)?;
         };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -5534,13 +6264,13 @@ impl ::protobuf::Message for Wire {
     }
 }
 
-impl ::protobuf::MessageStatic for Wire {
-    fn new() -> Wire {
-        Wire::new()
+impl ::protobuf::MessageStatic for Departure {
+    fn new() -> Departure {
+        Departure::new()
     }
 
     fn descriptor_static(
-        _: ::std::option::Option<Wire>,
+        _: ::std::option::Option<Departure>,
     ) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> =
             ::protobuf::lazy::Lazy {
@@ -5550,23 +6280,13 @@ impl ::protobuf::MessageStatic for Wire {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "encrypted",
-                    Wire::get_encrypted_for_reflect,
-                    Wire::mut_encrypted_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "nonce",
-                    Wire::get_nonce_for_reflect,
-                    Wire::mut_nonce_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "payload",
-                    Wire::get_payload_for_reflect,
-                    Wire::mut_payload_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "member_id",
+                    Departure::get_member_id_for_reflect,
+                    Departure::mut_member_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Wire>(
-                    "Wire",
+                ::protobuf::reflect::MessageDescriptor::new::<Departure>(
+                    "Departure",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5575,22 +6295,20 @@ impl ::protobuf::MessageStatic for Wire {
     }
 }
 
-impl ::protobuf::Clear for Wire {
+impl ::protobuf::Clear for Departure {
     fn clear(&mut self) {
-        self.clear_encrypted();
-        self.clear_nonce();
-        self.clear_payload();
+        self.clear_member_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Wire {
+impl ::std::fmt::Debug for Departure {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Wire {
+impl ::protobuf::reflect::ProtobufValue for Departure {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -6034,7 +6752,7 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x65,
     0x74,
     0x22,
-    0x8a,
+    0x98,
     0x01,
     0x0a,
     0x0a,
@@ -6126,7 +6844,7 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x74,
     0x68,
     0x22,
-    0x2f,
+    0x3d,
     0x0a,
     0x06,
     0x48,
@@ -6174,6 +6892,20 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x44,
     0x10,
     0x03,
+    0x12,
+    0x0c,
+    0x0a,
+    0x08,
+    0x44,
+    0x45,
+    0x50,
+    0x41,
+    0x52,
+    0x54,
+    0x45,
+    0x44,
+    0x10,
+    0x04,
     0x22,
     0xf5,
     0x01,
@@ -6423,8 +7155,8 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x10,
     0x03,
     0x22,
-    0xcf,
-    0x01,
+    0xbb,
+    0x02,
     0x0a,
     0x07,
     0x53,
@@ -6615,23 +7347,131 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x0c,
     0x20,
     0x01,
-    0x28,
-    0x0b,
-    0x32,
+    0x28,
+    0x0b,
+    0x32,
+    0x08,
+    0x2e,
+    0x53,
+    0x79,
+    0x73,
+    0x49,
+    0x6e,
+    0x66,
+    0x6f,
+    0x52,
+    0x03,
+    0x73,
+    0x79,
+    0x73,
+    0x12,
+    0x30,
+    0x0a,
+    0x06,
+    0x68,
+    0x65,
+    0x61,
+    0x6c,
+    0x74,
+    0x68,
+    0x18,
+    0x0d,
+    0x20,
+    0x01,
+    0x28,
+    0x0e,
+    0x32,
+    0x0f,
+    0x2e,
+    0x53,
+    0x65,
+    0x72,
+    0x76,
+    0x69,
+    0x63,
+    0x65,
+    0x2e,
+    0x48,
+    0x65,
+    0x61,
+    0x6c,
+    0x74,
+    0x68,
+    0x3a,
+    0x07,
+    0x55,
+    0x4e,
+    0x4b,
+    0x4e,
+    0x4f,
+    0x57,
+    0x4e,
+    0x52,
+    0x06,
+    0x68,
+    0x65,
+    0x61,
+    0x6c,
+    0x74,
+    0x68,
+    0x22,
+    0x38,
+    0x0a,
+    0x06,
+    0x48,
+    0x65,
+    0x61,
+    0x6c,
+    0x74,
+    0x68,
+    0x12,
+    0x06,
+    0x0a,
+    0x02,
+    0x4f,
+    0x4b,
+    0x10,
+    0x00,
+    0x12,
+    0x0b,
+    0x0a,
+    0x07,
+    0x57,
+    0x41,
+    0x52,
+    0x4e,
+    0x49,
+    0x4e,
+    0x47,
+    0x10,
+    0x01,
+    0x12,
+    0x0c,
+    0x0a,
     0x08,
-    0x2e,
-    0x53,
-    0x79,
-    0x73,
-    0x49,
-    0x6e,
-    0x66,
-    0x6f,
+    0x43,
     0x52,
+    0x49,
+    0x54,
+    0x49,
+    0x43,
+    0x41,
+    0x4c,
+    0x10,
+    0x02,
+    0x12,
+    0x0b,
+    0x0a,
+    0x07,
+    0x55,
+    0x4e,
+    0x4b,
+    0x4e,
+    0x4f,
+    0x57,
+    0x4e,
+    0x10,
     0x03,
-    0x73,
-    0x79,
-    0x73,
     0x22,
     0x8c,
     0x01,
@@ -6776,7 +7616,7 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x69,
     0x67,
     0x22,
-    0xa2,
+    0xcd,
     0x01,
     0x0a,
     0x0b,
@@ -6940,6 +7780,117 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x6f,
     0x64,
     0x79,
+    0x12,
+    0x29,
+    0x0a,
+    0x06,
+    0x62,
+    0x75,
+    0x6e,
+    0x64,
+    0x6c,
+    0x65,
+    0x18,
+    0x06,
+    0x20,
+    0x03,
+    0x28,
+    0x0b,
+    0x32,
+    0x11,
+    0x2e,
+    0x53,
+    0x65,
+    0x72,
+    0x76,
+    0x69,
+    0x63,
+    0x65,
+    0x46,
+    0x69,
+    0x6c,
+    0x65,
+    0x45,
+    0x6e,
+    0x74,
+    0x72,
+    0x79,
+    0x52,
+    0x06,
+    0x62,
+    0x75,
+    0x6e,
+    0x64,
+    0x6c,
+    0x65,
+    0x22,
+    0x42,
+    0x0a,
+    0x10,
+    0x53,
+    0x65,
+    0x72,
+    0x76,
+    0x69,
+    0x63,
+    0x65,
+    0x46,
+    0x69,
+    0x6c,
+    0x65,
+    0x45,
+    0x6e,
+    0x74,
+    0x72,
+    0x79,
+    0x12,
+    0x1a,
+    0x0a,
+    0x08,
+    0x66,
+    0x69,
+    0x6c,
+    0x65,
+    0x6e,
+    0x61,
+    0x6d,
+    0x65,
+    0x18,
+    0x01,
+    0x20,
+    0x01,
+    0x28,
+    0x09,
+    0x52,
+    0x08,
+    0x66,
+    0x69,
+    0x6c,
+    0x65,
+    0x6e,
+    0x61,
+    0x6d,
+    0x65,
+    0x12,
+    0x12,
+    0x0a,
+    0x04,
+    0x62,
+    0x6f,
+    0x64,
+    0x79,
+    0x18,
+    0x02,
+    0x20,
+    0x01,
+    0x28,
+    0x0c,
+    0x52,
+    0x04,
+    0x62,
+    0x6f,
+    0x64,
+    0x79,
     0x22,
     0xf3,
     0x01,
@@ -7417,7 +8368,7 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x61,
     0x64,
     0x22,
-    0xbc,
+    0xf8,
     0x03,
     0x0a,
     0x05,
@@ -7728,8 +8679,53 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x69,
     0x6f,
     0x6e,
+    0x12,
+    0x2a,
+    0x0a,
+    0x09,
+    0x64,
+    0x65,
+    0x70,
+    0x61,
+    0x72,
+    0x74,
+    0x75,
+    0x72,
+    0x65,
+    0x18,
+    0x09,
+    0x20,
+    0x01,
+    0x28,
+    0x0b,
+    0x32,
+    0x0a,
+    0x2e,
+    0x44,
+    0x65,
+    0x70,
+    0x61,
+    0x72,
+    0x74,
+    0x75,
+    0x72,
+    0x65,
+    0x48,
+    0x00,
+    0x52,
+    0x09,
+    0x64,
+    0x65,
+    0x70,
+    0x61,
+    0x72,
+    0x74,
+    0x75,
+    0x72,
+    0x65,
     0x22,
-    0x7a,
+    0x89,
+    0x01,
     0x0a,
     0x04,
     0x54,
@@ -7852,6 +8848,21 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x65,
     0x10,
     0x08,
+    0x12,
+    0x0d,
+    0x0a,
+    0x09,
+    0x44,
+    0x65,
+    0x70,
+    0x61,
+    0x72,
+    0x74,
+    0x75,
+    0x72,
+    0x65,
+    0x10,
+    0x09,
     0x42,
     0x09,
     0x0a,
@@ -7949,6 +8960,48 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x6f,
     0x61,
     0x64,
+    0x22,
+    0x28,
+    0x0a,
+    0x09,
+    0x44,
+    0x65,
+    0x70,
+    0x61,
+    0x72,
+    0x74,
+    0x75,
+    0x72,
+    0x65,
+    0x12,
+    0x1b,
+    0x0a,
+    0x09,
+    0x6d,
+    0x65,
+    0x6d,
+    0x62,
+    0x65,
+    0x72,
+    0x5f,
+    0x69,
+    0x64,
+    0x18,
+    0x01,
+    0x20,
+    0x01,
+    0x28,
+    0x09,
+    0x52,
+    0x08,
+    0x6d,
+    0x65,
+    0x6d,
+    0x62,
+    0x65,
+    0x72,
+    0x49,
+    0x64,
     0x4a,
     0xf4,
     0x29,
@@ -24,10 +24,11 @@ use rand::{Rng, thread_rng};
 use error::{Result, Error};
 use member::{Health, Member, MemberList};
 use message::swim::Membership as ProtoMembership;
-use rumor::{Election, ElectionUpdate, Rumor, RumorStore, Service, ServiceConfig, ServiceFile};
+use rumor::{Departure, Election, ElectionUpdate, Rumor, RumorStore, Service, ServiceConfig,
+            ServiceFile};
 use server::Server;
 
-const HEADER_VERSION: u8 = 1;
+const HEADER_VERSION: u8 = 2;
 
 /// A versioned binary file containing rumors exchanged by the butterfly server which have
 /// been periodically persisted to disk.
@@ -72,12 +73,14 @@ impl DatFile {
             Error::DatFileIO(self.path.clone(), err)
         })?;
         debug!("Header Version: {}", version[0]);
-        self.header = Header::from_file(&mut reader).map_err(|err| {
+        self.header = Header::from_file(&mut reader, version[0]).map_err(|err| {
             Error::DatFileIO(self.path.clone(), err)
         })?;
         debug!("Header: {:?}", self.header);
 
-        reader.seek(SeekFrom::Start(self.member_offset())).map_err(
+        reader
+            .seek(SeekFrom::Start(1 + Header::size(version[0])))
+            .map_err(
             |err| {
                 Error::DatFileIO(self.path.clone(), err)
             },
@@ -202,6 +205,27 @@ impl DatFile {
             server.insert_update_election(rumor);
             bytes_read += size_buf.len() as u64 + rumor_size;
         }
+
+        // Departures are read last, so that they evict the members and service rumors read
+        // before them, as they did when they were first received.
+        debug!("Reading departure rumors from {}", self.path().display());
+        bytes_read = 0;
+        loop {
+            if bytes_read >= self.header.departure_len {
+                break;
+            }
+            reader.read_exact(&mut size_buf).map_err(|err| {
+                Error::DatFileIO(self.path.clone(), err)
+            })?;
+            let rumor_size = LittleEndian::read_u64(&size_buf);
+            rumor_buf.resize(rumor_size as usize, 0);
+            reader.read_exact(&mut rumor_buf).map_err(|err| {
+                Error::DatFileIO(self.path.clone(), err)
+            })?;
+            let rumor = Departure::from_bytes(&rumor_buf)?;
+            server.insert_departure(rumor);
+            bytes_read += size_buf.len() as u64 + rumor_size;
+        }
         Ok(())
     }
 
@@ -234,6 +258,7 @@ impl DatFile {
             )?;
             header.election_len = self.write_rumor_store(&mut writer, &server.election_store)?;
             header.update_len = self.write_rumor_store(&mut writer, &server.update_store)?;
+            header.departure_len = self.write_rumor_store(&mut writer, &server.departure_store)?;
             writer.seek(SeekFrom::Start(1)).map_err(|err| {
                 Error::DatFileIO(self.path.clone(), err)
             })?;
@@ -292,6 +317,11 @@ impl DatFile {
         self.election_offset() + self.header.election_len
     }
 
+    #[allow(dead_code)]
+    fn departure_offset(&self) -> u64 {
+        self.update_offset() + self.header.update_len
+    }
+
     fn write_header<W>(&self, writer: &mut W, header: &Header) -> Result<usize>
     where
         W: Write,
@@ -385,14 +415,24 @@ pub struct Header {
     pub service_file_len: u64,
     pub election_len: u64,
     pub update_len: u64,
+    pub departure_len: u64,
 }
 
 impl Header {
-    pub fn from_file<R>(reader: &mut R) -> io::Result<Self>
+    /// Returns the size in bytes of the header of a dat file of the given version. Version 1
+    /// files were written before departures were persisted.
+    pub fn size(version: u8) -> u64 {
+        match version {
+            1 => 48,
+            _ => mem::size_of::<Self>() as u64,
+        }
+    }
+
+    pub fn from_file<R>(reader: &mut R, version: u8) -> io::Result<Self>
     where
         R: Read,
     {
-        let mut bytes = vec![0; mem::size_of::<Self>()];
+        let mut bytes = vec![0; Self::size(version) as usize];
         reader.read_exact(&mut bytes)?;
         Ok(Self::from_bytes(&bytes))
     }
@@ -405,6 +445,11 @@ impl Header {
             service_file_len: LittleEndian::read_u64(&bytes[24..32]),
             election_len: LittleEndian::read_u64(&bytes[32..40]),
             update_len: LittleEndian::read_u64(&bytes[40..48]),
+            departure_len: if bytes.len() >= 56 {
+                LittleEndian::read_u64(&bytes[48..56])
+            } else {
+                0
+            },
         }
     }
 
@@ -416,6 +461,7 @@ impl Header {
         LittleEndian::write_u64(&mut bytes[24..32], self.service_file_len);
         LittleEndian::write_u64(&mut bytes[32..40], self.election_len);
         LittleEndian::write_u64(&mut bytes[40..48], self.update_len);
+        LittleEndian::write_u64(&mut bytes[48..56], self.departure_len);
         Ok(bytes)
     }
 }
@@ -424,9 +470,37 @@ impl Header {
 mod tests {
     use std::mem;
 
+    use habitat_core::service::ServiceGroup;
     use rand;
+    use tempdir::TempDir;
+
+    use member::{Health, Member};
+    use rumor::Departure;
+    use server::{Server, Suitability};
+    use trace::Trace;
     use super::*;
 
+    #[derive(Debug)]
+    struct ZeroSuitability;
+    impl Suitability for ZeroSuitability {
+        fn get(&self, _service_group: &ServiceGroup) -> u64 {
+            0
+        }
+    }
+
+    fn server() -> Server {
+        Server::new(
+            "127.0.0.1:0",
+            "127.0.0.1:0",
+            Member::default(),
+            Trace::default(),
+            None,
+            None,
+            None::<PathBuf>,
+            Box::new(ZeroSuitability),
+        ).unwrap()
+    }
+
     #[test]
     fn read_write_header() {
         let mut original = Header::default();
@@ -435,9 +509,41 @@ mod tests {
         original.service_file_len = rand::random::<u64>();
         original.election_len = rand::random::<u64>();
         original.update_len = rand::random::<u64>();
+        original.departure_len = rand::random::<u64>();
         let bytes = original.write_to_bytes().unwrap();
         let restored = Header::from_bytes(&bytes);
         assert_eq!(bytes.len(), mem::size_of::<Header>());
         assert_eq!(original, restored);
     }
+
+    #[test]
+    fn read_header_of_version_1() {
+        let mut bytes = Header::default().write_to_bytes().unwrap();
+        LittleEndian::write_u64(&mut bytes[40..48], 12);
+        LittleEndian::write_u64(&mut bytes[48..56], 34);
+        let restored = Header::from_file(&mut &bytes[..], 1).unwrap();
+        assert_eq!(restored.update_len, 12);
+        assert_eq!(restored.departure_len, 0);
+    }
+
+    #[test]
+    fn departures_survive_a_restart() {
+        let dir = TempDir::new("butterfly-dat-file").unwrap();
+        let original = server();
+        let mut member = Member::default();
+        member.set_id(String::from("adam"));
+        original.insert_member(member, Health::Alive);
+        original.insert_departure(Departure::new("butterflyclient", "adam"));
+        DatFile::new("member", dir.path()).write(&original).unwrap();
+
+        let restored = server();
+        let mut dat_file = DatFile::new("member", dir.path());
+        dat_file.read_into(&restored).unwrap();
+        assert!(dat_file.header.departure_len > 0);
+        assert!(restored.departure_store.contains_rumor("departure", "adam"));
+        assert_eq!(
+            restored.member_list.health_of_by_id("adam"),
+            Some(Health::Departed)
+        );
+    }
 }
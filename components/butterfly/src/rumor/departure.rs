@@ -0,0 +1,145 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The Departure rumor.
+//!
+//! Announces that a member has permanently left the ring. Once a departure has been gossiped, the
+//! member is evicted and is never allowed to rejoin with the same id.
+
+use std::ops::{Deref, DerefMut};
+
+use protobuf::{self, Message};
+
+use error::Result;
+use message::swim::{Departure as ProtoDeparture, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Departure(ProtoRumor);
+
+impl PartialEq for Departure {
+    fn eq(&self, other: &Departure) -> bool {
+        self.get_member_id() == other.get_member_id()
+    }
+}
+
+impl From<ProtoRumor> for Departure {
+    fn from(pr: ProtoRumor) -> Departure {
+        Departure(pr)
+    }
+}
+
+impl From<Departure> for ProtoRumor {
+    fn from(departure: Departure) -> ProtoRumor {
+        departure.0
+    }
+}
+
+impl Deref for Departure {
+    type Target = ProtoDeparture;
+
+    fn deref(&self) -> &ProtoDeparture {
+        self.0.get_departure()
+    }
+}
+
+impl DerefMut for Departure {
+    fn deref_mut(&mut self) -> &mut ProtoDeparture {
+        self.0.mut_departure()
+    }
+}
+
+impl Departure {
+    /// Creates a new Departure, sent by `member_id`, for the member with `departed_id`.
+    pub fn new<S1, S2>(member_id: S1, departed_id: S2) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let mut rumor = ProtoRumor::new();
+        let from_id = member_id.into();
+        rumor.set_from_id(from_id);
+        rumor.set_field_type(ProtoRumor_Type::Departure);
+
+        let mut proto = ProtoDeparture::new();
+        proto.set_member_id(departed_id.into());
+        rumor.set_departure(proto);
+        Departure(rumor)
+    }
+}
+
+impl Rumor for Departure {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let rumor = protobuf::parse_from_bytes::<ProtoRumor>(bytes)?;
+        Ok(Departure::from(rumor))
+    }
+
+    /// A departure is final; there is nothing to merge once we know a member has left.
+    fn merge(&mut self, _other: Departure) -> bool {
+        false
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::Departure
+    }
+
+    fn id(&self) -> &str {
+        self.get_member_id()
+    }
+
+    fn key(&self) -> &str {
+        "departure"
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(try!(self.0.write_to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Departure;
+    use rumor::Rumor;
+
+    #[test]
+    fn departures_for_the_same_member_are_equal() {
+        let d1 = Departure::new("butterflyclient", "adam");
+        let d2 = Departure::new("butterflyclient", "adam");
+        assert_eq!(d1, d2);
+    }
+
+    #[test]
+    fn merge_never_changes_a_departure() {
+        let mut d1 = Departure::new("butterflyclient", "adam");
+        let d2 = Departure::new("butterflyclient", "adam");
+        assert_eq!(d1.merge(d2), false);
+        assert_eq!(d1.get_member_id(), "adam");
+    }
+
+    #[test]
+    fn id_is_the_departed_member() {
+        let d1 = Departure::new("butterflyclient", "adam");
+        assert_eq!(d1.id(), "adam");
+        assert_eq!(d1.key(), "departure");
+    }
+
+    #[test]
+    fn round_trips_through_bytes() {
+        let d1 = Departure::new("butterflyclient", "adam");
+        let bytes = d1.write_to_bytes().unwrap();
+        let d2 = Departure::from_bytes(&bytes).unwrap();
+        assert_eq!(d1, d2);
+    }
+}
@@ -24,17 +24,19 @@
 //! new rumors, and dispatch them according to their `kind`.
 
 pub mod dat_file;
+pub mod departure;
 pub mod election;
 pub mod service;
 pub mod service_config;
 pub mod service_file;
 
+pub use self::departure::Departure;
 pub use self::election::{Election, ElectionUpdate};
 pub use self::service::Service;
 pub use self::service_config::ServiceConfig;
 pub use self::service_file::ServiceFile;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::collections::hash_map::Entry;
 use std::default::Default;
 use std::ops::Deref;
@@ -199,6 +201,49 @@ impl<T: Rumor> RumorStore<T> {
         list.get_mut(key).and_then(|mut r| r.remove(id));
     }
 
+    /// Remove every rumor with the given id, whatever key it is stored under. Returns true if
+    /// anything was removed.
+    pub fn remove_id(&self, id: &str) -> bool {
+        let mut list = self.list.write().expect("Rumor store lock poisoned");
+        let mut removed = false;
+        for rumors in list.values_mut() {
+            if rumors.remove(id).is_some() {
+                removed = true;
+            }
+        }
+        if removed {
+            self.increment_update_counter();
+        }
+        removed
+    }
+
+    /// Remove every rumor stored under the given key. Returns true if anything was removed.
+    pub fn remove_key(&self, key: &str) -> bool {
+        let mut list = self.list.write().expect("Rumor store lock poisoned");
+        let removed = match list.remove(key) {
+            Some(rumors) => !rumors.is_empty(),
+            None => false,
+        };
+        if removed {
+            self.increment_update_counter();
+        }
+        removed
+    }
+
+    /// Returns the keys of every rumor in this RumorStore.
+    pub fn keys(&self) -> Vec<String> {
+        let list = self.list.read().expect("Rumor store lock poisoned");
+        list.keys().cloned().collect()
+    }
+
+    /// Returns the ids of every rumor in this RumorStore, whatever key they are stored under.
+    pub fn ids(&self) -> HashSet<String> {
+        let list = self.list.read().expect("Rumor store lock poisoned");
+        list.values()
+            .flat_map(|rumors| rumors.keys().cloned())
+            .collect()
+    }
+
     pub fn with_keys<F>(&self, mut with_closure: F)
     where
         F: FnMut((&String, &HashMap<String, T>)),
@@ -287,6 +332,28 @@ impl RumorList {
         rumors.insert(rk, HashMap::new());
     }
 
+    /// Stop sharing every rumor with the given id.
+    pub fn remove_id(&self, id: &str) {
+        let mut rumors = self.rumor_list.write().expect("Rumor Map lock poisoned");
+        let keys: Vec<RumorKey> = rumors.keys().filter(|rk| rk.id == id).cloned().collect();
+        for rk in keys {
+            rumors.remove(&rk);
+        }
+    }
+
+    /// Stop sharing every rumor of the given kind stored under the given key.
+    pub fn remove_key(&self, kind: Rumor_Type, key: &str) {
+        let mut rumors = self.rumor_list.write().expect("Rumor Map lock poisoned");
+        let keys: Vec<RumorKey> = rumors
+            .keys()
+            .filter(|rk| rk.kind == kind && rk.key == key)
+            .cloned()
+            .collect();
+        for rk in keys {
+            rumors.remove(&rk);
+        }
+    }
+
     /// Return a list of rumors, along with their current heat, sorted by heat. Lowest to highest.
     /// So all the "0" rumors sort higher than the "2" rumors.
     pub fn rumors(&self, id: &str) -> RumorVec {
@@ -536,6 +603,57 @@ mod tests {
             let rs = create_rumor_store();
             rs.with_rumor("bar", "foo", |o| assert!(o.is_none()));
         }
+
+        #[test]
+        fn remove_id_removes_rumors_under_every_key() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let mut f2 = FakeRumor::default();
+            f2.id = f1.id.clone();
+            f2.key = String::from("other");
+            let f3 = FakeRumor::default();
+            let f1_id = f1.id.clone();
+            rs.insert(f1);
+            rs.insert(f2);
+            rs.insert(f3);
+            assert!(rs.remove_id(&f1_id));
+            assert_eq!(rs.len(), 1);
+            assert_eq!(rs.remove_id(&f1_id), false);
+        }
+
+        #[test]
+        fn remove_key_removes_every_rumor_for_the_key() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let f2 = FakeRumor::default();
+            let mut f3 = FakeRumor::default();
+            f3.key = String::from("other");
+            rs.insert(f1);
+            rs.insert(f2);
+            rs.insert(f3);
+            let counter = rs.get_update_counter();
+            assert!(rs.remove_key("fakerton"));
+            assert_eq!(rs.len(), 1);
+            assert_eq!(rs.keys(), vec![String::from("other")]);
+            assert_eq!(rs.get_update_counter(), counter + 1);
+            assert_eq!(rs.remove_key("fakerton"), false);
+        }
+
+        #[test]
+        fn ids_returns_ids_under_every_key() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let mut f2 = FakeRumor::default();
+            f2.key = String::from("other");
+            let f1_id = f1.id.clone();
+            let f2_id = f2.id.clone();
+            rs.insert(f1);
+            rs.insert(f2);
+            let ids = rs.ids();
+            assert_eq!(ids.len(), 2);
+            assert!(ids.contains(&f1_id));
+            assert!(ids.contains(&f2_id));
+        }
     }
 
     mod rumor_list {
@@ -593,6 +711,35 @@ mod tests {
             );
         }
 
+        #[test]
+        fn remove_id() {
+            let rl = RumorList::default();
+            let rumor = FakeRumor::default();
+            rl.insert(&rumor);
+            rl.insert(&FakeRumor::default());
+            rl.remove_id(&rumor.id);
+            let rumors = rl.rumors(&String::from("fake"));
+            assert_eq!(rumors.len(), 1);
+            assert!(rumors[0].0.id != rumor.id);
+        }
+
+        #[test]
+        fn remove_key_only_removes_the_given_kind() {
+            let rl = RumorList::default();
+            let rumor = FakeRumor::default();
+            let mut other = FakeRumor::default();
+            other.key = String::from("other");
+            rl.insert(&rumor);
+            rl.insert(&other);
+            rl.insert(&TrumpRumor::default());
+            rl.remove_key(Rumor_Type::Fake, "fakerton");
+            let rumors = rl.rumors(&String::from("fake"));
+            assert_eq!(rumors.len(), 2);
+            assert!(rumors.iter().all(|&(ref rk, ref _heat)| {
+                rk.kind == Rumor_Type::Fake2 || rk.key == "other"
+            }));
+        }
+
         #[test]
         fn update_heat() {
             let rl = RumorList::default();
@@ -25,7 +25,7 @@ use habitat_core::package::Identifiable;
 use protobuf::{self, Message};
 use toml;
 
-pub use message::swim::SysInfo;
+pub use message::swim::{Service_Health, SysInfo};
 use error::Result;
 use message::swim::{Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type, Service as ProtoService};
 use rumor::Rumor;
@@ -169,7 +169,7 @@ mod tests {
 
     use super::Service;
     use rumor::Rumor;
-    use rumor::service::SysInfo;
+    use rumor::service::{Service_Health, SysInfo};
 
     fn create_service(member_id: &str) -> Service {
         let pkg = PackageIdent::from_str("core/neurosis/1.2.3/20161208121212").unwrap();
@@ -255,6 +255,16 @@ mod tests {
         assert_eq!(s1, s1_check);
     }
 
+    #[test]
+    fn health_round_trips_through_bytes() {
+        let mut s1 = create_service("adam");
+        assert_eq!(s1.get_health(), Service_Health::UNKNOWN);
+        s1.set_health(Service_Health::CRITICAL);
+        let bytes = s1.write_to_bytes().unwrap();
+        let s2 = Service::from_bytes(&bytes).unwrap();
+        assert_eq!(s2.get_health(), Service_Health::CRITICAL);
+    }
+
     #[test]
     #[should_panic]
     fn service_package_name_mismatch() {
@@ -14,7 +14,8 @@
 
 //! The ServiceFile rumor.
 //!
-//! Holds the toml configuration injected for a service.
+//! Holds a file injected for a service. A bundle holds several files which are always updated
+//! together, under a single incarnation.
 
 use std::cmp::Ordering;
 use std::mem;
@@ -22,10 +23,10 @@ use std::ops::{Deref, DerefMut};
 
 use habitat_core::crypto::{BoxKeyPair, default_cache_key_path};
 use habitat_core::service::ServiceGroup;
-use protobuf::{self, Message};
+use protobuf::{self, Message, RepeatedField};
 
 use error::Result;
-use message::swim::{ServiceFile as ProtoServiceFile, Rumor as ProtoRumor,
+use message::swim::{ServiceFile as ProtoServiceFile, ServiceFileEntry, Rumor as ProtoRumor,
                     Rumor_Type as ProtoRumor_Type};
 use rumor::Rumor;
 
@@ -48,7 +49,8 @@ impl PartialEq for ServiceFile {
             self.get_incarnation() == other.get_incarnation() &&
             self.get_encrypted() == other.get_encrypted() &&
             self.get_filename() == other.get_filename() &&
-            self.get_body() == other.get_body()
+            self.get_body() == other.get_body() &&
+            self.get_bundle() == other.get_bundle()
     }
 }
 
@@ -105,11 +107,51 @@ impl ServiceFile {
         ServiceFile(rumor)
     }
 
-    /// Encrypt the contents of the service file
+    /// Creates a new ServiceFile bundle named `name`, holding every file in `files` as a
+    /// `(filename, body)` pair.
+    pub fn new_bundle<S1, S2>(
+        member_id: S1,
+        service_group: ServiceGroup,
+        name: S2,
+        files: Vec<(String, Vec<u8>)>,
+    ) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let mut service_file = ServiceFile::new(member_id, service_group, name, Vec::new());
+        service_file.clear_body();
+        let entries = files
+            .into_iter()
+            .map(|(filename, body)| {
+                let mut entry = ServiceFileEntry::new();
+                entry.set_filename(filename);
+                entry.set_body(body);
+                entry
+            })
+            .collect();
+        service_file.set_bundle(RepeatedField::from_vec(entries));
+        service_file
+    }
+
+    /// Returns true if this is a bundle of several files rather than a single file.
+    pub fn is_bundle(&self) -> bool {
+        !self.get_bundle().is_empty()
+    }
+
+    /// Encrypt the contents of the service file, or of every file in the bundle.
     pub fn encrypt(&mut self, user_pair: &BoxKeyPair, service_pair: &BoxKeyPair) -> Result<()> {
-        let body = self.take_body();
-        let encrypted_body = try!(user_pair.encrypt(&body, service_pair));
-        self.set_body(encrypted_body);
+        if self.is_bundle() {
+            for entry in self.mut_bundle().iter_mut() {
+                let body = entry.take_body();
+                let encrypted_body = try!(user_pair.encrypt(&body, service_pair));
+                entry.set_body(encrypted_body);
+            }
+        } else {
+            let body = self.take_body();
+            let encrypted_body = try!(user_pair.encrypt(&body, service_pair));
+            self.set_body(encrypted_body);
+        }
         self.set_encrypted(true);
         Ok(())
     }
@@ -117,14 +159,31 @@ impl ServiceFile {
     /// Return the body of the service file as a stream of bytes. Always returns a new copy, due to
     /// the fact that we might be encrypted.
     pub fn body(&self) -> Result<Vec<u8>> {
+        self.decrypt(self.get_body())
+    }
+
+    /// Return every file held by this rumor as a `(filename, body)` pair; a single file for a
+    /// plain service file, or every file of a bundle. Always returns new copies, due to the fact
+    /// that we might be encrypted.
+    pub fn files(&self) -> Result<Vec<(String, Vec<u8>)>> {
+        if self.is_bundle() {
+            let mut files = Vec::with_capacity(self.get_bundle().len());
+            for entry in self.get_bundle() {
+                let body = try!(self.decrypt(entry.get_body()));
+                files.push((entry.get_filename().to_string(), body));
+            }
+            Ok(files)
+        } else {
+            Ok(vec![(self.get_filename().to_string(), try!(self.body()))])
+        }
+    }
+
+    fn decrypt(&self, bytes: &[u8]) -> Result<Vec<u8>> {
         if self.get_encrypted() {
-            let bytes = try!(BoxKeyPair::decrypt(
-                self.get_body(),
-                &default_cache_key_path(None),
-            ));
+            let bytes = try!(BoxKeyPair::decrypt(bytes, &default_cache_key_path(None)));
             Ok(bytes)
         } else {
-            Ok(self.get_body().to_vec())
+            Ok(bytes.to_vec())
         }
     }
 }
@@ -252,4 +311,66 @@ mod tests {
             String::from("tcp-backlog = 128")
         );
     }
+
+    fn create_bundle(member_id: &str, incarnation: u64) -> ServiceFile {
+        let mut bundle = ServiceFile::new_bundle(
+            member_id,
+            ServiceGroup::new("neurosis", "production", None).unwrap(),
+            "tls",
+            vec![
+                (String::from("cert.pem"), Vec::from("certificate")),
+                (String::from("key.pem"), Vec::from("private key")),
+            ],
+        );
+        bundle.set_incarnation(incarnation);
+        bundle
+    }
+
+    #[test]
+    fn files_returns_the_single_file() {
+        let s1 = create_service_file("adam", "yep", "tcp-backlog = 128");
+        assert!(!s1.is_bundle());
+        assert_eq!(
+            s1.files().unwrap(),
+            vec![(String::from("yep"), Vec::from("tcp-backlog = 128"))]
+        );
+    }
+
+    #[test]
+    fn files_returns_every_file_in_a_bundle() {
+        let b1 = create_bundle("adam", 0);
+        assert!(b1.is_bundle());
+        assert_eq!(b1.id(), "tls");
+        assert_eq!(
+            b1.files().unwrap(),
+            vec![
+                (String::from("cert.pem"), Vec::from("certificate")),
+                (String::from("key.pem"), Vec::from("private key")),
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_replaces_the_whole_bundle() {
+        let mut b1 = create_bundle("adam", 0);
+        let mut b2 = ServiceFile::new_bundle(
+            "adam",
+            ServiceGroup::new("neurosis", "production", None).unwrap(),
+            "tls",
+            vec![(String::from("cert.pem"), Vec::from("new certificate"))],
+        );
+        b2.set_incarnation(1);
+        let b2_check = b2.clone();
+        assert_eq!(b1.merge(b2), true);
+        assert_eq!(b1, b2_check);
+        assert_eq!(b1.files().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn bundle_round_trips_through_bytes() {
+        let b1 = create_bundle("adam", 3);
+        let bytes = b1.write_to_bytes().unwrap();
+        let b2 = ServiceFile::from_bytes(&bytes).unwrap();
+        assert_eq!(b1, b2);
+    }
 }
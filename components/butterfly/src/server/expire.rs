@@ -12,15 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Expire suspected members.
+//! Expire suspected members, and the rumors of members which are gone.
 //!
 //! This module keeps track of suspected members, and sets their status to confirmed if they remain
-//! suspect long enough.
+//! suspect long enough. The service rumors of members which have departed, or which remain
+//! confirmed for longer than the rumor TTL, are expired; so are the service config, service file
+//! and election rumors of service groups which lost their last member longer than the rumor TTL
+//! ago.
 
+use std::collections::{HashMap, HashSet};
 use std::thread;
 use std::time::Duration;
 
-use time::SteadyTime;
+use time::{Duration as TimeDuration, SteadyTime};
 
 use message::swim::Rumor_Type;
 use member::Health;
@@ -32,6 +36,9 @@ use trace::TraceKind;
 pub struct Expire {
     pub server: Server,
     pub timing: Timing,
+    /// When each rumor owner was first seen confirmed, or unknown to the member list.
+    confirmed_since: HashMap<String, SteadyTime>,
+    orphaned: OrphanedGroups,
 }
 
 impl Expire {
@@ -40,21 +47,20 @@ impl Expire {
         Expire {
             server: server,
             timing: timing,
+            confirmed_since: HashMap::new(),
+            orphaned: OrphanedGroups::default(),
         }
     }
 
     /// Run the expire thread.
-    pub fn run(&self) {
+    pub fn run(&mut self) {
         loop {
             let mut expired_list: Vec<String> = Vec::new();
             self.server.member_list.with_suspects(|(id, suspect)| {
                 let now = SteadyTime::now();
                 if *suspect + self.timing.suspicion_timeout_duration() > now {
                     expired_list.push(String::from(id));
-                    self.server.member_list.insert_health_by_id(
-                        id,
-                        Health::Confirmed,
-                    );
+                    self.server.mark_member_health_by_id(id, Health::Confirmed);
                     self.server.member_list.with_member(id, |has_member| {
                         let member = has_member.expect("Member does not exist when expiring it");
                         debug!("Marking {:?} as Confirmed", member);
@@ -72,7 +78,189 @@ impl Expire {
                     "",
                 ));
             }
+            let now = SteadyTime::now();
+            let expired_members = self.expire_member_rumors(now);
+            let expired_groups = self.expire_service_group_rumors(now);
+            if expired_members || expired_groups {
+                self.server.persist_data();
+            }
             thread::sleep(Duration::from_millis(500));
         }
     }
+
+    /// Expires the service rumors of members which have departed, or which have been confirmed
+    /// or unknown for longer than the rumor TTL. Returns true if any rumor was expired.
+    fn expire_member_rumors(&mut self, now: SteadyTime) -> bool {
+        let owners = self.server.service_store.ids();
+        self.confirmed_since.retain(|id, _| owners.contains(id));
+        let mut expired = false;
+        for owner in owners.iter() {
+            if owner == self.server.member_id() {
+                continue;
+            }
+            let expire = match self.server.member_list.health_of_by_id(owner) {
+                Some(Health::Departed) => true,
+                Some(Health::Confirmed) | None => {
+                    let since = self.confirmed_since.entry(owner.clone()).or_insert(now);
+                    *since + self.timing.rumor_ttl_duration() <= now
+                }
+                Some(_) => {
+                    self.confirmed_since.remove(owner);
+                    false
+                }
+            };
+            if expire && self.server.expire_member_rumors(owner) {
+                debug!("Expired the service rumors of {}", owner);
+                self.confirmed_since.remove(owner);
+                expired = true;
+            }
+        }
+        expired
+    }
+
+    /// Expires the service config, service file and election rumors of service groups which lost
+    /// their last service rumor longer than the rumor TTL ago. Returns true if any rumor was
+    /// expired.
+    fn expire_service_group_rumors(&mut self, now: SteadyTime) -> bool {
+        let mut groups = self.server.service_store.keys();
+        groups.extend(self.server.service_config_store.keys());
+        groups.extend(self.server.service_file_store.keys());
+        groups.extend(self.server.election_store.keys());
+        groups.extend(self.server.update_store.keys());
+        groups.sort();
+        groups.dedup();
+        let groups: Vec<(String, bool)> = groups
+            .into_iter()
+            .map(|group| {
+                let has_members = self.server.service_store.len_for_key(&group) > 0;
+                (group, has_members)
+            })
+            .collect();
+        let mut expired = false;
+        for group in self.orphaned
+            .expired(groups, now, self.timing.rumor_ttl_duration())
+            .iter()
+        {
+            if self.server.expire_service_group_rumors(group) {
+                debug!("Expired the rumors of service group {}", group);
+                expired = true;
+            }
+            self.orphaned.forget(group);
+        }
+        expired
+    }
+}
+
+/// Tracks the service groups which have lost their last service rumor. A group which has never
+/// had one is left alone, since its config may well have been applied before any member loaded
+/// the service.
+#[derive(Default)]
+struct OrphanedGroups {
+    /// Service groups which have had service rumors since their rumors were last expired.
+    populated: HashSet<String>,
+    /// When each populated service group was first seen without any service rumors.
+    since: HashMap<String, SteadyTime>,
+}
+
+impl OrphanedGroups {
+    /// Takes every service group we have rumors for, along with whether it has any service
+    /// rumors, and returns the groups which have been orphaned for at least the given TTL.
+    fn expired(
+        &mut self,
+        groups: Vec<(String, bool)>,
+        now: SteadyTime,
+        ttl: TimeDuration,
+    ) -> Vec<String> {
+        let mut orphans = Vec::new();
+        for &(ref group, has_members) in groups.iter() {
+            if has_members {
+                self.populated.insert(group.clone());
+            } else if self.populated.contains(group) {
+                orphans.push(group.clone());
+            }
+        }
+        self.populated.retain(
+            |group| groups.iter().any(|&(ref g, _)| g == group),
+        );
+        self.since.retain(|group, _| orphans.contains(group));
+        orphans
+            .into_iter()
+            .filter(|group| {
+                *self.since.entry(group.clone()).or_insert(now) + ttl <= now
+            })
+            .collect()
+    }
+
+    /// Forget a service group whose rumors were expired, so that any it is given afterwards are
+    /// kept until a member loads it again.
+    fn forget(&mut self, group: &str) {
+        self.populated.remove(group);
+        self.since.remove(group);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Duration as TimeDuration, SteadyTime};
+
+    use super::OrphanedGroups;
+
+    fn ttl() -> TimeDuration {
+        TimeDuration::seconds(60)
+    }
+
+    fn group(has_members: bool) -> Vec<(String, bool)> {
+        vec![(String::from("redis.default"), has_members)]
+    }
+
+    #[test]
+    fn config_staged_before_any_member_loads_is_kept() {
+        let now = SteadyTime::now();
+        let mut orphaned = OrphanedGroups::default();
+        assert!(orphaned.expired(group(false), now, ttl()).is_empty());
+        assert!(
+            orphaned
+                .expired(group(false), now + ttl() + ttl(), ttl())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn group_expires_once_its_last_member_is_gone_for_the_ttl() {
+        let now = SteadyTime::now();
+        let mut orphaned = OrphanedGroups::default();
+        assert!(orphaned.expired(group(true), now, ttl()).is_empty());
+        assert!(orphaned.expired(group(false), now, ttl()).is_empty());
+        assert!(
+            orphaned
+                .expired(group(false), now + TimeDuration::seconds(59), ttl())
+                .is_empty()
+        );
+        assert_eq!(
+            orphaned.expired(group(false), now + ttl(), ttl()),
+            vec![String::from("redis.default")]
+        );
+
+        // Config given again after the group was expired waits on a member again
+        orphaned.forget("redis.default");
+        assert!(
+            orphaned
+                .expired(group(false), now + ttl() + ttl(), ttl())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn group_is_no_longer_orphaned_once_a_member_returns() {
+        let now = SteadyTime::now();
+        let mut orphaned = OrphanedGroups::default();
+        orphaned.expired(group(true), now, ttl());
+        orphaned.expired(group(false), now, ttl());
+        orphaned.expired(group(true), now + TimeDuration::seconds(30), ttl());
+        assert!(
+            orphaned
+                .expired(group(false), now + ttl(), ttl())
+                .is_empty()
+        );
+    }
 }
@@ -43,20 +43,38 @@ use std::thread;
 
 use habitat_core::service::ServiceGroup;
 use habitat_core::crypto::SymKey;
+use prometheus::{CounterVec, GaugeVec};
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
 
 use error::{Result, Error};
 use member::{Member, Health, MemberList};
 use message;
+use message::swim::Rumor_Type;
 use rumor::{Rumor, RumorList, RumorKey, RumorStore};
 use rumor::dat_file::DatFile;
+use rumor::departure::Departure;
 use rumor::service::Service;
 use rumor::service_config::ServiceConfig;
 use rumor::service_file::ServiceFile;
 use rumor::election::{Election, ElectionUpdate};
 use trace::{Trace, TraceKind};
 
+lazy_static! {
+    static ref RUMORS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_butterfly_rumors",
+            "Number of rumors held in each rumor store."),
+        &["store"]).unwrap();
+
+    /// Members this server's failure detector marked as suspect or confirmed.
+    static ref MEMBER_HEALTH_TRANSITIONS: CounterVec = register_counter_vec!(
+        opts!(
+            "hab_butterfly_member_health_transitions_total",
+            "Total number of members marked suspect or confirmed by this member."),
+        &["health"]).unwrap();
+}
+
 pub trait Suitability: Debug + Send + Sync {
     fn get(&self, service_group: &ServiceGroup) -> u64;
 }
@@ -75,6 +93,7 @@ pub struct Server {
     pub service_file_store: RumorStore<ServiceFile>,
     pub election_store: RumorStore<Election>,
     pub update_store: RumorStore<ElectionUpdate>,
+    pub departure_store: RumorStore<Departure>,
     swim_addr: Arc<RwLock<SocketAddr>>,
     gossip_addr: Arc<RwLock<SocketAddr>>,
     suitability_lookup: Arc<Box<Suitability>>,
@@ -125,6 +144,7 @@ impl Server {
                     service_file_store: RumorStore::default(),
                     election_store: RumorStore::default(),
                     update_store: RumorStore::default(),
+                    departure_store: RumorStore::default(),
                     swim_addr: Arc::new(RwLock::new(swim_socket_addr)),
                     gossip_addr: Arc::new(RwLock::new(gossip_socket_addr)),
                     suitability_lookup: Arc::new(suitability_lookup),
@@ -199,6 +219,21 @@ impl Server {
         }
     }
 
+    /// Publishes the number of rumors held in each rumor store.
+    fn update_rumor_metrics(&self) {
+        let stores = [
+            ("service", self.service_store.len()),
+            ("service_config", self.service_config_store.len()),
+            ("service_file", self.service_file_store.len()),
+            ("election", self.election_store.len()),
+            ("election_update", self.update_store.len()),
+            ("departure", self.departure_store.len()),
+        ];
+        for &(store, len) in stores.iter() {
+            RUMORS.with_label_values(&[store]).set(len as f64);
+        }
+    }
+
     /// Start the server, along with a `Timing` for outbound connections. Spawns the `inbound`,
     /// `outbound`, and `expire` threads.
     ///
@@ -380,7 +415,7 @@ impl Server {
     }
 
     /// Insert a member to the `MemberList`, and update its `RumorKey` appropriately.
-    pub fn insert_member(&self, member: Member, health: Health) {
+    pub fn insert_member(&self, member: Member, health: Health) -> bool {
         let rk: RumorKey = RumorKey::from(&member);
         // NOTE: This sucks so much right here. Check out how we allocate no matter what, because
         // of just how the logic goes. The value of the trace is really high, though, so we suck it
@@ -397,19 +432,48 @@ impl Server {
                 trace_health
             );
             self.rumor_list.insert(rk);
+            true
+        } else {
+            false
         }
     }
 
+    /// Insert a member with the health this server's failure detector decided on. The change is
+    /// counted as a health transition only if it changed the member's health.
+    pub fn mark_member(&self, member: Member, health: Health) -> bool {
+        let changed = self.insert_member(member, health);
+        if changed {
+            count_health_transition(health);
+        }
+        changed
+    }
+
+    /// Set the health this server's failure detector decided on for the member with the given
+    /// id. The change is counted as a health transition only if it changed the member's health.
+    pub fn mark_member_health_by_id(&self, member_id: &str, health: Health) -> bool {
+        let changed = self.member_list.insert_health_by_id(member_id, health);
+        if changed {
+            count_health_transition(health);
+        }
+        changed
+    }
+
     /// Given a membership record and some health, insert it into the Member List.
     fn insert_member_from_rumor(&self, member: Member, mut health: Health) {
+        if self.member_list.is_departed(member.get_id()) {
+            return;
+        }
         let mut incremented_incarnation = false;
         let rk: RumorKey = RumorKey::from(&member);
         if member.get_id() == self.member_id() {
-            if health != Health::Alive {
+            if health != Health::Alive && health != Health::Departed {
                 let mut me = self.member.write().expect("Member lock is poisoned");
                 let mut incarnation = me.get_incarnation();
                 incarnation += 1;
                 me.set_incarnation(incarnation);
+                if health == Health::Confirmed {
+                    self.reheat_own_rumors();
+                }
                 health = Health::Alive;
                 incremented_incarnation = true;
             }
@@ -440,8 +504,11 @@ impl Server {
         }
     }
 
-    /// Insert a service rumor into the service store.
+    /// Insert a service rumor into the service store. Rumors for departed members are dropped.
     pub fn insert_service(&self, service: Service) {
+        if self.member_list.is_departed(service.get_member_id()) {
+            return;
+        }
         let rk = RumorKey::from(&service);
         if self.service_store.insert(service) {
             self.rumor_list.insert(rk);
@@ -464,6 +531,62 @@ impl Server {
         }
     }
 
+    /// Insert a departure rumor into the departure store, evicting the departed member from the
+    /// member list and its service rumors from the service store. Service config, service file
+    /// and election rumors belong to the service group rather than to a member; they are expired
+    /// by the expire thread once the group has no members left.
+    pub fn insert_departure(&self, departure: Departure) {
+        let rk = RumorKey::from(&departure);
+        let member_id = String::from(departure.get_member_id());
+        if !self.departure_store.insert(departure) {
+            return;
+        }
+        if member_id == self.member_id() {
+            warn!("This member has departed the ring; it will no longer be gossiped about");
+        }
+        self.member_list.depart(&member_id);
+        self.expire_member_rumors(&member_id);
+        self.rumor_list.insert(rk);
+    }
+
+    /// Remove the service rumors of the given member, and stop sharing any rumor it owns.
+    /// Returns true if any service rumor was removed.
+    fn expire_member_rumors(&self, member_id: &str) -> bool {
+        let removed = self.service_store.remove_id(member_id);
+        self.rumor_list.remove_id(member_id);
+        removed
+    }
+
+    /// Remove the service config, service file and election rumors of the given service group.
+    /// Returns true if any rumor was removed.
+    fn expire_service_group_rumors(&self, service_group: &str) -> bool {
+        let mut removed = self.service_config_store.remove_key(service_group);
+        removed = self.service_file_store.remove_key(service_group) || removed;
+        removed = self.election_store.remove_key(service_group) || removed;
+        removed = self.update_store.remove_key(service_group) || removed;
+        for kind in [
+            Rumor_Type::ServiceConfig,
+            Rumor_Type::ServiceFile,
+            Rumor_Type::Election,
+            Rumor_Type::ElectionUpdate,
+        ].iter()
+        {
+            self.rumor_list.remove_key(*kind, service_group);
+        }
+        removed
+    }
+
+    /// Start sharing every service rumor of this member again. Members which confirmed us dead may
+    /// have expired them while we were gone.
+    fn reheat_own_rumors(&self) {
+        let member_id = self.member_id();
+        self.service_store.with_keys(|(_, rumors)| {
+            if let Some(service) = rumors.get(member_id) {
+                self.rumor_list.insert(service);
+            }
+        });
+    }
+
     /// Get all the Member ID's who are present in a given service group.
     fn get_electorate(&self, key: &str) -> Vec<String> {
         let mut electorate = vec![];
@@ -528,7 +651,8 @@ impl Server {
     /// Check to see if this server needs to restart a given election. This happens when:
     ///
     /// a) We are the leader, and we have lost quorum with the rest of the group.
-    /// b) We are not the leader, and we have detected that the leader is confirmed dead.
+    /// b) We are not the leader, and we have detected that the leader is confirmed dead or has
+    ///    departed.
     pub fn restart_elections(&self) {
         let mut elections_to_restart = vec![];
         let mut update_elections_to_restart = vec![];
@@ -562,7 +686,7 @@ impl Server {
                     if self.member_list.check_health_of_by_id(
                         election.get_member_id(),
                         Health::Confirmed,
-                    )
+                    ) || self.member_list.is_departed(election.get_member_id())
                     {
                         warn!(
                             "Restarting election with a new term as the leader is dead {}: {:?}",
@@ -607,7 +731,7 @@ impl Server {
                     if self.member_list.check_health_of_by_id(
                         election.get_member_id(),
                         Health::Confirmed,
-                    )
+                    ) || self.member_list.is_departed(election.get_member_id())
                     {
                         warn!(
                             "Restarting election with a new term as the leader is dead {}: {:?}",
@@ -857,7 +981,7 @@ impl Serialize for Server {
     where
         S: Serializer,
     {
-        let mut strukt = try!(serializer.serialize_struct("butterfly", 6));
+        let mut strukt = try!(serializer.serialize_struct("butterfly", 7));
         try!(strukt.serialize_field("member", &self.member_list));
         try!(strukt.serialize_field("service", &self.service_store));
         try!(strukt.serialize_field(
@@ -873,6 +997,7 @@ impl Serialize for Server {
             "election_update",
             &self.update_store,
         ));
+        try!(strukt.serialize_field("departure", &self.departure_store));
         strukt.end()
     }
 }
@@ -904,13 +1029,19 @@ fn persist_loop(server: Server) {
     }
 }
 
+fn count_health_transition(health: Health) {
+    MEMBER_HEALTH_TRANSITIONS
+        .with_label_values(&[&health.to_string()])
+        .inc();
+}
+
 #[cfg(test)]
 mod tests {
     mod server {
         use habitat_core::service::ServiceGroup;
-        use server::{Server, Suitability};
+        use server::{MEMBER_HEALTH_TRANSITIONS, Server, Suitability};
         use server::timing::Timing;
-        use member::Member;
+        use member::{Health, Member};
         use trace::Trace;
         use std::path::PathBuf;
         use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
@@ -979,5 +1110,25 @@ mod tests {
                 "Server failed to start",
             );
         }
+
+        #[test]
+        fn marking_members_counts_only_health_changes() {
+            let server = start_server();
+            let member = Member::default();
+            let member_id = String::from(member.get_id());
+            server.insert_member(member.clone(), Health::Alive);
+            let suspect = MEMBER_HEALTH_TRANSITIONS.with_label_values(&["suspect"]);
+            let confirmed = MEMBER_HEALTH_TRANSITIONS.with_label_values(&["confirmed"]);
+            let suspect_before = suspect.get();
+            let confirmed_before = confirmed.get();
+
+            assert!(server.mark_member(member.clone(), Health::Suspect));
+            assert!(!server.mark_member(member, Health::Suspect));
+            assert_eq!(suspect.get() - suspect_before, 1.0);
+
+            assert!(server.mark_member_health_by_id(&member_id, Health::Confirmed));
+            assert!(!server.mark_member_health_by_id(&member_id, Health::Confirmed));
+            assert_eq!(confirmed.get() - confirmed_before, 1.0);
+        }
     }
 }
@@ -20,10 +20,11 @@ use std::sync::atomic::Ordering;
 use std::sync::mpsc;
 use std::net::{SocketAddr, UdpSocket};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::fmt;
 
 use time::SteadyTime;
+use prometheus::HistogramVec;
 use protobuf::{Message, RepeatedField};
 
 use message::swim::{Ack, Ping, PingReq, Swim, Swim_Type, Rumor_Type};
@@ -35,6 +36,14 @@ use trace::TraceKind;
 /// How long to sleep between calls to `recv`.
 const PING_RECV_QUEUE_EMPTY_SLEEP_MS: u64 = 10;
 
+lazy_static! {
+    static ref ACK_LATENCY: HistogramVec = register_histogram_vec!(
+        histogram_opts!(
+            "hab_butterfly_swim_ack_duration_seconds",
+            "Time between probing a member and receiving its ack in seconds."),
+        &["ack_from"]).unwrap();
+}
+
 /// Where an Ack came from; either Ping or PingReq.
 #[derive(Debug)]
 enum AckFrom {
@@ -196,7 +205,7 @@ impl Outbound {
             warn!("Marking {} as Suspect", member.get_id());
             trace_it!(PROBE: &self.server, TraceKind::ProbeSuspect, member.get_id(), addr);
             trace_it!(PROBE: &self.server, TraceKind::ProbeComplete, member.get_id(), addr);
-            self.server.insert_member(member, Health::Suspect);
+            self.server.mark_member(member, Health::Suspect);
         } else {
             trace_it!(PROBE: &self.server, TraceKind::ProbeComplete, member.get_id(), addr);
         }
@@ -204,6 +213,8 @@ impl Outbound {
 
     /// Listen for an ack from the `Inbound` thread.
     fn recv_ack(&mut self, member: &Member, addr: SocketAddr, ack_from: AckFrom) -> bool {
+        let sent = Instant::now();
+        let latency = ACK_LATENCY.with_label_values(&[&ack_from.to_string().to_lowercase()]);
         let timeout = match ack_from {
             AckFrom::Ping => self.timing.ping_timeout(),
             AckFrom::PingReq => self.timing.pingreq_timeout(),
@@ -229,6 +240,10 @@ impl Outbound {
                     }
                     let ack_from_member: Member = ack_from.into();
                     self.server.insert_member(ack_from_member, Health::Alive);
+                    let elapsed = sent.elapsed();
+                    latency.observe(
+                        elapsed.as_secs() as f64 + elapsed.subsec_nanos() as f64 / 1e9,
+                    );
                     // We got the ack we are looking for; return.
                     return true;
                 }
@@ -267,7 +282,10 @@ pub fn populate_membership_rumors(server: &Server, target: &Member, swim: &mut S
         Rumor_Type::Member,
     );
     for &(ref rkey, _heat) in rumors.iter() {
-        membership_entries.push(server.member_list.membership_for(&rkey.key()));
+        // Departed members are evicted from the member list; there is nothing left to share.
+        if server.member_list.contains_member(&rkey.key()) {
+            membership_entries.push(server.member_list.membership_for(&rkey.key()));
+        }
     }
     // We don't want to update the heat for rumors that we know we are sending to a target that is
     // confirmed dead; the odds are, they won't receive them. Lets spam them a little harder with
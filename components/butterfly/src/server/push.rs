@@ -64,6 +64,7 @@ impl Push {
             }
 
             self.server.update_gossip_round();
+            self.server.update_rumor_metrics();
 
             let mut check_list = self.server.member_list.check_list(self.server.member_id());
             let long_wait = self.timing.gossip_timeout();
@@ -180,6 +181,10 @@ impl PushWorker {
         'rumorlist: for &(ref rumor_key, ref _heat) in rumors.iter() {
             let rumor_as_bytes = match rumor_key.kind {
                 ProtoRumor_Type::Member => {
+                    // The member may have departed since we took this rumor off the list.
+                    if !self.server.member_list.contains_member(&rumor_key.key()) {
+                        continue 'rumorlist;
+                    }
                     let send_rumor = self.create_member_rumor(&rumor_key);
                     trace_it!(
                         GOSSIP: &self.server,
@@ -294,6 +299,22 @@ impl PushWorker {
                         }
                     }
                 }
+                ProtoRumor_Type::Departure => {
+                    match self.server.departure_store.write_to_bytes(
+                        &rumor_key.key,
+                        &rumor_key.id,
+                    ) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            println!(
+                                "Could not write our own rumor to bytes; abandoning sending \
+                                      rumor: {:?}",
+                                e
+                            );
+                            continue 'rumorlist;
+                        }
+                    }
+                }
                 ProtoRumor_Type::Fake |
                 ProtoRumor_Type::Fake2 => {
                     debug!("You have fake rumors; how odd!");
@@ -22,6 +22,9 @@ const PINGREQ_TIMING_DEFAULT_MS: i64 = 2100;
 const SUSPICION_TIMEOUT_DEFAULT_PROTOCOL_PERIODS: i64 = 3;
 /// How long is the gossip period
 const GOSSIP_PERIOD_DEFAULT_MS: i64 = 1000;
+/// How long the rumors of a confirmed member, or of a service group without any members, are kept
+/// before they are expired.
+pub const RUMOR_TTL_DEFAULT_MS: i64 = 3_600_000;
 
 /// The timing of the outbound threads.
 #[derive(Debug, Clone)]
@@ -30,6 +33,7 @@ pub struct Timing {
     pub pingreq_ms: i64,
     pub gossip_period_ms: i64,
     pub suspicion_timeout_protocol_periods: i64,
+    pub rumor_ttl_ms: i64,
 }
 
 impl Default for Timing {
@@ -39,6 +43,7 @@ impl Default for Timing {
             pingreq_ms: PINGREQ_TIMING_DEFAULT_MS,
             gossip_period_ms: GOSSIP_PERIOD_DEFAULT_MS,
             suspicion_timeout_protocol_periods: SUSPICION_TIMEOUT_DEFAULT_PROTOCOL_PERIODS,
+            rumor_ttl_ms: RUMOR_TTL_DEFAULT_MS,
         }
     }
 }
@@ -50,12 +55,14 @@ impl Timing {
         pingreq_ms: i64,
         gossip_period_ms: i64,
         suspicion_timeout_protocol_periods: i64,
+        rumor_ttl_ms: i64,
     ) -> Timing {
         Timing {
             ping_ms: ping_ms,
             pingreq_ms: pingreq_ms,
             gossip_period_ms: gossip_period_ms,
             suspicion_timeout_protocol_periods: suspicion_timeout_protocol_periods,
+            rumor_ttl_ms: rumor_ttl_ms,
         }
     }
 
@@ -90,4 +97,9 @@ impl Timing {
             self.protocol_period_ms() * self.suspicion_timeout_protocol_periods,
         )
     }
+
+    /// How long before the rumors of a confirmed member or an empty service group expire
+    pub fn rumor_ttl_duration(&self) -> TimeDuration {
+        TimeDuration::milliseconds(self.rumor_ttl_ms)
+    }
 }
@@ -393,6 +393,9 @@ macro_rules! trace_it {
                                 $payload.get_election().get_status(),
                                 $payload.get_election().get_votes())
                     }
+                    Rumor_Type::Departure => {
+                        format!("{}", $payload.get_departure().get_member_id())
+                    }
                     Rumor_Type::Fake | Rumor_Type::Fake2 => format!("nothing-to-see"),
                 };
 
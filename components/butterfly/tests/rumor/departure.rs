@@ -0,0 +1,48 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use btest;
+use habitat_butterfly::client::Client;
+use habitat_butterfly::member::Health;
+
+#[test]
+fn departure_via_client() {
+    let mut net = btest::SwimNet::new(3);
+    net.mesh();
+    net.add_service(2, "core/witcher/1.2.3/20161208121212");
+    net.wait_for_gossip_rounds(1);
+    let departed_id = String::from(net[2].member_id());
+    net[1].service_store.with_rumor(
+        "witcher.prod",
+        &departed_id,
+        |u| assert!(u.is_some()),
+    );
+
+    let mut client =
+        Client::new(net[0].gossip_addr(), None).expect("Cannot create Butterfly Client");
+    client.send_departure(departed_id.clone()).expect(
+        "Cannot send the departure",
+    );
+    net.wait_for_gossip_rounds(1);
+    assert_eq!(
+        net[1].member_list.health_of_by_id(&departed_id),
+        Some(Health::Departed)
+    );
+    assert!(!net[1].member_list.contains_member(&departed_id));
+    net[1].service_store.with_rumor(
+        "witcher.prod",
+        &departed_id,
+        |u| assert!(u.is_none()),
+    );
+}
@@ -59,3 +59,32 @@ fn service_file_via_client() {
         |u| assert!(u.is_some()),
     );
 }
+
+#[test]
+fn service_file_bundle_via_client() {
+    let mut net = btest::SwimNet::new(2);
+    net.mesh();
+
+    net.wait_for_gossip_rounds(1);
+    let mut client =
+        Client::new(net[0].gossip_addr(), None).expect("Cannot create Butterfly Client");
+    let files = vec![
+        (String::from("cert.pem"), Vec::from("certificate".as_bytes())),
+        (String::from("key.pem"), Vec::from("private key".as_bytes())),
+    ];
+    client
+        .send_service_file_bundle(
+            ServiceGroup::new("witcher", "prod", None).unwrap(),
+            "tls",
+            0,
+            files,
+            false,
+        )
+        .expect("Cannot send the service file bundle");
+    net.wait_for_gossip_rounds(1);
+    net[1].service_file_store.with_rumor(
+        "witcher.prod",
+        "tls",
+        |u| assert_eq!(u.expect("Bundle was not gossiped").get_bundle().len(), 2),
+    );
+}
@@ -0,0 +1,320 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bundles a package, its transitive dependencies and the public origin keys they are signed
+//! with into a single file, for installing packages where no Depot can be reached.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg bundle core/redis
+//! $ hab pkg install core-redis-3.2.4-20170514150022-x86_64-linux.bundle
+//! ```
+//!
+//! # Internals
+//!
+//! A bundle is a tar archive holding an `IDENT` file naming the bundled package, every artifact
+//! under `artifacts/` and every public origin key under `keys/`. Installing a bundle unpacks it
+//! into the artifact and key caches, from where the install finds everything it needs.
+//!
+//! Anyone can write a bundle, so the keys it carries prove nothing about the artifacts signed
+//! with them. A bundled key is only imported when its origin is explicitly trusted; otherwise
+//! the key must already be in the key cache.
+
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use hcore;
+use hcore::crypto::{artifact, SigKeyPair};
+use hcore::crypto::keys::parse_name_with_rev;
+use hcore::fs::cache_key_path;
+use hcore::package::{PackageIdent, PackageTarget};
+use tar;
+
+use error::{Error, Result};
+use ui::{Status, UI};
+
+use super::install;
+
+pub const BUNDLE_EXT: &'static str = "bundle";
+const IDENT_FILE: &'static str = "IDENT";
+const ARTIFACTS_DIR: &'static str = "artifacts";
+const KEYS_DIR: &'static str = "keys";
+const PUBLIC_KEY_EXT: &'static str = "pub";
+
+pub fn start<P1, P2>(
+    ui: &mut UI,
+    url: &str,
+    channel: Option<&str>,
+    ident: &PackageIdent,
+    product: &str,
+    version: &str,
+    fs_root_path: &P1,
+    cache_artifact_path: &P2,
+    dst_path: &Path,
+    target: &PackageTarget,
+    ignore_target: bool,
+) -> Result<PathBuf>
+where
+    P1: AsRef<Path> + ?Sized,
+    P2: AsRef<Path> + ?Sized,
+{
+    try!(ui.begin(format!("Bundling {}", ident)));
+    let mut artifacts = try!(install::cache(
+        ui,
+        url,
+        channel,
+        ident,
+        product,
+        version,
+        fs_root_path,
+        cache_artifact_path,
+        target,
+        ignore_target,
+    ));
+    let cache_key_path = cache_key_path(Some(fs_root_path.as_ref()));
+
+    let mut keys = BTreeSet::new();
+    for artifact in artifacts.iter() {
+        let nwr = try!(artifact::artifact_signer(&artifact.path));
+        keys.insert(try!(SigKeyPair::get_public_key_path(&nwr, &cache_key_path)));
+    }
+    let ident = match artifacts.last_mut() {
+        Some(artifact) => try!(artifact.ident()),
+        None => return Err(Error::PackageNotFound),
+    };
+    let name = match ident.archive_name_with_target(target) {
+        Some(name) => name,
+        None => {
+            return Err(Error::HabitatCore(
+                hcore::Error::InvalidPackageIdent(ident.to_string()),
+            ))
+        }
+    };
+    let bundle_path = dst_path.join(format!(
+        "{}.{}",
+        name.trim_right_matches(".hart"),
+        BUNDLE_EXT
+    ));
+    let artifact_paths: Vec<PathBuf> = artifacts.iter().map(|a| a.path.clone()).collect();
+    let key_paths: Vec<PathBuf> = keys.into_iter().collect();
+
+    try!(ui.status(Status::Creating, bundle_path.display()));
+    try!(write(&bundle_path, &ident, &artifact_paths, &key_paths));
+    try!(ui.end(format!(
+        "Bundled {} with {} artifacts and {} public origin keys into {}",
+        &ident,
+        artifact_paths.len(),
+        key_paths.len(),
+        bundle_path.display()
+    )));
+    Ok(bundle_path)
+}
+
+/// Returns true if the file at `path` looks like a bundle.
+pub fn is_bundle(path: &Path) -> bool {
+    path.extension().map_or(false, |ext| ext == BUNDLE_EXT)
+}
+
+/// Unpacks the artifacts and public origin keys of the bundle at `bundle_path` into the artifact
+/// and key caches, returning the identifier of the bundled package.
+///
+/// Keys already in the key cache are never replaced, and anything in the bundle which is not an
+/// artifact, a key or the bundle's `IDENT` is ignored.
+///
+/// # Errors
+///
+/// * If the bundle carries a key which is not in the key cache and whose origin is not one of
+///   `trusted_origins`
+pub fn unpack(
+    bundle_path: &Path,
+    cache_artifact_path: &Path,
+    cache_key_path: &Path,
+    trusted_origins: &[String],
+) -> Result<PackageIdent> {
+    try!(fs::create_dir_all(cache_artifact_path));
+    try!(fs::create_dir_all(cache_key_path));
+    let mut archive = tar::Archive::new(try!(File::open(bundle_path)));
+    let mut ident = None;
+    for entry in try!(archive.entries()) {
+        let mut entry = try!(entry);
+        let path = try!(entry.path()).into_owned();
+        let parent = path.parent().and_then(|p| p.to_str());
+        match (parent, path.file_name()) {
+            (Some(""), Some(name)) if name == IDENT_FILE => {
+                let mut body = String::new();
+                try!(entry.read_to_string(&mut body));
+                ident = Some(try!(PackageIdent::from_str(body.trim())));
+            }
+            (Some(ARTIFACTS_DIR), Some(name)) => {
+                try!(entry.unpack(cache_artifact_path.join(name)));
+            }
+            (Some(KEYS_DIR), Some(name)) => {
+                let key_path = cache_key_path.join(name);
+                if !key_path.exists() {
+                    try!(check_trusted(&key_path, trusted_origins));
+                    try!(entry.unpack(key_path));
+                }
+            }
+            _ => debug!("Skipping {} in bundle", path.display()),
+        }
+    }
+    match ident {
+        Some(ident) => Ok(ident),
+        None => Err(Error::InvalidBundle(bundle_path.display().to_string())),
+    }
+}
+
+/// Returns an error unless the public origin key at `key_path` belongs to one of
+/// `trusted_origins`.
+fn check_trusted(key_path: &Path, trusted_origins: &[String]) -> Result<()> {
+    let name_with_rev = match (
+        key_path.file_stem().and_then(|s| s.to_str()),
+        key_path.extension(),
+    ) {
+        (Some(stem), Some(ext)) if ext == PUBLIC_KEY_EXT => stem,
+        _ => return Err(Error::CryptoKeyError(key_path.display().to_string())),
+    };
+    let (origin, _) = try!(parse_name_with_rev(name_with_rev));
+    if trusted_origins.iter().any(|trusted| *trusted == origin) {
+        Ok(())
+    } else {
+        Err(Error::UntrustedBundleKey(
+            (name_with_rev.to_string(), origin),
+        ))
+    }
+}
+
+/// Writes a bundle of `artifacts` and `keys` for `ident` to `bundle_path`.
+fn write(
+    bundle_path: &Path,
+    ident: &PackageIdent,
+    artifacts: &[PathBuf],
+    keys: &[PathBuf],
+) -> Result<()> {
+    let tmp_path = bundle_path.with_extension(format!("{}.tmp", BUNDLE_EXT));
+    let mut builder = tar::Builder::new(try!(File::create(&tmp_path)));
+
+    let body = ident.to_string();
+    let mut header = tar::Header::new_gnu();
+    try!(header.set_path(IDENT_FILE));
+    header.set_size(body.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    try!(builder.append(&header, body.as_bytes()));
+
+    for (dir, paths) in vec![(ARTIFACTS_DIR, artifacts), (KEYS_DIR, keys)] {
+        for path in paths {
+            let name = match path.file_name() {
+                Some(name) => Path::new(dir).join(name),
+                None => return Err(Error::FileNameError),
+            };
+            try!(builder.append_file(name, &mut try!(File::open(path))));
+        }
+    }
+    try!(builder.into_inner());
+    try!(fs::rename(&tmp_path, bundle_path));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::{Read, Write};
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+    use tempdir::TempDir;
+
+    use error::Error;
+    use super::{is_bundle, unpack, write};
+
+    fn file(dir: &Path, name: &str, body: &str) -> PathBuf {
+        let path = dir.join(name);
+        File::create(&path)
+            .unwrap()
+            .write_all(body.as_bytes())
+            .unwrap();
+        path
+    }
+
+    fn read(path: &Path) -> String {
+        let mut body = String::new();
+        File::open(path).unwrap().read_to_string(&mut body).unwrap();
+        body
+    }
+
+    #[test]
+    fn unpack_restores_what_was_bundled() {
+        let src = TempDir::new("src").unwrap();
+        let dst = TempDir::new("dst").unwrap();
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap();
+        let artifacts = vec![
+            file(src.path(), "core-glibc.hart", "glibc"),
+            file(src.path(), "core-redis.hart", "redis"),
+        ];
+        let keys = vec![file(src.path(), "core-20160810182414.pub", "key")];
+        let bundle_path = src.path().join("redis.bundle");
+        write(&bundle_path, &ident, &artifacts, &keys).unwrap();
+        assert!(is_bundle(&bundle_path));
+
+        let artifact_path = dst.path().join("artifacts");
+        let key_path = dst.path().join("keys");
+        let trusted = vec!["core".to_string()];
+        let unpacked = unpack(&bundle_path, &artifact_path, &key_path, &trusted).unwrap();
+        assert_eq!(unpacked, ident);
+        assert_eq!(read(&artifact_path.join("core-glibc.hart")), "glibc");
+        assert_eq!(read(&artifact_path.join("core-redis.hart")), "redis");
+        assert_eq!(read(&key_path.join("core-20160810182414.pub")), "key");
+    }
+
+    #[test]
+    fn unpack_keeps_keys_already_in_the_cache() {
+        let src = TempDir::new("src").unwrap();
+        let dst = TempDir::new("dst").unwrap();
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap();
+        let keys = vec![file(src.path(), "core-20160810182414.pub", "bundled")];
+        let bundle_path = src.path().join("redis.bundle");
+        write(&bundle_path, &ident, &[], &keys).unwrap();
+
+        let cached = file(dst.path(), "core-20160810182414.pub", "cached");
+        unpack(&bundle_path, &dst.path().join("artifacts"), dst.path(), &[]).unwrap();
+        assert_eq!(read(&cached), "cached");
+    }
+
+    #[test]
+    fn unpack_refuses_keys_of_untrusted_origins() {
+        let src = TempDir::new("src").unwrap();
+        let dst = TempDir::new("dst").unwrap();
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap();
+        let keys = vec![file(src.path(), "core-20160810182414.pub", "bundled")];
+        let bundle_path = src.path().join("redis.bundle");
+        write(&bundle_path, &ident, &[], &keys).unwrap();
+
+        let trusted = vec!["acme".to_string()];
+        match unpack(&bundle_path, &dst.path().join("artifacts"), dst.path(), &trusted) {
+            Err(Error::UntrustedBundleKey((key, origin))) => {
+                assert_eq!(key, "core-20160810182414");
+                assert_eq!(origin, "core");
+            }
+            Err(e) => panic!("Unexpected error {}", e),
+            Ok(_) => panic!("Imported a key of an untrusted origin"),
+        }
+        assert!(!dst.path().join("core-20160810182414.pub").exists());
+    }
+}
@@ -31,31 +31,58 @@
 //! # Internals
 //!
 //! * Download the artifact
-//! * Verify it is un-altered
-//! * Unpack it
+//! * Download the artifacts of its missing dependencies, several at a time
+//! * Verify they are un-altered
+//! * Unpack them
+//!
+//! A package can also be installed from a lockfile, which pins it and its dependencies to exact
+//! releases and artifact checksums. No release is then resolved through a channel, and any
+//! artifact whose checksum differs from the lockfile's is refused.
+//!
+//! A bundle made by [`bundle`](../bundle) is installed without any Depot access: its artifacts
+//! and public origin keys are unpacked into the caches before installing the bundled package.
+//!
+//! Downloads resume where an earlier, interrupted attempt left off. The number of artifacts
+//! downloaded at once can be set with the `HAB_DOWNLOAD_WORKERS` environment variable.
 //!
 
+use std::cell::Cell;
+use std::cmp;
 use std::fs;
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
 use std::str::FromStr;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
 
-use depot_client::{self, Client};
+use depot_client::{self, Client, DisplayProgress};
 use depot_client::Error::APIError;
 use hcore;
+use hcore::env as henv;
 use hcore::fs::{am_i_root, cache_key_path};
 use hcore::crypto::{artifact, SigKeyPair};
 use hcore::crypto::keys::parse_name_with_rev;
-use hcore::package::{Identifiable, PackageArchive, PackageIdent, Target, PackageInstall};
+use hcore::package::{Identifiable, LockedPackage, Lockfile, PackageArchive, PackageIdent,
+                     PackageTarget, Target, PackageInstall};
 use hyper::status::StatusCode;
 
 use error::{Error, Result};
 use ui::{Status, UI};
 
+use super::bundle;
+
 use retry::retry;
 
 pub const RETRIES: u64 = 5;
 pub const RETRY_WAIT: u64 = 3000;
+pub const DEFAULT_DOWNLOAD_WORKERS: usize = 4;
+pub const DOWNLOAD_WORKERS_ENVVAR: &'static str = "HAB_DOWNLOAD_WORKERS";
 
+/// Installs a package given its identifier or the path to an artifact or a bundle.
+///
+/// A bundle is only installed if the public origin keys it carries are already in the key cache.
+/// Use `start_trusting_origins` to import the keys of trusted origins from a bundle.
 pub fn start<P1, P2>(
     ui: &mut UI,
     url: &str,
@@ -65,6 +92,41 @@ pub fn start<P1, P2>(
     version: &str,
     fs_root_path: &P1,
     cache_artifact_path: &P2,
+    target: &PackageTarget,
+    ignore_target: bool,
+) -> Result<PackageIdent>
+where
+    P1: AsRef<Path> + ?Sized,
+    P2: AsRef<Path> + ?Sized,
+{
+    start_trusting_origins(
+        ui,
+        url,
+        channel,
+        ident_or_archive,
+        &[],
+        product,
+        version,
+        fs_root_path,
+        cache_artifact_path,
+        target,
+        ignore_target,
+    )
+}
+
+/// Installs a package like `start` does, importing the public origin keys a bundle carries for
+/// any of `trusted_origins` into the key cache.
+pub fn start_trusting_origins<P1, P2>(
+    ui: &mut UI,
+    url: &str,
+    channel: Option<&str>,
+    ident_or_archive: &str,
+    trusted_origins: &[String],
+    product: &str,
+    version: &str,
+    fs_root_path: &P1,
+    cache_artifact_path: &P2,
+    target: &PackageTarget,
     ignore_target: bool,
 ) -> Result<PackageIdent>
 where
@@ -81,6 +143,10 @@ where
         return Err(Error::RootRequired);
     }
 
+    if !ignore_target {
+        try!(target.validate());
+    }
+
     let cache_key_path = cache_key_path(Some(fs_root_path.as_ref()));
     debug!("install cache_key_path: {}", cache_key_path.display());
 
@@ -91,22 +157,177 @@ where
         fs_root_path.as_ref(),
         cache_artifact_path.as_ref(),
         &cache_key_path,
+        target,
         ignore_target,
+        download_workers(),
     ));
 
-    if Path::new(ident_or_archive).is_file() {
-        task.from_artifact(ui, &Path::new(ident_or_archive))
+    let path = Path::new(ident_or_archive);
+    if path.is_file() && bundle::is_bundle(path) {
+        try!(ui.status(
+            Status::Custom('☛', "Unbundling".to_string()),
+            path.display(),
+        ));
+        let ident = try!(bundle::unpack(
+            path,
+            cache_artifact_path.as_ref(),
+            &cache_key_path,
+            trusted_origins,
+        ));
+        task.from_ident(ui, ident, None)
+    } else if path.is_file() {
+        task.from_artifact(ui, &path)
     } else {
         task.from_ident(ui, PackageIdent::from_str(ident_or_archive)?, channel)
     }
 }
 
+/// Installs the package a lockfile was made for, along with its transitive dependencies, using
+/// exactly the releases the lockfile lists.
+pub fn start_from_lockfile<P1, P2>(
+    ui: &mut UI,
+    url: &str,
+    lockfile: &Lockfile,
+    product: &str,
+    version: &str,
+    fs_root_path: &P1,
+    cache_artifact_path: &P2,
+    target: &PackageTarget,
+    ignore_target: bool,
+) -> Result<PackageIdent>
+where
+    P1: AsRef<Path> + ?Sized,
+    P2: AsRef<Path> + ?Sized,
+{
+    if !am_i_root() {
+        try!(ui.warn(
+            "Installing a package requires root or administrator privileges. Please retry \
+                   this command as a super user or use a privilege-granting facility such as \
+                   sudo.",
+        ));
+        try!(ui.br());
+        return Err(Error::RootRequired);
+    }
+    if !ignore_target {
+        try!(target.validate());
+    }
+
+    let cache_key_path = cache_key_path(Some(fs_root_path.as_ref()));
+    let task = try!(InstallTask::new(
+        url,
+        product,
+        version,
+        fs_root_path.as_ref(),
+        cache_artifact_path.as_ref(),
+        &cache_key_path,
+        target,
+        ignore_target,
+        download_workers(),
+    ));
+    task.from_lockfile(ui, lockfile)
+}
+
+/// Returns a lockfile pinning a package and its transitive dependencies to the releases
+/// currently resolved for them, fetching any artifact missing from the artifact cache.
+pub fn lock<P1, P2>(
+    ui: &mut UI,
+    url: &str,
+    channel: Option<&str>,
+    ident: &PackageIdent,
+    product: &str,
+    version: &str,
+    fs_root_path: &P1,
+    cache_artifact_path: &P2,
+    target: &PackageTarget,
+    ignore_target: bool,
+) -> Result<Lockfile>
+where
+    P1: AsRef<Path> + ?Sized,
+    P2: AsRef<Path> + ?Sized,
+{
+    let mut artifacts = try!(cache(
+        ui,
+        url,
+        channel,
+        ident,
+        product,
+        version,
+        fs_root_path,
+        cache_artifact_path,
+        target,
+        ignore_target,
+    ));
+    let mut packages = Vec::new();
+    for artifact in artifacts.iter_mut() {
+        packages.push(LockedPackage {
+            ident: try!(artifact.ident()),
+            checksum: try!(artifact.checksum()),
+        });
+    }
+    let ident = match packages.last() {
+        Some(package) => package.ident.clone(),
+        None => return Err(Error::PackageNotFound),
+    };
+    Ok(try!(Lockfile::new(ident, packages)))
+}
+
+/// Fetches a package and its transitive dependencies into the artifact cache, along with the
+/// public origin keys they are signed with, without installing anything. As nothing is
+/// installed, the artifacts may be built for a target other than this system's.
+///
+/// Returns the verified artifacts, dependencies first.
+pub fn cache<P1, P2>(
+    ui: &mut UI,
+    url: &str,
+    channel: Option<&str>,
+    ident: &PackageIdent,
+    product: &str,
+    version: &str,
+    fs_root_path: &P1,
+    cache_artifact_path: &P2,
+    target: &PackageTarget,
+    ignore_target: bool,
+) -> Result<Vec<PackageArchive>>
+where
+    P1: AsRef<Path> + ?Sized,
+    P2: AsRef<Path> + ?Sized,
+{
+    let cache_key_path = cache_key_path(Some(fs_root_path.as_ref()));
+    let task = try!(InstallTask::new(
+        url,
+        product,
+        version,
+        fs_root_path.as_ref(),
+        cache_artifact_path.as_ref(),
+        &cache_key_path,
+        target,
+        ignore_target,
+        download_workers(),
+    ));
+    task.cache_package(ui, ident.clone(), channel)
+}
+
+/// Returns the number of artifacts to download at once.
+fn download_workers() -> usize {
+    match henv::var(DOWNLOAD_WORKERS_ENVVAR).ok().and_then(
+        |v| v.parse::<usize>().ok(),
+    ) {
+        Some(workers) if workers > 0 => workers,
+        _ => DEFAULT_DOWNLOAD_WORKERS,
+    }
+}
+
 struct InstallTask<'a> {
     depot_client: Client,
+    url: String,
+    product: String,
+    version: String,
     fs_root_path: &'a Path,
     cache_artifact_path: &'a Path,
     cache_key_path: &'a Path,
+    target: PackageTarget,
     ignore_target: bool,
+    download_workers: usize,
 }
 
 impl<'a> InstallTask<'a> {
@@ -117,14 +338,21 @@ impl<'a> InstallTask<'a> {
         fs_root_path: &'a Path,
         cache_artifact_path: &'a Path,
         cache_key_path: &'a Path,
+        target: &PackageTarget,
         ignore_target: bool,
+        download_workers: usize,
     ) -> Result<Self> {
         Ok(InstallTask {
             depot_client: try!(Client::new(url, product, version, Some(fs_root_path))),
+            url: url.to_string(),
+            product: product.to_string(),
+            version: version.to_string(),
             fs_root_path: fs_root_path,
             cache_artifact_path: cache_artifact_path,
             cache_key_path: cache_key_path,
+            target: target.clone(),
             ignore_target: ignore_target,
+            download_workers: download_workers,
         })
     }
 
@@ -165,6 +393,87 @@ impl<'a> InstallTask<'a> {
             try!(ui.begin(format!("Installing {}", &ident)));
         }
 
+        let ident = try!(self.resolve_ident(ui, ident, channel));
+        if try!(self.is_package_installed(&ident)) {
+            try!(ui.status(Status::Using, &ident));
+            try!(ui.end(format!(
+                "Install of {} complete with {} new packages installed.",
+                &ident,
+                0
+            )));
+            return Ok(ident);
+        }
+
+        self.install_package(ui, ident, None)
+    }
+
+    /// Installs the package a lockfile was made for and its dependencies.
+    ///
+    /// Only the releases the lockfile lists are fetched, and an artifact is refused if its
+    /// checksum differs from the lockfile's or if it depends on anything the lockfile does not
+    /// list.
+    pub fn from_lockfile(&self, ui: &mut UI, lockfile: &Lockfile) -> Result<PackageIdent> {
+        let ident = lockfile.ident.clone();
+        try!(ui.begin(format!("Installing {} from lockfile", &ident)));
+
+        let mut missing = Vec::new();
+        for package in lockfile.packages.iter() {
+            if try!(self.is_package_installed(&package.ident)) {
+                try!(ui.status(Status::Using, &package.ident));
+            } else {
+                missing.push(package.ident.clone());
+            }
+        }
+        try!(self.fetch_artifacts(ui, &missing, None));
+
+        let mut artifacts: Vec<PackageArchive> = Vec::new();
+        for dep in missing.iter() {
+            let mut artifact = try!(self.verified_artifact(ui, dep));
+            try!(verify_locked_artifact(lockfile, dep, &mut artifact));
+            artifacts.push(artifact);
+        }
+
+        let num_installed = artifacts.len();
+        for mut artifact in artifacts {
+            try!(self.extract_artifact(ui, &mut artifact));
+        }
+        try!(ui.end(format!(
+            "Install of {} complete with {} new packages installed.",
+            &ident,
+            num_installed
+        )));
+        Ok(ident)
+    }
+
+    /// Caches the artifacts of a package and its transitive dependencies, returning them verified
+    /// and dependencies first.
+    fn cache_package(
+        &self,
+        ui: &mut UI,
+        ident: PackageIdent,
+        channel: Option<&str>,
+    ) -> Result<Vec<PackageArchive>> {
+        let ident = try!(self.resolve_ident(ui, ident, channel));
+        let mut artifact = try!(self.get_cached_artifact(ui, ident, None));
+        let tdeps = try!(artifact.tdeps());
+        try!(self.fetch_artifacts(ui, &tdeps, None));
+
+        let mut artifacts = Vec::new();
+        for ident in tdeps.iter() {
+            artifacts.push(try!(self.verified_artifact(ui, ident)));
+        }
+        artifacts.push(artifact);
+        Ok(artifacts)
+    }
+
+    /// Returns the fully qualified identifier of the latest release matching `ident` in the
+    /// Depot, or `ident` itself if it is fully qualified already.
+    fn resolve_ident(
+        &self,
+        ui: &mut UI,
+        ident: PackageIdent,
+        channel: Option<&str>,
+    ) -> Result<PackageIdent> {
         let mut ident = ident;
         if !ident.fully_qualified() {
             ident = match self.fetch_latest_pkg_ident_for(&ident, channel) {
@@ -194,18 +503,7 @@ impl<'a> InstallTask<'a> {
                 }
             }
         }
-
-        if try!(self.is_package_installed(&ident)) {
-            try!(ui.status(Status::Using, &ident));
-            try!(ui.end(format!(
-                "Install of {} complete with {} new packages installed.",
-                &ident,
-                0
-            )));
-            return Ok(ident);
-        }
-
-        self.install_package(ui, ident, None)
+        Ok(ident)
     }
 
     pub fn from_artifact(&self, ui: &mut UI, artifact_path: &Path) -> Result<PackageIdent> {
@@ -232,15 +530,21 @@ impl<'a> InstallTask<'a> {
         src_path: Option<&Path>,
     ) -> Result<PackageIdent> {
         let mut artifact = try!(self.get_cached_artifact(ui, ident.clone(), src_path));
-        let mut artifacts: Vec<PackageArchive> = Vec::new();
+        let mut missing = Vec::new();
 
         for ident in try!(artifact.tdeps()) {
             if try!(self.is_package_installed(&ident)) {
                 try!(ui.status(Status::Using, &ident));
             } else {
-                artifacts.push(try!(self.get_cached_artifact(ui, ident, src_path)));
+                missing.push(ident);
             }
         }
+        try!(self.fetch_artifacts(ui, &missing, src_path));
+
+        let mut artifacts: Vec<PackageArchive> = Vec::new();
+        for ident in missing {
+            artifacts.push(try!(self.verified_artifact(ui, &ident)));
+        }
         artifacts.push(artifact);
 
         let num_installed = artifacts.len();
@@ -282,8 +586,111 @@ impl<'a> InstallTask<'a> {
             }
         }
 
-        let mut artifact = PackageArchive::new(try!(self.cached_artifact_path(&ident)));
-        try!(self.verify_artifact(ui, &ident, &mut artifact));
+        self.verified_artifact(ui, &ident)
+    }
+
+    /// Fetches the artifacts of `idents` into the artifact cache.
+    ///
+    /// Artifacts which are neither cached already nor found next to the artifact being installed
+    /// are downloaded by up to `download_workers` threads at once, each retrying its download
+    /// `RETRIES` times. The progress of every download is shown in a single progress bar.
+    fn fetch_artifacts(
+        &self,
+        ui: &mut UI,
+        idents: &[PackageIdent],
+        src_path: Option<&Path>,
+    ) -> Result<()> {
+        let mut downloads = Vec::new();
+        for ident in idents {
+            if try!(self.is_artifact_cached(ident)) {
+                debug!("Found {} in artifact cache, skipping remote download", ident);
+            } else if !try!(self.cache_local_artifact(ident, src_path)) {
+                downloads.push(ident.clone());
+            }
+        }
+        if downloads.is_empty() {
+            return Ok(());
+        }
+        for ident in downloads.iter() {
+            try!(ui.status(Status::Downloading, ident));
+        }
+
+        let mut progress = ui.progress();
+        if let Some(ref mut progress) = progress {
+            progress.size(0);
+        }
+        let workers = cmp::min(self.download_workers, downloads.len());
+        // Workers pop from the back, so reverse the queue to download in dependency order.
+        let queue = Arc::new(Mutex::new(
+            downloads.iter().rev().cloned().collect::<Vec<_>>(),
+        ));
+        let (tx, rx) = mpsc::channel();
+        for i in 0..workers {
+            let worker = DownloadWorker {
+                client: try!(Client::new(
+                    self.url.as_str(),
+                    &self.product,
+                    &self.version,
+                    Some(self.fs_root_path),
+                )),
+                queue: queue.clone(),
+                target: self.target.clone(),
+                cache_artifact_path: self.cache_artifact_path.to_path_buf(),
+                show_progress: progress.is_some(),
+                tx: tx.clone(),
+            };
+            try!(
+                thread::Builder::new()
+                    .name(format!("download-{}", i))
+                    .spawn(move || worker.run())
+            );
+        }
+        // The channel closes once every worker has run out of downloads and hung up.
+        drop(tx);
+
+        let mut unsupported = Vec::new();
+        let mut failed = Vec::new();
+        for event in rx.iter() {
+            match event {
+                DownloadEvent::Size(size) => {
+                    if let Some(ref mut progress) = progress {
+                        progress.grow(size);
+                    }
+                }
+                DownloadEvent::Progress(amount) => {
+                    if let Some(ref mut progress) = progress {
+                        progress.advance(amount);
+                    }
+                }
+                DownloadEvent::Unsupported(ident) => unsupported.push(ident),
+                DownloadEvent::Failed(ident) => failed.push(ident),
+            }
+        }
+        if let Some(ref mut progress) = progress {
+            progress.finish();
+        }
+
+        for ident in unsupported {
+            println!(
+                "Host platform or architecture not supported by the targted depot; \
+                      skipping {}.",
+                &ident
+            );
+        }
+        match failed.first() {
+            Some(ident) => Err(Error::from(depot_client::Error::DownloadFailed(format!(
+                "We tried {} times but could not download {}. Giving up.",
+                RETRIES,
+                ident
+            )))),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the cached artifact for `ident` once it has been verified.
+    fn verified_artifact(&self, ui: &mut UI, ident: &PackageIdent) -> Result<PackageArchive> {
+        let mut artifact = PackageArchive::new(try!(self.cached_artifact_path(ident)));
+        try!(self.verify_artifact(ui, ident, &mut artifact));
         Ok(artifact)
     }
 
@@ -306,7 +713,7 @@ impl<'a> InstallTask<'a> {
     }
 
     fn cached_artifact_path(&self, ident: &PackageIdent) -> Result<PathBuf> {
-        let name = match ident.archive_name() {
+        let name = match ident.archive_name_with_target(&self.target) {
             Some(n) => n,
             None => {
                 return Err(Error::HabitatCore(
@@ -322,7 +729,11 @@ impl<'a> InstallTask<'a> {
         ident: &PackageIdent,
         channel: Option<&str>,
     ) -> Result<PackageIdent> {
-        Ok(self.depot_client.show_package(ident, channel)?.into())
+        Ok(
+            self.depot_client
+                .show_package(ident, channel, Some(&self.target))?
+                .into(),
+        )
     }
 
     fn fetch_artifact(
@@ -331,25 +742,14 @@ impl<'a> InstallTask<'a> {
         ident: &PackageIdent,
         src_path: Option<&Path>,
     ) -> Result<()> {
-        if let Some(src_path) = src_path {
-            let name = match ident.archive_name() {
-                Some(n) => n,
-                None => {
-                    return Err(Error::HabitatCore(
-                        hcore::Error::InvalidPackageIdent(ident.to_string()),
-                    ))
-                }
-            };
-            let local_artifact = src_path.join(name);
-            if local_artifact.is_file() {
-                try!(self.cache_artifact(ident, &local_artifact));
-                return Ok(());
-            }
+        if try!(self.cache_local_artifact(ident, src_path)) {
+            return Ok(());
         }
 
         try!(ui.status(Status::Downloading, ident));
         match self.depot_client.fetch_package(
             ident,
+            &self.target,
             self.cache_artifact_path,
             ui.progress(),
         ) {
@@ -365,6 +765,32 @@ impl<'a> InstallTask<'a> {
         }
     }
 
+    /// Caches the artifact for `ident` found in `src_path`, returning false if there is none.
+    fn cache_local_artifact(
+        &self,
+        ident: &PackageIdent,
+        src_path: Option<&Path>,
+    ) -> Result<bool> {
+        let src_path = match src_path {
+            Some(src_path) => src_path,
+            None => return Ok(false),
+        };
+        let name = match ident.archive_name_with_target(&self.target) {
+            Some(n) => n,
+            None => {
+                return Err(Error::HabitatCore(
+                    hcore::Error::InvalidPackageIdent(ident.to_string()),
+                ))
+            }
+        };
+        let local_artifact = src_path.join(name);
+        if !local_artifact.is_file() {
+            return Ok(false);
+        }
+        try!(self.cache_artifact(ident, &local_artifact));
+        Ok(true)
+    }
+
     fn fetch_origin_key(&self, ui: &mut UI, name_with_rev: &str) -> Result<()> {
         try!(ui.status(
             Status::Downloading,
@@ -385,7 +811,7 @@ impl<'a> InstallTask<'a> {
     }
 
     fn cache_artifact(&self, ident: &PackageIdent, artifact_path: &Path) -> Result<()> {
-        let name = match ident.archive_name() {
+        let name = match ident.archive_name_with_target(&self.target) {
             Some(n) => n,
             None => {
                 return Err(Error::HabitatCore(
@@ -417,7 +843,14 @@ impl<'a> InstallTask<'a> {
             info!("Skipping target validation for this package.");
         } else {
             let artifact_target = try!(artifact.target());
-            try!(artifact_target.validate());
+            if artifact_target != self.target {
+                return Err(Error::HabitatCore(hcore::Error::TargetMatchError(format!(
+                    "Artifact {} was built for {}, not {}.",
+                    artifact.file_name(),
+                    artifact_target,
+                    self.target
+                ))));
+            }
         }
 
 
@@ -431,3 +864,216 @@ impl<'a> InstallTask<'a> {
         Ok(())
     }
 }
+
+/// Checks that an artifact matches the checksum a lockfile pins it to, and that the lockfile
+/// lists every one of its dependencies.
+fn verify_locked_artifact(
+    lockfile: &Lockfile,
+    ident: &PackageIdent,
+    artifact: &mut PackageArchive,
+) -> Result<()> {
+    let checksum = try!(artifact.checksum());
+    match lockfile.get(ident) {
+        Some(locked) if locked.checksum == checksum => (),
+        Some(locked) => {
+            return Err(Error::LockfileChecksumMismatch((
+                ident.to_string(),
+                locked.checksum.clone(),
+                checksum,
+            )))
+        }
+        None => {
+            return Err(Error::LockfileIncomplete(
+                (lockfile.ident.to_string(), ident.to_string()),
+            ))
+        }
+    }
+    for dep in try!(artifact.tdeps()) {
+        if lockfile.get(&dep).is_none() {
+            return Err(Error::LockfileIncomplete(
+                (ident.to_string(), dep.to_string()),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// What a download worker reports back to the thread which owns the `UI`.
+enum DownloadEvent {
+    /// A download learned how many bytes it has left to fetch.
+    Size(u64),
+    /// A download fetched this many more bytes.
+    Progress(u64),
+    /// The Depot does not serve artifacts for the target we asked for.
+    Unsupported(PackageIdent),
+    /// Every attempt to download the artifact failed.
+    Failed(PackageIdent),
+}
+
+/// Downloads artifacts from a shared queue into the artifact cache until the queue is empty.
+struct DownloadWorker {
+    client: Client,
+    queue: Arc<Mutex<Vec<PackageIdent>>>,
+    target: PackageTarget,
+    cache_artifact_path: PathBuf,
+    show_progress: bool,
+    tx: mpsc::Sender<DownloadEvent>,
+}
+
+impl DownloadWorker {
+    fn run(self) {
+        loop {
+            let ident = match self.queue.lock().expect("Download queue lock poisoned").pop() {
+                Some(ident) => ident,
+                None => break,
+            };
+            let reported = Rc::new(Cell::new(Reported::default()));
+            let result = retry(
+                RETRIES,
+                RETRY_WAIT,
+                || self.fetch(&ident, &reported),
+                |res| res.is_ok(),
+            );
+            let event = match result {
+                Ok(Ok(true)) => continue,
+                Ok(Ok(false)) => DownloadEvent::Unsupported(ident),
+                _ => DownloadEvent::Failed(ident),
+            };
+            if self.tx.send(event).is_err() {
+                break;
+            }
+        }
+    }
+
+    /// Downloads the artifact for `ident`, returning false if the Depot does not serve our
+    /// target. A partial download left by an earlier attempt is resumed.
+    fn fetch(
+        &self,
+        ident: &PackageIdent,
+        reported: &Rc<Cell<Reported>>,
+    ) -> depot_client::Result<bool> {
+        let progress = if self.show_progress {
+            Some(ProgressSender {
+                tx: self.tx.clone(),
+                reported: reported.clone(),
+            })
+        } else {
+            None
+        };
+        match self.client.fetch_package(
+            ident,
+            &self.target,
+            &self.cache_artifact_path,
+            progress,
+        ) {
+            Ok(_) => Ok(true),
+            Err(depot_client::Error::APIError(StatusCode::NotImplemented, _)) => Ok(false),
+            Err(e) => {
+                debug!("Failed to download {}: {:?}", ident, e);
+                Err(e)
+            }
+        }
+    }
+}
+
+/// How many bytes of one artifact have been reported to the progress bar, over every attempt
+/// to download it.
+#[derive(Clone, Copy, Debug, Default)]
+struct Reported {
+    size: u64,
+    fetched: u64,
+}
+
+/// Forwards the progress of a download on a worker thread to the thread which owns the `UI`.
+struct ProgressSender {
+    tx: mpsc::Sender<DownloadEvent>,
+    reported: Rc<Cell<Reported>>,
+}
+
+impl DisplayProgress for ProgressSender {
+    /// Each attempt reports the bytes it has left to fetch. Bytes fetched by earlier attempts
+    /// already count towards the artifact's size, so only what goes beyond the size reported so
+    /// far grows the progress bar.
+    fn size(&mut self, size: u64) {
+        let mut reported = self.reported.get();
+        let total = reported.fetched + size;
+        if total > reported.size {
+            let _ = self.tx.send(DownloadEvent::Size(total - reported.size));
+            reported.size = total;
+            self.reported.set(reported);
+        }
+    }
+
+    fn finish(&mut self) {}
+}
+
+impl Write for ProgressSender {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut reported = self.reported.get();
+        reported.fetched += buf.len() as u64;
+        self.reported.set(reported);
+        let _ = self.tx.send(DownloadEvent::Progress(buf.len() as u64));
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sizes(rx: &mpsc::Receiver<DownloadEvent>) -> Vec<u64> {
+        rx.try_iter()
+            .filter_map(|event| match event {
+                DownloadEvent::Size(size) => Some(size),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn resumed_download_does_not_grow_progress() {
+        let (tx, rx) = mpsc::channel();
+        let reported = Rc::new(Cell::new(Reported::default()));
+        let mut first = ProgressSender {
+            tx: tx.clone(),
+            reported: reported.clone(),
+        };
+        first.size(100);
+        first.write_all(&[0; 40]).unwrap();
+        let mut retry = ProgressSender {
+            tx: tx,
+            reported: reported.clone(),
+        };
+        retry.size(60);
+        retry.write_all(&[0; 60]).unwrap();
+
+        assert_eq!(sizes(&rx), vec![100]);
+        assert_eq!(reported.get().size, 100);
+        assert_eq!(reported.get().fetched, 100);
+    }
+
+    #[test]
+    fn restarted_download_grows_progress_by_the_refetched_bytes() {
+        let (tx, rx) = mpsc::channel();
+        let reported = Rc::new(Cell::new(Reported::default()));
+        let mut first = ProgressSender {
+            tx: tx.clone(),
+            reported: reported.clone(),
+        };
+        first.size(100);
+        first.write_all(&[0; 40]).unwrap();
+        let mut retry = ProgressSender {
+            tx: tx,
+            reported: reported.clone(),
+        };
+        retry.size(100);
+        retry.write_all(&[0; 100]).unwrap();
+
+        assert_eq!(sizes(&rx), vec![100, 40]);
+        assert_eq!(reported.get().size, reported.get().fetched);
+    }
+}
@@ -35,12 +35,16 @@ pub enum Error {
     DepotClient(depot_client::Error),
     FileNameError,
     HabitatCore(hcore::Error),
+    InvalidBundle(String),
+    LockfileChecksumMismatch((String, String, String)),
+    LockfileIncomplete((String, String)),
     /// Occurs when making lower level IO calls.
     IO(io::Error),
     RootRequired,
     StrFromUtf8Error(str::Utf8Error),
     StringFromUtf8Error(string::FromUtf8Error),
     TomlSerializeError(toml::ser::Error),
+    UntrustedBundleKey((String, String)),
     WireDecode(String),
     PackageNotFound,
 }
@@ -69,13 +73,39 @@ impl fmt::Display for Error {
             Error::DepotClient(ref err) => format!("{}", err),
             Error::FileNameError => format!("Failed to extract a filename"),
             Error::HabitatCore(ref e) => format!("{}", e),
+            Error::InvalidBundle(ref p) => {
+                format!("Invalid bundle: {} does not name the package it bundles", p)
+            }
             Error::IO(ref err) => format!("{}", err),
+            Error::LockfileChecksumMismatch((ref i, ref e, ref a)) => {
+                format!(
+                    "Artifact for {} has checksum {} but the lockfile expects {}",
+                    i,
+                    a,
+                    e
+                )
+            }
+            Error::LockfileIncomplete((ref i, ref d)) => {
+                format!(
+                    "{} depends on {} which the lockfile does not list",
+                    i,
+                    d
+                )
+            }
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation".to_string()
             }
             Error::StrFromUtf8Error(ref e) => format!("{}", e),
             Error::StringFromUtf8Error(ref e) => format!("{}", e),
             Error::TomlSerializeError(ref e) => format!("Can't serialize TOML: {}", e),
+            Error::UntrustedBundleKey((ref key, ref origin)) => {
+                format!(
+                    "Refusing to import public origin key {} from the bundle: it is not in the \
+                     key cache and origin {} is not trusted",
+                    key,
+                    origin
+                )
+            }
             Error::WireDecode(ref m) => format!("Failed to decode wire message: {}", m),
             Error::PackageNotFound => format!("Package not found"),
         };
@@ -97,13 +127,21 @@ impl error::Error for Error {
             Error::DepotClient(ref err) => err.description(),
             Error::FileNameError => "Failed to extract a filename from a path",
             Error::HabitatCore(ref err) => err.description(),
+            Error::InvalidBundle(_) => "Bundle does not name the package it bundles",
             Error::IO(ref err) => err.description(),
+            Error::LockfileChecksumMismatch(_) => {
+                "Artifact checksum does not match the checksum in the lockfile"
+            }
+            Error::LockfileIncomplete(_) => "Lockfile does not list every dependency",
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation"
             }
             Error::StrFromUtf8Error(_) => "Failed to convert a string as UTF-8",
             Error::StringFromUtf8Error(_) => "Failed to convert a string as UTF-8",
             Error::TomlSerializeError(_) => "Can't serialize TOML",
+            Error::UntrustedBundleKey(_) => {
+                "Bundle carries a public origin key which isn't trusted"
+            }
             Error::WireDecode(_) => "Failed to decode wire message",
             Error::PackageNotFound => "Package not found",
         }
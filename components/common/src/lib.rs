@@ -22,6 +22,7 @@ extern crate log;
 extern crate pbr;
 extern crate regex;
 extern crate retry;
+extern crate tar;
 #[cfg(test)]
 extern crate tempdir;
 extern crate term;
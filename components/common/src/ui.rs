@@ -716,6 +716,22 @@ impl Default for ProgressBar {
     }
 }
 
+impl ProgressBar {
+    /// Adds `size` to the total size of the task, for tasks made up of several transfers which
+    /// each only learn their size once they have started.
+    pub fn grow(&mut self, size: u64) {
+        self.total += size;
+        self.bar.total = self.total;
+    }
+
+    /// Moves the bar `amount` towards completion on behalf of a transfer which is not written
+    /// through this bar.
+    pub fn advance(&mut self, amount: u64) {
+        self.bar.add(amount);
+        self.current += amount;
+    }
+}
+
 impl DisplayProgress for ProgressBar {
     fn size(&mut self, size: u64) {
         self.bar = pbr::ProgressBar::new(size);
@@ -97,6 +97,8 @@ pub enum Error {
     InvalidServiceGroup(String),
     /// Occurs when making lower level IO calls.
     IO(io::Error),
+    /// Occurs when a lockfile cannot be parsed or does not pin a complete set of releases.
+    LockfileMalformed(String),
     // When LogonUserW does not have the correct logon type
     LogonTypeNotGranted,
     /// Occurs when a call to LogonUserW fails
@@ -287,6 +289,7 @@ impl fmt::Display for Error {
                 )
             }
             Error::IO(ref err) => format!("{}", err),
+            Error::LockfileMalformed(ref e) => format!("Malformed lockfile: {}", e),
             Error::LogonTypeNotGranted => {
                 format!(
                     "hab_svc_user user must possess the 'SE_SERVICE_LOGON_NAME' \
@@ -418,6 +421,7 @@ impl error::Error for Error {
                 "Service group strings must be in service.group format (example: redis.production)"
             }
             Error::IO(ref err) => err.description(),
+            Error::LockfileMalformed(_) => "Lockfile cannot be parsed or is incomplete",
             Error::LogonTypeNotGranted => {
                 "Logon type not granted to hab_svc_user to be spawned by the supervisor"
             }
@@ -137,7 +137,7 @@ impl Child {
         }
     }
 
-    pub fn kill(&mut self) -> Result<ShutdownMethod> {
+    pub fn kill(&mut self, timeout: Duration) -> Result<ShutdownMethod> {
         // check the group of the process being killed
         // if it is the root process of the process group
         // we send our signals to the entire process group
@@ -154,7 +154,7 @@ impl Child {
         }
 
         signal(self.pid, Signal::TERM)?;
-        let stop_time = SteadyTime::now() + Duration::seconds(8);
+        let stop_time = SteadyTime::now() + timeout;
         loop {
             if let Ok(status) = self.status() {
                 if !status.no_status() {
@@ -29,6 +29,8 @@ use std::fmt;
 #[cfg(not(windows))]
 use std::process::Child;
 
+use time::Duration;
+
 #[cfg(windows)]
 use self::windows_child::Child;
 
@@ -36,6 +38,9 @@ use error::Result;
 
 pub use self::imp::{become_command, current_pid, is_alive, signal, Pid, SignalCode};
 
+/// Number of seconds a child process is given to shut down gracefully before it is killed.
+pub const DEFAULT_KILL_TIMEOUT_SECS: i64 = 8;
+
 pub trait OsSignal {
     fn os_signal(&self) -> SignalCode;
     fn from_signal_code(SignalCode) -> Option<Signal>;
@@ -96,7 +101,13 @@ impl HabChild {
     }
 
     pub fn kill(&mut self) -> Result<ShutdownMethod> {
-        self.inner.kill()
+        self.kill_with_timeout(Duration::seconds(DEFAULT_KILL_TIMEOUT_SECS))
+    }
+
+    /// Ask the child process to shut down gracefully, forcibly killing it if it has not exited
+    /// once the given timeout has elapsed.
+    pub fn kill_with_timeout(&mut self, timeout: Duration) -> Result<ShutdownMethod> {
+        self.inner.kill(timeout)
     }
 }
 
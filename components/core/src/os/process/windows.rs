@@ -189,7 +189,7 @@ impl Child {
         Ok(HabExitStatus { status: Some(exit_status) })
     }
 
-    pub fn kill(&mut self) -> Result<ShutdownMethod> {
+    pub fn kill(&mut self, timeout: Duration) -> Result<ShutdownMethod> {
         if self.last_status.is_some() {
             return Ok(ShutdownMethod::AlreadyExited);
         }
@@ -207,7 +207,7 @@ impl Child {
             }
         }
 
-        let stop_time = SteadyTime::now() + Duration::seconds(8);
+        let stop_time = SteadyTime::now() + timeout;
 
         let result;
         loop {
@@ -43,6 +43,9 @@ lazy_static! {
         map.insert(MetaFile::TDeps,
                    Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
                               MetaFile::TDeps)).unwrap());
+        map.insert(MetaFile::Description,
+                   Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
+                              MetaFile::Description)).unwrap());
         map.insert(MetaFile::Exposes,
                    Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
                               MetaFile::Exposes)).unwrap());
@@ -55,6 +58,12 @@ lazy_static! {
         map.insert(MetaFile::LdFlags,
                    Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
                               MetaFile::LdFlags)).unwrap());
+        map.insert(MetaFile::Licenses,
+                   Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
+                              MetaFile::Licenses)).unwrap());
+        map.insert(MetaFile::Maintainer,
+                   Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
+                              MetaFile::Maintainer)).unwrap());
         map.insert(MetaFile::Manifest,
                    Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
                               MetaFile::Manifest)).unwrap());
@@ -64,6 +73,9 @@ lazy_static! {
         map.insert(MetaFile::Target,
                    Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
                               MetaFile::Target)).unwrap());
+        map.insert(MetaFile::UpstreamUrl,
+                   Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
+                              MetaFile::UpstreamUrl)).unwrap());
         map
     };
 }
@@ -131,6 +143,14 @@ impl PackageArchive {
         self.read_deps(MetaFile::TDeps)
     }
 
+    /// Returns the package's description, as given by `pkg_description` in its plan.
+    pub fn description(&mut self) -> Result<Option<String>> {
+        match self.read_metadata(MetaFile::Description) {
+            Ok(data) => Ok(data.cloned()),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn exposes(&mut self) -> Result<Vec<u16>> {
         match self.read_metadata(MetaFile::Exposes) {
             Ok(Some(data)) => {
@@ -166,6 +186,23 @@ impl PackageArchive {
         }
     }
 
+    /// Returns the licenses the package is distributed under, as given by `pkg_license` in its
+    /// plan. Archives built before this metadata was recorded return an empty list.
+    pub fn licenses(&mut self) -> Result<Vec<String>> {
+        match self.read_metadata(MetaFile::Licenses) {
+            Ok(Some(data)) => Ok(data.lines().map(|l| l.trim().to_string()).collect()),
+            Ok(None) => Ok(vec![]),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn maintainer(&mut self) -> Result<Option<String>> {
+        match self.read_metadata(MetaFile::Maintainer) {
+            Ok(data) => Ok(data.cloned()),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn manifest(&mut self) -> Result<String> {
         match self.read_metadata(MetaFile::Manifest) {
             Ok(None) => Err(Error::MetaFileNotFound(MetaFile::Manifest)),
@@ -189,6 +226,13 @@ impl PackageArchive {
         }
     }
 
+    pub fn upstream_url(&mut self) -> Result<Option<String>> {
+        match self.read_metadata(MetaFile::UpstreamUrl) {
+            Ok(data) => Ok(data.cloned()),
+            Err(e) => Err(e),
+        }
+    }
+
     /// A plain string representation of the archive's file name.
     pub fn file_name(&self) -> String {
         self.path
@@ -85,17 +85,24 @@ impl PackageIdent {
         }
     }
 
+    /// Returns the file name of this package's artifact for the current system's target, or
+    /// `None` if the identifier isn't fully qualified.
     pub fn archive_name(&self) -> Option<String> {
+        self.archive_name_with_target(&PackageTarget::default())
+    }
+
+    /// Returns the file name of this package's artifact for the given target, or `None` if the
+    /// identifier isn't fully qualified.
+    pub fn archive_name_with_target(&self, target: &PackageTarget) -> Option<String> {
         if self.fully_qualified() {
-            let default_target = PackageTarget::default();
             Some(format!(
                 "{}-{}-{}-{}-{}-{}.hart",
                 self.origin,
                 self.name,
                 self.version.as_ref().unwrap(),
                 self.release.as_ref().unwrap(),
-                default_target.architecture,
-                default_target.platform
+                target.architecture,
+                target.platform
             ))
         } else {
             None
@@ -602,4 +609,16 @@ mod tests {
         assert!(!invalid1.valid());
         assert!(!invalid2.valid());
     }
+
+    #[test]
+    fn archive_name_with_target() {
+        let partial = PackageIdent::new("acme", "rocket", None, None);
+        let full = PackageIdent::new("acme", "rocket", Some("1.2.3"), Some("1234"));
+        let target = PackageTarget::from_str("x86_64-windows").unwrap();
+        assert_eq!(partial.archive_name_with_target(&target), None);
+        assert_eq!(
+            full.archive_name_with_target(&target),
+            Some("acme-rocket-1.2.3-1234-x86_64-windows.hart".to_string())
+        );
+    }
 }
@@ -188,6 +188,28 @@ impl PackageInstall {
         }
     }
 
+    /// Returns every package release installed in the package path.
+    ///
+    /// An optional `fs_root` path may be provided to search for packages that are mounted on a
+    /// filesystem not currently rooted at `/`.
+    pub fn installed_packages(fs_root_path: Option<&Path>) -> Result<Vec<PackageInstall>> {
+        let fs_root_path = fs_root_path.map_or(PathBuf::from("/"), |p| p.into());
+        let package_root_path = fs::pkg_root_path(Some(&fs_root_path));
+        if !package_root_path.exists() {
+            return Ok(Vec::new());
+        }
+        let packages = Self::package_list(&package_root_path)?
+            .into_iter()
+            .map(|ident| PackageInstall {
+                installed_path: fs::pkg_install_path(&ident, Some(&fs_root_path)),
+                fs_root_path: fs_root_path.clone(),
+                package_root_path: package_root_path.clone(),
+                ident: ident,
+            })
+            .collect();
+        Ok(packages)
+    }
+
     pub fn new_from_parts(
         ident: PackageIdent,
         fs_root_path: PathBuf,
@@ -257,7 +279,7 @@ impl PackageInstall {
         }
     }
 
-    fn deps(&self) -> Result<Vec<PackageIdent>> {
+    pub fn deps(&self) -> Result<Vec<PackageIdent>> {
         self.read_deps(MetaFile::Deps)
     }
 
@@ -334,6 +356,23 @@ impl PackageInstall {
         }
     }
 
+    /// Returns a Rust representation of the settings defined by the `pkg_health_check` plan
+    /// variable.
+    ///
+    /// These settings describe a built-in probe the Supervisor runs against the service when the
+    /// package doesn't provide a `health_check` hook.
+    pub fn health_check(&self) -> Result<HashMap<String, String>> {
+        match self.read_metafile(MetaFile::HealthCheck) {
+            Ok(body) => {
+                Ok(parse_key_value(&body).map_err(|_| {
+                    Error::MetaFileMalformed(MetaFile::HealthCheck)
+                })?)
+            }
+            Err(Error::MetaFileNotFound(MetaFile::HealthCheck)) => Ok(HashMap::new()),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn ident(&self) -> &PackageIdent {
         &self.ident
     }
@@ -445,6 +484,46 @@ impl PackageInstall {
         }
     }
 
+    /// Returns the package's description or None if the package doesn't contain a DESCRIPTION
+    /// Metafile
+    pub fn description(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::Description) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::Description)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the licenses the package is distributed under, or an empty list if the package
+    /// doesn't contain a LICENSES Metafile
+    pub fn licenses(&self) -> Result<Vec<String>> {
+        match self.read_metafile(MetaFile::Licenses) {
+            Ok(body) => Ok(body.lines().map(|l| l.trim().to_string()).collect()),
+            Err(Error::MetaFileNotFound(MetaFile::Licenses)) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the package's maintainer or None if the package doesn't contain a MAINTAINER
+    /// Metafile
+    pub fn maintainer(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::Maintainer) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::Maintainer)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the URL of the software the package was built from or None if the package
+    /// doesn't contain an UPSTREAM_URL Metafile
+    pub fn upstream_url(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::UpstreamUrl) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::UpstreamUrl)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Returns the group that the package is specified to run as
     /// or None if the package doesn't contain a SVC_GROUP Metafile
     pub fn svc_group(&self) -> Result<Option<String>> {
@@ -643,8 +722,11 @@ impl fmt::Display for PackageInstall {
 
 #[cfg(test)]
 mod test {
+    use std::fs::{self, File};
+    use std::io::Write;
     use std::str::FromStr;
     use std::path::PathBuf;
+    use tempdir::TempDir;
     use toml;
     use super::super::PackageIdent;
     use super::PackageInstall;
@@ -668,4 +750,76 @@ mod test {
             Err(e) => assert!(false, format!("{:?}", e)),
         }
     }
+
+    #[test]
+    fn installed_packages_lists_every_release() {
+        let fs_root = TempDir::new("fs-root").unwrap();
+        for ident in &["core/redis/3.2.4/20170514150022", "core/redis/3.2.4/20170601000000"] {
+            let ident = PackageIdent::from_str(ident).unwrap();
+            fs::create_dir_all(::fs::pkg_install_path(&ident, Some(fs_root.path()))).unwrap();
+        }
+
+        let mut installed: Vec<String> = PackageInstall::installed_packages(Some(fs_root.path()))
+            .unwrap()
+            .iter()
+            .map(|p| p.ident().to_string())
+            .collect();
+        installed.sort();
+        assert_eq!(
+            installed,
+            vec![
+                "core/redis/3.2.4/20170514150022",
+                "core/redis/3.2.4/20170601000000",
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_descriptive_metadata() {
+        let fs_root = TempDir::new("fs-root").unwrap();
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap();
+        let installed_path = ::fs::pkg_install_path(&ident, Some(fs_root.path()));
+        fs::create_dir_all(&installed_path).unwrap();
+        let package_install = PackageInstall {
+            ident: ident,
+            fs_root_path: fs_root.path().to_path_buf(),
+            package_root_path: PathBuf::from(""),
+            installed_path: installed_path.clone(),
+        };
+        assert_eq!(package_install.description().unwrap(), None);
+        assert!(package_install.licenses().unwrap().is_empty());
+
+        File::create(installed_path.join("DESCRIPTION"))
+            .unwrap()
+            .write_all(b"Persistent key-value database\n")
+            .unwrap();
+        File::create(installed_path.join("LICENSES"))
+            .unwrap()
+            .write_all(b"BSD-3-Clause\nMIT\n")
+            .unwrap();
+        File::create(installed_path.join("UPSTREAM_URL"))
+            .unwrap()
+            .write_all(b"http://redis.io\n")
+            .unwrap();
+        assert_eq!(
+            package_install.description().unwrap(),
+            Some("Persistent key-value database".to_string())
+        );
+        assert_eq!(package_install.licenses().unwrap(), vec!["BSD-3-Clause", "MIT"]);
+        assert_eq!(package_install.maintainer().unwrap(), None);
+        assert_eq!(
+            package_install.upstream_url().unwrap(),
+            Some("http://redis.io".to_string())
+        );
+    }
+
+    #[test]
+    fn installed_packages_without_a_package_path() {
+        let fs_root = TempDir::new("fs-root").unwrap();
+        assert!(
+            PackageInstall::installed_packages(Some(fs_root.path()))
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
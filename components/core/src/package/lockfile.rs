@@ -0,0 +1,203 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lockfiles pin a package and every one of its transitive dependencies to a fully qualified
+//! release and the checksum of its artifact, so that installing from a lockfile yields the same
+//! packages no matter what a Depot's channels hold at the time.
+//!
+//! A lockfile is TOML, listing dependencies before the packages which depend on them:
+//!
+//! ```toml
+//! ident = "core/redis/3.2.4/20170514150022"
+//!
+//! [[package]]
+//! ident = "core/glibc/2.22/20170513201042"
+//! checksum = "27fb2bca6a1fbd4e0c9e1e5e6ef7fc1d6e9d6d4c0ba4c6d1d8c0a4b0e1f3a9b2"
+//!
+//! [[package]]
+//! ident = "core/redis/3.2.4/20170514150022"
+//! checksum = "0f4cdb2b3a4e1de6a38bd8f9a3e4b7d3cbbb3c5a4f7e1d2c9b8a7f6e5d4c3b2a"
+//! ```
+
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::Path;
+use std::result;
+use std::str::FromStr;
+
+use toml;
+
+use super::{Identifiable, PackageIdent};
+use error::{Error, Result};
+use util::{deserialize_using_from_str, serialize_using_to_string};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Lockfile {
+    /// The package the lockfile was made for.
+    #[serde(deserialize_with = "deserialize_using_from_str",
+            serialize_with = "serialize_using_to_string")]
+    pub ident: PackageIdent,
+    /// The package and its transitive dependencies, dependencies first.
+    #[serde(rename = "package", default)]
+    pub packages: Vec<LockedPackage>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct LockedPackage {
+    #[serde(deserialize_with = "deserialize_using_from_str",
+            serialize_with = "serialize_using_to_string")]
+    pub ident: PackageIdent,
+    /// The checksum of the package's artifact.
+    pub checksum: String,
+}
+
+impl Lockfile {
+    pub fn new(ident: PackageIdent, packages: Vec<LockedPackage>) -> Result<Self> {
+        let lockfile = Lockfile {
+            ident: ident,
+            packages: packages,
+        };
+        try!(lockfile.validate());
+        Ok(lockfile)
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let mut body = String::new();
+        try!(try!(File::open(path.as_ref())).read_to_string(&mut body));
+        Self::from_str(&body)
+    }
+
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let tmp_path = path.as_ref().with_extension("tmp");
+        {
+            let mut file = try!(File::create(&tmp_path));
+            try!(file.write_all(self.to_string().as_bytes()));
+        }
+        try!(fs::rename(&tmp_path, path.as_ref()));
+        Ok(())
+    }
+
+    /// Returns the locked release of the package `ident` names, if the lockfile lists it.
+    pub fn get(&self, ident: &PackageIdent) -> Option<&LockedPackage> {
+        self.packages.iter().find(|p| &p.ident == ident)
+    }
+
+    /// Checks that every release is fully qualified and that the package the lockfile was made
+    /// for is listed.
+    fn validate(&self) -> Result<()> {
+        if !self.ident.fully_qualified() {
+            return Err(Error::LockfileMalformed(
+                format!("{} is not fully qualified", self.ident),
+            ));
+        }
+        for package in self.packages.iter() {
+            if !package.ident.fully_qualified() {
+                return Err(Error::LockfileMalformed(
+                    format!("{} is not fully qualified", package.ident),
+                ));
+            }
+        }
+        if self.get(&self.ident).is_none() {
+            return Err(Error::LockfileMalformed(
+                format!("{} is not listed as a package", self.ident),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Lockfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match toml::to_string(self) {
+            Ok(body) => write!(f, "{}", body),
+            Err(_) => Err(fmt::Error),
+        }
+    }
+}
+
+impl FromStr for Lockfile {
+    type Err = Error;
+
+    fn from_str(body: &str) -> result::Result<Self, Self::Err> {
+        let lockfile: Lockfile = try!(toml::from_str(body).map_err(|e| {
+            Error::LockfileMalformed(e.to_string())
+        }));
+        try!(lockfile.validate());
+        Ok(lockfile)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+    use package::PackageIdent;
+
+    fn locked(ident: &str, checksum: &str) -> LockedPackage {
+        LockedPackage {
+            ident: PackageIdent::from_str(ident).unwrap(),
+            checksum: checksum.to_string(),
+        }
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let lockfile = Lockfile::new(
+            PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap(),
+            vec![
+                locked("core/glibc/2.22/20170513201042", "abc"),
+                locked("core/redis/3.2.4/20170514150022", "def"),
+            ],
+        ).unwrap();
+        let parsed = Lockfile::from_str(&lockfile.to_string()).unwrap();
+        assert_eq!(parsed, lockfile);
+        assert_eq!(
+            parsed
+                .get(&PackageIdent::from_str("core/glibc/2.22/20170513201042").unwrap())
+                .unwrap()
+                .checksum,
+            "abc"
+        );
+    }
+
+    #[test]
+    fn rejects_releases_which_are_not_fully_qualified() {
+        let body = r#"
+            ident = "core/redis/3.2.4/20170514150022"
+
+            [[package]]
+            ident = "core/glibc"
+            checksum = "abc"
+
+            [[package]]
+            ident = "core/redis/3.2.4/20170514150022"
+            checksum = "def"
+        "#;
+        assert!(Lockfile::from_str(body).is_err());
+    }
+
+    #[test]
+    fn rejects_a_lockfile_which_does_not_list_its_package() {
+        let body = r#"
+            ident = "core/redis/3.2.4/20170514150022"
+
+            [[package]]
+            ident = "core/glibc/2.22/20170513201042"
+            checksum = "abc"
+        "#;
+        assert!(Lockfile::from_str(body).is_err());
+    }
+}
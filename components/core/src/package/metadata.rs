@@ -133,18 +133,23 @@ pub enum MetaFile {
     Config,
     Deps,
     TDeps,
+    Description,
     Environment,
     EnvironmentSep,
     Exports,
     Exposes,
+    HealthCheck,
     Ident,
     LdRunPath,
     LdFlags,
+    Licenses,
+    Maintainer,
     Manifest,
     Path,
     SvcUser,
     SvcGroup,
     Target,
+    UpstreamUrl,
 }
 
 impl fmt::Display for MetaFile {
@@ -156,18 +161,23 @@ impl fmt::Display for MetaFile {
             MetaFile::Config => "default.toml",
             MetaFile::Deps => "DEPS",
             MetaFile::TDeps => "TDEPS",
+            MetaFile::Description => "DESCRIPTION",
             MetaFile::Environment => "ENVIRONMENT",
             MetaFile::EnvironmentSep => "ENVIRONMENT_SEP",
             MetaFile::Exports => "EXPORTS",
             MetaFile::Exposes => "EXPOSES",
+            MetaFile::HealthCheck => "HEALTH_CHECK",
             MetaFile::Ident => "IDENT",
             MetaFile::LdRunPath => "LD_RUN_PATH",
             MetaFile::LdFlags => "LDFLAGS",
+            MetaFile::Licenses => "LICENSES",
+            MetaFile::Maintainer => "MAINTAINER",
             MetaFile::Manifest => "MANIFEST",
             MetaFile::Path => "PATH",
             MetaFile::SvcUser => "SVC_USER",
             MetaFile::SvcGroup => "SVC_GROUP",
             MetaFile::Target => "TARGET",
+            MetaFile::UpstreamUrl => "UPSTREAM_URL",
         };
         write!(f, "{}", id)
     }
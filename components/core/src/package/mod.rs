@@ -15,6 +15,7 @@
 pub mod archive;
 pub mod ident;
 pub mod install;
+pub mod lockfile;
 pub mod metadata;
 pub mod plan;
 pub mod target;
@@ -22,6 +23,7 @@ pub mod target;
 pub use self::archive::{FromArchive, PackageArchive};
 pub use self::ident::{Identifiable, PackageIdent};
 pub use self::install::PackageInstall;
+pub use self::lockfile::{LockedPackage, Lockfile};
 pub use self::plan::Plan;
 pub use self::target::{Target, PackageTarget};
 
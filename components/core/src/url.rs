@@ -26,3 +26,16 @@ pub fn default_depot_url() -> String {
         Err(_) => DEFAULT_DEPOT_URL.to_string(),
     }
 }
+
+/// Default Builder API URL
+pub const DEFAULT_BLDR_URL: &'static str = "https://willem.habitat.sh/v1";
+
+/// Default Builder API URL environment variable
+pub const BLDR_URL_ENVVAR: &'static str = "HAB_BLDR_URL";
+
+pub fn default_bldr_url() -> String {
+    match env::var(BLDR_URL_ENVVAR) {
+        Ok(val) => val,
+        Err(_) => DEFAULT_BLDR_URL.to_string(),
+    }
+}
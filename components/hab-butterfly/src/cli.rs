@@ -42,7 +42,9 @@ pub fn get() -> App<'static, 'static> {
                     "Target service group (ex: redis.default)")
                 (@arg VERSION_NUMBER: +required
                     "A version number (positive integer) for this configuration (ex: 42)")
-                (@arg FILE: +required {file_exists} "Path to local file on disk")
+                (@arg FILE: +required {file_or_dir_exists}
+                    "Path to local file on disk, or to a directory whose files are uploaded \
+                    together as a single bundle")
                 (@arg ORG: --org +takes_value "Name of service organization")
                 (@arg USER: -u --user +takes_value "Name of the user key")
                 (@arg PEER: -p --peer +takes_value
@@ -52,6 +54,23 @@ pub fn get() -> App<'static, 'static> {
                     "Ring key name, which will encrypt communication messages")
             )
         )
+        (@subcommand member =>
+            (about: "Commands relating to Habitat Supervisor ring members")
+            (aliases: &["m", "me", "mem", "memb", "membe"])
+            (@setting ArgRequiredElseHelp)
+            (@subcommand depart =>
+                (about: "Permanently remove a member from the supervisor ring. The member \
+                    cannot rejoin with the same member id.")
+                (aliases: &["d", "de", "dep", "depa", "depar"])
+                (@arg MEMBER_ID: +required +takes_value
+                    "The member id of the departing Supervisor")
+                (@arg PEER: -p --peer +takes_value
+                    "A comma-delimited list of one or more Habitat Supervisor peers \
+                    (default: 127.0.0.1:9638)")
+                (@arg RING: -r --ring +takes_value
+                    "Ring key name, which will encrypt communication messages")
+            )
+        )
     )
 }
 
@@ -82,6 +101,14 @@ fn file_exists(val: String) -> result::Result<(), String> {
     }
 }
 
+fn file_or_dir_exists(val: String) -> result::Result<(), String> {
+    if Path::new(&val).is_dir() {
+        Ok(())
+    } else {
+        file_exists(val)
+    }
+}
+
 fn file_exists_or_stdin(val: String) -> result::Result<(), String> {
     if val == "-" { Ok(()) } else { file_exists(val) }
 }
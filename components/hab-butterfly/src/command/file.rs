@@ -15,7 +15,7 @@
 pub mod upload {
     use std::path::Path;
     use std::io::Read;
-    use std::fs::File;
+    use std::fs::{self, File};
     use std::thread;
     use std::time;
 
@@ -26,6 +26,8 @@ pub mod upload {
 
     use error::{Error, Result};
 
+    /// Uploads a file to the ring. When `file_path` is a directory, every file in it is uploaded
+    /// as a single bundle, so that the files are always updated together.
     pub fn start(
         ui: &mut UI,
         sg: &ServiceGroup,
@@ -36,36 +38,41 @@ pub mod upload {
         user_pair: Option<&BoxKeyPair>,
         service_pair: Option<&BoxKeyPair>,
     ) -> Result<()> {
+        let bundle = file_path.is_dir();
         try!(ui.begin(format!(
-            "Uploading file {} to {} incarnation {}",
+            "Uploading {} {} to {} incarnation {}",
+            if bundle { "directory" } else { "file" },
             &file_path.display(),
             sg,
             number
         )));
-        try!(ui.status(Status::Creating, format!("service file")));
+        try!(ui.status(
+            Status::Creating,
+            format!("service file{}", if bundle { " bundle" } else { "" }),
+        ));
 
-        let mut body = Vec::new();
-        let mut file = try!(File::open(&file_path));
-        try!(file.read_to_end(&mut body));
+        let mut files = if bundle {
+            try!(read_dir_files(file_path))
+        } else {
+            vec![try!(read_file(file_path))]
+        };
 
-        // Safe because clap checks that this is a real file that exists
-        let filename = file_path
-            .file_name()
-            .unwrap()
-            .to_string_lossy()
-            .into_owned();
+        let filename = try!(file_name(file_path));
 
         let mut encrypted = false;
         if service_pair.is_some() && user_pair.is_some() {
             try!(ui.status(
                 Status::Encrypting,
                 format!(
-                    "file as {} for {}",
+                    "{} as {} for {}",
+                    if bundle { "files" } else { "file" },
                     user_pair.unwrap().name_with_rev(),
                     service_pair.unwrap().name_with_rev()
                 ),
             ));
-            body = try!(user_pair.unwrap().encrypt(&body, service_pair.unwrap()));
+            for &mut (_, ref mut body) in files.iter_mut() {
+                *body = try!(user_pair.unwrap().encrypt(body, service_pair.unwrap()));
+            }
             encrypted = true;
         }
 
@@ -76,17 +83,24 @@ pub mod upload {
                     Error::ButterflyError(format!("{}", e))
                 },
             ));
-            try!(
-                client
-                    .send_service_file(
-                        sg.clone(),
-                        filename.clone(),
-                        number,
-                        body.clone(),
-                        encrypted,
-                    )
-                    .map_err(|e| Error::ButterflyError(format!("{}", e)))
-            );
+            let sent = if bundle {
+                client.send_service_file_bundle(
+                    sg.clone(),
+                    filename.clone(),
+                    number,
+                    files.clone(),
+                    encrypted,
+                )
+            } else {
+                client.send_service_file(
+                    sg.clone(),
+                    filename.clone(),
+                    number,
+                    files[0].1.clone(),
+                    encrypted,
+                )
+            };
+            try!(sent.map_err(|e| Error::ButterflyError(format!("{}", e))));
 
             // please take a moment to weep over the following line
             // of code. We must sleep to allow messages to be sent
@@ -94,7 +108,44 @@ pub mod upload {
             // see https://github.com/zeromq/libzmq/issues/1264
             thread::sleep(time::Duration::from_millis(100));
         }
-        try!(ui.end("Uploaded file"));
+        try!(ui.end(
+            format!("Uploaded {}", if bundle { "directory" } else { "file" }),
+        ));
         Ok(())
     }
+
+    fn read_file(file_path: &Path) -> Result<(String, Vec<u8>)> {
+        let mut body = Vec::new();
+        let mut file = try!(File::open(&file_path));
+        try!(file.read_to_end(&mut body));
+        Ok((try!(file_name(file_path)), body))
+    }
+
+    /// Returns the name a file or directory is uploaded as. A path such as `.` or `..` is named
+    /// after the directory it resolves to, while `/` has no name and cannot be uploaded.
+    fn file_name(file_path: &Path) -> Result<String> {
+        if let Some(name) = file_path.file_name() {
+            return Ok(name.to_string_lossy().into_owned());
+        }
+        match try!(file_path.canonicalize()).file_name() {
+            Some(name) => Ok(name.to_string_lossy().into_owned()),
+            None => Err(Error::ArgumentError(
+                "Cannot upload the root directory, as it has no name",
+            )),
+        }
+    }
+
+    /// Reads every file directly inside `dir_path`, sorted by filename. Subdirectories are
+    /// skipped.
+    fn read_dir_files(dir_path: &Path) -> Result<Vec<(String, Vec<u8>)>> {
+        let mut files = Vec::new();
+        for entry in try!(fs::read_dir(dir_path)) {
+            let path = try!(entry).path();
+            if path.is_file() {
+                files.push(try!(read_file(&path)));
+            }
+        }
+        files.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(files)
+    }
 }
@@ -26,6 +26,8 @@ extern crate log;
 
 use std::env;
 use std::ffi::OsString;
+use std::fs;
+use std::io;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::thread;
@@ -94,6 +96,12 @@ fn start(ui: &mut UI) -> Result<()> {
                 _ => unreachable!(),
             }
         }
+        ("member", Some(matches)) => {
+            match matches.subcommand() {
+                ("depart", Some(m)) => try!(sub_member_depart(ui, m)),
+                _ => unreachable!(),
+            }
+        }
         _ => unreachable!(),
     };
     Ok(())
@@ -157,9 +165,9 @@ fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     }
     let number = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
     let file_path = Path::new(m.value_of("FILE").unwrap()); // Required via clap
-    match file_path.metadata() {
-        Ok(md) => {
-            if md.len() > MAX_FILE_UPLOAD_SIZE_BYTES {
+    match upload_size(file_path) {
+        Ok(size) => {
+            if size > MAX_FILE_UPLOAD_SIZE_BYTES {
                 return Err(Error::CryptoCLI(format!(
                     "Maximum encrypted file size is {} bytes",
                     MAX_FILE_UPLOAD_SIZE_BYTES
@@ -206,6 +214,42 @@ fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     )
 }
 
+/// Returns the size of the file at `path`, or the combined size of every file directly inside it
+/// when `path` is a directory.
+fn upload_size(path: &Path) -> io::Result<u64> {
+    if !path.is_dir() {
+        return path.metadata().map(|md| md.len());
+    }
+    let mut size = 0;
+    for entry in fs::read_dir(path)? {
+        let md = entry?.metadata()?;
+        if md.is_file() {
+            size += md.len();
+        }
+    }
+    Ok(size)
+}
+
+fn sub_member_depart(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    let member_id = m.value_of("MEMBER_ID").unwrap(); // Required via clap
+
+    init();
+    let cache = default_cache_key_path(Some(&*FS_ROOT));
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+    command::member::depart::start(ui, member_id, &peers, ring_key.as_ref())
+}
+
 fn ui() -> UI {
     let isatty = if henv::var(NONINTERACTIVE_ENVVAR)
         .map(|val| val == "true")
@@ -18,6 +18,7 @@ use std::str::FromStr;
 
 use clap::{App, AppSettings, Arg};
 use hcore::crypto::keys::PairType;
+use hcore::package::PackageTarget;
 use regex::Regex;
 use url::Url;
 
@@ -63,7 +64,9 @@ pub fn get() -> App<'static, 'static> {
                 (aliases: &["u", "up", "upl", "uplo", "uploa"])
                 (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
                     "Target service group (ex: redis.default)")
-                (@arg FILE: +required {file_exists} "Path to local file on disk")
+                (@arg FILE: +required {file_or_dir_exists}
+                    "Path to local file on disk, or to a directory whose files are uploaded \
+                    together as a single bundle")
                 (@arg VERSION_NUMBER: +required
                     "A version number (positive integer) for this configuration (ex: 42)")
                 (@arg ORG: --org +takes_value "Name of service organization")
@@ -75,6 +78,39 @@ pub fn get() -> App<'static, 'static> {
                     "Ring key name, which will encrypt communication messages")
             )
         )
+        (@subcommand job =>
+            (about: "Commands relating to Builder build jobs")
+            (aliases: &["j", "jo"])
+            (@setting ArgRequiredElseHelp)
+            (@subcommand log =>
+                (about: "Prints the log output of a build job, following it until the job \
+                    finishes")
+                (aliases: &["l", "lo"])
+                (@arg JOB_ID: +required +takes_value {valid_numeric}
+                    "The id of the build job (ex: 721389762341912576)")
+                (@arg START: -s --start +takes_value {valid_numeric}
+                    "Line of the log output to start from (default: 0)")
+                (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                    "Use a specific Builder API URL (ex: http://localhost:9636/v1)")
+            )
+        )
+        (@subcommand member =>
+            (about: "Commands relating to Habitat Supervisor ring members")
+            (aliases: &["m", "me", "mem", "memb", "membe"])
+            (@setting ArgRequiredElseHelp)
+            (@subcommand depart =>
+                (about: "Permanently remove a member from the supervisor ring. The member \
+                    cannot rejoin with the same member id.")
+                (aliases: &["d", "de", "dep", "depa", "depar"])
+                (@arg MEMBER_ID: +required +takes_value
+                    "The member id of the departing Supervisor")
+                (@arg PEER: -p --peer +takes_value
+                    "A comma-delimited list of one or more Habitat Supervisor peers \
+                    (default: 127.0.0.1:9638)")
+                (@arg RING: -r --ring +takes_value
+                    "Ring key name, which will encrypt communication messages")
+            )
+        )
         (@subcommand origin =>
             (about: "Commands relating to Habitat origin keys")
             (aliases: &["o", "or", "ori", "orig", "origi"])
@@ -141,6 +177,21 @@ pub fn get() -> App<'static, 'static> {
                 (@arg DEST_DIR: -d --dest +takes_value
                     "Sets the destination directory (default: /bin)")
             )
+            (@subcommand bundle =>
+                (about: "Bundles a package, its dependencies and their public origin keys into \
+                    a single file which installs without Depot access")
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg DEPOT_URL: --url -u +takes_value {valid_url}
+                    "Use a specific Depot URL [default: https://bldr.habitat.sh/v1/depot]")
+                (@arg CHANNEL: --channel -c +takes_value
+                    "Bundle from the specified release channel")
+                (@arg DEST_DIR: --("dest-dir") -d +takes_value {dir_exists}
+                    "The directory to write the bundle to [default: .]")
+                (@arg TARGET: --target -t +takes_value {valid_target}
+                    "Bundle the artifacts built for this target (ex: x86_64-windows) \
+                    [default: the target of this system]")
+            )
             (@subcommand config =>
                 (about: "Displays the default configuration options for a service")
                 (aliases: &["conf", "cfg"])
@@ -171,6 +222,18 @@ pub fn get() -> App<'static, 'static> {
                 (@arg PKG_IDENT: +required +takes_value
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
             )
+            (@subcommand gc =>
+                (about: "Removes old package releases and prunes the artifact cache")
+                (@arg KEEP_LATEST: --("keep-latest") +takes_value {valid_positive_numeric}
+                    "The number of newest releases of every package to keep [default: 2]")
+                (@arg CACHE_MAX_SIZE: --("cache-max-size") +takes_value {valid_numeric}
+                    "Remove the oldest cached artifacts until the artifact cache is no larger \
+                    than this many megabytes")
+                (@arg CACHE_MAX_AGE: --("cache-max-age") +takes_value {valid_numeric}
+                    "Remove cached artifacts older than this many days")
+                (@arg DRY_RUN: -n --("dry-run")
+                    "Show what would be removed without removing anything")
+            )
             (@subcommand hash =>
                 (about: "Generates a blake2b hashsum from a target at any given filepath")
                 (aliases: &["ha", "has"])
@@ -210,6 +273,18 @@ pub fn get() -> App<'static, 'static> {
                     "The destination path to the signed Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
             )
+            (@subcommand uninstall =>
+                (about: "Safely uninstalls a package release, refusing to remove anything another \
+                    installed package or a loaded service still depends on")
+                (aliases: &["un", "uni", "unin", "unins", "uninst", "uninsta", "uninstal"])
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg REMOVE_ORPHANS: --("remove-orphans")
+                    "Also remove the package's dependencies which no other installed package or \
+                    loaded service depends on")
+                (@arg DRY_RUN: -n --("dry-run")
+                    "Show what would be removed without removing anything")
+            )
             (@subcommand upload =>
                 (about: "Uploads a local Habitat Artifact to a Depot")
                 (aliases: &["u", "up", "upl", "uplo", "uploa"])
@@ -507,10 +582,20 @@ fn sub_pkg_install() -> App<'static, 'static> {
             "Use a specific Depot URL [default: https://bldr.habitat.sh/v1/depot]")
         (@arg CHANNEL: --channel -c +takes_value
             "Install from the specified release channel")
-        (@arg PKG_IDENT_OR_ARTIFACT: +required +multiple
+        (@arg PKG_IDENT_OR_ARTIFACT: +multiple required_unless[LOCKFILE]
             "One or more Habitat package identifiers (ex: acme/redis) and/or filepaths \
-            to a Habitat Artifact (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
+            to a Habitat Artifact (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart) \
+            or bundle (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.bundle)")
         (@arg BINLINK: -b --binlink "Binlink all binaries from installed package(s)")
+        (@arg LOCKFILE: --lockfile +takes_value {file_exists}
+            conflicts_with[PKG_IDENT_OR_ARTIFACT WRITE_LOCKFILE]
+            "Install exactly the releases pinned by a lockfile, refusing any artifact whose \
+            checksum differs from it")
+        (@arg WRITE_LOCKFILE: --("write-lockfile") +takes_value
+            "Write a lockfile pinning the installed package and its dependencies to this path")
+        (@arg TARGET: --target -t +takes_value {valid_target}
+            "Install the artifacts built for this target (ex: x86_64-linux) \
+            [default: the target of this system]")
     );
     sub.arg(
         Arg::with_name("IGNORE_TARGET")
@@ -518,7 +603,19 @@ fn sub_pkg_install() -> App<'static, 'static> {
             .short("i")
             .long("ignore-target")
             .hidden(true),
-    )
+    ).arg(
+            Arg::with_name("TRUST_ORIGIN")
+                .help(
+                    "Import the public origin keys of this origin carried by a bundle. Keys of \
+                    other origins must already be in the key cache",
+                )
+                .long("trust-origin")
+                .value_name("ORIGIN")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .conflicts_with("LOCKFILE"),
+        )
 }
 
 fn file_exists(val: String) -> result::Result<(), String> {
@@ -529,10 +626,40 @@ fn file_exists(val: String) -> result::Result<(), String> {
     }
 }
 
+fn dir_exists(val: String) -> result::Result<(), String> {
+    if Path::new(&val).is_dir() {
+        Ok(())
+    } else {
+        Err(format!("Directory: '{}' cannot be found", &val))
+    }
+}
+
+fn file_or_dir_exists(val: String) -> result::Result<(), String> {
+    if Path::new(&val).is_dir() {
+        Ok(())
+    } else {
+        file_exists(val)
+    }
+}
+
 fn file_exists_or_stdin(val: String) -> result::Result<(), String> {
     if val == "-" { Ok(()) } else { file_exists(val) }
 }
 
+fn valid_numeric(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("'{}' is not a valid number", &val)),
+    }
+}
+
+fn valid_positive_numeric(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(n) if n > 0 => Ok(()),
+        _ => Err(format!("'{}' is not a number greater than zero", &val)),
+    }
+}
+
 fn valid_pair_type(val: String) -> result::Result<(), String> {
     match PairType::from_str(&val) {
         Ok(_) => Ok(()),
@@ -554,6 +681,13 @@ fn valid_service_group(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_target(val: String) -> result::Result<(), String> {
+    match PackageTarget::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("TARGET: '{}' is not a valid package target", &val)),
+    }
+}
+
 fn valid_url(val: String) -> result::Result<(), String> {
     match Url::parse(&val) {
         Ok(_) => Ok(()),
@@ -0,0 +1,248 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Follows the log of a Builder job as it runs, by reading the Server-Sent Events stream served
+//! by the Builder API.
+
+use std::cmp;
+use std::io::{BufRead, BufReader, Write};
+use std::thread;
+use std::time::Duration;
+
+use common::ui::UI;
+use http_client::ApiClient;
+use hyper::status::StatusCode;
+
+use {PRODUCT, VERSION};
+use error::{Error, Result};
+
+/// Delay before the first reconnect to a log stream which ended early, doubled on each
+/// reconnect that brings no new lines
+const RECONNECT_DELAY_MS: u64 = 1_000;
+/// Longest delay between reconnects
+const MAX_RECONNECT_DELAY_MS: u64 = 30_000;
+/// Number of reconnects in a row without new lines after which following the log is given up
+const MAX_STALLED_RECONNECTS: u32 = 5;
+
+/// An event read from a job's log stream
+#[derive(Debug, PartialEq)]
+enum Event {
+    /// A line of log output, along with its zero-indexed line number
+    Line(Option<u64>, String),
+    /// The log is complete
+    Complete,
+}
+
+pub fn start(ui: &mut UI, bldr_url: &str, job_id: u64, start: u64) -> Result<()> {
+    let client = try!(ApiClient::new(bldr_url, PRODUCT, VERSION, None));
+    let color = ui.out().is_colored();
+    let mut next = start;
+    try!(ui.begin(format!("Following the log of job {}", job_id)));
+    let mut backoff = Backoff::default();
+    // The connection can drop while a long build is quiet, in which case the stream is picked
+    // up again from the next line to be printed
+    loop {
+        let last = next;
+        if try!(follow(ui, &client, job_id, color, &mut next)) {
+            break;
+        }
+        if next != last {
+            backoff.reset();
+        }
+        match backoff.next_delay() {
+            Some(delay) => {
+                debug!(
+                    "Log stream of job {} ended early, reconnecting at line {} in {:?}",
+                    job_id, next, delay
+                );
+                thread::sleep(delay);
+            }
+            None => return Err(Error::JobLogStalled((job_id, backoff.attempts))),
+        }
+    }
+    try!(ui.end(format!("Log of job {} is complete", job_id)));
+    Ok(())
+}
+
+/// Exponential backoff between reconnects to a log stream, reset whenever the log moves on
+#[derive(Debug, Default)]
+struct Backoff {
+    attempts: u32,
+}
+
+impl Backoff {
+    /// Returns how long to wait before the next reconnect, or `None` once too many reconnects
+    /// in a row brought no new lines.
+    fn next_delay(&mut self) -> Option<Duration> {
+        if self.attempts >= MAX_STALLED_RECONNECTS {
+            return None;
+        }
+        let delay = RECONNECT_DELAY_MS
+            .checked_shl(self.attempts)
+            .unwrap_or(MAX_RECONNECT_DELAY_MS);
+        self.attempts += 1;
+        Some(Duration::from_millis(cmp::min(delay, MAX_RECONNECT_DELAY_MS)))
+    }
+
+    fn reset(&mut self) {
+        self.attempts = 0;
+    }
+}
+
+/// Print the log from line `next` onwards until the stream ends. Returns whether the log was
+/// complete. A stream refused because the API is serving too many streams counts as one that
+/// ended early.
+fn follow(
+    ui: &mut UI,
+    client: &ApiClient,
+    job_id: u64,
+    color: bool,
+    next: &mut u64,
+) -> Result<bool> {
+    let path = format!("jobs/{}/log/stream", job_id);
+    let start = next.to_string();
+    let res = try!(client
+        .get_with_custom_url(&path, |url| {
+            url.query_pairs_mut()
+                .append_pair("start", &start)
+                .append_pair("color", if color { "true" } else { "false" });
+        })
+        .send());
+    if res.status == StatusCode::ServiceUnavailable {
+        return Ok(false);
+    }
+    if res.status != StatusCode::Ok {
+        return Err(Error::JobLogUnavailable((job_id, res.status)));
+    }
+    let mut reader = BufReader::new(res);
+    loop {
+        match try!(read_event(&mut reader)) {
+            Some(Event::Line(id, line)) => {
+                try!(writeln!(ui.out(), "{}", line));
+                *next = id.map(|id| id + 1).unwrap_or(*next + 1);
+            }
+            Some(Event::Complete) => return Ok(true),
+            None => return Ok(false),
+        }
+    }
+}
+
+/// Read the next event from the stream, skipping comments and fields that aren't used. Returns
+/// `None` once the stream has ended.
+fn read_event<R: BufRead>(reader: &mut R) -> Result<Option<Event>> {
+    let mut id = None;
+    let mut name = String::new();
+    let mut data: Option<String> = None;
+    let mut buf = String::new();
+    loop {
+        buf.clear();
+        if try!(reader.read_line(&mut buf)) == 0 {
+            return Ok(None);
+        }
+        let line = buf.trim_right_matches(|c: char| c == '\n' || c == '\r');
+        if line.is_empty() {
+            match data.take() {
+                Some(_) if name == "complete" => return Ok(Some(Event::Complete)),
+                Some(data) => return Ok(Some(Event::Line(id, data))),
+                // A keep-alive, or an event without any data
+                None => {
+                    id = None;
+                    name.clear();
+                    continue;
+                }
+            }
+        }
+        if line.starts_with(':') {
+            continue;
+        }
+        let (field, value) = match line.find(':') {
+            Some(idx) => {
+                let value = &line[idx + 1..];
+                (
+                    &line[..idx],
+                    if value.starts_with(' ') {
+                        &value[1..]
+                    } else {
+                        value
+                    },
+                )
+            }
+            None => (line, ""),
+        };
+        match field {
+            "id" => id = value.parse::<u64>().ok(),
+            "event" => name = value.to_string(),
+            "data" => {
+                data = Some(match data.take() {
+                    Some(prev) => format!("{}\n{}", prev, value),
+                    None => value.to_string(),
+                })
+            }
+            _ => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn read_events_from_stream() {
+        let mut stream = Cursor::new(
+            "id: 4\ndata: » Building core/nginx\n\n\
+             : keep-alive\n\n\
+             id: 5\ndata: \n\n\
+             id: 6\ndata:done\r\n\r\n\
+             event: complete\ndata: 7\n\n",
+        );
+        assert_eq!(
+            read_event(&mut stream).unwrap(),
+            Some(Event::Line(Some(4), "» Building core/nginx".to_string()))
+        );
+        assert_eq!(
+            read_event(&mut stream).unwrap(),
+            Some(Event::Line(Some(5), "".to_string()))
+        );
+        assert_eq!(
+            read_event(&mut stream).unwrap(),
+            Some(Event::Line(Some(6), "done".to_string()))
+        );
+        assert_eq!(read_event(&mut stream).unwrap(), Some(Event::Complete));
+        assert_eq!(read_event(&mut stream).unwrap(), None);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_limit() {
+        let mut backoff = Backoff::default();
+        let delays: Vec<u64> = (0..MAX_STALLED_RECONNECTS)
+            .map(|_| {
+                let delay = backoff.next_delay().unwrap();
+                delay.as_secs() * 1_000 + (delay.subsec_nanos() / 1_000_000) as u64
+            })
+            .collect();
+        assert_eq!(delays, vec![1_000, 2_000, 4_000, 8_000, 16_000]);
+        assert_eq!(backoff.next_delay(), None);
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), Some(Duration::from_millis(1_000)));
+    }
+
+    #[test]
+    fn read_event_from_truncated_stream() {
+        let mut stream = Cursor::new("id: 4\ndata: » Building");
+        assert_eq!(read_event(&mut stream).unwrap(), None);
+    }
+}
@@ -14,6 +14,7 @@
 
 pub mod butterfly;
 pub mod cli;
+pub mod job;
 pub mod pkg;
 pub mod plan;
 pub mod origin;
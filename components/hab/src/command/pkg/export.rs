@@ -51,7 +51,7 @@ mod inner {
     use common::command::package::install;
     use common::ui::{Status, UI};
     use hcore::fs::{cache_artifact_path, FS_ROOT_PATH};
-    use hcore::package::{PackageIdent, PackageInstall};
+    use hcore::package::{PackageIdent, PackageInstall, PackageTarget};
     use hcore::url::default_depot_url;
 
     use {PRODUCT, VERSION};
@@ -111,6 +111,7 @@ mod inner {
                     VERSION,
                     Path::new(&*FS_ROOT_PATH),
                     &cache_artifact_path(None),
+                    &PackageTarget::default(),
                     false,
                 ));
             }
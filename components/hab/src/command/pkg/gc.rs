@@ -0,0 +1,327 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Garbage collects the package path and the artifact cache.
+//!
+//! Only the newest releases of every package are kept, along with any release a loaded service,
+//! a running Supervisor or this very `hab` runs on, or a kept package depends on. Cached
+//! artifacts are pruned, oldest first, until the cache fits within the given size and age limits.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use common::ui::{Status, UI};
+use hcore::fs as hfs;
+use hcore::package::{PackageIdent, PackageInstall};
+use walkdir::WalkDir;
+
+use super::uninstall::{packages_in_use, remove_empty_parents};
+use error::{Error, Result};
+
+const ARTIFACT_EXT: &'static str = "hart";
+
+/// An artifact in the artifact cache.
+#[derive(Clone, Debug, PartialEq)]
+struct CachedArtifact {
+    path: PathBuf,
+    size: u64,
+    age: Duration,
+}
+
+pub fn start(
+    ui: &mut UI,
+    fs_root_path: &Path,
+    keep_latest: usize,
+    cache_max_size: Option<u64>,
+    cache_max_age: Option<Duration>,
+    dry_run: bool,
+) -> Result<()> {
+    if keep_latest == 0 {
+        return Err(Error::ArgumentError(
+            "At least the newest release of every package must be kept",
+        ));
+    }
+    try!(ui.begin(format!(
+        "{}Collecting garbage",
+        if dry_run { "[dry run] " } else { "" }
+    )));
+
+    let mut installed = HashMap::new();
+    for package in try!(PackageInstall::installed_packages(Some(fs_root_path))) {
+        let tdeps = try!(package.tdeps());
+        installed.insert(package.ident().clone(), tdeps);
+    }
+    let in_use = try!(packages_in_use(fs_root_path));
+    let releases = releases_to_remove(&installed, &in_use, keep_latest);
+    let mut release_bytes = 0;
+    for ident in releases.iter() {
+        let install_path = hfs::pkg_install_path(ident, Some(fs_root_path));
+        release_bytes += dir_size(&install_path);
+        if dry_run {
+            try!(ui.status(Status::Custom('☛', "Would remove".to_string()), ident));
+            continue;
+        }
+        try!(ui.status(Status::Deleting, ident));
+        try!(fs::remove_dir_all(&install_path));
+        try!(remove_empty_parents(&install_path, fs_root_path));
+    }
+
+    let mut artifacts = Vec::new();
+    if cache_max_size.is_some() || cache_max_age.is_some() {
+        let cached = try!(cached_artifacts(&hfs::cache_artifact_path(Some(fs_root_path))));
+        artifacts = artifacts_to_remove(&cached, cache_max_size, cache_max_age);
+    }
+    let mut artifact_bytes = 0;
+    for artifact in artifacts.iter() {
+        artifact_bytes += artifact.size;
+        if dry_run {
+            try!(ui.status(
+                Status::Custom('☛', "Would remove".to_string()),
+                artifact.path.display(),
+            ));
+            continue;
+        }
+        try!(ui.status(Status::Deleting, artifact.path.display()));
+        try!(fs::remove_file(&artifact.path));
+    }
+
+    try!(ui.status(
+        Status::Custom('Ω', "Releases".to_string()),
+        format!("{} removed, {}", releases.len(), human_size(release_bytes)),
+    ));
+    try!(ui.status(
+        Status::Custom('Ω', "Artifacts".to_string()),
+        format!("{} removed, {}", artifacts.len(), human_size(artifact_bytes)),
+    ));
+    try!(ui.end(format!(
+        "{} {}",
+        if dry_run { "Would reclaim" } else { "Reclaimed" },
+        human_size(release_bytes + artifact_bytes)
+    )));
+    Ok(())
+}
+
+/// Returns the releases garbage collection removes, sorted.
+///
+/// `installed` maps every installed package to its transitive dependencies, and `in_use` holds
+/// the packages which loaded services run on. The `keep_latest` newest releases of every package
+/// are kept, as is anything in `in_use` and every transitive dependency of a kept release.
+fn releases_to_remove(
+    installed: &HashMap<PackageIdent, Vec<PackageIdent>>,
+    in_use: &HashSet<PackageIdent>,
+    keep_latest: usize,
+) -> Vec<PackageIdent> {
+    let mut releases: HashMap<(&str, &str), Vec<&PackageIdent>> = HashMap::new();
+    for ident in installed.keys() {
+        releases
+            .entry((ident.origin.as_str(), ident.name.as_str()))
+            .or_insert(Vec::new())
+            .push(ident);
+    }
+    let mut keep: HashSet<&PackageIdent> = in_use.iter().collect();
+    for idents in releases.values_mut() {
+        idents.sort_by(|a, b| b.cmp(a));
+        keep.extend(idents.iter().take(keep_latest).cloned());
+    }
+    // Transitive dependencies are already complete, so a single pass keeps everything a kept
+    // release needs.
+    let kept: Vec<&PackageIdent> = keep.iter().cloned().collect();
+    for ident in kept {
+        if let Some(tdeps) = installed.get(ident) {
+            keep.extend(tdeps.iter());
+        }
+    }
+    let mut removals: Vec<PackageIdent> = installed
+        .keys()
+        .filter(|ident| !keep.contains(ident))
+        .cloned()
+        .collect();
+    removals.sort_by(|a, b| a.to_string().cmp(&b.to_string()));
+    removals
+}
+
+/// Returns the artifacts to remove so that the cache holds no artifact older than `max_age` and
+/// no more than `max_size` bytes. Newer artifacts are preferred over older ones.
+fn artifacts_to_remove(
+    artifacts: &[CachedArtifact],
+    max_size: Option<u64>,
+    max_age: Option<Duration>,
+) -> Vec<CachedArtifact> {
+    let mut artifacts = artifacts.to_vec();
+    artifacts.sort_by(|a, b| a.age.cmp(&b.age));
+    let mut kept_size = 0;
+    let mut removals = Vec::new();
+    for artifact in artifacts {
+        let too_old = max_age.map_or(false, |max_age| artifact.age > max_age);
+        let too_big = max_size.map_or(false, |max_size| kept_size + artifact.size > max_size);
+        if too_old || too_big {
+            removals.push(artifact);
+        } else {
+            kept_size += artifact.size;
+        }
+    }
+    removals
+}
+
+/// Returns every artifact in the artifact cache at `cache_path`.
+fn cached_artifacts(cache_path: &Path) -> Result<Vec<CachedArtifact>> {
+    let mut artifacts = Vec::new();
+    if !cache_path.is_dir() {
+        return Ok(artifacts);
+    }
+    let now = SystemTime::now();
+    for entry in try!(fs::read_dir(cache_path)) {
+        let entry = try!(entry);
+        let path = entry.path();
+        if !path.extension().map_or(false, |ext| ext == ARTIFACT_EXT) {
+            continue;
+        }
+        let metadata = try!(entry.metadata());
+        if !metadata.is_file() {
+            continue;
+        }
+        let age = match metadata.modified() {
+            Ok(modified) => now.duration_since(modified).unwrap_or(Duration::from_secs(0)),
+            Err(_) => Duration::from_secs(0),
+        };
+        artifacts.push(CachedArtifact {
+            path: path,
+            size: metadata.len(),
+            age: age,
+        });
+    }
+    Ok(artifacts)
+}
+
+/// Returns the total size of the files under `path`.
+fn dir_size(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.metadata().ok())
+        .filter(|m| m.is_file())
+        .map(|m| m.len())
+        .sum()
+}
+
+fn human_size(bytes: u64) -> String {
+    const UNITS: [&'static str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use hcore::package::PackageIdent;
+
+    use command::pkg::test_support::{ident, installed_releases};
+    use super::{artifacts_to_remove, human_size, releases_to_remove, CachedArtifact};
+
+    fn installed() -> HashMap<PackageIdent, Vec<PackageIdent>> {
+        installed_releases(
+            &[
+                ("core/glibc/2.22/20170513201042", &[]),
+                ("core/glibc/2.25/20170601000000", &[]),
+                ("core/redis/3.2.4/20170514150022", &["core/glibc/2.22/20170513201042"]),
+                ("core/redis/3.2.4/20170601120000", &["core/glibc/2.25/20170601000000"]),
+                ("core/redis/3.2.9/20170701120000", &["core/glibc/2.25/20170601000000"]),
+            ],
+        )
+    }
+
+    fn artifact(name: &str, size: u64, age_secs: u64) -> CachedArtifact {
+        CachedArtifact {
+            path: PathBuf::from(name),
+            size: size,
+            age: Duration::from_secs(age_secs),
+        }
+    }
+
+    #[test]
+    fn keeps_the_newest_releases_of_every_package() {
+        let removals = releases_to_remove(&installed(), &HashSet::new(), 1);
+        assert_eq!(
+            removals,
+            vec![
+                ident("core/glibc/2.22/20170513201042"),
+                ident("core/redis/3.2.4/20170514150022"),
+                ident("core/redis/3.2.4/20170601120000"),
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_the_dependencies_of_kept_releases() {
+        let mut installed = installed();
+        installed.insert(
+            ident("core/redis/3.2.9/20170701120000"),
+            vec![ident("core/glibc/2.22/20170513201042")],
+        );
+        let removals = releases_to_remove(&installed, &HashSet::new(), 1);
+        assert_eq!(
+            removals,
+            vec![
+                ident("core/redis/3.2.4/20170514150022"),
+                ident("core/redis/3.2.4/20170601120000"),
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_releases_used_by_a_service() {
+        let mut in_use = HashSet::new();
+        in_use.insert(ident("core/redis/3.2.4/20170514150022"));
+        let removals = releases_to_remove(&installed(), &in_use, 1);
+        assert_eq!(removals, vec![ident("core/redis/3.2.4/20170601120000")]);
+    }
+
+    #[test]
+    fn removes_artifacts_older_than_the_max_age() {
+        let artifacts = vec![artifact("new.hart", 10, 60), artifact("old.hart", 10, 7200)];
+        let removals = artifacts_to_remove(&artifacts, None, Some(Duration::from_secs(3600)));
+        assert_eq!(removals, vec![artifact("old.hart", 10, 7200)]);
+    }
+
+    #[test]
+    fn removes_the_oldest_artifacts_over_the_max_size() {
+        let artifacts = vec![
+            artifact("oldest.hart", 10, 300),
+            artifact("newest.hart", 10, 100),
+            artifact("older.hart", 10, 200),
+        ];
+        let removals = artifacts_to_remove(&artifacts, Some(25), None);
+        assert_eq!(removals, vec![artifact("oldest.hart", 10, 300)]);
+    }
+
+    #[test]
+    fn human_size_picks_a_readable_unit() {
+        assert_eq!(human_size(512), "512 B");
+        assert_eq!(human_size(1536), "1.5 KB");
+        assert_eq!(human_size(3 * 1024 * 1024 * 1024), "3.0 GB");
+    }
+}
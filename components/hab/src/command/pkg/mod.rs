@@ -17,13 +17,18 @@ pub mod build;
 pub mod env;
 pub mod exec;
 pub mod export;
+pub mod gc;
 pub mod hash;
 pub mod header;
 pub mod path;
 pub mod provides;
 pub mod search;
 pub mod sign;
+pub mod uninstall;
 pub mod upload;
 pub mod verify;
 pub mod promote;
 pub mod demote;
+
+#[cfg(test)]
+mod test_support;
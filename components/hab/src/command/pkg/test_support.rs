@@ -0,0 +1,37 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fixtures shared by the package command tests.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use hcore::package::PackageIdent;
+
+pub fn ident(s: &str) -> PackageIdent {
+    PackageIdent::from_str(s).unwrap()
+}
+
+/// Builds a map of installed releases to their dependencies from `(release, dependencies)`
+/// pairs.
+pub fn installed_releases(
+    releases: &[(&str, &[&str])],
+) -> HashMap<PackageIdent, Vec<PackageIdent>> {
+    releases
+        .iter()
+        .map(|&(release, deps)| {
+            (ident(release), deps.iter().map(|dep| ident(dep)).collect())
+        })
+        .collect()
+}
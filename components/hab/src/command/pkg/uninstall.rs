@@ -0,0 +1,437 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Uninstalls a package release from the package path.
+//!
+//! A release is never removed while another installed package, a service loaded into a
+//! Supervisor, a running Supervisor or this very `hab` still depends on it. Optionally, the
+//! transitive dependencies of the release which nothing else needs any more are removed along
+//! with it.
+
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use common::ui::{Status, UI};
+use hcore::fs as hfs;
+use hcore::package::{PackageIdent, PackageInstall};
+use serde_json;
+use toml;
+
+use error::{Error, Result};
+
+/// The directory holding the state of every Supervisor, relative to the filesystem root.
+const SUP_STATE_PATH: &'static str = "hab/sup";
+const SPEC_FILE_EXT: &'static str = "spec";
+/// The file holding the process id of a running Supervisor, relative to its state directory.
+const SUP_LOCK_FILE: &'static str = "LOCK";
+/// The file in which a Supervisor records the state of its services, relative to its state
+/// directory.
+const SUP_SERVICES_FILE: &'static str = "data/services.dat";
+
+/// The only part of a Supervisor's service spec file we care about.
+#[derive(Deserialize)]
+struct ServiceSpec {
+    ident: String,
+}
+
+/// The only part of the state a Supervisor records for each of its services we care about.
+#[derive(Deserialize)]
+struct ServiceState {
+    pkg: ServiceStatePkg,
+}
+
+#[derive(Deserialize)]
+struct ServiceStatePkg {
+    ident: String,
+}
+
+pub fn start(
+    ui: &mut UI,
+    ident: &PackageIdent,
+    fs_root_path: &Path,
+    remove_orphans: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let pkg_install = try!(PackageInstall::load(ident, Some(fs_root_path)));
+    let target = pkg_install.ident().clone();
+    try!(ui.begin(format!(
+        "{}Uninstalling {}",
+        if dry_run { "[dry run] " } else { "" },
+        &target
+    )));
+
+    let mut installed = HashMap::new();
+    for package in try!(PackageInstall::installed_packages(Some(fs_root_path))) {
+        let tdeps = try!(package.tdeps());
+        installed.insert(package.ident().clone(), tdeps);
+    }
+    let in_use = try!(packages_in_use(fs_root_path));
+    let removals = try!(removal_order(&target, &installed, &in_use, remove_orphans));
+
+    for ident in removals.iter() {
+        let install_path = hfs::pkg_install_path(ident, Some(fs_root_path));
+        if dry_run {
+            try!(ui.status(Status::Custom('☛', "Would remove".to_string()), ident));
+            continue;
+        }
+        try!(ui.status(Status::Deleting, ident));
+        try!(fs::remove_dir_all(&install_path));
+        try!(remove_empty_parents(&install_path, fs_root_path));
+    }
+
+    try!(ui.end(format!(
+        "{} {} package{}",
+        if dry_run { "Would uninstall" } else { "Uninstalled" },
+        removals.len(),
+        if removals.len() == 1 { "" } else { "s" }
+    )));
+    Ok(())
+}
+
+/// Returns the packages to remove in order to uninstall `target`, starting with `target` itself.
+///
+/// `installed` maps every installed package to its transitive dependencies, and `in_use` holds
+/// the packages which loaded services run on. Fails if anything still depends on `target`. When
+/// `remove_orphans` is set, the transitive dependencies of `target` which nothing else depends on
+/// are removed as well.
+fn removal_order(
+    target: &PackageIdent,
+    installed: &HashMap<PackageIdent, Vec<PackageIdent>>,
+    in_use: &HashSet<PackageIdent>,
+    remove_orphans: bool,
+) -> Result<Vec<PackageIdent>> {
+    let mut dependents: Vec<String> = installed
+        .iter()
+        .filter(|&(ident, tdeps)| ident != target && tdeps.contains(target))
+        .map(|(ident, _)| ident.to_string())
+        .collect();
+    if in_use.contains(target) {
+        dependents.push(String::from("a service loaded in a Supervisor"));
+    }
+    if !dependents.is_empty() {
+        dependents.sort();
+        return Err(Error::PackageInUse((target.to_string(), dependents)));
+    }
+
+    let mut removals = vec![target.clone()];
+    if !remove_orphans {
+        return Ok(removals);
+    }
+    let mut orphans: HashSet<&PackageIdent> = match installed.get(target) {
+        Some(tdeps) => {
+            tdeps
+                .iter()
+                .filter(|&dep| installed.contains_key(dep) && !in_use.contains(dep))
+                .collect()
+        }
+        None => HashSet::new(),
+    };
+    // A dependency is only an orphan if no package we keep depends on it. Keeping one dependency
+    // can keep others, so prune until nothing changes.
+    loop {
+        let needed: Vec<&PackageIdent> = orphans
+            .iter()
+            .filter(|&&dep| {
+                installed.iter().any(|(ident, tdeps)| {
+                    ident != target && !orphans.contains(ident) && tdeps.contains(dep)
+                })
+            })
+            .map(|&dep| dep)
+            .collect();
+        if needed.is_empty() {
+            break;
+        }
+        for dep in needed {
+            orphans.remove(dep);
+        }
+    }
+    let mut orphans: Vec<PackageIdent> = orphans.into_iter().cloned().collect();
+    orphans.sort();
+    removals.extend(orphans);
+    Ok(removals)
+}
+
+/// Returns the packages, along with their transitive dependencies, which must not be removed
+/// because something runs on them: the services loaded into any Supervisor on this system, the
+/// running Supervisors and this very `hab`.
+pub fn packages_in_use(fs_root_path: &Path) -> Result<HashSet<PackageIdent>> {
+    let mut packages = try!(loaded_service_packages(fs_root_path));
+    packages.extend(try!(running_packages(fs_root_path)));
+    Ok(packages)
+}
+
+/// Returns the packages, along with their transitive dependencies, which the services loaded into
+/// any Supervisor on this system run on.
+///
+/// A service runs on the release its Supervisor loaded, which is recorded in the Supervisor's
+/// services state and may well be older than the latest release installed since. Once restarted,
+/// it runs on the latest installed release satisfying its spec's identifier, so both are kept.
+fn loaded_service_packages(fs_root_path: &Path) -> Result<HashSet<PackageIdent>> {
+    let mut idents = Vec::new();
+    let sup_state_path = fs_root_path.join(SUP_STATE_PATH);
+    for spec_path in try!(spec_files(&sup_state_path)) {
+        let mut body = String::new();
+        try!(try!(File::open(&spec_path)).read_to_string(&mut body));
+        let spec: ServiceSpec = try!(toml::from_str(&body));
+        let ident = try!(PackageIdent::from_str(&spec.ident));
+        if let Ok(pkg_install) = PackageInstall::load(&ident, Some(fs_root_path)) {
+            idents.push(pkg_install.ident().clone());
+        }
+    }
+    for services_path in try!(sup_files(&sup_state_path, SUP_SERVICES_FILE)) {
+        let mut body = String::new();
+        try!(try!(File::open(&services_path)).read_to_string(&mut body));
+        idents.extend(try!(service_releases(&body)));
+    }
+    let mut packages = HashSet::new();
+    for ident in idents {
+        if let Ok(pkg_install) = PackageInstall::load(&ident, Some(fs_root_path)) {
+            packages.extend(try!(pkg_install.tdeps()));
+        }
+        packages.insert(ident);
+    }
+    Ok(packages)
+}
+
+/// Returns the releases the services in a Supervisor's services state run on.
+fn service_releases(body: &str) -> Result<Vec<PackageIdent>> {
+    let services: Vec<ServiceState> = try!(serde_json::from_str(body));
+    let mut idents = Vec::new();
+    for service in services {
+        idents.push(try!(PackageIdent::from_str(&service.pkg.ident)));
+    }
+    Ok(idents)
+}
+
+/// Returns the packages, along with their transitive dependencies, which the running Supervisors
+/// and this very `hab` run on.
+fn running_packages(fs_root_path: &Path) -> Result<HashSet<PackageIdent>> {
+    let mut binaries = Vec::new();
+    if let Ok(exe) = env::current_exe() {
+        binaries.push(exe);
+    }
+    for lock_path in try!(sup_files(&fs_root_path.join(SUP_STATE_PATH), SUP_LOCK_FILE)) {
+        if let Some(exe) = supervisor_binary(&lock_path) {
+            binaries.push(exe);
+        }
+    }
+    let mut packages = HashSet::new();
+    for binary in binaries {
+        if let Some(ident) = release_of_binary(&binary, fs_root_path) {
+            if let Ok(pkg_install) = PackageInstall::load(&ident, Some(fs_root_path)) {
+                packages.extend(try!(pkg_install.tdeps()));
+            }
+            packages.insert(ident);
+        }
+    }
+    Ok(packages)
+}
+
+/// Returns the binary the Supervisor whose process id is in the lock file at `lock_path` runs.
+/// Only Linux tells which binary a process runs, and a Supervisor which isn't running has no lock
+/// file, so this is often `None`.
+fn supervisor_binary(lock_path: &Path) -> Option<PathBuf> {
+    let mut pid = String::new();
+    match File::open(lock_path).and_then(|mut f| f.read_to_string(&mut pid)) {
+        Ok(_) => fs::read_link(format!("/proc/{}/exe", pid.trim())).ok(),
+        Err(_) => None,
+    }
+}
+
+/// Returns the release a binary belongs to, if it is installed in the package path.
+fn release_of_binary(binary: &Path, fs_root_path: &Path) -> Option<PackageIdent> {
+    let pkg_root_path = hfs::pkg_root_path(Some(fs_root_path));
+    let parts: Vec<String> = match binary.strip_prefix(&pkg_root_path) {
+        Ok(path) => {
+            path.components()
+                .take(4)
+                .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                .collect()
+        }
+        Err(_) => return None,
+    };
+    if parts.len() < 4 {
+        return None;
+    }
+    Some(PackageIdent::new(
+        parts[0].clone(),
+        parts[1].clone(),
+        Some(parts[2].clone()),
+        Some(parts[3].clone()),
+    ))
+}
+
+/// Returns the path of the file at `path` in the state directory of every Supervisor under
+/// `sup_state_path` which has it.
+fn sup_files(sup_state_path: &Path, path: &str) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    if !sup_state_path.is_dir() {
+        return Ok(files);
+    }
+    for sup_dir in try!(fs::read_dir(sup_state_path)) {
+        let file_path = try!(sup_dir).path().join(path);
+        if file_path.is_file() {
+            files.push(file_path);
+        }
+    }
+    Ok(files)
+}
+
+/// Returns the path of every spec file of every Supervisor under `sup_state_path`.
+fn spec_files(sup_state_path: &Path) -> Result<Vec<PathBuf>> {
+    let mut spec_files = Vec::new();
+    if !sup_state_path.is_dir() {
+        return Ok(spec_files);
+    }
+    for sup_dir in try!(fs::read_dir(sup_state_path)) {
+        let specs_path = try!(sup_dir).path().join("specs");
+        if !specs_path.is_dir() {
+            continue;
+        }
+        for entry in try!(fs::read_dir(&specs_path)) {
+            let path = try!(entry).path();
+            if path.extension().map_or(false, |ext| ext == SPEC_FILE_EXT) {
+                spec_files.push(path);
+            }
+        }
+    }
+    Ok(spec_files)
+}
+
+/// Removes the version and name directories of an uninstalled release once they are empty.
+pub fn remove_empty_parents(install_path: &Path, fs_root_path: &Path) -> Result<()> {
+    let pkg_root_path = hfs::pkg_root_path(Some(fs_root_path));
+    let mut path = install_path.parent();
+    while let Some(dir) = path {
+        if dir == pkg_root_path || try!(fs::read_dir(dir)).next().is_some() {
+            break;
+        }
+        try!(fs::remove_dir(dir));
+        path = dir.parent();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use std::path::Path;
+
+    use hcore::fs as hfs;
+    use hcore::package::PackageIdent;
+
+    use command::pkg::test_support::{ident, installed_releases};
+    use super::{release_of_binary, removal_order, service_releases};
+    use error::Error;
+
+    fn installed() -> HashMap<PackageIdent, Vec<PackageIdent>> {
+        installed_releases(
+            &[
+                ("core/glibc/2.22/20170513201042", &[]),
+                ("core/openssl/1.0.2l/20170513215106", &["core/glibc/2.22/20170513201042"]),
+                (
+                    "core/redis/3.2.4/20170514150022",
+                    &[
+                        "core/glibc/2.22/20170513201042",
+                        "core/openssl/1.0.2l/20170513215106",
+                    ],
+                ),
+                ("core/curl/7.54.0/20170513215502", &["core/glibc/2.22/20170513201042"]),
+            ],
+        )
+    }
+
+    #[test]
+    fn refuses_to_remove_a_dependency_of_another_package() {
+        let target = ident("core/openssl/1.0.2l/20170513215106");
+        match removal_order(&target, &installed(), &HashSet::new(), false) {
+            Err(Error::PackageInUse((ref pkg, ref dependents))) => {
+                assert_eq!(pkg, "core/openssl/1.0.2l/20170513215106");
+                assert_eq!(dependents, &vec!["core/redis/3.2.4/20170514150022".to_string()]);
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn refuses_to_remove_a_package_used_by_a_service() {
+        let target = ident("core/redis/3.2.4/20170514150022");
+        let mut in_use = HashSet::new();
+        in_use.insert(target.clone());
+        assert!(removal_order(&target, &installed(), &in_use, false).is_err());
+    }
+
+    #[test]
+    fn removes_only_the_target_by_default() {
+        let target = ident("core/redis/3.2.4/20170514150022");
+        let removals = removal_order(&target, &installed(), &HashSet::new(), false).unwrap();
+        assert_eq!(removals, vec![target]);
+    }
+
+    #[test]
+    fn removes_orphaned_dependencies() {
+        let target = ident("core/redis/3.2.4/20170514150022");
+        let removals = removal_order(&target, &installed(), &HashSet::new(), true).unwrap();
+        // glibc is still needed by curl
+        assert_eq!(
+            removals,
+            vec![target, ident("core/openssl/1.0.2l/20170513215106")]
+        );
+    }
+
+    #[test]
+    fn reads_the_releases_services_run_on() {
+        let body = r#"[
+            {"pkg": {"ident": "core/redis/3.2.4/20170514150022", "name": "redis"},
+             "service_group": "redis.default"},
+            {"pkg": {"ident": "core/nginx/1.11.10/20170513215502", "name": "nginx"}}
+        ]"#;
+        assert_eq!(
+            service_releases(body).unwrap(),
+            vec![
+                ident("core/redis/3.2.4/20170514150022"),
+                ident("core/nginx/1.11.10/20170513215502"),
+            ]
+        );
+        assert!(service_releases("[]").unwrap().is_empty());
+    }
+
+    #[test]
+    fn finds_the_release_of_an_installed_binary() {
+        let fs_root_path = Path::new("/");
+        let binary = hfs::pkg_root_path(Some(fs_root_path))
+            .join("core/hab-sup/0.28.0/20170729003209/bin/hab-sup");
+        assert_eq!(
+            release_of_binary(&binary, fs_root_path),
+            Some(ident("core/hab-sup/0.28.0/20170729003209"))
+        );
+        assert_eq!(
+            release_of_binary(Path::new("/usr/local/bin/hab"), fs_root_path),
+            None
+        );
+    }
+
+    #[test]
+    fn keeps_orphaned_dependencies_used_by_a_service() {
+        let target = ident("core/redis/3.2.4/20170514150022");
+        let mut in_use = HashSet::new();
+        in_use.insert(ident("core/openssl/1.0.2l/20170513215106"));
+        let removals = removal_order(&target, &installed(), &in_use, true).unwrap();
+        assert_eq!(removals, vec![target]);
+    }
+}
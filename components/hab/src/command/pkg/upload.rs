@@ -36,7 +36,7 @@ use common::command::package::install::{RETRIES, RETRY_WAIT};
 use depot_client::{self, Client};
 use hcore::crypto::artifact::get_artifact_header;
 use hcore::crypto::keys::parse_name_with_rev;
-use hcore::package::{PackageArchive, PackageIdent};
+use hcore::package::{PackageArchive, PackageIdent, PackageTarget};
 use hyper::status::StatusCode;
 
 use {PRODUCT, VERSION};
@@ -103,14 +103,15 @@ pub fn start<P: AsRef<Path>>(
     ));
     let tdeps = try!(archive.tdeps());
     let ident = try!(archive.ident());
-    match depot_client.show_package(&ident, None) {
+    let target = try!(archive.target());
+    match depot_client.show_package(&ident, None, Some(&target)) {
         Ok(_) => {
             try!(ui.status(Status::Using, format!("existing {}", &ident)));
             Ok(())
         }
         Err(depot_client::Error::APIError(StatusCode::NotFound, _)) => {
             for dep in tdeps.into_iter() {
-                match depot_client.show_package(&dep, None) {
+                match depot_client.show_package(&dep, None, Some(&target)) {
                     Ok(_) => try!(ui.status(Status::Using, format!("existing {}", &dep))),
                     Err(depot_client::Error::APIError(StatusCode::NotFound, _)) => {
                         let candidate_path = match archive_path.as_ref().parent() {
@@ -126,6 +127,7 @@ pub fn start<P: AsRef<Path>>(
                                     &depot_client,
                                     token,
                                     &dep,
+                                    &target,
                                     channel,
                                     &candidate_path,
                                 )
@@ -242,10 +244,11 @@ fn attempt_upload_dep(
     depot_client: &Client,
     token: &str,
     ident: &PackageIdent,
+    target: &PackageTarget,
     channel: Option<&str>,
     archives_dir: &PathBuf,
 ) -> Result<()> {
-    let candidate_path = archives_dir.join(ident.archive_name().unwrap());
+    let candidate_path = archives_dir.join(ident.archive_name_with_target(target).unwrap());
     if candidate_path.is_file() {
         let mut archive = PackageArchive::new(candidate_path);
         upload_into_depot(ui, &depot_client, token, &ident, channel, &mut archive)
@@ -254,7 +257,7 @@ fn attempt_upload_dep(
             Status::Missing,
             format!(
                 "artifact for {} was not found in {}",
-                ident.archive_name().unwrap(),
+                ident.archive_name_with_target(target).unwrap(),
                 archives_dir.display()
             ),
         ));
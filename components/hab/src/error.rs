@@ -23,6 +23,9 @@ use depot_client;
 use common;
 use hcore;
 use handlebars;
+use http_client;
+use hyper;
+use serde_json;
 use toml;
 
 pub type Result<T> = result::Result<T, Error>;
@@ -46,8 +49,14 @@ pub enum Error {
     HabitatCommon(common::Error),
     HabitatCore(hcore::Error),
     HandlebarsRenderError(handlebars::TemplateRenderError),
+    HttpClient(http_client::Error),
+    HyperError(hyper::error::Error),
     IO(io::Error),
+    JsonDeserializeError(serde_json::Error),
+    JobLogStalled((u64, u32)),
+    JobLogUnavailable((u64, hyper::status::StatusCode)),
     PackageArchiveMalformed(String),
+    PackageInUse((String, Vec<String>)),
     PathPrefixError(path::StripPrefixError),
     ProvidesError(String),
     RootRequired,
@@ -118,13 +127,33 @@ impl fmt::Display for Error {
             Error::HabitatCommon(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HandlebarsRenderError(ref e) => format!("{}", e),
+            Error::HttpClient(ref e) => format!("{}", e),
+            Error::HyperError(ref e) => format!("{}", e),
             Error::IO(ref err) => format!("{}", err),
+            Error::JsonDeserializeError(ref e) => format!("Can't deserialize JSON: {}", e),
+            Error::JobLogStalled((ref id, ref attempts)) => {
+                format!(
+                    "Gave up following the log of job {} after {} reconnects without new lines",
+                    id,
+                    attempts
+                )
+            }
+            Error::JobLogUnavailable((ref id, ref status)) => {
+                format!("Unable to stream the log of job {}: {}", id, status)
+            }
             Error::PackageArchiveMalformed(ref e) => {
                 format!(
                     "Package archive was unreadable or contained unexpected contents: {:?}",
                     e
                 )
             }
+            Error::PackageInUse((ref p, ref dependents)) => {
+                format!(
+                    "{} cannot be uninstalled; it is still required by {}",
+                    p,
+                    dependents.join(", ")
+                )
+            }
             Error::PathPrefixError(ref err) => format!("{}", err),
             Error::ProvidesError(ref err) => format!("Can't find {}", err),
             Error::RootRequired => {
@@ -165,10 +194,16 @@ impl error::Error for Error {
             Error::HabitatCommon(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
             Error::HandlebarsRenderError(ref err) => err.description(),
+            Error::HttpClient(ref err) => err.description(),
+            Error::HyperError(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::JsonDeserializeError(_) => "Can't deserialize JSON",
+            Error::JobLogStalled(_) => "The job's log stream kept ending without new lines",
+            Error::JobLogUnavailable(_) => "The job's log could not be streamed from Builder",
             Error::PackageArchiveMalformed(_) => {
                 "Package archive was unreadable or had unexpected contents"
             }
+            Error::PackageInUse(_) => "Package is still required by other packages or services",
             Error::PathPrefixError(ref err) => err.description(),
             Error::ProvidesError(_) => {
                 "Can't find a package that provides the given search parameter"
@@ -215,12 +250,30 @@ impl From<handlebars::TemplateRenderError> for Error {
     }
 }
 
+impl From<http_client::Error> for Error {
+    fn from(err: http_client::Error) -> Error {
+        Error::HttpClient(err)
+    }
+}
+
+impl From<hyper::error::Error> for Error {
+    fn from(err: hyper::error::Error) -> Error {
+        Error::HyperError(err)
+    }
+}
+
 impl From<io::Error> for Error {
     fn from(err: io::Error) -> Error {
         Error::IO(err)
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::JsonDeserializeError(err)
+    }
+}
+
 impl From<path::StripPrefixError> for Error {
     fn from(err: path::StripPrefixError) -> Error {
         Error::PathPrefixError(err)
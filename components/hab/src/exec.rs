@@ -18,7 +18,7 @@ use common;
 use common::ui::{Status, UI};
 use hcore;
 use hcore::fs::{self, cache_artifact_path};
-use hcore::package::{PackageIdent, PackageInstall};
+use hcore::package::{PackageIdent, PackageInstall, PackageTarget};
 use hcore::url::default_depot_url;
 
 use {PRODUCT, VERSION};
@@ -71,6 +71,7 @@ pub fn command_from_min_pkg(
                 VERSION,
                 fs_root_path,
                 &cache_artifact_path(None),
+                &PackageTarget::default(),
                 false,
             ));
             command_from_min_pkg(ui, &command, &ident, &cache_key_path, retry + 1)
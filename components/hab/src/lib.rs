@@ -30,6 +30,7 @@ extern crate retry;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+extern crate serde_json;
 extern crate toml;
 extern crate url;
 extern crate uuid;
@@ -30,6 +30,7 @@ use std::io::prelude::*;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::thread;
+use std::time::Duration;
 
 use clap::{ArgMatches, Shell};
 
@@ -39,8 +40,8 @@ use hcore::crypto::{init, default_cache_key_path, SigKeyPair};
 use hcore::crypto::keys::PairType;
 use hcore::fs::{cache_artifact_path, cache_analytics_path, cache_key_path};
 use hcore::service::ServiceGroup;
-use hcore::package::PackageIdent;
-use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
+use hcore::package::{Lockfile, PackageIdent, PackageTarget};
+use hcore::url::{default_bldr_url, DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 use hcore::channel::{DEFAULT_DEPOT_CHANNEL, DEPOT_CHANNEL_ENVVAR};
 
 use hab::{analytics, cli, command, config, AUTH_TOKEN_ENVVAR, ORIGIN_ENVVAR, PRODUCT, VERSION};
@@ -49,6 +50,7 @@ use hab::error::{Error, Result};
 /// Makes the --org CLI param optional when this env var is set
 const HABITAT_ORG_ENVVAR: &'static str = "HAB_ORG";
 const DEFAULT_BINLINK_DIR: &'static str = "/bin";
+const DEFAULT_GC_KEEP_LATEST: usize = 2;
 
 lazy_static! {
     /// The default filesystem root path to base all commands from. This is lazily generated on
@@ -95,6 +97,12 @@ fn start(ui: &mut UI) -> Result<()> {
             }
         }
         ("install", Some(m)) => try!(sub_pkg_install(ui, m)),
+        ("job", Some(matches)) => {
+            match matches.subcommand() {
+                ("log", Some(m)) => try!(sub_job_log(ui, m)),
+                _ => unreachable!(),
+            }
+        }
         ("origin", Some(matches)) => {
             match matches.subcommand() {
                 ("key", Some(m)) => {
@@ -114,16 +122,19 @@ fn start(ui: &mut UI) -> Result<()> {
             match matches.subcommand() {
                 ("binlink", Some(m)) => try!(sub_pkg_binlink(ui, m)),
                 ("build", Some(m)) => try!(sub_pkg_build(ui, m)),
+                ("bundle", Some(m)) => try!(sub_pkg_bundle(ui, m)),
                 ("config", Some(m)) => try!(sub_pkg_config(m)),
                 ("env", Some(m)) => try!(sub_pkg_env(m)),
                 ("exec", Some(m)) => try!(sub_pkg_exec(m, remaining_args)),
                 ("export", Some(m)) => try!(sub_pkg_export(ui, m)),
+                ("gc", Some(m)) => try!(sub_pkg_gc(ui, m)),
                 ("hash", Some(m)) => try!(sub_pkg_hash(m)),
                 ("install", Some(m)) => try!(sub_pkg_install(ui, m)),
                 ("path", Some(m)) => try!(sub_pkg_path(m)),
                 ("provides", Some(m)) => try!(sub_pkg_provides(m)),
                 ("search", Some(m)) => try!(sub_pkg_search(m)),
                 ("sign", Some(m)) => try!(sub_pkg_sign(ui, m)),
+                ("uninstall", Some(m)) => try!(sub_pkg_uninstall(ui, m)),
                 ("upload", Some(m)) => try!(sub_pkg_upload(ui, m)),
                 ("verify", Some(m)) => try!(sub_pkg_verify(ui, m)),
                 ("header", Some(m)) => try!(sub_pkg_header(ui, m)),
@@ -197,6 +208,16 @@ fn sub_cli_completers(m: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+fn sub_job_log(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let url = m.value_of("BLDR_URL").map(String::from).unwrap_or_else(
+        default_bldr_url,
+    );
+    let job_id = m.value_of("JOB_ID").unwrap().parse::<u64>().unwrap();
+    let start = m.value_of("START").map_or(0, |s| s.parse::<u64>().unwrap());
+
+    command::job::log::start(ui, &url, job_id, start)
+}
+
 fn sub_origin_key_download(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let origin = m.value_of("ORIGIN").unwrap(); // Required via clap
     let revision = m.value_of("REVISION");
@@ -301,6 +322,32 @@ fn sub_pkg_build(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::pkg::build::start(ui, plan_context, root, src, keys, reuse)
 }
 
+fn sub_pkg_bundle(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let channel_env_or_default =
+        henv::var(DEPOT_CHANNEL_ENVVAR).unwrap_or(DEFAULT_DEPOT_CHANNEL.to_string());
+    let channel = m.value_of("CHANNEL").unwrap_or(&channel_env_or_default);
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
+    let dest_dir = Path::new(m.value_of("DEST_DIR").unwrap_or("."));
+    init();
+
+    try!(common::command::package::bundle::start(
+        ui,
+        url,
+        Some(channel),
+        &ident,
+        PRODUCT,
+        VERSION,
+        &*FS_ROOT,
+        &cache_artifact_path(Some(&*FS_ROOT)),
+        dest_dir,
+        &try!(target_param(m)),
+        false,
+    ));
+    Ok(())
+}
+
 fn sub_pkg_config(m: &ArgMatches) -> Result<()> {
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
 
@@ -328,6 +375,26 @@ fn sub_pkg_export(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::pkg::export::start(ui, &ident, &export_fmt)
 }
 
+fn sub_pkg_gc(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let keep_latest = m.value_of("KEEP_LATEST")
+        .map_or(DEFAULT_GC_KEEP_LATEST, |v| v.parse().unwrap());
+    let cache_max_size = m.value_of("CACHE_MAX_SIZE").map(|v| {
+        v.parse::<u64>().unwrap() * 1024 * 1024
+    });
+    let cache_max_age = m.value_of("CACHE_MAX_AGE").map(|v| {
+        Duration::from_secs(v.parse::<u64>().unwrap() * 24 * 60 * 60)
+    });
+
+    command::pkg::gc::start(
+        ui,
+        &*FS_ROOT,
+        keep_latest,
+        cache_max_size,
+        cache_max_age,
+        m.is_present("DRY_RUN"),
+    )
+}
+
 fn sub_pkg_hash(m: &ArgMatches) -> Result<()> {
     init();
     match m.value_of("SOURCE") {
@@ -360,34 +427,102 @@ fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let channel_env_or_default =
         henv::var(DEPOT_CHANNEL_ENVVAR).unwrap_or(DEFAULT_DEPOT_CHANNEL.to_string());
     let channel = m.value_of("CHANNEL").unwrap_or(&channel_env_or_default);
-    let ident_or_artifacts = m.values_of("PKG_IDENT_OR_ARTIFACT").unwrap(); // Required via clap
     let ignore_target = if m.is_present("IGNORE_TARGET") {
         true
     } else {
         false
     };
+    let target = try!(target_param(m));
     init();
 
-    for ident_or_artifact in ident_or_artifacts {
-        let pkg_ident = try!(common::command::package::install::start(
+    let mut pkg_idents = Vec::new();
+    match m.value_of("LOCKFILE") {
+        Some(path) => {
+            let lockfile = try!(Lockfile::from_file(path));
+            pkg_idents.push(try!(
+                common::command::package::install::start_from_lockfile(
+                    ui,
+                    url,
+                    &lockfile,
+                    PRODUCT,
+                    VERSION,
+                    &*FS_ROOT,
+                    &cache_artifact_path(Some(&*FS_ROOT)),
+                    &target,
+                    ignore_target,
+                )
+            ));
+        }
+        None => {
+            // Required via clap unless installing from a lockfile
+            let ident_or_artifacts = m.values_of("PKG_IDENT_OR_ARTIFACT").unwrap();
+            if m.is_present("WRITE_LOCKFILE") && ident_or_artifacts.len() > 1 {
+                return Err(Error::ArgumentError(
+                    "A lockfile can only be written when installing a single package",
+                ));
+            }
+            let trusted_origins: Vec<String> = m.values_of("TRUST_ORIGIN")
+                .map(|origins| origins.map(|o| o.to_string()).collect())
+                .unwrap_or(vec![]);
+            for ident_or_artifact in ident_or_artifacts {
+                pkg_idents.push(try!(common::command::package::install::start_trusting_origins(
+                    ui,
+                    url,
+                    Some(channel),
+                    ident_or_artifact,
+                    &trusted_origins,
+                    PRODUCT,
+                    VERSION,
+                    &*FS_ROOT,
+                    &cache_artifact_path(Some(&*FS_ROOT)),
+                    &target,
+                    ignore_target,
+                )));
+            }
+        }
+    }
+    if let Some(path) = m.value_of("WRITE_LOCKFILE") {
+        let lockfile = try!(common::command::package::install::lock(
             ui,
             url,
             Some(channel),
-            ident_or_artifact,
+            &pkg_idents[0],
             PRODUCT,
             VERSION,
             &*FS_ROOT,
             &cache_artifact_path(Some(&*FS_ROOT)),
+            &target,
             ignore_target,
         ));
-        if m.is_present("BINLINK") {
-            let dest_dir = Path::new(m.value_of("DEST_DIR").unwrap_or(DEFAULT_BINLINK_DIR));
-            command::pkg::binlink::binlink_all_in_pkg(ui, &pkg_ident, dest_dir, &*FS_ROOT)?;
+        try!(lockfile.to_file(path));
+        try!(ui.end(format!(
+            "Wrote lockfile for {} with {} packages to {}",
+            &lockfile.ident,
+            lockfile.packages.len(),
+            path
+        )));
+    }
+    if m.is_present("BINLINK") {
+        let dest_dir = Path::new(m.value_of("DEST_DIR").unwrap_or(DEFAULT_BINLINK_DIR));
+        for pkg_ident in pkg_idents.iter() {
+            command::pkg::binlink::binlink_all_in_pkg(ui, pkg_ident, dest_dir, &*FS_ROOT)?;
         }
     }
     Ok(())
 }
 
+fn sub_pkg_uninstall(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
+
+    command::pkg::uninstall::start(
+        ui,
+        &ident,
+        &*FS_ROOT,
+        m.is_present("REMOVE_ORPHANS"),
+        m.is_present("DRY_RUN"),
+    )
+}
+
 fn sub_pkg_path(m: &ArgMatches) -> Result<()> {
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
 
@@ -565,7 +700,7 @@ fn exec_subcommand_if_called(ui: &mut UI) -> Result<()> {
             args.insert(0, OsString::from("config"));
             command::butterfly::start(ui, args)
         }
-        ("config", _) | ("file", _) => {
+        ("config", _) | ("file", _) | ("member", _) => {
             command::butterfly::start(ui, env::args_os().skip(1).collect())
         }
         ("run", _) => command::sup::start(ui, env::args_os().skip(1).collect()),
@@ -630,6 +765,14 @@ fn auth_token_param_or_env(m: &ArgMatches) -> Result<String> {
     }
 }
 
+/// Returns the package target passed with `--target`, or the target of this system.
+fn target_param(m: &ArgMatches) -> Result<PackageTarget> {
+    match m.value_of("TARGET") {
+        Some(target) => Ok(try!(PackageTarget::from_str(target))),
+        None => Ok(PackageTarget::default()),
+    }
+}
+
 /// Check to see if the user has passed in an ORIGIN param.  If not, check the HABITAT_ORIGIN env
 /// var. If not, check the CLI config to see if there is a default origin set. If that's empty too,
 /// then error.
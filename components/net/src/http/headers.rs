@@ -16,3 +16,6 @@ header! { (CacheControl, "Cache-Control") => [String] }
 header! { (ContentDisposition, "Content-Disposition") => [String] }
 header! { (XFileName, "X-Filename") => [String] }
 header! { (ETag, "ETag") => [String] }
+header! { (LastEventId, "Last-Event-ID") => [String] }
+header! { (XGitHubEvent, "X-GitHub-Event") => [String] }
+header! { (XHubSignature, "X-Hub-Signature") => [String] }
@@ -1061,6 +1061,10 @@ $(Get-Content "$PLAN_CONTEXT\plan.ps1" -Raw)
 # * `$pkg_prefix/LDFLAGS` - Any LDFLAGS for things that link against us
 # * `$pkg_prefix/LD_RUN_PATH` - The LD_RUN_PATH for things that link against us
 # * `$pkg_prefix/PATH` - Any PATH entries for things that link against us
+# * `$pkg_prefix/DESCRIPTION` - The package's description, from `pkg_description`
+# * `$pkg_prefix/LICENSES` - The package's licenses, one per line, from `pkg_license`
+# * `$pkg_prefix/MAINTAINER` - The package's maintainer, from `pkg_maintainer`
+# * `$pkg_prefix/UPSTREAM_URL` - The package's upstream URL, from `pkg_upstream_url`
 function _Write-Metadata {
     Write-BuildLine "Building pacakge metadata"
 
@@ -1119,6 +1123,19 @@ function _Write-Metadata {
         Resolve-HabPkgPath $_ | Out-File $pkg_prefix\TDEPS -Encoding ascii -Append
     }
 
+    if (-Not ([string]::IsNullOrEmpty($pkg_description))) {
+        "$pkg_description" | Out-File "$pkg_prefix\DESCRIPTION" -Encoding ascii
+    }
+    $pkg_license | % {
+        "$_" | Out-File "$pkg_prefix\LICENSES" -Encoding ascii -Append
+    }
+    if (-Not ([string]::IsNullOrEmpty($pkg_maintainer))) {
+        "$pkg_maintainer" | Out-File "$pkg_prefix\MAINTAINER" -Encoding ascii
+    }
+    if (-Not ([string]::IsNullOrEmpty($pkg_upstream_url))) {
+        "$pkg_upstream_url" | Out-File "$pkg_prefix\UPSTREAM_URL" -Encoding ascii
+    }
+
    "$pkg_target" | Out-File "$pkg_prefix\TARGET" -Encoding ascii
 
    "$pkg_origin/$pkg_name/$pkg_version/$pkg_release" |
@@ -193,6 +193,19 @@
 # pkg_exposes=(port)
 # ```
 #
+# ### pkg_health_check
+# An associative array describing a built-in health check the Supervisor runs against the service
+# when the package has no `health_check` hook. The `type` is either `tcp`, which succeeds when a
+# connection to `port` can be made, or `http`, which succeeds when a `GET` request for `path`
+# (default `/`) on `port` is answered with a 2xx status code. The `host` defaults to `127.0.0.1`.
+# ```
+# pkg_health_check=(
+#   [type]=http
+#   [port]=8080
+#   [path]=/health
+# )
+# ```
+#
 # ### pkg_binds
 # An associative array representing services which you depend on and the configuration keys that
 # you expect the service to export (by their `pkg_exports`). These binds *must* be set for the
@@ -404,6 +417,7 @@ pkg_pconfig_dirs=()
 pkg_svc_run=''
 pkg_exposes=()
 declare -A pkg_exports
+declare -A pkg_health_check
 declare -A pkg_binds
 declare -A pkg_binds_optional
 # The user to run the service as
@@ -2497,11 +2511,16 @@ do_default_install() {
 # * `$pkg_prefix/ENVIRONMENT_SEP` - A list of Internal Field Separators for environment keys
 # * `$pkg_prefix/EXPORTS` - A list of exported configuration keys and their public name
 # * `$pkg_prefix/EXPOSES` - An array of `pkg_exports` for which ports that this package exposes
+# * `$pkg_prefix/HEALTH_CHECK` - The settings of the built-in health check run by the Supervisor
 # * `$pkg_prefix/BINDS` - A list of services you connect to and keys that you expect to be exported
 # * `$pkg_prefix/BINDS_OPTIONAL` - Same as `BINDS` but not required for the service to start
 # * `$pkg_prefix/FILES` - blake2b checksums of all files in the package
 # * `$pkg_prefix/LDFLAGS` - Any LDFLAGS for things that link against us
 # * `$pkg_prefix/LD_RUN_PATH` - The LD_RUN_PATH for things that link against us
+# * `$pkg_prefix/DESCRIPTION` - The package's description, from `pkg_description`
+# * `$pkg_prefix/LICENSES` - The package's licenses, one per line, from `pkg_license`
+# * `$pkg_prefix/MAINTAINER` - The package's maintainer, from `pkg_maintainer`
+# * `$pkg_prefix/UPSTREAM_URL` - The package's upstream URL, from `pkg_upstream_url`
 _build_metadata() {
   build_line "Building package metadata"
   local ld_run_path_part=()
@@ -2581,6 +2600,19 @@ _build_metadata() {
     echo "$export=${pkg_exports[$export]}" >> $pkg_prefix/EXPORTS
   done
 
+  if [[ ${#pkg_health_check[@]} -gt 0 ]]; then
+    case "${pkg_health_check[type]}" in
+      tcp|http) ;;
+      *) exit_with "Bad value in pkg_health_check; type must be one of tcp or http: ${pkg_health_check[type]}" ;;
+    esac
+    if ! _port_is_valid "${pkg_health_check[port]}"; then
+      exit_with "Bad value in pkg_health_check; port is not a valid TCP port number: ${pkg_health_check[port]}"
+    fi
+    for setting in "${!pkg_health_check[@]}"; do
+      echo "$setting=${pkg_health_check[$setting]}" >> $pkg_prefix/HEALTH_CHECK
+    done
+  fi
+
   for bind in "${!pkg_binds[@]}"; do
     echo "$bind=${pkg_binds[$bind]}" >> $pkg_prefix/BINDS
   done
@@ -2639,6 +2671,19 @@ _build_metadata() {
     echo "$deps" > $pkg_prefix/TDEPS
   fi
 
+  if [[ -n "${pkg_description:-}" ]]; then
+    printf '%s\n' "$pkg_description" > $pkg_prefix/DESCRIPTION
+  fi
+  if [[ ${#pkg_license[@]} -gt 0 ]]; then
+    printf '%s\n' "${pkg_license[@]}" > $pkg_prefix/LICENSES
+  fi
+  if [[ -n "${pkg_maintainer:-}" ]]; then
+    echo "$pkg_maintainer" > $pkg_prefix/MAINTAINER
+  fi
+  if [[ -n "${pkg_upstream_url:-}" ]]; then
+    echo "$pkg_upstream_url" > $pkg_prefix/UPSTREAM_URL
+  fi
+
   echo "$pkg_target" > $pkg_prefix/TARGET
   echo "${pkg_origin}/${pkg_name}/${pkg_version}/${pkg_release}" \
     >> $pkg_prefix/IDENT
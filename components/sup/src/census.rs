@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
 use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::str::FromStr;
@@ -33,6 +34,7 @@ use hcore::package::PackageIdent;
 use toml;
 
 use error::{Error, SupError};
+use manager::service::HealthCheck;
 
 static LOGKEY: &'static str = "CE";
 
@@ -340,6 +342,15 @@ impl CensusGroup {
             .collect()
     }
 
+    /// Returns the alive members which receive a canary update first. This is the given
+    /// percentage of alive members, rounded up and never less than one, in member-id order.
+    pub fn canaries(&self, percent: u8) -> Vec<&CensusMember> {
+        let alive_members: Vec<&CensusMember> =
+            self.population.values().filter(|cm| cm.alive).collect();
+        let count = (alive_members.len() * percent as usize + 99) / 100;
+        alive_members.into_iter().take(cmp::max(count, 1)).collect()
+    }
+
     /// Return previous alive peer, the peer to your left in the ordered members list, or None if
     /// you have no alive peers.
     pub fn previous_peer(&self) -> Option<&CensusMember> {
@@ -363,6 +374,16 @@ impl CensusGroup {
     }
 
     fn update_from_service_rumors(&mut self, rumors: &HashMap<String, ServiceRumor>) {
+        // Members whose service rumor has been evicted, such as departed members, are no longer
+        // part of the census.
+        let evicted: Vec<MemberId> = self.population
+            .keys()
+            .filter(|member_id| !rumors.contains_key(*member_id))
+            .cloned()
+            .collect();
+        for member_id in evicted {
+            self.population.remove(&member_id);
+        }
         for (member_id, service_rumor) in rumors.iter() {
             // Yeah - we are ourself - we're alive.
             let is_self = member_id == &self.local_member_id;
@@ -434,35 +455,47 @@ impl CensusGroup {
         }
     }
 
+    /// Updates the service files from their rumors. A bundle is only applied when its incarnation
+    /// is newer than that of every file it holds, in which case all of them are updated; a bundle
+    /// is never applied to some of its files only.
     fn update_from_service_file_rumors(
         &mut self,
         service_file_rumors: &HashMap<String, ServiceFileRumor>,
     ) {
         self.changed_service_files.clear();
         for (_m_id, service_file_rumor) in service_file_rumors.iter() {
-            let filename = service_file_rumor.get_filename().to_string();
-            let file = self.service_files.entry(filename.clone()).or_insert(
-                ServiceFile::default(),
-            );
-
-            if service_file_rumor.get_incarnation() > file.incarnation {
-                match service_file_rumor.body() {
-                    Ok(body) => {
-                        self.changed_service_files.push(filename.clone());
-                        file.filename = filename.clone();
-                        file.incarnation = service_file_rumor.get_incarnation();
-                        file.body = body;
-                    }
-                    Err(e) => {
-                        warn!(
-                            "Cannot decrypt service file for {} {} {}: {}",
-                            self.service_group,
-                            service_file_rumor.get_filename(),
-                            service_file_rumor.get_incarnation(),
-                            e
-                        )
-                    }
+            let incarnation = service_file_rumor.get_incarnation();
+            let files = match service_file_rumor.files() {
+                Ok(files) => files,
+                Err(e) => {
+                    warn!(
+                        "Cannot decrypt service file for {} {} {}: {}",
+                        self.service_group,
+                        service_file_rumor.get_filename(),
+                        incarnation,
+                        e
+                    );
+                    continue;
                 }
+            };
+            let stale = files.iter().any(|&(ref filename, _)| {
+                self.service_files.get(filename).map_or(false, |file| {
+                    file.incarnation >= incarnation
+                })
+            });
+            if stale {
+                continue;
+            }
+            for (filename, body) in files {
+                self.changed_service_files.push(filename.clone());
+                self.service_files.insert(
+                    filename.clone(),
+                    ServiceFile {
+                        filename: filename,
+                        incarnation: incarnation,
+                        body: body,
+                    },
+                );
             }
         }
     }
@@ -495,6 +528,7 @@ pub struct CensusMember {
     alive: bool,
     suspect: bool,
     confirmed: bool,
+    health: HealthCheck,
     sys: SysInfo,
     // Maps must be represented last in a serializable struct for the current version of the toml
     // crate. Additionally, this deserialization method is required to correct any ordering issues
@@ -504,6 +538,11 @@ pub struct CensusMember {
 }
 
 impl CensusMember {
+    /// The health of the member's service, as last gossiped
+    pub fn health(&self) -> HealthCheck {
+        self.health
+    }
+
     pub fn as_protobuf(&self) -> CensusEntryProto {
         let mut cep = CensusEntryProto::new();
         cep.set_member_id(self.member_id.clone());
@@ -565,6 +604,7 @@ impl CensusMember {
             Err(err) => warn!("Received a bad package ident from gossip data, err={}", err),
         };
         self.sys = rumor.get_sys().clone().into();
+        self.health = rumor.get_health().into();
         self.cfg = toml::from_slice(rumor.get_cfg()).unwrap_or(toml::value::Table::default());
     }
 
@@ -626,6 +666,8 @@ fn service_group_from_str(sg: &str) -> Result<ServiceGroup, hcore::Error> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use hcore::package::ident::PackageIdent;
     use hcore::service::ServiceGroup;
     use butterfly::member::MemberList;
@@ -634,9 +676,10 @@ mod tests {
     use butterfly::rumor::service_file::ServiceFile as ServiceFileRumor;
     use butterfly::rumor::election::Election as ElectionRumor;
     use butterfly::rumor::election::ElectionUpdate as ElectionUpdateRumor;
-    use butterfly::rumor::service::SysInfo;
+    use butterfly::rumor::service::{Service_Health, SysInfo};
     use butterfly::rumor::RumorStore;
-    use census::CensusRing;
+    use census::{CensusGroup, CensusMember, CensusRing};
+    use manager::service::HealthCheck;
 
     #[test]
     fn update_from_rumors() {
@@ -710,4 +753,187 @@ mod tests {
         assert_eq!(members[0].member_id, "member-a");
         assert_eq!(members[1].member_id, "member-b");
     }
+
+    #[test]
+    fn update_from_rumors_removes_evicted_members() {
+        let sys_info = SysInfo::new();
+        let pg_id = PackageIdent::new(
+            "starkandwayne",
+            "shield",
+            Some("0.10.4"),
+            Some("20170419115548"),
+        );
+        let sg = ServiceGroup::new("shield", "one", None).unwrap();
+        let service_store: RumorStore<ServiceRumor> = RumorStore::default();
+        service_store.insert(ServiceRumor::new(
+            "member-a".to_string(),
+            &pg_id,
+            &sg,
+            &sys_info,
+            None,
+        ));
+        service_store.insert(ServiceRumor::new(
+            "member-b".to_string(),
+            &pg_id,
+            &sg,
+            &sys_info,
+            None,
+        ));
+        let election_store: RumorStore<ElectionRumor> = RumorStore::default();
+        let election_update_store: RumorStore<ElectionUpdateRumor> = RumorStore::default();
+        let member_list = MemberList::new();
+        let service_config_store: RumorStore<ServiceConfigRumor> = RumorStore::default();
+        let service_file_store: RumorStore<ServiceFileRumor> = RumorStore::default();
+        let mut ring = CensusRing::new("member-b".to_string());
+        ring.update_from_rumors(
+            &service_store,
+            &election_store,
+            &election_update_store,
+            &member_list,
+            &service_config_store,
+            &service_file_store,
+        );
+        assert_eq!(ring.census_group_for(&sg).unwrap().members().len(), 2);
+
+        service_store.remove_id("member-a");
+        ring.update_from_rumors(
+            &service_store,
+            &election_store,
+            &election_update_store,
+            &member_list,
+            &service_config_store,
+            &service_file_store,
+        );
+        let members = ring.census_group_for(&sg).unwrap().members();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].member_id, "member-b");
+    }
+
+    #[test]
+    fn update_from_rumors_sets_health() {
+        let pg_id = PackageIdent::new(
+            "starkandwayne",
+            "shield",
+            Some("0.10.4"),
+            Some("20170419115548"),
+        );
+        let sg = ServiceGroup::new("shield", "one", None).unwrap();
+        let service_store: RumorStore<ServiceRumor> = RumorStore::default();
+        let mut service = ServiceRumor::new(
+            "member-a".to_string(),
+            &pg_id,
+            &sg,
+            &SysInfo::new(),
+            None,
+        );
+        service.set_health(Service_Health::CRITICAL);
+        service_store.insert(service);
+        service_store.insert(ServiceRumor::new(
+            "member-b".to_string(),
+            &pg_id,
+            &sg,
+            &SysInfo::new(),
+            None,
+        ));
+        let election_store: RumorStore<ElectionRumor> = RumorStore::default();
+        let election_update_store: RumorStore<ElectionUpdateRumor> = RumorStore::default();
+        let member_list = MemberList::new();
+        let service_config_store: RumorStore<ServiceConfigRumor> = RumorStore::default();
+        let service_file_store: RumorStore<ServiceFileRumor> = RumorStore::default();
+        let mut ring = CensusRing::new("member-b".to_string());
+        ring.update_from_rumors(
+            &service_store,
+            &election_store,
+            &election_update_store,
+            &member_list,
+            &service_config_store,
+            &service_file_store,
+        );
+        let members = ring.census_group_for(&sg).unwrap().members();
+        assert_eq!(members[0].health, HealthCheck::Critical);
+        assert_eq!(members[1].health, HealthCheck::Unknown);
+    }
+
+    #[test]
+    fn update_from_service_file_rumors_changes_every_file_of_a_bundle() {
+        let sg = ServiceGroup::new("shield", "one", None).unwrap();
+        let mut census_group = CensusGroup::new(sg.clone(), &"member-a".to_string());
+        let mut bundle = ServiceFileRumor::new_bundle(
+            "member-a",
+            sg,
+            "tls",
+            vec![
+                ("cert.pem".to_string(), Vec::from("certificate")),
+                ("key.pem".to_string(), Vec::from("private key")),
+            ],
+        );
+        bundle.set_incarnation(1);
+        let mut rumors = HashMap::new();
+        rumors.insert("tls".to_string(), bundle);
+
+        census_group.update_from_service_file_rumors(&rumors);
+        let mut changed: Vec<&str> = census_group
+            .changed_service_files()
+            .iter()
+            .map(|f| f.filename.as_str())
+            .collect();
+        changed.sort();
+        assert_eq!(changed, vec!["cert.pem", "key.pem"]);
+
+        census_group.update_from_service_file_rumors(&rumors);
+        assert!(census_group.changed_service_files().is_empty());
+    }
+
+    #[test]
+    fn update_from_service_file_rumors_skips_a_bundle_older_than_one_of_its_files() {
+        let sg = ServiceGroup::new("shield", "one", None).unwrap();
+        let mut census_group = CensusGroup::new(sg.clone(), &"member-a".to_string());
+        let mut cert =
+            ServiceFileRumor::new("member-a", sg.clone(), "cert.pem", Vec::from("newer"));
+        cert.set_incarnation(3);
+        let mut rumors = HashMap::new();
+        rumors.insert("cert.pem".to_string(), cert);
+        census_group.update_from_service_file_rumors(&rumors);
+
+        let mut bundle = ServiceFileRumor::new_bundle(
+            "member-a",
+            sg,
+            "tls",
+            vec![
+                ("cert.pem".to_string(), Vec::from("certificate")),
+                ("key.pem".to_string(), Vec::from("private key")),
+            ],
+        );
+        bundle.set_incarnation(2);
+        let mut rumors = HashMap::new();
+        rumors.insert("tls".to_string(), bundle);
+        census_group.update_from_service_file_rumors(&rumors);
+
+        assert!(census_group.changed_service_files().is_empty());
+        assert_eq!(census_group.service_files["cert.pem"].body, Vec::from("newer"));
+        assert!(!census_group.service_files.contains_key("key.pem"));
+    }
+
+    #[test]
+    fn canaries() {
+        let sg = ServiceGroup::new("shield", "one", None).unwrap();
+        let mut census_group = CensusGroup::new(sg, &"member-a".to_string());
+        for id in &["member-a", "member-b", "member-c", "member-d", "member-e"] {
+            let mut member = CensusMember::default();
+            member.member_id = id.to_string();
+            member.alive = *id != "member-b";
+            census_group.population.insert(id.to_string(), member);
+        }
+
+        let canaries = census_group.canaries(10);
+        assert_eq!(canaries.len(), 1);
+        assert_eq!(canaries[0].member_id, "member-a");
+
+        let canaries = census_group.canaries(50);
+        assert_eq!(canaries.len(), 2);
+        assert_eq!(canaries[0].member_id, "member-a");
+        assert_eq!(canaries[1].member_id, "member-c");
+
+        assert_eq!(census_group.canaries(100).len(), 4);
+    }
 }
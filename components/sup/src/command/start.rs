@@ -53,6 +53,7 @@ use ansi_term::Colour::Yellow;
 use common;
 use common::ui::UI;
 use hcore::fs::{self, FS_ROOT_PATH};
+use hcore::package::PackageTarget;
 
 use {PRODUCT, VERSION};
 use error::Result;
@@ -88,6 +89,7 @@ pub fn run(
                 VERSION,
                 Path::new(&*FS_ROOT_PATH),
                 &fs::cache_artifact_path(None),
+                &PackageTarget::default(),
                 false,
             )?;
         }
@@ -107,9 +107,11 @@ pub enum Error {
     BadDataPath(PathBuf, io::Error),
     BadDesiredState(String),
     BadElectionStatus(String),
+    BadHealthCheckInterval,
     BadPackage(PackageInstall, hcore::error::Error),
     BadSpecsPath(PathBuf, io::Error),
     BadStartStyle(String),
+    BindCycle(Vec<String>),
     ButterflyError(butterfly::error::Error),
     DepotClient(depot_client::Error),
     EnvJoinPathsError(env::JoinPathsError),
@@ -121,12 +123,14 @@ pub enum Error {
     TemplateRenderError(handlebars::RenderError),
     InvalidBinding(String),
     InvalidBinds(Vec<String>),
+    InvalidHealthCheck(String),
     InvalidKeyParameter(String),
     InvalidPidFile,
     InvalidTopology(String),
     InvalidUpdateStrategy(String),
     Io(io::Error),
     IPFailed,
+    LockfileIdentMismatch(package::PackageIdent, package::PackageIdent),
     MissingRequiredBind(Vec<String>),
     MissingRequiredIdent,
     NameLookup(io::Error),
@@ -181,6 +185,9 @@ impl fmt::Display for SupError {
                 format!("Unknown service desired state style '{}'", state)
             }
             Error::BadElectionStatus(ref status) => format!("Unknown election status '{}'", status),
+            Error::BadHealthCheckInterval => {
+                format!("The health check interval must be at least 1 second")
+            }
             Error::BadPackage(ref pkg, ref err) => format!("Bad package, {}, {}", pkg, err),
             Error::BadSpecsPath(ref path, ref err) => {
                 format!(
@@ -190,6 +197,9 @@ impl fmt::Display for SupError {
                 )
             }
             Error::BadStartStyle(ref style) => format!("Unknown service start style '{}'", style),
+            Error::BindCycle(ref groups) => {
+                format!("Service binds form a cycle, {}", groups.join(" -> "))
+            }
             Error::ButterflyError(ref err) => format!("Butterfly error: {}", err),
             Error::ExecCommandNotFound(ref c) => {
                 format!("`{}' was not found on the filesystem or in PATH", c)
@@ -210,6 +220,7 @@ impl fmt::Display for SupError {
                 )
             }
             Error::InvalidBinds(ref e) => format!("Invalid bind(s), {}", e.join(", ")),
+            Error::InvalidHealthCheck(ref e) => format!("Invalid health check, {}", e),
             Error::InvalidKeyParameter(ref e) => {
                 format!("Invalid parameter for key generation: {:?}", e)
             }
@@ -218,6 +229,9 @@ impl fmt::Display for SupError {
             Error::InvalidUpdateStrategy(ref s) => format!("Invalid update strategy: {}", s),
             Error::Io(ref err) => format!("{}", err),
             Error::IPFailed => format!("Failed to discover this hosts outbound IP address"),
+            Error::LockfileIdentMismatch(ref lock, ref ident) => {
+                format!("Lockfile for {} cannot pin the {} service", lock, ident)
+            }
             Error::MissingRequiredBind(ref e) => {
                 format!("Missing required bind(s), {}", e.join(", "))
             }
@@ -317,9 +331,11 @@ impl error::Error for SupError {
             Error::BadDataPath(_, _) => "Unable to read or write to data directory",
             Error::BadElectionStatus(_) => "Unknown election status",
             Error::BadDesiredState(_) => "Unknown desired state in service spec",
+            Error::BadHealthCheckInterval => "Health check interval in service spec is 0",
             Error::BadPackage(_, _) => "Package was malformed or contained malformed contents",
             Error::BadSpecsPath(_, _) => "Unable to create the specs directory",
             Error::BadStartStyle(_) => "Unknown start style in service spec",
+            Error::BindCycle(_) => "Services on this Supervisor bind to each other in a cycle",
             Error::ButterflyError(ref err) => err.description(),
             Error::ExecCommandNotFound(_) => "Exec command was not found on filesystem or in PATH",
             Error::TemplateFileError(ref err) => err.description(),
@@ -333,12 +349,14 @@ impl error::Error for SupError {
             Error::InvalidBinds(_) => {
                 "Service binds detected that are neither required nor optional package binds"
             }
+            Error::InvalidHealthCheck(_) => "Invalid pkg_health_check settings in package",
             Error::InvalidKeyParameter(_) => "Key parameter error",
             Error::InvalidPidFile => "Invalid child process PID file",
             Error::InvalidTopology(_) => "Invalid topology",
             Error::InvalidUpdateStrategy(_) => "Invalid update strategy",
             Error::Io(ref err) => err.description(),
             Error::IPFailed => "Failed to discover the outbound IP address",
+            Error::LockfileIdentMismatch(_, _) => "Lockfile was made for another package",
             Error::MissingRequiredBind(_) => {
                 "A service to start without specifying a service group for all required binds"
             }
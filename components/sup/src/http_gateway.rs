@@ -14,7 +14,7 @@
 
 use std::collections::HashMap;
 use std::fmt;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{self, Read};
 use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs, SocketAddr, SocketAddrV4};
 use std::ops::{Deref, DerefMut};
@@ -25,6 +25,7 @@ use std::str::FromStr;
 use std::sync::Arc;
 use std::thread::{self, JoinHandle};
 
+use hcore::package::PackageIdent;
 use hcore::service::ServiceGroup;
 use iron::prelude::*;
 use iron::{headers, status, typemap};
@@ -36,7 +37,7 @@ use serde_json::{self, Value as Json};
 
 use error::{Result, Error, SupError};
 use manager;
-use manager::service::HealthCheck;
+use manager::service::{DesiredState, HealthCheck, ServiceSpec, StartStyle};
 use manager::service::hooks::{self, HealthCheckHook};
 
 static LOGKEY: &'static str = "HG";
@@ -141,10 +142,24 @@ impl typemap::Key for ManagerFs {
     type Value = manager::FsCfg;
 }
 
+struct GatewayAuthToken;
+
+impl typemap::Key for GatewayAuthToken {
+    type Value = Option<String>;
+}
+
 pub struct Server(Iron<Chain>, ListenAddr);
 
 impl Server {
-    pub fn new(manager_state: Arc<manager::FsCfg>, listen_addr: ListenAddr) -> Self {
+    /// Create a new http-gateway server.
+    ///
+    /// Routes which modify the Supervisor's loaded services are only served when an `auth_token`
+    /// is given and the request carries it as a bearer token in its `Authorization` header.
+    pub fn new(
+        manager_state: Arc<manager::FsCfg>,
+        listen_addr: ListenAddr,
+        auth_token: Option<String>,
+    ) -> Self {
         let router =
             router!(
             doc: get "/" => with_metrics!(doc, "doc"),
@@ -167,10 +182,38 @@ impl Server {
             },
             service_health_org: get "/services/:svc/:group/:org/health" => {
                 with_metrics!(health, "config")
+            },
+            service_load: post "/services" => with_metrics!(service_load, "service_load"),
+            service_update: put "/services/:svc/:group" => {
+                with_metrics!(service_update, "service_update")
+            },
+            service_update_org: put "/services/:svc/:group/:org" => {
+                with_metrics!(service_update, "service_update")
+            },
+            service_unload: delete "/services/:svc/:group" => {
+                with_metrics!(service_unload, "service_unload")
+            },
+            service_unload_org: delete "/services/:svc/:group/:org" => {
+                with_metrics!(service_unload, "service_unload")
+            },
+            service_start: post "/services/:svc/:group/start" => {
+                with_metrics!(service_start, "service_start")
+            },
+            service_start_org: post "/services/:svc/:group/:org/start" => {
+                with_metrics!(service_start, "service_start")
+            },
+            service_stop: post "/services/:svc/:group/stop" => {
+                with_metrics!(service_stop, "service_stop")
+            },
+            service_stop_org: post "/services/:svc/:group/:org/stop" => {
+                with_metrics!(service_stop, "service_stop")
             }
         );
         let mut chain = Chain::new(router);
         chain.link(persistent::Read::<ManagerFs>::both(manager_state));
+        chain.link(persistent::Read::<GatewayAuthToken>::both(
+            Arc::new(auth_token),
+        ));
         Server(Iron::new(chain), listen_addr)
     }
 
@@ -194,6 +237,12 @@ struct HealthCheckBody {
     stderr: String,
 }
 
+#[derive(Serialize)]
+struct ServiceSpecBody {
+    spec: ServiceSpec,
+    status: Option<Json>,
+}
+
 fn butterfly(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
     match File::open(&state.butterfly_data_path) {
@@ -297,6 +346,83 @@ fn services(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn service_load(req: &mut Request) -> IronResult<Response> {
+    if let Err(status) = authorize(req) {
+        return Ok(Response::with(status));
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let mut spec = match spec_from_body(req) {
+        Ok(spec) => spec,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    if state.spec_path_for(&spec).is_file() {
+        return Ok(Response::with(status::Conflict));
+    }
+    spec.start_style = StartStyle::Persistent;
+    if let Err(err) = state.check_bind_cycles(&spec, None) {
+        return Ok(Response::with((status::UnprocessableEntity, err.to_string())));
+    }
+    save_spec(&state, spec)
+}
+
+fn service_update(req: &mut Request) -> IronResult<Response> {
+    if let Err(status) = authorize(req) {
+        return Ok(Response::with(status));
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let current = match spec_for_service_group(&state, &service_group) {
+        Ok(Some(spec)) => spec,
+        Ok(None) => return Ok(Response::with(status::NotFound)),
+        Err(_) => return Ok(Response::with(status::ServiceUnavailable)),
+    };
+    let mut spec = match spec_from_body(req) {
+        Ok(spec) => spec,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    if spec.ident.name != current.ident.name {
+        return Ok(Response::with(status::BadRequest));
+    }
+    spec.group = service_group.group().to_string();
+    spec.start_style = current.start_style;
+    if let Err(err) = state.check_bind_cycles(&spec, service_group.org()) {
+        return Ok(Response::with((status::UnprocessableEntity, err.to_string())));
+    }
+    save_spec(&state, spec)
+}
+
+fn service_unload(req: &mut Request) -> IronResult<Response> {
+    if let Err(status) = authorize(req) {
+        return Ok(Response::with(status));
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let spec = match spec_for_service_group(&state, &service_group) {
+        Ok(Some(spec)) => spec,
+        Ok(None) => return Ok(Response::with(status::NotFound)),
+        Err(_) => return Ok(Response::with(status::ServiceUnavailable)),
+    };
+    if let Err(err) = fs::remove_file(state.spec_path_for(&spec)) {
+        warn!("Unable to remove service spec for {}, {}", spec.ident, err);
+        return Ok(Response::with(status::InternalServerError));
+    }
+    spec_response(&state, spec)
+}
+
+fn service_start(req: &mut Request) -> IronResult<Response> {
+    set_desired_state(req, DesiredState::Up)
+}
+
+fn service_stop(req: &mut Request) -> IronResult<Response> {
+    set_desired_state(req, DesiredState::Down)
+}
+
 fn doc(_req: &mut Request) -> IronResult<Response> {
     Ok(Response::with(
         (status::Ok, Header(headers::ContentType::html()), APIDOCS),
@@ -331,6 +457,101 @@ impl Into<status::Status> for HealthCheck {
     }
 }
 
+/// Checks the request's bearer token against the gateway's configured auth token.
+///
+/// Returns the status to respond with if the request may not modify the Supervisor's services.
+fn authorize(req: &mut Request) -> result::Result<(), status::Status> {
+    let token = req.get::<persistent::Read<GatewayAuthToken>>().unwrap();
+    let token = match *token {
+        Some(ref token) => token,
+        None => return Err(status::Forbidden),
+    };
+    match req.headers.get::<headers::Authorization<headers::Bearer>>() {
+        Some(&headers::Authorization(headers::Bearer { token: ref given }))
+            if tokens_match(given, token) => Ok(()),
+        _ => Err(status::Unauthorized),
+    }
+}
+
+/// Compares a given token to the expected one in time which doesn't depend on how much of the
+/// given token is right, so that it can't be guessed a byte at a time.
+fn tokens_match(given: &str, expected: &str) -> bool {
+    let (given, expected) = (given.as_bytes(), expected.as_bytes());
+    if given.len() != expected.len() {
+        return false;
+    }
+    given.iter().zip(expected).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+fn set_desired_state(req: &mut Request, desired_state: DesiredState) -> IronResult<Response> {
+    if let Err(status) = authorize(req) {
+        return Ok(Response::with(status));
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let mut spec = match spec_for_service_group(&state, &service_group) {
+        Ok(Some(spec)) => spec,
+        Ok(None) => return Ok(Response::with(status::NotFound)),
+        Err(_) => return Ok(Response::with(status::ServiceUnavailable)),
+    };
+    spec.desired_state = desired_state;
+    save_spec(&state, spec)
+}
+
+fn save_spec(state: &manager::FsCfg, spec: ServiceSpec) -> IronResult<Response> {
+    if let Err(err) = state.save_spec(&spec) {
+        warn!("Unable to save service spec for {}, {}", spec.ident, err);
+        return Ok(Response::with(status::InternalServerError));
+    }
+    spec_response(state, spec)
+}
+
+/// Respond with the given spec and the last known status of its service. The status of a freshly
+/// loaded service will be `null` until the Manager has picked up the spec.
+fn spec_response(state: &manager::FsCfg, spec: ServiceSpec) -> IronResult<Response> {
+    let status = service_by_name_from_file(&spec.ident.name, &state.services_data_path)
+        .unwrap_or(None);
+    let body = ServiceSpecBody {
+        spec: spec,
+        status: status,
+    };
+    Ok(Response::with((
+        status::Accepted,
+        Header(headers::ContentType::json()),
+        serde_json::to_string(&body).unwrap(),
+    )))
+}
+
+fn spec_from_body(req: &mut Request) -> Result<ServiceSpec> {
+    let mut buf = String::new();
+    req.body.read_to_string(&mut buf)?;
+    let spec: ServiceSpec = serde_json::from_str(&buf).map_err(|e| {
+        sup_error!(Error::ServiceDeserializationError(e))
+    })?;
+    if spec.ident == PackageIdent::default() {
+        return Err(sup_error!(Error::MissingRequiredIdent));
+    }
+    Ok(spec)
+}
+
+fn spec_for_service_group(
+    state: &manager::FsCfg,
+    service_group: &ServiceGroup,
+) -> Result<Option<ServiceSpec>> {
+    let spec_file = state.spec_path_for_service(service_group.service());
+    if !spec_file.is_file() {
+        return Ok(None);
+    }
+    let spec = ServiceSpec::from_file(&spec_file)?;
+    if spec.group != service_group.group() {
+        return Ok(None);
+    }
+    Ok(Some(spec))
+}
+
 fn build_service_group(req: &mut Request) -> Result<ServiceGroup> {
     let sg = ServiceGroup::new(
         req.extensions
@@ -369,3 +590,146 @@ where
         Err(err) => Err(err),
     }
 }
+
+fn service_by_name_from_file<T>(
+    name: &str,
+    services_data_path: T,
+) -> result::Result<Option<Json>, io::Error>
+where
+    T: AsRef<Path>,
+{
+    match File::open(services_data_path) {
+        Ok(file) => {
+            match serde_json::from_reader(file) {
+                Ok(Json::Array(services)) => {
+                    Ok(services.into_iter().find(|s| s["pkg"]["name"] == name))
+                }
+                _ => Ok(None),
+            }
+        }
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use iron::{headers, status};
+    use iron::headers::Headers;
+    use iron_test::request;
+    use tempdir::TempDir;
+
+    use manager::FsCfg;
+    use manager::service::{DesiredState, ServiceSpec};
+    use super::{tokens_match, Server, ListenAddr};
+
+    const TOKEN: &'static str = "0ff1ce";
+
+    fn server(root: &TempDir, auth_token: Option<&str>) -> Server {
+        Server::new(
+            Arc::new(FsCfg::new(root.path())),
+            ListenAddr::default(),
+            auth_token.map(|t| t.to_string()),
+        )
+    }
+
+    fn bearer(token: &str) -> Headers {
+        let mut headers = Headers::new();
+        headers.set(headers::Authorization(
+            headers::Bearer { token: token.to_string() },
+        ));
+        headers
+    }
+
+    fn spec(root: &TempDir, name: &str) -> Option<ServiceSpec> {
+        let path = FsCfg::new(root.path()).spec_path_for_service(name);
+        if path.is_file() {
+            Some(ServiceSpec::from_file(path).unwrap())
+        } else {
+            None
+        }
+    }
+
+    #[test]
+    fn tokens_match_only_identical_tokens() {
+        assert!(tokens_match("0ff1ce", "0ff1ce"));
+        assert!(!tokens_match("0ff1cf", "0ff1ce"));
+        assert!(!tokens_match("0ff1c", "0ff1ce"));
+        assert!(!tokens_match("", "0ff1ce"));
+    }
+
+    #[test]
+    fn write_routes_are_forbidden_without_an_auth_token() {
+        let root = TempDir::new("http-gateway").unwrap();
+        let server = server(&root, None);
+        let response = request::post(
+            "http://localhost:9631/services",
+            bearer(TOKEN),
+            r#"{"ident": "core/redis"}"#,
+            &server.0.handler,
+        ).unwrap();
+        assert_eq!(response.status, Some(status::Forbidden));
+        assert!(spec(&root, "redis").is_none());
+    }
+
+    #[test]
+    fn write_routes_reject_a_missing_or_wrong_token() {
+        let root = TempDir::new("http-gateway").unwrap();
+        let server = server(&root, Some(TOKEN));
+        for headers in vec![Headers::new(), bearer("0ff1cf")] {
+            let response = request::post(
+                "http://localhost:9631/services",
+                headers,
+                r#"{"ident": "core/redis"}"#,
+                &server.0.handler,
+            ).unwrap();
+            assert_eq!(response.status, Some(status::Unauthorized));
+        }
+        assert!(spec(&root, "redis").is_none());
+    }
+
+    #[test]
+    fn write_routes_modify_specs_with_the_right_token() {
+        let root = TempDir::new("http-gateway").unwrap();
+        let server = server(&root, Some(TOKEN));
+
+        let response = request::post(
+            "http://localhost:9631/services",
+            bearer(TOKEN),
+            r#"{"ident": "core/redis"}"#,
+            &server.0.handler,
+        ).unwrap();
+        assert_eq!(response.status, Some(status::Accepted));
+        assert_eq!(spec(&root, "redis").unwrap().desired_state, DesiredState::Up);
+
+        let response = request::post(
+            "http://localhost:9631/services/redis/default/stop",
+            bearer(TOKEN),
+            "",
+            &server.0.handler,
+        ).unwrap();
+        assert_eq!(response.status, Some(status::Accepted));
+        assert_eq!(spec(&root, "redis").unwrap().desired_state, DesiredState::Down);
+
+        let response = request::put(
+            "http://localhost:9631/services/redis/default",
+            bearer(TOKEN),
+            r#"{"ident": "core/redis/3.2.4"}"#,
+            &server.0.handler,
+        ).unwrap();
+        assert_eq!(response.status, Some(status::Accepted));
+        assert_eq!(
+            spec(&root, "redis").unwrap().ident.to_string(),
+            "core/redis/3.2.4"
+        );
+
+        let response = request::delete(
+            "http://localhost:9631/services/redis/default",
+            bearer(TOKEN),
+            &server.0.handler,
+        ).unwrap();
+        assert_eq!(response.status, Some(status::Accepted));
+        assert!(spec(&root, "redis").is_none());
+    }
+}
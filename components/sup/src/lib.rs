@@ -56,6 +56,8 @@ extern crate habitat_eventsrv as eventsrv;
 extern crate habitat_eventsrv_client as eventsrv_client;
 extern crate handlebars;
 extern crate iron;
+#[cfg(test)]
+extern crate iron_test;
 #[macro_use]
 extern crate lazy_static;
 extern crate libc;
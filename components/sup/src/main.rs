@@ -38,7 +38,7 @@ use hcore::crypto::{default_cache_key_path, SymKey};
 #[cfg(windows)]
 use hcore::crypto::dpapi::encrypt;
 use hcore::crypto::init as crypto_init;
-use hcore::package::{PackageArchive, PackageIdent};
+use hcore::package::{Identifiable, Lockfile, PackageArchive, PackageIdent};
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 use url::Url;
 
@@ -58,6 +58,7 @@ static LOGKEY: &'static str = "MN";
 
 static RING_ENVVAR: &'static str = "HAB_RING";
 static RING_KEY_ENVVAR: &'static str = "HAB_RING_KEY";
+static GATEWAY_AUTH_TOKEN_ENVVAR: &'static str = "HAB_SUP_GATEWAY_AUTH_TOKEN";
 
 fn main() {
     env_logger::init().unwrap();
@@ -125,7 +126,21 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
                 "Service topology; [default: none]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
+            (@arg LOCKFILE: --lockfile +takes_value {file_exists}
+                "Pin the service and its dependencies to the releases of a lockfile made by \
+                `hab pkg install --write-lockfile`; a pinned service is never updated")
+            (@arg CANARY_PERCENT: --("canary-percent") +takes_value {valid_canary_percent}
+                "Percentage of the service group updated first by the canary strategy \
+                [default: 10]")
+            (@arg CANARY_SOAK_PERIOD: --("canary-soak-period") +takes_value {valid_numeric}
+                "Seconds canaries must stay healthy before the rest of the service group is \
+                updated [default: 300]")
+            (@arg STOP_TIMEOUT: --("stop-timeout") +takes_value {valid_numeric}
+                "Seconds the service is given to stop before it is killed [default: 8]")
+            (@arg HEALTH_CHECK_INTERVAL: --("health-check-interval") +takes_value
+                {valid_health_check_interval}
+                "Seconds between health checks of the service [default: 30]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
             (@arg FORCE: --force -f "Load or reload an already loaded service. If the service was \
@@ -156,6 +171,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg PEER: --peer +takes_value +multiple
                 "The listen address of an initial peer (IP[:PORT])")
             (@arg PERMANENT_PEER: --("permanent-peer") -I "If this Supervisor is a permanent peer")
+            (@arg RUMOR_TTL: --("rumor-ttl") +takes_value {valid_numeric}
+                "Seconds the rumors of a departed or dead peer, or of an empty service group, \
+                are kept before they are forgotten [default: 3600]")
             (@arg RING: --ring -r +takes_value "Ring key name")
         )
         (@subcommand sh =>
@@ -180,6 +198,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg PEER: --peer +takes_value +multiple
                 "The listen address of an initial peer (IP[:PORT])")
             (@arg PERMANENT_PEER: --("permanent-peer") -I "If this Supervisor is a permanent peer")
+            (@arg RUMOR_TTL: --("rumor-ttl") +takes_value {valid_numeric}
+                "Seconds the rumors of a departed or dead peer, or of an empty service group, \
+                are kept before they are forgotten [default: 3600]")
             (@arg RING: --ring -r +takes_value "Ring key name")
             (@arg PKG_IDENT_OR_ARTIFACT: +required +takes_value
                 "A Habitat package identifier (ex: core/redis) or filepath to a Habitat Artifact \
@@ -194,7 +215,21 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
                 "Service topology; [default: none]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
+            (@arg LOCKFILE: --lockfile +takes_value {file_exists}
+                "Pin the service and its dependencies to the releases of a lockfile made by \
+                `hab pkg install --write-lockfile`; a pinned service is never updated")
+            (@arg CANARY_PERCENT: --("canary-percent") +takes_value {valid_canary_percent}
+                "Percentage of the service group updated first by the canary strategy \
+                [default: 10]")
+            (@arg CANARY_SOAK_PERIOD: --("canary-soak-period") +takes_value {valid_numeric}
+                "Seconds canaries must stay healthy before the rest of the service group is \
+                updated [default: 300]")
+            (@arg STOP_TIMEOUT: --("stop-timeout") +takes_value {valid_numeric}
+                "Seconds the service is given to stop before it is killed [default: 8]")
+            (@arg HEALTH_CHECK_INTERVAL: --("health-check-interval") +takes_value
+                {valid_health_check_interval}
+                "Seconds between health checks of the service [default: 30]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
             (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
@@ -263,7 +298,21 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
                 "Service topology; [default: none]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
+            (@arg LOCKFILE: --lockfile +takes_value {file_exists}
+                "Pin the service and its dependencies to the releases of a lockfile made by \
+                `hab pkg install --write-lockfile`; a pinned service is never updated")
+            (@arg CANARY_PERCENT: --("canary-percent") +takes_value {valid_canary_percent}
+                "Percentage of the service group updated first by the canary strategy \
+                [default: 10]")
+            (@arg CANARY_SOAK_PERIOD: --("canary-soak-period") +takes_value {valid_numeric}
+                "Seconds canaries must stay healthy before the rest of the service group is \
+                updated [default: 300]")
+            (@arg STOP_TIMEOUT: --("stop-timeout") +takes_value {valid_numeric}
+                "Seconds the service is given to stop before it is killed [default: 8]")
+            (@arg HEALTH_CHECK_INTERVAL: --("health-check-interval") +takes_value
+                {valid_health_check_interval}
+                "Seconds between health checks of the service [default: 30]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
             (@arg FORCE: --force -f "Load or reload an already loaded service. If the service was \
@@ -296,6 +345,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg PEER: --peer +takes_value +multiple
                 "The listen address of an initial peer (IP[:PORT])")
             (@arg PERMANENT_PEER: --("permanent-peer") -I "If this Supervisor is a permanent peer")
+            (@arg RUMOR_TTL: --("rumor-ttl") +takes_value {valid_numeric}
+                "Seconds the rumors of a departed or dead peer, or of an empty service group, \
+                are kept before they are forgotten [default: 3600]")
             (@arg RING: --ring -r +takes_value "Ring key name")
         )
         (@subcommand sh =>
@@ -320,6 +372,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg PEER: --peer +takes_value +multiple
                 "The listen address of an initial peer (IP[:PORT])")
             (@arg PERMANENT_PEER: --("permanent-peer") -I "If this Supervisor is a permanent peer")
+            (@arg RUMOR_TTL: --("rumor-ttl") +takes_value {valid_numeric}
+                "Seconds the rumors of a departed or dead peer, or of an empty service group, \
+                are kept before they are forgotten [default: 3600]")
             (@arg RING: --ring -r +takes_value "Ring key name")
             (@arg PKG_IDENT_OR_ARTIFACT: +required +takes_value
                 "A Habitat package identifier (ex: core/redis) or filepath to a Habitat Artifact \
@@ -334,7 +389,21 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
                 "Service topology; [default: none]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
+            (@arg LOCKFILE: --lockfile +takes_value {file_exists}
+                "Pin the service and its dependencies to the releases of a lockfile made by \
+                `hab pkg install --write-lockfile`; a pinned service is never updated")
+            (@arg CANARY_PERCENT: --("canary-percent") +takes_value {valid_canary_percent}
+                "Percentage of the service group updated first by the canary strategy \
+                [default: 10]")
+            (@arg CANARY_SOAK_PERIOD: --("canary-soak-period") +takes_value {valid_numeric}
+                "Seconds canaries must stay healthy before the rest of the service group is \
+                updated [default: 300]")
+            (@arg STOP_TIMEOUT: --("stop-timeout") +takes_value {valid_numeric}
+                "Seconds the service is given to stop before it is killed [default: 8]")
+            (@arg HEALTH_CHECK_INTERVAL: --("health-check-interval") +takes_value
+                {valid_health_check_interval}
+                "Seconds between health checks of the service [default: 30]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
             (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
@@ -402,6 +471,7 @@ fn sub_load(m: &ArgMatches) -> Result<()> {
     }
     let mut spec = spec_from_matches(default_spec.ident, m)?;
     spec.start_style = StartStyle::Persistent;
+    Manager::check_bind_cycles_for(&cfg, &spec)?;
     util::pkg::install_from_spec(&mut UI::default(), &spec)?;
 
     Manager::save_spec_for(&cfg, spec.clone())?;
@@ -477,6 +547,7 @@ fn sub_start(m: &ArgMatches) -> Result<()> {
                 }
                 Err(_) => {
                     let spec = spec_from_matches(default_spec.ident, m)?;
+                    Manager::check_bind_cycles_for(&cfg, &spec)?;
                     util::pkg::install_from_spec(&mut UI::default(), &spec)?;
                     Some(spec)
                 }
@@ -598,7 +669,11 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
         outputln!("");
     }
     cfg.organization = m.value_of("ORGANIZATION").map(|org| org.to_string());
+    cfg.http_auth_token = henv::var(GATEWAY_AUTH_TOKEN_ENVVAR).ok();
     cfg.gossip_permanent = m.is_present("PERMANENT_PEER");
+    if let Some(ttl) = m.value_of("RUMOR_TTL") {
+        cfg.rumor_ttl = Some(ttl.parse().unwrap());
+    }
     // TODO fn: Clean this up--using a for loop doesn't feel good however an iterator was
     // causing a lot of developer/compiler type confusion
     let mut gossip_peers: Vec<SocketAddr> = Vec::new();
@@ -671,6 +746,18 @@ fn spec_from_matches(ident: PackageIdent, m: &ArgMatches) -> Result<ServiceSpec>
     if let Some(ref strategy) = m.value_of("STRATEGY") {
         spec.update_strategy = UpdateStrategy::from_str(strategy)?;
     }
+    if let Some(percent) = m.value_of("CANARY_PERCENT") {
+        spec.canary_percent = percent.parse().unwrap();
+    }
+    if let Some(period) = m.value_of("CANARY_SOAK_PERIOD") {
+        spec.canary_soak_period = period.parse().unwrap();
+    }
+    if let Some(timeout) = m.value_of("STOP_TIMEOUT") {
+        spec.stop_timeout = timeout.parse().unwrap();
+    }
+    if let Some(interval) = m.value_of("HEALTH_CHECK_INTERVAL") {
+        spec.health_check_interval = interval.parse().unwrap();
+    }
     if let Some(bind_strs) = m.values_of("BIND") {
         let mut binds = Vec::new();
         for bind_str in bind_strs {
@@ -678,6 +765,15 @@ fn spec_from_matches(ident: PackageIdent, m: &ArgMatches) -> Result<ServiceSpec>
         }
         spec.binds = binds;
     }
+    if let Some(path) = m.value_of("LOCKFILE") {
+        let lockfile = Lockfile::from_file(path)?;
+        if !lockfile.ident.satisfies(&spec.ident) {
+            return Err(sup_error!(
+                Error::LockfileIdentMismatch(lockfile.ident, spec.ident)
+            ));
+        }
+        spec.lockfile = Some(lockfile);
+    }
     if let Some(ref config_from) = m.value_of("CONFIG_DIR") {
         spec.config_from = Some(PathBuf::from(config_from));
         outputln!("");
@@ -709,6 +805,14 @@ fn set_spec_password(m: &ArgMatches, spec: &mut ServiceSpec) -> Result<()> {
     Ok(())
 }
 
+fn file_exists(val: String) -> result::Result<(), String> {
+    if Path::new(&val).is_file() {
+        Ok(())
+    } else {
+        Err(format!("File: '{}' cannot be found", &val))
+    }
+}
+
 fn dir_exists(val: String) -> result::Result<(), String> {
     if Path::new(&val).is_dir() {
         Ok(())
@@ -731,6 +835,27 @@ fn valid_update_strategy(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_canary_percent(val: String) -> result::Result<(), String> {
+    match val.parse::<u8>() {
+        Ok(percent) if percent > 0 && percent <= 100 => Ok(()),
+        _ => Err(format!("Canary percent: '{}' is not between 1 and 100", &val)),
+    }
+}
+
+fn valid_health_check_interval(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(interval) if interval > 0 => Ok(()),
+        _ => Err(format!("Health check interval: '{}' is not a number of seconds above 0", &val)),
+    }
+}
+
+fn valid_numeric(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("'{}' is not a valid number", &val)),
+    }
+}
+
 fn valid_url(val: String) -> result::Result<(), String> {
     match Url::parse(&val) {
         Ok(_) => Ok(()),
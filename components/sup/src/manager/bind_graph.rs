@@ -0,0 +1,181 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A dependency graph of the services running on a single Supervisor.
+//!
+//! A service depends on another when one of its binds points at the other service's group. Binds
+//! to service groups which aren't running on this Supervisor are not part of the graph.
+
+use hcore::service::ServiceGroup;
+
+use error::{Error, Result};
+use manager::service::ServiceSpec;
+
+static LOGKEY: &'static str = "BG";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Visit {
+    New,
+    InProgress,
+    Done,
+}
+
+#[derive(Debug, Default)]
+pub struct BindGraph {
+    service_groups: Vec<ServiceGroup>,
+    binds: Vec<Vec<ServiceGroup>>,
+}
+
+impl BindGraph {
+    pub fn new() -> Self {
+        BindGraph::default()
+    }
+
+    /// Adds a service and the service groups it binds to, returning the service's index in the
+    /// graph.
+    pub fn add(&mut self, service_group: ServiceGroup, binds: Vec<ServiceGroup>) -> usize {
+        self.service_groups.push(service_group);
+        self.binds.push(binds);
+        self.service_groups.len() - 1
+    }
+
+    /// Adds the service described by the given spec, returning its index in the graph.
+    pub fn add_spec(&mut self, spec: &ServiceSpec, organization: Option<&str>) -> Result<usize> {
+        let service_group = ServiceGroup::new(&spec.ident.name, &spec.group, organization)?;
+        let binds = spec.binds
+            .iter()
+            .map(|bind| bind.service_group.clone())
+            .collect();
+        Ok(self.add(service_group, binds))
+    }
+
+    pub fn len(&self) -> usize {
+        self.service_groups.len()
+    }
+
+    pub fn service_group(&self, idx: usize) -> &ServiceGroup {
+        &self.service_groups[idx]
+    }
+
+    /// Returns the indices of the services which the service at `idx` binds to.
+    pub fn dependencies(&self, idx: usize) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&other| self.depends_on(idx, other))
+            .collect()
+    }
+
+    /// Returns an error naming the service groups of the first bind cycle found in the graph.
+    pub fn check_cycles(&self) -> Result<()> {
+        let mut visits = vec![Visit::New; self.len()];
+        let mut path = Vec::new();
+        for idx in 0..self.len() {
+            if let Some(cycle) = self.find_cycle(idx, &mut visits, &mut path) {
+                let groups = cycle.iter().map(|g| g.to_string()).collect();
+                return Err(sup_error!(Error::BindCycle(groups)));
+            }
+        }
+        Ok(())
+    }
+
+    fn depends_on(&self, dependent: usize, dependency: usize) -> bool {
+        dependent != dependency &&
+            self.binds[dependent].contains(&self.service_groups[dependency])
+    }
+
+    fn find_cycle(
+        &self,
+        idx: usize,
+        visits: &mut Vec<Visit>,
+        path: &mut Vec<usize>,
+    ) -> Option<Vec<ServiceGroup>> {
+        match visits[idx] {
+            Visit::Done => return None,
+            Visit::InProgress => {
+                let start = path.iter().position(|&i| i == idx).expect(
+                    "Service in progress is missing from the path",
+                );
+                let mut cycle: Vec<ServiceGroup> = path[start..]
+                    .iter()
+                    .map(|&i| self.service_groups[i].clone())
+                    .collect();
+                cycle.push(self.service_groups[idx].clone());
+                return Some(cycle);
+            }
+            Visit::New => {}
+        }
+        visits[idx] = Visit::InProgress;
+        path.push(idx);
+        for dependency in self.dependencies(idx) {
+            if let Some(cycle) = self.find_cycle(dependency, visits, path) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        visits[idx] = Visit::Done;
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::service::ServiceGroup;
+
+    use super::BindGraph;
+    use error::Error;
+
+    fn sg(value: &str) -> ServiceGroup {
+        ServiceGroup::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn dependencies_ignore_remote_and_self_binds() {
+        let mut graph = BindGraph::new();
+        graph.add(sg("redis.default"), vec![sg("redis.default")]);
+        graph.add(sg("app.default"), vec![sg("redis.default"), sg("remote.default")]);
+
+        assert!(graph.dependencies(0).is_empty());
+        assert_eq!(vec![0], graph.dependencies(1));
+    }
+
+    #[test]
+    fn check_cycles_without_cycle() {
+        let mut graph = BindGraph::new();
+        graph.add(sg("redis.default"), vec![]);
+        graph.add(sg("app.default"), vec![sg("redis.default")]);
+
+        assert!(graph.check_cycles().is_ok());
+    }
+
+    #[test]
+    fn check_cycles_with_cycle() {
+        let mut graph = BindGraph::new();
+        graph.add(sg("web.default"), vec![sg("app.default")]);
+        graph.add(sg("app.default"), vec![sg("db.default")]);
+        graph.add(sg("db.default"), vec![sg("app.default")]);
+
+        match graph.check_cycles() {
+            Err(e) => {
+                match e.err {
+                    Error::BindCycle(groups) => {
+                        assert_eq!(vec!["app.default", "db.default", "app.default"], groups)
+                    }
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("Bind cycle should be reported"),
+        }
+    }
+}
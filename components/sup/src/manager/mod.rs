@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bind_graph;
 pub mod service;
 mod signals;
 mod service_updater;
@@ -50,7 +51,8 @@ use time::{self, Timespec, Duration as TimeDuration};
 
 pub use self::service::{Service, ServiceSpec, UpdateStrategy, Topology};
 pub use self::sys::Sys;
-use self::service::{DesiredState, Pkg, ProcessState, StartStyle};
+use self::bind_graph::BindGraph;
+use self::service::{DesiredState, HealthCheck, Pkg, ProcessState, StartStyle};
 use self::service_updater::ServiceUpdater;
 use self::spec_watcher::{SpecWatcher, SpecWatcherEvent};
 use error::{Error, Result, SupError};
@@ -88,7 +90,7 @@ pub struct FsCfg {
 }
 
 impl FsCfg {
-    fn new<T>(sup_svc_root: T) -> Self
+    pub fn new<T>(sup_svc_root: T) -> Self
     where
         T: Into<PathBuf>,
     {
@@ -110,6 +112,35 @@ impl FsCfg {
             format!("{}.health", service_group.service()),
         )
     }
+
+    /// Returns the path to the spec file of the given service.
+    pub fn spec_path_for(&self, spec: &ServiceSpec) -> PathBuf {
+        self.spec_path_for_service(&spec.ident.name)
+    }
+
+    /// Returns the path to the spec file of a service with the given package name.
+    pub fn spec_path_for_service(&self, name: &str) -> PathBuf {
+        self.specs_path.join(ServiceSpec::file_name_for(name))
+    }
+
+    /// Persist the given spec to the specs directory where it will be picked up by the
+    /// `SpecWatcher` of a running Manager.
+    pub fn save_spec(&self, spec: &ServiceSpec) -> Result<()> {
+        spec.to_file(self.spec_path_for(spec))
+    }
+
+    /// Returns an error if the given spec and the other specs in the specs directory describe
+    /// services which bind to each other in a cycle.
+    pub fn check_bind_cycles(&self, spec: &ServiceSpec, organization: Option<&str>) -> Result<()> {
+        let mut graph = BindGraph::new();
+        for (name, saved) in SpecWatcher::specs_from_path(&self.specs_path)? {
+            if name != spec.ident.name {
+                graph.add_spec(&saved, organization)?;
+            }
+        }
+        graph.add_spec(spec, organization)?;
+        graph.check_cycles()
+    }
 }
 
 #[derive(Clone, Default)]
@@ -120,20 +151,26 @@ pub struct ManagerConfig {
     pub gossip_permanent: bool,
     pub ring: Option<String>,
     pub name: Option<String>,
+    pub http_auth_token: Option<String>,
     custom_state_path: Option<PathBuf>,
     pub organization: Option<String>,
+    pub rumor_ttl: Option<u64>,
 }
 
 pub struct Manager {
     butterfly: butterfly::Server,
+    butterfly_timing: Timing,
     census_ring: CensusRing,
     fs_cfg: Arc<FsCfg>,
+    http_auth_token: Option<String>,
     services: Arc<RwLock<Vec<Service>>>,
     updater: ServiceUpdater,
     watcher: SpecWatcher,
     organization: Option<String>,
     service_states: HashMap<PackageIdent, Timespec>,
     sys: Arc<Sys>,
+    /// The bound service group each service which can't start yet was last reported waiting on
+    waiting_on_binds: HashMap<ServiceGroup, ServiceGroup>,
 }
 
 impl Manager {
@@ -235,16 +272,23 @@ impl Manager {
             peer.set_gossip_port(peer_addr.port() as i32);
             server.member_list.add_initial_member(peer);
         }
+        let mut timing = Timing::default();
+        if let Some(ttl) = cfg.rumor_ttl {
+            timing.rumor_ttl_ms = (ttl * 1000) as i64;
+        }
         Ok(Manager {
             updater: ServiceUpdater::new(server.clone()),
             census_ring: CensusRing::new(sys.member_id.clone()),
             butterfly: server,
+            butterfly_timing: timing,
             services: services,
             watcher: SpecWatcher::run(&fs_cfg.specs_path)?,
             fs_cfg: Arc::new(fs_cfg),
+            http_auth_token: cfg.http_auth_token,
             organization: cfg.organization,
             service_states: HashMap::new(),
             sys: Arc::new(sys),
+            waiting_on_binds: HashMap::new(),
         })
     }
 
@@ -285,11 +329,20 @@ impl Manager {
     }
 
     pub fn spec_path_for(cfg: &ManagerConfig, spec: &ServiceSpec) -> PathBuf {
-        Self::specs_path(&Self::state_path_from(cfg)).join(spec.file_name())
+        FsCfg::new(Self::state_path_from(cfg)).spec_path_for(spec)
     }
 
     pub fn save_spec_for(cfg: &ManagerConfig, spec: ServiceSpec) -> Result<()> {
-        spec.to_file(Self::spec_path_for(cfg, &spec))
+        FsCfg::new(Self::state_path_from(cfg)).save_spec(&spec)
+    }
+
+    /// Returns an error if loading the given spec would make the services loaded on the
+    /// Supervisor bind to each other in a cycle.
+    pub fn check_bind_cycles_for(cfg: &ManagerConfig, spec: &ServiceSpec) -> Result<()> {
+        FsCfg::new(Self::state_path_from(cfg)).check_bind_cycles(
+            spec,
+            cfg.organization.as_ref().map(|org| &**org),
+        )
     }
 
     fn clean_dirty_state<T>(state_path: T) -> Result<()>
@@ -365,6 +418,13 @@ impl Manager {
 
     fn add_service(&mut self, spec: ServiceSpec) {
         outputln!("Starting {}", &spec.ident);
+        if let Err(err) = self.check_bind_cycles(&spec) {
+            outputln!("Unable to start {}, {}", &spec.ident, err);
+            if spec.start_style == StartStyle::Transient {
+                self.remove_spec(&spec);
+            }
+            return;
+        }
         // JW TODO: This clone sucks, but our data structures are a bit messy here. What we really
         // want is the service to hold the spec and, on failure, return an error with the spec
         // back to us. Since we consume and deconstruct the spec in `Service::new()` which
@@ -415,6 +475,15 @@ impl Manager {
             .push(service);
     }
 
+    /// Returns an error if starting the service described by the given spec would make the
+    /// services running on this Supervisor bind to each other in a cycle.
+    fn check_bind_cycles(&self, spec: &ServiceSpec) -> Result<()> {
+        let services = self.services.read().expect("Services lock is poisoned!");
+        let mut graph = bind_graph(&services);
+        graph.add_spec(spec, self.organization.as_ref().map(|org| &**org))?;
+        graph.check_cycles()
+    }
+
     fn remove_service(&self, service: &mut Service) {
         // JW TODO: Update service rumor to remove service from cluster
         service.stop();
@@ -448,13 +517,16 @@ impl Manager {
             "Starting gossip-listener on {}",
             self.butterfly.gossip_addr()
         );
-        self.butterfly.start(Timing::default())?;
+        self.butterfly.start(self.butterfly_timing.clone())?;
         debug!("gossip-listener started");
         self.persist_state();
         let http_listen_addr = self.sys.http_listen();
         outputln!("Starting http-gateway on {}", &http_listen_addr);
-        http_gateway::Server::new(self.fs_cfg.clone(), http_listen_addr)
-            .start()?;
+        http_gateway::Server::new(
+            self.fs_cfg.clone(),
+            http_listen_addr,
+            self.http_auth_token.clone(),
+        ).start()?;
         debug!("http-gateway started");
 
         let (event_tx, event_rx) = channel::<Vec<CensusEntryProto>>();
@@ -549,15 +621,30 @@ impl Manager {
                 }
             }
 
-            for service in self.services
-                .write()
-                .expect("Services lock is poisoned!")
-                .iter_mut()
-            {
+            let mut services = self.services.write().expect("Services lock is poisoned!");
+            let waiting = waiting_on_local_binds(&services);
+            for (service, waiting_on) in services.iter_mut().zip(waiting) {
+                let changed = note_bind_wait(
+                    &mut self.waiting_on_binds,
+                    &service.service_group,
+                    waiting_on.as_ref(),
+                );
+                if let Some(dep) = waiting_on {
+                    if changed {
+                        outputln!(preamble service.service_group,
+                                  "Waiting for bound service group '{}' to become healthy",
+                                  dep);
+                    }
+                    continue;
+                }
                 if service.tick(&self.census_ring) {
                     self.gossip_latest_service_rumor(&service);
                 }
             }
+            self.waiting_on_binds.retain(|service_group, _| {
+                services.iter().any(|s| &s.service_group == service_group)
+            });
+            drop(services);
             let time_to_wait = (next_check - time::get_time()).num_milliseconds();
             if time_to_wait > 0 {
                 thread::sleep(Duration::from_millis(time_to_wait as u64));
@@ -575,11 +662,9 @@ impl Manager {
     fn check_for_incoming_signals(&mut self) -> bool {
         match signals::check_for_signal() {
             Some(SignalEvent::Shutdown) => {
-                for service in self.services
-                    .write()
-                    .expect("Services lock is poisoned!")
-                    .iter_mut()
-                {
+                let mut services = self.services.write().expect("Services lock is poisoned!");
+                for idx in shutdown_order(&services) {
+                    let service = &mut services[idx];
                     outputln!("Shutting down {}", service);
                     service.down().unwrap_or_else(|err| {
                         outputln!("Failed to shutdown {}: {}", service, err)
@@ -827,9 +912,10 @@ impl Manager {
 
     fn shutdown(&self) {
         let mut services = self.services.write().expect("Services lock is poisend!");
-        for mut service in services.drain(..) {
-            self.remove_service(&mut service);
+        for idx in shutdown_order(&services) {
+            self.remove_service(&mut services[idx]);
         }
+        services.clear();
         release_process_lock(&self.fs_cfg);
         outputln!("Hasta la vista, services.");
     }
@@ -967,6 +1053,90 @@ impl Suitability for SuitabilityLookup {
     }
 }
 
+/// Returns the indices of the given services in the order they should be stopped: a service
+/// which binds to another service running on this Supervisor is stopped before it.
+fn shutdown_order(services: &[Service]) -> Vec<usize> {
+    dependents_first(services.len(), |dependent, dependency| {
+        dependent != dependency &&
+            services[dependent].binds_to(&services[dependency].service_group)
+    })
+}
+
+/// Orders `count` nodes so that every node comes before the nodes it depends on, as reported by
+/// `depends_on(dependent, dependency)`. Nodes taking part in a dependency cycle can't be ordered
+/// and are returned last, in their original order.
+fn dependents_first<F>(count: usize, depends_on: F) -> Vec<usize>
+where
+    F: Fn(usize, usize) -> bool,
+{
+    let mut remaining: Vec<usize> = (0..count).collect();
+    let mut order = Vec::with_capacity(count);
+    loop {
+        let next = remaining.iter().position(|&candidate| {
+            !remaining.iter().any(|&other| depends_on(other, candidate))
+        });
+        match next {
+            Some(idx) => order.push(remaining.remove(idx)),
+            None => break,
+        }
+    }
+    order.extend(remaining);
+    order
+}
+
+/// Builds the dependency graph of the given services. Each service's index in the graph is its
+/// index in the given slice.
+fn bind_graph(services: &[Service]) -> BindGraph {
+    let mut graph = BindGraph::new();
+    for service in services {
+        graph.add(
+            service.service_group.clone(),
+            service.bound_service_groups(),
+        );
+    }
+    graph
+}
+
+/// Returns, for each of the given services which must wait before starting because a service it
+/// binds to on this Supervisor is not healthy yet, the service group it waits on.
+fn waiting_on_local_binds(services: &[Service]) -> Vec<Option<ServiceGroup>> {
+    let graph = bind_graph(services);
+    services
+        .iter()
+        .enumerate()
+        .map(|(idx, service)| {
+            if service.is_initialized() {
+                return None;
+            }
+            graph
+                .dependencies(idx)
+                .into_iter()
+                .find(|&dep| services[dep].health_check() != HealthCheck::Ok)
+                .map(|dep| graph.service_group(dep).clone())
+        })
+        .collect()
+}
+
+/// Records which bound service group, if any, the given service is waiting on. Returns true if
+/// that changed since the last time it was recorded, so the wait is only reported once rather
+/// than on every tick.
+fn note_bind_wait(
+    waiting: &mut HashMap<ServiceGroup, ServiceGroup>,
+    service_group: &ServiceGroup,
+    waiting_on: Option<&ServiceGroup>,
+) -> bool {
+    match waiting_on {
+        Some(dep) => {
+            if waiting.get(service_group) == Some(dep) {
+                return false;
+            }
+            waiting.insert(service_group.clone(), dep.clone());
+            true
+        }
+        None => waiting.remove(service_group).is_some(),
+    }
+}
+
 fn deserialize_time<'de, D>(d: D) -> result::Result<TimeDuration, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -1072,9 +1242,13 @@ where
 
 #[cfg(test)]
 mod test {
+    use std::collections::HashMap;
     use std::path::PathBuf;
+    use std::str::FromStr;
+
+    use hcore::service::ServiceGroup;
 
-    use super::{Manager, ManagerConfig, STATE_PATH_PREFIX};
+    use super::{dependents_first, note_bind_wait, Manager, ManagerConfig, STATE_PATH_PREFIX};
 
     #[test]
     fn manager_state_path_default() {
@@ -1117,4 +1291,44 @@ mod test {
 
         assert_eq!(PathBuf::from("/tmp/partay"), path);
     }
+
+    #[test]
+    fn dependents_first_orders_dependents_before_dependencies() {
+        // 0 binds to 1, 1 binds to 2
+        let deps = vec![(0, 1), (1, 2)];
+        let order = dependents_first(3, |a, b| deps.contains(&(a, b)));
+
+        assert_eq!(vec![0, 1, 2], order);
+
+        // 2 binds to 1, 1 binds to 0
+        let deps = vec![(2, 1), (1, 0)];
+        let order = dependents_first(3, |a, b| deps.contains(&(a, b)));
+
+        assert_eq!(vec![2, 1, 0], order);
+    }
+
+    #[test]
+    fn dependents_first_places_cycles_last() {
+        // 0 and 1 bind to each other, 2 binds to 0
+        let deps = vec![(0, 1), (1, 0), (2, 0)];
+        let order = dependents_first(3, |a, b| deps.contains(&(a, b)));
+
+        assert_eq!(vec![2, 0, 1], order);
+    }
+
+    #[test]
+    fn note_bind_wait_reports_only_changes() {
+        let mut waiting = HashMap::new();
+        let app = ServiceGroup::from_str("app.default").unwrap();
+        let redis = ServiceGroup::from_str("redis.default").unwrap();
+        let db = ServiceGroup::from_str("db.default").unwrap();
+
+        assert!(!note_bind_wait(&mut waiting, &app, None));
+        assert!(note_bind_wait(&mut waiting, &app, Some(&redis)));
+        assert!(!note_bind_wait(&mut waiting, &app, Some(&redis)));
+        assert!(note_bind_wait(&mut waiting, &app, Some(&db)));
+        assert!(note_bind_wait(&mut waiting, &app, None));
+        assert!(!note_bind_wait(&mut waiting, &app, None));
+        assert!(waiting.is_empty());
+    }
 }
@@ -12,7 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::fmt;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::result;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use butterfly::rumor::service::Service_Health;
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "HC";
+const DEFAULT_PROBE_HOST: &'static str = "127.0.0.1";
+const DEFAULT_PROBE_PATH: &'static str = "/";
+const PROBE_TIMEOUT_MS: u64 = 5_000;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
 pub enum HealthCheck {
@@ -40,6 +56,28 @@ impl From<i8> for HealthCheck {
     }
 }
 
+impl From<Service_Health> for HealthCheck {
+    fn from(value: Service_Health) -> HealthCheck {
+        match value {
+            Service_Health::OK => HealthCheck::Ok,
+            Service_Health::WARNING => HealthCheck::Warning,
+            Service_Health::CRITICAL => HealthCheck::Critical,
+            Service_Health::UNKNOWN => HealthCheck::Unknown,
+        }
+    }
+}
+
+impl From<HealthCheck> for Service_Health {
+    fn from(value: HealthCheck) -> Service_Health {
+        match value {
+            HealthCheck::Ok => Service_Health::OK,
+            HealthCheck::Warning => Service_Health::WARNING,
+            HealthCheck::Critical => Service_Health::CRITICAL,
+            HealthCheck::Unknown => Service_Health::UNKNOWN,
+        }
+    }
+}
+
 impl fmt::Display for HealthCheck {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
@@ -75,3 +113,348 @@ impl fmt::Display for SmokeCheck {
         write!(f, "{}", msg)
     }
 }
+
+/// A built-in health check declared with the `pkg_health_check` plan variable. The Supervisor
+/// runs it in place of a `health_check` hook when the package doesn't provide one.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub enum HealthProbe {
+    /// Healthy when a TCP connection to the port can be established.
+    Tcp { host: String, port: u16 },
+    /// Healthy when a `GET` request for the path is answered with a 2xx status code.
+    Http { host: String, port: u16, path: String },
+}
+
+impl HealthProbe {
+    /// Builds the probe described by the settings of a package's `HEALTH_CHECK` metafile, or
+    /// `None` if the package doesn't declare one.
+    pub fn from_metadata(settings: &HashMap<String, String>) -> Result<Option<Self>> {
+        if settings.is_empty() {
+            return Ok(None);
+        }
+        let port = match settings.get("port") {
+            Some(port) => {
+                port.parse::<u16>().map_err(|_| {
+                    sup_error!(Error::InvalidHealthCheck(
+                        format!("'{}' is not a valid port", port),
+                    ))
+                })?
+            }
+            None => {
+                return Err(sup_error!(
+                    Error::InvalidHealthCheck(String::from("a port is required"))
+                ))
+            }
+        };
+        let host = settings.get("host").cloned().unwrap_or(
+            DEFAULT_PROBE_HOST.to_string(),
+        );
+        match settings.get("type").map(String::as_ref) {
+            Some("tcp") => Ok(Some(HealthProbe::Tcp {
+                host: host,
+                port: port,
+            })),
+            Some("http") => {
+                let path = settings.get("path").cloned().unwrap_or(
+                    DEFAULT_PROBE_PATH.to_string(),
+                );
+                if !path.starts_with('/') {
+                    return Err(sup_error!(Error::InvalidHealthCheck(
+                        format!("path '{}' must start with a '/'", path),
+                    )));
+                }
+                Ok(Some(HealthProbe::Http {
+                    host: host,
+                    port: port,
+                    path: path,
+                }))
+            }
+            Some(kind) => Err(sup_error!(Error::InvalidHealthCheck(
+                format!("unknown type '{}', must be one of 'tcp' or 'http'", kind),
+            ))),
+            None => Err(sup_error!(
+                Error::InvalidHealthCheck(String::from("a type is required"))
+            )),
+        }
+    }
+
+    /// Runs the probe, returning the reason it failed if the service isn't healthy.
+    pub fn check(&self) -> result::Result<(), String> {
+        match *self {
+            HealthProbe::Tcp { ref host, port } => {
+                connect(host, port).map(|_| ()).map_err(|e| e.to_string())
+            }
+            HealthProbe::Http {
+                ref host,
+                port,
+                ref path,
+            } => {
+                match http_status(host, port, path) {
+                    Ok(status) if status >= 200 && status < 300 => Ok(()),
+                    Ok(status) => Err(format!("responded with status {}", status)),
+                    Err(e) => Err(e.to_string()),
+                }
+            }
+        }
+    }
+}
+
+/// A health probe running on a thread of its own, so that probing a slow service can't hold up
+/// the manager. Its result is picked up on a later tick.
+#[derive(Debug)]
+pub struct ProbeRun {
+    probe: HealthProbe,
+    rx: mpsc::Receiver<result::Result<(), String>>,
+}
+
+impl ProbeRun {
+    pub fn start(probe: &HealthProbe) -> io::Result<ProbeRun> {
+        let (tx, rx) = mpsc::channel();
+        let run_probe = probe.clone();
+        thread::Builder::new()
+            .name(format!("health-probe-{}", probe))
+            .spawn(move || {
+                let _ = tx.send(run_probe.check());
+            })?;
+        Ok(ProbeRun {
+            probe: probe.clone(),
+            rx: rx,
+        })
+    }
+
+    pub fn probe(&self) -> &HealthProbe {
+        &self.probe
+    }
+
+    /// Returns the result of the probe once it has finished, without waiting for it.
+    pub fn result(&self) -> Option<result::Result<(), String>> {
+        match self.rx.try_recv() {
+            Ok(result) => Some(result),
+            Err(mpsc::TryRecvError::Empty) => None,
+            Err(mpsc::TryRecvError::Disconnected) => {
+                Some(Err(String::from("the probe stopped without a result")))
+            }
+        }
+    }
+}
+
+impl fmt::Display for HealthProbe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HealthProbe::Tcp { ref host, port } => write!(f, "tcp://{}:{}", host, port),
+            HealthProbe::Http {
+                ref host,
+                port,
+                ref path,
+            } => write!(f, "http://{}:{}{}", host, port, path),
+        }
+    }
+}
+
+/// Connects to the probe's address, giving up after `PROBE_TIMEOUT_MS`. Resolving the host and
+/// connecting happen on a thread of their own, so that an unresponsive address can't hold up
+/// the manager for longer than that.
+fn connect(host: &str, port: u16) -> io::Result<TcpStream> {
+    let (tx, rx) = mpsc::channel();
+    let target = host.to_string();
+    thread::Builder::new()
+        .name(format!("health-probe-{}:{}", host, port))
+        .spawn(move || {
+            // Nobody is waiting for the result anymore if we took too long
+            let _ = tx.send(TcpStream::connect((target.as_str(), port)));
+        })?;
+    let stream = match rx.recv_timeout(Duration::from_millis(PROBE_TIMEOUT_MS)) {
+        Ok(result) => result?,
+        Err(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                format!("timed out connecting to {}:{}", host, port),
+            ))
+        }
+    };
+    stream.set_read_timeout(
+        Some(Duration::from_millis(PROBE_TIMEOUT_MS)),
+    )?;
+    stream.set_write_timeout(
+        Some(Duration::from_millis(PROBE_TIMEOUT_MS)),
+    )?;
+    Ok(stream)
+}
+
+/// Sends a bare HTTP/1.0 `GET` request and returns the status code of the response.
+fn http_status(host: &str, port: u16, path: &str) -> io::Result<u16> {
+    let mut stream = connect(host, port)?;
+    write!(
+        stream,
+        "GET {} HTTP/1.0\r\nHost: {}:{}\r\nUser-Agent: hab-sup\r\nConnection: close\r\n\r\n",
+        path,
+        host,
+        port
+    )?;
+    stream.flush()?;
+    let mut status_line = String::new();
+    BufReader::new(stream).read_line(&mut status_line)?;
+    status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("malformed status line '{}'", status_line.trim()),
+            )
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use super::{HealthProbe, ProbeRun, PROBE_TIMEOUT_MS};
+    use error::Error;
+
+    fn settings(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|&(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    /// Serves a single request with the given status line and returns the port it listens on.
+    fn serve_once(status_line: &'static str) -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                line.clear();
+            }
+            write!(reader.get_mut(), "{}\r\n\r\n", status_line).unwrap();
+        });
+        port
+    }
+
+    #[test]
+    fn from_metadata_without_settings() {
+        assert_eq!(HealthProbe::from_metadata(&HashMap::new()).unwrap(), None);
+    }
+
+    #[test]
+    fn from_metadata_with_defaults() {
+        let probe = HealthProbe::from_metadata(&settings(&[("type", "http"), ("port", "8080")]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            probe,
+            HealthProbe::Http {
+                host: String::from("127.0.0.1"),
+                port: 8080,
+                path: String::from("/"),
+            }
+        );
+        assert_eq!(probe.to_string(), "http://127.0.0.1:8080/");
+    }
+
+    #[test]
+    fn from_metadata_with_bad_settings() {
+        let bad = vec![
+            settings(&[("type", "tcp")]),
+            settings(&[("type", "tcp"), ("port", "http")]),
+            settings(&[("type", "udp"), ("port", "53")]),
+            settings(&[("type", "http"), ("port", "80"), ("path", "health")]),
+            settings(&[("port", "80")]),
+        ];
+        for s in bad {
+            match HealthProbe::from_metadata(&s) {
+                Err(e) => {
+                    match e.err {
+                        Error::InvalidHealthCheck(_) => (),
+                        wrong => panic!("Unexpected error returned: {:?}", wrong),
+                    }
+                }
+                Ok(probe) => panic!("Settings {:?} should be invalid, got {:?}", s, probe),
+            }
+        }
+    }
+
+    #[test]
+    fn tcp_check() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let probe = HealthProbe::Tcp {
+            host: String::from("127.0.0.1"),
+            port: port,
+        };
+        assert!(probe.check().is_ok());
+        drop(listener);
+        assert!(probe.check().is_err());
+    }
+
+    #[test]
+    fn probe_run_reports_back_without_blocking() {
+        let probe = HealthProbe::Http {
+            host: String::from("127.0.0.1"),
+            port: serve_once("HTTP/1.1 200 OK"),
+            path: String::from("/health"),
+        };
+        let run = ProbeRun::start(&probe).unwrap();
+        let started = Instant::now();
+        let mut result = run.result();
+        while result.is_none() {
+            assert!(started.elapsed() < Duration::from_millis(PROBE_TIMEOUT_MS * 2));
+            thread::sleep(Duration::from_millis(10));
+            result = run.result();
+        }
+        assert_eq!(result, Some(Ok(())));
+        assert_eq!(run.probe(), &probe);
+    }
+
+    #[test]
+    fn http_check_ok() {
+        let probe = HealthProbe::Http {
+            host: String::from("127.0.0.1"),
+            port: serve_once("HTTP/1.1 200 OK"),
+            path: String::from("/health"),
+        };
+        assert!(probe.check().is_ok());
+    }
+
+    #[test]
+    fn http_check_error_status() {
+        let probe = HealthProbe::Http {
+            host: String::from("127.0.0.1"),
+            port: serve_once("HTTP/1.1 503 Service Unavailable"),
+            path: String::from("/health"),
+        };
+        assert_eq!(
+            probe.check(),
+            Err(String::from("responded with status 503"))
+        );
+    }
+
+    #[test]
+    fn http_check_gives_up_on_a_silent_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            // Hold the connection open without ever responding
+            let (stream, _) = listener.accept().unwrap();
+            thread::sleep(Duration::from_millis(PROBE_TIMEOUT_MS * 2));
+            drop(stream);
+        });
+        let probe = HealthProbe::Http {
+            host: String::from("127.0.0.1"),
+            port: port,
+            path: String::from("/health"),
+        };
+        let started = Instant::now();
+        assert!(probe.check().is_err());
+        assert!(started.elapsed() < Duration::from_millis(PROBE_TIMEOUT_MS * 2));
+        server.join().unwrap();
+    }
+}
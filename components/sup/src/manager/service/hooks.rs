@@ -27,6 +27,7 @@ use std::result;
 use ansi_term::Colour;
 use hcore;
 use hcore::service::ServiceGroup;
+use prometheus::{CounterVec, HistogramVec};
 use serde::{Serialize, Serializer};
 
 use super::{exec, health, Pkg};
@@ -37,6 +38,20 @@ use templating::{RenderContext, TemplateRenderer};
 pub const HOOK_PERMISSIONS: u32 = 0o755;
 static LOGKEY: &'static str = "HK";
 
+lazy_static! {
+    static ref HOOK_DURATION: HistogramVec = register_histogram_vec!(
+        histogram_opts!(
+            "hab_sup_hook_duration_seconds",
+            "Hook execution time in seconds."),
+        &["service_group", "hook"]).unwrap();
+
+    static ref HOOK_EXITS: CounterVec = register_counter_vec!(
+        opts!(
+            "hab_sup_hook_exits_total",
+            "Total number of hook executions by exit code."),
+        &["service_group", "hook", "exit_code"]).unwrap();
+}
+
 pub fn stdout_log_path<T>(service_group: &ServiceGroup) -> PathBuf
 where
     T: Hook,
@@ -118,6 +133,10 @@ pub trait Hook: fmt::Debug + Sized {
         pkg: &Pkg,
         svc_encrypted_password: Option<&str>,
     ) -> Self::ExitValue {
+        let service_group_label = service_group.to_string();
+        let timer = HOOK_DURATION
+            .with_label_values(&[&service_group_label, Self::file_name()])
+            .start_timer();
         let mut child = match exec::run_cmd(self.path(), &pkg, svc_encrypted_password) {
             Ok(child) => child,
             Err(err) => {
@@ -129,7 +148,17 @@ pub trait Hook: fmt::Debug + Sized {
         let mut hook_output = HookOutput::new(self.stdout_log_path(), self.stderr_log_path());
         hook_output.stream_output::<Self>(service_group, &mut child);
         match child.wait() {
-            Ok(status) => self.handle_exit(service_group, &hook_output, &status),
+            Ok(status) => {
+                timer.observe_duration();
+                let exit_code = status.code().map_or(
+                    String::from("none"),
+                    |code| code.to_string(),
+                );
+                HOOK_EXITS
+                    .with_label_values(&[&service_group_label, Self::file_name(), &exit_code])
+                    .inc();
+                self.handle_exit(service_group, &hook_output, &status)
+            }
             Err(err) => {
                 outputln!(preamble service_group,
                     "Hook failed to run, {}, {}", Self::file_name(), err);
@@ -442,6 +471,116 @@ impl Hook for PostRunHook {
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct PostStopHook {
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+}
+
+impl Hook for PostStopHook {
+    type ExitValue = ExitCode;
+
+    fn file_name() -> &'static str {
+        "post-stop"
+    }
+
+    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+        PostStopHook {
+            render_pair: pair,
+            stdout_log_path: stdout_log_path::<Self>(service_group),
+            stderr_log_path: stderr_log_path::<Self>(service_group),
+        }
+    }
+
+    fn handle_exit<'a>(
+        &self,
+        service_group: &ServiceGroup,
+        _: &'a HookOutput,
+        status: &ExitStatus,
+    ) -> Self::ExitValue {
+        match status.code() {
+            Some(code) => ExitCode(code),
+            None => {
+                outputln!(preamble service_group,
+                    "{} exited without a status code", Self::file_name());
+                ExitCode::default()
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    fn renderer(&self) -> &TemplateRenderer {
+        &self.render_pair.renderer
+    }
+
+    fn stdout_log_path(&self) -> &Path {
+        &self.stdout_log_path
+    }
+
+    fn stderr_log_path(&self) -> &Path {
+        &self.stderr_log_path
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PreStopHook {
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+}
+
+impl Hook for PreStopHook {
+    type ExitValue = ExitCode;
+
+    fn file_name() -> &'static str {
+        "pre-stop"
+    }
+
+    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+        PreStopHook {
+            render_pair: pair,
+            stdout_log_path: stdout_log_path::<Self>(service_group),
+            stderr_log_path: stderr_log_path::<Self>(service_group),
+        }
+    }
+
+    fn handle_exit<'a>(
+        &self,
+        service_group: &ServiceGroup,
+        _: &'a HookOutput,
+        status: &ExitStatus,
+    ) -> Self::ExitValue {
+        match status.code() {
+            Some(code) => ExitCode(code),
+            None => {
+                outputln!(preamble service_group,
+                    "{} exited without a status code", Self::file_name());
+                ExitCode::default()
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    fn renderer(&self) -> &TemplateRenderer {
+        &self.render_pair.renderer
+    }
+
+    fn stdout_log_path(&self) -> &Path {
+        &self.stdout_log_path
+    }
+
+    fn stderr_log_path(&self) -> &Path {
+        &self.stderr_log_path
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct ReloadHook {
     render_pair: RenderPair,
@@ -709,6 +848,8 @@ pub struct HookTable {
     pub suitability: Option<SuitabilityHook>,
     pub run: Option<RunHook>,
     pub post_run: Option<PostRunHook>,
+    pub pre_stop: Option<PreStopHook>,
+    pub post_stop: Option<PostStopHook>,
     pub smoke_test: Option<SmokeTestHook>,
 }
 
@@ -730,6 +871,8 @@ impl HookTable {
                 table.reconfigure = ReconfigureHook::load(service_group, &hooks, &templates);
                 table.run = RunHook::load(service_group, &hooks, &templates);
                 table.post_run = PostRunHook::load(service_group, &hooks, &templates);
+                table.pre_stop = PreStopHook::load(service_group, &hooks, &templates);
+                table.post_stop = PostStopHook::load(service_group, &hooks, &templates);
                 table.smoke_test = SmokeTestHook::load(service_group, &hooks, &templates);
             }
         }
@@ -768,6 +911,12 @@ impl HookTable {
         if let Some(ref hook) = self.post_run {
             self.compile_one(hook, service_group, ctx);
         }
+        if let Some(ref hook) = self.pre_stop {
+            self.compile_one(hook, service_group, ctx);
+        }
+        if let Some(ref hook) = self.post_stop {
+            self.compile_one(hook, service_group, ctx);
+        }
         if let Some(ref hook) = self.smoke_test {
             self.compile_one(hook, service_group, ctx);
         }
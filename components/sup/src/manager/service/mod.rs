@@ -36,10 +36,11 @@ use butterfly::rumor::service::Service as ServiceRumor;
 use common::ui::UI;
 use hcore::crypto::hash;
 use hcore::os::process;
-use hcore::package::{PackageIdent, PackageInstall};
+use hcore::package::{Lockfile, PackageIdent, PackageInstall};
 use hcore::service::ServiceGroup;
 use hcore::util::deserialize_using_from_str;
 use hcore::util::perm::{set_owner, set_permissions};
+use prometheus::GaugeVec;
 use serde;
 use time::Timespec;
 
@@ -55,7 +56,7 @@ use templating::RenderContext;
 use util;
 
 pub use self::config::Cfg;
-pub use self::health::{HealthCheck, SmokeCheck};
+pub use self::health::{HealthCheck, HealthProbe, ProbeRun, SmokeCheck};
 pub use self::package::Pkg;
 pub use self::spec::{DesiredState, ServiceBind, ServiceSpec, StartStyle};
 pub use self::supervisor::ProcessState;
@@ -63,9 +64,11 @@ pub use self::supervisor::ProcessState;
 static LOGKEY: &'static str = "SR";
 
 lazy_static! {
-    static ref HEALTH_CHECK_INTERVAL: Duration = {
-        Duration::from_millis(30_000)
-    };
+    static ref HEALTH_CHECK_STATUS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_sup_health_check_status",
+            "Result of the last health check: 0 is ok, 1 warning, 2 critical and 3 unknown."),
+        &["service_group"]).unwrap();
 }
 
 #[derive(Debug, Serialize)]
@@ -78,6 +81,11 @@ pub struct Service {
     pub start_style: StartStyle,
     pub topology: Topology,
     pub update_strategy: UpdateStrategy,
+    pub canary_percent: u8,
+    pub canary_soak_period: u64,
+    pub stop_timeout: u64,
+    pub health_check_interval: u64,
+    pub lockfile: Option<Lockfile>,
     pub cfg: Cfg,
     pub pkg: Pkg,
     pub sys: Arc<Sys>,
@@ -93,8 +101,12 @@ pub struct Service {
     binds: Vec<ServiceBind>,
     hooks: HookTable,
     config_from: Option<PathBuf>,
+    /// When the health check last ran; `None` until the first one is due
     #[serde(skip_serializing)]
-    last_health_check: Instant,
+    last_health_check: Option<Instant>,
+    /// The health probe which is still running, if any
+    #[serde(skip_serializing)]
+    health_probe_run: Option<ProbeRun>,
     manager_fs_cfg: Arc<manager::FsCfg>,
     #[serde(rename = "process")]
     supervisor: Supervisor,
@@ -128,7 +140,7 @@ impl Service {
             needs_reload: false,
             needs_reconfiguration: false,
             manager_fs_cfg: manager_fs_cfg,
-            supervisor: Supervisor::new(&service_group),
+            supervisor: Supervisor::new(&service_group, spec.stop_timeout),
             pkg: pkg,
             service_group: service_group,
             smoke_check: SmokeCheck::default(),
@@ -138,9 +150,15 @@ impl Service {
             start_style: spec.start_style,
             topology: spec.topology,
             update_strategy: spec.update_strategy,
+            canary_percent: spec.canary_percent,
+            canary_soak_period: spec.canary_soak_period,
+            stop_timeout: spec.stop_timeout,
+            health_check_interval: spec.health_check_interval,
             config_from: spec.config_from,
-            last_health_check: Instant::now() - *HEALTH_CHECK_INTERVAL,
+            last_health_check: None,
+            health_probe_run: None,
             svc_encrypted_password: spec.svc_encrypted_password,
+            lockfile: spec.lockfile,
         })
     }
 
@@ -240,9 +258,13 @@ impl Service {
     }
 
     pub fn stop(&mut self) {
+        let running = self.pre_stop();
         if let Err(err) = self.supervisor.stop() {
             outputln!(preamble self.service_group, "Service stop failed: {}", err);
         }
+        if running {
+            self.post_stop();
+        }
     }
 
     fn reload(&mut self) {
@@ -268,7 +290,12 @@ impl Service {
     }
 
     pub fn down(&mut self) -> Result<()> {
-        self.supervisor.down()
+        let running = self.pre_stop();
+        let result = self.supervisor.down();
+        if running {
+            self.post_stop();
+        }
+        result
     }
 
     pub fn send_signal(&self, signal: process::Signal) -> Result<()> {
@@ -292,11 +319,17 @@ impl Service {
         self.supervisor.check_process()
     }
 
+    /// Returns the result of the most recent health check of this service.
+    pub fn health_check(&self) -> HealthCheck {
+        self.health_check
+    }
+
     pub fn last_state_change(&self) -> Timespec {
         self.supervisor.state_entered
     }
 
     pub fn tick(&mut self, census_ring: &CensusRing) -> bool {
+        let last_health_check = self.health_check;
         if !self.initialized {
             if !self.all_binds_satisfied(census_ring) {
                 outputln!(preamble self.service_group, "Waiting for service binds...");
@@ -358,7 +391,8 @@ impl Service {
                 }
             }
         }
-        svc_updated
+        // A change in health is gossiped just like a change in configuration.
+        svc_updated || self.health_check != last_health_check
     }
 
     pub fn to_spec(&self) -> ServiceSpec {
@@ -368,9 +402,14 @@ impl Service {
         spec.channel = self.channel.clone();
         spec.topology = self.topology;
         spec.update_strategy = self.update_strategy;
+        spec.canary_percent = self.canary_percent;
+        spec.canary_soak_period = self.canary_soak_period;
+        spec.stop_timeout = self.stop_timeout;
+        spec.health_check_interval = self.health_check_interval;
         spec.binds = self.binds.clone();
         spec.start_style = self.start_style;
         spec.config_from = self.config_from.clone();
+        spec.lockfile = self.lockfile.clone();
         spec
     }
 
@@ -428,6 +467,8 @@ impl Service {
                     &Self::hooks_root(&pkg, self.config_from.as_ref()),
                 );
                 self.pkg = pkg;
+                self.health_check = HealthCheck::default();
+                self.health_probe_run = None;
             }
             Err(err) => {
                 outputln!(preamble self.service_group,
@@ -460,6 +501,7 @@ impl Service {
             exported.as_ref(),
         );
         rumor.set_incarnation(incarnation);
+        rumor.set_health(self.health_check.into());
         rumor
     }
 
@@ -502,6 +544,52 @@ impl Service {
         }
     }
 
+    /// Run pre-stop hook if present and the service's process is running. Returns whether the
+    /// process was running, so that the post-stop hook only follows a real stop.
+    fn pre_stop(&self) -> bool {
+        if self.is_down() {
+            return false;
+        }
+        if let Some(ref hook) = self.hooks.pre_stop {
+            hook.run(
+                &self.service_group,
+                &self.pkg,
+                self.svc_encrypted_password.as_ref().map(String::as_ref),
+            );
+        }
+        true
+    }
+
+    fn post_stop(&self) {
+        if let Some(ref hook) = self.hooks.post_stop {
+            hook.run(
+                &self.service_group,
+                &self.pkg,
+                self.svc_encrypted_password.as_ref().map(String::as_ref),
+            );
+        }
+    }
+
+    /// Returns true if one of this service's binds points at the given service group.
+    pub fn binds_to(&self, service_group: &ServiceGroup) -> bool {
+        self.binds.iter().any(
+            |bind| &bind.service_group == service_group,
+        )
+    }
+
+    /// Returns the service groups this service binds to.
+    pub fn bound_service_groups(&self) -> Vec<ServiceGroup> {
+        self.binds
+            .iter()
+            .map(|bind| bind.service_group.clone())
+            .collect()
+    }
+
+    /// Returns true once the service has been initialized and its process started.
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
     pub fn suitability(&self) -> Option<u64> {
         if !self.initialized {
             return None;
@@ -626,7 +714,17 @@ impl Service {
             }
         } else {
             self.check_process();
-            if Instant::now().duration_since(self.last_health_check) >= *HEALTH_CHECK_INTERVAL {
+            self.check_health_probe();
+            // A probe which is still running has to finish before the next check starts
+            let health_check_due = self.health_probe_run.is_none() &&
+                match self.last_health_check {
+                    Some(last) => {
+                        Instant::now().duration_since(last) >=
+                            Duration::from_secs(self.health_check_interval)
+                    }
+                    None => true,
+                };
+            if health_check_due {
                 self.run_health_check_hook();
             }
 
@@ -706,13 +804,55 @@ impl Service {
                 &self.pkg,
                 self.svc_encrypted_password.as_ref().map(String::as_ref),
             )
+        } else if let Some(ref probe) = self.pkg.health_probe {
+            // The probe's result is picked up by `check_health_probe` once it's done
+            self.last_health_check = Some(Instant::now());
+            match ProbeRun::start(probe) {
+                Ok(run) => {
+                    self.health_probe_run = Some(run);
+                    return;
+                }
+                Err(err) => {
+                    outputln!(preamble self.service_group,
+                              "Unable to start health probe {}, {}", probe, err);
+                    HealthCheck::Unknown
+                }
+            }
         } else {
             match self.supervisor.status() {
                 (true, _) => HealthCheck::Ok,
                 (false, _) => HealthCheck::Critical,
             }
         };
-        self.last_health_check = Instant::now();
+        self.last_health_check = Some(Instant::now());
+        self.set_health_check(check_result);
+    }
+
+    /// Records the result of the running health probe, if it has finished.
+    fn check_health_probe(&mut self) {
+        let check_result = match self.health_probe_run {
+            Some(ref run) => {
+                match run.result() {
+                    Some(Ok(())) => HealthCheck::Ok,
+                    Some(Err(reason)) => {
+                        outputln!(preamble self.service_group,
+                                  "Health probe {} failed, {}", run.probe(), reason);
+                        HealthCheck::Critical
+                    }
+                    None => return,
+                }
+            }
+            None => return,
+        };
+        self.health_probe_run = None;
+        self.set_health_check(check_result);
+    }
+
+    fn set_health_check(&mut self, check_result: HealthCheck) {
+        HEALTH_CHECK_STATUS
+            .with_label_values(&[&self.service_group.to_string()])
+            .set(check_result as i8 as f64);
+        self.health_check = check_result;
         self.cache_health_check(check_result);
     }
 
@@ -844,6 +984,7 @@ pub enum UpdateStrategy {
     None,
     AtOnce,
     Rolling,
+    Canary,
 }
 
 impl UpdateStrategy {
@@ -852,6 +993,7 @@ impl UpdateStrategy {
             UpdateStrategy::None => "none",
             UpdateStrategy::AtOnce => "at-once",
             UpdateStrategy::Rolling => "rolling",
+            UpdateStrategy::Canary => "canary",
         }
     }
 }
@@ -864,6 +1006,7 @@ impl FromStr for UpdateStrategy {
             "none" => Ok(UpdateStrategy::None),
             "at-once" => Ok(UpdateStrategy::AtOnce),
             "rolling" => Ok(UpdateStrategy::Rolling),
+            "canary" => Ok(UpdateStrategy::Canary),
             _ => Err(sup_error!(
                 Error::InvalidUpdateStrategy(String::from(strategy))
             )),
@@ -989,6 +1132,14 @@ mod test {
         assert_eq!(strategy, UpdateStrategy::AtOnce);
     }
 
+    #[test]
+    fn update_strategy_from_str_canary() {
+        let strategy = UpdateStrategy::from_str("canary").unwrap();
+
+        assert_eq!(strategy, UpdateStrategy::Canary);
+        assert_eq!("canary", strategy.to_string());
+    }
+
     #[test]
     fn update_strategy_from_str_invalid() {
         let strategy_str = "dope";
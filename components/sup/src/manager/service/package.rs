@@ -23,6 +23,7 @@ use hcore::util::{deserialize_using_from_str, serialize_using_to_string};
 
 use error::{Error, Result};
 use fs;
+use super::health::HealthProbe;
 use util;
 
 const PATH_KEY: &'static str = "PATH";
@@ -90,6 +91,7 @@ pub struct Pkg {
     pub env: Env,
     pub exposes: Vec<String>,
     pub exports: HashMap<String, String>,
+    pub health_probe: Option<HealthProbe>,
     pub path: PathBuf,
     pub svc_path: PathBuf,
     pub svc_config_path: PathBuf,
@@ -127,6 +129,9 @@ impl Pkg {
             exports: package.exports().map_err(|e| {
                 sup_error!(Error::BadPackage(package.clone(), e))
             })?,
+            health_probe: HealthProbe::from_metadata(&package.health_check().map_err(|e| {
+                sup_error!(Error::BadPackage(package.clone(), e))
+            })?)?,
             path: package.installed_path,
             ident: package.ident.clone(),
             origin: package.ident.origin.clone(),
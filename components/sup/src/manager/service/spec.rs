@@ -21,7 +21,7 @@ use std::path::{Path, PathBuf};
 use std::result;
 use std::str::FromStr;
 
-use hcore::package::{PackageIdent, PackageInstall};
+use hcore::package::{Lockfile, PackageIdent, PackageInstall};
 use hcore::service::ServiceGroup;
 use hcore::url::DEFAULT_DEPOT_URL;
 use hcore::util::{deserialize_using_from_str, serialize_using_to_string};
@@ -34,6 +34,10 @@ use error::{Error, Result, SupError};
 
 static LOGKEY: &'static str = "SS";
 static DEFAULT_GROUP: &'static str = "default";
+const DEFAULT_CANARY_PERCENT: u8 = 10;
+const DEFAULT_CANARY_SOAK_PERIOD: u64 = 300;
+const DEFAULT_STOP_TIMEOUT: u64 = 8;
+const DEFAULT_HEALTH_CHECK_INTERVAL: u64 = 30;
 const SPEC_FILE_EXT: &'static str = "spec";
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -81,6 +85,16 @@ pub struct ServiceSpec {
     pub channel: Option<String>,
     pub topology: Topology,
     pub update_strategy: UpdateStrategy,
+    /// Percentage of the service group's alive members which are updated first by the canary
+    /// update strategy.
+    pub canary_percent: u8,
+    /// Seconds the canaries must stay healthy before the rest of the group is updated.
+    pub canary_soak_period: u64,
+    /// Seconds the service's process is given to exit after being asked to stop before it is
+    /// forcibly killed.
+    pub stop_timeout: u64,
+    /// Seconds between two runs of the service's health check.
+    pub health_check_interval: u64,
     pub binds: Vec<ServiceBind>,
     pub config_from: Option<PathBuf>,
     #[serde(deserialize_with = "deserialize_using_from_str",
@@ -90,6 +104,9 @@ pub struct ServiceSpec {
             serialize_with = "serialize_using_to_string")]
     pub start_style: StartStyle,
     pub svc_encrypted_password: Option<String>,
+    /// Pins the service and its dependencies to the exact releases of a lockfile, which also
+    /// keeps the service from being updated. Kept last as TOML tables must follow plain values.
+    pub lockfile: Option<Lockfile>,
 }
 
 impl ServiceSpec {
@@ -154,7 +171,12 @@ impl ServiceSpec {
     }
 
     pub fn file_name(&self) -> String {
-        format!("{}.{}", &self.ident.name, SPEC_FILE_EXT)
+        Self::file_name_for(&self.ident.name)
+    }
+
+    /// Returns the spec file name for a service with the given package name.
+    pub fn file_name_for(name: &str) -> String {
+        format!("{}.{}", name, SPEC_FILE_EXT)
     }
 
     pub fn validate(&self, package: &PackageInstall) -> Result<()> {
@@ -215,11 +237,16 @@ impl Default for ServiceSpec {
             channel: None,
             topology: Topology::default(),
             update_strategy: UpdateStrategy::default(),
+            canary_percent: DEFAULT_CANARY_PERCENT,
+            canary_soak_period: DEFAULT_CANARY_SOAK_PERIOD,
+            stop_timeout: DEFAULT_STOP_TIMEOUT,
+            health_check_interval: DEFAULT_HEALTH_CHECK_INTERVAL,
             binds: Vec::default(),
             config_from: None,
             desired_state: DesiredState::default(),
             start_style: StartStyle::default(),
             svc_encrypted_password: None,
+            lockfile: None,
         }
     }
 }
@@ -234,6 +261,10 @@ impl FromStr for ServiceSpec {
         if spec.ident == PackageIdent::default() {
             return Err(sup_error!(Error::MissingRequiredIdent));
         }
+        // The health check would run on every tick of the manager
+        if spec.health_check_interval == 0 {
+            return Err(sup_error!(Error::BadHealthCheckInterval));
+        }
         Ok(spec)
     }
 }
@@ -326,7 +357,7 @@ mod test {
     use std::path::{Path, PathBuf};
 
     use hcore::error::Error as HError;
-    use hcore::package::PackageIdent;
+    use hcore::package::{LockedPackage, Lockfile, PackageIdent};
     use hcore::service::ServiceGroup;
     use tempdir::TempDir;
     use toml;
@@ -361,7 +392,11 @@ mod test {
             group = "jobs"
             depot_url = "http://example.com/depot"
             topology = "leader"
-            update_strategy = "rolling"
+            update_strategy = "canary"
+            canary_percent = 20
+            canary_soak_period = 120
+            stop_timeout = 30
+            health_check_interval = 10
             binds = ["cache:redis.cache@acmecorp", "db:postgres.app@acmecorp"]
             start_style = "persistent"
             config_from = "/only/for/development"
@@ -377,7 +412,11 @@ mod test {
         assert_eq!(spec.group, String::from("jobs"));
         assert_eq!(spec.depot_url, String::from("http://example.com/depot"));
         assert_eq!(spec.topology, Topology::Leader);
-        assert_eq!(spec.update_strategy, UpdateStrategy::Rolling);
+        assert_eq!(spec.update_strategy, UpdateStrategy::Canary);
+        assert_eq!(spec.canary_percent, 20);
+        assert_eq!(spec.canary_soak_period, 120);
+        assert_eq!(spec.stop_timeout, 30);
+        assert_eq!(spec.health_check_interval, 10);
         assert_eq!(
             spec.binds,
             vec![
@@ -407,6 +446,24 @@ mod test {
         }
     }
 
+    #[test]
+    fn service_spec_from_str_zero_health_check_interval() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            health_check_interval = 0
+            "#;
+
+        match ServiceSpec::from_str(toml) {
+            Err(e) => {
+                match e.err {
+                    BadHealthCheckInterval => assert!(true),
+                    e => panic!("Unexpected error returned: {:?}", e),
+                }
+            }
+            Ok(_) => panic!("Spec TOML should fail to parse"),
+        }
+    }
+
     #[test]
     fn service_spec_from_str_invalid_topology() {
         let toml = r#"
@@ -453,6 +510,10 @@ mod test {
             channel: Some(String::from("stable")),
             topology: Topology::Leader,
             update_strategy: UpdateStrategy::AtOnce,
+            canary_percent: 25,
+            canary_soak_period: 600,
+            stop_timeout: 15,
+            health_check_interval: 5,
             binds: vec![
                 ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                 ServiceBind::from_str("db:postgres.app@acmecorp").unwrap(),
@@ -461,6 +522,7 @@ mod test {
             desired_state: DesiredState::Down,
             start_style: StartStyle::Persistent,
             svc_encrypted_password: None,
+            lockfile: None,
         };
         let toml = spec.to_toml_string().unwrap();
 
@@ -472,6 +534,10 @@ mod test {
         assert!(toml.contains(r#"channel = "stable""#));
         assert!(toml.contains(r#"topology = "leader""#));
         assert!(toml.contains(r#"update_strategy = "at-once""#));
+        assert!(toml.contains(r#"canary_percent = 25"#));
+        assert!(toml.contains(r#"canary_soak_period = 600"#));
+        assert!(toml.contains(r#"stop_timeout = 15"#));
+        assert!(toml.contains(r#"health_check_interval = 5"#));
         assert!(toml.contains(r#""cache:redis.cache@acmecorp""#));
         assert!(toml.contains(r#""db:postgres.app@acmecorp""#));
         assert!(toml.contains(r#"desired_state = "down""#));
@@ -479,6 +545,31 @@ mod test {
         assert!(toml.contains(r#"config_from = "/only/for/development""#));
     }
 
+    #[test]
+    fn service_spec_with_lockfile_round_trips_through_toml() {
+        let ident = PackageIdent::from_str("origin/name/1.2.3/20170223130020").unwrap();
+        let mut spec = ServiceSpec::default_for(PackageIdent::from_str("origin/name").unwrap());
+        spec.lockfile = Some(
+            Lockfile::new(
+                ident.clone(),
+                vec![
+                    LockedPackage {
+                        ident: PackageIdent::from_str("core/glibc/2.22/20170513201042").unwrap(),
+                        checksum: String::from("abc"),
+                    },
+                    LockedPackage {
+                        ident: ident,
+                        checksum: String::from("def"),
+                    },
+                ],
+            ).unwrap(),
+        );
+        let toml = spec.to_toml_string().unwrap();
+
+        assert!(toml.contains(r#"checksum = "def""#));
+        assert_eq!(ServiceSpec::from_str(&toml).unwrap(), spec);
+    }
+
     #[test]
     fn service_spec_to_toml_string_invalid_ident() {
         // Remember: the default implementation of `PackageIdent` is an invalid identifier, missing
@@ -596,6 +687,10 @@ mod test {
             channel: Some(String::from("stable")),
             topology: Topology::Leader,
             update_strategy: UpdateStrategy::AtOnce,
+            canary_percent: 25,
+            canary_soak_period: 600,
+            stop_timeout: 15,
+            health_check_interval: 5,
             binds: vec![
                 ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                 ServiceBind::from_str("db:postgres.app@acmecorp").unwrap(),
@@ -604,6 +699,7 @@ mod test {
             desired_state: DesiredState::Down,
             start_style: StartStyle::Persistent,
             svc_encrypted_password: None,
+            lockfile: None,
         };
         spec.to_file(&path).unwrap();
         let toml = string_from_file(path);
@@ -616,6 +712,10 @@ mod test {
         assert!(toml.contains(r#"channel = "stable""#));
         assert!(toml.contains(r#"topology = "leader""#));
         assert!(toml.contains(r#"update_strategy = "at-once""#));
+        assert!(toml.contains(r#"canary_percent = 25"#));
+        assert!(toml.contains(r#"canary_soak_period = 600"#));
+        assert!(toml.contains(r#"stop_timeout = 15"#));
+        assert!(toml.contains(r#"health_check_interval = 5"#));
         assert!(toml.contains(r#""cache:redis.cache@acmecorp""#));
         assert!(toml.contains(r#""db:postgres.app@acmecorp""#));
         assert!(toml.contains(r#"desired_state = "down""#));
@@ -649,6 +749,11 @@ mod test {
         assert_eq!(String::from("hoopa.spec"), spec.file_name());
     }
 
+    #[test]
+    fn service_spec_file_name_for() {
+        assert_eq!(String::from("hoopa.spec"), ServiceSpec::file_name_for("hoopa"));
+    }
+
     #[test]
     fn service_bind_from_str() {
         let bind_str = "name:service.group@organization";
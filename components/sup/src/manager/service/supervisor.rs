@@ -35,9 +35,10 @@ use ansi_term::Colour;
 use hcore::os::process::{HabChild, ExitStatusExt};
 use hcore::util::perm::set_owner;
 use hcore::service::ServiceGroup;
+use prometheus::CounterVec;
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
-use time::{self, Timespec};
+use time::{self, Duration, Timespec};
 
 use super::exec;
 use error::{Result, Error};
@@ -45,6 +46,26 @@ use manager::service::Pkg;
 
 static LOGKEY: &'static str = "SV";
 
+lazy_static! {
+    static ref PROCESS_STATE_TRANSITIONS: CounterVec = register_counter_vec!(
+        opts!(
+            "hab_sup_process_state_transitions_total",
+            "Total number of times a service's process entered each state."),
+        &["service_group", "state"]).unwrap();
+
+    static ref PROCESS_RESTARTS: CounterVec = register_counter_vec!(
+        opts!(
+            "hab_sup_process_restarts_total",
+            "Total number of times a service's process was restarted."),
+        &["service_group"]).unwrap();
+
+    static ref PROCESS_EXITS: CounterVec = register_counter_vec!(
+        opts!(
+            "hab_sup_process_exits_total",
+            "Total number of times a service's process exited on its own."),
+        &["service_group"]).unwrap();
+}
+
 #[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub enum ProcessState {
     Down,
@@ -73,10 +94,11 @@ pub struct Supervisor {
     pub state_entered: Timespec,
     pub has_started: bool,
     pid: Option<PathBuf>,
+    stop_timeout: u64,
 }
 
 impl Supervisor {
-    pub fn new(service_group: &ServiceGroup) -> Supervisor {
+    pub fn new(service_group: &ServiceGroup, stop_timeout: u64) -> Supervisor {
         Supervisor {
             child: None,
             preamble: format!("{}", service_group),
@@ -84,10 +106,16 @@ impl Supervisor {
             state_entered: time::get_time(),
             has_started: false,
             pid: None,
+            stop_timeout: stop_timeout,
         }
     }
 
     fn enter_state(&mut self, state: ProcessState) {
+        if self.state != state {
+            PROCESS_STATE_TRANSITIONS
+                .with_label_values(&[&self.preamble, &state.to_string()])
+                .inc();
+        }
         self.state = state;
         self.state_entered = time::get_time();
     }
@@ -143,12 +171,13 @@ impl Supervisor {
         Ok(())
     }
 
-    /// Send a SIGTERM to a process, wait 8 seconds, then send SIGKILL
+    /// Send a SIGTERM to a process, wait for the configured stop timeout, then send SIGKILL
     pub fn stop(&mut self) -> Result<()> {
         match self.child {
             Some(ref mut child) => {
                 outputln!(preamble & self.preamble, "Stopping...");
-                let shutdown = try!(child.kill());
+                let timeout = Duration::seconds(self.stop_timeout as i64);
+                let shutdown = try!(child.kill_with_timeout(timeout));
                 outputln!("{} - Shutdown method: {}", self.preamble, shutdown);
             }
             None => {}
@@ -166,6 +195,7 @@ impl Supervisor {
 
     pub fn restart(&mut self, pkg: &Pkg, svc_encrypted_password: Option<&str>) -> Result<()> {
         self.enter_state(ProcessState::Restart);
+        PROCESS_RESTARTS.with_label_values(&[&self.preamble]).inc();
         try!(self.stop());
         try!(self.start(pkg, svc_encrypted_password));
         Ok(())
@@ -207,6 +237,7 @@ impl Supervisor {
             match self.state {
                 ProcessState::Up | ProcessState::Start | ProcessState::Restart => {
                     outputln!("{} - Service exited", self.preamble);
+                    PROCESS_EXITS.with_label_values(&[&self.preamble]).inc();
                     self.child = None;
                 }
                 ProcessState::Down => {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TryRecvError};
 use std::thread;
@@ -22,7 +22,7 @@ use butterfly;
 use common::ui::UI;
 use depot_client;
 use env;
-use hcore::package::{PackageIdent, PackageInstall};
+use hcore::package::{PackageIdent, PackageInstall, PackageTarget};
 use hcore::service::ServiceGroup;
 use hcore::crypto::default_cache_key_path;
 use hcore::fs::{CACHE_ARTIFACT_PATH, FS_ROOT_PATH};
@@ -30,8 +30,8 @@ use time::{SteadyTime, Duration as TimeDuration};
 
 use {PRODUCT, VERSION};
 use error::Result;
-use census::CensusRing;
-use manager::service::{Service, Topology, UpdateStrategy};
+use census::{CensusMember, CensusRing};
+use manager::service::{HealthCheck, Service, Topology, UpdateStrategy};
 
 static LOGKEY: &'static str = "SU";
 const FREQUENCY_ENVVAR: &'static str = "HAB_UPDATE_STRATEGY_FREQUENCY_MS";
@@ -42,6 +42,7 @@ type UpdaterStateList = HashMap<ServiceGroup, UpdaterState>;
 enum UpdaterState {
     AtOnce(Receiver<PackageInstall>),
     Rolling(RollingState),
+    Canary(CanaryState),
 }
 
 enum RollingState {
@@ -61,6 +62,189 @@ enum FollowerState {
     Updating(Receiver<PackageInstall>),
 }
 
+enum CanaryState {
+    /// Polling the depot for a newer package. Holds the last package which failed a canary
+    /// rollout so that it isn't rolled out again.
+    Polling(Receiver<PackageInstall>, Option<PackageIdent>),
+    /// A newer package was found and is being rolled out across the service group.
+    RollingOut(CanaryRollout),
+}
+
+/// Where the local member is in a canary rollout.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RolloutPhase {
+    /// This member isn't a canary; waiting for the canaries to finish their soak period.
+    Waiting,
+    /// Running the newer package and watching health checks until the soak period is over.
+    Soaking,
+    /// Passed its soak period. Keeps running the newer package, but still reverts should
+    /// another member fail with it, until every member of the group runs it.
+    Promoted,
+}
+
+/// What the local member has seen of its service group during a canary rollout.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct RolloutView {
+    /// A member which had been running the newer package has gone back to another one.
+    reverted: bool,
+    /// Every canary runs the newer package.
+    canaries_updated: bool,
+    /// Every alive member runs the newer package.
+    all_updated: bool,
+    /// The local service's health check, or the gossiped health of a canary running the newer
+    /// package, is critical.
+    critical: bool,
+    /// The local member is one of the group's canaries.
+    canary: bool,
+}
+
+/// What the local member has to do after a step of a canary rollout.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RolloutStep {
+    /// Nothing to do yet.
+    Wait,
+    /// Start running the newer package.
+    Update,
+    /// The rollout failed; go back to the previous package.
+    Revert,
+    /// The rollout failed before this member ran the newer package.
+    Abandon,
+    /// Every member runs the newer package; the rollout is over.
+    Finish,
+}
+
+/// Tracks the local member's phase of a canary rollout and the soak period's timing.
+#[derive(Debug)]
+struct RolloutProgress {
+    phase: RolloutPhase,
+    /// When the current soak period started.
+    soak_started: Option<SteadyTime>,
+}
+
+impl RolloutProgress {
+    fn new(phase: RolloutPhase) -> Self {
+        RolloutProgress {
+            phase: phase,
+            soak_started: None,
+        }
+    }
+
+    fn step(
+        &mut self,
+        view: RolloutView,
+        soak_period: TimeDuration,
+        now: SteadyTime,
+    ) -> RolloutStep {
+        match self.phase {
+            RolloutPhase::Waiting => {
+                if view.reverted {
+                    RolloutStep::Abandon
+                } else if view.canary {
+                    // Membership changed and made us a canary; we don't wait on ourselves
+                    self.phase = RolloutPhase::Soaking;
+                    self.soak_started = None;
+                    RolloutStep::Update
+                } else if !view.canaries_updated {
+                    self.soak_started = None;
+                    RolloutStep::Wait
+                } else if self.soaked(soak_period, now) {
+                    self.phase = RolloutPhase::Soaking;
+                    self.soak_started = None;
+                    RolloutStep::Update
+                } else {
+                    RolloutStep::Wait
+                }
+            }
+            RolloutPhase::Soaking => {
+                if view.critical || view.reverted {
+                    RolloutStep::Revert
+                } else if self.soaked(soak_period, now) {
+                    self.phase = RolloutPhase::Promoted;
+                    RolloutStep::Wait
+                } else {
+                    RolloutStep::Wait
+                }
+            }
+            RolloutPhase::Promoted => {
+                if view.reverted {
+                    RolloutStep::Revert
+                } else if view.all_updated {
+                    RolloutStep::Finish
+                } else {
+                    RolloutStep::Wait
+                }
+            }
+        }
+    }
+
+    /// Returns true once the soak period has passed since it was first checked.
+    fn soaked(&mut self, soak_period: TimeDuration, now: SteadyTime) -> bool {
+        let started = match self.soak_started {
+            Some(started) => started,
+            None => {
+                self.soak_started = Some(now);
+                now
+            }
+        };
+        now - started >= soak_period
+    }
+}
+
+struct CanaryRollout {
+    /// The package being rolled out.
+    package: PackageInstall,
+    /// The package to revert to if the rollout fails.
+    previous: PackageInstall,
+    /// Members which have been seen running the package being rolled out.
+    updated_members: HashSet<String>,
+    progress: RolloutProgress,
+}
+
+impl CanaryRollout {
+    fn new(package: PackageInstall, previous: PackageInstall, phase: RolloutPhase) -> Self {
+        CanaryRollout {
+            package: package,
+            previous: previous,
+            updated_members: HashSet::new(),
+            progress: RolloutProgress::new(phase),
+        }
+    }
+
+    /// Records the members of the census group running the package being rolled out, and
+    /// returns what the local member sees of the rollout.
+    fn observe(&mut self, census_ring: &CensusRing, service: &Service) -> RolloutView {
+        let mut view = RolloutView::default();
+        view.critical = service.health_check() == HealthCheck::Critical;
+        let census_group = match census_ring.census_group_for(&service.service_group) {
+            Some(census_group) => census_group,
+            None => return view,
+        };
+        for member in census_group.members() {
+            if member.pkg.as_ref() == Some(&self.package.ident) {
+                self.updated_members.insert(member.member_id.clone());
+            } else if self.updated_members.contains(&member.member_id) {
+                outputln!(preamble service.service_group,
+                          "Canary update to {} reverted by {}",
+                          self.package.ident, member.member_id);
+                view.reverted = true;
+            }
+        }
+        let runs_package = |cm: &&CensusMember| cm.pkg.as_ref() == Some(&self.package.ident);
+        let canaries = census_group.canaries(service.canary_percent);
+        view.canaries_updated = canaries.iter().all(&runs_package);
+        view.critical = view.critical ||
+            canaries.iter().any(|cm| {
+                runs_package(cm) && cm.health() == HealthCheck::Critical
+            });
+        view.canary = match census_group.me() {
+            Some(me) => canaries.iter().any(|cm| cm.member_id == me.member_id),
+            None => false,
+        };
+        view.all_updated = census_group.canaries(100).iter().all(&runs_package);
+        view
+    }
+}
+
 pub struct ServiceUpdater {
     states: UpdaterStateList,
     butterfly: butterfly::Server,
@@ -75,6 +259,10 @@ impl ServiceUpdater {
     }
 
     pub fn add(&mut self, service: &Service) -> bool {
+        // A service pinned by a lockfile runs exactly the releases the lockfile lists.
+        if service.lockfile.is_some() {
+            return false;
+        }
         match service.update_strategy {
             UpdateStrategy::None => false,
             UpdateStrategy::AtOnce => {
@@ -92,6 +280,15 @@ impl ServiceUpdater {
                 );
                 true
             }
+            UpdateStrategy::Canary => {
+                self.states
+                    .entry(service.service_group.clone())
+                    .or_insert_with(|| {
+                        let rx = Worker::new(service).start(&service.service_group, None);
+                        UpdaterState::Canary(CanaryState::Polling(rx, None))
+                    });
+                true
+            }
         }
     }
 
@@ -280,14 +477,140 @@ impl ServiceUpdater {
                     *state = FollowerState::Waiting;
                 }
             }
+            Some(&mut UpdaterState::Canary(ref mut state)) => {
+                return Self::check_canary(state, service, census_ring);
+            }
             None => {}
         }
         updated
     }
+
+    fn check_canary(
+        state: &mut CanaryState,
+        service: &mut Service,
+        census_ring: &CensusRing,
+    ) -> bool {
+        let mut updated = false;
+        let next = match *state {
+            CanaryState::Polling(ref mut rx, ref mut rejected) => {
+                let package = match rx.try_recv() {
+                    Ok(package) => package,
+                    Err(TryRecvError::Empty) => return false,
+                    Err(TryRecvError::Disconnected) => {
+                        debug!("Service Updater worker has died; restarting...");
+                        *rx = Worker::new(service).rejecting(rejected.clone()).start(
+                            &service.service_group,
+                            None,
+                        );
+                        return false;
+                    }
+                };
+                let previous = match PackageInstall::load(
+                    &service.pkg.ident,
+                    Some(&Path::new(&*FS_ROOT_PATH)),
+                ) {
+                    Ok(previous) => previous,
+                    Err(err) => {
+                        outputln!(preamble service.service_group,
+                                  "Unable to load {} to revert to, skipping canary update to {}, {}",
+                                  service.pkg.ident, package.ident, err);
+                        *rx = Worker::new(service)
+                            .rejecting(Some(package.ident.clone()))
+                            .start(&service.service_group, None);
+                        *rejected = Some(package.ident);
+                        return false;
+                    }
+                };
+                if is_canary(census_ring, service) {
+                    outputln!(preamble service.service_group,
+                              "Canary update to {}", package.ident);
+                    service.update_package(package.clone());
+                    updated = true;
+                    CanaryState::RollingOut(
+                        CanaryRollout::new(package, previous, RolloutPhase::Soaking),
+                    )
+                } else {
+                    debug!("Canary update, waiting for canaries to soak");
+                    CanaryState::RollingOut(
+                        CanaryRollout::new(package, previous, RolloutPhase::Waiting),
+                    )
+                }
+            }
+            CanaryState::RollingOut(ref mut rollout) => {
+                let view = rollout.observe(census_ring, service);
+                let soak_period = TimeDuration::seconds(service.canary_soak_period as i64);
+                match rollout.progress.step(view, soak_period, SteadyTime::now()) {
+                    RolloutStep::Wait => return false,
+                    RolloutStep::Update => {
+                        debug!("Canary update, canaries are healthy; updating");
+                        service.update_package(rollout.package.clone());
+                        return true;
+                    }
+                    RolloutStep::Revert => {
+                        outputln!(preamble service.service_group,
+                                  "Canary update to {} failed, reverting to {}",
+                                  rollout.package.ident, rollout.previous.ident);
+                        service.update_package(rollout.previous.clone());
+                        updated = true;
+                        let rejected = rollout.package.ident.clone();
+                        CanaryState::Polling(
+                            Worker::new(service)
+                                .rejecting(Some(rejected.clone()))
+                                .start(&service.service_group, None),
+                            Some(rejected),
+                        )
+                    }
+                    RolloutStep::Abandon => {
+                        outputln!(preamble service.service_group,
+                                  "Canary update to {} failed, staying on {}",
+                                  rollout.package.ident, service.pkg.ident);
+                        let rejected = rollout.package.ident.clone();
+                        CanaryState::Polling(
+                            Worker::new(service)
+                                .rejecting(Some(rejected.clone()))
+                                .start(&service.service_group, None),
+                            Some(rejected),
+                        )
+                    }
+                    RolloutStep::Finish => {
+                        outputln!(preamble service.service_group,
+                                  "Canary update to {} has reached every member",
+                                  rollout.package.ident);
+                        CanaryState::Polling(
+                            Worker::new(service).start(&service.service_group, None),
+                            None,
+                        )
+                    }
+                }
+            }
+        };
+        *state = next;
+        updated
+    }
+}
+
+/// Returns true if the local member is one of the service group's canaries.
+fn is_canary(census_ring: &CensusRing, service: &Service) -> bool {
+    match census_ring.census_group_for(&service.service_group) {
+        Some(census_group) => {
+            match census_group.me() {
+                Some(me) => {
+                    census_group
+                        .canaries(service.canary_percent)
+                        .iter()
+                        .any(|cm| cm.member_id == me.member_id)
+                }
+                None => false,
+            }
+        }
+        None => false,
+    }
 }
 
 struct Worker {
     current: PackageIdent,
+    /// A package which failed a canary rollout and is never to be offered again
+    rejected: Option<PackageIdent>,
     spec_ident: PackageIdent,
     depot: depot_client::Client,
     channel: Option<String>,
@@ -299,6 +622,7 @@ impl Worker {
     fn new(service: &Service) -> Self {
         Worker {
             current: service.pkg.ident.clone(),
+            rejected: None,
             spec_ident: service.spec_ident.clone(),
             depot: depot_client::Client::new(&service.depot_url, PRODUCT, VERSION, None).unwrap(),
             channel: service.channel.clone(),
@@ -307,6 +631,11 @@ impl Worker {
         }
     }
 
+    fn rejecting(mut self, rejected: Option<PackageIdent>) -> Self {
+        self.rejected = rejected;
+        self
+    }
+
     /// Start a new update worker.
     ///
     /// Passing an optional package identifier will make the worker perform a run-once update to
@@ -352,10 +681,13 @@ impl Worker {
             match self.depot.show_package(
                 &self.spec_ident,
                 self.channel.as_ref().map(String::as_ref),
+                Some(&PackageTarget::default()),
             ) {
                 Ok(remote) => {
                     let latest: PackageIdent = remote.get_ident().clone().into();
-                    if latest > self.current {
+                    if self.rejected.as_ref() == Some(&latest) {
+                        debug!("Ignoring {}, which failed a canary update", latest);
+                    } else if latest > self.current {
                         outputln!("Updating from {} to {}", self.current, latest);
                         match self.install(&latest, true) {
                             Ok(pkg) => package = Some(pkg),
@@ -413,6 +745,7 @@ impl Worker {
         outputln!("Downloading {}", package);
         let mut archive = try!(self.depot.fetch_package(
             package,
+            &PackageTarget::default(),
             &Path::new(&*FS_ROOT_PATH).join(
                 CACHE_ARTIFACT_PATH,
             ),
@@ -446,3 +779,150 @@ impl Worker {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use time::{Duration as TimeDuration, SteadyTime};
+
+    use super::*;
+
+    fn soak() -> TimeDuration {
+        TimeDuration::seconds(60)
+    }
+
+    fn view(
+        reverted: bool,
+        canaries_updated: bool,
+        all_updated: bool,
+        critical: bool,
+    ) -> RolloutView {
+        RolloutView {
+            reverted: reverted,
+            canaries_updated: canaries_updated,
+            all_updated: all_updated,
+            critical: critical,
+            canary: false,
+        }
+    }
+
+    #[test]
+    fn waiting_member_updates_once_canaries_have_soaked() {
+        let now = SteadyTime::now();
+        let mut progress = RolloutProgress::new(RolloutPhase::Waiting);
+        let updated = view(false, true, false, false);
+        assert_eq!(progress.step(updated, soak(), now), RolloutStep::Wait);
+        assert_eq!(
+            progress.step(updated, soak(), now + TimeDuration::seconds(59)),
+            RolloutStep::Wait
+        );
+        assert_eq!(
+            progress.step(updated, soak(), now + soak()),
+            RolloutStep::Update
+        );
+        assert_eq!(progress.phase, RolloutPhase::Soaking);
+    }
+
+    #[test]
+    fn waiting_member_restarts_soak_when_a_canary_is_not_updated() {
+        let now = SteadyTime::now();
+        let mut progress = RolloutProgress::new(RolloutPhase::Waiting);
+        let updated = view(false, true, false, false);
+        assert_eq!(progress.step(updated, soak(), now), RolloutStep::Wait);
+        assert_eq!(
+            progress.step(view(false, false, false, false), soak(), now + soak()),
+            RolloutStep::Wait
+        );
+        assert_eq!(
+            progress.step(updated, soak(), now + soak()),
+            RolloutStep::Wait
+        );
+        assert_eq!(progress.phase, RolloutPhase::Waiting);
+    }
+
+    #[test]
+    fn waiting_member_updates_once_it_becomes_a_canary() {
+        let now = SteadyTime::now();
+        let mut progress = RolloutProgress::new(RolloutPhase::Waiting);
+        assert_eq!(
+            progress.step(view(false, false, false, false), soak(), now),
+            RolloutStep::Wait
+        );
+        let mut canary = view(false, false, false, false);
+        canary.canary = true;
+        assert_eq!(progress.step(canary, soak(), now), RolloutStep::Update);
+        assert_eq!(progress.phase, RolloutPhase::Soaking);
+    }
+
+    #[test]
+    fn waiting_member_abandons_a_reverted_rollout() {
+        let mut progress = RolloutProgress::new(RolloutPhase::Waiting);
+        assert_eq!(
+            progress.step(view(true, true, false, false), soak(), SteadyTime::now()),
+            RolloutStep::Abandon
+        );
+    }
+
+    #[test]
+    fn soaking_member_is_promoted_after_soak_period() {
+        let now = SteadyTime::now();
+        let mut progress = RolloutProgress::new(RolloutPhase::Soaking);
+        let healthy = view(false, true, false, false);
+        assert_eq!(progress.step(healthy, soak(), now), RolloutStep::Wait);
+        assert_eq!(progress.phase, RolloutPhase::Soaking);
+        assert_eq!(
+            progress.step(healthy, soak(), now + soak()),
+            RolloutStep::Wait
+        );
+        assert_eq!(progress.phase, RolloutPhase::Promoted);
+    }
+
+    #[test]
+    fn soaking_member_reverts_when_critical() {
+        let mut progress = RolloutProgress::new(RolloutPhase::Soaking);
+        assert_eq!(
+            progress.step(view(false, true, false, true), soak(), SteadyTime::now()),
+            RolloutStep::Revert
+        );
+    }
+
+    #[test]
+    fn soaking_member_reverts_when_another_member_reverted() {
+        let mut progress = RolloutProgress::new(RolloutPhase::Soaking);
+        assert_eq!(
+            progress.step(view(true, true, false, false), soak(), SteadyTime::now()),
+            RolloutStep::Revert
+        );
+    }
+
+    #[test]
+    fn promoted_member_reverts_when_another_member_reverted() {
+        let now = SteadyTime::now();
+        let mut progress = RolloutProgress::new(RolloutPhase::Promoted);
+        assert_eq!(
+            progress.step(view(false, true, false, false), soak(), now),
+            RolloutStep::Wait
+        );
+        assert_eq!(
+            progress.step(view(true, true, false, false), soak(), now),
+            RolloutStep::Revert
+        );
+    }
+
+    #[test]
+    fn promoted_member_ignores_its_own_later_health() {
+        let mut progress = RolloutProgress::new(RolloutPhase::Promoted);
+        assert_eq!(
+            progress.step(view(false, true, false, true), soak(), SteadyTime::now()),
+            RolloutStep::Wait
+        );
+    }
+
+    #[test]
+    fn promoted_member_finishes_once_every_member_is_updated() {
+        let mut progress = RolloutProgress::new(RolloutPhase::Promoted);
+        assert_eq!(
+            progress.step(view(false, true, true, false), soak(), SteadyTime::now()),
+            RolloutStep::Finish
+        );
+    }
+}
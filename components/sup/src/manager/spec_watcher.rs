@@ -15,7 +15,7 @@
 use std::collections::{HashMap, HashSet};
 use std::error::Error as StdErr;
 use std::ffi::OsStr;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::channel;
@@ -210,8 +210,16 @@ impl SpecWatcher {
     }
 
     pub fn specs_from_watch_path<'a>(&self) -> Result<HashMap<String, ServiceSpec>> {
+        Self::specs_from_path(&self.watch_path)
+    }
+
+    /// Reads every valid service spec file in the given directory, keyed by service name.
+    pub fn specs_from_path<P>(path: P) -> Result<HashMap<String, ServiceSpec>>
+    where
+        P: AsRef<Path>,
+    {
         let spec_files: Vec<PathBuf> =
-            glob(&self.watch_path.join(SPEC_FILE_GLOB).display().to_string())?
+            glob(&path.as_ref().join(SPEC_FILE_GLOB).display().to_string())?
                 .filter_map(|p| p.ok())
                 .filter(|p| p.is_file())
                 .collect();
@@ -0,0 +1,37 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use handlebars::{Handlebars, Helper, HelperDef, RenderContext};
+use serde_json::{Map, Value as Json};
+
+use super::super::RenderResult;
+use super::each_member::each_member;
+
+#[derive(Clone, Copy)]
+pub struct EachHealthyHelper;
+
+impl HelperDef for EachHealthyHelper {
+    fn call(&self, h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult<()> {
+        each_member("eachHealthy", h, r, rc, is_healthy)
+    }
+}
+/// A census member is healthy when it is alive and its last health check wasn't critical. Members
+/// which haven't reported their health yet are considered healthy.
+fn is_healthy(member: &Map<String, Json>) -> bool {
+    let alive = member.get("alive").and_then(|a| a.as_bool()).unwrap_or(false);
+    let critical = member.get("health").and_then(|h| h.as_str()) == Some("Critical");
+    alive && !critical
+}
+
+pub static EACH_HEALTHY: EachHealthyHelper = EachHealthyHelper;
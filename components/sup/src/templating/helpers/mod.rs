@@ -13,6 +13,8 @@
 // limitations under the License.
 
 mod each_alive;
+mod each_healthy;
+mod each_member;
 mod pkg_path_for;
 mod str_replace;
 mod to_json;
@@ -25,6 +27,7 @@ use serde::Serialize;
 use serde_json::{self, Value as Json};
 
 pub use self::each_alive::EACH_ALIVE;
+pub use self::each_healthy::EACH_HEALTHY;
 pub use self::pkg_path_for::PKG_PATH_FOR;
 pub use self::str_replace::STR_REPLACE;
 pub use self::to_json::TO_JSON;
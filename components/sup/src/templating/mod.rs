@@ -36,6 +36,7 @@ impl TemplateRenderer {
     pub fn new() -> Self {
         let mut handlebars = Handlebars::new();
         handlebars.register_helper("eachAlive", Box::new(helpers::EACH_ALIVE));
+        handlebars.register_helper("eachHealthy", Box::new(helpers::EACH_HEALTHY));
         handlebars.register_helper("pkgPathFor", Box::new(helpers::PKG_PATH_FOR));
         handlebars.register_helper("strReplace", Box::new(helpers::STR_REPLACE));
         handlebars.register_helper("toUppercase", Box::new(helpers::TO_UPPERCASE));
@@ -308,4 +309,19 @@ test: something"#
 
         assert_eq!(each_alive_render, each_if_render);
     }
+
+    #[test]
+    fn each_healthy_helper_content() {
+        let content = "{{#eachHealthy svc.members as |member|}}{{member.member_id}};{{/eachHealthy}}"
+            .to_string();
+        let mut renderer = TemplateRenderer::new();
+        renderer.register_template_string("t", content).unwrap();
+
+        let data = service_config_json_from_toml_file("multiple_supervisors_health_config.toml");
+        let rendered = renderer.render("t", &data).unwrap();
+        assert_eq!(
+            rendered,
+            "b162bfc10cf54eb4bce93689a8023eb9;2e5e6d2b17b34fd7a1d1e6bc22d4cb5a;"
+        );
+    }
 }
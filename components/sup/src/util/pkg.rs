@@ -19,7 +19,7 @@ use common;
 use common::ui::UI;
 use depot_client::Client;
 use hcore::fs::{self, FS_ROOT_PATH};
-use hcore::package::{PackageIdent, PackageInstall};
+use hcore::package::{Lockfile, PackageIdent, PackageInstall, PackageTarget};
 
 use {PRODUCT, VERSION};
 use error::Result;
@@ -44,6 +44,7 @@ pub fn install(
         VERSION,
         fs_root_path,
         &fs::cache_artifact_path(None),
+        &PackageTarget::default(),
         false,
     )?;
     Ok(PackageInstall::load(&installed_ident, Some(&fs_root_path))?)
@@ -56,7 +57,11 @@ pub fn maybe_install_newer(
 ) -> Result<PackageInstall> {
     let latest_ident: PackageIdent = {
         let depot_client = Client::new(&spec.depot_url, PRODUCT, VERSION, None)?;
-        match depot_client.show_package(&spec.ident, spec.channel.as_ref().map(String::as_ref)) {
+        match depot_client.show_package(
+            &spec.ident,
+            spec.channel.as_ref().map(String::as_ref),
+            Some(&PackageTarget::default()),
+        ) {
             Ok(pkg) => pkg.get_ident().clone().into(),
             Err(_) => return Ok(current),
         }
@@ -85,7 +90,38 @@ pub fn maybe_install_newer(
     }
 }
 
+/// Installs the releases a lockfile pins, unless the package it was made for is installed.
+pub fn install_from_lockfile(
+    ui: &mut UI,
+    url: &str,
+    lockfile: &Lockfile,
+) -> Result<PackageInstall> {
+    let fs_root_path = Path::new(&*FS_ROOT_PATH);
+    if let Ok(package) = PackageInstall::load(&lockfile.ident, Some(&fs_root_path)) {
+        return Ok(package);
+    }
+    outputln!(
+        "{} not found in local package cache, installing from lockfile",
+        Yellow.bold().paint(lockfile.ident.to_string())
+    );
+    let installed_ident = common::command::package::install::start_from_lockfile(
+        ui,
+        url,
+        lockfile,
+        PRODUCT,
+        VERSION,
+        fs_root_path,
+        &fs::cache_artifact_path(None),
+        &PackageTarget::default(),
+        false,
+    )?;
+    Ok(PackageInstall::load(&installed_ident, Some(&fs_root_path))?)
+}
+
 pub fn install_from_spec(ui: &mut UI, spec: &ServiceSpec) -> Result<PackageInstall> {
+    if let Some(ref lockfile) = spec.lockfile {
+        return install_from_lockfile(ui, spec.depot_url.as_str(), lockfile);
+    }
     match PackageInstall::load(&spec.ident, Some(&Path::new(&*FS_ROOT_PATH))) {
         Ok(package) => {
             match spec.update_strategy {
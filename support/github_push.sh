@@ -0,0 +1,26 @@
+#!/bin/bash
+
+# Stands in for GitHub by POSTing a push event payload, signed with a project's webhook secret,
+# to a locally running builder-api.
+#
+# Usage: github_push.sh <origin> <secret> [payload.json]
+
+set -eu
+
+if [ $# -lt 2 ]; then
+    echo "Usage: $0 <origin> <secret> [payload.json]" >&2
+    exit 1
+fi
+
+origin="$1"
+secret="$2"
+payload="${3:-components/builder-api/tests/fixtures/github-push.json}"
+url="${BLDR_API_URL:-http://localhost:9636/v1}/notify/github/${origin}"
+
+signature="sha1=$(openssl dgst -sha1 -hmac "$secret" < "$payload" | sed 's/^.* //')"
+
+curl -i -X POST "$url" \
+    -H "Content-Type: application/json" \
+    -H "X-GitHub-Event: push" \
+    -H "X-Hub-Signature: ${signature}" \
+    --data-binary "@${payload}"